@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+set -e
+
+err() {
+    echo -e "\e[31m\e[1merror:\e[0m $@" 1>&2;
+}
+
+status() {
+    WIDTH=12
+    printf "\e[32m\e[1m%${WIDTH}s\e[0m %s\n" "$1" "$2"
+}
+
+TARGET=${1:-riscv32imac-unknown-none-elf}
+ARGS="-p fuel-vm --no-default-features --features zk-guest"
+
+# Dependencies that bring std, randomness or threads into the guest
+DENIED='feature "std"|getrandom|rayon|tokio|secp256k1|backtrace'
+
+status "Checking" "dependencies of the zk-guest build"
+DENIED_DEPS=$(cargo tree $ARGS -e normal,features --prefix none | grep -E "$DENIED" | sort -u)
+if [ -n "$DENIED_DEPS" ]; then
+    err "The zk-guest build depends on:\n$DENIED_DEPS"
+    exit 1
+fi
+
+status "Building" "zk-guest for $TARGET"
+cargo build $ARGS --target "$TARGET" --profile zk-guest
@@ -7,6 +7,7 @@
 # - Nightly rust formatter
 # - `rustup target add thumbv6m-none-eabi`
 # - `rustup target add wasm32-unknown-unknown`
+# - `rustup target add x86_64-unknown-none`
 # - `cargo install cargo-sort`
 
 cargo +nightly fmt --all -- --check &&
@@ -27,8 +28,11 @@ cargo check --target wasm32-unknown-unknown -p fuel-crypto --no-default-features
 cargo check --target wasm32-unknown-unknown -p fuel-types --features serde --no-default-features &&
 cargo check --target wasm32-unknown-unknown -p fuel-tx --features serde --no-default-features &&
 cargo check --target wasm32-unknown-unknown -p fuel-vm --features alloc --no-default-features &&
+cargo check --target x86_64-unknown-none -p fuel-vm --features alloc --no-default-features &&
+cargo check --target wasm32-unknown-unknown -p fuel-vm --features memory-storage,serde --no-default-features &&
 cargo rustc --target wasm32-unknown-unknown -p fuel-types --features typescript --crate-type=cdylib &&
 cargo rustc --target wasm32-unknown-unknown -p fuel-asm --features typescript --crate-type=cdylib &&
+cargo rustc -p fuel-vm --features capi --crate-type=cdylib &&
 cargo make check &&
 cargo test --all-targets --all-features &&
 cargo test --all-targets --no-default-features &&
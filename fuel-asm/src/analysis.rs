@@ -0,0 +1,289 @@
+//! Static properties of instructions, useful for analyzing programs without
+//! executing them.
+
+use crate::{
+    Instruction,
+    Opcode,
+    RegId,
+};
+
+/// The group of an opcode, following the sections of the instruction set
+/// specification.
+///
+/// <https://github.com/FuelLabs/fuel-specs/blob/master/src/fuel-vm/instruction-set.md>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OpcodeGroup {
+    /// Arithmetic and logic, including the wide integer operations.
+    Arithmetic,
+    /// Jumps and returning from the current context.
+    ControlFlow,
+    /// Memory access, allocation and stack manipulation.
+    Memory,
+    /// Contract calls, storage, assets, logs and blockchain state.
+    Contract,
+    /// Hashing and signature verification.
+    Crypto,
+    /// Flags, transaction and context metadata, and external calls.
+    Other,
+}
+
+/// A set of registers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RegisterSet(u64);
+
+impl RegisterSet {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    fn bit(reg: RegId) -> u64 {
+        1u64.checked_shl(u32::from(reg.to_u8())).unwrap_or_default()
+    }
+
+    /// Adds the register to the set.
+    pub fn insert(&mut self, reg: RegId) {
+        self.0 |= Self::bit(reg);
+    }
+
+    /// Returns `true` if the set contains the register.
+    pub fn contains(&self, reg: RegId) -> bool {
+        self.0 & Self::bit(reg) != 0
+    }
+
+    /// Returns `true` if the set contains no registers.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Number of registers in the set.
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Registers present in either set.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Iterates over the registers in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RegId> + '_ {
+        (0u8..64).map(RegId::new).filter(|reg| self.contains(*reg))
+    }
+}
+
+impl FromIterator<RegId> for RegisterSet {
+    fn from_iter<I: IntoIterator<Item = RegId>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<RegId> for RegisterSet {
+    fn extend<I: IntoIterator<Item = RegId>>(&mut self, iter: I) {
+        for reg in iter {
+            self.insert(reg);
+        }
+    }
+}
+
+impl Opcode {
+    /// The group of the opcode, as specified in the instruction set.
+    pub const fn group(&self) -> OpcodeGroup {
+        use Opcode::*;
+        match self {
+            ADD | AND | DIV | EQ | EXP | GT | LT | MLOG | MROO | MOD | MOVE | MUL
+            | NOT | OR | SLL | SRL | SUB | XOR | MLDV | NOOP | ADDI | ANDI | DIVI
+            | EXPI | MODI | MULI | ORI | SLLI | SRLI | SUBI | XORI | MOVI | WDCM
+            | WQCM | WDOP | WQOP | WDML | WQML | WDDV | WQDV | WDMD | WQMD | WDAM
+            | WQAM | WDMM | WQMM => OpcodeGroup::Arithmetic,
+            RET | JMP | JNE | JNEI | JNZI | JMPF | JMPB | JNZF | JNZB | JNEF | JNEB
+            | JI => OpcodeGroup::ControlFlow,
+            ALOC | MCL | MCP | MEQ | LB | LW | SB | SW | MCPI | MCLI | CFEI | CFSI
+            | CFE | CFS | PSHL | PSHH | POPL | POPH => OpcodeGroup::Memory,
+            RETD | BHSH | BHEI | BURN | CALL | CCP | CROO | CSIZ | CB | LDC | LOG
+            | LOGD | MINT | RVRT | SCWQ | SRW | SRWQ | SWW | SWWQ | TR | TRO | TIME
+            | BAL | SMO | BSIZ | BLDD | ASSERT => OpcodeGroup::Contract,
+            ECK1 | ECR1 | ED19 | K256 | S256 => OpcodeGroup::Crypto,
+            FLAG | GM | GTF | ECAL => OpcodeGroup::Other,
+        }
+    }
+
+    /// Returns `true` if the instruction may continue at an instruction other than
+    /// the next one, i.e. it is a jump.
+    pub const fn can_branch(&self) -> bool {
+        use Opcode::*;
+        matches!(
+            self,
+            JMP | JNE | JNEI | JNZI | JMPF | JMPB | JNZF | JNZB | JNEF | JNEB | JI
+        )
+    }
+
+    /// Returns `true` if the instruction may end the execution of the current context.
+    /// `ASSERT` only ends it if the condition is false, so it can also fall through.
+    pub const fn can_halt(&self) -> bool {
+        matches!(
+            self,
+            Opcode::RET | Opcode::RETD | Opcode::RVRT | Opcode::ASSERT
+        )
+    }
+
+    /// Returns `true` if execution may proceed to the next instruction without
+    /// taking a jump, i.e. the instruction is neither an unconditional jump nor
+    /// always halts.
+    pub const fn can_fall_through(&self) -> bool {
+        use Opcode::*;
+        !matches!(self, RET | RETD | RVRT | JMP | JMPF | JMPB | JI)
+    }
+
+    /// Bitmask of the register operands the instruction writes to, where the lowest
+    /// bit is the first operand.
+    const fn written_operands(&self) -> u8 {
+        use Opcode::*;
+        match self {
+            ADD | AND | DIV | EQ | EXP | GT | LT | MLOG | MROO | MOD | MOVE | MUL
+            | NOT | OR | SLL | SRL | SUB | XOR | MLDV | ADDI | ANDI | DIVI | EXPI
+            | MODI | MULI | ORI | SLLI | SRLI | SUBI | XORI | MOVI | WDCM | WQCM | LB
+            | LW | MEQ | BHEI | CSIZ | TIME | BAL | GM | GTF | BSIZ => 0b0001,
+            SRW => 0b0011,
+            SCWQ | SRWQ | SWW | SWWQ => 0b0010,
+            _ => 0,
+        }
+    }
+}
+
+impl Instruction {
+    /// The group of the instruction's opcode.
+    pub fn group(&self) -> OpcodeGroup {
+        self.opcode().group()
+    }
+
+    /// Returns `true` if the instruction may continue at an instruction other than
+    /// the next one. See [`Opcode::can_branch`].
+    pub fn can_branch(&self) -> bool {
+        self.opcode().can_branch()
+    }
+
+    /// Returns `true` if the instruction may end the execution of the current context.
+    /// See [`Opcode::can_halt`].
+    pub fn can_halt(&self) -> bool {
+        self.opcode().can_halt()
+    }
+
+    /// Returns `true` if execution may proceed to the next instruction without taking
+    /// a jump. See [`Opcode::can_fall_through`].
+    pub fn can_fall_through(&self) -> bool {
+        self.opcode().can_fall_through()
+    }
+
+    /// Registers whose values are read by the instruction.
+    ///
+    /// Covers the register operands, and the registers selected by the bitmask of
+    /// `PSHL` and `PSHH`. Implicit uses of reserved registers, e.g. `$pc` or `$sp`,
+    /// aren't included.
+    pub fn read_registers(&self) -> RegisterSet {
+        let written = self.opcode().written_operands();
+        let mut set = self.operands(|index| written & (1 << index) == 0);
+        match self {
+            Self::PSHL(op) => set.extend(bitmask_registers(16, op.imm24().to_u32())),
+            Self::PSHH(op) => set.extend(bitmask_registers(40, op.imm24().to_u32())),
+            _ => {}
+        }
+        set
+    }
+
+    /// Registers written by the instruction.
+    ///
+    /// Covers the register operands, and the registers selected by the bitmask of
+    /// `POPL` and `POPH`. Implicit writes to reserved registers, e.g. `$of`, `$err`
+    /// or `$sp`, aren't included.
+    pub fn written_registers(&self) -> RegisterSet {
+        let written = self.opcode().written_operands();
+        let mut set = self.operands(|index| written & (1 << index) != 0);
+        match self {
+            Self::POPL(op) => set.extend(bitmask_registers(16, op.imm24().to_u32())),
+            Self::POPH(op) => set.extend(bitmask_registers(40, op.imm24().to_u32())),
+            _ => {}
+        }
+        set
+    }
+
+    fn operands(&self, filter: impl Fn(usize) -> bool) -> RegisterSet {
+        self.reg_ids()
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| filter(*index))
+            .filter_map(|(_, reg)| reg)
+            .collect()
+    }
+}
+
+/// Registers selected by the bitmask of the push and pop instructions, where the
+/// lowest bit selects the register `first`.
+fn bitmask_registers(first: u8, bitmask: u32) -> impl Iterator<Item = RegId> {
+    (0u8..24)
+        .filter(move |bit| bitmask & (1 << bit) != 0)
+        .map(move |bit| RegId::new(first.saturating_add(bit)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op;
+
+    fn regs<const N: usize>(regs: [u8; N]) -> RegisterSet {
+        regs.into_iter().map(RegId::new).collect()
+    }
+
+    #[test]
+    fn group_follows_the_specification() {
+        assert_eq!(op::add(0x10, 0x11, 0x12).group(), OpcodeGroup::Arithmetic);
+        assert_eq!(op::jnzi(0x10, 0).group(), OpcodeGroup::ControlFlow);
+        assert_eq!(op::mcp(0x10, 0x11, 0x12).group(), OpcodeGroup::Memory);
+        assert_eq!(op::sww(0x10, 0x11, 0x12).group(), OpcodeGroup::Contract);
+        assert_eq!(op::k256(0x10, 0x11, 0x12).group(), OpcodeGroup::Crypto);
+        assert_eq!(op::gtf(0x10, 0x11, 0).group(), OpcodeGroup::Other);
+    }
+
+    #[test]
+    fn registers_distinguish_reads_and_writes() {
+        let add = op::add(0x10, 0x11, 0x12);
+        assert_eq!(add.read_registers(), regs([0x11, 0x12]));
+        assert_eq!(add.written_registers(), regs([0x10]));
+
+        let srw = op::srw(0x10, 0x11, 0x12);
+        assert_eq!(srw.read_registers(), regs([0x12]));
+        assert_eq!(srw.written_registers(), regs([0x10, 0x11]));
+
+        let sw = op::sw(0x10, 0x11, 0);
+        assert_eq!(sw.read_registers(), regs([0x10, 0x11]));
+        assert!(sw.written_registers().is_empty());
+    }
+
+    #[test]
+    fn registers_include_push_and_pop_bitmasks() {
+        let pshl = op::pshl(0b101);
+        assert_eq!(pshl.read_registers(), regs([16, 18]));
+        assert!(pshl.written_registers().is_empty());
+
+        let poph = op::poph(1 << 23);
+        assert_eq!(poph.written_registers(), regs([63]));
+        assert!(poph.written_registers().iter().eq([RegId::new(63)]));
+    }
+
+    #[test]
+    fn control_flow_properties() {
+        assert!(op::jnzf(0x10, RegId::ZERO, 0).can_branch());
+        assert!(op::jnzf(0x10, RegId::ZERO, 0).can_fall_through());
+        assert!(op::ji(0).can_branch());
+        assert!(!op::ji(0).can_fall_through());
+        assert!(op::rvrt(RegId::ONE).can_halt());
+        assert!(!op::rvrt(RegId::ONE).can_fall_through());
+        assert!(op::assert(0x10, 0x11, 0x12, 0x13).can_halt());
+        assert!(op::assert(0x10, 0x11, 0x12, 0x13).can_fall_through());
+        assert!(!op::assert(0x10, 0x11, 0x12, 0x13).can_branch());
+        assert!(!op::call(0x10, 0x11, 0x12, 0x13).can_branch());
+        assert!(op::call(0x10, 0x11, 0x12, 0x13).can_fall_through());
+    }
+}
@@ -0,0 +1,473 @@
+//! Parsing of textual assembly into instructions.
+
+use crate::{
+    pack,
+    Imm06,
+    Imm12,
+    Imm18,
+    Imm24,
+    Instruction,
+    Opcode,
+    RegId,
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::fmt;
+
+/// The reason a line of assembly couldn't be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AsmParseErrorKind {
+    /// The mnemonic doesn't name any opcode.
+    UnknownMnemonic(String),
+    /// The operand is neither a register, an immediate nor a label.
+    InvalidOperand(String),
+    /// The operands don't match the layout of the instruction.
+    OperandMismatch(Opcode),
+    /// The immediate doesn't fit into the immediate field of the instruction.
+    ImmediateOutOfRange(u32),
+    /// The label is defined more than once.
+    DuplicateLabel(String),
+    /// The label is referenced but never defined.
+    UndefinedLabel(String),
+    /// The label can't be reached in the direction of the relative jump.
+    UnreachableLabel(String),
+}
+
+/// An error produced by [`parse_asm`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AsmParseError {
+    /// The 1-based number of the line that failed to parse.
+    pub line: usize,
+    /// The reason the line failed to parse.
+    pub kind: AsmParseErrorKind,
+}
+
+impl fmt::Display for AsmParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            AsmParseErrorKind::UnknownMnemonic(mnemonic) => {
+                write!(f, "unknown mnemonic `{mnemonic}`")
+            }
+            AsmParseErrorKind::InvalidOperand(operand) => {
+                write!(f, "invalid operand `{operand}`")
+            }
+            AsmParseErrorKind::OperandMismatch(opcode) => {
+                write!(f, "operands don't match the layout of {opcode:?}")
+            }
+            AsmParseErrorKind::ImmediateOutOfRange(imm) => {
+                write!(f, "immediate `{imm}` is out of range")
+            }
+            AsmParseErrorKind::DuplicateLabel(label) => {
+                write!(f, "label `{label}` is defined more than once")
+            }
+            AsmParseErrorKind::UndefinedLabel(label) => {
+                write!(f, "label `{label}` is not defined")
+            }
+            AsmParseErrorKind::UnreachableLabel(label) => {
+                write!(f, "label `{label}` is not reachable by the relative jump")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsmParseError {}
+
+/// A parsed but not yet encoded instruction.
+struct Statement<'a> {
+    line: usize,
+    opcode: Opcode,
+    operands: Vec<&'a str>,
+}
+
+/// An operand after label resolution.
+enum Operand {
+    Reg(RegId),
+    Imm(u32),
+}
+
+/// Parses assembly text into instructions.
+///
+/// Each line holds at most one instruction, written as a case-insensitive mnemonic
+/// followed by its operands, separated by whitespace or commas. Registers are written
+/// as `$r16` or by their reserved name, e.g. `$sp`. Immediates are decimal, `0x`
+/// hexadecimal or `0b` binary numbers, optionally prefixed with `i`. Everything after
+/// `;` is a comment.
+///
+/// A line may start with any number of `label:` definitions. A label used in place of
+/// an immediate resolves to the index of the instruction following its definition.
+/// For relative jumps, such as `JMPF`, it resolves to the offset from the jump,
+/// assuming the dynamic offset register is `$zero`.
+///
+/// Lines produced by [`disassemble`](crate::disassemble) are accepted as well; the
+/// leading offset and raw bytes are skipped.
+pub fn parse_asm(asm: &str) -> Result<Vec<Instruction>, AsmParseError> {
+    let mut labels = BTreeMap::new();
+    let mut statements = Vec::new();
+
+    for (index, line) in asm.lines().enumerate() {
+        let line_number = index.saturating_add(1);
+        let error = |kind| AsmParseError {
+            line: line_number,
+            kind,
+        };
+
+        let code = line.split(';').next().unwrap_or_default();
+        let mut tokens: Vec<&str> = code
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .collect();
+
+        skip_listing_prefix(&mut tokens);
+
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(label) = tokens.peek().and_then(|token| token.strip_suffix(':')) {
+            if !is_label(label) {
+                return Err(error(AsmParseErrorKind::InvalidOperand(label.to_string())))
+            }
+            if labels.insert(label, statements.len()).is_some() {
+                return Err(error(AsmParseErrorKind::DuplicateLabel(label.to_string())))
+            }
+            tokens.next();
+        }
+
+        let Some(mnemonic) = tokens.next() else {
+            continue
+        };
+        let opcode = mnemonic.parse().map_err(|_| {
+            error(AsmParseErrorKind::UnknownMnemonic(mnemonic.to_string()))
+        })?;
+
+        statements.push(Statement {
+            line: line_number,
+            opcode,
+            operands: tokens.collect(),
+        });
+    }
+
+    statements
+        .iter()
+        .enumerate()
+        .map(|(index, statement)| {
+            let operands = statement
+                .operands
+                .iter()
+                .map(|operand| resolve_operand(operand, statement.opcode, index, &labels))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|kind| AsmParseError {
+                    line: statement.line,
+                    kind,
+                })?;
+
+            encode(statement.opcode, &operands).map_err(|kind| AsmParseError {
+                line: statement.line,
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// Removes the offset and raw bytes that start a line of disassembler output.
+fn skip_listing_prefix(tokens: &mut Vec<&str>) {
+    let is_offset = tokens.first().is_some_and(|token| {
+        token.strip_suffix(':').is_some_and(|offset| {
+            offset.len() == 8 && offset.chars().all(|c| c.is_ascii_hexdigit())
+        })
+    });
+    let byte_count = tokens
+        .iter()
+        .skip(1)
+        .take(Instruction::SIZE)
+        .take_while(|token| {
+            token.len() == 2 && token.chars().all(|c| c.is_ascii_hexdigit())
+        })
+        .count();
+
+    if is_offset && byte_count == Instruction::SIZE {
+        tokens.drain(..=Instruction::SIZE);
+    }
+}
+
+fn is_label(token: &str) -> bool {
+    let mut chars = token.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '.')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+fn resolve_operand(
+    operand: &str,
+    opcode: Opcode,
+    index: usize,
+    labels: &BTreeMap<&str, usize>,
+) -> Result<Operand, AsmParseErrorKind> {
+    let invalid = || AsmParseErrorKind::InvalidOperand(operand.to_string());
+
+    if let Some(reg) = operand.strip_prefix('$') {
+        return parse_reg(reg).map(Operand::Reg).ok_or_else(invalid)
+    }
+
+    let number = operand.strip_prefix('i').unwrap_or(operand);
+    if number.starts_with(|c: char| c.is_ascii_digit()) {
+        return parse_number(number).map(Operand::Imm).ok_or_else(invalid)
+    }
+
+    if !is_label(operand) {
+        return Err(invalid())
+    }
+
+    let target = *labels
+        .get(operand)
+        .ok_or_else(|| AsmParseErrorKind::UndefinedLabel(operand.to_string()))?;
+    let unreachable = || AsmParseErrorKind::UnreachableLabel(operand.to_string());
+
+    let imm = match opcode {
+        Opcode::JMPF | Opcode::JNZF | Opcode::JNEF => target
+            .checked_sub(index)
+            .and_then(|offset| offset.checked_sub(1))
+            .ok_or_else(unreachable)?,
+        Opcode::JMPB | Opcode::JNZB | Opcode::JNEB => index
+            .checked_sub(target)
+            .and_then(|offset| offset.checked_sub(1))
+            .ok_or_else(unreachable)?,
+        _ => target,
+    };
+
+    u32::try_from(imm)
+        .map(Operand::Imm)
+        .map_err(|_| unreachable())
+}
+
+fn parse_reg(reg: &str) -> Option<RegId> {
+    if let Some(id) = reg.strip_prefix('r').and_then(|id| id.parse().ok()) {
+        return RegId::new_checked(id)
+    }
+
+    (0..RegId::WRITABLE.to_u8())
+        .map(RegId::new)
+        .find(|id| id.reserved_name() == Some(reg))
+}
+
+fn parse_number(number: &str) -> Option<u32> {
+    if let Some(hex) = number.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16).ok()
+    } else if let Some(bin) = number.strip_prefix("0b") {
+        u32::from_str_radix(bin, 2).ok()
+    } else {
+        number.parse().ok()
+    }
+}
+
+/// Encodes the instruction, verifying that the operands match its layout.
+fn encode(
+    opcode: Opcode,
+    operands: &[Operand],
+) -> Result<Instruction, AsmParseErrorKind> {
+    let mismatch = || AsmParseErrorKind::OperandMismatch(opcode);
+
+    let (imm, regs) = match operands.split_last() {
+        Some((Operand::Imm(imm), regs)) => (Some(*imm), regs),
+        _ => (None, operands),
+    };
+    let regs = regs
+        .iter()
+        .map(|operand| match operand {
+            Operand::Reg(reg) => Ok(*reg),
+            Operand::Imm(_) => Err(mismatch()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let out_of_range = |imm| AsmParseErrorKind::ImmediateOutOfRange(imm);
+    let imm06 = |imm| {
+        u8::try_from(imm)
+            .ok()
+            .and_then(Imm06::new_checked)
+            .ok_or(out_of_range(imm))
+    };
+    let imm12 = |imm| {
+        u16::try_from(imm)
+            .ok()
+            .and_then(Imm12::new_checked)
+            .ok_or(out_of_range(imm))
+    };
+
+    let bytes = match (regs.as_slice(), imm) {
+        ([], None) => [0; 3],
+        ([ra], None) => pack::bytes_from_ra(*ra),
+        ([ra, rb], None) => pack::bytes_from_ra_rb(*ra, *rb),
+        ([ra, rb, rc], None) => pack::bytes_from_ra_rb_rc(*ra, *rb, *rc),
+        ([ra, rb, rc, rd], None) => pack::bytes_from_ra_rb_rc_rd(*ra, *rb, *rc, *rd),
+        ([ra, rb, rc], Some(imm)) => {
+            pack::bytes_from_ra_rb_rc_imm06(*ra, *rb, *rc, imm06(imm)?)
+        }
+        ([ra, rb], Some(imm)) => pack::bytes_from_ra_rb_imm12(*ra, *rb, imm12(imm)?),
+        ([ra], Some(imm)) => pack::bytes_from_ra_imm18(
+            *ra,
+            Imm18::new_checked(imm).ok_or(out_of_range(imm))?,
+        ),
+        ([], Some(imm)) => {
+            pack::bytes_from_imm24(Imm24::new_checked(imm).ok_or(out_of_range(imm))?)
+        }
+        _ => return Err(mismatch()),
+    };
+
+    let [a, b, c] = bytes;
+    let instruction =
+        Instruction::try_from([opcode as u8, a, b, c]).map_err(|_| mismatch())?;
+
+    // Decoding only checks that the unused bits are zero, so an operand list
+    // shorter than the layout would still decode, e.g. `ADD` with two registers.
+    let reg_count = instruction.reg_ids().into_iter().flatten().count();
+    if reg_count != regs.len() || instruction.imm().is_some() != imm.is_some() {
+        return Err(mismatch())
+    }
+
+    Ok(instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        disassemble,
+        op,
+    };
+    use alloc::vec;
+
+    #[test]
+    fn parse_asm_parses_mnemonics_and_operands() {
+        let asm = "
+            MOVI $r16, 0x2a   ; load the answer
+            add $r17 $one $r16
+            gtf $r18 $zero i10
+            wqop $r16 $r17 $r18 0b100000
+            noop
+            ret $one
+        ";
+
+        let program = parse_asm(asm).expect("valid assembly");
+
+        assert_eq!(
+            program,
+            vec![
+                op::movi(0x10, 42),
+                op::add(0x11, RegId::ONE, 0x10),
+                op::gtf(0x12, RegId::ZERO, 10),
+                op::wqop(0x10, 0x11, 0x12, 0b100000),
+                op::noop(),
+                op::ret(RegId::ONE),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_asm_resolves_labels() {
+        let asm = "
+            start:
+                ji end
+            loop: subi $r16 $r16 1
+                jnzb $r16 $zero loop
+                jmpf $zero end
+                jnei $r16 $zero start
+            end:
+                ret $one
+        ";
+
+        let program = parse_asm(asm).expect("valid assembly");
+
+        assert_eq!(
+            program,
+            vec![
+                op::ji(5),
+                op::subi(0x10, 0x10, 1),
+                op::jnzb(0x10, RegId::ZERO, 0),
+                op::jmpf(RegId::ZERO, 1),
+                op::jnei(0x10, RegId::ZERO, 0),
+                op::ret(RegId::ONE),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_asm_round_trips_disassembly() {
+        let program = vec![
+            op::movi(0x10, 3),
+            op::subi(0x10, 0x10, 1),
+            op::jnzb(0x10, RegId::ZERO, 0),
+            op::gm(0x11, 4),
+            op::cb(0x12),
+            op::ji(0),
+        ];
+        let bytes: Vec<u8> = program.iter().copied().collect();
+
+        let listing: Vec<_> = disassemble(&bytes)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(parse_asm(&listing.join("\n")), Ok(program));
+    }
+
+    #[test]
+    fn parse_asm_reports_errors_with_line_numbers() {
+        let cases = [
+            (
+                "noop\nfoo $r1",
+                2,
+                AsmParseErrorKind::UnknownMnemonic("foo".into()),
+            ),
+            (
+                "add $r1 $r2",
+                1,
+                AsmParseErrorKind::OperandMismatch(Opcode::ADD),
+            ),
+            (
+                "add $r1 $r2 $r3 $r4",
+                1,
+                AsmParseErrorKind::OperandMismatch(Opcode::ADD),
+            ),
+            (
+                "ret $one i0",
+                1,
+                AsmParseErrorKind::OperandMismatch(Opcode::RET),
+            ),
+            (
+                "addi $r1 $r2 4096",
+                1,
+                AsmParseErrorKind::ImmediateOutOfRange(4096),
+            ),
+            (
+                "move $r64 $zero",
+                1,
+                AsmParseErrorKind::InvalidOperand("$r64".into()),
+            ),
+            (
+                "ji nowhere",
+                1,
+                AsmParseErrorKind::UndefinedLabel("nowhere".into()),
+            ),
+            (
+                "a:\na: noop",
+                2,
+                AsmParseErrorKind::DuplicateLabel("a".into()),
+            ),
+            (
+                "back: noop\njmpf $zero back",
+                2,
+                AsmParseErrorKind::UnreachableLabel("back".into()),
+            ),
+        ];
+
+        for (asm, line, kind) in cases {
+            assert_eq!(parse_asm(asm), Err(AsmParseError { line, kind }), "{asm}");
+        }
+    }
+}
@@ -0,0 +1,377 @@
+//! Programmatic construction of programs with symbolic jump targets.
+
+use crate::{
+    op,
+    Imm06,
+    Imm12,
+    Imm18,
+    Imm24,
+    Instruction,
+    RegId,
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::fmt;
+
+/// An error produced by [`AsmBuilder::finalize`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AsmBuildError {
+    /// The label is defined more than once.
+    DuplicateLabel(String),
+    /// The label is jumped to but never defined.
+    UndefinedLabel(String),
+    /// The offset or index of the label doesn't fit into the immediate of the jump.
+    JumpOutOfRange(String),
+}
+
+impl fmt::Display for AsmBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateLabel(label) => {
+                write!(f, "label `{label}` is defined more than once")
+            }
+            Self::UndefinedLabel(label) => write!(f, "label `{label}` is not defined"),
+            Self::JumpOutOfRange(label) => {
+                write!(f, "label `{label}` is out of range of the jump")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsmBuildError {}
+
+/// The condition under which a jump is taken.
+#[derive(Clone, Copy, Debug)]
+enum Condition {
+    Always,
+    NotZero(RegId),
+    NotEqual(RegId, RegId),
+}
+
+/// A program item whose encoding may depend on the labels.
+#[derive(Clone, Debug)]
+enum Item {
+    Instruction(Instruction),
+    Jump {
+        label: String,
+        condition: Condition,
+        relative: bool,
+    },
+}
+
+/// Builds a program from instructions and jumps to symbolic labels.
+///
+/// Jump immediates are computed by [`AsmBuilder::finalize`], once the positions of
+/// all labels are known. Absolute jumps target the instruction index relative to
+/// `$is`, while relative jumps select the forwards or backwards variant of the
+/// instruction based on the position of the label.
+///
+/// ```
+/// use fuel_asm::{
+///     op,
+///     AsmBuilder,
+///     RegId,
+/// };
+///
+/// let program = AsmBuilder::new()
+///     .push(op::movi(0x10, 3))
+///     .label("loop")
+///     .push(op::subi(0x10, 0x10, 1))
+///     .jump_relative_if_not_zero(0x10, "loop")
+///     .push(op::ret(RegId::ONE))
+///     .finalize()
+///     .expect("all labels are defined");
+///
+/// assert_eq!(program[2], op::jnzb(0x10, RegId::ZERO, 0));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AsmBuilder {
+    items: Vec<Item>,
+    labels: BTreeMap<String, usize>,
+    duplicate_label: Option<String>,
+}
+
+impl AsmBuilder {
+    /// Creates an empty program.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an instruction.
+    pub fn push(&mut self, instruction: Instruction) -> &mut Self {
+        self.items.push(Item::Instruction(instruction));
+        self
+    }
+
+    /// Appends instructions.
+    pub fn extend<I>(&mut self, instructions: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Instruction>,
+    {
+        self.items
+            .extend(instructions.into_iter().map(Item::Instruction));
+        self
+    }
+
+    /// Defines the label at the position of the next instruction.
+    pub fn label(&mut self, label: &str) -> &mut Self {
+        if self
+            .labels
+            .insert(label.to_string(), self.items.len())
+            .is_some()
+        {
+            self.duplicate_label
+                .get_or_insert_with(|| label.to_string());
+        }
+        self
+    }
+
+    /// Appends an unconditional absolute jump (`JI`) to the label.
+    pub fn jump_to(&mut self, label: &str) -> &mut Self {
+        self.jump(label, Condition::Always, false)
+    }
+
+    /// Appends an absolute jump (`JNZI`) to the label, taken if `cond` isn't zero.
+    pub fn jump_if_not_zero<A: Into<RegId>>(
+        &mut self,
+        cond: A,
+        label: &str,
+    ) -> &mut Self {
+        self.jump(label, Condition::NotZero(cond.into()), false)
+    }
+
+    /// Appends an absolute jump (`JNEI`) to the label, taken if `lhs` and `rhs` differ.
+    pub fn jump_if_not_equal<A: Into<RegId>, B: Into<RegId>>(
+        &mut self,
+        lhs: A,
+        rhs: B,
+        label: &str,
+    ) -> &mut Self {
+        self.jump(label, Condition::NotEqual(lhs.into(), rhs.into()), false)
+    }
+
+    /// Appends an unconditional relative jump (`JMPF` or `JMPB`) to the label.
+    pub fn jump_relative_to(&mut self, label: &str) -> &mut Self {
+        self.jump(label, Condition::Always, true)
+    }
+
+    /// Appends a relative jump (`JNZF` or `JNZB`) to the label, taken if `cond` isn't
+    /// zero.
+    pub fn jump_relative_if_not_zero<A: Into<RegId>>(
+        &mut self,
+        cond: A,
+        label: &str,
+    ) -> &mut Self {
+        self.jump(label, Condition::NotZero(cond.into()), true)
+    }
+
+    /// Appends a relative jump (`JNEF` or `JNEB`) to the label, taken if `lhs` and
+    /// `rhs` differ.
+    pub fn jump_relative_if_not_equal<A: Into<RegId>, B: Into<RegId>>(
+        &mut self,
+        lhs: A,
+        rhs: B,
+        label: &str,
+    ) -> &mut Self {
+        self.jump(label, Condition::NotEqual(lhs.into(), rhs.into()), true)
+    }
+
+    fn jump(&mut self, label: &str, condition: Condition, relative: bool) -> &mut Self {
+        self.items.push(Item::Jump {
+            label: label.to_string(),
+            condition,
+            relative,
+        });
+        self
+    }
+
+    /// Resolves the labels and returns the program.
+    pub fn finalize(&self) -> Result<Vec<Instruction>, AsmBuildError> {
+        if let Some(label) = &self.duplicate_label {
+            return Err(AsmBuildError::DuplicateLabel(label.clone()))
+        }
+
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| match item {
+                Item::Instruction(instruction) => Ok(*instruction),
+                Item::Jump {
+                    label,
+                    condition,
+                    relative,
+                } => {
+                    let target = *self
+                        .labels
+                        .get(label)
+                        .ok_or_else(|| AsmBuildError::UndefinedLabel(label.clone()))?;
+
+                    if *relative {
+                        encode_relative(index, target, *condition)
+                    } else {
+                        encode_absolute(target, *condition)
+                    }
+                    .ok_or_else(|| AsmBuildError::JumpOutOfRange(label.clone()))
+                }
+            })
+            .collect()
+    }
+}
+
+fn encode_absolute(target: usize, condition: Condition) -> Option<Instruction> {
+    let target = u32::try_from(target).ok()?;
+
+    let instruction = match condition {
+        Condition::Always => op::JI::new(Imm24::new_checked(target)?).into(),
+        Condition::NotZero(cond) => {
+            op::JNZI::new(cond, Imm18::new_checked(target)?).into()
+        }
+        Condition::NotEqual(lhs, rhs) => {
+            op::JNEI::new(lhs, rhs, Imm12::new_checked(u16::try_from(target).ok()?)?)
+                .into()
+        }
+    };
+
+    Some(instruction)
+}
+
+fn encode_relative(
+    index: usize,
+    target: usize,
+    condition: Condition,
+) -> Option<Instruction> {
+    // Relative jumps skip at least one instruction, as jumping to the jump itself
+    // isn't expressible.
+    let forwards = target > index;
+    let offset = if forwards {
+        target.checked_sub(index)?.checked_sub(1)?
+    } else {
+        index.checked_sub(target)?.checked_sub(1)?
+    };
+    let offset = u32::try_from(offset).ok()?;
+    let imm12 = || u16::try_from(offset).ok().and_then(Imm12::new_checked);
+    let imm06 = || u8::try_from(offset).ok().and_then(Imm06::new_checked);
+
+    let instruction = match (condition, forwards) {
+        (Condition::Always, true) => {
+            op::JMPF::new(RegId::ZERO, Imm18::new_checked(offset)?).into()
+        }
+        (Condition::Always, false) => {
+            op::JMPB::new(RegId::ZERO, Imm18::new_checked(offset)?).into()
+        }
+        (Condition::NotZero(cond), true) => {
+            op::JNZF::new(cond, RegId::ZERO, imm12()?).into()
+        }
+        (Condition::NotZero(cond), false) => {
+            op::JNZB::new(cond, RegId::ZERO, imm12()?).into()
+        }
+        (Condition::NotEqual(lhs, rhs), true) => {
+            op::JNEF::new(lhs, rhs, RegId::ZERO, imm06()?).into()
+        }
+        (Condition::NotEqual(lhs, rhs), false) => {
+            op::JNEB::new(lhs, rhs, RegId::ZERO, imm06()?).into()
+        }
+    };
+
+    Some(instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn finalize_resolves_absolute_jumps() {
+        let program = AsmBuilder::new()
+            .label("start")
+            .jump_to("end")
+            .jump_if_not_zero(0x10, "start")
+            .jump_if_not_equal(0x10, 0x11, "end")
+            .label("end")
+            .push(op::ret(RegId::ONE))
+            .finalize();
+
+        assert_eq!(
+            program,
+            Ok(vec![
+                op::ji(3),
+                op::jnzi(0x10, 0),
+                op::jnei(0x10, 0x11, 3),
+                op::ret(RegId::ONE),
+            ])
+        );
+    }
+
+    #[test]
+    fn finalize_selects_relative_jump_direction() {
+        let program = AsmBuilder::new()
+            .label("back")
+            .push(op::noop())
+            .jump_relative_to("forward")
+            .jump_relative_if_not_zero(0x10, "back")
+            .jump_relative_if_not_equal(0x10, 0x11, "back")
+            .jump_relative_if_not_zero(0x10, "forward")
+            .push(op::noop())
+            .label("forward")
+            .push(op::ret(RegId::ONE))
+            .finalize();
+
+        assert_eq!(
+            program,
+            Ok(vec![
+                op::noop(),
+                op::jmpf(RegId::ZERO, 4),
+                op::jnzb(0x10, RegId::ZERO, 1),
+                op::jneb(0x10, 0x11, RegId::ZERO, 2),
+                op::jnzf(0x10, RegId::ZERO, 1),
+                op::noop(),
+                op::ret(RegId::ONE),
+            ])
+        );
+    }
+
+    #[test]
+    fn finalize_reports_label_errors() {
+        assert_eq!(
+            AsmBuilder::new().jump_to("missing").finalize(),
+            Err(AsmBuildError::UndefinedLabel("missing".into()))
+        );
+        assert_eq!(
+            AsmBuilder::new()
+                .label("twice")
+                .push(op::noop())
+                .label("twice")
+                .finalize(),
+            Err(AsmBuildError::DuplicateLabel("twice".into()))
+        );
+        assert_eq!(
+            AsmBuilder::new()
+                .label("self")
+                .jump_relative_to("self")
+                .finalize(),
+            Err(AsmBuildError::JumpOutOfRange("self".into()))
+        );
+    }
+
+    #[test]
+    fn finalize_reports_out_of_range_offsets() {
+        let mut builder = AsmBuilder::new();
+        builder
+            .jump_relative_if_not_equal(0x10, 0x11, "far")
+            .extend(core::iter::repeat(op::noop()).take(64))
+            .label("far");
+
+        assert_eq!(
+            builder.finalize(),
+            Err(AsmBuildError::JumpOutOfRange("far".into()))
+        );
+    }
+}
@@ -0,0 +1,385 @@
+//! Control-flow graph of bytecode.
+//!
+//! The bytecode is split into basic blocks, sequences of instructions that are always
+//! executed from the first to the last one. Blocks are connected by the jumps whose
+//! targets can be determined statically, see [`Instruction::static_jump_target`], and
+//! by falling through to the next instruction. Jumps that depend on register values
+//! are reported, but they have no edges.
+
+use crate::{
+    Instruction,
+    InvalidOpcode,
+    RegId,
+};
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use core::ops::Range;
+
+/// How the execution leaves a basic block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Exit {
+    /// The execution continues with the next block, as it is a jump target.
+    FallThrough,
+    /// The last instruction is a jump with a statically known target.
+    Jump {
+        /// Instruction index of the target, or `None` if the target is before the
+        /// start of the program.
+        target: Option<usize>,
+        /// Whether the execution falls through to the next block if the jump isn't
+        /// taken.
+        conditional: bool,
+    },
+    /// The last instruction is a jump whose target depends on register values.
+    DynamicJump {
+        /// Whether the execution falls through to the next block if the jump isn't
+        /// taken.
+        conditional: bool,
+    },
+    /// The last instruction returns from or reverts the current context.
+    Halt {
+        /// Whether the execution falls through to the next block if the context
+        /// isn't ended, e.g. after an `ASSERT` whose condition holds.
+        conditional: bool,
+    },
+    /// The last instruction can't be decoded and panics.
+    Invalid,
+    /// The block is at the end of the program and the execution runs past it.
+    End,
+}
+
+/// A sequence of instructions without jumps in between.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasicBlock {
+    /// Instruction indices of the block.
+    pub range: Range<usize>,
+    /// How the execution leaves the block.
+    pub exit: Exit,
+    /// Indices of the blocks the execution may continue with, excluding targets of
+    /// dynamic jumps.
+    pub successors: Vec<usize>,
+}
+
+/// Control-flow graph of a program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ControlFlowGraph {
+    instructions: Vec<Result<Instruction, InvalidOpcode>>,
+    blocks: Vec<BasicBlock>,
+}
+
+impl ControlFlowGraph {
+    /// Builds the graph of the bytecode. Trailing bytes that don't form a whole
+    /// instruction are treated as an invalid instruction.
+    pub fn new(bytes: &[u8]) -> Self {
+        let instructions = bytes
+            .chunks(Instruction::SIZE)
+            .map(|chunk| {
+                <[u8; 4]>::try_from(chunk)
+                    .map_err(|_| InvalidOpcode)
+                    .and_then(Instruction::try_from)
+            })
+            .collect();
+
+        Self::from_instructions(instructions)
+    }
+
+    /// Builds the graph of the decoded instructions.
+    pub fn from_instructions(
+        instructions: Vec<Result<Instruction, InvalidOpcode>>,
+    ) -> Self {
+        let len = instructions.len();
+
+        let mut leaders = vec![false; len];
+        if let Some(first) = leaders.first_mut() {
+            *first = true;
+        }
+        for (index, instruction) in instructions.iter().enumerate() {
+            let ends_block = match instruction {
+                Ok(instruction) => {
+                    if let Some(target) = instruction.static_jump_target(index) {
+                        if let Some(leader) = leaders.get_mut(target) {
+                            *leader = true;
+                        }
+                    }
+                    instruction.can_branch() || instruction.can_halt()
+                }
+                Err(_) => true,
+            };
+            if ends_block {
+                if let Some(leader) =
+                    index.checked_add(1).and_then(|i| leaders.get_mut(i))
+                {
+                    *leader = true;
+                }
+            }
+        }
+
+        let starts: Vec<usize> = leaders
+            .iter()
+            .enumerate()
+            .filter_map(|(index, leader)| leader.then_some(index))
+            .collect();
+        let block_of = |index: usize| starts.binary_search(&index).ok();
+
+        let blocks = starts
+            .iter()
+            .enumerate()
+            .map(|(block, start)| {
+                let end = starts.get(block.saturating_add(1)).copied().unwrap_or(len);
+                let last = end.saturating_sub(1);
+                let next = (end < len).then(|| block.saturating_add(1));
+
+                let exit = match instructions[last] {
+                    Err(_) => Exit::Invalid,
+                    Ok(instruction) if instruction.can_halt() => Exit::Halt {
+                        conditional: instruction.can_fall_through(),
+                    },
+                    Ok(instruction) if instruction.can_branch() => {
+                        let conditional = instruction.can_fall_through();
+                        if is_dynamic_jump(&instruction) {
+                            Exit::DynamicJump { conditional }
+                        } else {
+                            Exit::Jump {
+                                target: instruction.static_jump_target(last),
+                                conditional,
+                            }
+                        }
+                    }
+                    Ok(_) if next.is_some() => Exit::FallThrough,
+                    Ok(_) => Exit::End,
+                };
+
+                let mut successors = Vec::new();
+                if let Exit::Jump {
+                    target: Some(target),
+                    ..
+                } = exit
+                {
+                    successors.extend(block_of(target));
+                }
+                let falls_through = match exit {
+                    Exit::FallThrough => true,
+                    Exit::Jump { conditional, .. }
+                    | Exit::DynamicJump { conditional }
+                    | Exit::Halt { conditional } => conditional,
+                    _ => false,
+                };
+                if falls_through {
+                    successors.extend(next);
+                }
+                successors.sort_unstable();
+                successors.dedup();
+
+                BasicBlock {
+                    range: *start..end,
+                    exit,
+                    successors,
+                }
+            })
+            .collect();
+
+        Self {
+            instructions,
+            blocks,
+        }
+    }
+
+    /// The basic blocks, ordered by their position in the program. The first block is
+    /// the entry point.
+    pub fn blocks(&self) -> &[BasicBlock] {
+        &self.blocks
+    }
+
+    /// The decoded instructions of the block.
+    pub fn instructions(&self, block: usize) -> &[Result<Instruction, InvalidOpcode>] {
+        self.blocks
+            .get(block)
+            .and_then(|block| self.instructions.get(block.range.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Index of the block containing the instruction.
+    pub fn block_of(&self, index: usize) -> Option<usize> {
+        if index >= self.instructions.len() {
+            return None
+        }
+        match self
+            .blocks
+            .binary_search_by_key(&index, |block| block.range.start)
+        {
+            Ok(block) => Some(block),
+            Err(block) => block.checked_sub(1),
+        }
+    }
+
+    /// Indices of the blocks that may continue with the block.
+    pub fn predecessors(&self, block: usize) -> Vec<usize> {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.successors.contains(&block))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns `true` if any block ends with a dynamic jump.
+    pub fn has_dynamic_jumps(&self) -> bool {
+        self.blocks
+            .iter()
+            .any(|block| matches!(block.exit, Exit::DynamicJump { .. }))
+    }
+
+    /// Marks the blocks reachable from the entry point by following the edges.
+    ///
+    /// Targets of dynamic jumps are unknown, so blocks reported as unreachable may
+    /// still be executed if [`ControlFlowGraph::has_dynamic_jumps`] is `true`.
+    pub fn reachable(&self) -> Vec<bool> {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut stack: Vec<usize> =
+            (!self.blocks.is_empty()).then_some(0).into_iter().collect();
+        while let Some(block) = stack.pop() {
+            if core::mem::replace(&mut reachable[block], true) {
+                continue
+            }
+            stack.extend(
+                self.blocks[block]
+                    .successors
+                    .iter()
+                    .filter(|successor| !reachable[**successor]),
+            );
+        }
+        reachable
+    }
+}
+
+/// Returns `true` if the instruction is a jump whose target depends on a register.
+pub(crate) fn is_dynamic_jump(instruction: &Instruction) -> bool {
+    match instruction {
+        Instruction::JMP(_) | Instruction::JNE(_) => true,
+        Instruction::JMPF(op) => op.ra() != RegId::ZERO,
+        Instruction::JMPB(op) => op.ra() != RegId::ZERO,
+        Instruction::JNZF(op) => op.rb() != RegId::ZERO,
+        Instruction::JNZB(op) => op.rb() != RegId::ZERO,
+        Instruction::JNEF(op) => op.rc() != RegId::ZERO,
+        Instruction::JNEB(op) => op.rc() != RegId::ZERO,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op;
+
+    fn graph(program: &[Instruction]) -> ControlFlowGraph {
+        ControlFlowGraph::new(&program.iter().copied().collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn loop_is_split_into_blocks() {
+        let cfg = graph(&[
+            op::movi(0x10, 3),
+            op::subi(0x10, 0x10, 1),
+            op::jnzb(0x10, RegId::ZERO, 0),
+            op::ret(RegId::ONE),
+        ]);
+
+        assert_eq!(
+            cfg.blocks(),
+            [
+                BasicBlock {
+                    range: 0..1,
+                    exit: Exit::FallThrough,
+                    successors: vec![1],
+                },
+                BasicBlock {
+                    range: 1..3,
+                    exit: Exit::Jump {
+                        target: Some(1),
+                        conditional: true,
+                    },
+                    successors: vec![1, 2],
+                },
+                BasicBlock {
+                    range: 3..4,
+                    exit: Exit::Halt { conditional: false },
+                    successors: vec![],
+                },
+            ]
+        );
+        assert_eq!(cfg.predecessors(1), [0, 1]);
+        assert_eq!(cfg.block_of(2), Some(1));
+        assert_eq!(cfg.instructions(2), [Ok(op::ret(RegId::ONE))]);
+    }
+
+    #[test]
+    fn assert_ends_block_and_falls_through() {
+        let cfg = graph(&[
+            op::movi(0x10, 1),
+            op::assert(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ]);
+
+        assert_eq!(
+            cfg.blocks(),
+            [
+                BasicBlock {
+                    range: 0..2,
+                    exit: Exit::Halt { conditional: true },
+                    successors: vec![1],
+                },
+                BasicBlock {
+                    range: 2..3,
+                    exit: Exit::Halt { conditional: false },
+                    successors: vec![],
+                },
+            ]
+        );
+        assert_eq!(cfg.reachable(), [true, true]);
+    }
+
+    #[test]
+    fn unconditional_jump_skips_unreachable_code() {
+        let cfg = graph(&[op::ji(2), op::noop(), op::ret(RegId::ONE)]);
+
+        assert_eq!(cfg.blocks().len(), 3);
+        assert_eq!(cfg.blocks()[0].successors, [2]);
+        assert_eq!(cfg.reachable(), [true, false, true]);
+        assert!(!cfg.has_dynamic_jumps());
+    }
+
+    #[test]
+    fn dynamic_jumps_have_no_static_edges() {
+        let cfg = graph(&[
+            op::jne(0x10, 0x11, 0x12),
+            op::jmpf(0x10, 0),
+            op::ret(RegId::ONE),
+        ]);
+
+        assert_eq!(
+            cfg.blocks()[0].exit,
+            Exit::DynamicJump { conditional: true }
+        );
+        assert_eq!(cfg.blocks()[0].successors, [1]);
+        assert_eq!(
+            cfg.blocks()[1].exit,
+            Exit::DynamicJump { conditional: false }
+        );
+        assert!(cfg.blocks()[1].successors.is_empty());
+        assert!(cfg.has_dynamic_jumps());
+    }
+
+    #[test]
+    fn invalid_and_trailing_instructions_end_blocks() {
+        let mut bytes: Vec<u8> = op::noop().to_bytes().to_vec();
+        bytes.extend([0xff, 0, 0, 0]);
+        bytes.extend(op::noop().to_bytes());
+
+        let cfg = ControlFlowGraph::new(&bytes);
+
+        assert_eq!(cfg.blocks()[0].exit, Exit::Invalid);
+        assert_eq!(cfg.blocks()[1].exit, Exit::End);
+        assert!(ControlFlowGraph::new(&[]).blocks().is_empty());
+    }
+}
@@ -0,0 +1,274 @@
+//! Disassembly of raw bytecode into human-readable lines.
+
+use crate::{
+    wideint,
+    GMArgs,
+    GTFArgs,
+    Instruction,
+    InvalidOpcode,
+};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A single line of disassembled bytecode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisassembledLine {
+    /// Offset of the line in bytes from the start of the disassembled slice.
+    pub offset: usize,
+    /// The raw bytes of the line. Only trailing bytes that don't form a whole
+    /// instruction are shorter than [`Instruction::SIZE`].
+    pub bytes: Vec<u8>,
+    /// The decoded instruction, or an error if the bytes don't form a valid one.
+    pub instruction: Result<Instruction, InvalidOpcode>,
+    /// The label of this line, if it is the static target of a jump.
+    pub label: Option<usize>,
+    /// The label of the line this instruction jumps to, if the target is static and
+    /// within the disassembled slice.
+    pub jump_label: Option<usize>,
+}
+
+/// Disassembles the bytes into lines, one per instruction.
+///
+/// Bytes that fail to decode are kept as data lines and decoding resumes with the
+/// following instruction. Lines targeted by jumps that can be resolved statically
+/// are labeled in the order of their appearance.
+pub fn disassemble(bytes: &[u8]) -> Vec<DisassembledLine> {
+    let mut lines: Vec<DisassembledLine> = bytes
+        .chunks(Instruction::SIZE)
+        .enumerate()
+        .map(|(index, chunk)| DisassembledLine {
+            offset: index.saturating_mul(Instruction::SIZE),
+            bytes: chunk.to_vec(),
+            instruction: <[u8; 4]>::try_from(chunk)
+                .map_err(|_| InvalidOpcode)
+                .and_then(Instruction::try_from),
+            label: None,
+            jump_label: None,
+        })
+        .collect();
+
+    let targets: Vec<Option<usize>> = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            line.instruction
+                .ok()
+                .and_then(|instruction| instruction.static_jump_target(index))
+                .filter(|target| *target < lines.len())
+        })
+        .collect();
+
+    let mut is_target = alloc::vec![false; lines.len()];
+    for target in targets.iter().flatten() {
+        is_target[*target] = true;
+    }
+
+    let mut next_label = 0usize;
+    for (line, is_target) in lines.iter_mut().zip(is_target) {
+        if is_target {
+            line.label = Some(next_label);
+            next_label = next_label.saturating_add(1);
+        }
+    }
+
+    for (index, target) in targets.into_iter().enumerate() {
+        lines[index].jump_label = target.and_then(|target| lines[target].label);
+    }
+
+    lines
+}
+
+impl DisassembledLine {
+    /// Writes the comment describing decoded immediates and jump targets, if any.
+    fn fmt_comment(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(label) = self.jump_label {
+            return write!(f, "  ; -> .L{label}");
+        }
+
+        let Ok(instruction) = self.instruction else {
+            return Ok(());
+        };
+
+        match instruction {
+            Instruction::GM(op) => match GMArgs::try_from(u32::from(op.imm18())) {
+                Ok(args) => write!(f, "  ; {args:?}"),
+                Err(_) => Ok(()),
+            },
+            Instruction::GTF(op) => match GTFArgs::try_from(u16::from(op.imm12())) {
+                Ok(args) => write!(f, "  ; {args:?}"),
+                Err(_) => Ok(()),
+            },
+            Instruction::WDCM(op) => {
+                fmt_args(f, wideint::CompareArgs::from_imm(op.imm06()))
+            }
+            Instruction::WQCM(op) => {
+                fmt_args(f, wideint::CompareArgs::from_imm(op.imm06()))
+            }
+            Instruction::WDOP(op) => fmt_args(f, wideint::MathArgs::from_imm(op.imm06())),
+            Instruction::WQOP(op) => fmt_args(f, wideint::MathArgs::from_imm(op.imm06())),
+            Instruction::WDML(op) => fmt_args(f, wideint::MulArgs::from_imm(op.imm06())),
+            Instruction::WQML(op) => fmt_args(f, wideint::MulArgs::from_imm(op.imm06())),
+            Instruction::WDDV(op) => fmt_args(f, wideint::DivArgs::from_imm(op.imm06())),
+            Instruction::WQDV(op) => fmt_args(f, wideint::DivArgs::from_imm(op.imm06())),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn fmt_args<T: fmt::Debug>(f: &mut fmt::Formatter<'_>, args: Option<T>) -> fmt::Result {
+    match args {
+        Some(args) => write!(f, "  ; {args:?}"),
+        None => Ok(()),
+    }
+}
+
+impl fmt::Display for DisassembledLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(label) = self.label {
+            writeln!(f, ".L{label}:")?;
+        }
+
+        write!(f, "{:08x}:", self.offset)?;
+        for byte in &self.bytes {
+            write!(f, " {byte:02x}")?;
+        }
+        for _ in self.bytes.len()..Instruction::SIZE {
+            write!(f, "   ")?;
+        }
+        write!(f, "  ")?;
+
+        match (self.instruction, self.bytes.as_slice()) {
+            (Ok(instruction), _) => {
+                let mnemonic = alloc::format!("{:?}", instruction.opcode());
+                write!(f, "{}", mnemonic.to_ascii_lowercase())?;
+                for reg in instruction.reg_ids().into_iter().flatten() {
+                    write!(f, " {reg}")?;
+                }
+                if let Some(imm) = instruction.imm() {
+                    write!(f, " {imm}")?;
+                }
+            }
+            (Err(_), &[a, b, c, d]) => {
+                write!(f, ".word 0x{:08x}", u32::from_be_bytes([a, b, c, d]))?;
+            }
+            (Err(_), bytes) => {
+                write!(f, ".bytes")?;
+                for byte in bytes {
+                    write!(f, " 0x{byte:02x}")?;
+                }
+            }
+        }
+
+        self.fmt_comment(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        op,
+        RegId,
+    };
+    use alloc::{
+        string::ToString,
+        vec,
+    };
+
+    fn program_bytes(program: &[Instruction]) -> Vec<u8> {
+        program.iter().copied().collect()
+    }
+
+    #[test]
+    fn disassemble_formats_registers_and_immediates() {
+        let bytes = program_bytes(&[
+            op::movi(0x10, 42),
+            op::add(0x11, RegId::ONE, 0x10),
+            op::ret(RegId::ONE),
+        ]);
+
+        let text: Vec<_> = disassemble(&bytes)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            text,
+            vec![
+                "00000000: 72 40 00 2a  movi $r16 i42",
+                "00000004: 10 44 14 00  add $r17 $one $r16",
+                "00000008: 24 04 00 00  ret $one",
+            ]
+        );
+    }
+
+    #[test]
+    fn disassemble_labels_static_jump_targets() {
+        let bytes = program_bytes(&[
+            op::noop(),
+            op::ji(3),
+            op::jmpb(RegId::ZERO, 1),
+            op::jnzf(0x10, RegId::ZERO, 0),
+            op::jmp(0x10),
+        ]);
+
+        let lines = disassemble(&bytes);
+        let labels: Vec<_> = lines.iter().map(|line| line.label).collect();
+        let jump_labels: Vec<_> = lines.iter().map(|line| line.jump_label).collect();
+
+        assert_eq!(labels, vec![Some(0), None, None, Some(1), Some(2)]);
+        assert_eq!(jump_labels, vec![None, Some(1), Some(0), Some(2), None]);
+        assert_eq!(
+            lines[3].to_string(),
+            ".L1:\n0000000c: 76 40 00 00  jnzf $r16 $zero i0  ; -> .L2"
+        );
+    }
+
+    #[test]
+    fn disassemble_recovers_from_invalid_instructions() {
+        let mut bytes = vec![0xff, 0x00, 0x00, 0x00];
+        bytes.extend(op::noop().to_bytes());
+        bytes.extend([0x01, 0x02]);
+
+        let lines = disassemble(&bytes);
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].instruction, Err(InvalidOpcode));
+        assert_eq!(
+            lines[0].to_string(),
+            "00000000: ff 00 00 00  .word 0xff000000"
+        );
+        assert_eq!(lines[1].instruction, Ok(op::noop()));
+        assert_eq!(lines[1].offset, 4);
+        assert_eq!(
+            lines[2].to_string(),
+            "00000008: 01 02        .bytes 0x01 0x02"
+        );
+    }
+
+    #[test]
+    fn disassemble_decodes_selector_immediates() {
+        let bytes = program_bytes(&[
+            op::gm_args(0x10, GMArgs::GetChainId),
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+            op::wqop_args(
+                0x10,
+                0x11,
+                0x12,
+                wideint::MathArgs {
+                    op: wideint::MathOp::ADD,
+                    indirect_rhs: true,
+                },
+            ),
+        ]);
+
+        let text: Vec<_> = disassemble(&bytes)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert!(text[0].ends_with("gm $r16 i4  ; GetChainId"));
+        assert!(text[1].ends_with("gtf $r16 $zero i10  ; ScriptData"));
+        assert!(text[2].ends_with("; MathArgs { op: ADD, indirect_rhs: true }"));
+    }
+}
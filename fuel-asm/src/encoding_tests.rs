@@ -82,3 +82,57 @@ fn panic_reason_description() {
         }
     }
 }
+
+#[test]
+fn strict_decoding_accepts_canonical_instructions() {
+    let r = RegId::new_checked(0x2d).unwrap();
+    let mut instructions = vec![
+        op::add(r, r, r),
+        op::wdcm_args(
+            r,
+            r,
+            r,
+            wideint::CompareArgs {
+                mode: wideint::CompareMode::LT,
+                indirect_rhs: true,
+            },
+        ),
+        op::wqml_args(
+            r,
+            r,
+            r,
+            wideint::MulArgs {
+                indirect_lhs: true,
+                indirect_rhs: false,
+            },
+        ),
+    ];
+    instructions.extend(GMArgs::iter().map(|arg| op::gm_args(r, arg)));
+    instructions.extend(GTFArgs::iter().map(|arg| op::gtf_args(r, r, arg)));
+
+    let bytes: Vec<u8> = instructions.iter().copied().collect();
+    let decoded: Result<Vec<Instruction>, _> = from_bytes_strict(bytes).collect();
+
+    assert_eq!(instructions, decoded.unwrap());
+}
+
+#[test]
+fn strict_decoding_rejects_non_canonical_arguments() {
+    let r = RegId::new_checked(0x2d).unwrap();
+    let non_canonical = [
+        // Reserved flag bits of the comparison mode
+        op::wdcm(r, r, r, 0b011000),
+        op::wqdv(r, r, r, 0b000001),
+        op::wdml(r, r, r, 0b000100),
+        op::wqop(r, r, r, 0b011111),
+        op::gm(r, 0),
+        op::gtf(r, r, 0xfff),
+    ];
+
+    for instruction in non_canonical {
+        assert!(!instruction.is_canonical(), "{instruction:?}");
+        let raw = u32::from_be_bytes(instruction.to_bytes());
+        assert_eq!(Instruction::try_from(raw), Ok(instruction));
+        assert_eq!(Instruction::try_from_strict(raw), Err(InvalidOpcode));
+    }
+}
@@ -17,7 +17,16 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod analysis;
 mod args;
+#[cfg(feature = "alloc")]
+mod asm;
+#[cfg(feature = "alloc")]
+mod asm_builder;
+#[cfg(feature = "alloc")]
+pub mod cfg;
+#[cfg(feature = "alloc")]
+mod disassemble;
 mod panic_instruction;
 // This is `pub` to make documentation for the private `impl_instructions!` macro more
 // accessible.
@@ -27,10 +36,16 @@ pub mod op;
 mod pack;
 mod panic_reason;
 mod unpack;
+#[cfg(feature = "alloc")]
+mod validate;
 
 #[cfg(test)]
 mod encoding_tests;
 
+pub use analysis::{
+    OpcodeGroup,
+    RegisterSet,
+};
 #[doc(no_inline)]
 pub use args::{
     wideint,
@@ -44,8 +59,31 @@ pub type RegisterId = usize;
 /// Register value type
 pub type Word = u64;
 
+#[cfg(feature = "alloc")]
+pub use asm::{
+    parse_asm,
+    AsmParseError,
+    AsmParseErrorKind,
+};
+#[cfg(feature = "alloc")]
+pub use asm_builder::{
+    AsmBuildError,
+    AsmBuilder,
+};
+#[cfg(feature = "alloc")]
+pub use disassemble::{
+    disassemble,
+    DisassembledLine,
+};
 pub use panic_instruction::PanicInstruction;
 pub use panic_reason::PanicReason;
+#[cfg(feature = "alloc")]
+pub use validate::{
+    validate,
+    ValidationError,
+    ValidationErrorKind,
+    ValidationRules,
+};
 
 /// Represents a 6-bit register ID, guaranteed to be masked by construction.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -72,12 +110,31 @@ pub struct Imm18(u32);
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
 pub struct Imm24(u32);
 
+/// An immediate value of any of the widths carried by instructions.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Immediate {
+    /// 6-bit immediate value.
+    Imm06(Imm06),
+    /// 12-bit immediate value.
+    Imm12(Imm12),
+    /// 18-bit immediate value.
+    Imm18(Imm18),
+    /// 24-bit immediate value.
+    Imm24(Imm24),
+}
+
+impl core::fmt::Display for Immediate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "i{}", u32::from(*self))
+    }
+}
+
 /// An instruction in its raw, packed, unparsed representation.
 pub type RawInstruction = u32;
 
 /// Given opcode doesn't exist, or is the reserved part of
 /// the instruction (i.e. space outside arguments) is non-zero.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct InvalidOpcode;
 
 bitflags::bitflags! {
@@ -91,6 +148,15 @@ bitflags::bitflags! {
         const UNSAFEMATH = 0x01;
         /// If set, arithmetic overflows result in setting $of instead of panicking.
         const WRAPPING = 0x02;
+        /// Experimental. If set, `CALL` performs a static call. The callee, and the
+        /// contracts it calls, can't modify the state or balances, see
+        /// [`Opcode::is_static_call_allowed`], and can't forward coins. Only
+        /// accepted by VMs with static calls enabled, otherwise setting it panics.
+        const STATIC_CALL = 0x04;
+        /// Experimental. If set, `CALL` executes the code of the target contract
+        /// against the storage and balances of the calling contract. Only accepted
+        /// by VMs with delegate calls enabled, otherwise setting it panics.
+        const DELEGATE_CALL = 0x08;
     }
 }
 /// Type is convertible to a [`RegId`]
@@ -345,6 +411,9 @@ impl_instructions! {
     0xba BSIZ bsiz [dst: RegId blob_id_ptr: RegId]
     "Load blob as data"
     0xbb BLDD bldd [dst_ptr: RegId blob_id_ptr: RegId offset: RegId len: RegId]
+
+    "Revert with the code and log the message if the condition is zero"
+    0xbc ASSERT assert [cond: RegId code: RegId msg_ptr: RegId msg_len: RegId]
 }
 
 impl Instruction {
@@ -355,6 +424,74 @@ impl Instruction {
     pub fn to_bytes(self) -> [u8; 4] {
         self.into()
     }
+
+    /// Returns `true` if the arguments of the instruction are encoded canonically.
+    ///
+    /// Decoding already rejects non-zero bits outside of the instruction arguments.
+    /// This additionally verifies immediates that carry flags or selectors, which are
+    /// otherwise only rejected once the instruction is executed.
+    pub fn is_canonical(&self) -> bool {
+        match self {
+            Self::WDCM(op) => wideint::CompareArgs::from_imm(op.imm06()).is_some(),
+            Self::WQCM(op) => wideint::CompareArgs::from_imm(op.imm06()).is_some(),
+            Self::WDOP(op) => wideint::MathArgs::from_imm(op.imm06()).is_some(),
+            Self::WQOP(op) => wideint::MathArgs::from_imm(op.imm06()).is_some(),
+            Self::WDML(op) => wideint::MulArgs::from_imm(op.imm06()).is_some(),
+            Self::WQML(op) => wideint::MulArgs::from_imm(op.imm06()).is_some(),
+            Self::WDDV(op) => wideint::DivArgs::from_imm(op.imm06()).is_some(),
+            Self::WQDV(op) => wideint::DivArgs::from_imm(op.imm06()).is_some(),
+            Self::GM(op) => GMArgs::try_from(u32::from(op.imm18())).is_ok(),
+            Self::GTF(op) => GTFArgs::try_from(u16::from(op.imm12())).is_ok(),
+            _ => true,
+        }
+    }
+
+    /// Decodes the instruction like [`Instruction::try_from`], but also rejects
+    /// arguments that aren't encoded canonically. See [`Instruction::is_canonical`].
+    pub fn try_from_strict<R: Into<RawInstruction>>(
+        raw: R,
+    ) -> Result<Self, InvalidOpcode> {
+        let instruction = Self::try_from(raw.into())?;
+
+        if instruction.is_canonical() {
+            Ok(instruction)
+        } else {
+            Err(InvalidOpcode)
+        }
+    }
+
+    /// Returns the instruction index this instruction jumps to, if the target can be
+    /// determined without knowing register values.
+    ///
+    /// `index` is the position of this instruction, counted in instructions from `$is`.
+    /// Relative jumps are only resolved when their dynamic offset register is `$zero`.
+    pub fn static_jump_target(&self, index: usize) -> Option<usize> {
+        let forwards = |dynamic: RegId, fixed: u32| {
+            (dynamic == RegId::ZERO)
+                .then(|| usize::try_from(fixed).ok())
+                .flatten()
+                .and_then(|fixed| index.checked_add(fixed)?.checked_add(1))
+        };
+        let backwards = |dynamic: RegId, fixed: u32| {
+            (dynamic == RegId::ZERO)
+                .then(|| usize::try_from(fixed).ok())
+                .flatten()
+                .and_then(|fixed| index.checked_sub(fixed.checked_add(1)?))
+        };
+
+        match self {
+            Self::JI(op) => usize::try_from(op.imm24().to_u32()).ok(),
+            Self::JNEI(op) => Some(usize::from(op.imm12().to_u16())),
+            Self::JNZI(op) => usize::try_from(op.imm18().to_u32()).ok(),
+            Self::JMPF(op) => forwards(op.ra(), op.imm18().into()),
+            Self::JMPB(op) => backwards(op.ra(), op.imm18().into()),
+            Self::JNZF(op) => forwards(op.rb(), op.imm12().into()),
+            Self::JNZB(op) => backwards(op.rb(), op.imm12().into()),
+            Self::JNEF(op) => forwards(op.rc(), op.imm06().into()),
+            Self::JNEB(op) => backwards(op.rc(), op.imm06().into()),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(feature = "typescript")]
@@ -467,6 +604,40 @@ impl RegId {
     pub const fn to_u8(self) -> u8 {
         self.0
     }
+
+    /// The assembly name of a reserved register, e.g. `"sp"` for [`RegId::SP`].
+    ///
+    /// Returns `None` for general purpose registers.
+    pub const fn reserved_name(self) -> Option<&'static str> {
+        match self {
+            Self::ZERO => Some("zero"),
+            Self::ONE => Some("one"),
+            Self::OF => Some("of"),
+            Self::PC => Some("pc"),
+            Self::SSP => Some("ssp"),
+            Self::SP => Some("sp"),
+            Self::FP => Some("fp"),
+            Self::HP => Some("hp"),
+            Self::ERR => Some("err"),
+            Self::GGAS => Some("ggas"),
+            Self::CGAS => Some("cgas"),
+            Self::BAL => Some("bal"),
+            Self::IS => Some("is"),
+            Self::RET => Some("ret"),
+            Self::RETL => Some("retl"),
+            Self::FLAG => Some("flag"),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for RegId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.reserved_name() {
+            Some(name) => write!(f, "${name}"),
+            None => write!(f, "$r{}", self.0),
+        }
+    }
 }
 
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
@@ -705,6 +876,13 @@ impl Opcode {
             _ => false,
         }
     }
+
+    /// Returns `true` if the opcode is allowed in a static call, i.e. it doesn't
+    /// modify the contract state or balances, or send messages.
+    pub fn is_static_call_allowed(&self) -> bool {
+        use Opcode::*;
+        !matches!(self, SWW | SWWQ | SCWQ | MINT | BURN | TR | TRO | SMO)
+    }
 }
 
 // Direct conversions
@@ -843,6 +1021,23 @@ impl From<Imm24> for u128 {
     }
 }
 
+impl From<Immediate> for u32 {
+    fn from(imm: Immediate) -> Self {
+        match imm {
+            Immediate::Imm06(imm) => imm.into(),
+            Immediate::Imm12(imm) => imm.into(),
+            Immediate::Imm18(imm) => imm.into(),
+            Immediate::Imm24(imm) => imm.into(),
+        }
+    }
+}
+
+impl From<Immediate> for u64 {
+    fn from(imm: Immediate) -> Self {
+        u32::from(imm).into()
+    }
+}
+
 impl From<Opcode> for u8 {
     fn from(op: Opcode) -> Self {
         op as u8
@@ -921,6 +1116,27 @@ where
     })
 }
 
+/// Given an iterator yielding bytes, produces an iterator yielding `Instruction`s,
+/// rejecting the non-canonical encodings.
+///
+/// Behaves like [`from_bytes`], but decodes with [`Instruction::try_from_strict`].
+pub fn from_bytes_strict<I>(
+    bs: I,
+) -> impl Iterator<Item = Result<Instruction, InvalidOpcode>>
+where
+    I: IntoIterator<Item = u8>,
+{
+    from_bytes(bs).map(|instruction| {
+        instruction.and_then(|instruction| {
+            if instruction.is_canonical() {
+                Ok(instruction)
+            } else {
+                Err(InvalidOpcode)
+            }
+        })
+    })
+}
+
 /// Given an iterator yielding u32s (i.e. "half words" or "raw instructions"), produces an
 /// iterator yielding `Instruction`s.
 ///
@@ -993,7 +1209,7 @@ fn check_predicate_allowed() {
             let should_allow = match repr {
                 BAL | BHEI | BHSH | BURN | CALL | CB | CCP | CROO | CSIZ | LDC | LOG
                 | LOGD | MINT | RETD | RVRT | SMO | SCWQ | SRW | SRWQ | SWW | SWWQ
-                | TIME | TR | TRO | ECAL | BSIZ | BLDD => false,
+                | TIME | TR | TRO | ECAL | BSIZ | BLDD | ASSERT => false,
                 _ => true,
             };
             assert_eq!(should_allow, repr.is_predicate_allowed());
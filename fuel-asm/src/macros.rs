@@ -818,6 +818,35 @@ macro_rules! op_reg_ids {
     };
 }
 
+// Generate a private fn for use within the `Instruction::imm` implementation.
+macro_rules! op_imm {
+    (RegId RegId RegId Imm06) => {
+        pub(super) fn imm(&self) -> Option<Immediate> {
+            Some(Immediate::Imm06(unpack::imm06_from_bytes(self.0)))
+        }
+    };
+    (RegId RegId Imm12) => {
+        pub(super) fn imm(&self) -> Option<Immediate> {
+            Some(Immediate::Imm12(unpack::imm12_from_bytes(self.0)))
+        }
+    };
+    (RegId Imm18) => {
+        pub(super) fn imm(&self) -> Option<Immediate> {
+            Some(Immediate::Imm18(unpack::imm18_from_bytes(self.0)))
+        }
+    };
+    (Imm24) => {
+        pub(super) fn imm(&self) -> Option<Immediate> {
+            Some(Immediate::Imm24(unpack::imm24_from_bytes(self.0)))
+        }
+    };
+    ($($rest:tt)*) => {
+        pub(super) fn imm(&self) -> Option<Immediate> {
+            None
+        }
+    };
+}
+
 // Generate test constructors that can be used to generate instructions from non-matching
 // input.
 #[cfg(test)]
@@ -1121,6 +1150,7 @@ macro_rules! impl_instructions {
             op_unpack!($($field)*);
             op_reserved_part!($($field)*);
             op_reg_ids!($($field)*);
+            op_imm!($($field)*);
         }
 
         op_constructor!($doc $Op $op [$($fname: $field)*]);
@@ -1178,6 +1208,20 @@ macro_rules! impl_instructions {
             }
         }
 
+        impl core::str::FromStr for Opcode {
+            type Err = InvalidOpcode;
+
+            /// Parses the opcode from its mnemonic, ignoring ASCII case.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($Op)) {
+                        return Ok(Opcode::$Op);
+                    }
+                )*
+                Err(InvalidOpcode)
+            }
+        }
+
         impl Opcode {
             /// Construct the instruction from all possible raw fields, ignoring inapplicable ones.
             #[cfg(test)]
@@ -1211,6 +1255,15 @@ macro_rules! impl_instructions {
                     )*
                 }
             }
+
+            /// Unpacks the immediate value, if the instruction has one.
+            pub fn imm(&self) -> Option<Immediate> {
+                match self {
+                    $(
+                        Self::$Op(op) => op.imm(),
+                    )*
+                }
+            }
         }
 
         impl From<Instruction> for [u8; 4] {
@@ -152,6 +152,19 @@ enum_from! {
         BlobIdAlreadyUploaded = 0x37,
         /// Active gas costs do not define the cost for this instruction.
         GasCostNotDefined = 0x38,
+        /// The transaction already sent the maximum number of messages.
+        TooManyMessageOutputs = 0x39,
+        /// The total length of the data of the messages sent by the transaction
+        /// exceeds the maximum.
+        MessageOutputsDataTooLong = 0x3a,
+        /// The instruction modifies the state or balances, or forwards coins, in a
+        /// static call.
+        StateChangeInStaticCall = 0x3b,
+        /// The opcode is disabled by the configuration of the interpreter.
+        OpcodeDisabled = 0x3c,
+        /// The call exceeds the maximum call depth set by the configuration of the
+        /// interpreter.
+        CallDepthExceeded = 0x3d,
     }
 }
 
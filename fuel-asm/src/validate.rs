@@ -0,0 +1,274 @@
+//! Eager validation of bytecode, reporting malformed programs before they are
+//! executed.
+
+use crate::{
+    cfg::is_dynamic_jump,
+    Instruction,
+    Opcode,
+    RegId,
+};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The checks performed by [`validate`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ValidationRules {
+    /// Reject reserved opcodes, and instructions with non-zero bits outside of their
+    /// arguments.
+    pub invalid_instructions: bool,
+    /// Reject statically known jump targets outside of the program.
+    pub jump_targets: bool,
+    /// Reject instructions writing to the reserved registers below
+    /// [`RegId::WRITABLE`].
+    pub read_only_registers: bool,
+    /// Reject trailing bytes that don't form a whole instruction.
+    pub truncated_instructions: bool,
+    /// Reject instructions with non-canonically encoded arguments. See
+    /// [`Instruction::is_canonical`].
+    pub non_canonical_arguments: bool,
+}
+
+impl ValidationRules {
+    /// Enables all of the checks.
+    pub const fn strict() -> Self {
+        Self {
+            invalid_instructions: true,
+            jump_targets: true,
+            read_only_registers: true,
+            truncated_instructions: true,
+            non_canonical_arguments: true,
+        }
+    }
+}
+
+impl Default for ValidationRules {
+    /// Enables all of the checks except [`ValidationRules::non_canonical_arguments`],
+    /// which the VM only enforces in the strict decoding mode.
+    fn default() -> Self {
+        Self {
+            non_canonical_arguments: false,
+            ..Self::strict()
+        }
+    }
+}
+
+/// The reason a program failed the validation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValidationErrorKind {
+    /// The opcode is reserved.
+    ReservedOpcode(u8),
+    /// The instruction has non-zero bits outside of its arguments.
+    InvalidInstruction,
+    /// The static jump target, counted in instructions, is outside of the program.
+    JumpTargetOutOfBounds(Option<usize>),
+    /// The instruction writes to a reserved register.
+    ReadOnlyRegisterWrite(RegId),
+    /// The trailing bytes don't form a whole instruction.
+    TruncatedInstruction,
+    /// The arguments of the instruction aren't encoded canonically.
+    NonCanonicalArguments,
+}
+
+/// An error produced by [`validate`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ValidationError {
+    /// Offset in bytes of the offending instruction.
+    pub offset: usize,
+    /// The reason the instruction failed the validation.
+    pub kind: ValidationErrorKind,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "offset {:#x}: ", self.offset)?;
+        match self.kind {
+            ValidationErrorKind::ReservedOpcode(opcode) => {
+                write!(f, "reserved opcode {opcode:#04x}")
+            }
+            ValidationErrorKind::InvalidInstruction => {
+                write!(f, "non-zero bits outside of the instruction arguments")
+            }
+            ValidationErrorKind::JumpTargetOutOfBounds(Some(target)) => {
+                write!(f, "jump target {target} is outside of the program")
+            }
+            ValidationErrorKind::JumpTargetOutOfBounds(None) => {
+                write!(f, "jump target is before the start of the program")
+            }
+            ValidationErrorKind::ReadOnlyRegisterWrite(reg) => {
+                write!(f, "write to the read-only register {reg:?}")
+            }
+            ValidationErrorKind::TruncatedInstruction => {
+                write!(f, "truncated instruction")
+            }
+            ValidationErrorKind::NonCanonicalArguments => {
+                write!(f, "non-canonical instruction arguments")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// Validates the bytecode according to the rules, reporting every violation in the
+/// order of the offending instructions.
+///
+/// The VM only panics once a malformed instruction is executed. This allows tooling
+/// to reject such programs when they are built or deployed instead.
+pub fn validate(
+    bytes: &[u8],
+    rules: &ValidationRules,
+) -> Result<(), Vec<ValidationError>> {
+    let len = bytes.len() / Instruction::SIZE;
+    let mut errors = Vec::new();
+
+    for (index, chunk) in bytes.chunks(Instruction::SIZE).enumerate() {
+        let offset = index.saturating_mul(Instruction::SIZE);
+        let mut report = |kind| errors.push(ValidationError { offset, kind });
+
+        let Ok(raw) = <[u8; 4]>::try_from(chunk) else {
+            if rules.truncated_instructions {
+                report(ValidationErrorKind::TruncatedInstruction);
+            }
+            continue
+        };
+        let instruction = match Instruction::try_from(raw) {
+            Ok(instruction) => instruction,
+            Err(_) => {
+                if rules.invalid_instructions {
+                    report(match Opcode::try_from(raw[0]) {
+                        Ok(_) => ValidationErrorKind::InvalidInstruction,
+                        Err(_) => ValidationErrorKind::ReservedOpcode(raw[0]),
+                    });
+                }
+                continue
+            }
+        };
+
+        if rules.jump_targets && instruction.can_branch() {
+            let target = instruction.static_jump_target(index);
+            let is_static = target.is_some() || !is_dynamic_jump(&instruction);
+            if is_static && target.filter(|target| *target < len).is_none() {
+                report(ValidationErrorKind::JumpTargetOutOfBounds(target));
+            }
+        }
+        if rules.read_only_registers {
+            if let Some(reg) = instruction
+                .written_registers()
+                .iter()
+                .find(|reg| *reg < RegId::WRITABLE)
+            {
+                report(ValidationErrorKind::ReadOnlyRegisterWrite(reg));
+            }
+        }
+        if rules.non_canonical_arguments && !instruction.is_canonical() {
+            report(ValidationErrorKind::NonCanonicalArguments);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op;
+    use alloc::vec;
+
+    fn bytecode(program: &[Instruction]) -> Vec<u8> {
+        program.iter().copied().collect()
+    }
+
+    #[test]
+    fn valid_program_passes() {
+        let program = bytecode(&[
+            op::movi(0x10, 3),
+            op::subi(0x10, 0x10, 1),
+            op::jnzb(0x10, RegId::ZERO, 0),
+            op::jmp(0x11),
+            op::ret(RegId::ONE),
+        ]);
+
+        assert_eq!(validate(&program, &ValidationRules::strict()), Ok(()));
+    }
+
+    #[test]
+    fn every_violation_is_reported() {
+        let mut program = bytecode(&[
+            // Targets the truncated instruction
+            op::ji(6),
+            op::add(RegId::SP, 0x10, 0x11),
+            op::jmpb(RegId::ZERO, 3),
+            op::noop(),
+        ]);
+        program.extend([0xff, 0, 0, 0]);
+        program.extend([op::noop().to_bytes()[0], 0, 0, 1]);
+        program.extend([0, 0]);
+
+        let errors = validate(&program, &ValidationRules::default());
+
+        assert_eq!(
+            errors,
+            Err(vec![
+                ValidationError {
+                    offset: 0,
+                    kind: ValidationErrorKind::JumpTargetOutOfBounds(Some(6)),
+                },
+                ValidationError {
+                    offset: 4,
+                    kind: ValidationErrorKind::ReadOnlyRegisterWrite(RegId::SP),
+                },
+                ValidationError {
+                    offset: 8,
+                    kind: ValidationErrorKind::JumpTargetOutOfBounds(None),
+                },
+                ValidationError {
+                    offset: 16,
+                    kind: ValidationErrorKind::ReservedOpcode(0xff),
+                },
+                ValidationError {
+                    offset: 20,
+                    kind: ValidationErrorKind::InvalidInstruction,
+                },
+                ValidationError {
+                    offset: 24,
+                    kind: ValidationErrorKind::TruncatedInstruction,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn disabled_rules_are_skipped() {
+        let mut program = bytecode(&[op::ji(5), op::movi(RegId::HP, 0)]);
+        program.push(0);
+
+        let rules = ValidationRules {
+            jump_targets: false,
+            read_only_registers: false,
+            truncated_instructions: false,
+            ..ValidationRules::default()
+        };
+
+        assert_eq!(validate(&program, &rules), Ok(()));
+    }
+
+    #[test]
+    fn non_canonical_arguments_are_rejected_in_strict_mode() {
+        // `GM` with an unknown metadata selector
+        let program = bytecode(&[op::gm(0x10, 0)]);
+
+        assert_eq!(validate(&program, &ValidationRules::default()), Ok(()));
+        assert_eq!(
+            validate(&program, &ValidationRules::strict()),
+            Err(vec![ValidationError {
+                offset: 0,
+                kind: ValidationErrorKind::NonCanonicalArguments,
+            }])
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::traits::*;
 use crate::RegistryKey;
+use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 use fuel_types::{
     Address,
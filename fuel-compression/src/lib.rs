@@ -1,11 +1,14 @@
 //! Compression and decompression of fuel-types for the DA layer
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![no_std]
 #![warn(missing_docs)]
 #![deny(unsafe_code)]
 #![deny(unused_crate_dependencies)]
 #![deny(clippy::cast_possible_truncation)]
 
+extern crate alloc;
+
 mod impls;
 mod key;
 mod traits;
@@ -140,7 +140,7 @@ fn signatures(c: &mut Criterion) {
 
     group_sign.bench_with_input(
         "fuel-crypto-sign",
-        &(fc_key, fc_message),
+        &(fc_key.clone(), fc_message),
         |b, (key, message)| {
             b.iter(|| fuel_crypto::Signature::sign(black_box(key), black_box(message)))
         },
@@ -0,0 +1,64 @@
+//! Verification of many signatures at once, e.g. of the inputs of a block.
+//!
+//! Despite the name, this is not a batch verification: ECDSA has no batch
+//! verification equation, and the one of Ed25519 accepts some signatures rejected by
+//! the strict verification of the `ED19` instruction. So each signature is verified
+//! on its own with the same rules as the single verification, and the invalid ones
+//! are reported by their indices.
+//! With the `parallel` feature, the signatures are verified on the threads of the
+//! rayon pool.
+
+use crate::{
+    Message,
+    PublicKey,
+    Signature,
+};
+
+use alloc::vec::Vec;
+
+/// Verify the secp256k1 signatures of the messages, returning the indices of the
+/// invalid ones in the ascending order. All the signatures are valid if it is
+/// empty.
+///
+/// Each signature is checked on its own, as by [`Signature::verify`], there is no
+/// batch verification equation.
+pub fn batch_verify<I>(signatures: I) -> Vec<usize>
+where
+    I: IntoIterator<Item = (Signature, PublicKey, Message)>,
+{
+    failed_indices(signatures, |(signature, public_key, message)| {
+        signature.verify(public_key, message).is_ok()
+    })
+}
+
+/// The indices of the `items` for which `verify` returns `false`.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn failed_indices<T, I, F>(items: I, verify: F) -> Vec<usize>
+where
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> bool,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, item)| (!verify(&item)).then_some(index))
+        .collect()
+}
+
+/// The indices of the `items` for which `verify` returns `false`.
+#[cfg(feature = "parallel")]
+pub(crate) fn failed_indices<T, I, F>(items: I, verify: F) -> Vec<usize>
+where
+    T: Sync,
+    I: IntoIterator<Item = T>,
+    F: Fn(&T) -> bool + Sync,
+{
+    use rayon::prelude::*;
+
+    let items = items.into_iter().collect::<Vec<_>>();
+    items
+        .par_iter()
+        .enumerate()
+        .filter_map(|(index, item)| (!verify(item)).then_some(index))
+        .collect()
+}
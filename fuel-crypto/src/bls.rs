@@ -0,0 +1,321 @@
+//! BLS12-381 signatures with aggregation.
+//!
+//! The public keys are points of G1 and the signatures are points of G2, both in the
+//! compressed form, as in Ethereum consensus. The messages are signed with the
+//! proof-of-possession ciphersuite, so the public keys aggregated by
+//! [`Signature::verify_aggregate`] must come with a verified
+//! [proof of possession](SecretKey::proof_of_possession), otherwise the aggregate
+//! is vulnerable to rogue key attacks.
+
+use crate::{
+    macros::{
+        bytes_wrapper,
+        constant_time_secret,
+    },
+    Error,
+    Message,
+};
+
+use alloc::vec::Vec;
+use blst::{
+    min_pk,
+    BLST_ERROR,
+};
+use core::str;
+use fuel_types::{
+    Bytes32,
+    Bytes48,
+    Bytes96,
+};
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+};
+
+#[cfg(feature = "random")]
+use rand::{
+    CryptoRng,
+    RngCore,
+};
+
+/// Domain separation tag of the signatures of the messages.
+const SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// Domain separation tag of the proofs of possession.
+const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// BLS12-381 secret key, guaranteed to be valid by construction.
+///
+/// The key is zeroized when dropped, and is compared in constant time.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct SecretKey(Bytes32);
+
+bytes_wrapper!(SecretKey, Bytes32);
+constant_time_secret!(SecretKey);
+
+impl SecretKey {
+    /// Create a new random secret.
+    #[cfg(feature = "random")]
+    pub fn random(rng: &mut (impl CryptoRng + RngCore)) -> Self {
+        let mut ikm = [0u8; 32];
+        rng.fill_bytes(&mut ikm);
+        let secret = Self::from_seed(&ikm).expect("The seed is 32 bytes long");
+        ikm.zeroize();
+        secret
+    }
+
+    /// Derive the secret deterministically from the `seed`, i.e. the input key
+    /// material of at least 32 bytes, as specified by EIP-2333.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        min_pk::SecretKey::key_gen(seed, &[])
+            .map(|secret| Self(secret.to_bytes().into()))
+            .map_err(|_| Error::InvalidSecretKey)
+    }
+
+    /// Return the public key of this secret.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(self.secret().sk_to_pk().compress().into())
+    }
+
+    /// Sign the public key of this secret, proving its possession to the verifiers
+    /// of the aggregated signatures.
+    pub fn proof_of_possession(&self) -> Signature {
+        let public_key = self.public_key();
+        Signature(
+            self.secret()
+                .sign(public_key.as_ref(), POP_DST, &[])
+                .compress()
+                .into(),
+        )
+    }
+
+    fn secret(&self) -> min_pk::SecretKey {
+        min_pk::SecretKey::from_bytes(self.as_ref())
+            .expect("SecretKey is guaranteed to be valid")
+    }
+}
+
+impl TryFrom<Bytes32> for SecretKey {
+    type Error = Error;
+
+    fn try_from(b: Bytes32) -> Result<Self, Self::Error> {
+        match min_pk::SecretKey::from_bytes(b.as_ref()) {
+            Ok(_) => Ok(Self(b)),
+            Err(_) => Err(Error::InvalidSecretKey),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for SecretKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes32::try_from(slice)
+            .map_err(|_| Error::InvalidSecretKey)
+            .and_then(SecretKey::try_from)
+    }
+}
+
+impl str::FromStr for SecretKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes32::from_str(s)
+            .map_err(|_| Error::InvalidSecretKey)
+            .and_then(SecretKey::try_from)
+    }
+}
+
+/// BLS12-381 public key, a point of G1 in the compressed form, guaranteed to be
+/// valid by construction.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct PublicKey(Bytes48);
+
+bytes_wrapper!(PublicKey, Bytes48);
+
+impl PublicKey {
+    /// Aggregate the public keys, e.g. to verify later the signatures aggregated
+    /// from the same message.
+    pub fn aggregate(public_keys: &[PublicKey]) -> Result<Self, Error> {
+        let points = public_keys
+            .iter()
+            .map(PublicKey::point)
+            .collect::<Result<Vec<_>, _>>()?;
+        let points = points.iter().collect::<Vec<_>>();
+        let aggregate = min_pk::AggregatePublicKey::aggregate(&points, false)
+            .map_err(|_| Error::InvalidPublicKey)?;
+        Ok(Self(aggregate.to_public_key().compress().into()))
+    }
+
+    /// Verify that the `proof` proves the possession of the secret of this key.
+    pub fn verify_proof_of_possession(&self, proof: &Signature) -> Result<(), Error> {
+        let point = self.point()?;
+        let proof = proof.point()?;
+        check(proof.verify(false, self.as_ref(), POP_DST, &[], &point, false))
+    }
+
+    fn point(&self) -> Result<min_pk::PublicKey, Error> {
+        min_pk::PublicKey::key_validate(self.as_ref())
+            .map_err(|_| Error::InvalidPublicKey)
+    }
+}
+
+impl TryFrom<Bytes48> for PublicKey {
+    type Error = Error;
+
+    fn try_from(b: Bytes48) -> Result<Self, Self::Error> {
+        let public_key = Self(b);
+        public_key.point()?;
+        Ok(public_key)
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes48::try_from(slice)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+impl str::FromStr for PublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes48::from_str(s)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+/// BLS12-381 signature, a point of G2 in the compressed form.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Signature(Bytes96);
+
+bytes_wrapper!(Signature, Bytes96);
+
+impl Signature {
+    /// Construct a `Signature` directly from its bytes.
+    ///
+    /// This constructor expects the given bytes to be a valid signature. No signing is
+    /// performed.
+    pub fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Produce the signature of the message.
+    pub fn sign(secret: &SecretKey, message: &Message) -> Self {
+        Self(
+            secret
+                .secret()
+                .sign(message.as_ref(), SIGNATURE_DST, &[])
+                .compress()
+                .into(),
+        )
+    }
+
+    /// Verify that the signature matches the given public key.
+    pub fn verify(&self, public_key: &PublicKey, message: &Message) -> Result<(), Error> {
+        let point = public_key.point()?;
+        check(self.point()?.verify(
+            false,
+            message.as_ref(),
+            SIGNATURE_DST,
+            &[],
+            &point,
+            false,
+        ))
+    }
+
+    /// Aggregate the signatures into one signature, verified with
+    /// [`Self::verify_aggregate`] if all the signatures are of the same message, or
+    /// with [`Self::verify_aggregate_messages`] otherwise.
+    pub fn aggregate(signatures: &[Signature]) -> Result<Self, Error> {
+        let points = signatures
+            .iter()
+            .map(Signature::point)
+            .collect::<Result<Vec<_>, _>>()?;
+        let points = points.iter().collect::<Vec<_>>();
+        let aggregate = min_pk::AggregateSignature::aggregate(&points, false)
+            .map_err(|_| Error::InvalidSignature)?;
+        Ok(Self(aggregate.to_signature().compress().into()))
+    }
+
+    /// Verify that the aggregated signature matches the public keys which all signed
+    /// the message. The possession of the secrets of the public keys must be
+    /// verified beforehand, see [`PublicKey::verify_proof_of_possession`].
+    pub fn verify_aggregate(
+        &self,
+        public_keys: &[PublicKey],
+        message: &Message,
+    ) -> Result<(), Error> {
+        let points = public_keys
+            .iter()
+            .map(PublicKey::point)
+            .collect::<Result<Vec<_>, _>>()?;
+        let points = points.iter().collect::<Vec<_>>();
+        check(self.point()?.fast_aggregate_verify(
+            false,
+            message.as_ref(),
+            SIGNATURE_DST,
+            &points,
+        ))
+    }
+
+    /// Verify that the aggregated signature matches the public keys, each of which
+    /// signed the message at the same position.
+    pub fn verify_aggregate_messages(
+        &self,
+        public_keys: &[PublicKey],
+        messages: &[Message],
+    ) -> Result<(), Error> {
+        if public_keys.len() != messages.len() {
+            return Err(Error::InvalidMessage)
+        }
+        let points = public_keys
+            .iter()
+            .map(PublicKey::point)
+            .collect::<Result<Vec<_>, _>>()?;
+        let points = points.iter().collect::<Vec<_>>();
+        let messages = messages.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        check(self.point()?.aggregate_verify(
+            false,
+            &messages,
+            SIGNATURE_DST,
+            &points,
+            false,
+        ))
+    }
+
+    fn point(&self) -> Result<min_pk::Signature, Error> {
+        min_pk::Signature::sig_validate(self.as_ref(), true)
+            .map_err(|_| Error::InvalidSignature)
+    }
+}
+
+impl str::FromStr for Signature {
+    type Err = Error;
+
+    /// Parse a `Signature` directly from its bytes encoded as hex in a string.
+    ///
+    /// This constructor does not perform any signing.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes96::from_str(s)
+            .map_err(|_| Error::InvalidSignature)
+            .map(|s| Self::from_bytes(s.into()))
+    }
+}
+
+fn check(result: BLST_ERROR) -> Result<(), Error> {
+    match result {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(Error::InvalidSignature),
+    }
+}
@@ -1,12 +1,30 @@
-//! ED25519 signature verification
+//! ED25519 signatures, as verified by the `ED19` instruction.
 
-use ed25519_dalek::Signature;
+use ed25519_dalek::Signer;
 use fuel_types::{
     Bytes32,
     Bytes64,
 };
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+};
+
+use core::str;
 
-use crate::Error;
+use crate::{
+    macros::{
+        bytes_wrapper,
+        constant_time_secret,
+    },
+    Error,
+};
+
+#[cfg(feature = "random")]
+use rand::{
+    CryptoRng,
+    RngCore,
+};
 
 /// Verify a signature against a message digest and a public key.
 pub fn verify(
@@ -14,7 +32,7 @@ pub fn verify(
     signature: &Bytes64,
     message: &[u8],
 ) -> Result<(), Error> {
-    let signature = Signature::from_bytes(signature);
+    let signature = ed25519_dalek::Signature::from_bytes(signature);
 
     let pub_key = ed25519_dalek::VerifyingKey::from_bytes(pub_key)
         .map_err(|_| Error::InvalidPublicKey)?;
@@ -25,3 +43,162 @@ pub fn verify(
         Err(Error::InvalidSignature)
     }
 }
+
+/// Verify the signatures of the messages, returning the indices of the invalid ones
+/// in the ascending order, see [`crate::batch_verify`].
+///
+/// Each signature is checked on its own, as by [`verify`], since the batch
+/// verification equation of Ed25519 is more permissive than the strict verification.
+#[cfg(feature = "alloc")]
+pub fn batch_verify<'a, I>(signatures: I) -> alloc::vec::Vec<usize>
+where
+    I: IntoIterator<Item = (Signature, PublicKey, &'a [u8])>,
+{
+    crate::batch::failed_indices(signatures, |(signature, public_key, message)| {
+        signature.verify(public_key, message).is_ok()
+    })
+}
+
+/// ED25519 secret key, i.e. the 32 bytes seed of the signing key.
+///
+/// The key is zeroized when dropped, and is compared in constant time.
+#[derive(Default, Clone, Zeroize, ZeroizeOnDrop)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct SecretKey(Bytes32);
+
+bytes_wrapper!(SecretKey, Bytes32);
+constant_time_secret!(SecretKey);
+
+impl SecretKey {
+    /// Create a new random secret.
+    #[cfg(feature = "random")]
+    pub fn random(rng: &mut (impl CryptoRng + RngCore)) -> Self {
+        let mut bytes = Bytes32::zeroed();
+        rng.fill_bytes(bytes.as_mut());
+        Self(bytes)
+    }
+
+    /// Construct a `SecretKey` directly from its bytes. Any 32 bytes are a valid
+    /// secret.
+    pub fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Return the public key of this secret.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(self.signing_key().verifying_key().to_bytes().into())
+    }
+
+    fn signing_key(&self) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&self.0)
+    }
+}
+
+impl From<Bytes32> for SecretKey {
+    fn from(b: Bytes32) -> Self {
+        Self(b)
+    }
+}
+
+impl TryFrom<&[u8]> for SecretKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes32::try_from(slice)
+            .map(Self)
+            .map_err(|_| Error::InvalidSecretKey)
+    }
+}
+
+impl str::FromStr for SecretKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes32::from_str(s)
+            .map(Self)
+            .map_err(|_| Error::InvalidSecretKey)
+    }
+}
+
+/// ED25519 public key, i.e. the compressed Edwards point of the verifying key,
+/// guaranteed to be valid by construction.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct PublicKey(Bytes32);
+
+bytes_wrapper!(PublicKey, Bytes32);
+
+impl TryFrom<Bytes32> for PublicKey {
+    type Error = Error;
+
+    fn try_from(b: Bytes32) -> Result<Self, Self::Error> {
+        match ed25519_dalek::VerifyingKey::from_bytes(&b) {
+            Ok(_) => Ok(Self(b)),
+            Err(_) => Err(Error::InvalidPublicKey),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes32::try_from(slice)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+impl str::FromStr for PublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes32::from_str(s)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(PublicKey::try_from)
+    }
+}
+
+/// ED25519 signature.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Signature(Bytes64);
+
+bytes_wrapper!(Signature, Bytes64);
+
+impl Signature {
+    /// Construct a `Signature` directly from its bytes.
+    ///
+    /// This constructor expects the given bytes to be a valid signature. No signing is
+    /// performed.
+    pub fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Produce the signature of the message.
+    pub fn sign(secret: &SecretKey, message: &[u8]) -> Self {
+        Self(secret.signing_key().sign(message).to_bytes().into())
+    }
+
+    /// Verify that the signature matches the given public key, as done by the
+    /// `ED19` instruction.
+    pub fn verify(&self, public_key: &PublicKey, message: &[u8]) -> Result<(), Error> {
+        verify(&public_key.0, &self.0, message)
+    }
+}
+
+impl str::FromStr for Signature {
+    type Err = Error;
+
+    /// Parse a `Signature` directly from its bytes encoded as hex in a string.
+    ///
+    /// This constructor does not perform any signing.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes64::from_str(s)
+            .map_err(|_| Error::InvalidSignature)
+            .map(|s| Self::from_bytes(s.into()))
+    }
+}
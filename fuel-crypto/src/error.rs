@@ -33,6 +33,9 @@ pub enum Error {
 
     /// Bip32-related error
     Bip32Error,
+
+    /// The bytes aren't an element of the scalar field
+    InvalidFieldElement,
 }
 
 impl From<Error> for Infallible {
@@ -90,3 +90,11 @@ where
         iter.into_iter().for_each(|b| self.input(b))
     }
 }
+
+/// Hashes the canonical encoding as it is produced, without buffering it.
+impl fuel_types::canonical::Output for Hasher {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), fuel_types::canonical::Error> {
+        self.input(bytes);
+        Ok(())
+    }
+}
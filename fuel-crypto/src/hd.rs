@@ -0,0 +1,313 @@
+//! BIP-32 hierarchical deterministic keys.
+
+use crate::{
+    Error,
+    PublicKey,
+    SecretKey,
+};
+
+use coins_bip32::{
+    ecdsa,
+    enc::{
+        MainnetEncoder,
+        XKeyEncoder,
+    },
+    path::DerivationPath,
+    primitives::{
+        ChainCode,
+        Hint,
+        KeyFingerprint,
+        XKeyInfo,
+    },
+    xkeys::{
+        Parent,
+        XPriv,
+        XPub,
+    },
+};
+use coins_bip39::{
+    English,
+    Mnemonic,
+};
+use fuel_types::Bytes32;
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+};
+
+use core::{
+    fmt,
+    str,
+};
+
+/// The BIP-44 coin type of Fuel, i.e. "FUEL" read as a big-endian number.
+pub const FUEL_COIN_TYPE: u32 = 1179993420;
+
+/// The BIP-44 path of the first address of the Fuel wallets.
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/1179993420'/0'/0/0";
+
+/// The index of the first hardened child.
+pub const HARDENED_INDEX: u32 = 1 << 31;
+
+/// The length of the base58 encoding of the extended keys.
+const ENCODED_LEN: usize = 111;
+
+/// The position of an extended key in the BIP-32 tree.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroize)]
+struct KeyInfo {
+    chain_code: Bytes32,
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+}
+
+impl From<&XKeyInfo> for KeyInfo {
+    fn from(info: &XKeyInfo) -> Self {
+        Self {
+            chain_code: info.chain_code.0.into(),
+            depth: info.depth,
+            parent_fingerprint: info.parent.0,
+            child_number: info.index,
+        }
+    }
+}
+
+impl From<&KeyInfo> for XKeyInfo {
+    fn from(info: &KeyInfo) -> Self {
+        Self {
+            depth: info.depth,
+            parent: KeyFingerprint(info.parent_fingerprint),
+            index: info.child_number,
+            chain_code: ChainCode(*info.chain_code),
+            hint: Hint::Legacy,
+        }
+    }
+}
+
+/// Extended secp256k1 secret key, i.e. the secret key with the chain code to
+/// derive its children, as specified by BIP-32.
+///
+/// The key is zeroized when dropped.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Zeroize, ZeroizeOnDrop)]
+pub struct ExtendedSecretKey {
+    secret_key: SecretKey,
+    info: KeyInfo,
+}
+
+impl ExtendedSecretKey {
+    /// Create the master key from the `seed` of 16 to 64 bytes.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        Ok(Self::from(&XPriv::root_from_seed(seed, None)?))
+    }
+
+    /// Create the master key from the BIP-39 mnemonic phrase in English, protected
+    /// by the optional `password`.
+    pub fn from_mnemonic_phrase(
+        phrase: &str,
+        password: Option<&str>,
+    ) -> Result<Self, Error> {
+        let mnemonic = Mnemonic::<English>::new_from_phrase(phrase)?;
+        Ok(Self::from(&mnemonic.master_key(password)?))
+    }
+
+    /// Derive the child with the `index`, hardened if it is at least
+    /// [`HARDENED_INDEX`].
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        Ok(Self::from(&self.xpriv().derive_child(index)?))
+    }
+
+    /// Derive the descendant at the `path`, e.g. [`DEFAULT_DERIVATION_PATH`].
+    pub fn derive_path(&self, path: &str) -> Result<Self, Error> {
+        let path: DerivationPath = path.parse()?;
+        Ok(Self::from(&self.xpriv().derive_path(path)?))
+    }
+
+    /// The secret key.
+    pub fn secret_key(&self) -> SecretKey {
+        self.secret_key.clone()
+    }
+
+    /// The public key of the secret key.
+    pub fn public_key(&self) -> PublicKey {
+        self.secret_key.public_key()
+    }
+
+    /// The extended public key, deriving the public keys of the non-hardened
+    /// children.
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            public_key: self.public_key(),
+            info: self.info,
+        }
+    }
+
+    /// The chain code.
+    pub fn chain_code(&self) -> Bytes32 {
+        self.info.chain_code
+    }
+
+    /// The depth in the tree, zero for the master key.
+    pub fn depth(&self) -> u8 {
+        self.info.depth
+    }
+
+    /// The index of the key among the children of its parent.
+    pub fn child_number(&self) -> u32 {
+        self.info.child_number
+    }
+
+    /// The fingerprint of the parent, zeroed for the master key.
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.info.parent_fingerprint
+    }
+
+    /// The fingerprint of the key, i.e. the first 4 bytes of the HASH160 of the
+    /// compressed public key.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        self.xpriv().fingerprint().0
+    }
+
+    fn xpriv(&self) -> XPriv {
+        let key = k256::SecretKey::from(&self.secret_key);
+        XPriv::new(ecdsa::SigningKey::from(key), XKeyInfo::from(&self.info))
+    }
+}
+
+impl From<&XPriv> for ExtendedSecretKey {
+    fn from(xpriv: &XPriv) -> Self {
+        let key: &ecdsa::SigningKey = xpriv.as_ref();
+        let info: &XKeyInfo = xpriv.as_ref();
+        Self {
+            secret_key: SecretKey::from(k256::SecretKey::from(key)),
+            info: KeyInfo::from(info),
+        }
+    }
+}
+
+impl fmt::Debug for ExtendedSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedSecretKey")
+            .field("depth", &self.info.depth)
+            .field("child_number", &self.info.child_number)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ExtendedSecretKey {
+    /// Formats the key as an `xprv` string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded =
+            MainnetEncoder::xpriv_to_base58(&self.xpriv()).map_err(|_| fmt::Error)?;
+        f.write_str(&encoded)
+    }
+}
+
+impl str::FromStr for ExtendedSecretKey {
+    type Err = Error;
+
+    /// Parse the key from an `xprv` string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != ENCODED_LEN {
+            return Err(Error::Bip32Error)
+        }
+        Ok(Self::from(&MainnetEncoder::xpriv_from_base58(s)?))
+    }
+}
+
+/// Extended secp256k1 public key, i.e. the public key with the chain code to derive
+/// the public keys of its non-hardened children, as specified by BIP-32.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExtendedPublicKey {
+    public_key: PublicKey,
+    info: KeyInfo,
+}
+
+impl ExtendedPublicKey {
+    /// Derive the public key of the non-hardened child with the `index`.
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        Ok(Self::from(&self.xpub()?.derive_child(index)?))
+    }
+
+    /// Derive the public key of the descendant at the `path` without hardened
+    /// indices.
+    pub fn derive_path(&self, path: &str) -> Result<Self, Error> {
+        let path: DerivationPath = path.parse()?;
+        Ok(Self::from(&self.xpub()?.derive_path(path)?))
+    }
+
+    /// The public key.
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// The chain code.
+    pub fn chain_code(&self) -> Bytes32 {
+        self.info.chain_code
+    }
+
+    /// The depth in the tree, zero for the master key.
+    pub fn depth(&self) -> u8 {
+        self.info.depth
+    }
+
+    /// The index of the key among the children of its parent.
+    pub fn child_number(&self) -> u32 {
+        self.info.child_number
+    }
+
+    /// The fingerprint of the parent, zeroed for the master key.
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.info.parent_fingerprint
+    }
+
+    fn xpub(&self) -> Result<XPub, Error> {
+        let mut sec1 = [0u8; PublicKey::LEN + 1];
+        sec1[0] = 0x04;
+        sec1[1..].copy_from_slice(self.public_key.as_ref());
+        let key = ecdsa::VerifyingKey::from_sec1_bytes(&sec1)
+            .map_err(|_| Error::InvalidPublicKey)?;
+        Ok(XPub::new(key, XKeyInfo::from(&self.info)))
+    }
+}
+
+impl From<&XPub> for ExtendedPublicKey {
+    fn from(xpub: &XPub) -> Self {
+        let key: &ecdsa::VerifyingKey = xpub.as_ref();
+        let info: &XKeyInfo = xpub.as_ref();
+        Self {
+            public_key: PublicKey::from(key),
+            info: KeyInfo::from(info),
+        }
+    }
+}
+
+impl fmt::Debug for ExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPublicKey")
+            .field("public_key", &self.public_key)
+            .field("depth", &self.info.depth)
+            .field("child_number", &self.info.child_number)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
+    /// Formats the key as an `xpub` string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xpub = self.xpub().map_err(|_| fmt::Error)?;
+        let encoded = MainnetEncoder::xpub_to_base58(&xpub).map_err(|_| fmt::Error)?;
+        f.write_str(&encoded)
+    }
+}
+
+impl str::FromStr for ExtendedPublicKey {
+    type Err = Error;
+
+    /// Parse the key from an `xpub` string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != ENCODED_LEN {
+            return Err(Error::Bip32Error)
+        }
+        Ok(Self::from(&MainnetEncoder::xpub_from_base58(s)?))
+    }
+}
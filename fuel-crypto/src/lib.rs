@@ -15,6 +15,9 @@
     clippy::string_slice
 )]
 
+#[cfg(any(feature = "alloc", feature = "bls"))]
+extern crate alloc;
+
 // Satisfy unused_crate_dependencies lint for self-dependency enabling test features
 #[cfg(test)]
 use fuel_crypto as _;
@@ -35,18 +38,30 @@ pub use fuel_types;
 /// Required export to use randomness features
 pub use rand;
 
+#[cfg(feature = "alloc")]
+mod batch;
 mod error;
 mod hasher;
+mod macros;
 mod message;
 mod mnemonic;
 mod secp256;
 
+#[cfg(feature = "bls")]
+pub mod bls;
 pub mod ed25519;
+#[cfg(feature = "std")]
+pub mod hd;
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
+pub mod schnorr;
 
 pub use secp256::backend::r1 as secp256r1;
 
 pub use secp256::{
+    CompactSignature,
     PublicKey,
+    RecoverableSignature,
     SecretKey,
     Signature,
 };
@@ -54,9 +69,13 @@ pub use secp256::{
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "alloc")]
+pub use batch::batch_verify;
 pub use error::Error;
 pub use hasher::Hasher;
 pub use message::Message;
 
 #[cfg(all(feature = "std", feature = "random"))]
 pub use mnemonic::generate_mnemonic_phrase;
+#[cfg(feature = "std")]
+pub use mnemonic::Mnemonic;
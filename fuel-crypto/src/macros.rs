@@ -0,0 +1,115 @@
+/// Implements the traits of the key types wrapping the `fuel_types` byte arrays.
+macro_rules! bytes_wrapper {
+    ($t:ident, $bytes:ident) => {
+        impl $t {
+            /// Memory length of the type in bytes.
+            pub const LEN: usize = $bytes::LEN;
+        }
+
+        impl core::ops::Deref for $t {
+            type Target = [u8; $t::LEN];
+
+            fn deref(&self) -> &[u8; $t::LEN] {
+                core::ops::Deref::deref(&self.0)
+            }
+        }
+
+        impl AsRef<[u8]> for $t {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl From<$t> for [u8; $t::LEN] {
+            fn from(value: $t) -> [u8; $t::LEN] {
+                value.0.into()
+            }
+        }
+
+        impl From<$t> for $bytes {
+            fn from(value: $t) -> $bytes {
+                value.0
+            }
+        }
+
+        impl core::fmt::LowerHex for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::UpperHex for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::Debug for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::Display for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+pub(crate) use bytes_wrapper;
+
+/// Implements the comparisons of the secret key types wrapping the `fuel_types` byte
+/// arrays in constant time, so their timing doesn't leak the keys.
+macro_rules! constant_time_secret {
+    ($t:ident) => {
+        impl subtle::ConstantTimeEq for $t {
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(self.0.as_slice(), other.0.as_slice())
+            }
+        }
+
+        impl PartialEq for $t {
+            fn eq(&self, other: &Self) -> bool {
+                subtle::ConstantTimeEq::ct_eq(self, other).into()
+            }
+        }
+
+        impl Eq for $t {}
+
+        impl PartialOrd for $t {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $t {
+            /// Compares the keys lexicographically in constant time.
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                use subtle::{
+                    ConstantTimeGreater,
+                    ConstantTimeLess,
+                };
+
+                let mut less = subtle::Choice::from(0);
+                let mut greater = subtle::Choice::from(0);
+                for (a, b) in self.0.iter().zip(other.0.iter()) {
+                    let undecided = !(less | greater);
+                    less |= undecided & a.ct_lt(b);
+                    greater |= undecided & a.ct_gt(b);
+                }
+
+                if bool::from(less) {
+                    core::cmp::Ordering::Less
+                } else if bool::from(greater) {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use constant_time_secret;
@@ -1,17 +1,114 @@
-#![cfg(all(feature = "std", feature = "random"))]
+#![cfg(feature = "std")]
 
-use crate::Error;
-use coins_bip39::{
-    English,
-    Mnemonic,
+use crate::{
+    hd::{
+        ExtendedSecretKey,
+        FUEL_COIN_TYPE,
+    },
+    Error,
+    SecretKey,
 };
+use coins_bip39::English;
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+};
+
+use core::{
+    fmt,
+    str,
+};
+
+#[cfg(feature = "random")]
 use rand::Rng;
 
 /// Generates a random mnemonic phrase given a random number generator and
 /// the number of words to generate, `count`.
+#[cfg(feature = "random")]
 pub fn generate_mnemonic_phrase<R: Rng>(
     rng: &mut R,
     count: usize,
 ) -> Result<String, Error> {
-    Ok(Mnemonic::<English>::new_with_count(rng, count)?.to_phrase())
+    Ok(coins_bip39::Mnemonic::<English>::new_with_count(rng, count)?.to_phrase())
+}
+
+/// BIP-39 mnemonic phrase in English, guaranteed to be valid by construction.
+///
+/// The phrase is zeroized when dropped.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct Mnemonic {
+    phrase: String,
+}
+
+impl Mnemonic {
+    /// Generates a random mnemonic of `count` words, i.e. 12, 15, 18, 21 or 24.
+    #[cfg(feature = "random")]
+    pub fn random<R: Rng>(rng: &mut R, count: usize) -> Result<Self, Error> {
+        Ok(Self {
+            phrase: generate_mnemonic_phrase(rng, count)?,
+        })
+    }
+
+    /// Validates the words and the checksum of the `phrase`.
+    pub fn from_phrase(phrase: &str) -> Result<Self, Error> {
+        let mnemonic = coins_bip39::Mnemonic::<English>::new_from_phrase(phrase)?;
+        Ok(Self {
+            phrase: mnemonic.to_phrase(),
+        })
+    }
+
+    /// The phrase, with the words separated by single spaces.
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// The number of words of the phrase.
+    pub fn word_count(&self) -> usize {
+        self.phrase.split(' ').count()
+    }
+
+    /// The 64 bytes seed of the phrase, protected by the optional `password`.
+    pub fn to_seed(&self, password: Option<&str>) -> Result<[u8; 64], Error> {
+        Ok(self.inner()?.to_seed(password)?)
+    }
+
+    /// The BIP-32 master key of the phrase, protected by the optional `password`.
+    pub fn master_key(&self, password: Option<&str>) -> Result<ExtendedSecretKey, Error> {
+        let mut seed = self.to_seed(password)?;
+        let master_key = ExtendedSecretKey::from_seed(&seed);
+        seed.zeroize();
+        master_key
+    }
+
+    /// The secret key at the derivation `path`, e.g.
+    /// [`DEFAULT_DERIVATION_PATH`](crate::hd::DEFAULT_DERIVATION_PATH).
+    pub fn secret_key(&self, path: &str) -> Result<SecretKey, Error> {
+        Ok(self.master_key(None)?.derive_path(path)?.secret_key())
+    }
+
+    /// The secret key of the first address of the Fuel `account`, i.e. at the path
+    /// `m/44'/1179993420'/{account}'/0/0`, as derived by the Fuel wallets.
+    pub fn account_secret_key(&self, account: u32) -> Result<SecretKey, Error> {
+        self.secret_key(&format!("m/44'/{FUEL_COIN_TYPE}'/{account}'/0/0"))
+    }
+
+    fn inner(&self) -> Result<coins_bip39::Mnemonic<English>, Error> {
+        Ok(coins_bip39::Mnemonic::new_from_phrase(&self.phrase)?)
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("word_count", &self.word_count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl str::FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_phrase(s)
+    }
 }
@@ -0,0 +1,147 @@
+//! Poseidon hash over the scalar fields of BN254 and BLS12-381, for the commitments
+//! verified by the zero-knowledge circuits.
+//!
+//! The parameters are fixed, so the hashes are stable across the versions:
+//!
+//! - the width of the state is 3, i.e. the rate is 2 and the capacity is 1;
+//! - the S-box is `x^5`, with 8 full rounds and 57 partial rounds;
+//! - the round constants and the MDS matrix are generated by the Grain LFSR of the
+//!   Poseidon paper, as done by the `arkworks` default parameters.
+//!
+//! The field elements are encoded as 32 bytes big-endian integers, and must be
+//! lower than the modulus of the field. The input is padded with a single `1`
+//! element, so the inputs of different lengths never collide.
+
+use crate::Error;
+
+use alloc::vec::Vec;
+use ark_crypto_primitives::sponge::{
+    poseidon::{
+        find_poseidon_ark_and_mds,
+        PoseidonConfig,
+        PoseidonSponge,
+    },
+    Absorb,
+    CryptographicSponge,
+};
+use ark_ff::{
+    BigInteger,
+    PrimeField,
+};
+use fuel_types::Bytes32;
+
+const RATE: usize = 2;
+const CAPACITY: usize = 1;
+const ALPHA: u64 = 5;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 57;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Scalar field of a curve, hashed by [`Poseidon`].
+pub trait Field: sealed::Sealed {
+    #[doc(hidden)]
+    type Scalar: PrimeField + Absorb;
+}
+
+/// The scalar field of BN254, as used by the Ethereum precompiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bn254;
+
+impl sealed::Sealed for Bn254 {}
+
+impl Field for Bn254 {
+    type Scalar = ark_bn254::Fr;
+}
+
+/// The scalar field of BLS12-381.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bls12_381;
+
+impl sealed::Sealed for Bls12_381 {}
+
+impl Field for Bls12_381 {
+    type Scalar = ark_bls12_381::Fr;
+}
+
+/// Poseidon hasher over the scalar field `F`.
+#[derive(Clone)]
+pub struct Poseidon<F: Field> {
+    sponge: PoseidonSponge<F::Scalar>,
+}
+
+impl<F: Field> Default for Poseidon<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> Poseidon<F> {
+    /// Create a new hasher. Generating the parameters takes a while, so clone the
+    /// hasher to hash many inputs.
+    pub fn new() -> Self {
+        let prime_bits = u64::from(F::Scalar::MODULUS_BIT_SIZE);
+        let (ark, mds) = find_poseidon_ark_and_mds::<F::Scalar>(
+            prime_bits,
+            RATE,
+            FULL_ROUNDS as u64,
+            PARTIAL_ROUNDS as u64,
+            0,
+        );
+        let config = PoseidonConfig::new(
+            FULL_ROUNDS,
+            PARTIAL_ROUNDS,
+            ALPHA,
+            mds,
+            ark,
+            RATE,
+            CAPACITY,
+        );
+
+        Self {
+            sponge: PoseidonSponge::new(&config),
+        }
+    }
+
+    /// Absorb the field element. Fails if the `element` isn't lower than the modulus.
+    pub fn input(&mut self, element: &Bytes32) -> Result<(), Error> {
+        let element = Self::element(element)?;
+        self.sponge.absorb(&element);
+        Ok(())
+    }
+
+    /// Pad the input and squeeze the hash.
+    pub fn finalize(mut self) -> Bytes32 {
+        self.sponge.absorb(&F::Scalar::from(1u8));
+        let hash = self.sponge.squeeze_field_elements::<F::Scalar>(1)[0];
+
+        let bytes = hash.into_bigint().to_bytes_be();
+        let mut output = Bytes32::zeroed();
+        output[Bytes32::LEN.saturating_sub(bytes.len())..].copy_from_slice(&bytes);
+        output
+    }
+
+    /// Hash the field elements.
+    pub fn hash<'a, I>(elements: I) -> Result<Bytes32, Error>
+    where
+        I: IntoIterator<Item = &'a Bytes32>,
+    {
+        let mut hasher = Self::new();
+        for element in elements {
+            hasher.input(element)?;
+        }
+        Ok(hasher.finalize())
+    }
+
+    fn element(bytes: &Bytes32) -> Result<F::Scalar, Error> {
+        let element = F::Scalar::from_be_bytes_mod_order(bytes.as_ref());
+        let canonical: Vec<u8> = element.into_bigint().to_bytes_be();
+        if canonical.as_slice() == bytes.as_ref() {
+            Ok(element)
+        } else {
+            Err(Error::InvalidFieldElement)
+        }
+    }
+}
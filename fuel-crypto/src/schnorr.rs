@@ -0,0 +1,165 @@
+//! BIP-340 Schnorr signatures over secp256k1, as used by Taproot.
+//!
+//! The keys are the secp256k1 [`SecretKey`]s, whose public keys are used x-only, i.e.
+//! without the parity of `y`. The signatures are linear in the keys, so protocols
+//! like MuSig2 aggregate them into a single signature of the aggregated key.
+
+use crate::{
+    macros::bytes_wrapper,
+    Error,
+    Message,
+    PublicKey,
+    SecretKey,
+};
+
+use core::str;
+use fuel_types::{
+    Bytes32,
+    Bytes64,
+};
+use k256::schnorr::{
+    SigningKey,
+    VerifyingKey,
+};
+
+/// Verify a signature against a message digest and an x-only public key.
+pub fn verify(
+    pub_key: &Bytes32,
+    signature: &Bytes64,
+    message: &Message,
+) -> Result<(), Error> {
+    let signature = k256::schnorr::Signature::try_from(signature.as_ref())
+        .map_err(|_| Error::InvalidSignature)?;
+
+    let pub_key = VerifyingKey::from_bytes(pub_key.as_ref())
+        .map_err(|_| Error::InvalidPublicKey)?;
+
+    pub_key
+        .verify_raw(message.as_ref(), &signature)
+        .map_err(|_| Error::InvalidSignature)
+}
+
+/// X-only secp256k1 public key, i.e. the `x` coordinate of a point with an even `y`,
+/// guaranteed to be valid by construction.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct XOnlyPublicKey(Bytes32);
+
+bytes_wrapper!(XOnlyPublicKey, Bytes32);
+
+impl XOnlyPublicKey {
+    /// Return the x-only public key of the secret.
+    pub fn from_secret(secret: &SecretKey) -> Self {
+        Self::from(&secret.public_key())
+    }
+}
+
+/// Drop the `y` coordinate of the public key. The public keys differing only by the
+/// parity of `y` have the same x-only public key.
+impl From<&PublicKey> for XOnlyPublicKey {
+    fn from(public_key: &PublicKey) -> Self {
+        let mut x = [0u8; Self::LEN];
+        x.copy_from_slice(&public_key[..Self::LEN]);
+        Self(x.into())
+    }
+}
+
+impl From<PublicKey> for XOnlyPublicKey {
+    fn from(public_key: PublicKey) -> Self {
+        Self::from(&public_key)
+    }
+}
+
+impl TryFrom<Bytes32> for XOnlyPublicKey {
+    type Error = Error;
+
+    fn try_from(b: Bytes32) -> Result<Self, Self::Error> {
+        match VerifyingKey::from_bytes(b.as_ref()) {
+            Ok(_) => Ok(Self(b)),
+            Err(_) => Err(Error::InvalidPublicKey),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for XOnlyPublicKey {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes32::try_from(slice)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(XOnlyPublicKey::try_from)
+    }
+}
+
+impl str::FromStr for XOnlyPublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes32::from_str(s)
+            .map_err(|_| Error::InvalidPublicKey)
+            .and_then(XOnlyPublicKey::try_from)
+    }
+}
+
+/// BIP-340 Schnorr signature, i.e. the `x` coordinate of the nonce point followed by
+/// the scalar `s`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Signature(Bytes64);
+
+bytes_wrapper!(Signature, Bytes64);
+
+impl Signature {
+    /// Construct a `Signature` directly from its bytes.
+    ///
+    /// This constructor expects the given bytes to be a valid signature. No signing is
+    /// performed.
+    pub fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Produce the signature of the message, with the nonce derived deterministically
+    /// from the secret and the message.
+    pub fn sign(secret: &SecretKey, message: &Message) -> Self {
+        Self::sign_with_aux_rand(secret, message, &[0; 32])
+    }
+
+    /// Produce the signature of the message, mixing the auxiliary random data into
+    /// the nonce as recommended by BIP-340 against the side-channel attacks.
+    pub fn sign_with_aux_rand(
+        secret: &SecretKey,
+        message: &Message,
+        aux_rand: &[u8; 32],
+    ) -> Self {
+        let signing_key = SigningKey::from_bytes(secret.as_ref())
+            .expect("SecretKey is guaranteed to be valid");
+        let signature = signing_key
+            .sign_raw(message.as_ref(), aux_rand)
+            .expect("The nonce is zero with a negligible probability");
+        Self(signature.to_bytes().into())
+    }
+
+    /// Verify that the signature matches the given x-only public key.
+    pub fn verify(
+        &self,
+        public_key: &XOnlyPublicKey,
+        message: &Message,
+    ) -> Result<(), Error> {
+        verify(&public_key.0, &self.0, message)
+    }
+}
+
+impl str::FromStr for Signature {
+    type Err = Error;
+
+    /// Parse a `Signature` directly from its bytes encoded as hex in a string.
+    ///
+    /// This constructor does not perform any signing.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes64::from_str(s)
+            .map_err(|_| Error::InvalidSignature)
+            .map(|s| Self::from_bytes(s.into()))
+    }
+}
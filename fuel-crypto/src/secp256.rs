@@ -7,4 +7,10 @@ mod signature_format;
 
 pub use public::PublicKey;
 pub use secret::SecretKey;
-pub use signature::Signature;
+pub use signature::{
+    CompactSignature,
+    RecoverableSignature,
+};
+
+/// The signature of the Fuel transactions, see [`RecoverableSignature`].
+pub type Signature = RecoverableSignature;
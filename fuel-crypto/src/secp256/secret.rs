@@ -6,9 +6,13 @@ use core::{
     str,
 };
 
-use zeroize::Zeroize;
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+};
 
 use crate::{
+    macros::constant_time_secret,
     secp256::PublicKey,
     Error,
 };
@@ -29,7 +33,10 @@ use rand::{
 };
 
 /// Asymmetric secret key, guaranteed to be valid by construction
-#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroize)]
+///
+/// The key is zeroized when dropped, and is compared in constant time, so neither a
+/// leftover copy in the memory nor the timing of the comparisons leaks it.
+#[derive(Default, Clone, Zeroize, ZeroizeOnDrop)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SecretKey(Bytes32);
@@ -39,6 +46,8 @@ impl SecretKey {
     pub const LEN: usize = Bytes32::LEN;
 }
 
+constant_time_secret!(SecretKey);
+
 impl Deref for SecretKey {
     type Target = [u8; SecretKey::LEN];
 
@@ -125,6 +134,16 @@ impl SecretKey {
         super::backend::k1::random_secret(rng)
     }
 
+    /// Generate the secret key of the first Fuel address from a mnemonic phrase, i.e.
+    /// at the [`DEFAULT_DERIVATION_PATH`](crate::hd::DEFAULT_DERIVATION_PATH).
+    #[cfg(feature = "std")]
+    pub fn new_from_mnemonic_phrase(phrase: &str) -> Result<Self, Error> {
+        Self::new_from_mnemonic_phrase_with_path(
+            phrase,
+            crate::hd::DEFAULT_DERIVATION_PATH,
+        )
+    }
+
     /// Generate a new secret key from a mnemonic phrase and its derivation path.
     /// Both are passed as `&str`. If you want to manually create a `DerivationPath`
     /// and `Mnemonic`, use [`SecretKey::new_from_mnemonic`].
@@ -1,5 +1,13 @@
-use super::backend::k1;
+use super::{
+    backend::k1,
+    signature_format::{
+        decode_signature,
+        encode_signature,
+        RecoveryId,
+    },
+};
 use crate::{
+    macros::bytes_wrapper,
     Error,
     Message,
     PublicKey,
@@ -14,17 +22,23 @@ use core::{
     str,
 };
 
-/// Compressed-form Secp256k1 signature.
+/// Secp256k1 signature with the recovery id, as signed by the owners of the Fuel
+/// transactions and the messages recovered by the VM.
+///
+/// The recovery id is compressed into the highest bit of `s`, as described in
+/// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/cryptographic-primitives.md>,
+/// so the public key of the signer can be recovered. Use [`CompactSignature`] where
+/// the signature is only verified against a known public key.
 #[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
-pub struct Signature(Bytes64);
+pub struct RecoverableSignature(Bytes64);
 
-impl Signature {
+impl RecoverableSignature {
     /// Memory length of the type in bytes.
     pub const LEN: usize = Bytes64::LEN;
 
-    /// Construct a `Signature` directly from its bytes.
+    /// Construct a `RecoverableSignature` directly from its bytes.
     ///
     /// This constructor expects the given bytes to be a valid signature. No signing is
     /// performed.
@@ -32,7 +46,8 @@ impl Signature {
         Self(bytes.into())
     }
 
-    /// Construct a `Signature` reference directly from a reference to its bytes.
+    /// Construct a `RecoverableSignature` reference directly from a reference to its
+    /// bytes.
     ///
     /// This constructor expects the given bytes to be a valid signature. No signing is
     /// performed.
@@ -45,72 +60,73 @@ impl Signature {
     }
 
     /// Kept temporarily for backwards compatibility.
-    #[deprecated = "Use `Signature::from_bytes` instead"]
+    #[deprecated = "Use `RecoverableSignature::from_bytes` instead"]
     pub fn from_bytes_unchecked(bytes: [u8; Self::LEN]) -> Self {
         Self::from_bytes(bytes)
     }
 }
 
-impl Deref for Signature {
-    type Target = [u8; Signature::LEN];
+impl Deref for RecoverableSignature {
+    type Target = [u8; RecoverableSignature::LEN];
 
-    fn deref(&self) -> &[u8; Signature::LEN] {
+    fn deref(&self) -> &[u8; RecoverableSignature::LEN] {
         self.0.deref()
     }
 }
 
-impl AsRef<[u8]> for Signature {
+impl AsRef<[u8]> for RecoverableSignature {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
     }
 }
 
-impl AsMut<[u8]> for Signature {
+impl AsMut<[u8]> for RecoverableSignature {
     fn as_mut(&mut self) -> &mut [u8] {
         self.0.as_mut()
     }
 }
 
-impl fmt::LowerHex for Signature {
+impl fmt::LowerHex for RecoverableSignature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl fmt::UpperHex for Signature {
+impl fmt::UpperHex for RecoverableSignature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl fmt::Debug for Signature {
+impl fmt::Debug for RecoverableSignature {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl fmt::Display for Signature {
+impl fmt::Display for RecoverableSignature {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl From<Signature> for [u8; Signature::LEN] {
-    fn from(salt: Signature) -> [u8; Signature::LEN] {
+impl From<RecoverableSignature> for [u8; RecoverableSignature::LEN] {
+    fn from(salt: RecoverableSignature) -> [u8; RecoverableSignature::LEN] {
         salt.0.into()
     }
 }
 
-impl From<Signature> for Bytes64 {
-    fn from(s: Signature) -> Self {
+impl From<RecoverableSignature> for Bytes64 {
+    fn from(s: RecoverableSignature) -> Self {
         s.0
     }
 }
 
-impl str::FromStr for Signature {
+impl str::FromStr for RecoverableSignature {
     type Err = Error;
 
-    /// Parse a `Signature` directly from its bytes encoded as hex in a string.
+    /// Parse a `RecoverableSignature` directly from its bytes encoded as hex in a
+    /// string.
     ///
     /// This constructor does not perform any signing.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -121,7 +137,7 @@ impl str::FromStr for Signature {
 }
 
 /// Secp256k1 methods
-impl Signature {
+impl RecoverableSignature {
     /// Produce secp256k1 signature
     pub fn sign(secret: &SecretKey, message: &Message) -> Self {
         Self(Bytes64::from(k1::sign(secret, message)))
@@ -136,4 +152,96 @@ impl Signature {
     pub fn verify(&self, public_key: &PublicKey, message: &Message) -> Result<(), Error> {
         k1::verify(*self.0, **public_key, message)
     }
+
+    /// Drop the recovery id, keeping the verifiable `(r, s)` signature.
+    pub fn to_compact(&self) -> CompactSignature {
+        let (signature, _) = decode_signature(*self.0);
+        CompactSignature(signature.into())
+    }
+}
+
+/// Secp256k1 signature without the recovery id, i.e. the `(r, s)` pair with the
+/// normalized `s`, as produced by most of the secp256k1 libraries.
+///
+/// The signature is only verified against a known public key. Convert it with
+/// [`CompactSignature::into_recoverable`] where the public key must be recovered,
+/// e.g. for the witnesses of the Fuel transactions.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct CompactSignature(Bytes64);
+
+bytes_wrapper!(CompactSignature, Bytes64);
+
+impl CompactSignature {
+    /// Construct a `CompactSignature` directly from its bytes.
+    ///
+    /// This constructor expects the given bytes to be a valid signature. No signing is
+    /// performed.
+    pub fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Produce secp256k1 signature
+    pub fn sign(secret: &SecretKey, message: &Message) -> Self {
+        RecoverableSignature::sign(secret, message).to_compact()
+    }
+
+    /// Verify that a signature matches given public key
+    pub fn verify(&self, public_key: &PublicKey, message: &Message) -> Result<(), Error> {
+        // The highest bit of a normalized `s` is never set, and would be taken for the
+        // recovery id
+        if self.0[32] & 0x80 != 0 {
+            return Err(Error::InvalidSignature)
+        }
+        k1::verify(*self.0, **public_key, message)
+    }
+
+    /// Add the recovery id of the `public_key` which signed the `message`. Fails if
+    /// the signature doesn't match the public key.
+    pub fn into_recoverable(
+        self,
+        public_key: &PublicKey,
+        message: &Message,
+    ) -> Result<RecoverableSignature, Error> {
+        self.verify(public_key, message)?;
+        [false, true]
+            .into_iter()
+            .map(|is_y_odd| {
+                RecoverableSignature(
+                    encode_signature(*self.0, RecoveryId { is_y_odd }).into(),
+                )
+            })
+            .find(|signature| signature.recover(message).as_ref() == Ok(public_key))
+            .ok_or(Error::InvalidSignature)
+    }
+}
+
+impl From<RecoverableSignature> for CompactSignature {
+    fn from(signature: RecoverableSignature) -> Self {
+        signature.to_compact()
+    }
+}
+
+impl TryFrom<&[u8]> for CompactSignature {
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bytes64::try_from(slice)
+            .map(Self)
+            .map_err(|_| Error::InvalidSignature)
+    }
+}
+
+impl str::FromStr for CompactSignature {
+    type Err = Error;
+
+    /// Parse a `CompactSignature` directly from its bytes encoded as hex in a string.
+    ///
+    /// This constructor does not perform any signing.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bytes64::from_str(s)
+            .map_err(|_| Error::InvalidSignature)
+            .map(Self)
+    }
 }
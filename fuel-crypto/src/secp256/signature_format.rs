@@ -7,7 +7,7 @@
 /// and rejects reduced-x recovery ids.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct RecoveryId {
-    is_y_odd: bool,
+    pub(crate) is_y_odd: bool,
 }
 
 impl From<RecoveryId> for k256::ecdsa::RecoveryId {
@@ -0,0 +1,159 @@
+use crate::{
+    bls::{
+        PublicKey,
+        SecretKey,
+        Signature,
+    },
+    Error,
+    Message,
+};
+
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+use core::str::FromStr;
+
+fn keys(rng: &mut StdRng, count: usize) -> (Vec<SecretKey>, Vec<PublicKey>) {
+    let secrets: Vec<_> = (0..count).map(|_| SecretKey::random(rng)).collect();
+    let publics = secrets.iter().map(SecretKey::public_key).collect();
+    (secrets, publics)
+}
+
+#[test]
+fn verify() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let message = Message::new(b"The spirit of the age");
+
+    for _ in 0..10 {
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+
+        let signature = Signature::sign(&secret, &message);
+
+        signature
+            .verify(&public, &message)
+            .expect("Failed to verify signature");
+    }
+}
+
+#[test]
+fn verify_error_invalid_signature() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let message = Message::new(b"The spirit of the age");
+    let (secrets, publics) = keys(rng, 2);
+
+    let signature = Signature::sign(&secrets[0], &message);
+
+    assert_eq!(
+        signature.verify(&publics[1], &message),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        signature.verify(&publics[0], &Message::new(b"Another message")),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        Signature::from_bytes([1; Signature::LEN]).verify(&publics[0], &message),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn verify_aggregate() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let message = Message::new(b"Consensus");
+    let (secrets, publics) = keys(rng, 5);
+
+    let signatures: Vec<_> = secrets
+        .iter()
+        .map(|secret| Signature::sign(secret, &message))
+        .collect();
+    let aggregate = Signature::aggregate(&signatures).expect("Failed to aggregate");
+
+    aggregate
+        .verify_aggregate(&publics, &message)
+        .expect("Failed to verify the aggregate");
+    aggregate
+        .verify(&PublicKey::aggregate(&publics).unwrap(), &message)
+        .expect("Failed to verify with the aggregated public key");
+    assert_eq!(
+        aggregate.verify_aggregate(&publics[1..], &message),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn verify_aggregate_messages() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let (secrets, publics) = keys(rng, 3);
+    let messages: Vec<_> = (0u8..3).map(|i| Message::new([i])).collect();
+
+    let signatures: Vec<_> = secrets
+        .iter()
+        .zip(&messages)
+        .map(|(secret, message)| Signature::sign(secret, message))
+        .collect();
+    let aggregate = Signature::aggregate(&signatures).expect("Failed to aggregate");
+
+    aggregate
+        .verify_aggregate_messages(&publics, &messages)
+        .expect("Failed to verify the aggregate");
+    assert_eq!(
+        aggregate.verify_aggregate_messages(&publics, &messages[..2]),
+        Err(Error::InvalidMessage)
+    );
+    let mut swapped = messages.clone();
+    swapped.swap(0, 1);
+    assert_eq!(
+        aggregate.verify_aggregate_messages(&publics, &swapped),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn aggregate_error_no_signatures() {
+    assert_eq!(Signature::aggregate(&[]), Err(Error::InvalidSignature));
+    assert_eq!(PublicKey::aggregate(&[]), Err(Error::InvalidPublicKey));
+}
+
+#[test]
+fn proof_of_possession() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let (secrets, publics) = keys(rng, 2);
+
+    let proof = secrets[0].proof_of_possession();
+
+    publics[0]
+        .verify_proof_of_possession(&proof)
+        .expect("Failed to verify the proof of possession");
+    assert_eq!(
+        publics[1].verify_proof_of_possession(&proof),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn keys_from_bytes() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let public = secret.public_key();
+
+    assert_eq!(SecretKey::try_from(secret.as_ref()), Ok(secret));
+    assert_eq!(PublicKey::try_from(public.as_ref()), Ok(public));
+    assert_eq!(PublicKey::from_str(&public.to_string()), Ok(public));
+    assert_eq!(
+        PublicKey::try_from([0u8; PublicKey::LEN].as_ref()),
+        Err(Error::InvalidPublicKey)
+    );
+    assert_eq!(
+        SecretKey::try_from([0xffu8; SecretKey::LEN].as_ref()),
+        Err(Error::InvalidSecretKey)
+    );
+    assert_eq!(SecretKey::from_seed(&[0; 31]), Err(Error::InvalidSecretKey));
+    assert_eq!(
+        SecretKey::from_seed(&[7; 32]),
+        SecretKey::from_seed(&[7; 32])
+    );
+}
@@ -0,0 +1,109 @@
+use crate::{
+    ed25519::{
+        self,
+        PublicKey,
+        SecretKey,
+        Signature,
+    },
+    Error,
+};
+
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+use core::str::FromStr;
+
+#[test]
+fn verify() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let message = b"The gift of words is the gift of deception and illusion.";
+
+    for _ in 0..10 {
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+
+        let signature = Signature::sign(&secret, message);
+
+        signature
+            .verify(&public, message)
+            .expect("Failed to verify signature");
+        ed25519::verify(&public.into(), &signature.into(), message)
+            .expect("Failed to verify signature");
+    }
+}
+
+#[test]
+fn verify_error_invalid_signature() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let message = b"The gift of words is the gift of deception and illusion.";
+    let secret = SecretKey::random(rng);
+    let other = SecretKey::random(rng).public_key();
+
+    let signature = Signature::sign(&secret, message);
+
+    assert_eq!(
+        signature.verify(&other, message),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        signature.verify(&secret.public_key(), b"Another message"),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn sign_matches_dalek() {
+    use ed25519_dalek::Signer;
+
+    let secret = SecretKey::from_bytes([7; SecretKey::LEN]);
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+
+    assert_eq!(*secret.public_key(), signing_key.verifying_key().to_bytes());
+    assert_eq!(
+        *Signature::sign(&secret, b"message"),
+        signing_key.sign(b"message").to_bytes()
+    );
+}
+
+#[test]
+fn keys_from_bytes() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let public = secret.public_key();
+
+    assert_eq!(SecretKey::try_from(secret.as_ref()), Ok(secret.clone()));
+    assert_eq!(SecretKey::from_str(&secret.to_string()), Ok(secret));
+    assert_eq!(PublicKey::try_from(public.as_ref()), Ok(public));
+    assert_eq!(PublicKey::from_str(&public.to_string()), Ok(public));
+    assert_eq!(
+        PublicKey::try_from([0u8; 31].as_ref()),
+        Err(Error::InvalidPublicKey)
+    );
+}
+
+#[test]
+fn batch_verify_returns_the_invalid_signatures() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let messages: Vec<_> = (0..20u8).map(|i| [i]).collect();
+
+    let mut signatures: Vec<_> = messages
+        .iter()
+        .map(|message| {
+            let secret = SecretKey::random(rng);
+            (
+                Signature::sign(&secret, message),
+                secret.public_key(),
+                message.as_ref(),
+            )
+        })
+        .collect();
+
+    assert!(ed25519::batch_verify(signatures.clone()).is_empty());
+
+    signatures[0].2 = b"Another message";
+    signatures[19].0 = Signature::default();
+
+    assert_eq!(ed25519::batch_verify(signatures), [0, 19]);
+}
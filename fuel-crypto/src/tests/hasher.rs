@@ -48,3 +48,18 @@ fn digest() {
 
     assert_eq!(digest, d);
 }
+
+#[test]
+fn canonical_output_hashes_the_encoding() {
+    use fuel_types::{
+        canonical::Serialize,
+        Bytes32,
+    };
+
+    let data = vec![Bytes32::from([1u8; 32]), Bytes32::from([2u8; 32])];
+
+    let mut h = Hasher::default();
+    data.encode(&mut h).expect("Hashing is infallible");
+
+    assert_eq!(h.finalize(), Hasher::hash(data.to_bytes()));
+}
@@ -0,0 +1,97 @@
+use crate::{
+    hd::{
+        ExtendedPublicKey,
+        ExtendedSecretKey,
+        DEFAULT_DERIVATION_PATH,
+        HARDENED_INDEX,
+    },
+    Error,
+    SecretKey,
+};
+
+use core::str::FromStr;
+
+/// The test vector 1 of BIP-32.
+const SEED: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+    0x0e, 0x0f,
+];
+
+#[test]
+fn derive_path_matches_bip32_test_vector() {
+    let master = ExtendedSecretKey::from_seed(&SEED).unwrap();
+
+    assert_eq!(
+        master.to_string(),
+        "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"
+    );
+
+    let child = master.derive_path("m/0'/1").unwrap();
+
+    assert_eq!(
+        child.to_string(),
+        "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs"
+    );
+    assert_eq!(
+        child.extended_public_key().to_string(),
+        "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ"
+    );
+    assert_eq!(child.depth(), 2);
+    assert_eq!(child.child_number(), 1);
+    assert_eq!(
+        child.parent_fingerprint(),
+        master.derive_child(HARDENED_INDEX).unwrap().fingerprint()
+    );
+}
+
+#[test]
+fn extended_public_key_derives_the_public_keys_of_the_children() {
+    let account = ExtendedSecretKey::from_seed(&SEED)
+        .unwrap()
+        .derive_path("m/44'/1179993420'/0'")
+        .unwrap();
+
+    let public = account.extended_public_key().derive_path("m/0/7").unwrap();
+
+    assert_eq!(
+        public,
+        account.derive_path("m/0/7").unwrap().extended_public_key()
+    );
+    assert_eq!(
+        account
+            .extended_public_key()
+            .derive_child(HARDENED_INDEX)
+            .map(|key| key.public_key()),
+        Err(Error::Bip32Error)
+    );
+}
+
+#[test]
+fn from_mnemonic_phrase_matches_secret_key_from_mnemonic() {
+    let phrase =
+        "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+
+    let key = ExtendedSecretKey::from_mnemonic_phrase(phrase, None)
+        .unwrap()
+        .derive_path(DEFAULT_DERIVATION_PATH)
+        .unwrap();
+
+    assert_eq!(
+        key.secret_key(),
+        SecretKey::new_from_mnemonic_phrase_with_path(phrase, DEFAULT_DERIVATION_PATH)
+            .unwrap()
+    );
+}
+
+#[test]
+fn extended_keys_roundtrip_through_strings() {
+    let key = ExtendedSecretKey::from_seed(&SEED)
+        .unwrap()
+        .derive_path(DEFAULT_DERIVATION_PATH)
+        .unwrap();
+    let public = key.extended_public_key();
+
+    assert_eq!(ExtendedSecretKey::from_str(&key.to_string()), Ok(key));
+    assert_eq!(ExtendedPublicKey::from_str(&public.to_string()), Ok(public));
+    assert_eq!(ExtendedSecretKey::from_str("xprv"), Err(Error::Bip32Error));
+}
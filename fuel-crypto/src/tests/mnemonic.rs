@@ -57,3 +57,60 @@ fn random_mnemonic_phrase() {
         SecretKey::new_from_mnemonic_phrase_with_path(&phrase, "m/44'/60'/0'/0/0")
             .expect("failed to create secret key from mnemonic phrase");
 }
+
+#[test]
+fn mnemonic_derives_the_secret_keys() {
+    let phrase =
+        "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+
+    let mnemonic = crate::Mnemonic::from_str(phrase).expect("failed to parse mnemonic");
+
+    assert_eq!(mnemonic.phrase(), phrase);
+    assert_eq!(mnemonic.word_count(), 12);
+    assert_eq!(
+        mnemonic.secret_key("m/44'/60'/0'/0/0").unwrap(),
+        SecretKey::new_from_mnemonic_phrase_with_path(phrase, "m/44'/60'/0'/0/0")
+            .unwrap()
+    );
+    assert_eq!(
+        mnemonic.account_secret_key(0).unwrap(),
+        SecretKey::new_from_mnemonic_phrase(phrase).unwrap()
+    );
+    assert_ne!(
+        mnemonic.account_secret_key(1).unwrap(),
+        mnemonic.account_secret_key(0).unwrap()
+    );
+    assert_ne!(
+        mnemonic.to_seed(Some("password")).unwrap(),
+        mnemonic.to_seed(None).unwrap()
+    );
+}
+
+#[test]
+fn mnemonic_rejects_invalid_phrases() {
+    let wrong_checksum =
+        "oblige salon price punch saddle immune slogan rare snap desert retire retire";
+
+    assert_eq!(
+        crate::Mnemonic::from_phrase(wrong_checksum),
+        Err(crate::Error::InvalidMnemonic)
+    );
+    assert_eq!(
+        crate::Mnemonic::from_phrase("not a mnemonic"),
+        Err(crate::Error::InvalidMnemonic)
+    );
+}
+
+#[test]
+fn random_mnemonic() {
+    let mut rng = rand::thread_rng();
+
+    let mnemonic =
+        crate::Mnemonic::random(&mut rng, 24).expect("failed to generate mnemonic");
+
+    assert_eq!(mnemonic.word_count(), 24);
+    assert_eq!(
+        crate::Mnemonic::from_phrase(mnemonic.phrase()),
+        Ok(mnemonic)
+    );
+}
@@ -2,11 +2,28 @@
 use criterion as _;
 use k256 as _;
 
+#[cfg(all(feature = "bls", feature = "std"))]
+mod bls;
+
+#[cfg(feature = "std")]
+mod ed25519;
+
 mod hasher;
 
+#[cfg(feature = "std")]
+mod hd;
+
 #[cfg(feature = "std")]
 mod mnemonic;
 
+#[cfg(feature = "poseidon")]
+mod poseidon;
+
+#[cfg(feature = "std")]
+mod schnorr;
+
+mod secret;
+
 mod signature;
 
 #[cfg(feature = "serde")]
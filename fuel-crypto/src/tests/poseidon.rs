@@ -0,0 +1,93 @@
+use crate::{
+    poseidon::{
+        Bls12_381,
+        Bn254,
+        Field,
+        Poseidon,
+    },
+    Error,
+};
+use core::str::FromStr;
+use fuel_types::Bytes32;
+use std::collections::HashSet;
+
+fn element(n: u8) -> Bytes32 {
+    let mut bytes = Bytes32::zeroed();
+    bytes[31] = n;
+    bytes
+}
+
+fn hex(s: &str) -> Bytes32 {
+    Bytes32::from_str(s).unwrap()
+}
+
+#[test]
+fn hash_is_stable() {
+    let inputs = [element(1), element(2)];
+
+    let bn254 = Poseidon::<Bn254>::hash(&inputs).unwrap();
+    let bls12_381 = Poseidon::<Bls12_381>::hash(&inputs).unwrap();
+
+    assert_eq!(
+        bn254,
+        hex("074deaf2e1953ac63a1fc256f41748506563bacf3968c25736c27b49a591d218")
+    );
+    assert_eq!(
+        bls12_381,
+        hex("4975d99f9511f5a7dc5108db8e1949c6f136e0b4fdfe3e594c3af5315b2262da")
+    );
+}
+
+fn hash_distinguishes_the_lengths<F: Field>() {
+    let hashes = [
+        Poseidon::<F>::hash(&[]).unwrap(),
+        Poseidon::<F>::hash(&[element(0)]).unwrap(),
+        Poseidon::<F>::hash(&[element(0), element(0)]).unwrap(),
+        Poseidon::<F>::hash(&[element(1)]).unwrap(),
+        Poseidon::<F>::hash(&[element(0), element(1)]).unwrap(),
+    ];
+
+    let unique: HashSet<_> = hashes.iter().collect();
+    assert_eq!(unique.len(), hashes.len());
+}
+
+#[test]
+fn hash_distinguishes_the_lengths_bn254() {
+    hash_distinguishes_the_lengths::<Bn254>();
+}
+
+#[test]
+fn hash_distinguishes_the_lengths_bls12_381() {
+    hash_distinguishes_the_lengths::<Bls12_381>();
+}
+
+#[test]
+fn input_matches_hash() {
+    let inputs: Vec<_> = (0..5).map(element).collect();
+    let mut hasher = Poseidon::<Bn254>::new();
+
+    for input in &inputs {
+        hasher.input(input).unwrap();
+    }
+
+    assert_eq!(hasher.finalize(), Poseidon::<Bn254>::hash(&inputs).unwrap());
+}
+
+#[test]
+fn input_rejects_the_elements_out_of_the_field() {
+    let bn254_modulus =
+        hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+    let bls12_381_modulus =
+        hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+
+    assert_eq!(
+        Poseidon::<Bn254>::new().input(&bn254_modulus),
+        Err(Error::InvalidFieldElement)
+    );
+    assert_eq!(
+        Poseidon::<Bls12_381>::new().input(&bls12_381_modulus),
+        Err(Error::InvalidFieldElement)
+    );
+    // The modulus of BN254 is lower than the one of BLS12-381
+    assert!(Poseidon::<Bls12_381>::new().input(&bn254_modulus).is_ok());
+}
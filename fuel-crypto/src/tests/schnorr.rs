@@ -0,0 +1,101 @@
+use crate::{
+    schnorr::{
+        self,
+        Signature,
+        XOnlyPublicKey,
+    },
+    Error,
+    Message,
+    SecretKey,
+};
+use core::str::FromStr;
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+fn message(hex: &str) -> Message {
+    Message::from_bytes(fuel_types::Bytes32::from_str(hex).unwrap().into())
+}
+
+// The vectors 0 and 1 of BIP-340
+#[test]
+fn sign_matches_the_bip340_test_vectors() {
+    let vectors = [
+        (
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            [0; 32],
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+        ),
+        (
+            "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            {
+                let mut aux = [0; 32];
+                aux[31] = 1;
+                aux
+            },
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+        ),
+    ];
+
+    for (secret, public_key, aux_rand, msg, signature) in vectors {
+        let secret = SecretKey::from_str(secret).unwrap();
+        let public_key = XOnlyPublicKey::from_str(public_key).unwrap();
+        let message = message(msg);
+        let expected = Signature::from_str(signature).unwrap();
+
+        assert_eq!(XOnlyPublicKey::from_secret(&secret), public_key);
+        assert_eq!(
+            Signature::sign_with_aux_rand(&secret, &message, &aux_rand),
+            expected
+        );
+        assert_eq!(expected.verify(&public_key, &message), Ok(()));
+    }
+}
+
+#[test]
+fn verify_rejects_the_tampered_signatures() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let public_key = XOnlyPublicKey::from_secret(&secret);
+    let message = Message::new(b"Verified with the x-only public key");
+
+    let signature = Signature::sign(&secret, &message);
+    assert_eq!(
+        schnorr::verify(&(*public_key).into(), &(*signature).into(), &message),
+        Ok(())
+    );
+
+    let mut tampered = *signature;
+    tampered[40] ^= 1;
+    assert_eq!(
+        Signature::from_bytes(tampered).verify(&public_key, &message),
+        Err(Error::InvalidSignature)
+    );
+
+    let other = XOnlyPublicKey::from_secret(&SecretKey::random(rng));
+    assert_eq!(
+        signature.verify(&other, &Message::new(b"Another message")),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        signature.verify(&other, &message),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn x_only_public_key_rejects_the_points_out_of_the_curve() {
+    // `x = 5` isn't on the curve
+    let mut x = [0; 32];
+    x[31] = 5;
+
+    assert_eq!(
+        XOnlyPublicKey::try_from(&x[..]),
+        Err(Error::InvalidPublicKey)
+    );
+}
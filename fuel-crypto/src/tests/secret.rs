@@ -0,0 +1,32 @@
+use crate::SecretKey;
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+#[test]
+fn secret_key_equality_matches_the_bytes() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let a = SecretKey::random(rng);
+    let b = SecretKey::random(rng);
+
+    assert_eq!(a, SecretKey::try_from(a.as_ref()).unwrap());
+    assert_ne!(a, b);
+}
+
+#[test]
+fn secret_key_ordering_matches_the_bytes() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut secrets: Vec<SecretKey> = (0..64).map(|_| SecretKey::random(rng)).collect();
+
+    // Keys sharing a prefix are ordered by their first different byte
+    let mut bytes = *secrets[0];
+    bytes[31] ^= 1;
+    secrets.push(SecretKey::try_from(bytes.as_ref()).unwrap());
+
+    for a in &secrets {
+        for b in &secrets {
+            assert_eq!(a.cmp(b), a.as_ref().cmp(b.as_ref()));
+        }
+    }
+}
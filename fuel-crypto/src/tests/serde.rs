@@ -40,3 +40,30 @@ fn serde() {
 
     assert_eq!(signature, signature_p);
 }
+
+#[test]
+fn serde_ed25519() {
+    use crate::ed25519;
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let secret = ed25519::SecretKey::random(rng);
+    let secret_p = bincode::serialize(&secret).expect("Failed to serialize secret");
+    let secret_p = bincode::deserialize(&secret_p).expect("Failed to deserialize secret");
+
+    assert_eq!(secret, secret_p);
+
+    let public = secret.public_key();
+    let public_p = bincode::serialize(&public).expect("Failed to serialize public");
+    let public_p = bincode::deserialize(&public_p).expect("Failed to deserialize public");
+
+    assert_eq!(public, public_p);
+
+    let signature = ed25519::Signature::sign(&secret, b"Mehr Licht!");
+    let signature_p =
+        bincode::serialize(&signature).expect("Failed to serialize signature");
+    let signature_p =
+        bincode::deserialize(&signature_p).expect("Failed to deserialize signature");
+
+    assert_eq!(signature, signature_p);
+}
@@ -1,4 +1,5 @@
 use crate::{
+    CompactSignature,
     Error,
     Message,
     PublicKey,
@@ -112,3 +113,83 @@ fn corrupted_signature() {
         });
     });
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn batch_verify_returns_the_invalid_signatures() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let mut signatures: Vec<_> = (0..20u8)
+        .map(|i| {
+            let secret = SecretKey::random(rng);
+            let message = Message::new([i]);
+            (
+                Signature::sign(&secret, &message),
+                secret.public_key(),
+                message,
+            )
+        })
+        .collect();
+
+    assert!(crate::batch_verify(signatures.clone()).is_empty());
+
+    signatures[3].2 = Message::new(b"Another message");
+    signatures[17].1 = SecretKey::random(rng).public_key();
+
+    assert_eq!(crate::batch_verify(signatures), [3, 17]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_signature_round_trips_through_the_recoverable_signature() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for i in 0..50u8 {
+        let message = Message::new([i]);
+        let secret = SecretKey::random(rng);
+        let public = secret.public_key();
+
+        let signature = Signature::sign(&secret, &message);
+        let compact = CompactSignature::from(signature);
+
+        assert_eq!(compact, CompactSignature::sign(&secret, &message));
+        compact
+            .verify(&public, &message)
+            .expect("Failed to verify compact signature");
+        assert_eq!(compact.into_recoverable(&public, &message), Ok(signature));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_signature_rejects_the_recovery_id() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let message = Message::new(b"The recovery id is set on half of the signatures");
+
+    let (secret, signature) = core::iter::repeat_with(|| SecretKey::random(rng))
+        .map(|secret| (secret.clone(), Signature::sign(&secret, &message)))
+        .find(|(_, signature)| signature[32] & 0x80 != 0)
+        .unwrap();
+    let compact = CompactSignature::from_bytes(*signature);
+
+    assert_eq!(
+        compact.verify(&secret.public_key(), &message),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_signature_into_recoverable_fails_for_another_key() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let message = Message::new(b"Signed by one key");
+    let secret = SecretKey::random(rng);
+    let other = SecretKey::random(rng).public_key();
+
+    let compact = CompactSignature::sign(&secret, &message);
+
+    assert_eq!(
+        compact.into_recoverable(&other, &message),
+        Err(Error::InvalidSignature)
+    );
+}
@@ -2,10 +2,12 @@ mod hash;
 mod merkle_tree;
 mod node;
 mod primitive;
+mod range_proof;
 mod verify;
 
+pub(crate) use hash::empty_sum;
+#[cfg(test)]
 pub(crate) use hash::{
-    empty_sum,
     leaf_sum,
     node_sum,
 };
@@ -19,4 +21,11 @@ pub use primitive::Primitive;
 pub mod in_memory;
 pub mod root_calculator;
 
-pub use verify::verify;
+pub use range_proof::{
+    verify_range,
+    verify_range_with_hasher,
+};
+pub use verify::{
+    verify,
+    verify_with_hasher,
+};
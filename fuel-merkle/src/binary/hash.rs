@@ -1,13 +1,12 @@
 use crate::common::{
     empty_sum_sha256,
     Bytes32,
-    Prefix,
 };
-
-use digest::Digest;
-use sha2::Sha256;
-
-type Hash = Sha256;
+#[cfg(test)]
+use crate::common::{
+    MerkleHasher,
+    Sha256Hasher,
+};
 
 // Merkle Tree hash of an empty list
 // MTH({}) = Hash()
@@ -17,23 +16,14 @@ pub const fn empty_sum() -> &'static Bytes32 {
 
 // Merkle tree hash of an n-element list D[n]
 // MTH(D[n]) = Hash(0x01 || MTH(D[0:k]) || MTH(D[k:n])
+#[cfg(test)]
 pub fn node_sum(lhs_data: &Bytes32, rhs_data: &Bytes32) -> Bytes32 {
-    let mut hash = Hash::new();
-
-    hash.update(Prefix::Node);
-    hash.update(lhs_data);
-    hash.update(rhs_data);
-
-    hash.finalize().into()
+    Sha256Hasher::node_sum(lhs_data, rhs_data)
 }
 
 // Merkle tree hash of a list with one entry
 // MTH({d(0)}) = Hash(0x00 || d(0))
+#[cfg(test)]
 pub fn leaf_sum(data: &[u8]) -> Bytes32 {
-    let mut hash = Hash::new();
-
-    hash.update(Prefix::Leaf);
-    hash.update(data);
-
-    hash.finalize().into()
+    Sha256Hasher::leaf_sum(&[data])
 }
@@ -11,6 +11,8 @@ use crate::{
     storage::Mappable,
 };
 
+use core::ops::Range;
+
 /// The table of the Binary Merkle Tree's nodes. [`MerkleTree`] works with it as
 /// a binary array, where the storage key of the node is the `u64` index and
 /// value is the [`Node`](crate::binary::Node).
@@ -51,6 +53,17 @@ impl MerkleTree {
         self.tree.prove(proof_index).ok()
     }
 
+    pub fn prove_range(&self, range: Range<u64>) -> Option<(Bytes32, ProofSet)> {
+        self.tree.prove_range(range).ok()
+    }
+
+    /// Computes the root of the tree with the `leaves` on multiple threads, see
+    /// [`MerkleRootCalculator::root_from_leaves_parallel`](binary::root_calculator::MerkleRootCalculator::root_from_leaves_parallel).
+    #[cfg(feature = "parallel")]
+    pub fn root_from_leaves_parallel<T: AsRef<[u8]> + Sync>(leaves: &[T]) -> Bytes32 {
+        <binary::root_calculator::MerkleRootCalculator>::root_from_leaves_parallel(leaves)
+    }
+
     pub fn reset(&mut self) {
         self.tree.reset();
     }
@@ -7,8 +7,10 @@ use crate::{
     },
     common::{
         Bytes32,
+        MerkleHasher,
         Position,
         ProofSet,
+        Sha256Hasher,
         StorageMap,
     },
     storage::{
@@ -24,11 +26,18 @@ use alloc::vec::Vec;
 use core::{
     convert::Infallible,
     marker::PhantomData,
+    ops::Range,
 };
 
-use super::root_calculator::{
-    MerkleRootCalculator,
-    NodeStackPushError,
+use super::{
+    range_proof::{
+        proof_subtrees,
+        subtree_key,
+    },
+    root_calculator::{
+        MerkleRootCalculator,
+        NodeStackPushError,
+    },
 };
 
 #[derive(Debug, Clone, derive_more::Display, PartialEq, Eq)]
@@ -36,6 +45,9 @@ pub enum MerkleTreeError<StorageError> {
     #[display(fmt = "proof index {_0} is not valid")]
     InvalidProofIndex(u64),
 
+    #[display(fmt = "proof range {_0}..{_1} is not valid")]
+    InvalidProofRange(u64, u64),
+
     #[display(fmt = "cannot load node with key {_0}; the key is not found in storage")]
     LoadError(u64),
 
@@ -52,10 +64,11 @@ impl<StorageError> From<StorageError> for MerkleTreeError<StorageError> {
     }
 }
 
+/// A binary Merkle tree, hashed with `H`.
 #[derive(Debug, Clone)]
-pub struct MerkleTree<TableType, StorageType> {
+pub struct MerkleTree<TableType, StorageType, H = Sha256Hasher> {
     storage: StorageType,
-    nodes: MerkleRootCalculator,
+    nodes: MerkleRootCalculator<H>,
     leaves_count: u64,
     phantom_table: PhantomData<TableType>,
 }
@@ -64,14 +77,16 @@ impl<TableType, StorageType> MerkleTree<TableType, StorageType> {
     pub const fn empty_root() -> &'static Bytes32 {
         empty_sum()
     }
+}
 
+impl<TableType, StorageType, H: MerkleHasher> MerkleTree<TableType, StorageType, H> {
     pub fn root(&self) -> Bytes32 {
         let mut scratch_storage = StorageMap::<NodesTable>::new();
         let root_node = self
             .root_node::<Infallible>(&mut scratch_storage)
             .expect("The type doesn't allow constructing invalid trees.");
         match root_node {
-            None => *Self::empty_root(),
+            None => H::empty_sum(),
             Some(ref node) => *node.hash(),
         }
     }
@@ -112,7 +127,7 @@ impl<TableType, StorageType> MerkleTree<TableType, StorageType> {
                 .position()
                 .parent()
                 .map_err(|_| MerkleTreeError::TooLarge)?;
-            head = Node::create_node(parent, node, &head);
+            head = Node::create_node::<H>(parent, node, &head);
             StorageMutateInfallible::insert(
                 scratch_storage,
                 &head.key(),
@@ -130,12 +145,7 @@ where
     StorageType: StorageInspect<TableType, Error = StorageError>,
 {
     pub fn new(storage: StorageType) -> Self {
-        Self {
-            storage,
-            nodes: MerkleRootCalculator::new(),
-            leaves_count: 0,
-            phantom_table: Default::default(),
-        }
+        Self::new_with_hasher(storage)
     }
 
     /// A binary Merkle tree can be built from a collection of Merkle Mountain
@@ -220,6 +230,31 @@ where
     pub fn load(
         storage: StorageType,
         leaves_count: u64,
+    ) -> Result<Self, MerkleTreeError<StorageError>> {
+        Self::load_with_hasher(storage, leaves_count)
+    }
+}
+
+impl<TableType, StorageType, StorageError, H> MerkleTree<TableType, StorageType, H>
+where
+    TableType: Mappable<Key = u64, Value = Primitive, OwnedValue = Primitive>,
+    StorageType: StorageInspect<TableType, Error = StorageError>,
+    H: MerkleHasher,
+{
+    /// Creates an empty tree hashed with `H`.
+    pub fn new_with_hasher(storage: StorageType) -> Self {
+        Self {
+            storage,
+            nodes: MerkleRootCalculator::new_with_hasher(),
+            leaves_count: 0,
+            phantom_table: Default::default(),
+        }
+    }
+
+    /// Same as [`load`](MerkleTree::load), for the tree hashed with `H`.
+    pub fn load_with_hasher(
+        storage: StorageType,
+        leaves_count: u64,
     ) -> Result<Self, MerkleTreeError<StorageError>> {
         let mut nodes = Vec::new();
         let peaks = peak_positions(leaves_count).ok_or(MerkleTreeError::TooLarge)?;
@@ -235,7 +270,7 @@ where
 
         Ok(Self {
             storage,
-            nodes: MerkleRootCalculator::new_with_stack(nodes),
+            nodes: MerkleRootCalculator::from_stack(nodes),
             leaves_count,
             phantom_table: Default::default(),
         })
@@ -286,22 +321,54 @@ where
         Ok((root, proof_set))
     }
 
+    /// Proves the inclusion of the leaves in the `range`, verified with
+    /// [`verify_range`](crate::binary::verify_range). The proof set holds the roots
+    /// of the subtrees without any proven leaf, ordered from left to right.
+    pub fn prove_range(
+        &self,
+        range: Range<u64>,
+    ) -> Result<(Bytes32, ProofSet), MerkleTreeError<StorageError>> {
+        if range.start >= range.end || range.end > self.leaves_count {
+            return Err(MerkleTreeError::InvalidProofRange(range.start, range.end))
+        }
+
+        let mut scratch_storage = StorageMap::<NodesTable>::new();
+        let root_node = self
+            .root_node(&mut scratch_storage)?
+            .expect("Root node must be present, as leaves_count is nonzero");
+
+        let mut proof_set = ProofSet::new();
+        for subtree in proof_subtrees(&range, self.leaves_count) {
+            let key = subtree_key(&subtree).ok_or(MerkleTreeError::TooLarge)?;
+            let primitive = StorageInspectInfallible::get(&scratch_storage, &key)
+                .or(StorageInspect::get(&self.storage, &key)?)
+                .ok_or(MerkleTreeError::LoadError(key))?
+                .into_owned();
+            let node = Node::from(primitive);
+            proof_set.push(*node.hash());
+        }
+
+        let root = *root_node.hash();
+        Ok((root, proof_set))
+    }
+
     pub fn reset(&mut self) {
         self.nodes.clear();
     }
 }
 
-impl<TableType, StorageType, StorageError> MerkleTree<TableType, StorageType>
+impl<TableType, StorageType, StorageError, H> MerkleTree<TableType, StorageType, H>
 where
     TableType: Mappable<Key = u64, Value = Primitive, OwnedValue = Primitive>,
     StorageType: StorageMutate<TableType, Error = StorageError>,
+    H: MerkleHasher,
 {
     /// Adds a new leaf node to the tree.
     /// # WARNING
     /// This code might modify the storage, and then return an error.
     /// TODO: fix this issue
     pub fn push(&mut self, data: &[u8]) -> Result<(), MerkleTreeError<StorageError>> {
-        let new_node = Node::create_leaf(self.leaves_count, data)
+        let new_node = Node::create_leaf::<H>(self.leaves_count, data)
             .ok_or(MerkleTreeError::TooLarge)?;
 
         // u64 cannot overflow, as memory is finite
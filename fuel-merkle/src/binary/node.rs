@@ -1,12 +1,7 @@
-use crate::{
-    binary::{
-        leaf_sum,
-        node_sum,
-    },
-    common::{
-        Bytes32,
-        Position,
-    },
+use crate::common::{
+    Bytes32,
+    MerkleHasher,
+    Position,
 };
 
 use core::fmt::Debug;
@@ -23,19 +18,19 @@ impl Node {
     }
 
     /// Returns `None` if the leaf cannot be created due to incorrect position.
-    pub fn create_leaf(index: u64, data: &[u8]) -> Option<Self> {
+    pub fn create_leaf<H: MerkleHasher>(index: u64, data: &[u8]) -> Option<Self> {
         let position = Position::from_leaf_index(index)?;
-        let hash = leaf_sum(data);
+        let hash = H::leaf_sum(&[data]);
         Some(Self { position, hash })
     }
 
     /// Creates a new node with the given children.
-    pub fn create_node(
+    pub fn create_node<H: MerkleHasher>(
         position: Position,
         left_child: &Self,
         right_child: &Self,
     ) -> Self {
-        let hash = node_sum(left_child.hash(), right_child.hash());
+        let hash = H::node_sum(left_child.hash(), right_child.hash());
         Self { position, hash }
     }
 
@@ -0,0 +1,212 @@
+//! Proofs of the inclusion of a contiguous range of leaves.
+//!
+//! The tree splits a subtree of `n` leaves into a balanced left subtree holding the
+//! largest power of two of leaves below `n`, and a right subtree holding the rest.
+//! The proof of a range holds the roots of the maximal subtrees without any proven
+//! leaf, ordered from left to right. The verifier rebuilds the root from the proven
+//! leaves and these roots, so a range of `k` leaves is proven with at most
+//! `2 * log2(n)` hashes instead of `k * log2(n)` for the single-leaf proofs.
+
+use crate::common::{
+    Bytes32,
+    MerkleHasher,
+    ProofSet,
+    Sha256Hasher,
+};
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The number of leaves of the left subtree of a subtree with `size > 1` leaves.
+fn split(size: u64) -> u64 {
+    #[allow(clippy::arithmetic_side_effects)] // size > 1, ilog2(..) < 64
+    {
+        1 << (size - 1).ilog2()
+    }
+}
+
+/// The storage key, i.e. the in-order index, of the root of the subtree holding the
+/// `leaves`. Returns `None` if the tree is too large.
+pub(crate) fn subtree_key(leaves: &Range<u64>) -> Option<u64> {
+    let size = leaves.end.checked_sub(leaves.start)?;
+    leaves
+        .start
+        .checked_mul(2)?
+        .checked_add(size.checked_next_power_of_two()?)?
+        .checked_sub(1)
+}
+
+/// The subtrees whose roots prove the `range` of a tree with `leaves_count` leaves,
+/// ordered from left to right. The range must be within the leaves.
+pub(crate) fn proof_subtrees(range: &Range<u64>, leaves_count: u64) -> Vec<Range<u64>> {
+    fn walk(subtree: Range<u64>, range: &Range<u64>, subtrees: &mut Vec<Range<u64>>) {
+        if subtree.end <= range.start || subtree.start >= range.end {
+            subtrees.push(subtree);
+        } else if range.start > subtree.start || range.end < subtree.end {
+            #[allow(clippy::arithmetic_side_effects)] // the range splits the subtree
+            let middle = subtree.start + split(subtree.end - subtree.start);
+            walk(subtree.start..middle, range, subtrees);
+            walk(middle..subtree.end, range, subtrees);
+        }
+    }
+
+    let mut subtrees = Vec::new();
+    walk(0..leaves_count, range, &mut subtrees);
+    subtrees
+}
+
+/// Returns `true` if the `leaves`, starting at the leaf `start` of the tree with
+/// `num_leaves` leaves, are included into the tree with the `root`.
+pub fn verify_range<T: AsRef<[u8]>>(
+    root: &Bytes32,
+    leaves: &[T],
+    proof_set: &ProofSet,
+    start: u64,
+    num_leaves: u64,
+) -> bool {
+    verify_range_with_hasher::<Sha256Hasher, T>(
+        root, leaves, proof_set, start, num_leaves,
+    )
+}
+
+/// Same as [`verify_range`], for the trees hashed by `H`.
+pub fn verify_range_with_hasher<H: MerkleHasher, T: AsRef<[u8]>>(
+    root: &Bytes32,
+    leaves: &[T],
+    proof_set: &ProofSet,
+    start: u64,
+    num_leaves: u64,
+) -> bool {
+    fn subtree_root<'a, H: MerkleHasher>(
+        subtree: Range<u64>,
+        range: &Range<u64>,
+        leaves: &mut impl Iterator<Item = &'a [u8]>,
+        proof_set: &mut impl Iterator<Item = &'a Bytes32>,
+    ) -> Option<Bytes32> {
+        if subtree.end <= range.start || subtree.start >= range.end {
+            return proof_set.next().copied()
+        }
+        #[allow(clippy::arithmetic_side_effects)] // the subtree isn't empty
+        let size = subtree.end - subtree.start;
+        if size == 1 {
+            return Some(H::leaf_sum(&[leaves.next()?]))
+        }
+        #[allow(clippy::arithmetic_side_effects)] // split(size) < size
+        let middle = subtree.start + split(size);
+        let left = subtree_root::<H>(subtree.start..middle, range, leaves, proof_set)?;
+        let right = subtree_root::<H>(middle..subtree.end, range, leaves, proof_set)?;
+        Some(H::node_sum(&left, &right))
+    }
+
+    let Ok(len) = u64::try_from(leaves.len()) else {
+        return false
+    };
+    let Some(end) = start.checked_add(len) else {
+        return false
+    };
+    if len == 0 || end > num_leaves {
+        return false
+    }
+
+    let mut leaves = leaves.iter().map(AsRef::as_ref);
+    let mut proof_set = proof_set.iter();
+    let Some(computed) =
+        subtree_root::<H>(0..num_leaves, &(start..end), &mut leaves, &mut proof_set)
+    else {
+        return false
+    };
+
+    proof_set.next().is_none() && computed == *root
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binary::in_memory::MerkleTree;
+    use fuel_merkle_test_helpers::TEST_DATA;
+
+    fn tree(leaves_count: usize) -> MerkleTree {
+        let mut tree = MerkleTree::new();
+        for datum in &TEST_DATA[..leaves_count] {
+            tree.push(datum);
+        }
+        tree
+    }
+
+    #[test]
+    fn verify_range_accepts_the_proofs_of_all_the_ranges() {
+        for leaves_count in 1..=TEST_DATA.len() {
+            let tree = tree(leaves_count);
+            for start in 0..leaves_count {
+                for end in start + 1..=leaves_count {
+                    let (root, proof_set) =
+                        tree.prove_range(start as u64..end as u64).unwrap();
+
+                    assert_eq!(root, tree.root());
+                    assert!(verify_range(
+                        &root,
+                        &TEST_DATA[start..end],
+                        &proof_set,
+                        start as u64,
+                        leaves_count as u64,
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn verify_range_proof_is_smaller_than_the_single_leaf_proofs() {
+        let tree = tree(10);
+
+        let (_, proof_set) = tree.prove_range(1..9).unwrap();
+
+        assert_eq!(proof_set.len(), 2);
+    }
+
+    #[test]
+    fn verify_range_rejects_the_tampered_leaves() {
+        let tree = tree(7);
+        let (root, proof_set) = tree.prove_range(2..5).unwrap();
+        let mut leaves = TEST_DATA[2..5].to_vec();
+        leaves[1] = TEST_DATA[9];
+
+        assert!(!verify_range(&root, &leaves, &proof_set, 2, 7));
+    }
+
+    #[test]
+    fn verify_range_rejects_the_shifted_range() {
+        let tree = tree(7);
+        let (root, proof_set) = tree.prove_range(2..5).unwrap();
+
+        assert!(!verify_range(&root, &TEST_DATA[2..5], &proof_set, 3, 7));
+        assert!(!verify_range(&root, &TEST_DATA[2..4], &proof_set, 2, 7));
+    }
+
+    #[test]
+    fn verify_range_rejects_the_extra_proof_nodes() {
+        let tree = tree(7);
+        let (root, mut proof_set) = tree.prove_range(2..5).unwrap();
+        proof_set.push(root);
+
+        assert!(!verify_range(&root, &TEST_DATA[2..5], &proof_set, 2, 7));
+    }
+
+    #[test]
+    fn verify_range_rejects_the_empty_and_out_of_bounds_ranges() {
+        let tree = tree(7);
+        let (root, proof_set) = tree.prove_range(5..7).unwrap();
+
+        assert!(!verify_range::<&[u8]>(&root, &[], &proof_set, 5, 7));
+        assert!(!verify_range(&root, &TEST_DATA[5..7], &proof_set, 6, 7));
+    }
+
+    #[test]
+    fn prove_range_returns_none_for_the_invalid_ranges() {
+        let tree = tree(7);
+
+        assert!(tree.prove_range(3..3).is_none());
+        assert!(tree.prove_range(5..8).is_none());
+        assert!(MerkleTree::new().prove_range(0..1).is_none());
+    }
+}
@@ -1,11 +1,15 @@
-use core::convert::Infallible;
+use core::{
+    convert::Infallible,
+    marker::PhantomData,
+};
 
 use crate::{
-    binary::{
-        empty_sum,
-        Node,
+    binary::Node,
+    common::{
+        Bytes32,
+        MerkleHasher,
+        Sha256Hasher,
     },
-    common::Bytes32,
 };
 
 use crate::alloc::borrow::ToOwned;
@@ -19,17 +23,34 @@ pub(crate) enum NodeStackPushError<E> {
 
 #[derive(Default, Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct MerkleRootCalculator {
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct MerkleRootCalculator<H = Sha256Hasher> {
     stack: Vec<Node>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    phantom_hasher: PhantomData<H>,
 }
 
 impl MerkleRootCalculator {
     pub fn new() -> Self {
-        Self { stack: Vec::new() }
+        Self::new_with_hasher()
     }
 
     pub fn new_with_stack(stack: Vec<Node>) -> Self {
-        Self { stack }
+        Self::from_stack(stack)
+    }
+}
+
+impl<H: MerkleHasher> MerkleRootCalculator<H> {
+    /// Creates a calculator hashing the tree with `H`.
+    pub fn new_with_hasher() -> Self {
+        Self::from_stack(Vec::new())
+    }
+
+    pub(crate) fn from_stack(stack: Vec<Node>) -> Self {
+        Self {
+            stack,
+            phantom_hasher: PhantomData,
+        }
     }
 
     pub fn clear(&mut self) {
@@ -62,7 +83,7 @@ impl MerkleRootCalculator {
                 .position()
                 .parent()
                 .map_err(|_| NodeStackPushError::TooLarge)?;
-            let new = Node::create_node(parent_pos, lhs, rhs);
+            let new = Node::create_node::<H>(parent_pos, lhs, rhs);
             node_created(&new).map_err(NodeStackPushError::Callback)?;
             let _ = self.stack.pop();
             let _ = self.stack.pop();
@@ -76,14 +97,15 @@ impl MerkleRootCalculator {
     /// Panics if the tree would be too large to compute the root for.
     /// In practice this never occurs, as you'd run out of memory first.
     pub fn push(&mut self, data: &[u8]) {
-        let node = Node::create_leaf(0, data).expect("Zero is a valid index for a leaf");
+        let node =
+            Node::create_leaf::<H>(0, data).expect("Zero is a valid index for a leaf");
         self.push_with_callback::<_, Infallible>(node, |_| Ok(()))
             .expect("Tree too large");
     }
 
     pub fn root(mut self) -> Bytes32 {
         if self.stack.is_empty() {
-            return empty_sum().to_owned()
+            return H::empty_sum()
         }
         while self.stack.len() > 1 {
             let right_child = self.stack.pop().expect("Checked in loop bound");
@@ -92,7 +114,8 @@ impl MerkleRootCalculator {
                 .position()
                 .parent()
                 .expect("Left child has no parent");
-            let merged_node = Node::create_node(merged_pos, &left_child, &right_child);
+            let merged_node =
+                Node::create_node::<H>(merged_pos, &left_child, &right_child);
             self.stack.push(merged_node);
         }
         self.stack.pop().unwrap().hash().to_owned()
@@ -102,7 +125,7 @@ impl MerkleRootCalculator {
         self,
         iterator: I,
     ) -> Bytes32 {
-        let mut calculator = MerkleRootCalculator::new();
+        let mut calculator = Self::new_with_hasher();
 
         for data in iterator {
             calculator.push(data.as_ref());
@@ -114,6 +137,137 @@ impl MerkleRootCalculator {
     pub fn stack(&self) -> &Vec<Node> {
         &self.stack
     }
+
+    /// Computes the root of the tree with the `leaves`, hashing the leaves and
+    /// building the subtrees on the threads of the rayon pool.
+    #[cfg(feature = "parallel")]
+    pub fn root_from_leaves_parallel<T: AsRef<[u8]> + Sync>(leaves: &[T]) -> Bytes32 {
+        /// Below this number of leaves, a subtree is built on a single thread.
+        const SEQUENTIAL_LEAVES: usize = 1024;
+
+        fn subtree_root<H: MerkleHasher, T: AsRef<[u8]> + Sync>(leaves: &[T]) -> Bytes32 {
+            if leaves.len() <= SEQUENTIAL_LEAVES {
+                let mut calculator = MerkleRootCalculator::<H>::new_with_hasher();
+                for leaf in leaves {
+                    calculator.push(leaf.as_ref());
+                }
+                return calculator.root()
+            }
+            // The left subtree holds the largest power of two of the leaves below
+            // their number.
+            #[allow(clippy::arithmetic_side_effects)] // len > 1
+            let middle = 1 << (leaves.len() - 1).ilog2();
+            let (left, right) = leaves.split_at(middle);
+            let (left, right) = rayon::join(
+                || subtree_root::<H, T>(left),
+                || subtree_root::<H, T>(right),
+            );
+            H::node_sum(&left, &right)
+        }
+
+        subtree_root::<H, T>(leaves)
+    }
+}
+
+impl<H: MerkleHasher, T: AsRef<[u8]>> Extend<T> for MerkleRootCalculator<H> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, leaves: I) {
+        for leaf in leaves {
+            self.push(leaf.as_ref());
+        }
+    }
+}
+
+/// Computes the root of the tree with the leaves cut from a stream of bytes, e.g. a
+/// large bytecode, without materializing the leaves.
+///
+/// The bytes are split into leaves of `leaf_size` bytes, and only the last leaf may
+/// be shorter. The memory used is the buffer of one leaf and the `O(log n)` stack of
+/// the [`MerkleRootCalculator`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkedRootCalculator<H = Sha256Hasher> {
+    leaf_size: usize,
+    buffer: Vec<u8>,
+    calculator: MerkleRootCalculator<H>,
+}
+
+impl ChunkedRootCalculator {
+    /// Creates a calculator with leaves of `leaf_size` bytes.
+    /// Panics if `leaf_size` is zero.
+    pub fn new(leaf_size: usize) -> Self {
+        Self::new_with_hasher(leaf_size)
+    }
+}
+
+impl<H: MerkleHasher> ChunkedRootCalculator<H> {
+    /// Creates a calculator with leaves of `leaf_size` bytes, hashing the tree with
+    /// `H`.
+    /// Panics if `leaf_size` is zero.
+    pub fn new_with_hasher(leaf_size: usize) -> Self {
+        assert!(leaf_size > 0, "The leaf size must not be zero");
+        Self {
+            leaf_size,
+            buffer: Vec::with_capacity(leaf_size),
+            calculator: MerkleRootCalculator::new_with_hasher(),
+        }
+    }
+
+    /// The size of the leaves in bytes.
+    pub fn leaf_size(&self) -> usize {
+        self.leaf_size
+    }
+
+    /// Appends the `bytes` to the stream, pushing the completed leaves to the tree.
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        if !self.buffer.is_empty() {
+            let missing = self.leaf_size.saturating_sub(self.buffer.len());
+            let (head, tail) = bytes.split_at(missing.min(bytes.len()));
+            self.buffer.extend_from_slice(head);
+            bytes = tail;
+            if self.buffer.len() < self.leaf_size {
+                return
+            }
+            self.calculator.push(&self.buffer);
+            self.buffer.clear();
+        }
+
+        let mut leaves = bytes.chunks_exact(self.leaf_size);
+        for leaf in leaves.by_ref() {
+            self.calculator.push(leaf);
+        }
+        self.buffer.extend_from_slice(leaves.remainder());
+    }
+
+    /// Computes the root, with the bytes left in the buffer as the last leaf.
+    pub fn root(mut self) -> Bytes32 {
+        if !self.buffer.is_empty() {
+            self.calculator.push(&self.buffer);
+        }
+        self.calculator.root()
+    }
+
+    /// Same as [`Self::root`], with the last leaf, if shorter than `leaf_size`,
+    /// padded by the `padding_byte` to a multiple of `multiple` bytes.
+    /// Panics if `multiple` is zero.
+    pub fn root_padded(mut self, multiple: usize, padding_byte: u8) -> Bytes32 {
+        let len = self.buffer.len();
+        if len > 0 {
+            let padded_len = len.next_multiple_of(multiple).min(self.leaf_size);
+            self.buffer.resize(padded_len, padding_byte);
+        }
+        self.root()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: MerkleHasher> std::io::Write for ChunkedRootCalculator<H> {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.update(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -204,4 +358,93 @@ mod test {
 
         assert_eq!(calculator.root(), deserialized_calculator.root());
     }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn root_from_leaves_parallel_returns_the_merkle_root() {
+        for leaves_count in [0, 1, 7, 1024, 1025, 5000] {
+            let leaves = (0..leaves_count as u64)
+                .map(u64::to_le_bytes)
+                .collect::<Vec<_>>();
+            let mut tree = MerkleTree::new();
+            for leaf in &leaves {
+                tree.push(leaf);
+            }
+
+            let root = <MerkleRootCalculator>::root_from_leaves_parallel(&leaves);
+
+            assert_eq!(root, tree.root());
+        }
+    }
+
+    #[test]
+    fn extend_pushes_the_leaves() {
+        let mut tree = MerkleTree::new();
+        let mut calculator = MerkleRootCalculator::new();
+
+        let data = &TEST_DATA[0..7];
+        for datum in data.iter() {
+            tree.push(datum);
+        }
+        calculator.extend(data.iter());
+
+        assert_eq!(tree.root(), calculator.root());
+    }
+
+    #[test]
+    fn chunked_root_matches_the_root_of_the_chunks() {
+        let bytes = (0..=u8::MAX).cycle().take(1000).collect::<Vec<_>>();
+
+        for leaf_size in [1, 7, 64, 1000, 2048] {
+            let mut tree = MerkleTree::new();
+            for leaf in bytes.chunks(leaf_size) {
+                tree.push(leaf);
+            }
+
+            for update_size in [1, 3, 64, 999, 1000] {
+                let mut calculator = ChunkedRootCalculator::new(leaf_size);
+                for update in bytes.chunks(update_size) {
+                    calculator.update(update);
+                }
+
+                assert_eq!(calculator.root(), tree.root());
+            }
+        }
+    }
+
+    #[test]
+    fn chunked_root_returns_the_empty_root_for_no_bytes() {
+        let mut calculator = ChunkedRootCalculator::new(16);
+        calculator.update(&[]);
+
+        assert_eq!(calculator.root(), MerkleTree::new().root());
+    }
+
+    #[test]
+    fn chunked_root_padded_pads_the_last_leaf() {
+        let bytes = [1u8; 21];
+        let mut tree = MerkleTree::new();
+        tree.push(&bytes[..16]);
+        tree.push(&[1, 1, 1, 1, 1, 0, 0, 0]);
+
+        let mut calculator = ChunkedRootCalculator::new(16);
+        calculator.update(&bytes);
+
+        assert_eq!(calculator.root_padded(8, 0), tree.root());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn chunked_root_can_be_written_to() {
+        let bytes = [7u8; 100];
+        let mut tree = MerkleTree::new();
+        for leaf in bytes.chunks(32) {
+            tree.push(leaf);
+        }
+
+        let mut calculator = ChunkedRootCalculator::new(32);
+        std::io::copy(&mut &bytes[..], &mut calculator).unwrap();
+
+        assert_eq!(calculator.root(), tree.root());
+    }
 }
@@ -1,12 +1,8 @@
-use crate::{
-    binary::{
-        leaf_sum,
-        node_sum,
-    },
-    common::{
-        Bytes32,
-        ProofSet,
-    },
+use crate::common::{
+    Bytes32,
+    MerkleHasher,
+    ProofSet,
+    Sha256Hasher,
 };
 
 /// Returns None if:
@@ -49,6 +45,17 @@ pub fn verify<T: AsRef<[u8]>>(
     proof_set: &ProofSet,
     proof_index: u64,
     num_leaves: u64,
+) -> bool {
+    verify_with_hasher::<Sha256Hasher, T>(root, data, proof_set, proof_index, num_leaves)
+}
+
+/// Same as [`verify`], for the trees hashed by `H`.
+pub fn verify_with_hasher<H: MerkleHasher, T: AsRef<[u8]>>(
+    root: &Bytes32,
+    data: &T,
+    proof_set: &ProofSet,
+    proof_index: u64,
+    num_leaves: u64,
 ) -> bool {
     if num_leaves <= 1 {
         if !proof_set.is_empty() {
@@ -62,7 +69,7 @@ pub fn verify<T: AsRef<[u8]>>(
         return false;
     }
 
-    let mut sum = leaf_sum(data.as_ref());
+    let mut sum = H::leaf_sum(&[data.as_ref()]);
     if proof_set.is_empty() {
         return if num_leaves == 1 { *root == sum } else { false }
     }
@@ -95,9 +102,9 @@ pub fn verify<T: AsRef<[u8]>>(
         let proof_data = proof_set[parent];
         #[allow(clippy::arithmetic_side_effects)] // proof_index > subtree_start_index
         if proof_index - subtree_start_index < (1 << parent) {
-            sum = node_sum(&sum, &proof_data);
+            sum = H::node_sum(&sum, &proof_data);
         } else {
-            sum = node_sum(&proof_data, &sum);
+            sum = H::node_sum(&proof_data, &sum);
         }
 
         #[allow(clippy::arithmetic_side_effects)] // path_length_from_key checks
@@ -111,7 +118,7 @@ pub fn verify<T: AsRef<[u8]>>(
             return false
         }
         let proof_data = proof_set[parent];
-        sum = node_sum(&sum, &proof_data);
+        sum = H::node_sum(&sum, &proof_data);
         #[allow(clippy::arithmetic_side_effects)] // path_length_from_key checks
         {
             parent += 1;
@@ -120,7 +127,7 @@ pub fn verify<T: AsRef<[u8]>>(
 
     while parent < proof_set.len() {
         let proof_data = proof_set[parent];
-        sum = node_sum(&proof_data, &sum);
+        sum = H::node_sum(&proof_data, &sum);
         #[allow(clippy::arithmetic_side_effects)] // path_length_from_key checks
         {
             parent += 1;
@@ -34,6 +34,8 @@ pub type ProofSet = Vec<Bytes32>;
 pub use hash::{
     sum,
     sum_iter,
+    MerkleHasher,
+    Sha256Hasher,
 };
 
 // Merkle Tree hash of an empty list
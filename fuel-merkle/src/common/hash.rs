@@ -1,4 +1,7 @@
-use super::Bytes32;
+use super::{
+    Bytes32,
+    Prefix,
+};
 
 pub fn sum<T: AsRef<[u8]>>(data: T) -> Bytes32 {
     use digest::Digest;
@@ -15,3 +18,57 @@ pub fn sum_iter<I: IntoIterator<Item = T>, T: AsRef<[u8]>>(iterator: I) -> Bytes
     }
     hash.finalize().into()
 }
+
+/// The hash function of the Merkle trees.
+///
+/// The trees only hash through the [`leaf_sum`](MerkleHasher::leaf_sum) and the
+/// [`node_sum`](MerkleHasher::node_sum) hooks. By default, they domain separate the
+/// leaves and the nodes by prefixing the hashed data with `0x00` and `0x01`
+/// respectively, and can be overridden by the hash functions that separate the
+/// domains differently, e.g. over field elements.
+pub trait MerkleHasher {
+    /// Hashes the concatenation of the `data`.
+    fn hash_iter<'a, I>(data: I) -> Bytes32
+    where
+        I: IntoIterator<Item = &'a [u8]>;
+
+    /// Hashes the `data`.
+    fn hash(data: &[u8]) -> Bytes32 {
+        Self::hash_iter([data])
+    }
+
+    /// The root of the empty binary Merkle tree.
+    fn empty_sum() -> Bytes32 {
+        Self::hash_iter([])
+    }
+
+    /// Hashes a leaf made of the concatenation of the `data`.
+    fn leaf_sum(data: &[&[u8]]) -> Bytes32 {
+        let prefix: [u8; 1] = Prefix::Leaf.into();
+        Self::hash_iter(core::iter::once(&prefix[..]).chain(data.iter().copied()))
+    }
+
+    /// Hashes a node from the hashes of its children.
+    fn node_sum(lhs: &Bytes32, rhs: &Bytes32) -> Bytes32 {
+        let prefix: [u8; 1] = Prefix::Node.into();
+        Self::hash_iter([&prefix[..], lhs, rhs])
+    }
+}
+
+/// The SHA-256 [`MerkleHasher`], used by the trees by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sha256Hasher;
+
+impl MerkleHasher for Sha256Hasher {
+    fn hash_iter<'a, I>(data: I) -> Bytes32
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        sum_iter(data)
+    }
+
+    fn empty_sum() -> Bytes32 {
+        *super::empty_sum_sha256()
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     alloc::borrow::ToOwned,
     storage::{
         Mappable,
+        StorageBatchWrite,
         StorageInspect,
         StorageMutate,
     },
@@ -89,6 +90,26 @@ where
     }
 }
 
+impl<Type> StorageBatchWrite<Type> for StorageMap<Type>
+where
+    Type: Mappable,
+    Type::Key: Eq + core::hash::Hash,
+    Type::OwnedKey: Eq + core::hash::Hash + core::borrow::Borrow<Type::Key>,
+{
+    fn write_batch<I>(&mut self, changes: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Type::OwnedKey, Option<Type::OwnedValue>)>,
+    {
+        for (key, value) in changes {
+            match value {
+                Some(value) => self.map.insert(key, value),
+                None => self.map.remove(&key),
+            };
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
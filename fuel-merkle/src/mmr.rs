@@ -0,0 +1,32 @@
+//! A Merkle Mountain Range (MMR): an append-only accumulator made of perfect binary
+//! trees, its peaks, with at most one tree of each height.
+//!
+//! The nodes are stored at their positions in the post-order traversal of the
+//! trees, so an append only writes the new leaf and the roots of the merged trees.
+//! The root bags the peaks from right to left, which makes it equal to the root of
+//! the [binary Merkle tree](crate::binary::MerkleTree) of the same leaves.
+
+mod merkle_mountain_range;
+mod proof;
+
+pub mod in_memory;
+
+pub use merkle_mountain_range::{
+    MerkleMountainRange,
+    MerkleMountainRangeError,
+};
+pub use proof::MmrProof;
+
+/// The heights of the peaks of a range with `leaves_count` leaves, from left to
+/// right.
+pub(crate) fn peak_heights(leaves_count: u64) -> impl Iterator<Item = u32> {
+    (0..u64::BITS)
+        .rev()
+        .filter(move |height| leaves_count.checked_shr(*height).unwrap_or(0) & 1 == 1)
+}
+
+/// The number of the nodes of a perfect tree with the `height`. Returns `None` if
+/// the tree is too large.
+pub(crate) fn tree_size(height: u32) -> Option<u64> {
+    1u64.checked_shl(height.checked_add(1)?)?.checked_sub(1)
+}
@@ -0,0 +1,64 @@
+use crate::{
+    common::{
+        Bytes32,
+        StorageMap,
+    },
+    mmr::{
+        self,
+        MmrProof,
+    },
+    storage::Mappable,
+};
+
+/// The table of the Merkle Mountain Range's nodes. The storage key of a node is its
+/// post-order position, and the value is its hash.
+#[derive(Debug, Clone)]
+pub struct NodesTable;
+
+impl Mappable for NodesTable {
+    type Key = Self::OwnedKey;
+    type OwnedKey = u64;
+    type OwnedValue = Bytes32;
+    type Value = Self::OwnedValue;
+}
+
+type Storage = StorageMap<NodesTable>;
+type MerkleMountainRangeImpl = mmr::MerkleMountainRange<NodesTable, Storage>;
+
+#[derive(Debug, Clone)]
+pub struct MerkleMountainRange {
+    range: MerkleMountainRangeImpl,
+}
+
+impl MerkleMountainRange {
+    pub fn new() -> Self {
+        Self {
+            range: MerkleMountainRangeImpl::new(Storage::new()),
+        }
+    }
+
+    /// Appends a new leaf and returns its index.
+    pub fn push(&mut self, data: &[u8]) -> u64 {
+        self.range
+            .push(data)
+            .expect("`Storage` can't return error, and the range can't be too large")
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        self.range.root()
+    }
+
+    pub fn leaves_count(&self) -> u64 {
+        self.range.leaves_count()
+    }
+
+    pub fn prove(&self, leaf_index: u64) -> Option<(Bytes32, MmrProof)> {
+        self.range.prove(leaf_index).ok()
+    }
+}
+
+impl Default for MerkleMountainRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
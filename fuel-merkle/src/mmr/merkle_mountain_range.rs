@@ -0,0 +1,397 @@
+use crate::{
+    common::{
+        Bytes32,
+        MerkleHasher,
+        ProofSet,
+        Sha256Hasher,
+    },
+    mmr::{
+        peak_heights,
+        tree_size,
+        MmrProof,
+    },
+    storage::{
+        Mappable,
+        StorageInspect,
+        StorageMutate,
+    },
+};
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone, derive_more::Display, PartialEq, Eq)]
+pub enum MerkleMountainRangeError<StorageError> {
+    #[display(fmt = "proof index {_0} is not valid")]
+    InvalidProofIndex(u64),
+
+    #[display(fmt = "cannot load node with position {_0}; it is not found in storage")]
+    LoadError(u64),
+
+    #[display(fmt = "{}", _0)]
+    StorageError(StorageError),
+
+    #[display(fmt = "the range is too large")]
+    TooLarge,
+}
+
+impl<StorageError> From<StorageError> for MerkleMountainRangeError<StorageError> {
+    fn from(err: StorageError) -> MerkleMountainRangeError<StorageError> {
+        MerkleMountainRangeError::StorageError(err)
+    }
+}
+
+/// A peak of the range: the root of one of its perfect trees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Peak {
+    height: u32,
+    hash: Bytes32,
+}
+
+/// A Merkle Mountain Range, hashed with `H`, see the [module](crate::mmr) docs. The
+/// storage maps the post-order positions of the nodes to their hashes.
+#[derive(Debug, Clone)]
+pub struct MerkleMountainRange<TableType, StorageType, H = Sha256Hasher> {
+    storage: StorageType,
+    peaks: Vec<Peak>,
+    leaves_count: u64,
+    size: u64,
+    phantom_table: PhantomData<TableType>,
+    phantom_hasher: PhantomData<H>,
+}
+
+impl<TableType, StorageType, H: MerkleHasher>
+    MerkleMountainRange<TableType, StorageType, H>
+{
+    /// Bags the peaks from right to left. The root of the empty range is the empty
+    /// sum.
+    pub fn root(&self) -> Bytes32 {
+        bag_peaks::<H>(self.peaks.iter().map(|peak| peak.hash))
+    }
+
+    pub fn leaves_count(&self) -> u64 {
+        self.leaves_count
+    }
+
+    /// The number of the nodes of the range.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn storage(&self) -> &StorageType {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> StorageType {
+        self.storage
+    }
+}
+
+impl<TableType, StorageType, StorageError> MerkleMountainRange<TableType, StorageType>
+where
+    TableType: Mappable<Key = u64, Value = Bytes32, OwnedValue = Bytes32>,
+    StorageType: StorageInspect<TableType, Error = StorageError>,
+{
+    pub fn new(storage: StorageType) -> Self {
+        Self::new_with_hasher(storage)
+    }
+
+    /// Loads the range with `leaves_count` leaves, from the peaks in the storage.
+    /// The nodes are never modified by the appends, so the range can be loaded at
+    /// any of its past sizes.
+    pub fn load(
+        storage: StorageType,
+        leaves_count: u64,
+    ) -> Result<Self, MerkleMountainRangeError<StorageError>> {
+        Self::load_with_hasher(storage, leaves_count)
+    }
+}
+
+impl<TableType, StorageType, StorageError, H>
+    MerkleMountainRange<TableType, StorageType, H>
+where
+    TableType: Mappable<Key = u64, Value = Bytes32, OwnedValue = Bytes32>,
+    StorageType: StorageInspect<TableType, Error = StorageError>,
+    H: MerkleHasher,
+{
+    /// Creates an empty range hashed with `H`.
+    pub fn new_with_hasher(storage: StorageType) -> Self {
+        Self {
+            storage,
+            peaks: Vec::new(),
+            leaves_count: 0,
+            size: 0,
+            phantom_table: PhantomData,
+            phantom_hasher: PhantomData,
+        }
+    }
+
+    /// Same as [`load`](MerkleMountainRange::load), for the range hashed with `H`.
+    pub fn load_with_hasher(
+        storage: StorageType,
+        leaves_count: u64,
+    ) -> Result<Self, MerkleMountainRangeError<StorageError>> {
+        let mut range = Self::new_with_hasher(storage);
+        for height in peak_heights(leaves_count) {
+            let size = tree_size(height).ok_or(MerkleMountainRangeError::TooLarge)?;
+            #[allow(clippy::arithmetic_side_effects)] // size > 0
+            let position = range
+                .size
+                .checked_add(size - 1)
+                .ok_or(MerkleMountainRangeError::TooLarge)?;
+            let hash = range.load_node(position)?;
+            range.peaks.push(Peak { height, hash });
+            range.size = position
+                .checked_add(1)
+                .ok_or(MerkleMountainRangeError::TooLarge)?;
+        }
+        range.leaves_count = leaves_count;
+        Ok(range)
+    }
+
+    /// Proves the inclusion of the leaf at the `leaf_index`, verified with
+    /// [`MmrProof::verify`].
+    pub fn prove(
+        &self,
+        leaf_index: u64,
+    ) -> Result<(Bytes32, MmrProof), MerkleMountainRangeError<StorageError>> {
+        if leaf_index >= self.leaves_count {
+            return Err(MerkleMountainRangeError::InvalidProofIndex(leaf_index))
+        }
+
+        let mut offset = 0u64;
+        let mut start = 0u64;
+        let mut path = ProofSet::new();
+        let mut peaks = Vec::new();
+        for peak in &self.peaks {
+            let size =
+                tree_size(peak.height).ok_or(MerkleMountainRangeError::TooLarge)?;
+            #[allow(clippy::arithmetic_side_effects)] // the leaves fit into u64
+            let end = start + (1 << peak.height);
+            if (start..end).contains(&leaf_index) {
+                #[allow(clippy::arithmetic_side_effects)] // leaf_index >= start
+                let index = leaf_index - start;
+                path = self.path(offset, peak.height, index)?;
+            } else {
+                peaks.push(peak.hash);
+            }
+            #[allow(clippy::arithmetic_side_effects)] // the nodes fit into u64
+            {
+                offset += size;
+            }
+            start = end;
+        }
+
+        let proof = MmrProof {
+            leaf_index,
+            leaves_count: self.leaves_count,
+            path,
+            peaks,
+        };
+        Ok((self.root(), proof))
+    }
+
+    /// The siblings of the path from the leaf at the `index` of the perfect tree
+    /// with the `height`, whose first node is at the `offset`, to its root, ordered
+    /// from the leaf to the root.
+    fn path(
+        &self,
+        mut offset: u64,
+        mut height: u32,
+        mut index: u64,
+    ) -> Result<ProofSet, MerkleMountainRangeError<StorageError>> {
+        let mut path = ProofSet::new();
+        #[allow(clippy::arithmetic_side_effects)] // the positions are within the tree
+        while height > 0 {
+            height -= 1;
+            let half = 1u64 << height;
+            let child_size =
+                tree_size(height).ok_or(MerkleMountainRangeError::TooLarge)?;
+            let left_root = offset + child_size - 1;
+            let right_root = left_root + child_size;
+            if index < half {
+                path.push(self.load_node(right_root)?);
+            } else {
+                path.push(self.load_node(left_root)?);
+                offset += child_size;
+                index -= half;
+            }
+        }
+        path.reverse();
+        Ok(path)
+    }
+
+    fn load_node(
+        &self,
+        position: u64,
+    ) -> Result<Bytes32, MerkleMountainRangeError<StorageError>> {
+        let hash = self
+            .storage
+            .get(&position)?
+            .ok_or(MerkleMountainRangeError::LoadError(position))?
+            .into_owned();
+        Ok(hash)
+    }
+}
+
+impl<TableType, StorageType, StorageError, H>
+    MerkleMountainRange<TableType, StorageType, H>
+where
+    TableType: Mappable<Key = u64, Value = Bytes32, OwnedValue = Bytes32>,
+    StorageType: StorageMutate<TableType, Error = StorageError>,
+    H: MerkleHasher,
+{
+    /// Appends a new leaf, merging the peaks of the same height. Returns the index of
+    /// the leaf.
+    pub fn push(
+        &mut self,
+        data: &[u8],
+    ) -> Result<u64, MerkleMountainRangeError<StorageError>> {
+        let leaf_index = self.leaves_count;
+        let leaves_count = leaf_index
+            .checked_add(1)
+            .ok_or(MerkleMountainRangeError::TooLarge)?;
+        // The positions of all the nodes must fit into u64.
+        leaves_count
+            .checked_mul(2)
+            .ok_or(MerkleMountainRangeError::TooLarge)?;
+
+        let mut peak = Peak {
+            height: 0,
+            hash: H::leaf_sum(&[data]),
+        };
+        self.insert_node(&peak.hash)?;
+        while let Some(left) = self.peaks.last() {
+            if left.height != peak.height {
+                break
+            }
+            #[allow(clippy::arithmetic_side_effects)] // height < 64
+            let height = peak.height + 1;
+            peak = Peak {
+                height,
+                hash: H::node_sum(&left.hash, &peak.hash),
+            };
+            self.peaks.pop();
+            self.insert_node(&peak.hash)?;
+        }
+        self.peaks.push(peak);
+        self.leaves_count = leaves_count;
+
+        Ok(leaf_index)
+    }
+
+    fn insert_node(
+        &mut self,
+        hash: &Bytes32,
+    ) -> Result<(), MerkleMountainRangeError<StorageError>> {
+        self.storage.insert(&self.size, hash)?;
+        #[allow(clippy::arithmetic_side_effects)] // checked by push
+        {
+            self.size += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Bags the `peaks` from right to left.
+pub(crate) fn bag_peaks<H: MerkleHasher>(
+    peaks: impl DoubleEndedIterator<Item = Bytes32>,
+) -> Bytes32 {
+    let mut peaks = peaks.rev();
+    let Some(mut root) = peaks.next() else {
+        return H::empty_sum()
+    };
+    for peak in peaks {
+        root = H::node_sum(&peak, &root);
+    }
+    root
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        binary,
+        common::StorageMap,
+    };
+    use fuel_merkle_test_helpers::TEST_DATA;
+
+    #[derive(Debug)]
+    struct TestTable;
+
+    impl Mappable for TestTable {
+        type Key = Self::OwnedKey;
+        type OwnedKey = u64;
+        type OwnedValue = Bytes32;
+        type Value = Self::OwnedValue;
+    }
+
+    #[test]
+    fn root_of_the_empty_range_is_the_empty_sum() {
+        let range = MerkleMountainRange::new(StorageMap::<TestTable>::new());
+
+        assert_eq!(range.root(), *binary::empty_sum());
+    }
+
+    #[test]
+    fn root_matches_the_binary_merkle_tree() {
+        let mut range = MerkleMountainRange::new(StorageMap::<TestTable>::new());
+        let mut tree = binary::root_calculator::MerkleRootCalculator::new();
+
+        for (index, datum) in TEST_DATA.iter().enumerate() {
+            assert_eq!(range.push(datum), Ok(index as u64));
+            tree.push(datum);
+            assert_eq!(range.root(), tree.clone().root());
+        }
+    }
+
+    #[test]
+    fn size_counts_the_leaves_and_the_merged_nodes() {
+        let mut range = MerkleMountainRange::new(StorageMap::<TestTable>::new());
+
+        for datum in &TEST_DATA[..7] {
+            range.push(datum).unwrap();
+        }
+
+        // 7 leaves: the peaks of heights 2, 1 and 0.
+        assert_eq!(range.size(), 11);
+        assert_eq!(range.storage().len(), 11);
+    }
+
+    #[test]
+    fn load_restores_the_range_at_a_past_size() {
+        let mut storage = StorageMap::<TestTable>::new();
+        let mut range = MerkleMountainRange::new(&mut storage);
+        let mut roots = Vec::new();
+        for datum in TEST_DATA.iter() {
+            range.push(datum).unwrap();
+            roots.push(range.root());
+        }
+
+        for (index, root) in roots.iter().enumerate() {
+            let loaded = MerkleMountainRange::load(&storage, index as u64 + 1).unwrap();
+            assert_eq!(loaded.root(), *root);
+        }
+    }
+
+    #[test]
+    fn load_returns_an_error_for_missing_nodes() {
+        let storage = StorageMap::<TestTable>::new();
+
+        assert_eq!(
+            MerkleMountainRange::load(&storage, 3).err(),
+            Some(MerkleMountainRangeError::LoadError(2))
+        );
+    }
+
+    #[test]
+    fn prove_returns_an_error_for_invalid_index() {
+        let mut range = MerkleMountainRange::new(StorageMap::<TestTable>::new());
+        range.push(TEST_DATA[0]).unwrap();
+
+        assert_eq!(
+            range.prove(1).err(),
+            Some(MerkleMountainRangeError::InvalidProofIndex(1))
+        );
+    }
+}
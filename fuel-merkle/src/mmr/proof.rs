@@ -0,0 +1,168 @@
+use crate::{
+    common::{
+        Bytes32,
+        MerkleHasher,
+        ProofSet,
+        Sha256Hasher,
+    },
+    mmr::{
+        merkle_mountain_range::bag_peaks,
+        peak_heights,
+    },
+};
+
+use alloc::vec::Vec;
+
+/// The proof of the inclusion of a leaf into a
+/// [`MerkleMountainRange`](crate::mmr::MerkleMountainRange).
+///
+/// The path leads from the leaf to the peak of its tree, and the other peaks bag
+/// this peak into the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MmrProof {
+    /// The index of the proven leaf.
+    pub leaf_index: u64,
+    /// The number of the leaves of the range.
+    pub leaves_count: u64,
+    /// The siblings on the path from the leaf to its peak.
+    pub path: ProofSet,
+    /// The peaks of the other trees, ordered from left to right.
+    pub peaks: Vec<Bytes32>,
+}
+
+impl MmrProof {
+    /// Returns `true` if the leaf with the `data` is included into the range with
+    /// the `root`.
+    pub fn verify(&self, root: &Bytes32, data: &[u8]) -> bool {
+        self.verify_with_hasher::<Sha256Hasher>(root, data)
+    }
+
+    /// Same as [`verify`](Self::verify), for the ranges hashed with `H`.
+    pub fn verify_with_hasher<H: MerkleHasher>(
+        &self,
+        root: &Bytes32,
+        data: &[u8],
+    ) -> bool {
+        if self.leaf_index >= self.leaves_count {
+            return false
+        }
+
+        // Find the tree of the leaf.
+        let mut start = 0u64;
+        let mut tree = None;
+        for (peak_index, height) in peak_heights(self.leaves_count).enumerate() {
+            #[allow(clippy::arithmetic_side_effects)] // the leaves fit into u64
+            let end = start + (1 << height);
+            if (start..end).contains(&self.leaf_index) {
+                #[allow(clippy::arithmetic_side_effects)] // leaf_index >= start
+                let index = self.leaf_index - start;
+                tree = Some((peak_index, height, index));
+                break
+            }
+            start = end;
+        }
+        let Some((peak_index, height, index)) = tree else {
+            return false
+        };
+        let peaks_count = peak_heights(self.leaves_count).count();
+        #[allow(clippy::arithmetic_side_effects)] // the range has a peak
+        if u32::try_from(self.path.len()).ok() != Some(height)
+            || self.peaks.len() != peaks_count - 1
+        {
+            return false
+        }
+
+        let mut peak = H::leaf_sum(&[data]);
+        for (level, sibling) in self.path.iter().enumerate() {
+            peak = if index >> level & 1 == 0 {
+                H::node_sum(&peak, sibling)
+            } else {
+                H::node_sum(sibling, &peak)
+            };
+        }
+
+        let mut peaks = self.peaks.clone();
+        peaks.insert(peak_index, peak);
+        bag_peaks::<H>(peaks.into_iter()) == *root
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        common::{
+            Bytes32,
+            StorageMap,
+        },
+        mmr::MerkleMountainRange,
+        storage::Mappable,
+    };
+    use fuel_merkle_test_helpers::TEST_DATA;
+
+    #[derive(Debug)]
+    struct TestTable;
+
+    impl Mappable for TestTable {
+        type Key = Self::OwnedKey;
+        type OwnedKey = u64;
+        type OwnedValue = Bytes32;
+        type Value = Self::OwnedValue;
+    }
+
+    fn range(
+        leaves_count: usize,
+    ) -> MerkleMountainRange<TestTable, StorageMap<TestTable>> {
+        let mut range = MerkleMountainRange::new(StorageMap::<TestTable>::new());
+        for datum in &TEST_DATA[..leaves_count] {
+            range.push(datum).unwrap();
+        }
+        range
+    }
+
+    #[test]
+    fn verify_accepts_the_proofs_of_all_the_leaves() {
+        for leaves_count in 1..=TEST_DATA.len() {
+            let range = range(leaves_count);
+            for (index, datum) in TEST_DATA[..leaves_count].iter().enumerate() {
+                let (root, proof) = range.prove(index as u64).unwrap();
+
+                assert!(proof.verify(&root, datum));
+            }
+        }
+    }
+
+    #[test]
+    fn verify_rejects_the_wrong_data() {
+        let range = range(7);
+        let (root, proof) = range.prove(4).unwrap();
+
+        assert!(!proof.verify(&root, TEST_DATA[5]));
+    }
+
+    #[test]
+    fn verify_rejects_the_wrong_index() {
+        let range = range(7);
+        let (root, mut proof) = range.prove(4).unwrap();
+        proof.leaf_index = 5;
+
+        assert!(!proof.verify(&root, TEST_DATA[4]));
+    }
+
+    #[test]
+    fn verify_rejects_the_proof_against_another_range() {
+        let range = range(7);
+        let (_, proof) = range.prove(4).unwrap();
+        let other_root = self::range(8).root();
+
+        assert!(!proof.verify(&other_root, TEST_DATA[4]));
+    }
+
+    #[test]
+    fn verify_rejects_the_missing_peaks() {
+        let range = range(7);
+        let (root, mut proof) = range.prove(4).unwrap();
+        proof.peaks.pop();
+
+        assert!(!proof.verify(&root, TEST_DATA[4]));
+    }
+}
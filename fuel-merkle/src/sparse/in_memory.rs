@@ -6,7 +6,10 @@ use crate::{
     sparse::{
         self,
         merkle_tree::MerkleTreeKey,
-        proof::Proof,
+        proof::{
+            ExclusionProof,
+            Proof,
+        },
         Primitive,
     },
     storage::{
@@ -192,6 +195,14 @@ impl MerkleTree {
         let _ = self.tree.update(key, data);
     }
 
+    pub fn update_batch<I, D>(&mut self, set: I)
+    where
+        I: IntoIterator<Item = (MerkleTreeKey, D)>,
+        D: AsRef<[u8]>,
+    {
+        let _ = self.tree.update_batch(set);
+    }
+
     pub fn delete(&mut self, key: MerkleTreeKey) {
         let _ = self.tree.delete(key);
     }
@@ -203,6 +214,15 @@ impl MerkleTree {
     pub fn generate_proof(&self, key: &MerkleTreeKey) -> Option<Proof> {
         self.tree.generate_proof(key).ok()
     }
+
+    /// Generates the proof that the `key` isn't included into the tree, or returns
+    /// `None` if it is.
+    pub fn generate_exclusion_proof(
+        &self,
+        key: &MerkleTreeKey,
+    ) -> Option<ExclusionProof> {
+        self.tree.generate_exclusion_proof(key).ok().flatten()
+    }
 }
 
 impl Default for MerkleTree {
@@ -15,8 +15,14 @@ use crate::{
     common::{
         error::DeserializeError,
         node::ChildError,
+        path::{
+            Path,
+            Side,
+        },
         AsPathIterator,
         Bytes32,
+        MerkleHasher,
+        Sha256Hasher,
     },
     sparse::{
         empty_sum,
@@ -27,6 +33,7 @@ use crate::{
             InclusionProof,
             Proof,
         },
+        zero_sum,
         Primitive,
     },
     storage::{
@@ -36,6 +43,7 @@ use crate::{
     },
 };
 use alloc::{
+    collections::BTreeMap,
     format,
     vec::Vec,
 };
@@ -156,18 +164,22 @@ impl From<Bytes32> for MerkleTreeKey {
     }
 }
 
+/// A sparse Merkle tree, hashed with `H`.
 #[derive(Debug)]
-pub struct MerkleTree<TableType, StorageType> {
+pub struct MerkleTree<TableType, StorageType, H = Sha256Hasher> {
     root_node: Node,
     storage: StorageType,
     phantom_table: PhantomData<TableType>,
+    phantom_hasher: PhantomData<H>,
 }
 
 impl<TableType, StorageType> MerkleTree<TableType, StorageType> {
     pub const fn empty_root() -> &'static Bytes32 {
         empty_sum()
     }
+}
 
+impl<TableType, StorageType, H> MerkleTree<TableType, StorageType, H> {
     pub fn root(&self) -> Bytes32 {
         *self.root_node().hash()
     }
@@ -196,19 +208,40 @@ where
     StorageType: StorageInspect<TableType, Error = StorageError>,
 {
     pub fn new(storage: StorageType) -> Self {
+        Self::new_with_hasher(storage)
+    }
+
+    pub fn load(
+        storage: StorageType,
+        root: &Bytes32,
+    ) -> Result<Self, MerkleTreeError<StorageError>> {
+        Self::load_with_hasher(storage, root)
+    }
+}
+
+impl<TableType, StorageType, StorageError, H> MerkleTree<TableType, StorageType, H>
+where
+    TableType: Mappable<Key = Bytes32, Value = Primitive, OwnedValue = Primitive>,
+    StorageType: StorageInspect<TableType, Error = StorageError>,
+    H: MerkleHasher,
+{
+    /// Creates an empty tree hashed with `H`.
+    pub fn new_with_hasher(storage: StorageType) -> Self {
         Self {
             root_node: Node::create_placeholder(),
             storage,
             phantom_table: Default::default(),
+            phantom_hasher: Default::default(),
         }
     }
 
-    pub fn load(
+    /// Same as [`load`](MerkleTree::load), for the tree hashed with `H`.
+    pub fn load_with_hasher(
         storage: StorageType,
         root: &Bytes32,
     ) -> Result<Self, MerkleTreeError<StorageError>> {
-        if root == Self::empty_root() {
-            let tree = Self::new(storage);
+        if root == empty_sum() {
+            let tree = Self::new_with_hasher(storage);
             Ok(tree)
         } else {
             let primitive = storage
@@ -216,11 +249,11 @@ where
                 .ok_or_else(|| MerkleTreeError::LoadError(*root))?
                 .into_owned();
             let tree = Self {
-                root_node: primitive
-                    .try_into()
+                root_node: Node::from_primitive::<H>(primitive)
                     .map_err(MerkleTreeError::DeserializeError)?,
                 storage,
                 phantom_table: Default::default(),
+                phantom_hasher: Default::default(),
             };
             Ok(tree)
         }
@@ -231,7 +264,7 @@ where
         leaf_key: &Bytes32,
     ) -> Result<(Vec<Node>, Vec<Bytes32>), MerkleTreeError<StorageError>> {
         let root_node = self.root_node().clone();
-        let root_storage_node = StorageNode::new(&self.storage, root_node);
+        let root_storage_node = StorageNode::<_, _, H>::new(&self.storage, root_node);
         let (mut path_nodes, mut side_nodes): (Vec<Node>, Vec<Bytes32>) =
             root_storage_node
                 .as_path_iter(leaf_key)
@@ -265,7 +298,24 @@ where
     /// preferred approach when the key-values are known upfront. Leaves can be
     /// appended to the returned tree using `update` to further accumulate leaf
     /// data.
-    pub fn from_set<B, I, D>(
+    pub fn from_set<B, I, D>(storage: StorageType, set: I) -> Result<Self, StorageError>
+    where
+        I: Iterator<Item = (B, D)>,
+        B: Into<Bytes32>,
+        D: AsRef<[u8]>,
+    {
+        Self::from_set_with_hasher(storage, set)
+    }
+}
+
+impl<TableType, StorageType, StorageError, H> MerkleTree<TableType, StorageType, H>
+where
+    TableType: Mappable<Key = Bytes32, Value = Primitive, OwnedValue = Primitive>,
+    StorageType: StorageMutate<TableType, Error = StorageError>,
+    H: MerkleHasher,
+{
+    /// Same as [`from_set`](MerkleTree::from_set), for the tree hashed with `H`.
+    pub fn from_set_with_hasher<B, I, D>(
         mut storage: StorageType,
         set: I,
     ) -> Result<Self, StorageError>
@@ -281,7 +331,7 @@ where
         let mut branches = sorted
             .iter()
             .filter(|(_, value)| !value.as_ref().is_empty())
-            .map(|(key, data)| Node::create_leaf(key, data))
+            .map(|(key, data)| Node::create_leaf::<H, _>(key, data))
             .map(Into::<Branch>::into)
             .collect::<Vec<_>>();
 
@@ -291,13 +341,13 @@ where
         }
 
         if branches.is_empty() {
-            let tree = Self::new(storage);
+            let tree = Self::new_with_hasher(storage);
             return Ok(tree)
         }
 
         if branches.len() == 1 {
             let leaf = branches.pop().expect("Expected at least 1 leaf").node;
-            let mut tree = Self::new(storage);
+            let mut tree = Self::new_with_hasher(storage);
             tree.set_root_node(leaf);
             return Ok(tree)
         }
@@ -354,7 +404,7 @@ where
                     let current =
                         nodes.pop().expect("Expected current node to be present");
                     let right = nodes.pop().expect("Expected right node to be present");
-                    let merged = merge_branches(&mut storage, current, right)?;
+                    let merged = merge_branches::<H, _, _>(&mut storage, current, right)?;
                     nodes.push(merged);
 
                     // Now that the current node and its right neighbour are
@@ -377,7 +427,7 @@ where
                 .pop()
                 .expect("Nodes stack must have at least 1 element");
             while let Some(next) = nodes.pop() {
-                node = merge_branches(&mut storage, node, next)?;
+                node = merge_branches::<H, _, _>(&mut storage, node, next)?;
             }
             node
         };
@@ -393,7 +443,7 @@ where
         let depth = Node::max_height() - height;
         let placeholders = iter::repeat(Node::create_placeholder()).take(depth as usize);
         for placeholder in placeholders {
-            node = Node::create_node_on_path(&path, &node, &placeholder);
+            node = Node::create_node_on_path::<H>(&path, &node, &placeholder);
             storage.insert(node.hash(), &node.as_ref().into())?;
         }
 
@@ -401,6 +451,7 @@ where
             root_node: node,
             storage,
             phantom_table: Default::default(),
+            phantom_hasher: Default::default(),
         };
         Ok(tree)
     }
@@ -417,7 +468,7 @@ where
             return Ok(())
         }
 
-        let leaf_node = Node::create_leaf(key.as_ref(), data);
+        let leaf_node = Node::create_leaf::<H, _>(key.as_ref(), data);
         self.storage
             .insert(leaf_node.hash(), &leaf_node.as_ref().into())?;
 
@@ -439,7 +490,7 @@ where
         &mut self,
         key: MerkleTreeKey,
     ) -> Result<(), MerkleTreeError<StorageError>> {
-        if self.root() == *Self::empty_root() {
+        if self.root() == *empty_sum() {
             // The zero root signifies that all leaves are empty, including the
             // given key.
             return Ok(())
@@ -458,6 +509,148 @@ where
         Ok(())
     }
 
+    /// Applies the updates of the `set`, as the sequential calls to
+    /// [`update`](Self::update) would, i.e. the empty data deletes the key and the
+    /// last update of a key wins. The updates are sorted by their keys and applied
+    /// in a single walk of the tree, so the nodes shared by the paths of several
+    /// updated keys are loaded and rebuilt once.
+    pub fn update_batch<I, D>(
+        &mut self,
+        set: I,
+    ) -> Result<(), MerkleTreeError<StorageError>>
+    where
+        I: IntoIterator<Item = (MerkleTreeKey, D)>,
+        D: AsRef<[u8]>,
+    {
+        let updates = set
+            .into_iter()
+            .map(|(key, data)| {
+                let data = data.as_ref();
+                let leaf = (!data.is_empty())
+                    .then(|| Node::create_leaf::<H, _>(key.as_ref(), data));
+                (key.into(), leaf)
+            })
+            .collect::<BTreeMap<Bytes32, Option<Node>>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let root_node = self.root_node().clone();
+        self.root_node = self.update_subtree(root_node, 0, &updates)?;
+
+        Ok(())
+    }
+
+    /// Applies the sorted `updates` to the subtree at the `depth` with the root
+    /// `node`, and returns the new root of the subtree. The keys of the updates are
+    /// within the subtree.
+    fn update_subtree(
+        &mut self,
+        node: Node,
+        depth: u32,
+        updates: &[(Bytes32, Option<Node>)],
+    ) -> Result<Node, MerkleTreeError<StorageError>> {
+        if updates.is_empty() {
+            return Ok(node)
+        }
+
+        // A subtree with at most one leaf is rebuilt from its updated leaves.
+        if node.is_leaf() {
+            let mut leaves = BTreeMap::new();
+            if !node.is_placeholder() {
+                leaves.insert(*node.leaf_key(), node.clone());
+            }
+            for (key, leaf) in updates {
+                match leaf {
+                    Some(leaf) => {
+                        self.storage.insert(leaf.hash(), &leaf.as_ref().into())?;
+                        leaves.insert(*key, leaf.clone());
+                    }
+                    None => {
+                        leaves.remove(key);
+                    }
+                }
+            }
+            if !node.is_placeholder() && leaves.get(node.leaf_key()) != Some(&node) {
+                self.storage.remove(node.hash())?;
+            }
+            let leaves = leaves.into_values().collect::<Vec<_>>();
+            return Ok(self.build_subtree(&leaves, depth)?)
+        }
+
+        let left = self.load_node(node.left_child_key())?;
+        let right = self.load_node(node.right_child_key())?;
+        let middle = updates.partition_point(|(key, _)| {
+            matches!(key.get_instruction(depth), Some(Side::Left))
+        });
+        let (left_updates, right_updates) = updates.split_at(middle);
+        #[allow(clippy::arithmetic_side_effects)] // depth < max_height for a node
+        let child_depth = depth + 1;
+        let new_left = self.update_subtree(left.clone(), child_depth, left_updates)?;
+        let new_right = self.update_subtree(right.clone(), child_depth, right_updates)?;
+        if new_left == left && new_right == right {
+            return Ok(node)
+        }
+
+        self.storage.remove(node.hash())?;
+        Ok(self.join_subtrees(new_left, new_right, node.height())?)
+    }
+
+    /// Builds the subtree at the `depth` holding the sorted `leaves`.
+    fn build_subtree(
+        &mut self,
+        leaves: &[Node],
+        depth: u32,
+    ) -> Result<Node, StorageError> {
+        match leaves {
+            [] => Ok(Node::create_placeholder()),
+            [leaf] => Ok(leaf.clone()),
+            _ => {
+                let middle = leaves.partition_point(|leaf| {
+                    matches!(leaf.leaf_key().get_instruction(depth), Some(Side::Left))
+                });
+                #[allow(clippy::arithmetic_side_effects)] // distinct keys diverge
+                let child_depth = depth + 1;
+                let left = self.build_subtree(&leaves[..middle], child_depth)?;
+                let right = self.build_subtree(&leaves[middle..], child_depth)?;
+                #[allow(clippy::arithmetic_side_effects)] // depth < max_height
+                let height = Node::max_height() - depth;
+                self.join_subtrees(left, right, height)
+            }
+        }
+    }
+
+    /// Joins the roots of the sibling subtrees into their parent at the `height`. A
+    /// parent of a single leaf is the leaf itself.
+    fn join_subtrees(
+        &mut self,
+        left: Node,
+        right: Node,
+        height: u32,
+    ) -> Result<Node, StorageError> {
+        match (left.is_placeholder(), right.is_placeholder()) {
+            (true, true) => Ok(Node::create_placeholder()),
+            (true, false) if right.is_leaf() => Ok(right),
+            (false, true) if left.is_leaf() => Ok(left),
+            _ => {
+                let node = Node::create_node::<H>(&left, &right, height);
+                self.storage.insert(node.hash(), &node.as_ref().into())?;
+                Ok(node)
+            }
+        }
+    }
+
+    fn load_node(&self, key: &Bytes32) -> Result<Node, MerkleTreeError<StorageError>> {
+        if key == zero_sum() {
+            return Ok(Node::create_placeholder())
+        }
+        let primitive = self
+            .storage
+            .get(key)?
+            .ok_or(MerkleTreeError::LoadError(*key))?
+            .into_owned();
+        Node::from_primitive::<H>(primitive).map_err(MerkleTreeError::DeserializeError)
+    }
+
     fn update_with_path_set(
         &mut self,
         requested_leaf_node: &Node,
@@ -498,7 +691,7 @@ where
             // Merge leaves
             if !actual_leaf_node.is_placeholder() {
                 current_node =
-                    Node::create_node_on_path(path, &current_node, actual_leaf_node);
+                    Node::create_node_on_path::<H>(path, &current_node, actual_leaf_node);
                 self.storage
                     .insert(current_node.hash(), &current_node.as_ref().into())?;
             }
@@ -512,7 +705,7 @@ where
                 iter::repeat(Node::create_placeholder()).take(placeholders_count);
             for placeholder in placeholders {
                 current_node =
-                    Node::create_node_on_path(path, &current_node, &placeholder);
+                    Node::create_node_on_path::<H>(path, &current_node, &placeholder);
                 self.storage
                     .insert(current_node.hash(), &current_node.as_ref().into())?;
             }
@@ -525,13 +718,13 @@ where
             side_nodes.iter().zip(path_nodes.iter().skip(1 /* leaf */))
         {
             let new_parent = if old_parent.bytes_lo() == side_node {
-                Node::create_node_from_hashes(
+                Node::create_node_from_hashes::<H>(
                     *side_node,
                     *current_node.hash(),
                     old_parent.height(),
                 )
             } else {
-                Node::create_node_from_hashes(
+                Node::create_node_from_hashes::<H>(
                     *current_node.hash(),
                     *side_node,
                     old_parent.height(),
@@ -575,12 +768,12 @@ where
         // calculation. We then create a valid ancestor node for the orphaned
         // leaf node by joining it with the earliest non-placeholder side node.
         if let Some(first_side_node) = side_nodes.first() {
-            let first_side_node: Node = self
+            let first_side_node = self
                 .storage
                 .get(first_side_node)?
                 .ok_or(MerkleTreeError::LoadError(*first_side_node))?
-                .into_owned()
-                .try_into()
+                .into_owned();
+            let first_side_node = Node::from_primitive::<H>(first_side_node)
                 .map_err(MerkleTreeError::DeserializeError)?;
 
             if first_side_node.is_leaf() {
@@ -608,13 +801,13 @@ where
                         parent.bytes_lo() == side_node || parent.bytes_hi() == side_node
                     }) {
                         let new_parent = if old_parent.bytes_lo() == side_node {
-                            Node::create_node_from_hashes(
+                            Node::create_node_from_hashes::<H>(
                                 *side_node,
                                 *current_node.hash(),
                                 old_parent.height(),
                             )
                         } else {
-                            Node::create_node_from_hashes(
+                            Node::create_node_from_hashes::<H>(
                                 *current_node.hash(),
                                 *side_node,
                                 old_parent.height(),
@@ -631,13 +824,13 @@ where
         // Merge side nodes
         for (side_node, old_parent) in side_nodes_iter.zip(path_nodes_iter) {
             let new_parent = if old_parent.bytes_lo() == side_node {
-                Node::create_node_from_hashes(
+                Node::create_node_from_hashes::<H>(
                     *side_node,
                     *current_node.hash(),
                     old_parent.height(),
                 )
             } else {
-                Node::create_node_from_hashes(
+                Node::create_node_from_hashes::<H>(
                     *current_node.hash(),
                     *side_node,
                     old_parent.height(),
@@ -655,10 +848,11 @@ where
     }
 }
 
-impl<TableType, StorageType, StorageError> MerkleTree<TableType, StorageType>
+impl<TableType, StorageType, StorageError, H> MerkleTree<TableType, StorageType, H>
 where
     TableType: Mappable<Key = Bytes32, Value = Primitive, OwnedValue = Primitive>,
     StorageType: StorageInspect<TableType, Error = StorageError>,
+    H: MerkleHasher,
 {
     pub fn generate_proof(
         &self,
@@ -721,6 +915,18 @@ where
         };
         Ok(proof)
     }
+
+    /// Generates the proof that the `key` isn't included into the tree, or returns
+    /// `None` if it is.
+    pub fn generate_exclusion_proof(
+        &self,
+        key: &MerkleTreeKey,
+    ) -> Result<Option<ExclusionProof>, MerkleTreeError<StorageError>> {
+        match self.generate_proof(key)? {
+            Proof::Exclusion(proof) => Ok(Some(proof)),
+            Proof::Inclusion(_) => Ok(None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -731,6 +937,7 @@ mod test {
         common::{
             sum,
             Bytes32,
+            Sha256Hasher,
             StorageMap,
         },
         sparse::{
@@ -1206,11 +1413,11 @@ mod test {
 
         let leaf_1_key = key(b"\x00\x00\x00\x00");
         let leaf_1_data = b"DATA_1";
-        let leaf_1 = Node::create_leaf(&leaf_1_key.0, leaf_1_data);
+        let leaf_1 = Node::create_leaf::<Sha256Hasher, _>(&leaf_1_key.0, leaf_1_data);
 
         let leaf_2_key = MerkleTreeKey::new_without_hash(*leaf_1.hash());
         let leaf_2_data = b"DATA_2";
-        let leaf_2 = Node::create_leaf(&leaf_2_key.0, leaf_2_data);
+        let leaf_2 = Node::create_leaf::<Sha256Hasher, _>(&leaf_2_key.0, leaf_2_data);
 
         tree.update(leaf_2_key, leaf_2_data).unwrap();
         tree.update(leaf_1_key, leaf_1_data).unwrap();
@@ -1545,14 +1752,14 @@ mod test {
         tree.update(MerkleTreeKey::new_without_hash(k3), &v3)
             .expect("Expected successful update");
 
-        let l0 = Node::create_leaf(&k0, v0);
-        let l1 = Node::create_leaf(&k1, v1);
-        let l2 = Node::create_leaf(&k2, v2);
-        let l3 = Node::create_leaf(&k3, v3);
-        let n0 = Node::create_node(&l1, &l3, 252);
-        let n1 = Node::create_node(&n0, &Node::create_placeholder(), 253);
-        let n2 = Node::create_node(&n1, &l2, 254);
-        let n3 = Node::create_node(&l0, &n2, 255);
+        let l0 = Node::create_leaf::<Sha256Hasher, _>(&k0, v0);
+        let l1 = Node::create_leaf::<Sha256Hasher, _>(&k1, v1);
+        let l2 = Node::create_leaf::<Sha256Hasher, _>(&k2, v2);
+        let l3 = Node::create_leaf::<Sha256Hasher, _>(&k3, v3);
+        let n0 = Node::create_node::<Sha256Hasher>(&l1, &l3, 252);
+        let n1 = Node::create_node::<Sha256Hasher>(&n0, &Node::create_placeholder(), 253);
+        let n2 = Node::create_node::<Sha256Hasher>(&n1, &l2, 254);
+        let n3 = Node::create_node::<Sha256Hasher>(&l0, &n2, 255);
 
         {
             // When
@@ -1715,4 +1922,114 @@ mod test {
         // Then
         assert!(proof.is_exclusion());
     }
+
+    fn sequential_and_batch_trees(
+        initial: &[(MerkleTreeKey, Bytes32)],
+        updates: &[(MerkleTreeKey, Vec<u8>)],
+    ) -> (
+        MerkleTree<TestTable, StorageMap<TestTable>>,
+        MerkleTree<TestTable, StorageMap<TestTable>>,
+    ) {
+        let mut sequential = MerkleTree::new(StorageMap::<TestTable>::new());
+        let mut batch = MerkleTree::new(StorageMap::<TestTable>::new());
+        for (key, data) in initial {
+            sequential.update(*key, data).unwrap();
+            batch.update(*key, data).unwrap();
+        }
+
+        for (key, data) in updates {
+            sequential.update(*key, data).unwrap();
+        }
+        batch
+            .update_batch(updates.iter().map(|(key, data)| (*key, data)))
+            .unwrap();
+
+        (sequential, batch)
+    }
+
+    #[test]
+    fn test_update_batch_matches_sequential_updates() {
+        use rand::{
+            rngs::StdRng,
+            SeedableRng,
+        };
+        let rng = &mut StdRng::seed_from_u64(0x5eed);
+
+        let initial = (0..100)
+            .map(|_| {
+                (
+                    MerkleTreeKey::new_without_hash(random_bytes32(rng)),
+                    random_bytes32(rng),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Overwrite, delete and insert keys, with a key updated twice and keys
+        // sharing long prefixes.
+        let mut updates = Vec::new();
+        for (key, _) in initial.iter().take(30) {
+            updates.push((*key, random_bytes32(rng).to_vec()));
+        }
+        for (key, _) in initial.iter().skip(30).take(30) {
+            updates.push((*key, Vec::new()));
+        }
+        for i in 0..20u8 {
+            let mut key = random_bytes32(rng);
+            key[..30].copy_from_slice(&[i % 2; 30]);
+            updates.push((MerkleTreeKey::new_without_hash(key), vec![i; 8]));
+        }
+        updates.push((initial[0].0, Vec::new()));
+        updates.push((initial[0].0, b"DATA".to_vec()));
+
+        let (sequential, batch) = sequential_and_batch_trees(&initial, &updates);
+
+        assert_eq!(batch.root(), sequential.root());
+        assert_eq!(batch.storage().len(), sequential.storage().len());
+        let loaded = MerkleTree::<TestTable, _>::load(batch.storage(), &batch.root());
+        assert!(loaded.is_ok());
+    }
+
+    #[test]
+    fn test_update_batch_on_empty_tree_matches_from_set() {
+        let updates = (0..10u8)
+            .map(|i| (key([i]), vec![i; 4]))
+            .collect::<Vec<_>>();
+
+        let (_, batch) = sequential_and_batch_trees(&[], &updates);
+        let from_set = MerkleTree::<TestTable, _>::from_set(
+            StorageMap::<TestTable>::new(),
+            updates.iter().map(|(key, data)| (*key, data)),
+        )
+        .unwrap();
+
+        assert_eq!(batch.root(), from_set.root());
+        assert_eq!(batch.storage().len(), from_set.storage().len());
+    }
+
+    #[test]
+    fn test_update_batch_deleting_all_keys_yields_empty_root() {
+        let initial = (0..5u8).map(|i| (key([i]), sum(&[i]))).collect::<Vec<_>>();
+        let updates = initial
+            .iter()
+            .map(|(key, _)| (*key, Vec::new()))
+            .collect::<Vec<_>>();
+
+        let (_, batch) = sequential_and_batch_trees(&initial, &updates);
+
+        assert_eq!(batch.root(), *empty_sum());
+        assert!(batch.storage().is_empty());
+    }
+
+    #[test]
+    fn test_update_batch_with_the_same_values_does_not_change_the_tree() {
+        let initial = (0..5u8).map(|i| (key([i]), sum(&[i]))).collect::<Vec<_>>();
+        let updates = initial
+            .iter()
+            .map(|(key, data)| (*key, data.to_vec()))
+            .collect::<Vec<_>>();
+
+        let (sequential, batch) = sequential_and_batch_trees(&initial, &updates);
+
+        assert_eq!(batch.root(), sequential.root());
+        assert_eq!(batch.storage().len(), sequential.storage().len());
+    }
 }
@@ -3,6 +3,7 @@ use crate::{
     common::{
         path::Path,
         Bytes32,
+        MerkleHasher,
     },
     sparse::Primitive,
 };
@@ -27,12 +28,13 @@ impl From<Node> for Branch {
     }
 }
 
-pub(super) fn merge_branches<Storage, Table>(
+pub(super) fn merge_branches<H, Storage, Table>(
     storage: &mut Storage,
     mut left_branch: Branch,
     mut right_branch: Branch,
 ) -> Result<Branch, Storage::Error>
 where
+    H: MerkleHasher,
     Storage: StorageMutate<Table>,
     Table: Mappable<Key = Bytes32, Value = Primitive, OwnedValue = Primitive>,
 {
@@ -61,14 +63,15 @@ where
                     iter::repeat(Node::create_placeholder()).take(stale_depth as usize);
                 for placeholder in placeholders {
                     branch.node =
-                        Node::create_node_on_path(&path, &branch.node, &placeholder);
+                        Node::create_node_on_path::<H>(&path, &branch.node, &placeholder);
                     storage.insert(branch.node.hash(), &branch.node.as_ref().into())?;
                 }
             }
         }
         ancestor_height
     };
-    let node = Node::create_node(&left_branch.node, &right_branch.node, ancestor_height);
+    let node =
+        Node::create_node::<H>(&left_branch.node, &right_branch.node, ancestor_height);
     storage.insert(node.hash(), &node.as_ref().into())?;
     Ok(Branch {
         bits: left_branch.bits,
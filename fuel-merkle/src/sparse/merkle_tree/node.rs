@@ -11,16 +11,11 @@ use crate::{
             Path,
             Side,
         },
-        sum,
         Bytes32,
+        MerkleHasher,
         Prefix,
     },
     sparse::{
-        hash::{
-            calculate_hash,
-            calculate_leaf_hash,
-            calculate_node_hash,
-        },
         primitive::{
             Primitive,
             PrimitiveView,
@@ -56,14 +51,18 @@ impl Node {
         Node::key_size_bits()
     }
 
-    pub fn new(
+    pub fn new<H: MerkleHasher>(
         height: u32,
         prefix: Prefix,
         bytes_lo: Bytes32,
         bytes_hi: Bytes32,
     ) -> Self {
+        let hash = match prefix {
+            Prefix::Leaf => H::leaf_sum(&[&bytes_lo, &bytes_hi]),
+            Prefix::Node => H::node_sum(&bytes_lo, &bytes_hi),
+        };
         Self::Node {
-            hash: calculate_hash(&prefix, &bytes_lo, &bytes_hi),
+            hash,
             height,
             prefix,
             bytes_lo,
@@ -71,10 +70,10 @@ impl Node {
         }
     }
 
-    pub fn create_leaf<D: AsRef<[u8]>>(key: &Bytes32, data: D) -> Self {
-        let bytes_hi = sum(data);
+    pub fn create_leaf<H: MerkleHasher, D: AsRef<[u8]>>(key: &Bytes32, data: D) -> Self {
+        let bytes_hi = H::hash(data.as_ref());
         Self::Node {
-            hash: calculate_leaf_hash(key, &bytes_hi),
+            hash: H::leaf_sum(&[key, &bytes_hi]),
             height: 0u32,
             prefix: Prefix::Leaf,
             bytes_lo: *key,
@@ -82,11 +81,15 @@ impl Node {
         }
     }
 
-    pub fn create_node(left_child: &Node, right_child: &Node, height: u32) -> Self {
+    pub fn create_node<H: MerkleHasher>(
+        left_child: &Node,
+        right_child: &Node,
+        height: u32,
+    ) -> Self {
         let bytes_lo = *left_child.hash();
         let bytes_hi = *right_child.hash();
         Self::Node {
-            hash: calculate_node_hash(&bytes_lo, &bytes_hi),
+            hash: H::node_sum(&bytes_lo, &bytes_hi),
             height,
             prefix: Prefix::Node,
             bytes_lo,
@@ -94,13 +97,13 @@ impl Node {
         }
     }
 
-    pub fn create_node_from_hashes(
+    pub fn create_node_from_hashes<H: MerkleHasher>(
         bytes_lo: Bytes32,
         bytes_hi: Bytes32,
         height: u32,
     ) -> Self {
         Self::Node {
-            hash: calculate_node_hash(&bytes_lo, &bytes_hi),
+            hash: H::node_sum(&bytes_lo, &bytes_hi),
             height,
             prefix: Prefix::Node,
             bytes_lo,
@@ -108,7 +111,7 @@ impl Node {
         }
     }
 
-    pub fn create_node_on_path(
+    pub fn create_node_on_path<H: MerkleHasher>(
         path: &dyn Path,
         path_node: &Node,
         side_node: &Node,
@@ -123,8 +126,10 @@ impl Node {
             #[allow(clippy::arithmetic_side_effects)] // parent_depth <= max_height
             let parent_height = Node::max_height() - parent_depth;
             match path.get_instruction(parent_depth).unwrap() {
-                Side::Left => Node::create_node(path_node, side_node, parent_height),
-                Side::Right => Node::create_node(side_node, path_node, parent_height),
+                Side::Left => Node::create_node::<H>(path_node, side_node, parent_height),
+                Side::Right => {
+                    Node::create_node::<H>(side_node, path_node, parent_height)
+                }
             }
         } else {
             // When joining two nodes, or a node and a leaf, the joined node is
@@ -136,8 +141,10 @@ impl Node {
             #[allow(clippy::arithmetic_side_effects)] // parent_height <= max_height
             let parent_depth = Node::max_height() - parent_height;
             match path.get_instruction(parent_depth).unwrap() {
-                Side::Left => Node::create_node(path_node, side_node, parent_height),
-                Side::Right => Node::create_node(side_node, path_node, parent_height),
+                Side::Left => Node::create_node::<H>(path_node, side_node, parent_height),
+                Side::Right => {
+                    Node::create_node::<H>(side_node, path_node, parent_height)
+                }
             }
         }
     }
@@ -315,15 +322,16 @@ impl From<&Node> for Primitive {
     }
 }
 
-impl TryFrom<Primitive> for Node {
-    type Error = DeserializeError;
-
-    fn try_from(primitive: Primitive) -> Result<Self, Self::Error> {
+impl Node {
+    /// Deserializes the node, recomputing its hash with `H`.
+    pub fn from_primitive<H: MerkleHasher>(
+        primitive: Primitive,
+    ) -> Result<Self, DeserializeError> {
         let height = primitive.height();
         let prefix = primitive.prefix()?;
         let bytes_lo = *primitive.bytes_lo();
         let bytes_hi = *primitive.bytes_hi();
-        let node = Self::new(height, prefix, bytes_lo, bytes_hi);
+        let node = Self::new::<H>(height, prefix, bytes_lo, bytes_hi);
         Ok(node)
     }
 }
@@ -348,33 +356,36 @@ impl fmt::Debug for Node {
     }
 }
 
-pub(super) struct StorageNode<'storage, TableType, StorageType> {
+pub(super) struct StorageNode<'storage, TableType, StorageType, H> {
     storage: &'storage StorageType,
     node: Node,
     phantom_table: PhantomData<TableType>,
+    phantom_hasher: PhantomData<H>,
 }
 
-impl<TableType, StorageType> Clone for StorageNode<'_, TableType, StorageType> {
+impl<TableType, StorageType, H> Clone for StorageNode<'_, TableType, StorageType, H> {
     fn clone(&self) -> Self {
         Self {
             storage: self.storage,
             node: self.node.clone(),
             phantom_table: Default::default(),
+            phantom_hasher: Default::default(),
         }
     }
 }
 
-impl<'s, TableType, StorageType> StorageNode<'s, TableType, StorageType> {
+impl<'s, TableType, StorageType, H> StorageNode<'s, TableType, StorageType, H> {
     pub fn new(storage: &'s StorageType, node: Node) -> Self {
         Self {
             node,
             storage,
             phantom_table: Default::default(),
+            phantom_hasher: Default::default(),
         }
     }
 }
 
-impl<TableType, StorageType> StorageNode<'_, TableType, StorageType> {
+impl<TableType, StorageType, H> StorageNode<'_, TableType, StorageType, H> {
     pub fn hash(&self) -> &Bytes32 {
         self.node.hash()
     }
@@ -384,7 +395,7 @@ impl<TableType, StorageType> StorageNode<'_, TableType, StorageType> {
     }
 }
 
-impl<TableType, StorageType> NodeTrait for StorageNode<'_, TableType, StorageType> {
+impl<TableType, StorageType, H> NodeTrait for StorageNode<'_, TableType, StorageType, H> {
     type Key = Bytes32;
 
     fn height(&self) -> u32 {
@@ -417,10 +428,12 @@ pub enum StorageNodeError<StorageError> {
     DeserializeError(DeserializeError),
 }
 
-impl<TableType, StorageType> ParentNodeTrait for StorageNode<'_, TableType, StorageType>
+impl<TableType, StorageType, H> ParentNodeTrait
+    for StorageNode<'_, TableType, StorageType, H>
 where
     StorageType: StorageInspect<TableType>,
     TableType: Mappable<Key = Bytes32, Value = Primitive, OwnedValue = Primitive>,
+    H: MerkleHasher,
 {
     type ChildKey = Bytes32;
     type Error = StorageNodeError<StorageType::Error>;
@@ -442,9 +455,7 @@ where
             .get(key)
             .map_err(StorageNodeError::StorageError)?
             .ok_or(ChildError::ChildNotFound(*key))?;
-        Ok(primitive
-            .into_owned()
-            .try_into()
+        Ok(Node::from_primitive::<H>(primitive.into_owned())
             .map(|node| Self::new(self.storage, node))
             .map_err(StorageNodeError::DeserializeError)?)
     }
@@ -469,9 +480,7 @@ where
             .get(key)
             .map_err(StorageNodeError::StorageError)?
             .ok_or(ChildError::ChildNotFound(*key))?;
-        Ok(primitive
-            .into_owned()
-            .try_into()
+        Ok(Node::from_primitive::<H>(primitive.into_owned())
             .map(|node| Self::new(self.storage, node))
             .map_err(StorageNodeError::DeserializeError)?)
     }
@@ -484,7 +493,7 @@ where
     }
 }
 
-impl<TableType, StorageType> fmt::Debug for StorageNode<'_, TableType, StorageType>
+impl<TableType, StorageType, H> fmt::Debug for StorageNode<'_, TableType, StorageType, H>
 where
     StorageType: StorageInspect<TableType>,
     TableType: Mappable<Key = Bytes32, Value = Primitive, OwnedValue = Primitive>,
@@ -518,6 +527,7 @@ mod test_node {
             Bytes32,
             Prefix,
             PrefixError,
+            Sha256Hasher,
         },
         sparse::{
             zero_sum,
@@ -535,7 +545,7 @@ mod test_node {
 
     #[test]
     fn test_create_leaf_returns_a_valid_leaf() {
-        let leaf = Node::create_leaf(&sum(b"LEAF"), [1u8; 32]);
+        let leaf = Node::create_leaf::<Sha256Hasher, _>(&sum(b"LEAF"), [1u8; 32]);
         assert_eq!(leaf.is_leaf(), true);
         assert_eq!(leaf.is_node(), false);
         assert_eq!(leaf.height(), 0);
@@ -546,9 +556,11 @@ mod test_node {
 
     #[test]
     fn test_create_node_returns_a_valid_node() {
-        let left_child = Node::create_leaf(&sum(b"LEFT CHILD"), [1u8; 32]);
-        let right_child = Node::create_leaf(&sum(b"RIGHT CHILD"), [1u8; 32]);
-        let node = Node::create_node(&left_child, &right_child, 1);
+        let left_child =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"LEFT CHILD"), [1u8; 32]);
+        let right_child =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"RIGHT CHILD"), [1u8; 32]);
+        let node = Node::create_node::<Sha256Hasher>(&left_child, &right_child, 1);
         assert_eq!(node.is_leaf(), false);
         assert_eq!(node.is_node(), true);
         assert_eq!(node.height(), 1);
@@ -574,7 +586,7 @@ mod test_node {
     fn test_create_leaf_from_primitive_returns_a_valid_leaf() {
         let primitive = (0, Prefix::Leaf as u8, [0xff; 32], [0xff; 32]);
 
-        let node: Node = primitive.try_into().unwrap();
+        let node: Node = Node::from_primitive::<Sha256Hasher>(primitive).unwrap();
         assert_eq!(node.is_leaf(), true);
         assert_eq!(node.is_node(), false);
         assert_eq!(node.height(), 0);
@@ -587,7 +599,7 @@ mod test_node {
     fn test_create_node_from_primitive_returns_a_valid_node() {
         let primitive = (255, Prefix::Node as u8, [0xff; 32], [0xff; 32]);
 
-        let node: Node = primitive.try_into().unwrap();
+        let node: Node = Node::from_primitive::<Sha256Hasher>(primitive).unwrap();
         assert_eq!(node.is_leaf(), false);
         assert_eq!(node.is_node(), true);
         assert_eq!(node.height(), 255);
@@ -601,7 +613,7 @@ mod test_node {
         let primitive = (0xff, 0xff, [0xff; 32], [0xff; 32]);
 
         // Should return Error; prefix 0xff is does not represent a node or leaf
-        let err = Node::try_from(primitive)
+        let err = Node::from_primitive::<Sha256Hasher>(primitive)
             .expect_err("Expected try_from() to be Error; got OK");
         assert!(matches!(
             err,
@@ -616,7 +628,7 @@ mod test_node {
         let expected_primitive =
             (0_u32, Prefix::Leaf as u8, sum(b"LEAF"), sum([1u8; 32]));
 
-        let leaf = Node::create_leaf(&sum(b"LEAF"), [1u8; 32]);
+        let leaf = Node::create_leaf::<Sha256Hasher, _>(&sum(b"LEAF"), [1u8; 32]);
         let primitive = Primitive::from(&leaf);
 
         assert_eq!(primitive, expected_primitive);
@@ -633,9 +645,11 @@ mod test_node {
             leaf_hash(&sum(b"RIGHT CHILD"), &[1u8; 32]),
         );
 
-        let left_child = Node::create_leaf(&sum(b"LEFT CHILD"), [1u8; 32]);
-        let right_child = Node::create_leaf(&sum(b"RIGHT CHILD"), [1u8; 32]);
-        let node = Node::create_node(&left_child, &right_child, 1);
+        let left_child =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"LEFT CHILD"), [1u8; 32]);
+        let right_child =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"RIGHT CHILD"), [1u8; 32]);
+        let node = Node::create_node::<Sha256Hasher>(&left_child, &right_child, 1);
         let primitive = Primitive::from(&node);
 
         assert_eq!(primitive, expected_primitive);
@@ -651,7 +665,7 @@ mod test_node {
         expected_buffer[33..65].clone_from_slice(&sum([1u8; 32]));
         let expected_value = sum(expected_buffer);
 
-        let node = Node::create_leaf(&sum(b"LEAF"), [1u8; 32]);
+        let node = Node::create_leaf::<Sha256Hasher, _>(&sum(b"LEAF"), [1u8; 32]);
         let value = *node.hash();
 
         assert_eq!(value, expected_value);
@@ -669,9 +683,11 @@ mod test_node {
             .clone_from_slice(&leaf_hash(&sum(b"RIGHT CHILD"), &[1u8; 32]));
         let expected_value = sum(expected_buffer);
 
-        let left_child = Node::create_leaf(&sum(b"LEFT CHILD"), [1u8; 32]);
-        let right_child = Node::create_leaf(&sum(b"RIGHT CHILD"), [1u8; 32]);
-        let node = Node::create_node(&left_child, &right_child, 1);
+        let left_child =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"LEFT CHILD"), [1u8; 32]);
+        let right_child =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"RIGHT CHILD"), [1u8; 32]);
+        let node = Node::create_node::<Sha256Hasher>(&left_child, &right_child, 1);
         let value = *node.hash();
 
         assert_eq!(value, expected_value);
@@ -695,6 +711,7 @@ mod test_storage_node {
             sum,
             Bytes32,
             PrefixError,
+            Sha256Hasher,
             StorageMap,
         },
         sparse::Primitive,
@@ -717,16 +734,18 @@ mod test_storage_node {
     fn test_node_left_child_returns_the_left_child() {
         let mut s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [1u8; 32]);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [1u8; 32]);
         let _ = s.insert(leaf_0.hash(), &leaf_0.as_ref().into());
 
-        let leaf_1 = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
+        let leaf_1 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
         let _ = s.insert(leaf_1.hash(), &leaf_1.as_ref().into());
 
-        let node_0 = Node::create_node(&leaf_0, &leaf_1, 1);
+        let node_0 = Node::create_node::<Sha256Hasher>(&leaf_0, &leaf_1, 1);
         let _ = s.insert(node_0.hash(), &node_0.as_ref().into());
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let child = storage_node.left_child().unwrap();
 
         assert_eq!(child.hash(), leaf_0.hash());
@@ -736,16 +755,18 @@ mod test_storage_node {
     fn test_node_right_child_returns_the_right_child() {
         let mut s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [1u8; 32]);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [1u8; 32]);
         let _ = s.insert(leaf_0.hash(), &leaf_0.as_ref().into());
 
-        let leaf_1 = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
+        let leaf_1 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
         let _ = s.insert(leaf_1.hash(), &leaf_1.as_ref().into());
 
-        let node_0 = Node::create_node(&leaf_0, &leaf_1, 1);
+        let node_0 = Node::create_node::<Sha256Hasher>(&leaf_0, &leaf_1, 1);
         let _ = s.insert(node_0.hash(), &node_0.as_ref().into());
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let child = storage_node.right_child().unwrap();
 
         assert_eq!(child.hash(), leaf_1.hash());
@@ -755,13 +776,15 @@ mod test_storage_node {
     fn test_node_left_child_returns_placeholder_when_key_is_zero_sum() {
         let mut s = StorageMap::<TestTable>::new();
 
-        let leaf = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
+        let leaf =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
         let _ = s.insert(leaf.hash(), &leaf.as_ref().into());
 
-        let node_0 = Node::create_node(&Node::create_placeholder(), &leaf, 1);
+        let node_0 =
+            Node::create_node::<Sha256Hasher>(&Node::create_placeholder(), &leaf, 1);
         let _ = s.insert(node_0.hash(), &node_0.as_ref().into());
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let child = storage_node.left_child().unwrap();
 
         assert!(child.node.is_placeholder());
@@ -771,13 +794,15 @@ mod test_storage_node {
     fn test_node_right_child_returns_placeholder_when_key_is_zero_sum() {
         let mut s = StorageMap::<TestTable>::new();
 
-        let leaf = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
+        let leaf =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
         let _ = s.insert(leaf.hash(), &leaf.as_ref().into());
 
-        let node_0 = Node::create_node(&leaf, &Node::create_placeholder(), 1);
+        let node_0 =
+            Node::create_node::<Sha256Hasher>(&leaf, &Node::create_placeholder(), 1);
         let _ = s.insert(node_0.hash(), &node_0.as_ref().into());
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let child = storage_node.right_child().unwrap();
 
         assert!(child.node.is_placeholder());
@@ -787,8 +812,9 @@ mod test_storage_node {
     fn test_node_left_child_returns_error_when_node_is_leaf() {
         let s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [1u8; 32]);
-        let storage_node = StorageNode::new(&s, leaf_0);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [1u8; 32]);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, leaf_0);
         let err = storage_node
             .left_child()
             .expect_err("Expected left_child() to return Error; got OK");
@@ -800,8 +826,9 @@ mod test_storage_node {
     fn test_node_right_child_returns_error_when_node_is_leaf() {
         let s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [1u8; 32]);
-        let storage_node = StorageNode::new(&s, leaf_0);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [1u8; 32]);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, leaf_0);
         let err = storage_node
             .right_child()
             .expect_err("Expected right_child() to return Error; got OK");
@@ -813,11 +840,13 @@ mod test_storage_node {
     fn test_node_left_child_returns_error_when_key_is_not_found() {
         let s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [0u8; 32]);
-        let leaf_1 = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
-        let node_0 = Node::create_node(&leaf_0, &leaf_1, 1);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [0u8; 32]);
+        let leaf_1 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
+        let node_0 = Node::create_node::<Sha256Hasher>(&leaf_0, &leaf_1, 1);
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let err = storage_node
             .left_child()
             .expect_err("Expected left_child() to return Error; got Ok");
@@ -833,11 +862,13 @@ mod test_storage_node {
     fn test_node_right_child_returns_error_when_key_is_not_found() {
         let s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [1u8; 32]);
-        let leaf_1 = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
-        let node_0 = Node::create_node(&leaf_0, &leaf_1, 1);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [1u8; 32]);
+        let leaf_1 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
+        let node_0 = Node::create_node::<Sha256Hasher>(&leaf_0, &leaf_1, 1);
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let err = storage_node
             .right_child()
             .expect_err("Expected right_child() to return Error; got Ok");
@@ -853,12 +884,14 @@ mod test_storage_node {
     fn test_node_left_child_returns_deserialize_error_when_primitive_is_invalid() {
         let mut s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [1u8; 32]);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [1u8; 32]);
         let _ = s.insert(leaf_0.hash(), &(0xff, 0xff, [0xff; 32], [0xff; 32]));
-        let leaf_1 = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
-        let node_0 = Node::create_node(&leaf_0, &leaf_1, 1);
+        let leaf_1 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
+        let node_0 = Node::create_node::<Sha256Hasher>(&leaf_0, &leaf_1, 1);
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let err = storage_node
             .left_child()
             .expect_err("Expected left_child() to be Error; got Ok");
@@ -875,12 +908,14 @@ mod test_storage_node {
     fn test_node_right_child_returns_deserialize_error_when_primitive_is_invalid() {
         let mut s = StorageMap::<TestTable>::new();
 
-        let leaf_0 = Node::create_leaf(&sum(b"Hello World"), [1u8; 32]);
-        let leaf_1 = Node::create_leaf(&sum(b"Goodbye World"), [1u8; 32]);
+        let leaf_0 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Hello World"), [1u8; 32]);
+        let leaf_1 =
+            Node::create_leaf::<Sha256Hasher, _>(&sum(b"Goodbye World"), [1u8; 32]);
         let _ = s.insert(leaf_1.hash(), &(0xff, 0xff, [0xff; 32], [0xff; 32]));
-        let node_0 = Node::create_node(&leaf_0, &leaf_1, 1);
+        let node_0 = Node::create_node::<Sha256Hasher>(&leaf_0, &leaf_1, 1);
 
-        let storage_node = StorageNode::new(&s, node_0);
+        let storage_node = StorageNode::<_, _, Sha256Hasher>::new(&s, node_0);
         let err = storage_node
             .right_child()
             .expect_err("Expected right_child() to be Error; got Ok");
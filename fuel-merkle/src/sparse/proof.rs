@@ -4,15 +4,12 @@ use crate::{
             Path,
             Side,
         },
-        sum,
         Bytes32,
+        MerkleHasher,
         ProofSet,
+        Sha256Hasher,
     },
     sparse::{
-        hash::{
-            calculate_leaf_hash,
-            calculate_node_hash,
-        },
         zero_sum,
         MerkleTreeKey,
     },
@@ -57,20 +54,30 @@ pub struct InclusionProof {
 
 impl InclusionProof {
     pub fn verify(&self, root: &Bytes32, key: &MerkleTreeKey, value: &[u8]) -> bool {
+        self.verify_with_hasher::<Sha256Hasher>(root, key, value)
+    }
+
+    /// Same as [`verify`](Self::verify), for the trees hashed with `H`.
+    pub fn verify_with_hasher<H: MerkleHasher>(
+        &self,
+        root: &Bytes32,
+        key: &MerkleTreeKey,
+        value: &[u8],
+    ) -> bool {
         let Self { proof_set } = self;
 
         if proof_set.len() > 256usize {
             return false;
         }
 
-        let mut current = calculate_leaf_hash(key, &sum(value));
+        let mut current = H::leaf_sum(&[key.as_ref(), &H::hash(value)]);
         for (i, side_hash) in proof_set.iter().enumerate() {
             #[allow(clippy::arithmetic_side_effects)] // Cannot underflow
             let index =
                 u32::try_from(proof_set.len() - 1 - i).expect("We've checked it above");
             current = match key.get_instruction(index).expect("Infallible") {
-                Side::Left => calculate_node_hash(&current, side_hash),
-                Side::Right => calculate_node_hash(side_hash, &current),
+                Side::Left => H::node_sum(&current, side_hash),
+                Side::Right => H::node_sum(side_hash, &current),
             };
         }
         current == *root
@@ -110,16 +117,38 @@ impl Debug for ExclusionLeafData {
 }
 
 impl ExclusionLeaf {
-    fn hash(&self) -> Bytes32 {
+    fn hash<H: MerkleHasher>(&self) -> Bytes32 {
         match self {
-            ExclusionLeaf::Leaf(data) => {
-                calculate_leaf_hash(&data.leaf_key, &data.leaf_value)
-            }
+            ExclusionLeaf::Leaf(data) => H::leaf_sum(&[&data.leaf_key, &data.leaf_value]),
             ExclusionLeaf::Placeholder => *zero_sum(),
         }
     }
 }
 
+/// The error of decoding an [`ExclusionProof`] with [`ExclusionProof::from_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum ProofDecodeError {
+    #[display(fmt = "the proof ends unexpectedly")]
+    UnexpectedEnd,
+    #[display(fmt = "the leaf tag {_0} is not valid")]
+    InvalidLeafTag(u8),
+    #[display(fmt = "the proof set of {_0} side nodes is too long")]
+    ProofSetTooLong(u16),
+    #[display(fmt = "the proof has trailing bytes")]
+    TrailingBytes,
+}
+
+/// Proves that a key isn't included into the tree, i.e. its path ends at a
+/// placeholder or at the leaf of another key.
+///
+/// The proof is encoded by [`to_bytes`](Self::to_bytes) as:
+///
+/// | Bytes    | Content                                                      |
+/// |----------|--------------------------------------------------------------|
+/// | 1        | leaf tag: `0` for a placeholder, `1` for a leaf              |
+/// | 0 or 64  | for a leaf: its key, followed by the hash of its value       |
+/// | 2        | number `n` of side nodes, big-endian, at most 256            |
+/// | `32 * n` | side nodes, ordered from the leaf to the root                |
 #[derive(Clone, Eq, PartialEq)]
 pub struct ExclusionProof {
     pub proof_set: ProofSet,
@@ -127,7 +156,75 @@ pub struct ExclusionProof {
 }
 
 impl ExclusionProof {
+    const LEAF_TAG: u8 = 1;
+    const PLACEHOLDER_TAG: u8 = 0;
+
+    /// Encodes the proof, see the [type](Self) docs for the encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            67usize.saturating_add(self.proof_set.len().saturating_mul(32)),
+        );
+        match &self.leaf {
+            ExclusionLeaf::Leaf(data) => {
+                bytes.push(Self::LEAF_TAG);
+                bytes.extend_from_slice(&data.leaf_key);
+                bytes.extend_from_slice(&data.leaf_value);
+            }
+            ExclusionLeaf::Placeholder => bytes.push(Self::PLACEHOLDER_TAG),
+        }
+        #[allow(clippy::cast_possible_truncation)] // the proof set has 256 nodes at most
+        bytes.extend_from_slice(&(self.proof_set.len() as u16).to_be_bytes());
+        for side_node in &self.proof_set {
+            bytes.extend_from_slice(side_node);
+        }
+        bytes
+    }
+
+    /// Decodes the proof encoded by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProofDecodeError> {
+        fn take<'a, const N: usize>(
+            bytes: &mut &'a [u8],
+        ) -> Result<&'a [u8; N], ProofDecodeError> {
+            let (head, tail) = bytes
+                .split_first_chunk::<N>()
+                .ok_or(ProofDecodeError::UnexpectedEnd)?;
+            *bytes = tail;
+            Ok(head)
+        }
+
+        let mut bytes = bytes;
+        let leaf = match take::<1>(&mut bytes)? {
+            [Self::PLACEHOLDER_TAG] => ExclusionLeaf::Placeholder,
+            [Self::LEAF_TAG] => ExclusionLeaf::Leaf(ExclusionLeafData {
+                leaf_key: *take::<32>(&mut bytes)?,
+                leaf_value: *take::<32>(&mut bytes)?,
+            }),
+            [tag] => return Err(ProofDecodeError::InvalidLeafTag(*tag)),
+        };
+        let len = u16::from_be_bytes(*take::<2>(&mut bytes)?);
+        if len > 256 {
+            return Err(ProofDecodeError::ProofSetTooLong(len))
+        }
+        let proof_set = (0..len)
+            .map(|_| take::<32>(&mut bytes).copied())
+            .collect::<Result<ProofSet, _>>()?;
+        if !bytes.is_empty() {
+            return Err(ProofDecodeError::TrailingBytes)
+        }
+
+        Ok(Self { proof_set, leaf })
+    }
+
     pub fn verify(&self, root: &Bytes32, key: &MerkleTreeKey) -> bool {
+        self.verify_with_hasher::<Sha256Hasher>(root, key)
+    }
+
+    /// Same as [`verify`](Self::verify), for the trees hashed with `H`.
+    pub fn verify_with_hasher<H: MerkleHasher>(
+        &self,
+        root: &Bytes32,
+        key: &MerkleTreeKey,
+    ) -> bool {
         let Self { proof_set, leaf } = self;
 
         if let ExclusionLeaf::Leaf(data) = leaf {
@@ -140,14 +237,14 @@ impl ExclusionProof {
             return false;
         }
 
-        let mut current = leaf.hash();
+        let mut current = leaf.hash::<H>();
         for (i, side_hash) in proof_set.iter().enumerate() {
             #[allow(clippy::arithmetic_side_effects)] // Cannot underflow
             let index =
                 u32::try_from(proof_set.len() - 1 - i).expect("We've checked it above");
             current = match key.get_instruction(index).expect("Infallible") {
-                Side::Left => calculate_node_hash(&current, side_hash),
-                Side::Right => calculate_node_hash(side_hash, &current),
+                Side::Left => H::node_sum(&current, side_hash),
+                Side::Right => H::node_sum(side_hash, &current),
             };
         }
         current == *root
@@ -618,7 +715,12 @@ mod test_random {
             StorageMap,
         },
         sparse::{
-            proof::Proof,
+            proof::{
+                ExclusionLeaf,
+                ExclusionProof,
+                Proof,
+                ProofDecodeError,
+            },
             MerkleTree,
             MerkleTreeKey,
             Primitive,
@@ -797,4 +899,82 @@ mod test_random {
         // Then
         assert!(exclusion);
     }
+
+    #[test]
+    fn exclusion_proof__from_bytes__decodes_the_encoded_proof() {
+        let mut rng = StdRng::seed_from_u64(0xDEADBEEF);
+        let mut storage = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage);
+
+        for _ in 0..100 {
+            let key = random_bytes32(&mut rng);
+            let value = random_bytes32(&mut rng);
+            tree.update(key.into(), &value).unwrap();
+        }
+        let root = tree.root();
+
+        // Given
+        let key: MerkleTreeKey = random_bytes32(&mut rng).into();
+        let proof = tree.generate_exclusion_proof(&key).unwrap().unwrap();
+
+        // When
+        let bytes = proof.to_bytes();
+        let decoded = ExclusionProof::from_bytes(&bytes).unwrap();
+
+        // Then
+        assert_eq!(decoded, proof);
+        assert!(decoded.verify(&root, &key));
+    }
+
+    #[test]
+    fn exclusion_proof__from_bytes__decodes_the_placeholder_leaf() {
+        // Given
+        let proof = ExclusionProof {
+            proof_set: vec![[1; 32], [2; 32]],
+            leaf: ExclusionLeaf::Placeholder,
+        };
+
+        // When
+        let bytes = proof.to_bytes();
+
+        // Then
+        assert_eq!(bytes.len(), 1 + 2 + 64);
+        assert_eq!(ExclusionProof::from_bytes(&bytes), Ok(proof));
+    }
+
+    #[test]
+    fn exclusion_proof__from_bytes__rejects_malformed_bytes() {
+        let proof = ExclusionProof {
+            proof_set: vec![[1; 32]],
+            leaf: ExclusionLeaf::Placeholder,
+        };
+        let bytes = proof.to_bytes();
+
+        assert_eq!(
+            ExclusionProof::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(ProofDecodeError::UnexpectedEnd)
+        );
+        assert_eq!(
+            ExclusionProof::from_bytes(&[bytes.as_slice(), &[0]].concat()),
+            Err(ProofDecodeError::TrailingBytes)
+        );
+        assert_eq!(
+            ExclusionProof::from_bytes(&[2, 0, 0]),
+            Err(ProofDecodeError::InvalidLeafTag(2))
+        );
+        assert_eq!(
+            ExclusionProof::from_bytes(&[0, 1, 1]),
+            Err(ProofDecodeError::ProofSetTooLong(257))
+        );
+    }
+
+    #[test]
+    fn generate_exclusion_proof__returns_none_for_included_key() {
+        let mut storage = StorageMap::<TestTable>::new();
+        let mut tree = MerkleTree::new(&mut storage);
+        let key: MerkleTreeKey = [0u8; 32].into();
+        tree.update(key, b"DATA").unwrap();
+
+        assert_eq!(tree.generate_exclusion_proof(&key).unwrap(), None);
+    }
 }
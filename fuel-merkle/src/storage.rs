@@ -1,6 +1,15 @@
 use alloc::borrow::Cow;
 use core::convert::Infallible;
 
+mod buffered;
+mod historical;
+
+pub use buffered::{
+    BufferedStorage,
+    StorageBatchWrite,
+};
+pub use historical::HistoricalStorage;
+
 // Re-export fuel-storage traits
 pub use fuel_storage::{
     Mappable,
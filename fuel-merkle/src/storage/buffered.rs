@@ -0,0 +1,208 @@
+use crate::storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+};
+
+use alloc::{
+    borrow::{
+        Cow,
+        ToOwned,
+    },
+    collections::BTreeMap,
+};
+use core::borrow::Borrow;
+
+/// A storage writing the changes of a tree in batches, e.g. a key-value database
+/// writing them in a single transaction.
+pub trait StorageBatchWrite<Type: Mappable>: StorageInspect<Type> {
+    /// Applies the `changes` at once: a `Some` value is inserted, and a `None` value
+    /// removes the key.
+    fn write_batch<I>(&mut self, changes: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Type::OwnedKey, Option<Type::OwnedValue>)>;
+}
+
+impl<Type, S> StorageBatchWrite<Type> for &mut S
+where
+    Type: Mappable,
+    S: StorageBatchWrite<Type> + ?Sized,
+{
+    fn write_batch<I>(&mut self, changes: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Type::OwnedKey, Option<Type::OwnedValue>)>,
+    {
+        <S as StorageBatchWrite<Type>>::write_batch(self, changes)
+    }
+}
+
+/// Buffers the writes of a tree in memory until they are [flushed](Self::flush) to
+/// the underlying storage in a single batch. The reads see the buffered writes.
+///
+/// Dropping the buffer discards the writes that weren't flushed.
+#[derive(Debug, Clone)]
+pub struct BufferedStorage<Type: Mappable, S> {
+    storage: S,
+    changes: BTreeMap<Type::OwnedKey, Option<Type::OwnedValue>>,
+}
+
+impl<Type: Mappable, S> BufferedStorage<Type, S> {
+    /// Buffers the writes to the `storage`.
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            changes: BTreeMap::new(),
+        }
+    }
+
+    /// The underlying storage, without the buffered writes.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// The number of the buffered writes.
+    pub fn pending(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Discards the buffered writes.
+    pub fn discard(&mut self) {
+        self.changes.clear();
+    }
+
+    /// Returns the underlying storage, discarding the buffered writes.
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+}
+
+impl<Type, S> BufferedStorage<Type, S>
+where
+    Type: Mappable,
+    S: StorageBatchWrite<Type>,
+{
+    /// Writes the buffered writes to the underlying storage in a single batch.
+    pub fn flush(&mut self) -> Result<(), S::Error> {
+        let changes = core::mem::take(&mut self.changes);
+        self.storage.write_batch(changes)
+    }
+}
+
+impl<Type, S> StorageInspect<Type> for BufferedStorage<Type, S>
+where
+    Type: Mappable,
+    Type::Key: Ord,
+    Type::OwnedKey: Ord + Borrow<Type::Key>,
+    S: StorageInspect<Type>,
+{
+    type Error = S::Error;
+
+    fn get(&self, key: &Type::Key) -> Result<Option<Cow<Type::OwnedValue>>, Self::Error> {
+        match self.changes.get(key) {
+            Some(value) => Ok(value.as_ref().map(Cow::Borrowed)),
+            None => self.storage.get(key),
+        }
+    }
+
+    fn contains_key(&self, key: &Type::Key) -> Result<bool, Self::Error> {
+        match self.changes.get(key) {
+            Some(value) => Ok(value.is_some()),
+            None => self.storage.contains_key(key),
+        }
+    }
+}
+
+impl<Type, S> StorageMutate<Type> for BufferedStorage<Type, S>
+where
+    Type: Mappable,
+    Type::Key: Ord,
+    Type::OwnedKey: Ord + Borrow<Type::Key>,
+    S: StorageInspect<Type>,
+{
+    fn replace(
+        &mut self,
+        key: &Type::Key,
+        value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        let previous = self.get(key)?.map(Cow::into_owned);
+        self.changes
+            .insert(key.to_owned().into(), Some(value.to_owned().into()));
+        Ok(previous)
+    }
+
+    fn take(&mut self, key: &Type::Key) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        let previous = self.get(key)?.map(Cow::into_owned);
+        self.changes.insert(key.to_owned().into(), None);
+        Ok(previous)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        binary::{
+            self,
+            Primitive,
+        },
+        common::StorageMap,
+    };
+    use fuel_merkle_test_helpers::TEST_DATA;
+
+    #[derive(Debug)]
+    struct TestTable;
+
+    impl Mappable for TestTable {
+        type Key = Self::OwnedKey;
+        type OwnedKey = u64;
+        type OwnedValue = Primitive;
+        type Value = Self::OwnedValue;
+    }
+
+    #[test]
+    fn flush_writes_the_buffered_nodes_to_the_storage() {
+        let mut storage = StorageMap::<TestTable>::new();
+        let mut buffered = BufferedStorage::<TestTable, _>::new(&mut storage);
+        let mut tree = binary::MerkleTree::new(&mut buffered);
+        for datum in TEST_DATA.iter() {
+            tree.push(datum).unwrap();
+        }
+        let root = tree.root();
+        let leaves_count = tree.leaves_count();
+        assert!(buffered.storage().is_empty());
+
+        buffered.flush().unwrap();
+
+        assert_eq!(buffered.pending(), 0);
+        let tree = binary::MerkleTree::load(&storage, leaves_count).unwrap();
+        assert_eq!(tree.root(), root);
+    }
+
+    #[test]
+    fn discard_drops_the_buffered_nodes() {
+        let mut storage = StorageMap::<TestTable>::new();
+        let mut buffered = BufferedStorage::<TestTable, _>::new(&mut storage);
+        let mut tree = binary::MerkleTree::new(&mut buffered);
+        tree.push(TEST_DATA[0]).unwrap();
+        assert_eq!(buffered.pending(), 1);
+
+        buffered.discard();
+        buffered.flush().unwrap();
+
+        assert!(storage.is_empty());
+    }
+
+    #[test]
+    fn get_returns_the_buffered_removal() {
+        let mut storage = StorageMap::<TestTable>::new();
+        let mut tree = binary::MerkleTree::new(&mut storage);
+        tree.push(TEST_DATA[0]).unwrap();
+        let mut buffered = BufferedStorage::<TestTable, _>::new(&mut storage);
+
+        let removed = buffered.take(&0).unwrap();
+
+        assert!(removed.is_some());
+        assert_eq!(buffered.get(&0).unwrap(), None);
+        assert!(buffered.storage().contains_key(&0).unwrap());
+    }
+}
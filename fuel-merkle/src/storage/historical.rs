@@ -0,0 +1,181 @@
+use crate::storage::{
+    Mappable,
+    StorageBatchWrite,
+    StorageInspect,
+    StorageMutate,
+};
+
+use alloc::borrow::Cow;
+
+/// Keeps all the nodes ever written by a tree, ignoring their removals, so the tree
+/// can be opened at any of its historical states to generate the proofs against it.
+///
+/// The nodes of the sparse tree are keyed by their hashes, so a historical root is
+/// opened with [`sparse::MerkleTree::load`](crate::sparse::MerkleTree::load). The
+/// nodes of the binary tree never change once written, so a historical state is
+/// opened with [`binary::MerkleTree::load`](crate::binary::MerkleTree::load) and its
+/// number of leaves, without this wrapper.
+///
+/// Behind a [`BufferedStorage`](crate::storage::BufferedStorage), only the states
+/// at the flushes are kept, as the nodes written and removed between two flushes
+/// never reach the storage.
+#[derive(Debug, Default, Clone)]
+pub struct HistoricalStorage<S> {
+    storage: S,
+}
+
+impl<S> HistoricalStorage<S> {
+    /// Keeps the history of the `storage`.
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    /// The underlying storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Returns the underlying storage.
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+}
+
+impl<Type, S> StorageInspect<Type> for HistoricalStorage<S>
+where
+    Type: Mappable,
+    S: StorageInspect<Type>,
+{
+    type Error = S::Error;
+
+    fn get(&self, key: &Type::Key) -> Result<Option<Cow<Type::OwnedValue>>, Self::Error> {
+        self.storage.get(key)
+    }
+
+    fn contains_key(&self, key: &Type::Key) -> Result<bool, Self::Error> {
+        self.storage.contains_key(key)
+    }
+}
+
+impl<Type, S> StorageMutate<Type> for HistoricalStorage<S>
+where
+    Type: Mappable,
+    S: StorageMutate<Type>,
+{
+    fn replace(
+        &mut self,
+        key: &Type::Key,
+        value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        self.storage.replace(key, value)
+    }
+
+    /// Returns the value without removing it.
+    fn take(&mut self, key: &Type::Key) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        Ok(self.storage.get(key)?.map(Cow::into_owned))
+    }
+}
+
+impl<Type, S> StorageBatchWrite<Type> for HistoricalStorage<S>
+where
+    Type: Mappable,
+    S: StorageBatchWrite<Type>,
+{
+    /// Writes the inserted values, ignoring the removals.
+    fn write_batch<I>(&mut self, changes: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = (Type::OwnedKey, Option<Type::OwnedValue>)>,
+    {
+        self.storage
+            .write_batch(changes.into_iter().filter(|(_, value)| value.is_some()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        common::{
+            Bytes32,
+            StorageMap,
+        },
+        sparse::{
+            self,
+            proof::Proof,
+            MerkleTreeKey,
+            Primitive,
+        },
+        storage::BufferedStorage,
+    };
+
+    #[derive(Debug)]
+    struct TestTable;
+
+    impl Mappable for TestTable {
+        type Key = Self::OwnedKey;
+        type OwnedKey = Bytes32;
+        type OwnedValue = Primitive;
+        type Value = Self::OwnedValue;
+    }
+
+    fn key(byte: u8) -> MerkleTreeKey {
+        MerkleTreeKey::new([byte; 32])
+    }
+
+    #[test]
+    fn load_opens_the_tree_at_a_historical_root() {
+        let mut storage = HistoricalStorage::new(StorageMap::<TestTable>::new());
+        let mut tree = sparse::MerkleTree::new(&mut storage);
+        for i in 0..10 {
+            tree.update(key(i), b"OLD").unwrap();
+        }
+        let old_root = tree.root();
+        for i in 0..5 {
+            tree.update(key(i), b"NEW").unwrap();
+        }
+        tree.delete(key(9)).unwrap();
+        let new_root = tree.root();
+
+        let old_tree = sparse::MerkleTree::load(&storage, &old_root).unwrap();
+        let Proof::Inclusion(proof) = old_tree.generate_proof(&key(0)).unwrap() else {
+            panic!("Expected InclusionProof");
+        };
+        assert!(proof.verify(&old_root, &key(0), b"OLD"));
+        let Proof::Inclusion(proof) = old_tree.generate_proof(&key(9)).unwrap() else {
+            panic!("Expected InclusionProof");
+        };
+        assert!(proof.verify(&old_root, &key(9), b"OLD"));
+
+        let new_tree = sparse::MerkleTree::load(&storage, &new_root).unwrap();
+        assert!(new_tree.generate_proof(&key(9)).unwrap().is_exclusion());
+    }
+
+    #[test]
+    fn load_fails_at_a_pruned_root() {
+        let mut storage = StorageMap::<TestTable>::new();
+        let mut tree = sparse::MerkleTree::new(&mut storage);
+        tree.update(key(0), b"OLD").unwrap();
+        tree.update(key(1), b"OLD").unwrap();
+        let old_root = tree.root();
+        tree.update(key(0), b"NEW").unwrap();
+
+        assert!(sparse::MerkleTree::load(&storage, &old_root).is_err());
+    }
+
+    #[test]
+    fn flush_keeps_the_nodes_of_the_flushed_states() {
+        let mut storage = HistoricalStorage::new(StorageMap::<TestTable>::new());
+        let mut buffered = BufferedStorage::<TestTable, _>::new(&mut storage);
+        let mut tree = sparse::MerkleTree::new(&mut buffered);
+        tree.update(key(0), b"OLD").unwrap();
+        tree.update(key(1), b"OLD").unwrap();
+        let old_root = tree.root();
+        buffered.flush().unwrap();
+
+        let mut tree = sparse::MerkleTree::load(&mut buffered, &old_root).unwrap();
+        tree.update(key(0), b"NEW").unwrap();
+        buffered.flush().unwrap();
+
+        assert!(sparse::MerkleTree::load(&storage, &old_root).is_ok());
+    }
+}
@@ -0,0 +1,123 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    binary::{
+        self,
+        root_calculator::MerkleRootCalculator,
+    },
+    common::{
+        sum_iter,
+        Bytes32,
+        MerkleHasher,
+        StorageMap,
+    },
+    sparse::{
+        self,
+        proof::Proof,
+        MerkleTreeKey,
+    },
+};
+use fuel_storage::Mappable;
+
+/// SHA-256 with the leaves and the nodes domain separated by suffixes instead of
+/// prefixes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SuffixedSha256;
+
+impl MerkleHasher for SuffixedSha256 {
+    fn hash_iter<'a, I>(data: I) -> Bytes32
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        sum_iter(data)
+    }
+
+    fn leaf_sum(data: &[&[u8]]) -> Bytes32 {
+        Self::hash_iter(data.iter().copied().chain([&b"leaf"[..]]))
+    }
+
+    fn node_sum(lhs: &Bytes32, rhs: &Bytes32) -> Bytes32 {
+        Self::hash_iter([&lhs[..], rhs, b"node"])
+    }
+}
+
+struct BinaryTable;
+
+impl Mappable for BinaryTable {
+    type Key = Self::OwnedKey;
+    type OwnedKey = u64;
+    type OwnedValue = binary::Primitive;
+    type Value = Self::OwnedValue;
+}
+
+struct SparseTable;
+
+impl Mappable for SparseTable {
+    type Key = Self::OwnedKey;
+    type OwnedKey = Bytes32;
+    type OwnedValue = sparse::Primitive;
+    type Value = Self::OwnedValue;
+}
+
+const DATA: [&[u8]; 5] = [b"Frankly", b"my", b"dear", b"I don't", b"give a damn"];
+
+#[test]
+fn binary_tree__with_hasher__is_hashed_with_the_hasher() {
+    // Given
+    let mut tree = binary::MerkleTree::<BinaryTable, _, SuffixedSha256>::new_with_hasher(
+        StorageMap::<BinaryTable>::new(),
+    );
+    let mut calculator = MerkleRootCalculator::<SuffixedSha256>::new_with_hasher();
+    let mut default_calculator = MerkleRootCalculator::new();
+
+    // When
+    for data in DATA {
+        tree.push(data).unwrap();
+        calculator.push(data);
+        default_calculator.push(data);
+    }
+
+    // Then
+    let root = tree.root();
+    assert_eq!(root, calculator.root());
+    assert_ne!(root, default_calculator.root());
+    let (proof_root, proof_set) = tree.prove(2).unwrap();
+    assert_eq!(proof_root, root);
+    assert!(binary::verify_with_hasher::<SuffixedSha256, _>(
+        &root, &DATA[2], &proof_set, 2, 5
+    ));
+    assert!(!binary::verify(&root, &DATA[2], &proof_set, 2, 5));
+}
+
+#[test]
+fn sparse_tree__with_hasher__is_hashed_with_the_hasher() {
+    // Given
+    let set = DATA.map(|data| (MerkleTreeKey::new(data), data));
+    let mut storage = StorageMap::<SparseTable>::new();
+
+    // When
+    let tree =
+        sparse::MerkleTree::<SparseTable, _, SuffixedSha256>::from_set_with_hasher(
+            &mut storage,
+            set.into_iter(),
+        )
+        .unwrap();
+    let root = tree.root();
+
+    // Then
+    assert_ne!(
+        root,
+        sparse::in_memory::MerkleTree::root_from_set(set.into_iter())
+    );
+    let tree = sparse::MerkleTree::<SparseTable, _, SuffixedSha256>::load_with_hasher(
+        &mut storage,
+        &root,
+    )
+    .unwrap();
+    let (key, data) = set[3];
+    let Proof::Inclusion(proof) = tree.generate_proof(&key).unwrap() else {
+        panic!("Expected an inclusion proof")
+    };
+    assert!(proof.verify_with_hasher::<SuffixedSha256>(&root, &key, data));
+    assert!(!proof.verify(&root, &key, data));
+}
@@ -5,4 +5,5 @@ use serde_yaml as _;
 
 mod binary;
 mod binary_verify;
+mod hasher;
 mod sparse;
@@ -0,0 +1,655 @@
+//! [`Arbitrary`] implementations and strategies for the property-based testing of the
+//! transactions, the inputs, the outputs, the policies and the receipts.
+//!
+//! The numbers are biased toward the boundary values, and the generated transactions
+//! keep the dependencies between their fields: the witness indexes of the signed
+//! inputs point into the witnesses, the predicate inputs have non-empty predicates,
+//! and the contract outputs refer to the contract inputs.
+
+use crate::{
+    input::{
+        self,
+        Input,
+    },
+    output::{
+        self,
+        Output,
+    },
+    policies::Policies,
+    BlobBody,
+    BlobId,
+    BlobIdExt,
+    Receipt,
+    ScriptExecutionResult,
+    StorageSlot,
+    Transaction,
+    TxPointer,
+    UpgradePurpose,
+    UploadBody,
+    UtxoId,
+    Witness,
+};
+use alloc::vec::Vec;
+use fuel_asm::{
+    PanicInstruction,
+    PanicReason,
+};
+use fuel_crypto::Hasher;
+use fuel_types::{
+    BlockHeight,
+    Bytes32,
+    Nonce,
+    Word,
+};
+use proptest::{
+    collection::vec,
+    prelude::*,
+    strategy::Union,
+};
+
+/// The maximum number of the inputs, the outputs and the witnesses of a transaction.
+const MAX_ITEMS: usize = 4;
+
+/// The maximum length of the generated byte vectors.
+const MAX_BYTES: usize = 64;
+
+/// A word biased toward zero, one and the maximum.
+pub fn word() -> BoxedStrategy<Word> {
+    prop_oneof![
+        4 => any::<Word>(),
+        1 => Just(0),
+        1 => Just(1),
+        1 => Just(Word::MAX),
+        1 => Just(Word::MAX - 1),
+    ]
+    .boxed()
+}
+
+/// 32 bytes biased toward all zeros and all ones, for the addresses, the ids and the
+/// roots.
+pub fn bytes32<T: From<[u8; 32]> + core::fmt::Debug + 'static>() -> BoxedStrategy<T> {
+    prop_oneof![
+        4 => any::<[u8; 32]>(),
+        1 => Just([0; 32]),
+        1 => Just([0xff; 32]),
+    ]
+    .prop_map(T::from)
+    .boxed()
+}
+
+/// Bytes with the length biased toward the empty and the word-aligned boundaries.
+pub fn bytes() -> BoxedStrategy<Vec<u8>> {
+    prop_oneof![
+        4 => vec(any::<u8>(), 0..=MAX_BYTES),
+        1 => Just(Vec::new()),
+        1 => vec(any::<u8>(), 7..=9),
+    ]
+    .boxed()
+}
+
+/// Non-empty bytes, for the predicates.
+fn non_empty_bytes() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 1..=MAX_BYTES)
+}
+
+fn utxo_id() -> impl Strategy<Value = UtxoId> {
+    (bytes32(), any::<u16>()).prop_map(|(tx_id, index)| UtxoId::new(tx_id, index))
+}
+
+fn tx_pointer() -> impl Strategy<Value = TxPointer> {
+    (any::<u32>(), any::<u16>())
+        .prop_map(|(height, index)| TxPointer::new(BlockHeight::new(height), index))
+}
+
+fn witness() -> impl Strategy<Value = Witness> {
+    bytes().prop_map(Witness::from)
+}
+
+/// Policies with every subset of the policies set, to boundary values.
+pub fn policies() -> impl Strategy<Value = Policies> {
+    (
+        proptest::option::of(word()),
+        proptest::option::of(word()),
+        proptest::option::of(any::<u32>()),
+        proptest::option::of(word()),
+    )
+        .prop_map(|(tip, witness_limit, maturity, max_fee)| {
+            let mut policies = Policies::new();
+            if let Some(tip) = tip {
+                policies = policies.with_tip(tip);
+            }
+            if let Some(witness_limit) = witness_limit {
+                policies = policies.with_witness_limit(witness_limit);
+            }
+            if let Some(maturity) = maturity {
+                policies = policies.with_maturity(maturity.into());
+            }
+            if let Some(max_fee) = max_fee {
+                policies = policies.with_max_fee(max_fee);
+            }
+            policies
+        })
+}
+
+fn contract_input() -> impl Strategy<Value = input::contract::Contract> {
+    (utxo_id(), bytes32(), bytes32(), tx_pointer(), bytes32()).prop_map(
+        |(utxo_id, balance_root, state_root, tx_pointer, contract_id)| {
+            input::contract::Contract {
+                utxo_id,
+                balance_root,
+                state_root,
+                tx_pointer,
+                contract_id,
+            }
+        },
+    )
+}
+
+/// An input of a transaction with `witnesses` witnesses.
+///
+/// The signed inputs are only generated if there are witnesses, and point into them.
+pub fn input(witnesses: usize) -> BoxedStrategy<Input> {
+    let predicate = prop_oneof![
+        (
+            utxo_id(),
+            bytes32(),
+            word(),
+            bytes32(),
+            tx_pointer(),
+            word(),
+            non_empty_bytes(),
+            bytes(),
+        )
+            .prop_map(
+                |(utxo_id, owner, amount, asset_id, tx_pointer, gas, predicate, data)| {
+                    Input::coin_predicate(
+                        utxo_id, owner, amount, asset_id, tx_pointer, gas, predicate,
+                        data,
+                    )
+                }
+            ),
+        (
+            bytes32(),
+            bytes32(),
+            word(),
+            bytes32::<Nonce>(),
+            word(),
+            bytes(),
+            non_empty_bytes(),
+            bytes(),
+        )
+            .prop_map(
+                |(
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    gas,
+                    data,
+                    predicate,
+                    predicate_data,
+                )| {
+                    if data.is_empty() {
+                        Input::message_coin_predicate(
+                            sender,
+                            recipient,
+                            amount,
+                            nonce,
+                            gas,
+                            predicate,
+                            predicate_data,
+                        )
+                    } else {
+                        Input::message_data_predicate(
+                            sender,
+                            recipient,
+                            amount,
+                            nonce,
+                            gas,
+                            data,
+                            predicate,
+                            predicate_data,
+                        )
+                    }
+                }
+            ),
+        contract_input().prop_map(Input::Contract),
+    ];
+
+    let Ok(witnesses) = u16::try_from(witnesses) else {
+        return predicate.boxed()
+    };
+    if witnesses == 0 {
+        return predicate.boxed()
+    }
+
+    let signed = prop_oneof![
+        (
+            utxo_id(),
+            bytes32(),
+            word(),
+            bytes32(),
+            tx_pointer(),
+            0..witnesses
+        )
+            .prop_map(
+                |(utxo_id, owner, amount, asset_id, tx_pointer, witness_index)| {
+                    Input::coin_signed(
+                        utxo_id,
+                        owner,
+                        amount,
+                        asset_id,
+                        tx_pointer,
+                        witness_index,
+                    )
+                }
+            ),
+        (
+            bytes32(),
+            bytes32(),
+            word(),
+            bytes32::<Nonce>(),
+            0..witnesses,
+            bytes()
+        )
+            .prop_map(
+                |(sender, recipient, amount, nonce, witness_index, data)| {
+                    if data.is_empty() {
+                        Input::message_coin_signed(
+                            sender,
+                            recipient,
+                            amount,
+                            nonce,
+                            witness_index,
+                        )
+                    } else {
+                        Input::message_data_signed(
+                            sender,
+                            recipient,
+                            amount,
+                            nonce,
+                            witness_index,
+                            data,
+                        )
+                    }
+                }
+            ),
+    ];
+
+    prop_oneof![signed, predicate].boxed()
+}
+
+/// An output of a transaction, with the contract outputs referring to one of the
+/// `contract_inputs` indexes.
+///
+/// The contract outputs are only generated if there are contract inputs.
+pub fn output(contract_inputs: Vec<u16>) -> BoxedStrategy<Output> {
+    let coin = prop_oneof![
+        (bytes32(), word(), bytes32())
+            .prop_map(|(to, amount, asset_id)| { Output::coin(to, amount, asset_id) }),
+        (bytes32(), word(), bytes32())
+            .prop_map(|(to, amount, asset_id)| { Output::change(to, amount, asset_id) }),
+        (bytes32(), word(), bytes32()).prop_map(|(to, amount, asset_id)| {
+            Output::variable(to, amount, asset_id)
+        }),
+    ];
+
+    if contract_inputs.is_empty() {
+        return coin.boxed()
+    }
+
+    let contract = (
+        proptest::sample::select(contract_inputs),
+        bytes32(),
+        bytes32(),
+    )
+        .prop_map(|(input_index, balance_root, state_root)| {
+            Output::contract(input_index, balance_root, state_root)
+        });
+
+    prop_oneof![coin, contract].boxed()
+}
+
+/// The inputs, the outputs and the witnesses of a transaction, with at least
+/// `min_witnesses` witnesses.
+pub fn io(
+    min_witnesses: usize,
+) -> impl Strategy<Value = (Vec<Input>, Vec<Output>, Vec<Witness>)> {
+    vec(
+        witness(),
+        min_witnesses..=min_witnesses.saturating_add(MAX_ITEMS),
+    )
+    .prop_flat_map(|witnesses| {
+        let inputs = vec(input(witnesses.len()), 0..=MAX_ITEMS);
+        (inputs, Just(witnesses))
+    })
+    .prop_flat_map(|(inputs, witnesses)| {
+        let contract_inputs = inputs
+            .iter()
+            .enumerate()
+            .filter(|(_, input)| input.is_contract())
+            .filter_map(|(index, _)| u16::try_from(index).ok())
+            .collect();
+        let outputs = vec(output(contract_inputs), 0..=MAX_ITEMS);
+        (Just(inputs), outputs, Just(witnesses))
+    })
+}
+
+/// A script transaction.
+pub fn script() -> impl Strategy<Value = crate::Script> {
+    (word(), bytes(), bytes(), policies(), io(0)).prop_map(
+        |(gas_limit, script, script_data, policies, (inputs, outputs, witnesses))| {
+            Transaction::script(
+                gas_limit,
+                script,
+                script_data,
+                policies,
+                inputs,
+                outputs,
+                witnesses,
+            )
+        },
+    )
+}
+
+/// A create transaction, with the bytecode in one of the witnesses and the
+/// `ContractCreated` output.
+pub fn create() -> impl Strategy<Value = crate::Create> {
+    (
+        policies(),
+        bytes32(),
+        vec(
+            (bytes32(), bytes32()).prop_map(|(key, value)| StorageSlot::new(key, value)),
+            0..=MAX_ITEMS,
+        ),
+        io(1),
+        bytes32(),
+        bytes32(),
+    )
+        .prop_flat_map(
+            |(
+                policies,
+                salt,
+                storage_slots,
+                (inputs, mut outputs, witnesses),
+                contract_id,
+                state_root,
+            )| {
+                let witnesses_len = u16::try_from(witnesses.len()).unwrap_or(u16::MAX);
+                outputs.push(Output::contract_created(contract_id, state_root));
+                (0..witnesses_len).prop_map(move |bytecode_witness_index| {
+                    Transaction::create(
+                        bytecode_witness_index,
+                        policies,
+                        salt,
+                        storage_slots.clone(),
+                        inputs.clone(),
+                        outputs.clone(),
+                        witnesses.clone(),
+                    )
+                })
+            },
+        )
+}
+
+/// A mint transaction, with the output contract referring to the input contract.
+pub fn mint() -> impl Strategy<Value = crate::Mint> {
+    (
+        tx_pointer(),
+        contract_input(),
+        bytes32(),
+        bytes32(),
+        word(),
+        bytes32(),
+        word(),
+    )
+        .prop_map(
+            |(
+                tx_pointer,
+                input_contract,
+                balance_root,
+                state_root,
+                mint_amount,
+                mint_asset_id,
+                gas_price,
+            )| {
+                Transaction::mint(
+                    tx_pointer,
+                    input_contract,
+                    output::contract::Contract {
+                        input_index: 0,
+                        balance_root,
+                        state_root,
+                    },
+                    mint_amount,
+                    mint_asset_id,
+                    gas_price,
+                )
+            },
+        )
+}
+
+/// An upgrade transaction. The consensus parameters upgrades point into the witnesses,
+/// with the checksum of the witness.
+pub fn upgrade() -> impl Strategy<Value = crate::Upgrade> {
+    (any::<bool>(), bytes32(), policies(), io(1)).prop_flat_map(
+        |(consensus_parameters, root, policies, (inputs, outputs, witnesses))| {
+            let witnesses_len = witnesses.len();
+            (0..witnesses_len).prop_map(move |witness_index| {
+                let purpose = if consensus_parameters {
+                    UpgradePurpose::ConsensusParameters {
+                        witness_index: u16::try_from(witness_index).unwrap_or(u16::MAX),
+                        checksum: Hasher::hash(witnesses[witness_index].as_ref()),
+                    }
+                } else {
+                    UpgradePurpose::StateTransition { root }
+                };
+                Transaction::upgrade(
+                    purpose,
+                    policies,
+                    inputs.clone(),
+                    outputs.clone(),
+                    witnesses.clone(),
+                )
+            })
+        },
+    )
+}
+
+/// An upload transaction, with the subsection in one of the witnesses and its index
+/// below the number of the subsections.
+pub fn upload() -> impl Strategy<Value = crate::Upload> {
+    (
+        bytes32(),
+        1..=u16::MAX,
+        vec(bytes32::<Bytes32>(), 0..=MAX_ITEMS),
+        policies(),
+        io(1),
+    )
+        .prop_flat_map(
+            |(
+                root,
+                subsections_number,
+                proof_set,
+                policies,
+                (inputs, outputs, witnesses),
+            )| {
+                let witnesses_len = u16::try_from(witnesses.len()).unwrap_or(u16::MAX);
+                (0..witnesses_len, 0..subsections_number).prop_map(
+                    move |(witness_index, subsection_index)| {
+                        Transaction::upload(
+                            UploadBody {
+                                root,
+                                witness_index,
+                                subsection_index,
+                                subsections_number,
+                                proof_set: proof_set.clone(),
+                            },
+                            policies,
+                            inputs.clone(),
+                            outputs.clone(),
+                            witnesses.clone(),
+                        )
+                    },
+                )
+            },
+        )
+}
+
+/// A blob transaction, with the id of the blob in one of the witnesses.
+pub fn blob() -> impl Strategy<Value = crate::Blob> {
+    (policies(), io(1)).prop_flat_map(|(policies, (inputs, outputs, witnesses))| {
+        let witnesses_len = witnesses.len();
+        (0..witnesses_len).prop_map(move |witness_index| {
+            Transaction::blob(
+                BlobBody {
+                    id: BlobId::compute(witnesses[witness_index].as_ref()),
+                    witness_index: u16::try_from(witness_index).unwrap_or(u16::MAX),
+                },
+                policies,
+                inputs.clone(),
+                outputs.clone(),
+                witnesses.clone(),
+            )
+        })
+    })
+}
+
+/// A transaction of any type.
+pub fn transaction() -> impl Strategy<Value = Transaction> {
+    prop_oneof![
+        script().prop_map(Transaction::from),
+        create().prop_map(Transaction::from),
+        mint().prop_map(Transaction::from),
+        upgrade().prop_map(Transaction::from),
+        upload().prop_map(Transaction::from),
+        blob().prop_map(Transaction::from),
+    ]
+}
+
+fn panic_instruction() -> impl Strategy<Value = PanicInstruction> {
+    (any::<u8>(), any::<u32>()).prop_map(|(reason, instruction)| {
+        PanicInstruction::error(PanicReason::from(reason), instruction)
+    })
+}
+
+fn script_execution_result() -> impl Strategy<Value = ScriptExecutionResult> {
+    prop_oneof![
+        Just(ScriptExecutionResult::Success),
+        Just(ScriptExecutionResult::Revert),
+        Just(ScriptExecutionResult::Panic),
+        word().prop_map(ScriptExecutionResult::GenericFailure),
+    ]
+}
+
+/// A receipt of any type, with the data matching its length and digest.
+pub fn receipt() -> impl Strategy<Value = Receipt> {
+    // The arms are boxed, as the value trees of so many tuples overflow the stack.
+    let call = (
+        bytes32(),
+        bytes32(),
+        word(),
+        bytes32(),
+        (word(), word(), word()),
+        word(),
+        word(),
+    )
+        .prop_map(
+            |(id, to, amount, asset_id, (gas, param1, param2), pc, is)| {
+                Receipt::call(id, to, amount, asset_id, gas, param1, param2, pc, is)
+            },
+        );
+    let ret = (bytes32(), word(), word(), word())
+        .prop_map(|(id, val, pc, is)| Receipt::ret(id, val, pc, is));
+    let return_data = (bytes32(), word(), word(), word(), bytes())
+        .prop_map(|(id, ptr, pc, is, data)| Receipt::return_data(id, ptr, pc, is, data));
+    let panic = (bytes32(), panic_instruction(), word(), word())
+        .prop_map(|(id, reason, pc, is)| Receipt::panic(id, reason, pc, is));
+    let revert = (bytes32(), word(), word(), word())
+        .prop_map(|(id, ra, pc, is)| Receipt::revert(id, ra, pc, is));
+    let log = (bytes32(), (word(), word(), word(), word()), word(), word()).prop_map(
+        |(id, (ra, rb, rc, rd), pc, is)| Receipt::log(id, ra, rb, rc, rd, pc, is),
+    );
+    let log_data = (bytes32(), word(), word(), word(), word(), word(), bytes()).prop_map(
+        |(id, ra, rb, ptr, pc, is, data)| {
+            Receipt::log_data(id, ra, rb, ptr, pc, is, data)
+        },
+    );
+    let transfer = (bytes32(), bytes32(), word(), bytes32(), word(), word()).prop_map(
+        |(id, to, amount, asset_id, pc, is)| {
+            Receipt::transfer(id, to, amount, asset_id, pc, is)
+        },
+    );
+    let transfer_out = (bytes32(), bytes32(), word(), bytes32(), word(), word())
+        .prop_map(|(id, to, amount, asset_id, pc, is)| {
+            Receipt::transfer_out(id, to, amount, asset_id, pc, is)
+        });
+    let script_result = (script_execution_result(), word())
+        .prop_map(|(result, gas_used)| Receipt::script_result(result, gas_used));
+    let message_out = (
+        bytes32::<Bytes32>(),
+        word(),
+        bytes32(),
+        bytes32(),
+        word(),
+        bytes(),
+    )
+        .prop_map(|(tx_id, index, sender, recipient, amount, data)| {
+            Receipt::message_out(&tx_id, index, sender, recipient, amount, data)
+        });
+    let mint = (bytes32(), bytes32(), word(), word(), word()).prop_map(
+        |(sub_id, contract_id, val, pc, is)| {
+            Receipt::mint(sub_id, contract_id, val, pc, is)
+        },
+    );
+    let burn = (bytes32(), bytes32(), word(), word(), word()).prop_map(
+        |(sub_id, contract_id, val, pc, is)| {
+            Receipt::burn(sub_id, contract_id, val, pc, is)
+        },
+    );
+
+    Union::new([
+        call.boxed(),
+        ret.boxed(),
+        return_data.boxed(),
+        panic.boxed(),
+        revert.boxed(),
+        log.boxed(),
+        log_data.boxed(),
+        transfer.boxed(),
+        transfer_out.boxed(),
+        script_result.boxed(),
+        message_out.boxed(),
+        mint.boxed(),
+        burn.boxed(),
+    ])
+}
+
+macro_rules! impl_arbitrary {
+    ($($ty:ty => $strategy:expr),* $(,)?) => {
+        $(
+            impl Arbitrary for $ty {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    $strategy.boxed()
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary! {
+    Transaction => transaction(),
+    crate::Script => script(),
+    crate::Create => create(),
+    crate::Mint => mint(),
+    crate::Upgrade => upgrade(),
+    crate::Upload => upload(),
+    crate::Blob => blob(),
+    Input => input(u8::MAX.into()),
+    Output => (0..=u16::MAX).prop_flat_map(|index| output(alloc::vec![index])),
+    Policies => policies(),
+    Receipt => receipt(),
+}
@@ -0,0 +1,173 @@
+//! Resolution of the asset ids back to the contracts and the sub ids minting them.
+
+use crate::{
+    ContractIdExt,
+    Receipt,
+};
+use fuel_types::{
+    AssetId,
+    Bytes32,
+    ContractId,
+};
+use hashbrown::HashMap;
+
+/// Derives the id of the asset minted by the `contract_id` with the `sub_id`, the
+/// same way as the `MINT` instruction.
+pub fn asset_id(contract_id: &ContractId, sub_id: &Bytes32) -> AssetId {
+    contract_id.asset_id(sub_id)
+}
+
+/// The contract and the sub id from which an asset id is derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetOrigin {
+    /// The contract minting the asset.
+    pub contract_id: ContractId,
+    /// The sub id of the asset within the contract.
+    pub sub_id: Bytes32,
+}
+
+impl AssetOrigin {
+    /// The id of the asset derived from the origin.
+    pub fn asset_id(&self) -> AssetId {
+        asset_id(&self.contract_id, &self.sub_id)
+    }
+}
+
+/// Maps the observed asset ids back to their [`AssetOrigin`].
+///
+/// The asset id is a hash, so the origin of an asset can't be recovered from the id
+/// alone. The registry learns the origins from the `Mint` receipts, or from the
+/// origins inserted directly for the assets known upfront.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AssetRegistry {
+    origins: HashMap<AssetId, AssetOrigin>,
+}
+
+impl AssetRegistry {
+    /// Creates the empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the origin, and returns the id of the asset derived from it.
+    pub fn insert(&mut self, contract_id: ContractId, sub_id: Bytes32) -> AssetId {
+        let origin = AssetOrigin {
+            contract_id,
+            sub_id,
+        };
+        let asset_id = origin.asset_id();
+        self.origins.insert(asset_id, origin);
+        asset_id
+    }
+
+    /// Registers the origin of the asset minted by the receipt. Returns the id of the
+    /// asset, or `None` if the receipt isn't a `Mint`.
+    pub fn observe(&mut self, receipt: &Receipt) -> Option<AssetId> {
+        let Receipt::Mint {
+            sub_id,
+            contract_id,
+            ..
+        } = receipt
+        else {
+            return None
+        };
+        Some(self.insert(*contract_id, *sub_id))
+    }
+
+    /// Registers the origins of the assets minted by all of the receipts.
+    pub fn observe_all<'a, I>(&mut self, receipts: I)
+    where
+        I: IntoIterator<Item = &'a Receipt>,
+    {
+        for receipt in receipts {
+            self.observe(receipt);
+        }
+    }
+
+    /// The origin of the asset, if it's registered.
+    pub fn origin(&self, asset_id: &AssetId) -> Option<&AssetOrigin> {
+        self.origins.get(asset_id)
+    }
+
+    /// The number of the registered assets.
+    pub fn len(&self) -> usize {
+        self.origins.len()
+    }
+
+    /// Returns `true` if no assets are registered.
+    pub fn is_empty(&self) -> bool {
+        self.origins.is_empty()
+    }
+
+    /// Iterates over the registered assets and their origins, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&AssetId, &AssetOrigin)> {
+        self.origins.iter()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    fn mint(contract_id: ContractId, sub_id: Bytes32) -> Receipt {
+        Receipt::mint(sub_id, contract_id, 100, 0, 0)
+    }
+
+    #[test]
+    fn asset_id__matches_the_contract_id_extension() {
+        let contract_id = ContractId::from([1; 32]);
+        let sub_id = Bytes32::from([2; 32]);
+
+        assert_eq!(
+            asset_id(&contract_id, &sub_id),
+            contract_id.asset_id(&sub_id)
+        );
+        assert_eq!(
+            asset_id(&contract_id, &Bytes32::zeroed()),
+            contract_id.default_asset()
+        );
+    }
+
+    #[test]
+    fn observe__registers_the_origins_of_the_minted_assets() {
+        let contract_id = ContractId::from([1; 32]);
+        let sub_id = Bytes32::from([2; 32]);
+        let mut registry = AssetRegistry::new();
+
+        registry.observe_all(&[
+            mint(contract_id, sub_id),
+            Receipt::ret(contract_id, 0, 0, 0),
+        ]);
+
+        let asset_id = asset_id(&contract_id, &sub_id);
+        assert_eq!(registry.len(), 1);
+        assert_eq!(
+            registry.origin(&asset_id),
+            Some(&AssetOrigin {
+                contract_id,
+                sub_id
+            })
+        );
+    }
+
+    #[test]
+    fn observe__ignores_other_receipts() {
+        let mut registry = AssetRegistry::new();
+
+        let observed = registry.observe(&Receipt::ret(Default::default(), 0, 0, 0));
+
+        assert_eq!(observed, None);
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn origin__unknown_asset_is_none() {
+        let mut registry = AssetRegistry::new();
+        registry.insert([1; 32].into(), [2; 32].into());
+
+        assert_eq!(registry.origin(&AssetId::from([3; 32])), None);
+    }
+}
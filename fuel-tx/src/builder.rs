@@ -1,4 +1,5 @@
 use crate::{
+    coin_selection::CoinSelectionError,
     input,
     output,
     transaction::{
@@ -16,6 +17,7 @@ use crate::{
     },
     Blob,
     BlobBody,
+    CoinSelector,
     ConsensusParameters,
     ContractParameters,
     CreateMetadata,
@@ -26,20 +28,27 @@ use crate::{
     Output,
     PredicateParameters,
     ScriptParameters,
+    SplitError,
     StorageSlot,
     Transaction,
     TxParameters,
     TxPointer,
     Upgrade,
+    UpgradeMetadata,
     UpgradePurpose,
     Upload,
     UploadBody,
+    UploadSubsection,
+    ValidityError,
     Witness,
 };
 
 use crate::{
+    AsyncSigner,
     Cacheable,
     Signable,
+    Signer,
+    SigningError,
 };
 
 use crate::{
@@ -59,10 +68,17 @@ use alloc::{
     collections::BTreeMap,
     vec::Vec,
 };
-use fuel_crypto::SecretKey;
+use fuel_crypto::{
+    Message,
+    PublicKey,
+    RecoverableSignature,
+    SecretKey,
+};
 use fuel_types::{
+    Address,
     AssetId,
     BlockHeight,
+    Bytes32,
     ChainId,
     Nonce,
     Salt,
@@ -118,6 +134,47 @@ impl<T> BuildableSet for T where T: BuildableAloc + BuildableStd {}
 
 impl<T> Buildable for T where T: BuildableSet {}
 
+/// Signers whose signatures over the transaction id are all provided in the
+/// witnesses, e.g. for a predicate verifying `m` of `n` signatures.
+///
+/// The signers are kept in the order they were added, without duplicates.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MultisigWitness {
+    signers: Vec<SecretKey>,
+}
+
+impl MultisigWitness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the signer. A signer that was already added is ignored.
+    pub fn add_signer(&mut self, secret: SecretKey) -> &mut Self {
+        if !self.signers.contains(&secret) {
+            self.signers.push(secret);
+        }
+        self
+    }
+
+    pub fn signers(&self) -> &[SecretKey] {
+        &self.signers
+    }
+
+    /// Public keys of the signers, in order.
+    pub fn public_keys(&self) -> Vec<PublicKey> {
+        self.signers.iter().map(PublicKey::from).collect()
+    }
+
+    /// Signs the transaction id by every signer, in order.
+    pub fn sign(&self, id: &Bytes32) -> Vec<RecoverableSignature> {
+        let message = Message::from_bytes_ref(id);
+        self.signers
+            .iter()
+            .map(|secret| RecoverableSignature::sign(secret, message))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionBuilder<Tx> {
     tx: Tx,
@@ -128,6 +185,12 @@ pub struct TransactionBuilder<Tx> {
     // zeroize the keys
     // Maps signing keys -> witness indexes
     sign_keys: BTreeMap<SecretKey, u16>,
+
+    // Maps the public keys of the external signers -> witness indexes
+    signer_keys: BTreeMap<PublicKey, u16>,
+
+    // Owner of the change outputs added on finalization
+    change_owner: Option<Address>,
 }
 
 impl TransactionBuilder<Script> {
@@ -201,6 +264,66 @@ impl TransactionBuilder<Upgrade> {
         };
         Self::with_tx(tx)
     }
+
+    /// Creates a builder for the `Upgrade` transaction that changes the consensus
+    /// parameters.
+    ///
+    /// The serialized `consensus_parameters` are stored in the first witness and the
+    /// checksum is computed from them. The serialization is verified to be decodable
+    /// back into [`ConsensusParameters`].
+    pub fn upgrade_consensus_parameters(
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Self, ValidityError> {
+        let tx = Transaction::upgrade_consensus_parameters(
+            consensus_parameters,
+            Policies::new().with_max_fee(0),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )?;
+        UpgradeMetadata::compute(&tx)?;
+
+        Ok(Self::with_tx(tx))
+    }
+
+    /// Creates a builder for the `Upgrade` transaction that changes the state
+    /// transition function to the bytecode uploaded by the `subsections`.
+    ///
+    /// The subsections must describe the whole bytecode, as returned by
+    /// [`UploadSubsection::split_bytecode`]; the proof of each of them is verified
+    /// against the common root.
+    pub fn upgrade_state_transition(
+        subsections: &[UploadSubsection],
+    ) -> Result<Self, ValidityError> {
+        let root = subsections
+            .first()
+            .map(|subsection| subsection.root)
+            .ok_or(ValidityError::TransactionUploadRootVerificationFailed)?;
+
+        for (index, subsection) in subsections.iter().enumerate() {
+            let proof_set = subsection
+                .proof_set
+                .iter()
+                .map(|proof| (*proof).into())
+                .collect::<Vec<_>>();
+            let valid = subsection.root == root
+                && subsection.subsection_index as usize == index
+                && subsection.subsections_number as usize == subsections.len()
+                && fuel_merkle::binary::verify(
+                    &root,
+                    &subsection.subsection,
+                    &proof_set,
+                    index as u64,
+                    subsections.len() as u64,
+                );
+
+            if !valid {
+                return Err(ValidityError::TransactionUploadRootVerificationFailed);
+            }
+        }
+
+        Ok(Self::upgrade(UpgradePurpose::StateTransition { root }))
+    }
 }
 
 impl TransactionBuilder<Upload> {
@@ -215,6 +338,38 @@ impl TransactionBuilder<Upload> {
         };
         Self::with_tx(tx)
     }
+
+    /// Creates a builder for the `Upload` transaction of the `subsection`.
+    ///
+    /// The bytes of the subsection are stored in the first witness.
+    pub fn upload_subsection(subsection: UploadSubsection) -> Self {
+        let tx = Transaction::upload_from_subsection(
+            subsection,
+            Policies::new().with_max_fee(0),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        Self::with_tx(tx)
+    }
+
+    /// Splits the `bytecode` into subsections of `subsection_size` bytes with their
+    /// Merkle proofs, and creates a builder for the `Upload` transaction of each of
+    /// them, in the order of the subsections.
+    ///
+    /// See [`UploadSubsection::split_bytecode`]. The root of the bytecode, required
+    /// by [`TransactionBuilder::upgrade_state_transition`], is the same in all of the
+    /// transactions.
+    pub fn upload_bytecode(
+        bytecode: &[u8],
+        subsection_size: usize,
+    ) -> Result<Vec<Self>, SplitError> {
+        let subsections = UploadSubsection::split_bytecode(bytecode, subsection_size)?;
+        Ok(subsections
+            .into_iter()
+            .map(Self::upload_subsection)
+            .collect())
+    }
 }
 
 impl TransactionBuilder<Blob> {
@@ -263,6 +418,8 @@ impl<Tx> TransactionBuilder<Tx> {
             tx,
             params: ConsensusParameters::standard(),
             sign_keys,
+            signer_keys: BTreeMap::new(),
+            change_owner: None,
         }
     }
 
@@ -356,6 +513,19 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self.sign_keys.keys()
     }
 
+    /// The public keys of the external signers, see [`Self::finalize_signed`].
+    pub fn signer_keys(&self) -> impl Iterator<Item = &PublicKey> {
+        self.signer_keys.keys()
+    }
+
+    /// On finalization, adds a change output to `owner` for every asset of the inputs
+    /// that doesn't have a change output yet, so no asset is burned by a forgotten
+    /// change output.
+    pub fn with_automatic_change(&mut self, owner: Address) -> &mut Self {
+        self.change_owner = Some(owner);
+        self
+    }
+
     pub fn tip(&mut self, tip: Word) -> &mut Self {
         self.tx.set_tip(tip);
         self
@@ -412,6 +582,57 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    /// Same as [`Self::add_unsigned_coin_input`], for a coin owned by the
+    /// `public_key` whose secret is held by a [`Signer`], e.g. a hardware wallet.
+    /// The input is signed by [`Self::finalize_signed`].
+    pub fn add_signer_coin_input(
+        &mut self,
+        public_key: PublicKey,
+        utxo_id: crate::UtxoId,
+        amount: Word,
+        asset_id: fuel_types::AssetId,
+        tx_pointer: TxPointer,
+    ) -> &mut Self {
+        let witness_index = self.upsert_public_key(public_key);
+
+        self.tx.add_unsigned_coin_input(
+            utxo_id,
+            &public_key,
+            amount,
+            asset_id,
+            tx_pointer,
+            witness_index,
+        );
+
+        self
+    }
+
+    /// Adds the inputs selected by the selector from `available`. The selected
+    /// signed inputs must be owned by `secret`, which signs them on finalization.
+    pub fn add_selected_inputs(
+        &mut self,
+        selector: &CoinSelector,
+        available: &[Input],
+        secret: SecretKey,
+    ) -> Result<&mut Self, CoinSelectionError> {
+        let selected = selector.select(available, self.params.base_asset_id())?;
+
+        for input in selected {
+            let mut input = input.clone();
+            match &mut input {
+                Input::CoinSigned(input::coin::CoinSigned { witness_index, .. })
+                | Input::MessageCoinSigned(input::message::MessageCoinSigned {
+                    witness_index,
+                    ..
+                }) => *witness_index = self.upsert_secret(secret.clone()),
+                _ => {}
+            }
+            self.tx.add_input(input);
+        }
+
+        Ok(self)
+    }
+
     #[cfg(feature = "rand")]
     pub fn add_random_fee_input(&mut self, rng: &mut StdRng) -> &mut Self {
         self.add_unsigned_coin_input(
@@ -439,6 +660,20 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         )
     }
 
+    /// Adds a witness for each of the `multisig` signers, and returns their indexes
+    /// in the order of the signers.
+    ///
+    /// A signer shares the witness with the inputs it signs, so the indexes of the
+    /// signers already known to the builder are reused. The witnesses are signed
+    /// when the transaction is finalized.
+    pub fn add_multisig_witness(&mut self, multisig: &MultisigWitness) -> Vec<u16> {
+        multisig
+            .signers()
+            .iter()
+            .map(|secret| self.upsert_secret(secret.clone()))
+            .collect()
+    }
+
     pub fn add_unsigned_message_input(
         &mut self,
         secret: SecretKey,
@@ -464,6 +699,39 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    /// Same as [`Self::add_unsigned_message_input`], for a message to the
+    /// `public_key` whose secret is held by a [`Signer`]. The input is signed by
+    /// [`Self::finalize_signed`].
+    pub fn add_signer_message_input(
+        &mut self,
+        public_key: PublicKey,
+        sender: fuel_types::Address,
+        nonce: Nonce,
+        amount: Word,
+        data: Vec<u8>,
+    ) -> &mut Self {
+        let recipient = Input::owner(&public_key);
+
+        let witness_index = self.upsert_public_key(public_key);
+
+        self.tx.add_unsigned_message_input(
+            sender,
+            recipient,
+            nonce,
+            amount,
+            data,
+            witness_index,
+        );
+
+        self
+    }
+
+    /// Adds a witness signed by the [`Signer`] of the `public_key` on finalization,
+    /// and returns its index. The witness is shared with the inputs of the key.
+    pub fn add_signer_witness(&mut self, public_key: PublicKey) -> u16 {
+        self.upsert_public_key(public_key)
+    }
+
     pub fn inputs(&self) -> &[Input] {
         self.tx.inputs()
     }
@@ -490,12 +758,7 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
 
     /// Adds a secret to the builder, and adds a corresponding witness if it's a new entry
     fn upsert_secret(&mut self, secret_key: SecretKey) -> u16 {
-        let witness_len = u16::try_from(self.witnesses().len())
-            .expect("The number of witnesses can't exceed `u16::MAX`");
-
-        if u32::from(witness_len) > self.params.tx_params().max_witnesses() {
-            panic!("Max witnesses exceeded");
-        }
+        let witness_len = self.next_witness_index();
 
         let witness_index = self.sign_keys.entry(secret_key).or_insert_with(|| {
             // if this private key hasn't been used before,
@@ -506,21 +769,168 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         *witness_index
     }
 
-    fn finalize_inner(&self) -> Tx {
+    /// Adds a public key of an external signer to the builder, and adds a
+    /// corresponding witness if it's a new entry
+    fn upsert_public_key(&mut self, public_key: PublicKey) -> u16 {
+        let witness_len = self.next_witness_index();
+
+        let witness_index = self.signer_keys.entry(public_key).or_insert_with(|| {
+            self.tx.witnesses_mut().push(Witness::default());
+            witness_len
+        });
+        *witness_index
+    }
+
+    fn next_witness_index(&self) -> u16 {
+        let witness_len = u16::try_from(self.witnesses().len())
+            .expect("The number of witnesses can't exceed `u16::MAX`");
+
+        if u32::from(witness_len) > self.params.tx_params().max_witnesses() {
+            panic!("Max witnesses exceeded");
+        }
+
+        witness_len
+    }
+
+    fn add_change_outputs(&self, tx: &mut Tx) {
+        let Some(owner) = self.change_owner else {
+            return
+        };
+
+        let missing: Vec<AssetId> = tx
+            .input_asset_ids_unique(self.params.base_asset_id())
+            .filter(|asset_id| {
+                !tx.outputs().iter().any(|output| {
+                    matches!(output, Output::Change { asset_id: change, .. } if change == *asset_id)
+                })
+            })
+            .copied()
+            .collect();
+
+        for asset_id in missing {
+            tx.outputs_mut().push(Output::change(owner, 0, asset_id));
+        }
+    }
+
+    /// Returns the transaction signed by the secret keys of the builder, and its id
+    /// as the message to sign by the external signers. The witnesses aren't part of
+    /// the id, so the signatures don't depend on each other.
+    fn sign_with_secrets(&self) -> (Tx, Message) {
         let mut tx = self.tx.clone();
+        self.add_change_outputs(&mut tx);
 
         self.sign_keys
             .iter()
             .for_each(|(k, _)| tx.sign_inputs(k, &self.get_chain_id()));
 
+        // Signers of the multisig witnesses may not own any input
+        let id = tx.id(&self.get_chain_id());
+        let message = Message::from_bytes(*id);
+        for (k, witness_index) in &self.sign_keys {
+            if let Some(witness) = tx.witnesses_mut().get_mut(*witness_index as usize) {
+                *witness = RecoverableSignature::sign(k, &message).into();
+            }
+        }
+
+        (tx, message)
+    }
+
+    fn finalize_inner(&self) -> Tx {
+        let (mut tx, _) = self.sign_with_secrets();
+
         tx.precompute(&self.get_chain_id())
             .expect("Should be able to calculate cache");
 
         tx
     }
 
+    /// Finalizes the transaction as [`Finalizable::finalize`] does, and signs the
+    /// witnesses of the [signer keys](Self::signer_keys) with the `signers`.
+    ///
+    /// The builder computes the id of the transaction, and the signers only sign it.
+    /// Every signature is verified against the public key of its signer, so a
+    /// device signing a different payload is caught before the transaction is sent.
+    /// Use `&dyn Signer<Error = E>` to mix the kinds of signers.
+    pub fn finalize_signed<S: Signer>(
+        &self,
+        signers: &[S],
+    ) -> Result<Tx, SigningError<S::Error>> {
+        let (mut tx, message) = self.sign_with_secrets();
+
+        for (public_key, witness_index) in &self.signer_keys {
+            let signer = signers
+                .iter()
+                .find(|signer| Signer::public_key(*signer) == *public_key)
+                .ok_or(SigningError::MissingSigner(*public_key))?;
+            let signature =
+                Signer::sign(signer, &message).map_err(SigningError::Signer)?;
+            Self::set_signature(
+                &mut tx,
+                public_key,
+                *witness_index,
+                &signature,
+                &message,
+            )?;
+        }
+
+        tx.precompute(&self.get_chain_id())
+            .expect("Should be able to calculate cache");
+
+        Ok(tx)
+    }
+
+    /// Same as [`Self::finalize_signed`], for the [`AsyncSigner`]s. The signers are
+    /// asked one after the other, e.g. for the user to confirm on each device.
+    pub async fn finalize_signed_async<S: AsyncSigner>(
+        &self,
+        signers: &[S],
+    ) -> Result<Tx, SigningError<S::Error>> {
+        let (mut tx, message) = self.sign_with_secrets();
+
+        for (public_key, witness_index) in &self.signer_keys {
+            let signer = signers
+                .iter()
+                .find(|signer| AsyncSigner::public_key(*signer) == *public_key)
+                .ok_or(SigningError::MissingSigner(*public_key))?;
+            let signature = AsyncSigner::sign(signer, &message)
+                .await
+                .map_err(SigningError::Signer)?;
+            Self::set_signature(
+                &mut tx,
+                public_key,
+                *witness_index,
+                &signature,
+                &message,
+            )?;
+        }
+
+        tx.precompute(&self.get_chain_id())
+            .expect("Should be able to calculate cache");
+
+        Ok(tx)
+    }
+
+    fn set_signature<E>(
+        tx: &mut Tx,
+        public_key: &PublicKey,
+        witness_index: u16,
+        signature: &RecoverableSignature,
+        message: &Message,
+    ) -> Result<(), SigningError<E>> {
+        signature
+            .verify(public_key, message)
+            .map_err(|_| SigningError::InvalidSignature(*public_key))?;
+
+        if let Some(witness) = tx.witnesses_mut().get_mut(witness_index as usize) {
+            *witness = (*signature).into();
+        }
+
+        Ok(())
+    }
+
     pub fn finalize_without_signature_inner(&self) -> Tx {
         let mut tx = self.tx.clone();
+        self.add_change_outputs(&mut tx);
 
         tx.precompute(&self.get_chain_id())
             .expect("Should be able to calculate cache");
@@ -0,0 +1,338 @@
+//! Selection of the coins and the messages spent by a transaction.
+
+use crate::Input;
+use alloc::vec::Vec;
+use fuel_types::{
+    AssetId,
+    Word,
+};
+
+/// The way [`CoinSelector`] picks the inputs covering the target.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectionStrategy {
+    /// Takes the largest inputs until the target is covered. Minimizes the number of
+    /// inputs.
+    #[default]
+    LargestFirst,
+    /// Searches for the inputs exceeding the target by the least amount, to minimize
+    /// the change. Falls back to [`SelectionStrategy::LargestFirst`] if no selection
+    /// is found within `max_tries` steps of the search.
+    BranchAndBound {
+        /// Maximum number of the steps of the search.
+        max_tries: usize,
+    },
+}
+
+/// An error produced by [`CoinSelector::select`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoinSelectionError {
+    /// The available inputs of the asset don't cover the target.
+    InsufficientFunds {
+        /// The total amount of the available inputs of the asset.
+        available: u128,
+        /// The amount and the fee.
+        required: u128,
+    },
+}
+
+/// Selects the inputs spending at least the amount and the fee of an asset.
+///
+/// Only the coins and the messages without data are spendable. The messages are
+/// inputs of the base asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoinSelector {
+    asset_id: AssetId,
+    amount: Word,
+    fee: Word,
+    strategy: SelectionStrategy,
+}
+
+impl CoinSelector {
+    /// Selects the inputs spending at least `amount` of the asset.
+    pub const fn new(asset_id: AssetId, amount: Word) -> Self {
+        Self {
+            asset_id,
+            amount,
+            fee: 0,
+            strategy: SelectionStrategy::LargestFirst,
+        }
+    }
+
+    /// Adds the fee to the target. The fee is paid in the selected asset, so the
+    /// base asset must be selected separately if it's another asset.
+    pub const fn with_fee(mut self, fee: Word) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Replaces the default [`SelectionStrategy::LargestFirst`].
+    pub const fn with_strategy(mut self, strategy: SelectionStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// The selected asset.
+    pub const fn asset_id(&self) -> &AssetId {
+        &self.asset_id
+    }
+
+    /// The amount and the fee covered by the selected inputs.
+    pub const fn target(&self) -> u128 {
+        (self.amount as u128).saturating_add(self.fee as u128)
+    }
+
+    /// Selects the inputs of the asset from `available`, in the order of their
+    /// selection.
+    pub fn select<'a>(
+        &self,
+        available: &'a [Input],
+        base_asset_id: &AssetId,
+    ) -> Result<Vec<&'a Input>, CoinSelectionError> {
+        let mut candidates: Vec<(&Input, Word)> = available
+            .iter()
+            .filter(|input| {
+                input.is_coin()
+                    || input.is_message_coin_signed()
+                    || input.is_message_coin_predicate()
+            })
+            .filter(|input| input.asset_id(base_asset_id) == Some(&self.asset_id))
+            .filter_map(|input| Some((input, input.amount()?)))
+            .collect();
+        candidates.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        let target = self.target();
+        let available = candidates.iter().fold(0u128, |sum, (_, amount)| {
+            sum.saturating_add(u128::from(*amount))
+        });
+        if available < target {
+            return Err(CoinSelectionError::InsufficientFunds {
+                available,
+                required: target,
+            })
+        }
+
+        let amounts: Vec<Word> = candidates.iter().map(|(_, amount)| *amount).collect();
+        let selected = match self.strategy {
+            SelectionStrategy::LargestFirst => largest_first(&amounts, target),
+            SelectionStrategy::BranchAndBound { max_tries } => {
+                branch_and_bound(&amounts, target, max_tries)
+                    .unwrap_or_else(|| largest_first(&amounts, target))
+            }
+        };
+
+        Ok(selected
+            .into_iter()
+            .filter_map(|index| candidates.get(index).map(|(input, _)| *input))
+            .collect())
+    }
+}
+
+/// Indices of the largest of the descending `amounts` covering the target.
+fn largest_first(amounts: &[Word], target: u128) -> Vec<usize> {
+    let mut sum = 0u128;
+    amounts
+        .iter()
+        .enumerate()
+        .take_while(|(_, amount)| {
+            let covered = sum >= target;
+            sum = sum.saturating_add(u128::from(**amount));
+            !covered
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Indices of the descending `amounts` exceeding the target by the least amount.
+fn branch_and_bound(
+    amounts: &[Word],
+    target: u128,
+    max_tries: usize,
+) -> Option<Vec<usize>> {
+    // The sums of `amounts[index..]`, to prune the branches not reaching the target.
+    let mut remaining = Vec::with_capacity(amounts.len().saturating_add(1));
+    remaining.push(0u128);
+    for amount in amounts.iter().rev() {
+        let sum = remaining.last().copied().unwrap_or_default();
+        remaining.push(sum.saturating_add(u128::from(*amount)));
+    }
+    remaining.reverse();
+
+    let mut search = Search {
+        amounts,
+        remaining: &remaining,
+        target,
+        tries: max_tries,
+        selected: Vec::new(),
+        best: None,
+    };
+    search.explore(0, 0);
+    search.best.map(|(_, selected)| selected)
+}
+
+struct Search<'a> {
+    amounts: &'a [Word],
+    remaining: &'a [u128],
+    target: u128,
+    tries: usize,
+    selected: Vec<usize>,
+    best: Option<(u128, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    fn explore(&mut self, index: usize, sum: u128) {
+        if self.tries == 0
+            || self
+                .best
+                .as_ref()
+                .is_some_and(|(best, _)| *best == self.target)
+        {
+            return
+        }
+        self.tries = self.tries.saturating_sub(1);
+
+        if sum >= self.target {
+            if self.best.as_ref().map_or(true, |(best, _)| sum < *best) {
+                self.best = Some((sum, self.selected.clone()));
+            }
+            return
+        }
+        let Some(amount) = self.amounts.get(index) else {
+            return
+        };
+        let remaining = self.remaining.get(index).copied().unwrap_or_default();
+        if sum.saturating_add(remaining) < self.target
+            || self.best.as_ref().is_some_and(|(best, _)| sum >= *best)
+        {
+            return
+        }
+
+        let next = index.saturating_add(1);
+        self.selected.push(index);
+        self.explore(next, sum.saturating_add(u128::from(*amount)));
+        self.selected.pop();
+        self.explore(next, sum);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        field::{
+            Inputs,
+            Witnesses,
+        },
+        Finalizable,
+        FormatValidityChecks,
+        TransactionBuilder,
+    };
+    use alloc::vec;
+    use fuel_crypto::SecretKey;
+    use fuel_types::ChainId;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn coins(rng: &mut StdRng, asset_id: AssetId, amounts: &[Word]) -> Vec<Input> {
+        amounts
+            .iter()
+            .map(|amount| {
+                Input::coin_signed(rng.gen(), rng.gen(), *amount, asset_id, rng.gen(), 0)
+            })
+            .collect()
+    }
+
+    fn amounts(selected: &[&Input]) -> Vec<Word> {
+        selected.iter().filter_map(|input| input.amount()).collect()
+    }
+
+    #[test]
+    fn largest_first_selects_the_fewest_inputs() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let available = coins(rng, AssetId::BASE, &[10, 50, 30, 20]);
+
+        let selected = CoinSelector::new(AssetId::BASE, 60)
+            .with_fee(5)
+            .select(&available, &AssetId::BASE)
+            .unwrap();
+
+        assert_eq!(amounts(&selected), [50, 30]);
+    }
+
+    #[test]
+    fn branch_and_bound_minimizes_the_change() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let available = coins(rng, AssetId::BASE, &[10, 50, 30, 20]);
+
+        let selected = CoinSelector::new(AssetId::BASE, 60)
+            .with_strategy(SelectionStrategy::BranchAndBound { max_tries: 100 })
+            .select(&available, &AssetId::BASE)
+            .unwrap();
+
+        assert_eq!(amounts(&selected), [50, 10]);
+    }
+
+    #[test]
+    fn only_spendable_inputs_of_the_asset_are_selected() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let other_asset: AssetId = rng.gen();
+        let mut available = coins(rng, other_asset, &[100]);
+        available.push(Input::message_coin_signed(
+            rng.gen(),
+            rng.gen(),
+            40,
+            rng.gen(),
+            0,
+        ));
+        available.push(Input::message_data_signed(
+            rng.gen(),
+            rng.gen(),
+            100,
+            rng.gen(),
+            0,
+            vec![1],
+        ));
+
+        let selector = CoinSelector::new(AssetId::BASE, 30);
+        assert_eq!(
+            amounts(&selector.select(&available, &AssetId::BASE).unwrap()),
+            [40]
+        );
+        assert_eq!(
+            CoinSelector::new(AssetId::BASE, 50).select(&available, &AssetId::BASE),
+            Err(CoinSelectionError::InsufficientFunds {
+                available: 40,
+                required: 50,
+            })
+        );
+    }
+
+    #[test]
+    fn selected_inputs_are_signed_by_the_builder() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let secret = SecretKey::random(rng);
+        let owner = Input::owner(&secret.public_key());
+        let available = [10, 20, 30].map(|amount| {
+            Input::coin_signed(rng.gen(), owner, amount, AssetId::BASE, rng.gen(), 0)
+        });
+
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .max_fee_limit(0)
+            .add_selected_inputs(
+                &CoinSelector::new(AssetId::BASE, 40),
+                &available,
+                secret,
+            )
+            .unwrap()
+            .finalize();
+
+        assert_eq!(tx.inputs().len(), 2);
+        assert_eq!(tx.witnesses().len(), 1);
+        assert_eq!(tx.check_signatures(&ChainId::default()), Ok(()));
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
 use derivative::Derivative;
 use fuel_crypto::Hasher;
 use fuel_merkle::{
-    binary::root_calculator::MerkleRootCalculator as BinaryMerkleTree,
+    binary::root_calculator::ChunkedRootCalculator,
     sparse::{
         in_memory::MerkleTree as SparseMerkleTree,
         MerkleTreeKey,
@@ -73,23 +73,11 @@ impl Contract {
     where
         B: AsRef<[u8]>,
     {
-        let mut tree = BinaryMerkleTree::new();
-        bytes.as_ref().chunks(LEAF_SIZE).for_each(|leaf| {
-            // If the bytecode is not a multiple of LEAF_SIZE, the final leaf
-            // should be zero-padded rounding up to the nearest multiple of 8
-            // bytes.
-            let len = leaf.len();
-            if len == LEAF_SIZE || len % MULTIPLE == 0 {
-                tree.push(leaf);
-            } else {
-                let padding_size = len.next_multiple_of(MULTIPLE);
-                let mut padded_leaf = [PADDING_BYTE; LEAF_SIZE];
-                padded_leaf[0..len].clone_from_slice(leaf);
-                tree.push(padded_leaf[..padding_size].as_ref());
-            }
-        });
-
-        tree.root().into()
+        let mut tree = ChunkedRootCalculator::new(LEAF_SIZE);
+        tree.update(bytes.as_ref());
+        // If the bytecode is not a multiple of LEAF_SIZE, the final leaf should be
+        // zero-padded rounding up to the nearest multiple of 8 bytes.
+        tree.root_padded(MULTIPLE, PADDING_BYTE).into()
     }
 
     /// Calculate the root of the initial storage slots for this contract
@@ -122,6 +110,23 @@ impl Contract {
 
         ContractId::from(*hasher.digest())
     }
+
+    /// Calculate the id of a contract created by the `creator` contract, provided a
+    /// salt and the code root of the created contract.
+    ///
+    /// The id doesn't depend on the initial state, so it can be computed before the
+    /// contract is created, and can't collide with the ids of contracts deployed with
+    /// transactions as a different seed is used.
+    pub fn child_id(creator: &ContractId, salt: &Salt, root: &Bytes32) -> ContractId {
+        let mut hasher = Hasher::default();
+
+        hasher.input(ContractId::CHILD_SEED);
+        hasher.input(creator);
+        hasher.input(salt);
+        hasher.input(root);
+
+        ContractId::from(*hasher.digest())
+    }
 }
 
 impl From<Vec<u8>> for Contract {
@@ -177,6 +182,7 @@ impl TryFrom<&Transaction> for Contract {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use fuel_merkle::binary::root_calculator::MerkleRootCalculator as BinaryMerkleTree;
     use fuel_types::{
         bytes::WORD_SIZE,
         Bytes64,
@@ -364,4 +370,25 @@ mod tests {
         let calculated_id = contract.id(&salt, &root, &state_root);
         assert_eq!(calculated_id, Contract::EMPTY_CONTRACT_ID)
     }
+
+    #[test]
+    fn child_id_depends_on_creator_salt_and_root() {
+        let creator = ContractId::zeroed();
+        let salt = Salt::zeroed();
+        let root = Contract::from(vec![]).root();
+
+        let id = Contract::child_id(&creator, &salt, &root);
+
+        assert_eq!(id, Contract::child_id(&creator, &salt, &root));
+        assert_ne!(
+            id,
+            Contract::child_id(&ContractId::new([1; 32]), &salt, &root)
+        );
+        assert_ne!(id, Contract::child_id(&creator, &Salt::new([1; 32]), &root));
+        assert_ne!(
+            id,
+            Contract::child_id(&creator, &salt, &Bytes32::new([1; 32]))
+        );
+        assert_ne!(id, Contract::EMPTY_CONTRACT_ID);
+    }
 }
@@ -0,0 +1,903 @@
+//! Canonical JSON encoding of the transactions, the receipts and the consensus
+//! parameters.
+//!
+//! Unlike the output of the derived `serde` implementations, the shape of the canonical
+//! encoding is part of the public API: it is written by hand and only changes together
+//! with the types. It is intended for the consumers outside of Rust, like explorers and
+//! hardware wallets.
+//!
+//! The encoding follows these rules:
+//! - Objects use `snake_case` keys named after the fields of the types. The order of the
+//!   keys isn't part of the encoding.
+//! - Every variant of [`Transaction`], [`Input`], [`Output`], [`Receipt`],
+//!   [`UpgradePurpose`], [`ScriptExecutionResult`] and [`DependentCost`] is an object
+//!   tagged by the `"type"` key holding the name of the variant, e.g. `"Script"`.
+//! - Versioned types, like [`ConsensusParameters`] and [`TxParameters`], are objects
+//!   tagged by the `"version"` key, e.g. `"V1"`.
+//! - Byte arrays and byte vectors are `0x`-prefixed lowercase hex strings.
+//! - 64-bit integers are decimal strings, so they survive parsers limited to `f64`.
+//!   Narrower integers are numbers.
+//! - [`Policies`] contain only the policies that are set, as decimal strings.
+//! - Gas costs contain only the costs defined by their version. Fixed costs are decimal
+//!   strings, dependent costs are tagged objects.
+//! - Fields skipped by the canonical binary encoding, like the data of the
+//!   [`Receipt::LogData`], are encoded as `null` when they are absent.
+//!
+//! ```json
+//! {
+//!   "type": "Blob",
+//!   "id": "0x0a...",
+//!   "witness_index": 0,
+//!   "policies": { "max_fee": "1000" },
+//!   "inputs": [],
+//!   "outputs": [],
+//!   "witnesses": ["0x..."]
+//! }
+//! ```
+
+use crate::{
+    consensus_parameters::gas::{
+        GasCostComponent,
+        GasCostsValues,
+    },
+    field::{
+        BlobId as _,
+        BytecodeRoot,
+        BytecodeWitnessIndex,
+        InputContract,
+        Inputs,
+        MintAmount,
+        MintAssetId,
+        MintGasPrice,
+        OutputContract,
+        Outputs,
+        ProofSet,
+        ReceiptsRoot,
+        Salt as _,
+        Script as _,
+        ScriptData,
+        ScriptGasLimit,
+        StorageSlots,
+        SubsectionIndex,
+        SubsectionsNumber,
+        TxPointer as _,
+        UpgradePurpose as _,
+        Witnesses,
+    },
+    input,
+    output,
+    policies::{
+        Policies,
+        PolicyType,
+    },
+    ConsensusParameters,
+    ContractParameters,
+    DependentCost,
+    FeeParameters,
+    GasCosts,
+    Input,
+    Output,
+    PredicateParameters,
+    Receipt,
+    ScriptExecutionResult,
+    ScriptParameters,
+    Transaction,
+    TxParameters,
+    TxPointer,
+    UpgradePurpose,
+    UtxoId,
+    Witness,
+};
+use alloc::{
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::fmt::Write;
+use fuel_types::Word;
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+use strum::IntoEnumIterator;
+
+/// The type with the canonical JSON encoding described in the [module](self)
+/// documentation.
+pub trait CanonicalJson {
+    /// Encodes `self` into the canonical JSON value.
+    fn to_canonical_json(&self) -> Value;
+
+    /// Encodes `self` into the compact canonical JSON string.
+    fn to_canonical_json_string(&self) -> String {
+        self.to_canonical_json().to_string()
+    }
+}
+
+fn hex(bytes: impl AsRef<[u8]>) -> Value {
+    let bytes = bytes.as_ref();
+    let mut encoded =
+        String::with_capacity(bytes.len().saturating_mul(2).saturating_add(2));
+    encoded.push_str("0x");
+    for byte in bytes {
+        write!(encoded, "{byte:02x}").expect("Writing into `String` is infallible");
+    }
+    Value::String(encoded)
+}
+
+fn word(value: Word) -> Value {
+    Value::String(value.to_string())
+}
+
+fn optional_hex(bytes: &Option<Vec<u8>>) -> Value {
+    bytes.as_ref().map(hex).unwrap_or(Value::Null)
+}
+
+/// Adds the `"type"` tag to the `fields` object.
+fn tagged(tag: &str, fields: Value) -> Value {
+    let mut object = Map::new();
+    object.insert("type".to_string(), Value::String(tag.to_string()));
+    if let Value::Object(fields) = fields {
+        object.extend(fields);
+    }
+    Value::Object(object)
+}
+
+/// Adds the `"version"` tag to the `fields` object.
+fn versioned(version: &str, fields: Value) -> Value {
+    let mut object = Map::new();
+    object.insert("version".to_string(), Value::String(version.to_string()));
+    if let Value::Object(fields) = fields {
+        object.extend(fields);
+    }
+    Value::Object(object)
+}
+
+/// Adds the fields shared by all chargeable transactions to the `body` object.
+fn chargeable<Tx>(tag: &str, tx: &Tx, body: Value) -> Value
+where
+    Tx: crate::field::Policies + Inputs + Outputs + Witnesses,
+{
+    let mut object = tagged(tag, body);
+    if let Value::Object(object) = &mut object {
+        object.insert("policies".to_string(), tx.policies().to_canonical_json());
+        object.insert("inputs".to_string(), tx.inputs().to_canonical_json());
+        object.insert("outputs".to_string(), tx.outputs().to_canonical_json());
+        object.insert("witnesses".to_string(), tx.witnesses().to_canonical_json());
+    }
+    object
+}
+
+impl<T: CanonicalJson> CanonicalJson for [T] {
+    fn to_canonical_json(&self) -> Value {
+        Value::Array(self.iter().map(CanonicalJson::to_canonical_json).collect())
+    }
+}
+
+impl<T: CanonicalJson> CanonicalJson for Vec<T> {
+    fn to_canonical_json(&self) -> Value {
+        self.as_slice().to_canonical_json()
+    }
+}
+
+impl CanonicalJson for Transaction {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            Transaction::Script(tx) => chargeable(
+                "Script",
+                tx,
+                json!({
+                    "script_gas_limit": word(*tx.script_gas_limit()),
+                    "receipts_root": hex(tx.receipts_root()),
+                    "script": hex(tx.script()),
+                    "script_data": hex(tx.script_data()),
+                }),
+            ),
+            Transaction::Create(tx) => chargeable(
+                "Create",
+                tx,
+                json!({
+                    "bytecode_witness_index": tx.bytecode_witness_index(),
+                    "salt": hex(tx.salt()),
+                    "storage_slots": tx
+                        .storage_slots()
+                        .iter()
+                        .map(|slot| json!({
+                            "key": hex(slot.key()),
+                            "value": hex(slot.value()),
+                        }))
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+            Transaction::Mint(tx) => tagged(
+                "Mint",
+                json!({
+                    "tx_pointer": tx.tx_pointer().to_canonical_json(),
+                    "input_contract": contract_input(tx.input_contract()),
+                    "output_contract": contract_output(tx.output_contract()),
+                    "mint_amount": word(*tx.mint_amount()),
+                    "mint_asset_id": hex(tx.mint_asset_id()),
+                    "gas_price": word(*tx.gas_price()),
+                }),
+            ),
+            Transaction::Upgrade(tx) => chargeable(
+                "Upgrade",
+                tx,
+                json!({
+                    "purpose": tx.upgrade_purpose().to_canonical_json(),
+                }),
+            ),
+            Transaction::Upload(tx) => chargeable(
+                "Upload",
+                tx,
+                json!({
+                    "root": hex(tx.bytecode_root()),
+                    "witness_index": tx.bytecode_witness_index(),
+                    "subsection_index": tx.subsection_index(),
+                    "subsections_number": tx.subsections_number(),
+                    "proof_set": tx.proof_set().iter().map(hex).collect::<Vec<_>>(),
+                }),
+            ),
+            Transaction::Blob(tx) => chargeable(
+                "Blob",
+                tx,
+                json!({
+                    "id": hex(tx.blob_id()),
+                    "witness_index": tx.bytecode_witness_index(),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for UpgradePurpose {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            UpgradePurpose::ConsensusParameters {
+                witness_index,
+                checksum,
+            } => tagged(
+                "ConsensusParameters",
+                json!({
+                    "witness_index": witness_index,
+                    "checksum": hex(checksum),
+                }),
+            ),
+            UpgradePurpose::StateTransition { root } => tagged(
+                "StateTransition",
+                json!({
+                    "root": hex(root),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for Policies {
+    fn to_canonical_json(&self) -> Value {
+        let policies = PolicyType::iter()
+            .filter_map(|policy_type| {
+                self.get(policy_type)
+                    .map(|value| (policy_type.name().to_string(), word(value)))
+            })
+            .collect();
+        Value::Object(policies)
+    }
+}
+
+impl CanonicalJson for Witness {
+    fn to_canonical_json(&self) -> Value {
+        hex(self.as_vec())
+    }
+}
+
+impl CanonicalJson for UtxoId {
+    fn to_canonical_json(&self) -> Value {
+        json!({
+            "tx_id": hex(self.tx_id()),
+            "output_index": self.output_index(),
+        })
+    }
+}
+
+impl CanonicalJson for TxPointer {
+    fn to_canonical_json(&self) -> Value {
+        json!({
+            "block_height": *self.block_height(),
+            "tx_index": self.tx_index(),
+        })
+    }
+}
+
+fn contract_input(contract: &input::contract::Contract) -> Value {
+    json!({
+        "utxo_id": contract.utxo_id.to_canonical_json(),
+        "balance_root": hex(contract.balance_root),
+        "state_root": hex(contract.state_root),
+        "tx_pointer": contract.tx_pointer.to_canonical_json(),
+        "contract_id": hex(contract.contract_id),
+    })
+}
+
+fn contract_output(contract: &output::contract::Contract) -> Value {
+    json!({
+        "input_index": contract.input_index,
+        "balance_root": hex(contract.balance_root),
+        "state_root": hex(contract.state_root),
+    })
+}
+
+impl CanonicalJson for Input {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            Input::CoinSigned(coin) => tagged(
+                "CoinSigned",
+                json!({
+                    "utxo_id": coin.utxo_id.to_canonical_json(),
+                    "owner": hex(coin.owner),
+                    "amount": word(coin.amount),
+                    "asset_id": hex(coin.asset_id),
+                    "tx_pointer": coin.tx_pointer.to_canonical_json(),
+                    "witness_index": coin.witness_index,
+                }),
+            ),
+            Input::CoinPredicate(coin) => tagged(
+                "CoinPredicate",
+                json!({
+                    "utxo_id": coin.utxo_id.to_canonical_json(),
+                    "owner": hex(coin.owner),
+                    "amount": word(coin.amount),
+                    "asset_id": hex(coin.asset_id),
+                    "tx_pointer": coin.tx_pointer.to_canonical_json(),
+                    "predicate_gas_used": word(coin.predicate_gas_used),
+                    "predicate": hex(&coin.predicate.bytes),
+                    "predicate_data": hex(&coin.predicate_data),
+                }),
+            ),
+            Input::Contract(contract) => tagged("Contract", contract_input(contract)),
+            Input::MessageCoinSigned(message) => tagged(
+                "MessageCoinSigned",
+                json!({
+                    "sender": hex(message.sender),
+                    "recipient": hex(message.recipient),
+                    "amount": word(message.amount),
+                    "nonce": hex(message.nonce),
+                    "witness_index": message.witness_index,
+                }),
+            ),
+            Input::MessageCoinPredicate(message) => tagged(
+                "MessageCoinPredicate",
+                json!({
+                    "sender": hex(message.sender),
+                    "recipient": hex(message.recipient),
+                    "amount": word(message.amount),
+                    "nonce": hex(message.nonce),
+                    "predicate_gas_used": word(message.predicate_gas_used),
+                    "predicate": hex(&message.predicate.bytes),
+                    "predicate_data": hex(&message.predicate_data),
+                }),
+            ),
+            Input::MessageDataSigned(message) => tagged(
+                "MessageDataSigned",
+                json!({
+                    "sender": hex(message.sender),
+                    "recipient": hex(message.recipient),
+                    "amount": word(message.amount),
+                    "nonce": hex(message.nonce),
+                    "witness_index": message.witness_index,
+                    "data": hex(&message.data),
+                }),
+            ),
+            Input::MessageDataPredicate(message) => tagged(
+                "MessageDataPredicate",
+                json!({
+                    "sender": hex(message.sender),
+                    "recipient": hex(message.recipient),
+                    "amount": word(message.amount),
+                    "nonce": hex(message.nonce),
+                    "data": hex(&message.data),
+                    "predicate_gas_used": word(message.predicate_gas_used),
+                    "predicate": hex(&message.predicate.bytes),
+                    "predicate_data": hex(&message.predicate_data),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for Output {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            Output::Coin {
+                to,
+                amount,
+                asset_id,
+            } => tagged(
+                "Coin",
+                json!({
+                    "to": hex(to),
+                    "amount": word(*amount),
+                    "asset_id": hex(asset_id),
+                }),
+            ),
+            Output::Contract(contract) => tagged("Contract", contract_output(contract)),
+            Output::Change {
+                to,
+                amount,
+                asset_id,
+            } => tagged(
+                "Change",
+                json!({
+                    "to": hex(to),
+                    "amount": word(*amount),
+                    "asset_id": hex(asset_id),
+                }),
+            ),
+            Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } => tagged(
+                "Variable",
+                json!({
+                    "to": hex(to),
+                    "amount": word(*amount),
+                    "asset_id": hex(asset_id),
+                }),
+            ),
+            Output::ContractCreated {
+                contract_id,
+                state_root,
+            } => tagged(
+                "ContractCreated",
+                json!({
+                    "contract_id": hex(contract_id),
+                    "state_root": hex(state_root),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for ScriptExecutionResult {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            ScriptExecutionResult::Success => tagged("Success", json!({})),
+            ScriptExecutionResult::Revert => tagged("Revert", json!({})),
+            ScriptExecutionResult::Panic => tagged("Panic", json!({})),
+            ScriptExecutionResult::GenericFailure(code) => tagged(
+                "GenericFailure",
+                json!({
+                    "code": word(*code),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for Receipt {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            Receipt::Call {
+                id,
+                to,
+                amount,
+                asset_id,
+                gas,
+                param1,
+                param2,
+                pc,
+                is,
+            } => tagged(
+                "Call",
+                json!({
+                    "id": hex(id),
+                    "to": hex(to),
+                    "amount": word(*amount),
+                    "asset_id": hex(asset_id),
+                    "gas": word(*gas),
+                    "param1": word(*param1),
+                    "param2": word(*param2),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+            Receipt::Return { id, val, pc, is } => tagged(
+                "Return",
+                json!({
+                    "id": hex(id),
+                    "val": word(*val),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+            Receipt::ReturnData {
+                id,
+                ptr,
+                len,
+                digest,
+                pc,
+                is,
+                data,
+            } => tagged(
+                "ReturnData",
+                json!({
+                    "id": hex(id),
+                    "ptr": word(*ptr),
+                    "len": word(*len),
+                    "digest": hex(digest),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                    "data": optional_hex(data),
+                }),
+            ),
+            Receipt::Panic {
+                id,
+                reason,
+                pc,
+                is,
+                contract_id,
+            } => tagged(
+                "Panic",
+                json!({
+                    "id": hex(id),
+                    "reason": {
+                        "reason": alloc::format!("{:?}", reason.reason()),
+                        "instruction": reason.instruction(),
+                    },
+                    "pc": word(*pc),
+                    "is": word(*is),
+                    "contract_id": contract_id.as_ref().map(hex),
+                }),
+            ),
+            Receipt::Revert { id, ra, pc, is } => tagged(
+                "Revert",
+                json!({
+                    "id": hex(id),
+                    "ra": word(*ra),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+            Receipt::Log {
+                id,
+                ra,
+                rb,
+                rc,
+                rd,
+                pc,
+                is,
+            } => tagged(
+                "Log",
+                json!({
+                    "id": hex(id),
+                    "ra": word(*ra),
+                    "rb": word(*rb),
+                    "rc": word(*rc),
+                    "rd": word(*rd),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+            Receipt::LogData {
+                id,
+                ra,
+                rb,
+                ptr,
+                len,
+                digest,
+                pc,
+                is,
+                data,
+            } => tagged(
+                "LogData",
+                json!({
+                    "id": hex(id),
+                    "ra": word(*ra),
+                    "rb": word(*rb),
+                    "ptr": word(*ptr),
+                    "len": word(*len),
+                    "digest": hex(digest),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                    "data": optional_hex(data),
+                }),
+            ),
+            Receipt::Transfer {
+                id,
+                to,
+                amount,
+                asset_id,
+                pc,
+                is,
+            } => tagged(
+                "Transfer",
+                json!({
+                    "id": hex(id),
+                    "to": hex(to),
+                    "amount": word(*amount),
+                    "asset_id": hex(asset_id),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+            Receipt::TransferOut {
+                id,
+                to,
+                amount,
+                asset_id,
+                pc,
+                is,
+            } => tagged(
+                "TransferOut",
+                json!({
+                    "id": hex(id),
+                    "to": hex(to),
+                    "amount": word(*amount),
+                    "asset_id": hex(asset_id),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+            Receipt::ScriptResult { result, gas_used } => tagged(
+                "ScriptResult",
+                json!({
+                    "result": result.to_canonical_json(),
+                    "gas_used": word(*gas_used),
+                }),
+            ),
+            Receipt::MessageOut {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                len,
+                digest,
+                data,
+            } => tagged(
+                "MessageOut",
+                json!({
+                    "sender": hex(sender),
+                    "recipient": hex(recipient),
+                    "amount": word(*amount),
+                    "nonce": hex(nonce),
+                    "len": word(*len),
+                    "digest": hex(digest),
+                    "data": optional_hex(data),
+                }),
+            ),
+            Receipt::Mint {
+                sub_id,
+                contract_id,
+                val,
+                pc,
+                is,
+            } => tagged(
+                "Mint",
+                json!({
+                    "sub_id": hex(sub_id),
+                    "contract_id": hex(contract_id),
+                    "val": word(*val),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+            Receipt::Burn {
+                sub_id,
+                contract_id,
+                val,
+                pc,
+                is,
+            } => tagged(
+                "Burn",
+                json!({
+                    "sub_id": hex(sub_id),
+                    "contract_id": hex(contract_id),
+                    "val": word(*val),
+                    "pc": word(*pc),
+                    "is": word(*is),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for ConsensusParameters {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            ConsensusParameters::V1(params) => versioned(
+                "V1",
+                json!({
+                    "tx_params": params.tx_params.to_canonical_json(),
+                    "predicate_params": params.predicate_params.to_canonical_json(),
+                    "script_params": params.script_params.to_canonical_json(),
+                    "contract_params": params.contract_params.to_canonical_json(),
+                    "fee_params": params.fee_params.to_canonical_json(),
+                    "chain_id": word(*params.chain_id),
+                    "gas_costs": params.gas_costs.to_canonical_json(),
+                    "base_asset_id": hex(params.base_asset_id),
+                    "block_gas_limit": word(params.block_gas_limit),
+                    "privileged_address": hex(params.privileged_address),
+                }),
+            ),
+            ConsensusParameters::V2(params) => versioned(
+                "V2",
+                json!({
+                    "tx_params": params.tx_params.to_canonical_json(),
+                    "predicate_params": params.predicate_params.to_canonical_json(),
+                    "script_params": params.script_params.to_canonical_json(),
+                    "contract_params": params.contract_params.to_canonical_json(),
+                    "fee_params": params.fee_params.to_canonical_json(),
+                    "chain_id": word(*params.chain_id),
+                    "gas_costs": params.gas_costs.to_canonical_json(),
+                    "base_asset_id": hex(params.base_asset_id),
+                    "block_gas_limit": word(params.block_gas_limit),
+                    "block_transaction_size_limit": word(params.block_transaction_size_limit),
+                    "privileged_address": hex(params.privileged_address),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for TxParameters {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            TxParameters::V1(params) => versioned(
+                "V1",
+                json!({
+                    "max_inputs": params.max_inputs,
+                    "max_outputs": params.max_outputs,
+                    "max_witnesses": params.max_witnesses,
+                    "max_gas_per_tx": word(params.max_gas_per_tx),
+                    "max_size": word(params.max_size),
+                    "max_bytecode_subsections": params.max_bytecode_subsections,
+                }),
+            ),
+            TxParameters::V2(params) => versioned(
+                "V2",
+                json!({
+                    "max_inputs": params.max_inputs,
+                    "max_outputs": params.max_outputs,
+                    "max_witnesses": params.max_witnesses,
+                    "max_gas_per_tx": word(params.max_gas_per_tx),
+                    "max_size": word(params.max_size),
+                    "max_bytecode_subsections": params.max_bytecode_subsections,
+                    "max_message_outputs": params.max_message_outputs,
+                    "max_message_outputs_data_length": word(params.max_message_outputs_data_length),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for PredicateParameters {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            PredicateParameters::V1(params) => versioned(
+                "V1",
+                json!({
+                    "max_predicate_length": word(params.max_predicate_length),
+                    "max_predicate_data_length": word(params.max_predicate_data_length),
+                    "max_message_data_length": word(params.max_message_data_length),
+                    "max_gas_per_predicate": word(params.max_gas_per_predicate),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for ScriptParameters {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            ScriptParameters::V1(params) => versioned(
+                "V1",
+                json!({
+                    "max_script_length": word(params.max_script_length),
+                    "max_script_data_length": word(params.max_script_data_length),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for ContractParameters {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            ContractParameters::V1(params) => versioned(
+                "V1",
+                json!({
+                    "contract_max_size": word(params.contract_max_size),
+                    "max_storage_slots": word(params.max_storage_slots),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for FeeParameters {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            FeeParameters::V1(params) => versioned(
+                "V1",
+                json!({
+                    "gas_price_factor": word(params.gas_price_factor),
+                    "gas_per_byte": word(params.gas_per_byte),
+                }),
+            ),
+        }
+    }
+}
+
+impl CanonicalJson for GasCosts {
+    fn to_canonical_json(&self) -> Value {
+        let version = match **self {
+            GasCostsValues::V1(_) => "V1",
+            GasCostsValues::V2(_) => "V2",
+            GasCostsValues::V3(_) => "V3",
+            GasCostsValues::V4(_) => "V4",
+            GasCostsValues::V5(_) => "V5",
+        };
+
+        let costs = crate::consensus_parameters::gas::costs(self)
+            .filter_map(|(operation, components)| {
+                let component = |component| {
+                    components
+                        .iter()
+                        .find(|(c, _)| *c == component)
+                        .map(|(_, value)| *value)
+                };
+                let base = component(GasCostComponent::Base);
+                let cost = match (
+                    component(GasCostComponent::Fixed),
+                    component(GasCostComponent::UnitsPerGas),
+                    component(GasCostComponent::GasPerUnit),
+                ) {
+                    (Some(fixed), _, _) => word(fixed),
+                    (_, Some(units_per_gas), _) => DependentCost::LightOperation {
+                        base: base.unwrap_or_default(),
+                        units_per_gas,
+                    }
+                    .to_canonical_json(),
+                    (_, _, Some(gas_per_unit)) => DependentCost::HeavyOperation {
+                        base: base.unwrap_or_default(),
+                        gas_per_unit,
+                    }
+                    .to_canonical_json(),
+                    _ => return None,
+                };
+                Some((operation.to_string(), cost))
+            })
+            .collect();
+
+        let mut object = Map::new();
+        object.insert("costs".to_string(), Value::Object(costs));
+        versioned(version, Value::Object(object))
+    }
+}
+
+impl CanonicalJson for DependentCost {
+    fn to_canonical_json(&self) -> Value {
+        match self {
+            DependentCost::LightOperation {
+                base,
+                units_per_gas,
+            } => tagged(
+                "LightOperation",
+                json!({
+                    "base": word(*base),
+                    "units_per_gas": word(*units_per_gas),
+                }),
+            ),
+            DependentCost::HeavyOperation { base, gas_per_unit } => tagged(
+                "HeavyOperation",
+                json!({
+                    "base": word(*base),
+                    "gas_per_unit": word(*gas_per_unit),
+                }),
+            ),
+        }
+    }
+}
@@ -42,15 +42,32 @@ pub use fuel_types::{
 };
 pub use tx_pointer::TxPointer;
 
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+
+#[cfg(feature = "alloc")]
+pub mod asset_registry;
+
 #[cfg(feature = "test-helpers")]
 mod builder;
 
+#[cfg(feature = "alloc")]
+pub mod coin_selection;
+
 #[cfg(feature = "alloc")]
 mod contract;
 
+#[cfg(feature = "serde")]
+pub mod json;
+
+#[cfg(feature = "proto")]
+pub mod proto;
+
 #[cfg(feature = "alloc")]
 mod receipt;
 
+mod signer;
+
 #[cfg(feature = "alloc")]
 mod transaction;
 
@@ -64,18 +81,38 @@ pub mod test_helper;
 pub use builder::{
     Buildable,
     Finalizable,
+    MultisigWitness,
     TransactionBuilder,
 };
 
 #[cfg(feature = "alloc")]
 pub use receipt::{
+    receipts_root,
+    DecodedLog,
+    InclusionProofError,
+    LogDecodeError,
+    LogDecodeFn,
+    LogDecoder,
+    MerkleProof,
     Receipt,
+    ReceiptInclusionProof,
+    Receipts,
     ScriptExecutionResult,
+    ScriptReturn,
+    WithdrawalPayload,
+    WithdrawalPayloadError,
+};
+
+pub use signer::{
+    AsyncSigner,
+    Signer,
+    SigningError,
 };
 
 #[cfg(feature = "alloc")]
 pub use transaction::{
     consensus_parameters,
+    fee,
     field,
     input,
     input::Input,
@@ -92,23 +129,50 @@ pub use transaction::{
     Chargeable,
     ChargeableMetadata,
     ChargeableTransaction,
+    CoinRef,
+    CoinSpent,
     ConsensusParameters,
+    ConsensusParametersBundle,
+    ConsensusParametersBundleError,
     ContractParameters,
+    ContractRef,
     Create,
     CreateMetadata,
     DependentCost,
     Executable,
+    FeeBreakdown,
     FeeParameters,
+    FeeScenario,
+    FeeSimulator,
     FormatValidityChecks,
+    GasCostModel,
     GasCosts,
     GasCostsValues,
+    GasModelRef,
+    InputRef,
+    MalleableField,
+    MalleableInputField,
+    MalleableOutputField,
+    MessageConsumed,
+    MessageRef,
     Mint,
+    OutputTo,
+    PartialSigningError,
+    PartiallySignedTransaction,
+    PolicyChange,
     PredicateParameters,
+    RequiredSignature,
     Script,
     ScriptCode,
     ScriptParameters,
+    ScriptRef,
+    SequenceDiff,
+    SharedGasCostModel,
+    SplitError,
     StorageSlot,
     Transaction,
+    TransactionDiff,
+    TransactionDiffError,
     TransactionFee,
     TransactionRepr,
     TxId,
@@ -123,6 +187,7 @@ pub use transaction::{
     UploadSubsection,
     UtxoId,
     ValidityError,
+    ValidityReport,
     Witness,
 };
 
@@ -139,6 +204,15 @@ pub use transaction::{
     UniqueIdentifier,
 };
 
+#[cfg(feature = "alloc")]
+pub use asset_registry::{
+    AssetOrigin,
+    AssetRegistry,
+};
+
+#[cfg(feature = "alloc")]
+pub use coin_selection::CoinSelector;
+
 #[cfg(feature = "alloc")]
 pub use contract::Contract;
 
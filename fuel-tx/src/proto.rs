@@ -0,0 +1,1577 @@
+//! Protobuf messages of the transactions and the receipts.
+//!
+//! The messages mirror the `proto/fuel_tx.proto` schema of the crate, so the consumers
+//! in other languages can generate the same messages from it. The conversions into the
+//! messages are infallible, while the conversions from them fail with [`ProtoError`] if
+//! a message doesn't describe a valid `fuel-tx` type.
+
+use crate::{
+    field::{
+        BlobId as _,
+        BytecodeRoot,
+        BytecodeWitnessIndex,
+        InputContract as _,
+        Inputs,
+        MintAmount,
+        MintAssetId,
+        MintGasPrice,
+        OutputContract as _,
+        Outputs,
+        ProofSet,
+        ReceiptsRoot,
+        Salt as _,
+        Script as _,
+        ScriptData,
+        ScriptGasLimit,
+        StorageSlots,
+        SubsectionIndex,
+        SubsectionsNumber,
+        TxPointer as _,
+        UpgradePurpose as _,
+        Witnesses,
+    },
+    input,
+    output,
+    policies::PolicyType,
+    PanicInstruction,
+    ScriptExecutionResult,
+};
+use alloc::vec::Vec;
+use fuel_types::Word;
+
+/// An error produced by the conversion of a message into a `fuel-tx` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProtoError {
+    /// The required field, or the variant of the `oneof` field, is missing.
+    MissingField(&'static str),
+    /// The byte field doesn't have the length of its fixed-size type.
+    InvalidLength(&'static str),
+    /// The integer field doesn't fit into its type.
+    OutOfRange(&'static str),
+}
+
+impl core::fmt::Display for ProtoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ProtoError::MissingField(field) => {
+                write!(f, "the `{field}` field is missing")
+            }
+            ProtoError::InvalidLength(field) => {
+                write!(f, "the `{field}` field has an invalid length")
+            }
+            ProtoError::OutOfRange(field) => {
+                write!(f, "the `{field}` field is out of range")
+            }
+        }
+    }
+}
+
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, ProtoError> {
+    value.ok_or(ProtoError::MissingField(field))
+}
+
+fn array<T>(bytes: &[u8], field: &'static str) -> Result<T, ProtoError>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    T::try_from(bytes).map_err(|_| ProtoError::InvalidLength(field))
+}
+
+fn narrow<T>(value: u32, field: &'static str) -> Result<T, ProtoError>
+where
+    T: TryFrom<u32>,
+{
+    T::try_from(value).map_err(|_| ProtoError::OutOfRange(field))
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UtxoId {
+    #[prost(bytes = "vec", tag = "1")]
+    pub tx_id: Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub output_index: u32,
+}
+
+impl From<&crate::UtxoId> for UtxoId {
+    fn from(utxo_id: &crate::UtxoId) -> Self {
+        Self {
+            tx_id: utxo_id.tx_id().to_vec(),
+            output_index: utxo_id.output_index().into(),
+        }
+    }
+}
+
+impl TryFrom<UtxoId> for crate::UtxoId {
+    type Error = ProtoError;
+
+    fn try_from(utxo_id: UtxoId) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            array(&utxo_id.tx_id, "utxo_id.tx_id")?,
+            narrow(utxo_id.output_index, "utxo_id.output_index")?,
+        ))
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TxPointer {
+    #[prost(uint32, tag = "1")]
+    pub block_height: u32,
+    #[prost(uint32, tag = "2")]
+    pub tx_index: u32,
+}
+
+impl From<&crate::TxPointer> for TxPointer {
+    fn from(tx_pointer: &crate::TxPointer) -> Self {
+        Self {
+            block_height: *tx_pointer.block_height(),
+            tx_index: tx_pointer.tx_index().into(),
+        }
+    }
+}
+
+impl TryFrom<TxPointer> for crate::TxPointer {
+    type Error = ProtoError;
+
+    fn try_from(tx_pointer: TxPointer) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            tx_pointer.block_height.into(),
+            narrow(tx_pointer.tx_index, "tx_pointer.tx_index")?,
+        ))
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Policies {
+    #[prost(uint64, optional, tag = "1")]
+    pub tip: Option<u64>,
+    #[prost(uint64, optional, tag = "2")]
+    pub witness_limit: Option<u64>,
+    #[prost(uint64, optional, tag = "3")]
+    pub maturity: Option<u64>,
+    #[prost(uint64, optional, tag = "4")]
+    pub max_fee: Option<u64>,
+}
+
+impl From<&crate::policies::Policies> for Policies {
+    fn from(policies: &crate::policies::Policies) -> Self {
+        Self {
+            tip: policies.get(PolicyType::Tip),
+            witness_limit: policies.get(PolicyType::WitnessLimit),
+            maturity: policies.get(PolicyType::Maturity),
+            max_fee: policies.get(PolicyType::MaxFee),
+        }
+    }
+}
+
+impl From<Policies> for crate::policies::Policies {
+    fn from(policies: Policies) -> Self {
+        let mut result = Self::new();
+        result.set(PolicyType::Tip, policies.tip);
+        result.set(PolicyType::WitnessLimit, policies.witness_limit);
+        result.set(PolicyType::Maturity, policies.maturity);
+        result.set(PolicyType::MaxFee, policies.max_fee);
+        result
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StorageSlot {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+impl From<&crate::StorageSlot> for StorageSlot {
+    fn from(slot: &crate::StorageSlot) -> Self {
+        Self {
+            key: slot.key().to_vec(),
+            value: slot.value().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<StorageSlot> for crate::StorageSlot {
+    type Error = ProtoError;
+
+    fn try_from(slot: StorageSlot) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            array(&slot.key, "storage_slot.key")?,
+            array(&slot.value, "storage_slot.value")?,
+        ))
+    }
+}
+
+/// A coin input. It is signed if the `predicate` is empty, otherwise the
+/// `witness_index` is ignored.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InputCoin {
+    #[prost(message, optional, tag = "1")]
+    pub utxo_id: Option<UtxoId>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub owner: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub amount: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub asset_id: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub tx_pointer: Option<TxPointer>,
+    #[prost(uint32, tag = "6")]
+    pub witness_index: u32,
+    #[prost(uint64, tag = "7")]
+    pub predicate_gas_used: u64,
+    #[prost(bytes = "vec", tag = "8")]
+    pub predicate: Vec<u8>,
+    #[prost(bytes = "vec", tag = "9")]
+    pub predicate_data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InputContract {
+    #[prost(message, optional, tag = "1")]
+    pub utxo_id: Option<UtxoId>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub balance_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub state_root: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub tx_pointer: Option<TxPointer>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub contract_id: Vec<u8>,
+}
+
+impl From<&input::contract::Contract> for InputContract {
+    fn from(contract: &input::contract::Contract) -> Self {
+        Self {
+            utxo_id: Some((&contract.utxo_id).into()),
+            balance_root: contract.balance_root.to_vec(),
+            state_root: contract.state_root.to_vec(),
+            tx_pointer: Some((&contract.tx_pointer).into()),
+            contract_id: contract.contract_id.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<InputContract> for input::contract::Contract {
+    type Error = ProtoError;
+
+    fn try_from(contract: InputContract) -> Result<Self, Self::Error> {
+        Ok(Self {
+            utxo_id: required(contract.utxo_id, "input_contract.utxo_id")?.try_into()?,
+            balance_root: array(&contract.balance_root, "input_contract.balance_root")?,
+            state_root: array(&contract.state_root, "input_contract.state_root")?,
+            tx_pointer: required(contract.tx_pointer, "input_contract.tx_pointer")?
+                .try_into()?,
+            contract_id: array(&contract.contract_id, "input_contract.contract_id")?,
+        })
+    }
+}
+
+/// A message input. It is a coin if the `data` is empty, and it is signed if the
+/// `predicate` is empty, otherwise the `witness_index` is ignored.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InputMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub sender: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub recipient: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub amount: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub nonce: Vec<u8>,
+    #[prost(uint32, tag = "5")]
+    pub witness_index: u32,
+    #[prost(uint64, tag = "6")]
+    pub predicate_gas_used: u64,
+    #[prost(bytes = "vec", tag = "7")]
+    pub data: Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub predicate: Vec<u8>,
+    #[prost(bytes = "vec", tag = "9")]
+    pub predicate_data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Input {
+    #[prost(oneof = "input_kind::Kind", tags = "1, 2, 3")]
+    pub kind: Option<input_kind::Kind>,
+}
+
+/// The variants of the [`Input`].
+pub mod input_kind {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Coin(super::InputCoin),
+        #[prost(message, tag = "2")]
+        Contract(super::InputContract),
+        #[prost(message, tag = "3")]
+        Message(super::InputMessage),
+    }
+}
+
+impl From<&crate::Input> for Input {
+    fn from(input: &crate::Input) -> Self {
+        let coin = |utxo_id, owner, amount, asset_id, tx_pointer| InputCoin {
+            utxo_id: Some(UtxoId::from(utxo_id)),
+            owner: <[u8; 32]>::from(owner).to_vec(),
+            amount,
+            asset_id: <[u8; 32]>::from(asset_id).to_vec(),
+            tx_pointer: Some(TxPointer::from(tx_pointer)),
+            ..Default::default()
+        };
+        let message = |sender, recipient, amount, nonce| InputMessage {
+            sender: <[u8; 32]>::from(sender).to_vec(),
+            recipient: <[u8; 32]>::from(recipient).to_vec(),
+            amount,
+            nonce: <[u8; 32]>::from(nonce).to_vec(),
+            ..Default::default()
+        };
+
+        let kind = match input {
+            crate::Input::CoinSigned(c) => input_kind::Kind::Coin(InputCoin {
+                witness_index: c.witness_index.into(),
+                ..coin(&c.utxo_id, c.owner, c.amount, c.asset_id, &c.tx_pointer)
+            }),
+            crate::Input::CoinPredicate(c) => input_kind::Kind::Coin(InputCoin {
+                predicate_gas_used: c.predicate_gas_used,
+                predicate: c.predicate.bytes.clone(),
+                predicate_data: c.predicate_data.clone(),
+                ..coin(&c.utxo_id, c.owner, c.amount, c.asset_id, &c.tx_pointer)
+            }),
+            crate::Input::Contract(c) => input_kind::Kind::Contract(c.into()),
+            crate::Input::MessageCoinSigned(m) => {
+                input_kind::Kind::Message(InputMessage {
+                    witness_index: m.witness_index.into(),
+                    ..message(m.sender, m.recipient, m.amount, m.nonce)
+                })
+            }
+            crate::Input::MessageCoinPredicate(m) => {
+                input_kind::Kind::Message(InputMessage {
+                    predicate_gas_used: m.predicate_gas_used,
+                    predicate: m.predicate.bytes.clone(),
+                    predicate_data: m.predicate_data.clone(),
+                    ..message(m.sender, m.recipient, m.amount, m.nonce)
+                })
+            }
+            crate::Input::MessageDataSigned(m) => {
+                input_kind::Kind::Message(InputMessage {
+                    witness_index: m.witness_index.into(),
+                    data: m.data.clone(),
+                    ..message(m.sender, m.recipient, m.amount, m.nonce)
+                })
+            }
+            crate::Input::MessageDataPredicate(m) => {
+                input_kind::Kind::Message(InputMessage {
+                    predicate_gas_used: m.predicate_gas_used,
+                    data: m.data.clone(),
+                    predicate: m.predicate.bytes.clone(),
+                    predicate_data: m.predicate_data.clone(),
+                    ..message(m.sender, m.recipient, m.amount, m.nonce)
+                })
+            }
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<Input> for crate::Input {
+    type Error = ProtoError;
+
+    fn try_from(input: Input) -> Result<Self, Self::Error> {
+        match required(input.kind, "input.kind")? {
+            input_kind::Kind::Coin(coin) => {
+                let utxo_id = required(coin.utxo_id, "input_coin.utxo_id")?.try_into()?;
+                let owner = array(&coin.owner, "input_coin.owner")?;
+                let asset_id = array(&coin.asset_id, "input_coin.asset_id")?;
+                let tx_pointer =
+                    required(coin.tx_pointer, "input_coin.tx_pointer")?.try_into()?;
+
+                if coin.predicate.is_empty() {
+                    Ok(Self::coin_signed(
+                        utxo_id,
+                        owner,
+                        coin.amount,
+                        asset_id,
+                        tx_pointer,
+                        narrow(coin.witness_index, "input_coin.witness_index")?,
+                    ))
+                } else {
+                    Ok(Self::coin_predicate(
+                        utxo_id,
+                        owner,
+                        coin.amount,
+                        asset_id,
+                        tx_pointer,
+                        coin.predicate_gas_used,
+                        coin.predicate,
+                        coin.predicate_data,
+                    ))
+                }
+            }
+            input_kind::Kind::Contract(contract) => {
+                Ok(Self::Contract(contract.try_into()?))
+            }
+            input_kind::Kind::Message(message) => {
+                let sender = array(&message.sender, "input_message.sender")?;
+                let recipient = array(&message.recipient, "input_message.recipient")?;
+                let nonce = array(&message.nonce, "input_message.nonce")?;
+                let witness_index =
+                    || narrow(message.witness_index, "input_message.witness_index");
+
+                match (message.data.is_empty(), message.predicate.is_empty()) {
+                    (true, true) => Ok(Self::message_coin_signed(
+                        sender,
+                        recipient,
+                        message.amount,
+                        nonce,
+                        witness_index()?,
+                    )),
+                    (true, false) => Ok(Self::message_coin_predicate(
+                        sender,
+                        recipient,
+                        message.amount,
+                        nonce,
+                        message.predicate_gas_used,
+                        message.predicate,
+                        message.predicate_data,
+                    )),
+                    (false, true) => Ok(Self::message_data_signed(
+                        sender,
+                        recipient,
+                        message.amount,
+                        nonce,
+                        witness_index()?,
+                        message.data,
+                    )),
+                    (false, false) => Ok(Self::message_data_predicate(
+                        sender,
+                        recipient,
+                        message.amount,
+                        nonce,
+                        message.predicate_gas_used,
+                        message.data,
+                        message.predicate,
+                        message.predicate_data,
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// The `Coin`, `Change` and `Variable` outputs.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OutputCoin {
+    #[prost(bytes = "vec", tag = "1")]
+    pub to: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub amount: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub asset_id: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OutputContract {
+    #[prost(uint32, tag = "1")]
+    pub input_index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub balance_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub state_root: Vec<u8>,
+}
+
+impl From<&output::contract::Contract> for OutputContract {
+    fn from(contract: &output::contract::Contract) -> Self {
+        Self {
+            input_index: contract.input_index.into(),
+            balance_root: contract.balance_root.to_vec(),
+            state_root: contract.state_root.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<OutputContract> for output::contract::Contract {
+    type Error = ProtoError;
+
+    fn try_from(contract: OutputContract) -> Result<Self, Self::Error> {
+        Ok(Self {
+            input_index: narrow(contract.input_index, "output_contract.input_index")?,
+            balance_root: array(&contract.balance_root, "output_contract.balance_root")?,
+            state_root: array(&contract.state_root, "output_contract.state_root")?,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OutputContractCreated {
+    #[prost(bytes = "vec", tag = "1")]
+    pub contract_id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub state_root: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Output {
+    #[prost(oneof = "output_kind::Kind", tags = "1, 2, 3, 4, 5")]
+    pub kind: Option<output_kind::Kind>,
+}
+
+/// The variants of the [`Output`].
+pub mod output_kind {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Coin(super::OutputCoin),
+        #[prost(message, tag = "2")]
+        Contract(super::OutputContract),
+        #[prost(message, tag = "3")]
+        Change(super::OutputCoin),
+        #[prost(message, tag = "4")]
+        Variable(super::OutputCoin),
+        #[prost(message, tag = "5")]
+        ContractCreated(super::OutputContractCreated),
+    }
+}
+
+impl From<&crate::Output> for Output {
+    fn from(output: &crate::Output) -> Self {
+        let coin = |to: &crate::Address, amount, asset_id: &crate::AssetId| OutputCoin {
+            to: to.to_vec(),
+            amount,
+            asset_id: asset_id.to_vec(),
+        };
+
+        let kind = match output {
+            crate::Output::Coin {
+                to,
+                amount,
+                asset_id,
+            } => output_kind::Kind::Coin(coin(to, *amount, asset_id)),
+            crate::Output::Contract(contract) => {
+                output_kind::Kind::Contract(contract.into())
+            }
+            crate::Output::Change {
+                to,
+                amount,
+                asset_id,
+            } => output_kind::Kind::Change(coin(to, *amount, asset_id)),
+            crate::Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } => output_kind::Kind::Variable(coin(to, *amount, asset_id)),
+            crate::Output::ContractCreated {
+                contract_id,
+                state_root,
+            } => output_kind::Kind::ContractCreated(OutputContractCreated {
+                contract_id: contract_id.to_vec(),
+                state_root: state_root.to_vec(),
+            }),
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<Output> for crate::Output {
+    type Error = ProtoError;
+
+    fn try_from(output: Output) -> Result<Self, Self::Error> {
+        let coin = |coin: OutputCoin| -> Result<_, ProtoError> {
+            Ok((
+                array(&coin.to, "output_coin.to")?,
+                coin.amount,
+                array(&coin.asset_id, "output_coin.asset_id")?,
+            ))
+        };
+
+        match required(output.kind, "output.kind")? {
+            output_kind::Kind::Coin(c) => {
+                let (to, amount, asset_id) = coin(c)?;
+                Ok(Self::coin(to, amount, asset_id))
+            }
+            output_kind::Kind::Contract(contract) => {
+                Ok(Self::Contract(contract.try_into()?))
+            }
+            output_kind::Kind::Change(c) => {
+                let (to, amount, asset_id) = coin(c)?;
+                Ok(Self::change(to, amount, asset_id))
+            }
+            output_kind::Kind::Variable(c) => {
+                let (to, amount, asset_id) = coin(c)?;
+                Ok(Self::variable(to, amount, asset_id))
+            }
+            output_kind::Kind::ContractCreated(created) => Ok(Self::contract_created(
+                array(&created.contract_id, "output_contract_created.contract_id")?,
+                array(&created.state_root, "output_contract_created.state_root")?,
+            )),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Script {
+    #[prost(uint64, tag = "1")]
+    pub script_gas_limit: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub receipts_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub script: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub script_data: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub policies: Option<Policies>,
+    #[prost(message, repeated, tag = "6")]
+    pub inputs: Vec<Input>,
+    #[prost(message, repeated, tag = "7")]
+    pub outputs: Vec<Output>,
+    #[prost(bytes = "vec", repeated, tag = "8")]
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Create {
+    #[prost(uint32, tag = "1")]
+    pub bytecode_witness_index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub salt: Vec<u8>,
+    #[prost(message, repeated, tag = "3")]
+    pub storage_slots: Vec<StorageSlot>,
+    #[prost(message, optional, tag = "4")]
+    pub policies: Option<Policies>,
+    #[prost(message, repeated, tag = "5")]
+    pub inputs: Vec<Input>,
+    #[prost(message, repeated, tag = "6")]
+    pub outputs: Vec<Output>,
+    #[prost(bytes = "vec", repeated, tag = "7")]
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Mint {
+    #[prost(message, optional, tag = "1")]
+    pub tx_pointer: Option<TxPointer>,
+    #[prost(message, optional, tag = "2")]
+    pub input_contract: Option<InputContract>,
+    #[prost(message, optional, tag = "3")]
+    pub output_contract: Option<OutputContract>,
+    #[prost(uint64, tag = "4")]
+    pub mint_amount: u64,
+    #[prost(bytes = "vec", tag = "5")]
+    pub mint_asset_id: Vec<u8>,
+    #[prost(uint64, tag = "6")]
+    pub gas_price: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpgradeConsensusParameters {
+    #[prost(uint32, tag = "1")]
+    pub witness_index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub checksum: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpgradeStateTransition {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UpgradePurpose {
+    #[prost(oneof = "upgrade_purpose_kind::Kind", tags = "1, 2")]
+    pub kind: Option<upgrade_purpose_kind::Kind>,
+}
+
+/// The variants of the [`UpgradePurpose`].
+pub mod upgrade_purpose_kind {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        ConsensusParameters(super::UpgradeConsensusParameters),
+        #[prost(message, tag = "2")]
+        StateTransition(super::UpgradeStateTransition),
+    }
+}
+
+impl From<&crate::UpgradePurpose> for UpgradePurpose {
+    fn from(purpose: &crate::UpgradePurpose) -> Self {
+        let kind = match purpose {
+            crate::UpgradePurpose::ConsensusParameters {
+                witness_index,
+                checksum,
+            } => upgrade_purpose_kind::Kind::ConsensusParameters(
+                UpgradeConsensusParameters {
+                    witness_index: (*witness_index).into(),
+                    checksum: checksum.to_vec(),
+                },
+            ),
+            crate::UpgradePurpose::StateTransition { root } => {
+                upgrade_purpose_kind::Kind::StateTransition(UpgradeStateTransition {
+                    root: root.to_vec(),
+                })
+            }
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<UpgradePurpose> for crate::UpgradePurpose {
+    type Error = ProtoError;
+
+    fn try_from(purpose: UpgradePurpose) -> Result<Self, Self::Error> {
+        match required(purpose.kind, "upgrade_purpose.kind")? {
+            upgrade_purpose_kind::Kind::ConsensusParameters(params) => {
+                Ok(Self::ConsensusParameters {
+                    witness_index: narrow(
+                        params.witness_index,
+                        "upgrade_consensus_parameters.witness_index",
+                    )?,
+                    checksum: array(
+                        &params.checksum,
+                        "upgrade_consensus_parameters.checksum",
+                    )?,
+                })
+            }
+            upgrade_purpose_kind::Kind::StateTransition(transition) => {
+                Ok(Self::StateTransition {
+                    root: array(&transition.root, "upgrade_state_transition.root")?,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Upgrade {
+    #[prost(message, optional, tag = "1")]
+    pub purpose: Option<UpgradePurpose>,
+    #[prost(message, optional, tag = "2")]
+    pub policies: Option<Policies>,
+    #[prost(message, repeated, tag = "3")]
+    pub inputs: Vec<Input>,
+    #[prost(message, repeated, tag = "4")]
+    pub outputs: Vec<Output>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Upload {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub witness_index: u32,
+    #[prost(uint32, tag = "3")]
+    pub subsection_index: u32,
+    #[prost(uint32, tag = "4")]
+    pub subsections_number: u32,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub proof_set: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "6")]
+    pub policies: Option<Policies>,
+    #[prost(message, repeated, tag = "7")]
+    pub inputs: Vec<Input>,
+    #[prost(message, repeated, tag = "8")]
+    pub outputs: Vec<Output>,
+    #[prost(bytes = "vec", repeated, tag = "9")]
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Blob {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub witness_index: u32,
+    #[prost(message, optional, tag = "3")]
+    pub policies: Option<Policies>,
+    #[prost(message, repeated, tag = "4")]
+    pub inputs: Vec<Input>,
+    #[prost(message, repeated, tag = "5")]
+    pub outputs: Vec<Output>,
+    #[prost(bytes = "vec", repeated, tag = "6")]
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Transaction {
+    #[prost(oneof = "transaction_kind::Kind", tags = "1, 2, 3, 4, 5, 6")]
+    pub kind: Option<transaction_kind::Kind>,
+}
+
+/// The variants of the [`Transaction`].
+pub mod transaction_kind {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Script(super::Script),
+        #[prost(message, tag = "2")]
+        Create(super::Create),
+        #[prost(message, tag = "3")]
+        Mint(super::Mint),
+        #[prost(message, tag = "4")]
+        Upgrade(super::Upgrade),
+        #[prost(message, tag = "5")]
+        Upload(super::Upload),
+        #[prost(message, tag = "6")]
+        Blob(super::Blob),
+    }
+}
+
+/// The fields shared by all chargeable transactions.
+struct Common {
+    policies: Policies,
+    inputs: Vec<Input>,
+    outputs: Vec<Output>,
+    witnesses: Vec<Vec<u8>>,
+}
+
+impl Common {
+    fn new<Tx>(tx: &Tx) -> Self
+    where
+        Tx: crate::field::Policies + Inputs + Outputs + Witnesses,
+    {
+        Self {
+            policies: tx.policies().into(),
+            inputs: tx.inputs().iter().map(Into::into).collect(),
+            outputs: tx.outputs().iter().map(Into::into).collect(),
+            witnesses: tx
+                .witnesses()
+                .iter()
+                .map(|witness| witness.as_vec().clone())
+                .collect(),
+        }
+    }
+}
+
+type DecodedCommon = (
+    crate::policies::Policies,
+    Vec<crate::Input>,
+    Vec<crate::Output>,
+    Vec<crate::Witness>,
+);
+
+fn decode_common(
+    policies: Option<Policies>,
+    inputs: Vec<Input>,
+    outputs: Vec<Output>,
+    witnesses: Vec<Vec<u8>>,
+) -> Result<DecodedCommon, ProtoError> {
+    Ok((
+        policies.map(Into::into).unwrap_or_default(),
+        inputs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?,
+        outputs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?,
+        witnesses.into_iter().map(Into::into).collect(),
+    ))
+}
+
+impl From<&crate::Transaction> for Transaction {
+    fn from(tx: &crate::Transaction) -> Self {
+        let kind = match tx {
+            crate::Transaction::Script(tx) => {
+                let common = Common::new(tx);
+                transaction_kind::Kind::Script(Script {
+                    script_gas_limit: *tx.script_gas_limit(),
+                    receipts_root: tx.receipts_root().to_vec(),
+                    script: tx.script().clone(),
+                    script_data: tx.script_data().clone(),
+                    policies: Some(common.policies),
+                    inputs: common.inputs,
+                    outputs: common.outputs,
+                    witnesses: common.witnesses,
+                })
+            }
+            crate::Transaction::Create(tx) => {
+                let common = Common::new(tx);
+                transaction_kind::Kind::Create(Create {
+                    bytecode_witness_index: (*tx.bytecode_witness_index()).into(),
+                    salt: tx.salt().to_vec(),
+                    storage_slots: tx.storage_slots().iter().map(Into::into).collect(),
+                    policies: Some(common.policies),
+                    inputs: common.inputs,
+                    outputs: common.outputs,
+                    witnesses: common.witnesses,
+                })
+            }
+            crate::Transaction::Mint(tx) => transaction_kind::Kind::Mint(Mint {
+                tx_pointer: Some(tx.tx_pointer().into()),
+                input_contract: Some(tx.input_contract().into()),
+                output_contract: Some(tx.output_contract().into()),
+                mint_amount: *tx.mint_amount(),
+                mint_asset_id: tx.mint_asset_id().to_vec(),
+                gas_price: *tx.gas_price(),
+            }),
+            crate::Transaction::Upgrade(tx) => {
+                let common = Common::new(tx);
+                transaction_kind::Kind::Upgrade(Upgrade {
+                    purpose: Some(tx.upgrade_purpose().into()),
+                    policies: Some(common.policies),
+                    inputs: common.inputs,
+                    outputs: common.outputs,
+                    witnesses: common.witnesses,
+                })
+            }
+            crate::Transaction::Upload(tx) => {
+                let common = Common::new(tx);
+                transaction_kind::Kind::Upload(Upload {
+                    root: tx.bytecode_root().to_vec(),
+                    witness_index: (*tx.bytecode_witness_index()).into(),
+                    subsection_index: (*tx.subsection_index()).into(),
+                    subsections_number: (*tx.subsections_number()).into(),
+                    proof_set: tx.proof_set().iter().map(|root| root.to_vec()).collect(),
+                    policies: Some(common.policies),
+                    inputs: common.inputs,
+                    outputs: common.outputs,
+                    witnesses: common.witnesses,
+                })
+            }
+            crate::Transaction::Blob(tx) => {
+                let common = Common::new(tx);
+                transaction_kind::Kind::Blob(Blob {
+                    id: tx.blob_id().to_vec(),
+                    witness_index: (*tx.bytecode_witness_index()).into(),
+                    policies: Some(common.policies),
+                    inputs: common.inputs,
+                    outputs: common.outputs,
+                    witnesses: common.witnesses,
+                })
+            }
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<Transaction> for crate::Transaction {
+    type Error = ProtoError;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match required(tx.kind, "transaction.kind")? {
+            transaction_kind::Kind::Script(tx) => {
+                let (policies, inputs, outputs, witnesses) =
+                    decode_common(tx.policies, tx.inputs, tx.outputs, tx.witnesses)?;
+                let mut script = Self::script(
+                    tx.script_gas_limit,
+                    tx.script,
+                    tx.script_data,
+                    policies,
+                    inputs,
+                    outputs,
+                    witnesses,
+                );
+                *script.receipts_root_mut() =
+                    array(&tx.receipts_root, "script.receipts_root")?;
+                Ok(script.into())
+            }
+            transaction_kind::Kind::Create(tx) => {
+                let (policies, inputs, outputs, witnesses) =
+                    decode_common(tx.policies, tx.inputs, tx.outputs, tx.witnesses)?;
+                Ok(Self::create(
+                    narrow(tx.bytecode_witness_index, "create.bytecode_witness_index")?,
+                    policies,
+                    array(&tx.salt, "create.salt")?,
+                    tx.storage_slots
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<_, _>>()?,
+                    inputs,
+                    outputs,
+                    witnesses,
+                )
+                .into())
+            }
+            transaction_kind::Kind::Mint(tx) => Ok(Self::mint(
+                required(tx.tx_pointer, "mint.tx_pointer")?.try_into()?,
+                required(tx.input_contract, "mint.input_contract")?.try_into()?,
+                required(tx.output_contract, "mint.output_contract")?.try_into()?,
+                tx.mint_amount,
+                array(&tx.mint_asset_id, "mint.mint_asset_id")?,
+                tx.gas_price,
+            )
+            .into()),
+            transaction_kind::Kind::Upgrade(tx) => {
+                let (policies, inputs, outputs, witnesses) =
+                    decode_common(tx.policies, tx.inputs, tx.outputs, tx.witnesses)?;
+                Ok(Self::upgrade(
+                    required(tx.purpose, "upgrade.purpose")?.try_into()?,
+                    policies,
+                    inputs,
+                    outputs,
+                    witnesses,
+                )
+                .into())
+            }
+            transaction_kind::Kind::Upload(tx) => {
+                let (policies, inputs, outputs, witnesses) =
+                    decode_common(tx.policies, tx.inputs, tx.outputs, tx.witnesses)?;
+                let body = crate::UploadBody {
+                    root: array(&tx.root, "upload.root")?,
+                    witness_index: narrow(tx.witness_index, "upload.witness_index")?,
+                    subsection_index: narrow(
+                        tx.subsection_index,
+                        "upload.subsection_index",
+                    )?,
+                    subsections_number: narrow(
+                        tx.subsections_number,
+                        "upload.subsections_number",
+                    )?,
+                    proof_set: tx
+                        .proof_set
+                        .iter()
+                        .map(|root| array(root, "upload.proof_set"))
+                        .collect::<Result<_, _>>()?,
+                };
+                Ok(Self::upload(body, policies, inputs, outputs, witnesses).into())
+            }
+            transaction_kind::Kind::Blob(tx) => {
+                let (policies, inputs, outputs, witnesses) =
+                    decode_common(tx.policies, tx.inputs, tx.outputs, tx.witnesses)?;
+                let body = crate::BlobBody {
+                    id: array(&tx.id, "blob.id")?,
+                    witness_index: narrow(tx.witness_index, "blob.witness_index")?,
+                };
+                Ok(Self::blob(body, policies, inputs, outputs, witnesses).into())
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptCall {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub to: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub amount: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub asset_id: Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub gas: u64,
+    #[prost(uint64, tag = "6")]
+    pub param1: u64,
+    #[prost(uint64, tag = "7")]
+    pub param2: u64,
+    #[prost(uint64, tag = "8")]
+    pub pc: u64,
+    #[prost(uint64, tag = "9")]
+    pub is: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptReturn {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub val: u64,
+    #[prost(uint64, tag = "3")]
+    pub pc: u64,
+    #[prost(uint64, tag = "4")]
+    pub is: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptReturnData {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub ptr: u64,
+    #[prost(uint64, tag = "3")]
+    pub len: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub digest: Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub pc: u64,
+    #[prost(uint64, tag = "6")]
+    pub is: u64,
+    #[prost(bytes = "vec", optional, tag = "7")]
+    pub data: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptPanic {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    /// The [`PanicInstruction`] packed into a word.
+    #[prost(uint64, tag = "2")]
+    pub reason: u64,
+    #[prost(uint64, tag = "3")]
+    pub pc: u64,
+    #[prost(uint64, tag = "4")]
+    pub is: u64,
+    #[prost(bytes = "vec", optional, tag = "5")]
+    pub contract_id: Option<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptRevert {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub ra: u64,
+    #[prost(uint64, tag = "3")]
+    pub pc: u64,
+    #[prost(uint64, tag = "4")]
+    pub is: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptLog {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub ra: u64,
+    #[prost(uint64, tag = "3")]
+    pub rb: u64,
+    #[prost(uint64, tag = "4")]
+    pub rc: u64,
+    #[prost(uint64, tag = "5")]
+    pub rd: u64,
+    #[prost(uint64, tag = "6")]
+    pub pc: u64,
+    #[prost(uint64, tag = "7")]
+    pub is: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptLogData {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub ra: u64,
+    #[prost(uint64, tag = "3")]
+    pub rb: u64,
+    #[prost(uint64, tag = "4")]
+    pub ptr: u64,
+    #[prost(uint64, tag = "5")]
+    pub len: u64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub digest: Vec<u8>,
+    #[prost(uint64, tag = "7")]
+    pub pc: u64,
+    #[prost(uint64, tag = "8")]
+    pub is: u64,
+    #[prost(bytes = "vec", optional, tag = "9")]
+    pub data: Option<Vec<u8>>,
+}
+
+/// The `Transfer` and `TransferOut` receipts. The `to` is a `ContractId` for the
+/// former and an `Address` for the latter.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptTransfer {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub to: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub amount: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub asset_id: Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub pc: u64,
+    #[prost(uint64, tag = "6")]
+    pub is: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptScriptResult {
+    /// The [`ScriptExecutionResult`] as a word.
+    #[prost(uint64, tag = "1")]
+    pub result: u64,
+    #[prost(uint64, tag = "2")]
+    pub gas_used: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptMessageOut {
+    #[prost(bytes = "vec", tag = "1")]
+    pub sender: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub recipient: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub amount: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub nonce: Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub len: u64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub digest: Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "7")]
+    pub data: Option<Vec<u8>>,
+}
+
+/// The `Mint` and `Burn` receipts.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReceiptMint {
+    #[prost(bytes = "vec", tag = "1")]
+    pub sub_id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub contract_id: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub val: u64,
+    #[prost(uint64, tag = "4")]
+    pub pc: u64,
+    #[prost(uint64, tag = "5")]
+    pub is: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Receipt {
+    #[prost(
+        oneof = "receipt_kind::Kind",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13"
+    )]
+    pub kind: Option<receipt_kind::Kind>,
+}
+
+/// The variants of the [`Receipt`].
+pub mod receipt_kind {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Call(super::ReceiptCall),
+        #[prost(message, tag = "2")]
+        Return(super::ReceiptReturn),
+        #[prost(message, tag = "3")]
+        ReturnData(super::ReceiptReturnData),
+        #[prost(message, tag = "4")]
+        Panic(super::ReceiptPanic),
+        #[prost(message, tag = "5")]
+        Revert(super::ReceiptRevert),
+        #[prost(message, tag = "6")]
+        Log(super::ReceiptLog),
+        #[prost(message, tag = "7")]
+        LogData(super::ReceiptLogData),
+        #[prost(message, tag = "8")]
+        Transfer(super::ReceiptTransfer),
+        #[prost(message, tag = "9")]
+        TransferOut(super::ReceiptTransfer),
+        #[prost(message, tag = "10")]
+        ScriptResult(super::ReceiptScriptResult),
+        #[prost(message, tag = "11")]
+        MessageOut(super::ReceiptMessageOut),
+        #[prost(message, tag = "12")]
+        Mint(super::ReceiptMint),
+        #[prost(message, tag = "13")]
+        Burn(super::ReceiptMint),
+    }
+}
+
+impl From<&crate::Receipt> for Receipt {
+    fn from(receipt: &crate::Receipt) -> Self {
+        let kind = match receipt.clone() {
+            crate::Receipt::Call {
+                id,
+                to,
+                amount,
+                asset_id,
+                gas,
+                param1,
+                param2,
+                pc,
+                is,
+            } => receipt_kind::Kind::Call(ReceiptCall {
+                id: id.to_vec(),
+                to: to.to_vec(),
+                amount,
+                asset_id: asset_id.to_vec(),
+                gas,
+                param1,
+                param2,
+                pc,
+                is,
+            }),
+            crate::Receipt::Return { id, val, pc, is } => {
+                receipt_kind::Kind::Return(ReceiptReturn {
+                    id: id.to_vec(),
+                    val,
+                    pc,
+                    is,
+                })
+            }
+            crate::Receipt::ReturnData {
+                id,
+                ptr,
+                len,
+                digest,
+                pc,
+                is,
+                data,
+            } => receipt_kind::Kind::ReturnData(ReceiptReturnData {
+                id: id.to_vec(),
+                ptr,
+                len,
+                digest: digest.to_vec(),
+                pc,
+                is,
+                data,
+            }),
+            crate::Receipt::Panic {
+                id,
+                reason,
+                pc,
+                is,
+                contract_id,
+            } => receipt_kind::Kind::Panic(ReceiptPanic {
+                id: id.to_vec(),
+                reason: Word::from(reason),
+                pc,
+                is,
+                contract_id: contract_id.map(|id| id.to_vec()),
+            }),
+            crate::Receipt::Revert { id, ra, pc, is } => {
+                receipt_kind::Kind::Revert(ReceiptRevert {
+                    id: id.to_vec(),
+                    ra,
+                    pc,
+                    is,
+                })
+            }
+            crate::Receipt::Log {
+                id,
+                ra,
+                rb,
+                rc,
+                rd,
+                pc,
+                is,
+            } => receipt_kind::Kind::Log(ReceiptLog {
+                id: id.to_vec(),
+                ra,
+                rb,
+                rc,
+                rd,
+                pc,
+                is,
+            }),
+            crate::Receipt::LogData {
+                id,
+                ra,
+                rb,
+                ptr,
+                len,
+                digest,
+                pc,
+                is,
+                data,
+            } => receipt_kind::Kind::LogData(ReceiptLogData {
+                id: id.to_vec(),
+                ra,
+                rb,
+                ptr,
+                len,
+                digest: digest.to_vec(),
+                pc,
+                is,
+                data,
+            }),
+            crate::Receipt::Transfer {
+                id,
+                to,
+                amount,
+                asset_id,
+                pc,
+                is,
+            } => receipt_kind::Kind::Transfer(ReceiptTransfer {
+                id: id.to_vec(),
+                to: to.to_vec(),
+                amount,
+                asset_id: asset_id.to_vec(),
+                pc,
+                is,
+            }),
+            crate::Receipt::TransferOut {
+                id,
+                to,
+                amount,
+                asset_id,
+                pc,
+                is,
+            } => receipt_kind::Kind::TransferOut(ReceiptTransfer {
+                id: id.to_vec(),
+                to: to.to_vec(),
+                amount,
+                asset_id: asset_id.to_vec(),
+                pc,
+                is,
+            }),
+            crate::Receipt::ScriptResult { result, gas_used } => {
+                receipt_kind::Kind::ScriptResult(ReceiptScriptResult {
+                    result: Word::from(result),
+                    gas_used,
+                })
+            }
+            crate::Receipt::MessageOut {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                len,
+                digest,
+                data,
+            } => receipt_kind::Kind::MessageOut(ReceiptMessageOut {
+                sender: sender.to_vec(),
+                recipient: recipient.to_vec(),
+                amount,
+                nonce: nonce.to_vec(),
+                len,
+                digest: digest.to_vec(),
+                data,
+            }),
+            crate::Receipt::Mint {
+                sub_id,
+                contract_id,
+                val,
+                pc,
+                is,
+            } => receipt_kind::Kind::Mint(ReceiptMint {
+                sub_id: sub_id.to_vec(),
+                contract_id: contract_id.to_vec(),
+                val,
+                pc,
+                is,
+            }),
+            crate::Receipt::Burn {
+                sub_id,
+                contract_id,
+                val,
+                pc,
+                is,
+            } => receipt_kind::Kind::Burn(ReceiptMint {
+                sub_id: sub_id.to_vec(),
+                contract_id: contract_id.to_vec(),
+                val,
+                pc,
+                is,
+            }),
+        };
+
+        Self { kind: Some(kind) }
+    }
+}
+
+impl TryFrom<Receipt> for crate::Receipt {
+    type Error = ProtoError;
+
+    fn try_from(receipt: Receipt) -> Result<Self, Self::Error> {
+        let receipt = match required(receipt.kind, "receipt.kind")? {
+            receipt_kind::Kind::Call(r) => Self::Call {
+                id: array(&r.id, "receipt_call.id")?,
+                to: array(&r.to, "receipt_call.to")?,
+                amount: r.amount,
+                asset_id: array(&r.asset_id, "receipt_call.asset_id")?,
+                gas: r.gas,
+                param1: r.param1,
+                param2: r.param2,
+                pc: r.pc,
+                is: r.is,
+            },
+            receipt_kind::Kind::Return(r) => Self::Return {
+                id: array(&r.id, "receipt_return.id")?,
+                val: r.val,
+                pc: r.pc,
+                is: r.is,
+            },
+            receipt_kind::Kind::ReturnData(r) => Self::ReturnData {
+                id: array(&r.id, "receipt_return_data.id")?,
+                ptr: r.ptr,
+                len: r.len,
+                digest: array(&r.digest, "receipt_return_data.digest")?,
+                pc: r.pc,
+                is: r.is,
+                data: r.data,
+            },
+            receipt_kind::Kind::Panic(r) => Self::Panic {
+                id: array(&r.id, "receipt_panic.id")?,
+                reason: PanicInstruction::from(r.reason),
+                pc: r.pc,
+                is: r.is,
+                contract_id: r
+                    .contract_id
+                    .map(|id| array(&id, "receipt_panic.contract_id"))
+                    .transpose()?,
+            },
+            receipt_kind::Kind::Revert(r) => Self::Revert {
+                id: array(&r.id, "receipt_revert.id")?,
+                ra: r.ra,
+                pc: r.pc,
+                is: r.is,
+            },
+            receipt_kind::Kind::Log(r) => Self::Log {
+                id: array(&r.id, "receipt_log.id")?,
+                ra: r.ra,
+                rb: r.rb,
+                rc: r.rc,
+                rd: r.rd,
+                pc: r.pc,
+                is: r.is,
+            },
+            receipt_kind::Kind::LogData(r) => Self::LogData {
+                id: array(&r.id, "receipt_log_data.id")?,
+                ra: r.ra,
+                rb: r.rb,
+                ptr: r.ptr,
+                len: r.len,
+                digest: array(&r.digest, "receipt_log_data.digest")?,
+                pc: r.pc,
+                is: r.is,
+                data: r.data,
+            },
+            receipt_kind::Kind::Transfer(r) => Self::Transfer {
+                id: array(&r.id, "receipt_transfer.id")?,
+                to: array(&r.to, "receipt_transfer.to")?,
+                amount: r.amount,
+                asset_id: array(&r.asset_id, "receipt_transfer.asset_id")?,
+                pc: r.pc,
+                is: r.is,
+            },
+            receipt_kind::Kind::TransferOut(r) => Self::TransferOut {
+                id: array(&r.id, "receipt_transfer.id")?,
+                to: array(&r.to, "receipt_transfer.to")?,
+                amount: r.amount,
+                asset_id: array(&r.asset_id, "receipt_transfer.asset_id")?,
+                pc: r.pc,
+                is: r.is,
+            },
+            receipt_kind::Kind::ScriptResult(r) => Self::ScriptResult {
+                result: ScriptExecutionResult::from(r.result),
+                gas_used: r.gas_used,
+            },
+            receipt_kind::Kind::MessageOut(r) => Self::MessageOut {
+                sender: array(&r.sender, "receipt_message_out.sender")?,
+                recipient: array(&r.recipient, "receipt_message_out.recipient")?,
+                amount: r.amount,
+                nonce: array(&r.nonce, "receipt_message_out.nonce")?,
+                len: r.len,
+                digest: array(&r.digest, "receipt_message_out.digest")?,
+                data: r.data,
+            },
+            receipt_kind::Kind::Mint(r) => Self::Mint {
+                sub_id: array(&r.sub_id, "receipt_mint.sub_id")?,
+                contract_id: array(&r.contract_id, "receipt_mint.contract_id")?,
+                val: r.val,
+                pc: r.pc,
+                is: r.is,
+            },
+            receipt_kind::Kind::Burn(r) => Self::Burn {
+                sub_id: array(&r.sub_id, "receipt_mint.sub_id")?,
+                contract_id: array(&r.contract_id, "receipt_mint.contract_id")?,
+                val: r.val,
+                pc: r.pc,
+                is: r.is,
+            },
+        };
+
+        Ok(receipt)
+    }
+}
@@ -18,11 +18,36 @@ use fuel_types::{
     Word,
 };
 
+mod inclusion;
+mod log_decoder;
 mod receipt_repr;
+mod receipts;
 mod script_result;
+mod script_return;
+mod withdrawal;
 
 use crate::input::message::compute_message_id;
+pub use inclusion::{
+    InclusionProofError,
+    MerkleProof,
+    ReceiptInclusionProof,
+};
+pub use log_decoder::{
+    DecodedLog,
+    LogDecodeError,
+    LogDecodeFn,
+    LogDecoder,
+};
+pub use receipts::{
+    receipts_root,
+    Receipts,
+};
 pub use script_result::ScriptExecutionResult;
+pub use script_return::ScriptReturn;
+pub use withdrawal::{
+    WithdrawalPayload,
+    WithdrawalPayloadError,
+};
 
 #[derive(Clone, Derivative)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
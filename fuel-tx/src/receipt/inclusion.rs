@@ -0,0 +1,336 @@
+//! Proofs of the inclusion of a receipt into a block, for the consumers of the
+//! cross-chain messages.
+//!
+//! The proof chains two binary Merkle trees. The receipts tree, whose root is the
+//! `receipts_root` of the script transaction, has the canonically encoded receipts as
+//! its leaves. The transactions tree of the block has the ids of the transactions as
+//! its leaves. The id of the script commits to its `receipts_root`, so the proven
+//! transaction links the two trees.
+
+use super::Receipt;
+use crate::{
+    field::ReceiptsRoot,
+    Script,
+    TxId,
+    UniqueIdentifier,
+};
+use alloc::vec::Vec;
+use fuel_merkle::binary::{
+    in_memory::MerkleTree,
+    verify,
+};
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
+    Bytes32,
+    ChainId,
+};
+
+/// The error produced while building or verifying a [`ReceiptInclusionProof`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum InclusionProofError {
+    /// The index of the receipt is out of the receipts.
+    ReceiptIndexOutOfBounds,
+    /// The index of the transaction is out of the transactions of the block.
+    TransactionIndexOutOfBounds,
+    /// The receipts don't have the `receipts_root` of the transaction.
+    ReceiptsRootMismatch,
+    /// The transaction at the index isn't the proven transaction.
+    TransactionIdMismatch,
+    /// The receipt isn't included into the `receipts_root` of the transaction.
+    InvalidReceiptProof,
+    /// The transaction isn't included into the transactions root of the block.
+    InvalidTransactionProof,
+}
+
+/// A Merkle proof of a leaf at the `index` of a tree with `leaves_count` leaves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MerkleProof {
+    /// The index of the proven leaf.
+    pub index: u64,
+    /// The number of the leaves of the tree.
+    pub leaves_count: u64,
+    /// The sibling nodes on the path from the leaf to the root.
+    pub proof_set: Vec<Bytes32>,
+}
+
+impl MerkleProof {
+    /// Proves the leaf at the `index` of the tree built from the `leaves`. Returns
+    /// `None` if the index is out of the leaves.
+    fn prove<L, I>(leaves: I, index: u64) -> Option<(Bytes32, Self)>
+    where
+        L: AsRef<[u8]>,
+        I: IntoIterator<Item = L>,
+    {
+        let mut tree = MerkleTree::new();
+        let mut leaves_count = 0u64;
+        for leaf in leaves {
+            tree.push(leaf.as_ref());
+            leaves_count = leaves_count.saturating_add(1);
+        }
+        let (root, proof_set) = tree.prove(index)?;
+        let proof = Self {
+            index,
+            leaves_count,
+            proof_set: proof_set.into_iter().map(Bytes32::from).collect(),
+        };
+        Some((root.into(), proof))
+    }
+
+    /// Returns `true` if the `leaf` is included into the tree with the `root`.
+    pub fn verify(&self, root: &Bytes32, leaf: &[u8]) -> bool {
+        let proof_set = self.proof_set.iter().map(|node| **node).collect();
+        verify(root, &leaf, &proof_set, self.index, self.leaves_count)
+    }
+}
+
+/// Proves that the receipt was produced by the transaction included into a block.
+///
+/// The proof is self-contained: [`ReceiptInclusionProof::verify`] only needs the
+/// transactions root of the block and the chain id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReceiptInclusionProof {
+    /// The proven receipt.
+    pub receipt: Receipt,
+    /// The proof of the receipt within the `receipts_root` of the transaction.
+    pub receipt_proof: MerkleProof,
+    /// The transaction producing the receipt.
+    pub transaction: Script,
+    /// The proof of the id of the transaction within the transactions root of the
+    /// block.
+    pub transaction_proof: MerkleProof,
+}
+
+impl ReceiptInclusionProof {
+    /// Builds the proof of the receipt at the `receipt_index` of the `receipts` of
+    /// the `transaction`, which is at the `transaction_index` of the `transactions`
+    /// ids of the block.
+    pub fn build(
+        transaction: Script,
+        receipts: &[Receipt],
+        receipt_index: u64,
+        transactions: &[TxId],
+        transaction_index: u64,
+        chain_id: &ChainId,
+    ) -> Result<Self, InclusionProofError> {
+        let receipt = usize::try_from(receipt_index)
+            .ok()
+            .and_then(|index| receipts.get(index))
+            .ok_or(InclusionProofError::ReceiptIndexOutOfBounds)?
+            .clone();
+        let (receipts_root, receipt_proof) =
+            MerkleProof::prove(receipts.iter().map(Receipt::to_bytes), receipt_index)
+                .ok_or(InclusionProofError::ReceiptIndexOutOfBounds)?;
+        if &receipts_root != transaction.receipts_root() {
+            return Err(InclusionProofError::ReceiptsRootMismatch)
+        }
+
+        let id = usize::try_from(transaction_index)
+            .ok()
+            .and_then(|index| transactions.get(index))
+            .ok_or(InclusionProofError::TransactionIndexOutOfBounds)?;
+        if id != &transaction.id(chain_id) {
+            return Err(InclusionProofError::TransactionIdMismatch)
+        }
+        let (_, transaction_proof) = MerkleProof::prove(transactions, transaction_index)
+            .ok_or(InclusionProofError::TransactionIndexOutOfBounds)?;
+
+        Ok(Self {
+            receipt,
+            receipt_proof,
+            transaction,
+            transaction_proof,
+        })
+    }
+
+    /// Verifies that the receipt is included into the block with the
+    /// `transactions_root`.
+    pub fn verify(
+        &self,
+        transactions_root: &Bytes32,
+        chain_id: &ChainId,
+    ) -> Result<(), InclusionProofError> {
+        if !self
+            .receipt_proof
+            .verify(self.transaction.receipts_root(), &self.receipt.to_bytes())
+        {
+            return Err(InclusionProofError::InvalidReceiptProof)
+        }
+
+        let id = self.transaction.id(chain_id);
+        if !self
+            .transaction_proof
+            .verify(transactions_root, id.as_ref())
+        {
+            return Err(InclusionProofError::InvalidTransactionProof)
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    fn receipts() -> Vec<Receipt> {
+        (0..5)
+            .map(|val| Receipt::ret([1; 32].into(), val, 0, 0))
+            .collect()
+    }
+
+    fn script(receipts: &[Receipt]) -> Script {
+        let mut tree = MerkleTree::new();
+        receipts
+            .iter()
+            .for_each(|receipt| tree.push(&receipt.to_bytes()));
+        let mut script = Script::default();
+        *script.receipts_root_mut() = tree.root().into();
+        script
+    }
+
+    fn block(transaction: &Script, chain_id: &ChainId) -> (Vec<TxId>, Bytes32) {
+        let transactions = vec![[1; 32].into(), transaction.id(chain_id), [3; 32].into()];
+        let mut tree = MerkleTree::new();
+        transactions
+            .iter()
+            .for_each(|id: &TxId| tree.push(id.as_ref()));
+        (transactions, tree.root().into())
+    }
+
+    #[test]
+    fn verify__built_proof_is_valid() {
+        let chain_id = ChainId::new(7);
+        let receipts = receipts();
+        let script = script(&receipts);
+        let (transactions, transactions_root) = block(&script, &chain_id);
+
+        let proof = ReceiptInclusionProof::build(
+            script,
+            &receipts,
+            3,
+            &transactions,
+            1,
+            &chain_id,
+        )
+        .unwrap();
+
+        assert_eq!(proof.receipt, receipts[3]);
+        assert_eq!(proof.verify(&transactions_root, &chain_id), Ok(()));
+    }
+
+    #[test]
+    fn verify__survives_the_canonical_encoding() {
+        let chain_id = ChainId::new(7);
+        let receipts = receipts();
+        let script = script(&receipts);
+        let (transactions, transactions_root) = block(&script, &chain_id);
+        let proof = ReceiptInclusionProof::build(
+            script,
+            &receipts,
+            0,
+            &transactions,
+            1,
+            &chain_id,
+        )
+        .unwrap();
+
+        let decoded = ReceiptInclusionProof::from_bytes(&proof.to_bytes()).unwrap();
+
+        assert_eq!(decoded, proof);
+        assert_eq!(decoded.verify(&transactions_root, &chain_id), Ok(()));
+    }
+
+    #[test]
+    fn verify__tampered_receipt_is_rejected() {
+        let chain_id = ChainId::new(7);
+        let receipts = receipts();
+        let script = script(&receipts);
+        let (transactions, transactions_root) = block(&script, &chain_id);
+        let mut proof = ReceiptInclusionProof::build(
+            script,
+            &receipts,
+            2,
+            &transactions,
+            1,
+            &chain_id,
+        )
+        .unwrap();
+
+        proof.receipt = receipts[1].clone();
+
+        assert_eq!(
+            proof.verify(&transactions_root, &chain_id),
+            Err(InclusionProofError::InvalidReceiptProof)
+        );
+    }
+
+    #[test]
+    fn verify__other_block_or_chain_is_rejected() {
+        let chain_id = ChainId::new(7);
+        let receipts = receipts();
+        let script = script(&receipts);
+        let (transactions, transactions_root) = block(&script, &chain_id);
+        let proof = ReceiptInclusionProof::build(
+            script,
+            &receipts,
+            2,
+            &transactions,
+            1,
+            &chain_id,
+        )
+        .unwrap();
+
+        assert_eq!(
+            proof.verify(&[9; 32].into(), &chain_id),
+            Err(InclusionProofError::InvalidTransactionProof)
+        );
+        assert_eq!(
+            proof.verify(&transactions_root, &ChainId::new(8)),
+            Err(InclusionProofError::InvalidTransactionProof)
+        );
+    }
+
+    #[test]
+    fn build__rejects_inconsistent_inputs() {
+        let chain_id = ChainId::new(7);
+        let receipts = receipts();
+        let script = script(&receipts);
+        let (transactions, _) = block(&script, &chain_id);
+
+        let build = |receipts: &[Receipt], receipt_index, transaction_index| {
+            ReceiptInclusionProof::build(
+                script.clone(),
+                receipts,
+                receipt_index,
+                &transactions,
+                transaction_index,
+                &chain_id,
+            )
+        };
+
+        assert_eq!(
+            build(&receipts, 5, 1),
+            Err(InclusionProofError::ReceiptIndexOutOfBounds)
+        );
+        assert_eq!(
+            build(&receipts[1..], 0, 1),
+            Err(InclusionProofError::ReceiptsRootMismatch)
+        );
+        assert_eq!(
+            build(&receipts, 0, 3),
+            Err(InclusionProofError::TransactionIndexOutOfBounds)
+        );
+        assert_eq!(
+            build(&receipts, 0, 0),
+            Err(InclusionProofError::TransactionIdMismatch)
+        );
+    }
+}
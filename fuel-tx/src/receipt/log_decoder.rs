@@ -0,0 +1,271 @@
+use super::{
+    Receipt,
+    Receipts,
+};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    vec::Vec,
+};
+use core::fmt;
+use fuel_types::{
+    canonical::{
+        self,
+        Deserialize,
+    },
+    ContractId,
+    Word,
+};
+
+/// Decodes the data of a log into a value.
+pub type LogDecodeFn<V, E> = Box<dyn Fn(&[u8]) -> Result<V, E> + Send + Sync>;
+
+/// The error of decoding a `LogData` receipt with a [`LogDecoder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LogDecodeError<E> {
+    /// No decode function is registered for the log id.
+    UnknownLogId(Word),
+    /// The data of the receipt was stripped.
+    DataStripped,
+    /// The decode function failed.
+    Codec(E),
+}
+
+impl<E: fmt::Display> fmt::Display for LogDecodeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownLogId(log_id) => write!(f, "unknown log id {log_id}"),
+            Self::DataStripped => f.write_str("the log data was stripped"),
+            Self::Codec(error) => write!(f, "failed to decode the log: {error}"),
+        }
+    }
+}
+
+/// A `LogData` receipt decoded by a [`LogDecoder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DecodedLog<V, E> {
+    /// Index of the receipt in the receipts.
+    pub receipt_index: usize,
+    /// The contract that logged the data, zeroed for the script.
+    pub contract_id: ContractId,
+    /// The log id, i.e. `$rb` of the `LOGD` instruction.
+    pub log_id: Word,
+    /// The decoded value.
+    pub value: Result<V, LogDecodeError<E>>,
+}
+
+/// Turns the `LogData` receipts into structured values, with a decode function
+/// registered per log id.
+///
+/// The decode functions are the codec: they can be generated from a Sway ABI JSON,
+/// use the canonical encoding with [`Self::register_canonical`], or any custom
+/// format.
+pub struct LogDecoder<V, E = canonical::Error> {
+    decoders: BTreeMap<Word, LogDecodeFn<V, E>>,
+    fallback: Option<LogDecodeFn<V, E>>,
+}
+
+impl<V, E> Default for LogDecoder<V, E> {
+    fn default() -> Self {
+        Self {
+            decoders: BTreeMap::new(),
+            fallback: None,
+        }
+    }
+}
+
+impl<V, E> fmt::Debug for LogDecoder<V, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogDecoder")
+            .field("log_ids", &self.decoders.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+impl<V, E> LogDecoder<V, E> {
+    /// Creates the decoder without any decode function.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the decode function of the logs with the id, replacing the previous
+    /// one.
+    pub fn register<F>(&mut self, log_id: Word, decode: F) -> &mut Self
+    where
+        F: Fn(&[u8]) -> Result<V, E> + Send + Sync + 'static,
+    {
+        self.decoders.insert(log_id, Box::new(decode));
+        self
+    }
+
+    /// Registers the decode function of the logs without a registered one.
+    pub fn register_fallback<F>(&mut self, decode: F) -> &mut Self
+    where
+        F: Fn(&[u8]) -> Result<V, E> + Send + Sync + 'static,
+    {
+        self.fallback = Some(Box::new(decode));
+        self
+    }
+
+    /// Returns `true` if the logs with the id can be decoded.
+    pub fn is_registered(&self, log_id: Word) -> bool {
+        self.fallback.is_some() || self.decoders.contains_key(&log_id)
+    }
+
+    /// Decodes the data of a log with the id.
+    pub fn decode(&self, log_id: Word, data: &[u8]) -> Result<V, LogDecodeError<E>> {
+        let decode = self
+            .decoders
+            .get(&log_id)
+            .or(self.fallback.as_ref())
+            .ok_or(LogDecodeError::UnknownLogId(log_id))?;
+        decode(data).map_err(LogDecodeError::Codec)
+    }
+
+    /// Decodes the receipt, or returns `None` if it isn't a `LogData` receipt.
+    pub fn decode_receipt(
+        &self,
+        receipt: &Receipt,
+    ) -> Option<(Word, Result<V, LogDecodeError<E>>)> {
+        match receipt {
+            Receipt::LogData { rb, data, .. } => {
+                let value = match data {
+                    Some(data) => self.decode(*rb, data),
+                    None => Err(LogDecodeError::DataStripped),
+                };
+                Some((*rb, value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes all the `LogData` receipts, in the order of the execution.
+    pub fn decode_receipts<'a>(
+        &'a self,
+        receipts: Receipts<'a>,
+    ) -> impl Iterator<Item = DecodedLog<V, E>> + 'a {
+        receipts
+            .iter()
+            .enumerate()
+            .filter_map(|(receipt_index, receipt)| {
+                let (log_id, value) = self.decode_receipt(receipt)?;
+                Some(DecodedLog {
+                    receipt_index,
+                    contract_id: receipt.id().copied().unwrap_or_default(),
+                    log_id,
+                    value,
+                })
+            })
+    }
+}
+
+impl<V, E> LogDecoder<V, E>
+where
+    E: From<canonical::Error>,
+{
+    /// Registers the canonical decoding of `T` for the logs with the id.
+    pub fn register_canonical<T>(&mut self, log_id: Word) -> &mut Self
+    where
+        T: Deserialize + Into<V>,
+    {
+        self.register(log_id, |data| {
+            T::from_bytes(data).map(Into::into).map_err(Into::into)
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use fuel_types::canonical::Serialize;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Event {
+        Amount(u64),
+        Flag(bool),
+    }
+
+    impl From<u64> for Event {
+        fn from(amount: u64) -> Self {
+            Self::Amount(amount)
+        }
+    }
+
+    fn log_data(log_id: Word, data: Vec<u8>) -> Receipt {
+        Receipt::log_data(ContractId::from([1; 32]), 0, log_id, 0, 0, 0, data)
+    }
+
+    fn decoder() -> LogDecoder<Event> {
+        let mut decoder = LogDecoder::new();
+        decoder
+            .register_canonical::<u64>(1)
+            .register(2, |data| match data {
+                [flag] => Ok(Event::Flag(*flag != 0)),
+                _ => Err(canonical::Error::BufferIsTooShort),
+            });
+        decoder
+    }
+
+    #[test]
+    fn decode_receipts__decodes_registered_logs() {
+        let receipts = vec![
+            Receipt::log(ContractId::zeroed(), 0, 0, 0, 0, 0, 0),
+            log_data(1, 42u64.to_bytes()),
+            log_data(2, vec![1]),
+        ];
+
+        let decoder = decoder();
+        let logs: Vec<_> = decoder.decode_receipts(Receipts::new(&receipts)).collect();
+
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].receipt_index, 1);
+        assert_eq!(logs[0].contract_id, ContractId::from([1; 32]));
+        assert_eq!(logs[0].value, Ok(Event::Amount(42)));
+        assert_eq!(logs[1].log_id, 2);
+        assert_eq!(logs[1].value, Ok(Event::Flag(true)));
+    }
+
+    #[test]
+    fn decode__unknown_log_id_is_error() {
+        assert_eq!(
+            decoder().decode(3, &[]),
+            Err(LogDecodeError::UnknownLogId(3))
+        );
+    }
+
+    #[test]
+    fn decode__codec_error_is_reported() {
+        assert_eq!(
+            decoder().decode(2, &[1, 2]),
+            Err(LogDecodeError::Codec(canonical::Error::BufferIsTooShort))
+        );
+    }
+
+    #[test]
+    fn decode__uses_fallback_for_unregistered_log_ids() {
+        let mut decoder = decoder();
+        decoder.register_fallback(|data| Ok(Event::Amount(data.len() as u64)));
+
+        assert_eq!(decoder.decode(3, &[0; 5]), Ok(Event::Amount(5)));
+        assert_eq!(decoder.decode(1, &42u64.to_bytes()), Ok(Event::Amount(42)));
+    }
+
+    #[test]
+    fn decode_receipt__stripped_data_is_error() {
+        let mut receipt = log_data(1, vec![]);
+        if let Receipt::LogData { data, .. } = &mut receipt {
+            *data = None;
+        }
+
+        assert_eq!(
+            decoder().decode_receipt(&receipt),
+            Some((1, Err(LogDecodeError::DataStripped)))
+        );
+    }
+}
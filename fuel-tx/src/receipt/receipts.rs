@@ -0,0 +1,304 @@
+use super::{
+    Receipt,
+    ScriptReturn,
+};
+use fuel_merkle::binary::root_calculator::MerkleRootCalculator;
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Error,
+        Serialize,
+    },
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+/// Computes the receipts root of a script, as committed by its `receipts_root`: the
+/// root of the binary Merkle tree with the canonically encoded receipts as leaves.
+pub fn receipts_root<'a, I>(receipts: I) -> Bytes32
+where
+    I: IntoIterator<Item = &'a Receipt>,
+{
+    let mut tree = MerkleRootCalculator::new();
+    for receipt in receipts {
+        tree.push(&receipt.to_bytes());
+    }
+    tree.root().into()
+}
+
+/// Query helpers over the receipts of a script execution.
+///
+/// The helpers keep the order of the receipts, i.e. the order of the execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Receipts<'a> {
+    receipts: &'a [Receipt],
+}
+
+impl<'a> Receipts<'a> {
+    /// Wraps the receipts.
+    pub const fn new(receipts: &'a [Receipt]) -> Self {
+        Self { receipts }
+    }
+
+    /// The wrapped receipts.
+    pub const fn as_slice(&self) -> &'a [Receipt] {
+        self.receipts
+    }
+
+    /// Iterates over all the receipts.
+    pub fn iter(&self) -> core::slice::Iter<'a, Receipt> {
+        self.receipts.iter()
+    }
+
+    /// The `Log` and `LogData` receipts.
+    pub fn logs(&self) -> impl Iterator<Item = &'a Receipt> {
+        self.iter()
+            .filter(|r| matches!(r, Receipt::Log { .. } | Receipt::LogData { .. }))
+    }
+
+    /// The data of the `LogData` receipts logged with the id in `$rb`, as done by the
+    /// Sway ABI, or of all of them if `log_id` is `None`. The receipts with stripped
+    /// data are skipped.
+    pub fn log_data(&self, log_id: Option<Word>) -> impl Iterator<Item = &'a [u8]> {
+        self.iter().filter_map(move |receipt| match receipt {
+            Receipt::LogData {
+                rb,
+                data: Some(data),
+                ..
+            } if log_id.map_or(true, |id| id == *rb) => Some(data.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Decodes the data of the `LogData` receipts, selected as by
+    /// [`Self::log_data`], as the canonically encoded `T`.
+    pub fn decode_logs<T: Deserialize + 'a>(
+        &self,
+        log_id: Option<Word>,
+    ) -> impl Iterator<Item = Result<T, Error>> + 'a {
+        self.decode_logs_with(log_id, T::from_bytes)
+    }
+
+    /// Decodes the data of the `LogData` receipts, selected as by
+    /// [`Self::log_data`], with the `codec`, e.g. the one of an ABI.
+    pub fn decode_logs_with<T, E, F>(
+        &self,
+        log_id: Option<Word>,
+        codec: F,
+    ) -> impl Iterator<Item = Result<T, E>> + 'a
+    where
+        T: 'a,
+        E: 'a,
+        F: FnMut(&[u8]) -> Result<T, E> + 'a,
+    {
+        self.log_data(log_id).map(codec)
+    }
+
+    /// The `Transfer` and `TransferOut` receipts.
+    pub fn transfers(&self) -> impl Iterator<Item = &'a Receipt> {
+        self.iter().filter(|r| {
+            matches!(r, Receipt::Transfer { .. } | Receipt::TransferOut { .. })
+        })
+    }
+
+    /// The `Call` receipts of the calls to the contract.
+    pub fn calls_to(&self, contract_id: ContractId) -> impl Iterator<Item = &'a Receipt> {
+        self.iter().filter(move |receipt| {
+            matches!(receipt, Receipt::Call { to, .. } if *to == contract_id)
+        })
+    }
+
+    /// The `Panic` receipts.
+    pub fn panics(&self) -> impl Iterator<Item = &'a Receipt> {
+        self.iter().filter(|r| matches!(r, Receipt::Panic { .. }))
+    }
+
+    /// The `Revert` receipts.
+    pub fn reverts(&self) -> impl Iterator<Item = &'a Receipt> {
+        self.iter().filter(|r| matches!(r, Receipt::Revert { .. }))
+    }
+
+    /// The final `ScriptResult` receipt, if any.
+    pub fn script_result(&self) -> Option<&'a Receipt> {
+        self.iter()
+            .rev()
+            .find(|r| matches!(r, Receipt::ScriptResult { .. }))
+    }
+
+    /// The receipts root, see [`receipts_root`].
+    pub fn root(&self) -> Bytes32 {
+        receipts_root(self.receipts)
+    }
+
+    /// The final outcome of the script, see [`ScriptReturn::from_receipts`].
+    pub fn script_return(&self) -> Option<ScriptReturn> {
+        ScriptReturn::from_receipts(self.receipts)
+    }
+}
+
+impl<'a> From<&'a [Receipt]> for Receipts<'a> {
+    fn from(receipts: &'a [Receipt]) -> Self {
+        Self::new(receipts)
+    }
+}
+
+impl<'a> IntoIterator for Receipts<'a> {
+    type IntoIter = core::slice::Iter<'a, Receipt>;
+    type Item = &'a Receipt;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::ScriptExecutionResult;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use fuel_asm::{
+        PanicInstruction,
+        PanicReason,
+    };
+    use fuel_merkle::binary::in_memory::MerkleTree;
+    use fuel_types::{
+        Address,
+        AssetId,
+    };
+
+    fn contract(byte: u8) -> ContractId {
+        ContractId::from([byte; 32])
+    }
+
+    fn log_data(log_id: Word, data: Vec<u8>) -> Receipt {
+        Receipt::log_data(contract(1), 0, log_id, 0, 0, 0, data)
+    }
+
+    fn receipts() -> Vec<Receipt> {
+        vec![
+            Receipt::call(
+                contract(0),
+                contract(1),
+                0,
+                AssetId::zeroed(),
+                0,
+                0,
+                0,
+                0,
+                0,
+            ),
+            Receipt::log(contract(1), 1, 2, 3, 4, 0, 0),
+            log_data(7, 42u64.to_bytes()),
+            log_data(8, vec![1, 2, 3]),
+            Receipt::transfer(contract(1), contract(2), 5, AssetId::zeroed(), 0, 0),
+            Receipt::transfer_out(
+                contract(1),
+                Address::zeroed(),
+                5,
+                AssetId::zeroed(),
+                0,
+                0,
+            ),
+            Receipt::call(
+                contract(0),
+                contract(2),
+                0,
+                AssetId::zeroed(),
+                0,
+                0,
+                0,
+                0,
+                0,
+            ),
+            Receipt::panic(
+                contract(2),
+                PanicInstruction::error(PanicReason::ArithmeticOverflow, 0),
+                0,
+                0,
+            ),
+            Receipt::script_result(ScriptExecutionResult::Panic, 100),
+        ]
+    }
+
+    #[test]
+    fn logs__returns_log_and_log_data() {
+        let receipts = receipts();
+
+        assert_eq!(Receipts::new(&receipts).logs().count(), 3);
+    }
+
+    #[test]
+    fn log_data__filters_by_log_id() {
+        let receipts = receipts();
+        let receipts = Receipts::new(&receipts);
+
+        assert_eq!(receipts.log_data(None).count(), 2);
+        assert_eq!(receipts.log_data(Some(8)).collect::<Vec<_>>(), [&[1, 2, 3]]);
+    }
+
+    #[test]
+    fn decode_logs__decodes_canonical_data() {
+        let receipts = receipts();
+
+        let values: Vec<u64> = Receipts::new(&receipts)
+            .decode_logs(Some(7))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(values, [42]);
+    }
+
+    #[test]
+    fn decode_logs_with__uses_the_codec() {
+        let receipts = receipts();
+
+        let lengths: Vec<usize> = Receipts::new(&receipts)
+            .decode_logs_with(None, |data| Ok::<_, ()>(data.len()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(lengths, [8, 3]);
+    }
+
+    #[test]
+    fn calls_to__returns_calls_of_the_contract() {
+        let receipts = receipts();
+        let receipts = Receipts::new(&receipts);
+
+        assert_eq!(receipts.calls_to(contract(2)).count(), 1);
+        assert_eq!(receipts.calls_to(contract(3)).count(), 0);
+    }
+
+    #[test]
+    fn transfers_and_panics__are_filtered() {
+        let receipts = receipts();
+        let receipts = Receipts::new(&receipts);
+
+        assert_eq!(receipts.transfers().count(), 2);
+        assert_eq!(receipts.panics().count(), 1);
+        assert_eq!(receipts.reverts().count(), 0);
+        assert_eq!(
+            receipts.script_result().and_then(Receipt::gas_used),
+            Some(100)
+        );
+        assert!(!receipts.script_return().unwrap().is_success());
+    }
+
+    #[test]
+    fn receipts_root__matches_the_merkle_tree_of_the_receipts() {
+        let receipts = receipts();
+        let mut tree = MerkleTree::new();
+        receipts
+            .iter()
+            .for_each(|receipt| tree.push(&receipt.to_bytes()));
+
+        assert_eq!(receipts_root(&receipts), Bytes32::from(tree.root()));
+        assert_eq!(Receipts::new(&receipts).root(), Bytes32::from(tree.root()));
+        assert_eq!(receipts_root(&[]), Bytes32::from(MerkleTree::new().root()));
+    }
+}
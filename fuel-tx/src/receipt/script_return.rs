@@ -0,0 +1,172 @@
+use super::Receipt;
+use alloc::vec::Vec;
+use fuel_asm::PanicInstruction;
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Error,
+    },
+    Word,
+};
+
+/// The final outcome of a script, extracted from its receipts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScriptReturn {
+    /// The script returned a word with `RET`.
+    Value(Word),
+    /// The script returned the data with `RETD`.
+    Data(Vec<u8>),
+    /// The script or a called contract reverted with the code.
+    Reverted(Word),
+    /// The script or a called contract panicked.
+    Panicked(PanicInstruction),
+}
+
+impl ScriptReturn {
+    /// Extracts the outcome of the script from the receipts of its execution.
+    ///
+    /// Returns `None` if the receipts don't contain the final `Return`, `ReturnData`,
+    /// `Revert` or `Panic` receipt, or if the data of the `ReturnData` receipt was
+    /// stripped.
+    pub fn from_receipts(receipts: &[Receipt]) -> Option<Self> {
+        // The execution halts at the first revert or panic, and the contracts return
+        // before the script, so the outcome is the last of these receipts.
+        receipts.iter().rev().find_map(|receipt| match receipt {
+            Receipt::Return { val, .. } => Some(Some(Self::Value(*val))),
+            Receipt::ReturnData { data, .. } => Some(data.clone().map(Self::Data)),
+            Receipt::Revert { ra, .. } => Some(Some(Self::Reverted(*ra))),
+            Receipt::Panic { reason, .. } => Some(Some(Self::Panicked(*reason))),
+            _ => None,
+        })?
+    }
+
+    /// Returns `true` if the script returned a value or data.
+    pub const fn is_success(&self) -> bool {
+        matches!(self, Self::Value(_) | Self::Data(_))
+    }
+
+    /// Decodes the returned value or data as the canonically encoded `T`.
+    ///
+    /// A [`ScriptReturn::Value`] is decoded from its big-endian bytes, i.e. from the
+    /// encoding of the `u64`. Returns `None` if the script reverted or panicked.
+    pub fn decode<T: Deserialize>(&self) -> Option<Result<T, Error>> {
+        match self {
+            Self::Value(value) => Some(T::from_bytes(&value.to_be_bytes())),
+            Self::Data(data) => Some(T::from_bytes(data)),
+            Self::Reverted(_) | Self::Panicked(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::ScriptExecutionResult;
+    use alloc::vec;
+    use fuel_asm::PanicReason;
+    use fuel_types::{
+        canonical::Serialize,
+        AssetId,
+        ContractId,
+    };
+
+    fn script_result() -> Receipt {
+        Receipt::script_result(ScriptExecutionResult::Success, 100)
+    }
+
+    #[test]
+    fn from_receipts__returns_the_script_value_after_contract_returns() {
+        let receipts = vec![
+            Receipt::call(
+                ContractId::zeroed(),
+                ContractId::from([1; 32]),
+                0,
+                AssetId::zeroed(),
+                0,
+                0,
+                0,
+                0,
+                0,
+            ),
+            Receipt::ret(ContractId::from([1; 32]), 7, 0, 0),
+            Receipt::log(ContractId::zeroed(), 1, 2, 3, 4, 0, 0),
+            Receipt::ret(ContractId::zeroed(), 42, 0, 0),
+            script_result(),
+        ];
+
+        assert_eq!(
+            ScriptReturn::from_receipts(&receipts),
+            Some(ScriptReturn::Value(42))
+        );
+    }
+
+    #[test]
+    fn from_receipts__returns_the_data() {
+        let data = AssetId::from([3; 32]).to_bytes();
+        let receipts = vec![
+            Receipt::return_data(ContractId::zeroed(), 0, 0, 0, data.clone()),
+            script_result(),
+        ];
+
+        let script_return = ScriptReturn::from_receipts(&receipts).unwrap();
+
+        assert_eq!(script_return, ScriptReturn::Data(data));
+        assert_eq!(script_return.decode(), Some(Ok(AssetId::from([3; 32]))));
+    }
+
+    #[test]
+    fn from_receipts__returns_the_revert_of_a_contract() {
+        let receipts = vec![
+            Receipt::revert(ContractId::from([1; 32]), 0xdead, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Revert, 100),
+        ];
+
+        let script_return = ScriptReturn::from_receipts(&receipts).unwrap();
+
+        assert_eq!(script_return, ScriptReturn::Reverted(0xdead));
+        assert!(!script_return.is_success());
+        assert_eq!(script_return.decode::<u64>(), None);
+    }
+
+    #[test]
+    fn from_receipts__returns_the_panic() {
+        let reason = PanicInstruction::error(PanicReason::OutOfGas, 0);
+        let receipts = vec![
+            Receipt::panic(ContractId::zeroed(), reason, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Panic, 100),
+        ];
+
+        assert_eq!(
+            ScriptReturn::from_receipts(&receipts),
+            Some(ScriptReturn::Panicked(reason))
+        );
+    }
+
+    #[test]
+    fn from_receipts__stripped_data_is_none() {
+        let mut receipt =
+            Receipt::return_data(ContractId::zeroed(), 0, 0, 0, vec![1, 2, 3]);
+        if let Receipt::ReturnData { data, .. } = &mut receipt {
+            *data = None;
+        }
+
+        assert_eq!(
+            ScriptReturn::from_receipts(&[receipt, script_result()]),
+            None
+        );
+        assert_eq!(ScriptReturn::from_receipts(&[script_result()]), None);
+    }
+
+    #[test]
+    fn decode__value_is_decoded_from_big_endian_bytes() {
+        let script_return = ScriptReturn::Value(0x0102);
+
+        assert_eq!(script_return.decode::<u64>(), Some(Ok(0x0102)));
+        assert_eq!(
+            script_return.decode::<Vec<u8>>(),
+            Some(Err(Error::BufferIsTooShort))
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! The payload of the `MessageOut` receipts withdrawing the native tokens to L1.
+//!
+//! The contracts producing the messages with `SMO` and the L1 relayer share the
+//! format, so any change of it must bump [`WithdrawalPayload::VERSION`].
+
+use super::Receipt;
+use fuel_types::{
+    Address,
+    AssetId,
+    Word,
+};
+
+/// The error produced while decoding or validating a [`WithdrawalPayload`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum WithdrawalPayloadError {
+    /// The receipt isn't a `MessageOut`.
+    NotAMessageOut,
+    /// The data of the `MessageOut` receipt was stripped.
+    DataIsStripped,
+    /// The payload doesn't have the length of the encoding.
+    InvalidLength {
+        /// The length of the payload.
+        len: usize,
+    },
+    /// The version of the payload isn't supported.
+    UnsupportedVersion {
+        /// The version of the payload.
+        version: u8,
+    },
+    /// The L1 recipient is zero.
+    ZeroRecipient,
+    /// The withdrawn amount is zero.
+    ZeroAmount,
+}
+
+/// A withdrawal of the `amount` of the asset to the `recipient` on L1.
+///
+/// The encoding is the version byte followed by the recipient, the asset and the
+/// big-endian amount, without padding, so that the L1 contracts can slice it
+/// directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawalPayload {
+    /// The recipient on L1, left-padded with zeros if it's shorter than 32 bytes.
+    pub recipient: Address,
+    /// The withdrawn asset.
+    pub asset_id: AssetId,
+    /// The withdrawn amount.
+    pub amount: Word,
+}
+
+impl WithdrawalPayload {
+    /// The length of the encoding.
+    pub const LEN: usize = 1 + Address::LEN + AssetId::LEN + 8;
+    /// The version of the encoding produced by [`WithdrawalPayload::encode`].
+    pub const VERSION: u8 = 1;
+
+    /// Creates the payload.
+    pub const fn new(recipient: Address, asset_id: AssetId, amount: Word) -> Self {
+        Self {
+            recipient,
+            asset_id,
+            amount,
+        }
+    }
+
+    /// Encodes the payload as the data of the `MessageOut` receipt.
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        let (version, rest) = bytes.split_at_mut(1);
+        let (recipient, rest) = rest.split_at_mut(Address::LEN);
+        let (asset_id, amount) = rest.split_at_mut(AssetId::LEN);
+        version[0] = Self::VERSION;
+        recipient.copy_from_slice(self.recipient.as_ref());
+        asset_id.copy_from_slice(self.asset_id.as_ref());
+        amount.copy_from_slice(&self.amount.to_be_bytes());
+        bytes
+    }
+
+    /// Decodes the payload from the data of the `MessageOut` receipt.
+    ///
+    /// The decoded payload isn't validated; see [`WithdrawalPayload::validate`].
+    pub fn decode(bytes: &[u8]) -> Result<Self, WithdrawalPayloadError> {
+        let bytes: &[u8; Self::LEN] = bytes
+            .try_into()
+            .map_err(|_| WithdrawalPayloadError::InvalidLength { len: bytes.len() })?;
+        let (version, rest) = bytes.split_at(1);
+        if version[0] != Self::VERSION {
+            return Err(WithdrawalPayloadError::UnsupportedVersion {
+                version: version[0],
+            })
+        }
+        let (recipient, rest) = rest.split_at(Address::LEN);
+        let (asset_id, amount) = rest.split_at(AssetId::LEN);
+
+        let recipient = Address::try_from(recipient).expect("The length is checked");
+        let asset_id = AssetId::try_from(asset_id).expect("The length is checked");
+        let amount = amount.try_into().expect("The length is checked");
+
+        Ok(Self::new(recipient, asset_id, Word::from_be_bytes(amount)))
+    }
+
+    /// Checks that the payload withdraws a non-zero amount to a non-zero recipient.
+    pub fn validate(&self) -> Result<(), WithdrawalPayloadError> {
+        if self.recipient == Address::zeroed() {
+            return Err(WithdrawalPayloadError::ZeroRecipient)
+        }
+        if self.amount == 0 {
+            return Err(WithdrawalPayloadError::ZeroAmount)
+        }
+        Ok(())
+    }
+
+    /// Decodes and validates the payload of the `MessageOut` receipt.
+    pub fn from_receipt(receipt: &Receipt) -> Result<Self, WithdrawalPayloadError> {
+        let Receipt::MessageOut { data, .. } = receipt else {
+            return Err(WithdrawalPayloadError::NotAMessageOut)
+        };
+        let data = data
+            .as_deref()
+            .ok_or(WithdrawalPayloadError::DataIsStripped)?;
+        let payload = Self::decode(data)?;
+        payload.validate()?;
+        Ok(payload)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    fn payload() -> WithdrawalPayload {
+        WithdrawalPayload::new([1; 32].into(), [2; 32].into(), 0x0102)
+    }
+
+    fn message_out(data: &[u8]) -> Receipt {
+        Receipt::message_out(
+            &[3; 32].into(),
+            0,
+            [4; 32].into(),
+            [5; 32].into(),
+            0,
+            data.to_vec(),
+        )
+    }
+
+    #[test]
+    fn encode__has_the_fixed_layout() {
+        let bytes = payload().encode();
+
+        assert_eq!(bytes.len(), 73);
+        assert_eq!(bytes[0], WithdrawalPayload::VERSION);
+        assert_eq!(bytes[1..33], [1; 32]);
+        assert_eq!(bytes[33..65], [2; 32]);
+        assert_eq!(bytes[65..], [0, 0, 0, 0, 0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn decode__round_trips_the_encoding() {
+        assert_eq!(
+            WithdrawalPayload::decode(&payload().encode()),
+            Ok(payload())
+        );
+    }
+
+    #[test]
+    fn decode__rejects_invalid_length_and_version() {
+        let mut bytes = payload().encode();
+
+        assert_eq!(
+            WithdrawalPayload::decode(&bytes[1..]),
+            Err(WithdrawalPayloadError::InvalidLength { len: 72 })
+        );
+        bytes[0] = 2;
+        assert_eq!(
+            WithdrawalPayload::decode(&bytes),
+            Err(WithdrawalPayloadError::UnsupportedVersion { version: 2 })
+        );
+    }
+
+    #[test]
+    fn validate__rejects_zero_recipient_and_amount() {
+        let mut payload = payload();
+        assert_eq!(payload.validate(), Ok(()));
+
+        payload.amount = 0;
+        assert_eq!(payload.validate(), Err(WithdrawalPayloadError::ZeroAmount));
+        payload.recipient = Address::zeroed();
+        assert_eq!(
+            payload.validate(),
+            Err(WithdrawalPayloadError::ZeroRecipient)
+        );
+    }
+
+    #[test]
+    fn from_receipt__decodes_the_message_out_data() {
+        let receipt = message_out(&payload().encode());
+
+        assert_eq!(WithdrawalPayload::from_receipt(&receipt), Ok(payload()));
+    }
+
+    #[test]
+    fn from_receipt__rejects_other_receipts_and_stripped_data() {
+        let mut receipt = message_out(&payload().encode());
+        if let Receipt::MessageOut { data, .. } = &mut receipt {
+            *data = None;
+        }
+
+        assert_eq!(
+            WithdrawalPayload::from_receipt(&receipt),
+            Err(WithdrawalPayloadError::DataIsStripped)
+        );
+        assert_eq!(
+            WithdrawalPayload::from_receipt(&Receipt::ret(Default::default(), 0, 0, 0)),
+            Err(WithdrawalPayloadError::NotAMessageOut)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Signing of the transactions by keys held outside of the process, e.g. by a Ledger
+//! or an HSM.
+
+use core::{
+    convert::Infallible,
+    fmt,
+    future::Future,
+};
+use fuel_crypto::{
+    Message,
+    PublicKey,
+    RecoverableSignature,
+    SecretKey,
+};
+
+/// Signs the ids of the transactions with a secp256k1 key.
+///
+/// The transaction id, i.e. the signing digest, is computed by the crate, so the
+/// signer only signs the 32 bytes [`Message`] it is given.
+pub trait Signer {
+    /// The error of the signer, e.g. a rejection by the user of the device.
+    type Error;
+
+    /// The public key of the key signing the messages.
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs the message.
+    fn sign(&self, message: &Message) -> Result<RecoverableSignature, Self::Error>;
+}
+
+/// Same as [`Signer`], for the signers answering asynchronously, e.g. over the
+/// network. All the [`Signer`]s are [`AsyncSigner`]s.
+pub trait AsyncSigner {
+    /// The error of the signer.
+    type Error;
+
+    /// The public key of the key signing the messages.
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs the message.
+    fn sign(
+        &self,
+        message: &Message,
+    ) -> impl Future<Output = Result<RecoverableSignature, Self::Error>> + Send;
+}
+
+impl<S> AsyncSigner for S
+where
+    S: Signer + Sync,
+    S::Error: Send,
+{
+    type Error = S::Error;
+
+    fn public_key(&self) -> PublicKey {
+        Signer::public_key(self)
+    }
+
+    fn sign(
+        &self,
+        message: &Message,
+    ) -> impl Future<Output = Result<RecoverableSignature, Self::Error>> + Send {
+        core::future::ready(Signer::sign(self, message))
+    }
+}
+
+impl Signer for SecretKey {
+    type Error = Infallible;
+
+    fn public_key(&self) -> PublicKey {
+        SecretKey::public_key(self)
+    }
+
+    fn sign(&self, message: &Message) -> Result<RecoverableSignature, Self::Error> {
+        Ok(RecoverableSignature::sign(self, message))
+    }
+}
+
+impl<S: Signer + ?Sized> Signer for &S {
+    type Error = S::Error;
+
+    fn public_key(&self) -> PublicKey {
+        (**self).public_key()
+    }
+
+    fn sign(&self, message: &Message) -> Result<RecoverableSignature, Self::Error> {
+        (**self).sign(message)
+    }
+}
+
+/// The error of signing a transaction with the [`Signer`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SigningError<E> {
+    /// No signer holds the key of the public key.
+    MissingSigner(PublicKey),
+    /// The signer returned a signature not matching its public key.
+    InvalidSignature(PublicKey),
+    /// The signer failed.
+    Signer(E),
+}
+
+impl<E: fmt::Display> fmt::Display for SigningError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSigner(public_key) => {
+                write!(f, "no signer for the public key {public_key}")
+            }
+            Self::InvalidSignature(public_key) => {
+                write!(f, "invalid signature of the public key {public_key}")
+            }
+            Self::Signer(error) => write!(f, "the signer failed: {error}"),
+        }
+    }
+}
@@ -298,7 +298,7 @@ mod use_std {
 
             input_coin_keys.iter().for_each(|k| {
                 builder.add_unsigned_coin_input(
-                    *k,
+                    k.clone(),
                     self.rng.gen(),
                     self.rng.gen(),
                     self.rng.gen(),
@@ -309,7 +309,7 @@ mod use_std {
             input_message_keys.iter().for_each(|(t, k)| match t {
                 MessageType::MessageCoin => {
                     builder.add_unsigned_message_input(
-                        *k,
+                        k.clone(),
                         self.rng.gen(),
                         self.rng.gen(),
                         self.rng.gen(),
@@ -318,7 +318,7 @@ mod use_std {
                 }
                 MessageType::MessageData => {
                     builder.add_unsigned_message_input(
-                        *k,
+                        k.clone(),
                         self.rng.gen(),
                         self.rng.gen(),
                         self.rng.gen(),
@@ -0,0 +1,94 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    field::{
+        ChargeableBody,
+        Inputs,
+        Outputs,
+        Witnesses,
+    },
+    policies::Policies,
+    *,
+};
+use fuel_types::canonical::{
+    Deserialize,
+    Serialize,
+};
+use proptest::prelude::*;
+
+fn assert_consistent<Tx: Inputs + Outputs + Witnesses>(tx: &Tx) {
+    let witnesses = tx.witnesses().len();
+    for input in tx.inputs() {
+        if let Some(index) = input.witness_index() {
+            if !input.is_coin_predicate()
+                && !input.is_message_coin_predicate()
+                && !input.is_message_data_predicate()
+            {
+                assert!(usize::from(index) < witnesses);
+            }
+        }
+        if let Some(predicate) = input.input_predicate() {
+            assert!(!predicate.is_empty());
+        }
+    }
+    for output in tx.outputs() {
+        if let Some(index) = output.input_index() {
+            assert!(tx.inputs()[usize::from(index)].is_contract());
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn transaction__canonical_encoding_round_trips(tx: Transaction) {
+        let decoded = Transaction::from_bytes(&tx.to_bytes()).unwrap();
+
+        prop_assert_eq!(decoded, tx);
+    }
+
+    #[test]
+    fn script__keeps_the_field_dependencies(tx: Script) {
+        assert_consistent(&tx);
+    }
+
+    #[test]
+    fn create__keeps_the_field_dependencies(tx: Create) {
+        assert_consistent(&tx);
+        prop_assert!(usize::from(*field::BytecodeWitnessIndex::bytecode_witness_index(&tx)) < tx.witnesses().len());
+        prop_assert_eq!(tx.outputs().iter().filter(|o| o.is_contract_created()).count(), 1);
+    }
+
+    #[test]
+    fn blob__id_matches_the_witness(tx: Blob) {
+        assert_consistent(&tx);
+        let body = tx.body();
+        prop_assert_eq!(body.id, BlobId::compute(tx.witnesses()[usize::from(body.witness_index)].as_ref()));
+    }
+
+    #[test]
+    fn upload__subsection_is_within_the_subsections(tx: Upload) {
+        assert_consistent(&tx);
+        let body = tx.body();
+        prop_assert!(body.subsection_index < body.subsections_number);
+        prop_assert!(usize::from(body.witness_index) < tx.witnesses().len());
+    }
+
+    #[test]
+    fn policies__canonical_encoding_round_trips(policies: Policies) {
+        prop_assert_eq!(Policies::from_bytes(&policies.to_bytes()).unwrap(), policies);
+    }
+
+    #[test]
+    fn receipt__canonical_encoding_round_trips(receipt: Receipt) {
+        // The canonical encoding skips the reason of the panic, so the re-encoding is
+        // compared instead.
+        let bytes = receipt.to_bytes();
+        prop_assert_eq!(Receipt::from_bytes(&bytes).unwrap().to_bytes(), bytes);
+    }
+
+    #[test]
+    fn input_and_output__canonical_encoding_round_trips(input: Input, output: Output) {
+        prop_assert_eq!(Input::from_bytes(&input.to_bytes()).unwrap(), input);
+        prop_assert_eq!(Output::from_bytes(&output.to_bytes()).unwrap(), output);
+    }
+}
@@ -0,0 +1,94 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    field::Outputs,
+    *,
+};
+use fuel_crypto::SecretKey;
+use fuel_types::{
+    AssetId,
+    ChainId,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn change_outputs(tx: &Script) -> Vec<(Address, AssetId)> {
+    tx.outputs()
+        .iter()
+        .filter_map(|output| match output {
+            Output::Change { to, asset_id, .. } => Some((*to, *asset_id)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn with_automatic_change__adds_change_for_every_asset_of_the_inputs() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let owner: Address = rng.gen();
+    let secondary_asset: AssetId = rng.gen();
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder
+        .max_fee_limit(0)
+        .with_automatic_change(owner)
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), 10, AssetId::BASE, rng.gen())
+        .add_unsigned_coin_input(
+            secret.clone(),
+            rng.gen(),
+            20,
+            secondary_asset,
+            rng.gen(),
+        )
+        .add_unsigned_coin_input(secret, rng.gen(), 30, secondary_asset, rng.gen());
+
+    // When
+    let tx = builder.finalize();
+
+    // Then
+    let mut changes = change_outputs(&tx);
+    changes.sort();
+    let mut expected = vec![(owner, AssetId::BASE), (owner, secondary_asset)];
+    expected.sort();
+    assert_eq!(changes, expected);
+    assert_eq!(tx.check_signatures(&ChainId::default()), Ok(()));
+}
+
+#[test]
+fn with_automatic_change__keeps_existing_change_outputs() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let owner: Address = rng.gen();
+    let other_owner: Address = rng.gen();
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder
+        .with_automatic_change(owner)
+        .add_unsigned_coin_input(secret, rng.gen(), 10, AssetId::BASE, rng.gen())
+        .add_output(Output::change(other_owner, 0, AssetId::BASE));
+
+    // When
+    let tx = builder.finalize_without_signature();
+
+    // Then
+    assert_eq!(change_outputs(&tx), vec![(other_owner, AssetId::BASE)]);
+}
+
+#[test]
+fn finalize__adds_no_change_without_automatic_change() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.add_unsigned_coin_input(secret, rng.gen(), 10, AssetId::BASE, rng.gen());
+
+    // When
+    let tx = builder.finalize();
+
+    // Then
+    assert!(change_outputs(&tx).is_empty());
+}
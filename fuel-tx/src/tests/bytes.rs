@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use crate::{
     field::{
         Inputs,
@@ -19,6 +21,7 @@ use fuel_asm::{
 use fuel_types::{
     bytes,
     canonical::{
+        self,
         Deserialize,
         Serialize,
     },
@@ -74,6 +77,14 @@ where
 
         assert_eq!(*data, d_p);
         assert_eq!(data.size(), d_p.size());
+
+        let mut d_stream = Vec::new();
+        data.encode_to_writer(&mut d_stream)
+            .expect("Failed to encode into the writer");
+        assert_eq!(d_bytes, d_stream);
+        let d_p = T::decode_from_reader(d_stream.as_slice())
+            .expect("Failed to deserialize T from the reader");
+        assert_eq!(*data, d_p);
     }
 }
 
@@ -634,6 +645,52 @@ fn transaction_serde_serialization_deserialization() {
     )]);
 }
 
+#[test]
+fn streaming__large_transactions_round_trip_one_after_another() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let blob = TransactionBuilder::blob(BlobBody {
+        id: rng.gen(),
+        witness_index: 0,
+    })
+    .add_witness(generate_bytes(rng).repeat(8 * 1024).into())
+    .finalize_as_transaction();
+    let upload = TransactionBuilder::upload(UploadBody {
+        root: rng.gen(),
+        witness_index: 0,
+        subsection_index: 0,
+        subsections_number: 1,
+        proof_set: vec![],
+    })
+    .add_witness(generate_bytes(rng).repeat(8 * 1024).into())
+    .finalize_as_transaction();
+
+    let mut output = canonical::StreamWriter::new(Vec::new());
+    blob.encode(&mut output).expect("Failed to encode");
+    upload.encode(&mut output).expect("Failed to encode");
+    assert_eq!(output.written(), blob.size() + upload.size());
+
+    let stream = output.into_inner();
+    let mut input =
+        canonical::StreamReader::new(std::io::BufReader::new(stream.as_slice()))
+            .expect("Failed to read");
+    assert_eq!(Transaction::decode(&mut input), Ok(blob));
+    assert_eq!(Transaction::decode(&mut input), Ok(upload));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn streaming__truncated_stream_is_unexpected_eof() {
+    let tx = TransactionBuilder::script(vec![0xaa; 64], vec![0xbb; 64])
+        .add_fee_input()
+        .finalize_as_transaction();
+    let bytes = tx.to_bytes();
+
+    let error = Transaction::decode_from_reader(&bytes[..bytes.len() - 8])
+        .expect_err("The stream is truncated");
+
+    assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
 #[test]
 fn create_input_data_offset() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -0,0 +1,219 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    json::CanonicalJson,
+    *,
+};
+use fuel_asm::PanicReason;
+use fuel_types::{
+    BlockHeight,
+    Nonce,
+};
+
+fn script() -> Transaction {
+    TransactionBuilder::script(vec![0x24, 0x00, 0x00, 0x00], vec![0xde, 0xad])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            UtxoId::new([1u8; 32].into(), 2),
+            [2u8; 32].into(),
+            u64::MAX,
+            [3u8; 32].into(),
+            TxPointer::new(46.into(), 5),
+            0,
+        ))
+        .add_input(Input::contract(
+            UtxoId::new([4u8; 32].into(), 1),
+            [5u8; 32].into(),
+            [6u8; 32].into(),
+            TxPointer::new(12.into(), 3),
+            [7u8; 32].into(),
+        ))
+        .add_input(Input::message_data_predicate(
+            [8u8; 32].into(),
+            [9u8; 32].into(),
+            100,
+            Nonce::from([10u8; 32]),
+            5_000,
+            vec![0xaa; 4],
+            vec![0xbb; 4],
+            vec![0xcc; 2],
+        ))
+        .add_output(Output::coin([11u8; 32].into(), 10, [3u8; 32].into()))
+        .add_output(Output::contract(1, [12u8; 32].into(), [13u8; 32].into()))
+        .add_output(Output::change([14u8; 32].into(), 0, [3u8; 32].into()))
+        .add_output(Output::variable(Default::default(), 0, Default::default()))
+        .add_witness(vec![0xff; 8].into())
+        .tip(1)
+        .maturity(123.into())
+        .max_fee_limit(1_000_000)
+        .witness_limit(1_000)
+        .finalize_as_transaction()
+}
+
+fn create() -> Transaction {
+    TransactionBuilder::create(
+        vec![0x24, 0x00, 0x00, 0x00].into(),
+        [15u8; 32].into(),
+        vec![StorageSlot::new([16u8; 32].into(), [17u8; 32].into())],
+    )
+    .add_output(Output::contract_created(
+        [18u8; 32].into(),
+        [19u8; 32].into(),
+    ))
+    .finalize_as_transaction()
+}
+
+fn mint() -> Transaction {
+    TransactionBuilder::mint(
+        BlockHeight::new(100),
+        0,
+        input::contract::Contract {
+            utxo_id: UtxoId::new([20u8; 32].into(), 0),
+            balance_root: [21u8; 32].into(),
+            state_root: [22u8; 32].into(),
+            tx_pointer: TxPointer::new(99.into(), 1),
+            contract_id: [23u8; 32].into(),
+        },
+        output::contract::Contract {
+            input_index: 0,
+            balance_root: [24u8; 32].into(),
+            state_root: [25u8; 32].into(),
+        },
+        500,
+        [3u8; 32].into(),
+        7,
+    )
+    .finalize_as_transaction()
+}
+
+fn upgrade() -> Transaction {
+    TransactionBuilder::upgrade(UpgradePurpose::StateTransition {
+        root: [26u8; 32].into(),
+    })
+    .finalize_as_transaction()
+}
+
+fn upload() -> Transaction {
+    TransactionBuilder::upload(UploadBody {
+        root: [27u8; 32].into(),
+        witness_index: 0,
+        subsection_index: 1,
+        subsections_number: 2,
+        proof_set: vec![[28u8; 32].into()],
+    })
+    .add_witness(vec![0x11; 4].into())
+    .finalize_as_transaction()
+}
+
+fn blob() -> Transaction {
+    TransactionBuilder::blob(BlobBody {
+        id: BlobId::new([29u8; 32]),
+        witness_index: 0,
+    })
+    .add_witness(vec![0x22; 4].into())
+    .finalize_as_transaction()
+}
+
+fn receipts() -> Vec<Receipt> {
+    let id = ContractId::from([30u8; 32]);
+    vec![
+        Receipt::call(id, [31u8; 32].into(), 1, [3u8; 32].into(), 2, 3, 4, 5, 6),
+        Receipt::ret(id, 7, 8, 9),
+        Receipt::return_data(id, 10, 11, 12, vec![0x01, 0x02]),
+        Receipt::panic(
+            id,
+            PanicInstruction::error(PanicReason::MemoryOverflow, 0x12345678),
+            13,
+            14,
+        ),
+        Receipt::revert(id, u64::MAX, 15, 16),
+        Receipt::log(id, 17, 18, 19, 20, 21, 22),
+        Receipt::log_data(id, 23, 24, 25, 26, 27, vec![0x03]),
+        Receipt::transfer(id, [32u8; 32].into(), 28, [3u8; 32].into(), 29, 30),
+        Receipt::transfer_out(id, [33u8; 32].into(), 31, [3u8; 32].into(), 32, 33),
+        Receipt::script_result(ScriptExecutionResult::GenericFailure(34), 35),
+        Receipt::message_out(
+            &[34u8; 32].into(),
+            0,
+            [35u8; 32].into(),
+            [36u8; 32].into(),
+            36,
+            vec![0x04, 0x05],
+        ),
+        Receipt::mint([37u8; 32].into(), id, 37, 38, 39),
+        Receipt::burn([38u8; 32].into(), id, 40, 41, 42),
+    ]
+}
+
+fn pretty(value: &impl CanonicalJson) -> String {
+    serde_json::to_string_pretty(&value.to_canonical_json())
+        .expect("Canonical JSON is always serializable")
+}
+
+#[test]
+fn canonical_json__script_snapshot() {
+    insta::assert_snapshot!(pretty(&script()));
+}
+
+#[test]
+fn canonical_json__create_snapshot() {
+    insta::assert_snapshot!(pretty(&create()));
+}
+
+#[test]
+fn canonical_json__mint_snapshot() {
+    insta::assert_snapshot!(pretty(&mint()));
+}
+
+#[test]
+fn canonical_json__upgrade_snapshot() {
+    insta::assert_snapshot!(pretty(&upgrade()));
+}
+
+#[test]
+fn canonical_json__upload_snapshot() {
+    insta::assert_snapshot!(pretty(&upload()));
+}
+
+#[test]
+fn canonical_json__blob_snapshot() {
+    insta::assert_snapshot!(pretty(&blob()));
+}
+
+#[test]
+fn canonical_json__receipts_snapshot() {
+    insta::assert_snapshot!(pretty(&receipts()));
+}
+
+#[test]
+fn canonical_json__consensus_parameters_snapshot() {
+    insta::assert_snapshot!(pretty(&ConsensusParameters::standard()));
+}
+
+#[test]
+fn canonical_json__words_are_strings_and_bytes_are_hex() {
+    // Given
+    let tx = script();
+
+    // When
+    let json = tx.to_canonical_json();
+
+    // Then
+    assert_eq!(json["type"], "Script");
+    assert_eq!(json["inputs"][0]["amount"], u64::MAX.to_string());
+    assert_eq!(json["inputs"][0]["witness_index"], 0);
+    assert_eq!(json["script_data"], "0xdead");
+    assert_eq!(json["policies"]["maturity"], "123");
+}
+
+#[test]
+fn canonical_json__unset_policies_are_omitted() {
+    // Given
+    let tx = upgrade();
+
+    // When
+    let json = tx.to_canonical_json();
+
+    // Then
+    assert_eq!(json["policies"], serde_json::json!({ "max_fee": "0" }));
+}
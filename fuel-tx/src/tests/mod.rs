@@ -1,14 +1,24 @@
 #![allow(clippy::arithmetic_side_effects, clippy::cast_possible_truncation)]
 
+mod automatic_change;
+mod multisig;
 mod offset;
+mod signer;
 mod valid_cases;
+mod view;
 
+#[cfg(feature = "proptest")]
+mod arbitrary;
 #[cfg(feature = "serde")]
 mod bytes;
+#[cfg(feature = "serde")]
+mod canonical_json;
 #[cfg(feature = "da-compression")]
 mod da_compression;
 #[cfg(feature = "serde")]
 mod display;
+#[cfg(feature = "proto")]
+mod proto;
 
 #[cfg(not(feature = "serde"))]
 use bincode as _;
@@ -17,5 +27,3 @@ use bincode as _;
 use bimap as _;
 #[cfg(not(feature = "da-compression"))]
 use pretty_assertions as _;
-#[cfg(not(feature = "da-compression"))]
-use tokio as _;
@@ -0,0 +1,118 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    field::{
+        Inputs,
+        Witnesses,
+    },
+    *,
+};
+use fuel_crypto::{
+    Message,
+    SecretKey,
+    Signature,
+};
+use fuel_types::ChainId;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn signature_of(tx: &Script, witness_index: u16) -> Signature {
+    let witness = &tx.witnesses()[witness_index as usize];
+    let bytes = witness
+        .as_ref()
+        .try_into()
+        .expect("witness is not a signature");
+    Signature::from_bytes(bytes)
+}
+
+#[test]
+fn multisig_witness__ignores_duplicate_signers() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let a = SecretKey::random(rng);
+    let b = SecretKey::random(rng);
+
+    let mut multisig = MultisigWitness::new();
+    multisig
+        .add_signer(a.clone())
+        .add_signer(b.clone())
+        .add_signer(a.clone());
+
+    assert_eq!(multisig.signers(), &[a.clone(), b.clone()]);
+    assert_eq!(multisig.public_keys(), vec![a.public_key(), b.public_key()]);
+}
+
+#[test]
+fn add_multisig_witness__signs_witnesses_of_all_signers() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let signers: Vec<SecretKey> = (0..3).map(|_| SecretKey::random(rng)).collect();
+
+    let mut multisig = MultisigWitness::new();
+    signers.iter().for_each(|secret| {
+        multisig.add_signer(secret.clone());
+    });
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    let indexes = builder.add_multisig_witness(&multisig);
+    let tx = builder.finalize();
+
+    assert_eq!(indexes, vec![0, 1, 2]);
+    assert_eq!(tx.witnesses().len(), 3);
+    let id = tx.id(&chain_id);
+    let signatures = multisig.sign(&id);
+    for ((secret, index), signature) in signers.iter().zip(indexes).zip(signatures) {
+        let witness = signature_of(&tx, index);
+        assert_eq!(witness, signature);
+        assert!(witness
+            .verify(&secret.public_key(), Message::from_bytes_ref(&id))
+            .is_ok());
+    }
+}
+
+#[test]
+fn add_multisig_witness__shares_witnesses_with_signed_inputs() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let owner = SecretKey::random(rng);
+    let cosigner = SecretKey::random(rng);
+
+    let mut multisig = MultisigWitness::new();
+    multisig
+        .add_signer(cosigner.clone())
+        .add_signer(owner.clone());
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(0).add_unsigned_coin_input(
+        owner,
+        rng.gen(),
+        1_000,
+        AssetId::BASE,
+        Default::default(),
+    );
+    let indexes = builder.add_multisig_witness(&multisig);
+    builder.add_unsigned_coin_input(
+        cosigner,
+        rng.gen(),
+        1_000,
+        AssetId::BASE,
+        Default::default(),
+    );
+    let tx = builder.finalize();
+
+    assert_eq!(indexes, vec![1, 0]);
+    assert_eq!(tx.witnesses().len(), 2);
+    assert_eq!(
+        tx.inputs()
+            .iter()
+            .map(|input| input.witness_index())
+            .collect::<Vec<_>>(),
+        vec![Some(0), Some(1)]
+    );
+    assert_eq!(
+        tx.check_signatures(&ChainId::default()),
+        Ok(()),
+        "inputs must be signed by the shared witnesses"
+    );
+}
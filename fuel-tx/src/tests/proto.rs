@@ -0,0 +1,106 @@
+use crate::{
+    proto,
+    proto::ProtoError,
+    test_helper::TransactionFactory,
+    *,
+};
+use fuel_asm::PanicReason;
+use prost::Message;
+
+fn round_trip(tx: Transaction) {
+    let message = proto::Transaction::from(&tx);
+    let bytes = message.encode_to_vec();
+    let decoded = proto::Transaction::decode(bytes.as_slice()).expect("valid message");
+
+    let restored = Transaction::try_from(decoded).expect("valid transaction");
+
+    assert_eq!(tx, restored);
+}
+
+#[test]
+fn transactions_round_trip() {
+    TransactionFactory::<_, Script>::from_seed(1295)
+        .take(20)
+        .for_each(|(tx, _)| round_trip(tx.into()));
+    TransactionFactory::<_, Create>::from_seed(1295)
+        .take(20)
+        .for_each(|(tx, _)| round_trip(tx.into()));
+    TransactionFactory::<_, Upgrade>::from_seed(1295)
+        .take(20)
+        .for_each(|(tx, _)| round_trip(tx.into()));
+    TransactionFactory::<_, Upload>::from_seed(1295)
+        .take(20)
+        .for_each(|(tx, _)| round_trip(tx.into()));
+    TransactionFactory::<_, Blob>::from_seed(1295)
+        .take(20)
+        .for_each(|(tx, _)| round_trip(tx.into()));
+    TransactionFactory::<_, Mint>::from_seed(1295)
+        .take(20)
+        .for_each(|tx| round_trip(tx.into()));
+}
+
+#[test]
+fn receipts_round_trip() {
+    let id = ContractId::from([1u8; 32]);
+    let receipts = vec![
+        Receipt::call(id, [2u8; 32].into(), 1, [3u8; 32].into(), 2, 3, 4, 5, 6),
+        Receipt::ret(id, 7, 8, 9),
+        Receipt::return_data(id, 10, 11, 12, vec![0x01, 0x02]),
+        Receipt::panic(
+            id,
+            PanicInstruction::error(PanicReason::MemoryOverflow, 0x12345678),
+            13,
+            14,
+        )
+        .with_panic_contract_id(Some([4u8; 32].into())),
+        Receipt::revert(id, Word::MAX, 15, 16),
+        Receipt::log(id, 17, 18, 19, 20, 21, 22),
+        Receipt::log_data(id, 23, 24, 25, 26, 27, vec![0x03]),
+        Receipt::transfer(id, [5u8; 32].into(), 28, [3u8; 32].into(), 29, 30),
+        Receipt::transfer_out(id, [6u8; 32].into(), 31, [3u8; 32].into(), 32, 33),
+        Receipt::script_result(ScriptExecutionResult::GenericFailure(34), 35),
+        Receipt::message_out(
+            &[7u8; 32].into(),
+            0,
+            [8u8; 32].into(),
+            [9u8; 32].into(),
+            36,
+            vec![0x04, 0x05],
+        ),
+        Receipt::mint([10u8; 32].into(), id, 37, 38, 39),
+        Receipt::burn([11u8; 32].into(), id, 40, 41, 42),
+    ];
+
+    for receipt in receipts {
+        let bytes = proto::Receipt::from(&receipt).encode_to_vec();
+        let decoded = proto::Receipt::decode(bytes.as_slice()).expect("valid message");
+
+        let restored = Receipt::try_from(decoded).expect("valid receipt");
+
+        assert_eq!(receipt, restored);
+        assert_eq!(receipt.data(), restored.data());
+        assert_eq!(receipt.contract_id(), restored.contract_id());
+    }
+}
+
+#[test]
+fn invalid_messages_are_rejected() {
+    assert_eq!(
+        Transaction::try_from(proto::Transaction { kind: None }),
+        Err(ProtoError::MissingField("transaction.kind"))
+    );
+
+    let mut blob = proto::Transaction::from(&Transaction::from(
+        TransactionFactory::<_, Blob>::from_seed(1295)
+            .next()
+            .expect("infinite iterator")
+            .0,
+    ));
+    if let Some(proto::transaction_kind::Kind::Blob(blob)) = &mut blob.kind {
+        blob.id.pop();
+    }
+    assert_eq!(
+        Transaction::try_from(blob),
+        Err(ProtoError::InvalidLength("blob.id"))
+    );
+}
@@ -0,0 +1,243 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    field::Witnesses,
+    *,
+};
+use core::cell::Cell;
+use fuel_crypto::{
+    Message,
+    PublicKey,
+    SecretKey,
+    Signature,
+};
+use fuel_types::ChainId;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// A device holding the secret, rejecting the signing when `approve` is unset.
+struct Device {
+    secret: SecretKey,
+    approve: bool,
+    signed: Cell<usize>,
+}
+
+impl Device {
+    fn new(secret: SecretKey) -> Self {
+        Self {
+            secret,
+            approve: true,
+            signed: Cell::new(0),
+        }
+    }
+}
+
+impl Signer for Device {
+    type Error = &'static str;
+
+    fn public_key(&self) -> PublicKey {
+        self.secret.public_key()
+    }
+
+    fn sign(&self, message: &Message) -> Result<Signature, Self::Error> {
+        if !self.approve {
+            return Err("rejected by the user")
+        }
+        self.signed.set(self.signed.get() + 1);
+        Ok(Signature::sign(&self.secret, message))
+    }
+}
+
+/// A remote signer answering asynchronously.
+struct Remote(SecretKey);
+
+impl AsyncSigner for Remote {
+    type Error = core::convert::Infallible;
+
+    fn public_key(&self) -> PublicKey {
+        self.0.public_key()
+    }
+
+    async fn sign(&self, message: &Message) -> Result<Signature, Self::Error> {
+        tokio::task::yield_now().await;
+        Ok(Signature::sign(&self.0, message))
+    }
+}
+
+fn builder(rng: &mut StdRng, owners: &[PublicKey]) -> TransactionBuilder<Script> {
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    for owner in owners {
+        builder.add_signer_coin_input(
+            *owner,
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            Default::default(),
+        );
+    }
+    builder.add_signer_message_input(owners[0], rng.gen(), rng.gen(), rng.gen(), vec![]);
+    builder
+}
+
+fn assert_signed_by(tx: &Script, owners: &[PublicKey]) {
+    let id = tx.id(&ChainId::default());
+    assert_eq!(tx.witnesses().len(), owners.len());
+    for (witness, owner) in tx.witnesses().iter().zip(owners) {
+        let signature = Signature::from_bytes(witness.as_ref().try_into().unwrap());
+        assert!(signature
+            .verify(owner, Message::from_bytes_ref(&id))
+            .is_ok());
+    }
+}
+
+#[test]
+fn finalize_signed__signs_the_inputs_of_the_signers() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let devices = [
+        Device::new(SecretKey::random(rng)),
+        Device::new(SecretKey::random(rng)),
+    ];
+    let owners = [devices[0].public_key(), devices[1].public_key()];
+
+    let builder = builder(rng, &owners);
+    let tx = builder.finalize_signed(&devices).unwrap();
+
+    assert_signed_by(&tx, &owners);
+    assert_eq!(devices[0].signed.get(), 1);
+    assert_eq!(builder.signer_keys().count(), 2);
+}
+
+#[test]
+fn finalize_signed__matches_the_signatures_of_the_secret_keys() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let utxo_id = rng.gen();
+
+    let expected = TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            secret.clone(),
+            utxo_id,
+            10,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .finalize();
+    let signed = TransactionBuilder::script(vec![], vec![])
+        .add_signer_coin_input(
+            secret.public_key(),
+            utxo_id,
+            10,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .finalize_signed(&[secret])
+        .unwrap();
+
+    assert_eq!(signed, expected);
+}
+
+#[test]
+fn finalize_signed__mixes_the_secret_keys_and_the_signers() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let device = Device::new(SecretKey::random(rng));
+
+    let mut builder = builder(rng, &[device.public_key()]);
+    builder.add_unsigned_coin_input(
+        secret.clone(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        Default::default(),
+    );
+    let witness_index = builder.add_signer_witness(device.public_key());
+    let tx = builder
+        .finalize_signed(&[&device as &dyn Signer<Error = _>])
+        .unwrap();
+
+    assert_eq!(witness_index, 0);
+    assert_signed_by(&tx, &[device.public_key(), secret.public_key()]);
+}
+
+#[test]
+fn finalize_signed__fails_without_the_signer_of_a_key() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let device = Device::new(SecretKey::random(rng));
+    let other = Device::new(SecretKey::random(rng));
+
+    let result = builder(rng, &[device.public_key()]).finalize_signed(&[other]);
+
+    assert_eq!(
+        result,
+        Err(SigningError::MissingSigner(device.public_key()))
+    );
+}
+
+#[test]
+fn finalize_signed__returns_the_errors_of_the_signer() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut device = Device::new(SecretKey::random(rng));
+    device.approve = false;
+
+    let result = builder(rng, &[device.public_key()]).finalize_signed(&[device]);
+
+    assert_eq!(result, Err(SigningError::Signer("rejected by the user")));
+}
+
+#[test]
+fn finalize_signed__rejects_the_signatures_of_other_keys() {
+    struct Impostor(PublicKey, SecretKey);
+
+    impl Signer for Impostor {
+        type Error = ();
+
+        fn public_key(&self) -> PublicKey {
+            self.0
+        }
+
+        fn sign(&self, message: &Message) -> Result<Signature, ()> {
+            Ok(Signature::sign(&self.1, message))
+        }
+    }
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let owner = SecretKey::random(rng).public_key();
+
+    let result = builder(rng, &[owner])
+        .finalize_signed(&[Impostor(owner, SecretKey::random(rng))]);
+
+    assert_eq!(result, Err(SigningError::InvalidSignature(owner)));
+}
+
+#[tokio::test]
+async fn finalize_signed_async__signs_with_the_async_signers() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let remote = Remote(SecretKey::random(rng));
+    let owners = [AsyncSigner::public_key(&remote)];
+
+    let tx = builder(rng, &owners)
+        .finalize_signed_async(&[remote])
+        .await
+        .unwrap();
+
+    assert_signed_by(&tx, &owners);
+}
+
+#[tokio::test]
+async fn finalize_signed_async__accepts_the_sync_signers() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let owners = [secret.public_key()];
+    let builder = builder(rng, &owners);
+
+    let tx = builder
+        .finalize_signed_async(core::slice::from_ref(&secret))
+        .await
+        .unwrap();
+
+    assert_eq!(tx, builder.finalize_signed(&[secret]).unwrap());
+    assert_signed_by(&tx, &owners);
+}
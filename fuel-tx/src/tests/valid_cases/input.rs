@@ -158,12 +158,12 @@ fn duplicate_secrets_reuse_witness() {
 
     let script = TransactionBuilder::script(vec![], vec![])
         // coin 1
-        .add_unsigned_coin_input(key, rng.gen(), 100, Default::default(), Default::default())
+        .add_unsigned_coin_input(key.clone(), rng.gen(), 100, Default::default(), Default::default())
         // coin 2
-        .add_unsigned_coin_input(key, rng.gen(), 200, rng.gen(), Default::default())
+        .add_unsigned_coin_input(key.clone(), rng.gen(), 200, rng.gen(), Default::default())
         // message 1
-        .add_unsigned_message_input(key, rng.gen(), rng.gen(), 100, vec![])
-        .add_unsigned_message_input(key, rng.gen(), rng.gen(), 100, vec![rng.gen()])
+        .add_unsigned_message_input(key.clone(), rng.gen(), rng.gen(), 100, vec![])
+        .add_unsigned_message_input(key.clone(), rng.gen(), rng.gen(), 100, vec![rng.gen()])
         .finalize();
 
     assert_eq!(
@@ -175,11 +175,11 @@ fn duplicate_secrets_reuse_witness() {
     // verify witness reuse for creation txs
     let create = TransactionBuilder::create(Witness::default(), rng.gen(), vec![])
         // coin 1
-        .add_unsigned_coin_input(key, rng.gen(), 100, Default::default(), Default::default())
+        .add_unsigned_coin_input(key.clone(), rng.gen(), 100, Default::default(), Default::default())
         // coin 2
-        .add_unsigned_coin_input(key, rng.gen(), 200, rng.gen(), Default::default())
+        .add_unsigned_coin_input(key.clone(), rng.gen(), 200, rng.gen(), Default::default())
         // message 1
-        .add_unsigned_message_input(key, rng.gen(), rng.gen(), 100, vec![])
+        .add_unsigned_message_input(key.clone(), rng.gen(), rng.gen(), 100, vec![])
         .add_unsigned_message_input(key, rng.gen(), rng.gen(), 100, vec![rng.gen()])
         .finalize();
 
@@ -364,7 +364,13 @@ fn max_iow() {
 
     let asset_id: AssetId = AssetId::BASE;
     secrets.iter().for_each(|k| {
-        builder.add_unsigned_coin_input(*k, rng.gen(), rng.gen(), asset_id, rng.gen());
+        builder.add_unsigned_coin_input(
+            k.clone(),
+            rng.gen(),
+            rng.gen(),
+            asset_id,
+            rng.gen(),
+        );
     });
 
     while builder.outputs().len() < TX_PARAMS.max_outputs() as usize {
@@ -392,7 +398,13 @@ fn max_iow() {
         .collect();
 
     secrets.iter().for_each(|k| {
-        builder.add_unsigned_coin_input(*k, rng.gen(), rng.gen(), rng.gen(), rng.gen());
+        builder.add_unsigned_coin_input(
+            k.clone(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+        );
     });
 
     while builder.outputs().len() < TX_PARAMS.max_outputs() as usize {
@@ -422,7 +434,13 @@ fn max_iow() {
         .collect();
 
     secrets.iter().for_each(|k| {
-        builder.add_unsigned_coin_input(*k, rng.gen(), rng.gen(), rng.gen(), rng.gen());
+        builder.add_unsigned_coin_input(
+            k.clone(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+        );
     });
 
     while builder.outputs().len() < 1 + TX_PARAMS.max_outputs() as usize {
@@ -452,7 +470,13 @@ fn max_iow() {
         .collect();
 
     secrets.iter().for_each(|k| {
-        builder.add_unsigned_coin_input(*k, rng.gen(), rng.gen(), rng.gen(), rng.gen());
+        builder.add_unsigned_coin_input(
+            k.clone(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+        );
     });
 
     while builder.outputs().len() < TX_PARAMS.max_outputs() as usize {
@@ -486,8 +510,8 @@ fn output_change_asset_id() {
 
     TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
         .maturity(maturity)
-        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), a, rng.gen())
-        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), b, rng.gen())
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), rng.gen(), a, rng.gen())
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), rng.gen(), b, rng.gen())
         .add_output(Output::change(rng.gen(), rng.next_u64(), a))
         .add_output(Output::change(rng.gen(), rng.next_u64(), b))
         .finalize()
@@ -496,8 +520,8 @@ fn output_change_asset_id() {
 
     let err = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
         .maturity(maturity)
-        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), a, rng.gen())
-        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), b, rng.gen())
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), rng.gen(), a, rng.gen())
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), rng.gen(), b, rng.gen())
         .add_output(Output::change(rng.gen(), rng.next_u64(), a))
         .add_output(Output::change(rng.gen(), rng.next_u64(), a))
         .finalize()
@@ -511,8 +535,8 @@ fn output_change_asset_id() {
 
     let err = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
         .maturity(maturity)
-        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), a, rng.gen())
-        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), b, rng.gen())
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), rng.gen(), a, rng.gen())
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), rng.gen(), b, rng.gen())
         .add_output(Output::change(rng.gen(), rng.next_u64(), a))
         .add_output(Output::change(rng.gen(), rng.next_u64(), c))
         .finalize()
@@ -526,7 +550,7 @@ fn output_change_asset_id() {
 
     let err = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
         .maturity(maturity)
-        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), a, rng.gen())
+        .add_unsigned_coin_input(secret.clone(), rng.gen(), rng.gen(), a, rng.gen())
         .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), b, rng.gen())
         .add_output(Output::coin(rng.gen(), rng.next_u64(), a))
         .add_output(Output::coin(rng.gen(), rng.next_u64(), c))
@@ -716,7 +740,7 @@ fn create__check__cannot_have_message_input() {
     let err = TransactionBuilder::create(generate_bytes(rng).into(), rng.gen(), vec![])
         .maturity(maturity)
         .add_unsigned_message_input(
-            secret,
+            secret.clone(),
             rng.gen(),
             rng.gen(),
             rng.gen(),
@@ -1028,7 +1052,13 @@ fn script__check__transaction_at_maximum_size_is_valid() {
 
     let base_size = {
         let tx = TransactionBuilder::script(vec![], vec![])
-            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen())
+            .add_unsigned_coin_input(
+                secret.clone(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+            )
             .finalize();
         tx.size()
     };
@@ -1062,7 +1092,13 @@ fn script__check__transaction_exceeding_maximum_size_is_invalid() {
 
     let base_size = {
         let tx = TransactionBuilder::script(vec![], vec![])
-            .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), rng.gen(), rng.gen())
+            .add_unsigned_coin_input(
+                secret.clone(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+            )
             .finalize();
         tx.size()
     };
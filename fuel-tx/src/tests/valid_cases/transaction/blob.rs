@@ -193,7 +193,7 @@ fn check__reached_max_inputs() {
             .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -224,7 +224,7 @@ fn check__reached_max_outputs() {
         .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -260,7 +260,7 @@ fn check__reached_max_witnesses() {
         .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -172,7 +172,7 @@ fn reached_max_inputs() {
             .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -203,7 +203,7 @@ fn reached_max_outputs() {
         .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -239,7 +239,7 @@ fn reached_max_witnesses() {
         .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -639,6 +639,115 @@ fn check__errors_when_consensus_parameters_different_than_calculated_metadata()
     assert_eq!(Err(ValidityError::TransactionMetadataMismatch), result);
 }
 
+fn privileged_predicate_input() -> Input {
+    Input::coin_predicate(
+        Default::default(),
+        Input::predicate_owner(predicate()),
+        Default::default(),
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+        predicate(),
+        vec![],
+    )
+}
+
+#[test]
+fn upgrade_consensus_parameters__builds_valid_transaction() {
+    let block_height = 1000.into();
+
+    // Given
+    let mut builder =
+        TransactionBuilder::upgrade_consensus_parameters(&test_params()).unwrap();
+    builder.max_fee_limit(0);
+    builder.add_input(privileged_predicate_input());
+    builder.with_params(test_params());
+
+    // When
+    let tx = builder.finalize();
+
+    // Then
+    let serialized_consensus_parameters = postcard::to_allocvec(&test_params()).unwrap();
+    assert_eq!(
+        tx.upgrade_purpose(),
+        &UpgradePurpose::ConsensusParameters {
+            witness_index: 0,
+            checksum: Hasher::hash(serialized_consensus_parameters.as_slice()),
+        }
+    );
+    assert_eq!(tx.check(block_height, &test_params()), Ok(()));
+}
+
+#[test]
+fn upgrade_state_transition__builds_valid_transaction() {
+    let block_height = 1000.into();
+    let subsections = UploadSubsection::split_bytecode(&[123; 1024], 100).unwrap();
+
+    // Given
+    let mut builder = TransactionBuilder::upgrade_state_transition(&subsections).unwrap();
+    builder.max_fee_limit(0);
+    builder.add_input(privileged_predicate_input());
+    builder.with_params(test_params());
+
+    // When
+    let tx = builder.finalize();
+
+    // Then
+    assert_eq!(
+        tx.upgrade_purpose(),
+        &UpgradePurpose::StateTransition {
+            root: subsections[0].root
+        }
+    );
+    assert_eq!(tx.check(block_height, &test_params()), Ok(()));
+}
+
+#[test]
+fn upgrade_state_transition__errors_when_subsections_missing() {
+    let mut subsections = UploadSubsection::split_bytecode(&[123; 1024], 100).unwrap();
+
+    // Given
+    subsections.pop();
+
+    // When
+    let result = TransactionBuilder::upgrade_state_transition(&subsections);
+
+    // Then
+    assert_eq!(
+        result.err(),
+        Some(ValidityError::TransactionUploadRootVerificationFailed)
+    );
+}
+
+#[test]
+fn upgrade_state_transition__errors_when_subsection_modified() {
+    let mut subsections = UploadSubsection::split_bytecode(&[123; 1024], 100).unwrap();
+
+    // Given
+    subsections[3].subsection[0] = 0;
+
+    // When
+    let result = TransactionBuilder::upgrade_state_transition(&subsections);
+
+    // Then
+    assert_eq!(
+        result.err(),
+        Some(ValidityError::TransactionUploadRootVerificationFailed)
+    );
+}
+
+#[test]
+fn upgrade_state_transition__errors_when_no_subsections() {
+    // When
+    let result = TransactionBuilder::upgrade_state_transition(&[]);
+
+    // Then
+    assert_eq!(
+        result.err(),
+        Some(ValidityError::TransactionUploadRootVerificationFailed)
+    );
+}
+
 // The module tests that `Upgrade` transaction can work with different input types.
 mod check_inputs {
     use super::*;
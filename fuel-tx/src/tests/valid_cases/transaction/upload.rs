@@ -257,7 +257,7 @@ fn check__reached_max_inputs() {
             .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -288,7 +288,7 @@ fn check__reached_max_outputs() {
         .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -324,7 +324,7 @@ fn check__reached_max_witnesses() {
         .collect();
     secrets.iter().for_each(|k| {
         builder.add_unsigned_coin_input(
-            *k,
+            k.clone(),
             rng.gen(),
             rng.gen(),
             AssetId::BASE,
@@ -854,3 +854,55 @@ mod inputs {
         assert_eq!(Err(ValidityError::InputPredicateOwner { index: 1 }), result);
     }
 }
+
+#[test]
+fn upload_bytecode__builds_valid_transactions() {
+    let block_height = 1000.into();
+
+    // Given
+    let builders = TransactionBuilder::upload_bytecode(&bytecode(), SUBSECTION_SIZE)
+        .expect("Should be able to split bytecode");
+    let subsections = UploadSubsection::split_bytecode(&bytecode(), SUBSECTION_SIZE)
+        .expect("Should be able to split bytecode");
+    assert_eq!(builders.len(), subsections.len());
+
+    for (mut builder, subsection) in builders.into_iter().zip(subsections) {
+        builder.max_fee_limit(0);
+        builder.add_input(Input::coin_predicate(
+            Default::default(),
+            Input::predicate_owner(predicate()),
+            Default::default(),
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            predicate(),
+            vec![],
+        ));
+
+        // When
+        let tx = builder.finalize();
+
+        // Then
+        assert_eq!(tx.bytecode_root(), &subsection.root);
+        assert_eq!(tx.subsection_index(), &subsection.subsection_index);
+        assert_eq!(tx.subsections_number(), &subsection.subsections_number);
+        assert_eq!(tx.proof_set(), &subsection.proof_set);
+        assert_eq!(
+            tx.witnesses()[*tx.bytecode_witness_index() as usize].as_ref(),
+            subsection.subsection.as_slice()
+        );
+        assert_eq!(tx.check(block_height, &test_params()), Ok(()));
+    }
+}
+
+#[test]
+fn upload_bytecode__errors_when_subsection_size_too_small() {
+    // When
+    let result = TransactionBuilder::upload_bytecode(&[0; 2 * u16::MAX as usize], 1);
+
+    // Then
+    assert_eq!(
+        result.map(|builders| builders.len()),
+        Err(SplitError::SubsectionSizeTooSmall)
+    );
+}
@@ -0,0 +1,183 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    field::{
+        Inputs,
+        Outputs,
+        Policies as _,
+        ReceiptsRoot,
+        Script as _,
+        ScriptData,
+        ScriptGasLimit,
+        Witnesses,
+    },
+    test_helper::TransactionFactory,
+    *,
+};
+use fuel_types::{
+    canonical::{
+        Error,
+        Serialize,
+    },
+    Nonce,
+};
+
+fn all_inputs() -> Script {
+    TransactionBuilder::script(vec![0x24, 0x00, 0x00, 0x00], vec![0xde, 0xad, 0xbe])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            UtxoId::new([1u8; 32].into(), 2),
+            [2u8; 32].into(),
+            100,
+            [3u8; 32].into(),
+            TxPointer::new(46.into(), 5),
+            0,
+        ))
+        .add_input(Input::coin_predicate(
+            UtxoId::new([4u8; 32].into(), 1),
+            [5u8; 32].into(),
+            200,
+            [3u8; 32].into(),
+            TxPointer::new(12.into(), 3),
+            1_000,
+            vec![0xaa; 9],
+            vec![0xbb; 3],
+        ))
+        .add_input(Input::contract(
+            UtxoId::new([6u8; 32].into(), 1),
+            [7u8; 32].into(),
+            [8u8; 32].into(),
+            TxPointer::new(12.into(), 3),
+            [9u8; 32].into(),
+        ))
+        .add_input(Input::message_coin_signed(
+            [10u8; 32].into(),
+            [11u8; 32].into(),
+            300,
+            Nonce::from([12u8; 32]),
+            0,
+        ))
+        .add_input(Input::message_coin_predicate(
+            [13u8; 32].into(),
+            [14u8; 32].into(),
+            400,
+            Nonce::from([15u8; 32]),
+            2_000,
+            vec![0xcc; 16],
+            vec![],
+        ))
+        .add_input(Input::message_data_signed(
+            [16u8; 32].into(),
+            [17u8; 32].into(),
+            500,
+            Nonce::from([18u8; 32]),
+            0,
+            vec![0xdd; 5],
+        ))
+        .add_input(Input::message_data_predicate(
+            [19u8; 32].into(),
+            [20u8; 32].into(),
+            600,
+            Nonce::from([21u8; 32]),
+            3_000,
+            vec![0xee; 7],
+            vec![0xff; 8],
+            vec![0x11; 1],
+        ))
+        .add_output(Output::coin([22u8; 32].into(), 10, [3u8; 32].into()))
+        .add_output(Output::contract(2, [23u8; 32].into(), [24u8; 32].into()))
+        .add_output(Output::change([25u8; 32].into(), 0, [3u8; 32].into()))
+        .add_witness(vec![0x42; 65].into())
+        .tip(1)
+        .maturity(123.into())
+        .max_fee_limit(1_000_000)
+        .finalize()
+}
+
+fn assert_view_matches(tx: &Script) {
+    let bytes = tx.to_bytes();
+
+    let view = ScriptRef::parse(&bytes).expect("Failed to parse the view");
+
+    assert_eq!(view.bytes(), bytes.as_slice());
+    assert_eq!(view.script_gas_limit(), *tx.script_gas_limit());
+    assert_eq!(view.receipts_root(), tx.receipts_root());
+    assert_eq!(view.script(), tx.script().as_slice());
+    assert_eq!(view.script_data(), tx.script_data().as_slice());
+    assert_eq!(view.policies(), tx.policies());
+    assert_eq!(
+        view.inputs()
+            .map(|input| input.to_owned())
+            .collect::<Vec<_>>(),
+        *tx.inputs()
+    );
+    assert_eq!(view.outputs().collect::<Vec<_>>(), *tx.outputs());
+    assert_eq!(
+        view.witnesses().collect::<Vec<_>>(),
+        tx.witnesses()
+            .iter()
+            .map(|witness| witness.as_vec().as_slice())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn script_ref__matches_the_owned_decoding_for_all_inputs() {
+    assert_view_matches(&all_inputs());
+}
+
+#[test]
+fn script_ref__matches_the_owned_decoding_for_random_transactions() {
+    TransactionFactory::<_, Script>::from_seed(3493)
+        .take(50)
+        .for_each(|(tx, _)| assert_view_matches(&tx));
+}
+
+#[test]
+fn script_ref__borrows_the_bytes() {
+    // Given
+    let tx = all_inputs();
+    let bytes = tx.to_bytes();
+
+    // When
+    let view = ScriptRef::parse(&bytes).unwrap();
+
+    // Then
+    let range = bytes.as_ptr_range();
+    assert!(range.contains(&view.script().as_ptr()));
+    let Some(InputRef::Coin(coin)) = view.inputs().nth(1) else {
+        panic!("Expected a coin input");
+    };
+    assert!(range.contains(&coin.predicate.as_ptr()));
+    assert!(view.inputs().nth(1).unwrap().is_predicate());
+}
+
+#[test]
+fn script_ref__rejects_other_transactions() {
+    // Given
+    let tx = Transaction::default_test_tx();
+    let create = TransactionBuilder::create(vec![].into(), Default::default(), vec![])
+        .finalize_as_transaction();
+
+    // Then
+    assert!(ScriptRef::parse(&tx.to_bytes()).is_ok());
+    assert_eq!(
+        ScriptRef::parse(&create.to_bytes()),
+        Err(Error::InvalidPrefix)
+    );
+}
+
+#[test]
+fn script_ref__rejects_truncated_and_trailing_bytes() {
+    // Given
+    let bytes = all_inputs().to_bytes();
+    let mut trailing = bytes.clone();
+    trailing.extend([0u8; 8]);
+
+    // Then
+    assert_eq!(
+        ScriptRef::parse(&bytes[..bytes.len() - 8]),
+        Err(Error::BufferIsTooShort)
+    );
+    assert!(ScriptRef::parse(&trailing).is_err());
+}
@@ -26,6 +26,7 @@ use fuel_types::{
     AssetId,
     BlobId,
     Bytes32,
+    ContractId,
     Nonce,
     Salt,
     Word,
@@ -46,38 +47,80 @@ use alloc::vec::{
 };
 use itertools::Itertools;
 
-mod fee;
+mod diff;
+mod malleability;
 mod metadata;
+mod partially_signed;
 mod repr;
+mod summary;
 mod types;
 mod validity;
+mod view;
 
 mod id;
 
 pub mod consensus_parameters;
+pub mod fee;
 pub mod policies;
 
 pub use consensus_parameters::{
     ConsensusParameters,
+    ConsensusParametersBundle,
+    ConsensusParametersBundleError,
     ContractParameters,
     DependentCost,
     FeeParameters,
+    GasCostModel,
     GasCosts,
     GasCostsValues,
+    GasModelRef,
     PredicateParameters,
     ScriptParameters,
+    SharedGasCostModel,
     TxParameters,
 };
+pub use diff::{
+    PolicyChange,
+    SequenceDiff,
+    TransactionDiff,
+    TransactionDiffError,
+};
 pub use fee::{
     Chargeable,
+    FeeBreakdown,
+    FeeScenario,
+    FeeSimulator,
     TransactionFee,
 };
+pub use malleability::{
+    MalleableField,
+    MalleableInputField,
+    MalleableOutputField,
+};
 pub use metadata::Cacheable;
+pub use partially_signed::{
+    PartialSigningError,
+    PartiallySignedTransaction,
+    RequiredSignature,
+};
 pub use repr::TransactionRepr;
+pub use summary::{
+    CoinSpent,
+    MessageConsumed,
+    OutputTo,
+};
 pub use types::*;
 pub use validity::{
     FormatValidityChecks,
     ValidityError,
+    ValidityReport,
+};
+pub use view::{
+    CoinRef,
+    ContractRef,
+    InputRef,
+    MessageRef,
+    ScriptRef,
 };
 
 #[cfg(feature = "alloc")]
@@ -468,6 +511,61 @@ impl Transaction {
     }
 }
 
+impl Transaction {
+    /// Returns the coins spent by the inputs, in the order of inputs.
+    pub fn coins_spent(&self) -> IntoIter<CoinSpent<'_>> {
+        match self {
+            Self::Script(tx) => tx.coins_spent(),
+            Self::Create(tx) => tx.coins_spent(),
+            Self::Mint(_) => Vec::new().into_iter(),
+            Self::Upgrade(tx) => tx.coins_spent(),
+            Self::Upload(tx) => tx.coins_spent(),
+            Self::Blob(tx) => tx.coins_spent(),
+        }
+    }
+
+    /// Returns the messages consumed by the inputs, in the order of inputs.
+    pub fn messages_consumed(&self) -> IntoIter<MessageConsumed<'_>> {
+        match self {
+            Self::Script(tx) => tx.messages_consumed(),
+            Self::Create(tx) => tx.messages_consumed(),
+            Self::Mint(_) => Vec::new().into_iter(),
+            Self::Upgrade(tx) => tx.messages_consumed(),
+            Self::Upload(tx) => tx.messages_consumed(),
+            Self::Blob(tx) => tx.messages_consumed(),
+        }
+    }
+
+    /// Returns the contracts used by the inputs, in the order of inputs. For
+    /// [`Mint`], it is the contract receiving the minted coins.
+    pub fn contracts_touched(&self) -> IntoIter<&ContractId> {
+        match self {
+            Self::Script(tx) => tx.contracts_touched(),
+            Self::Create(tx) => tx.contracts_touched(),
+            Self::Mint(tx) => {
+                Vec::from([&field::InputContract::input_contract(tx).contract_id])
+                    .into_iter()
+            }
+            Self::Upgrade(tx) => tx.contracts_touched(),
+            Self::Upload(tx) => tx.contracts_touched(),
+            Self::Blob(tx) => tx.contracts_touched(),
+        }
+    }
+
+    /// Returns the coin, change and variable outputs sent to the address, in the
+    /// order of outputs.
+    pub fn outputs_to<'a>(&'a self, address: &Address) -> IntoIter<OutputTo<'a>> {
+        match self {
+            Self::Script(tx) => tx.outputs_to(address),
+            Self::Create(tx) => tx.outputs_to(address),
+            Self::Mint(_) => Vec::new().into_iter(),
+            Self::Upgrade(tx) => tx.outputs_to(address),
+            Self::Upload(tx) => tx.outputs_to(address),
+            Self::Blob(tx) => tx.outputs_to(address),
+        }
+    }
+}
+
 pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
     /// Returns the assets' ids used in the inputs in the order of inputs.
     fn input_asset_ids<'a>(
@@ -530,6 +628,47 @@ pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
             })
     }
 
+    /// Returns the coins spent by the inputs, in the order of inputs.
+    fn coins_spent(&self) -> IntoIter<CoinSpent<'_>> {
+        self.inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| CoinSpent::from_input(index, input))
+            .collect_vec()
+            .into_iter()
+    }
+
+    /// Returns the messages consumed by the inputs, in the order of inputs.
+    fn messages_consumed(&self) -> IntoIter<MessageConsumed<'_>> {
+        self.inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| MessageConsumed::from_input(index, input))
+            .collect_vec()
+            .into_iter()
+    }
+
+    /// Returns the contracts used by the inputs, in the order of inputs.
+    fn contracts_touched(&self) -> IntoIter<&ContractId> {
+        self.inputs()
+            .iter()
+            .filter_map(Input::contract_id)
+            .collect_vec()
+            .into_iter()
+    }
+
+    /// Returns the coin, change and variable outputs sent to the address, in the
+    /// order of outputs.
+    fn outputs_to<'a>(&'a self, address: &Address) -> IntoIter<OutputTo<'a>> {
+        self.outputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| OutputTo::from_output(index, output))
+            .filter(|output| output.to == address)
+            .collect_vec()
+            .into_iter()
+    }
+
     /// Append a new unsigned coin input to the transaction.
     ///
     /// When the transaction is constructed, [`Signable::sign_inputs`] should
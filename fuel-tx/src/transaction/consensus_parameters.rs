@@ -6,13 +6,22 @@ use fuel_types::{
     ChainId,
 };
 
+mod bundle;
 pub mod gas;
 
+pub use bundle::{
+    ConsensusParametersBundle,
+    ConsensusParametersBundleError,
+    CONSENSUS_PARAMETERS_BUNDLE_VERSION,
+};
 pub use gas::{
     DependentCost,
+    GasCostModel,
     GasCostNotDefined,
     GasCosts,
     GasCostsValues,
+    GasModelRef,
+    SharedGasCostModel,
 };
 
 use crate::consts::BALANCE_ENTRY_SIZE;
@@ -620,12 +629,14 @@ impl Default for PredicateParametersV1 {
 pub enum TxParameters {
     /// Version 1 of the transaction parameters.
     V1(TxParametersV1),
+    /// Version 2 of the transaction parameters.
+    V2(TxParametersV2),
 }
 
 impl TxParameters {
     #[cfg(feature = "test-helpers")]
     /// Default parameters just for testing.
-    pub const DEFAULT: Self = Self::V1(TxParametersV1::DEFAULT);
+    pub const DEFAULT: Self = Self::V2(TxParametersV2::DEFAULT);
 
     /// Transaction memory offset in VM runtime
     pub const fn tx_offset(&self) -> usize {
@@ -651,6 +662,10 @@ impl TxParameters {
                 params.max_inputs = max_inputs;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_inputs = max_inputs;
+                Self::V2(params)
+            }
         }
     }
 
@@ -661,6 +676,10 @@ impl TxParameters {
                 params.max_outputs = max_outputs;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_outputs = max_outputs;
+                Self::V2(params)
+            }
         }
     }
 
@@ -671,6 +690,10 @@ impl TxParameters {
                 params.max_witnesses = max_witnesses;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_witnesses = max_witnesses;
+                Self::V2(params)
+            }
         }
     }
 
@@ -681,6 +704,10 @@ impl TxParameters {
                 params.max_gas_per_tx = max_gas_per_tx;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_gas_per_tx = max_gas_per_tx;
+                Self::V2(params)
+            }
         }
     }
 
@@ -691,6 +718,10 @@ impl TxParameters {
                 params.max_size = max_size;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_size = max_size;
+                Self::V2(params)
+            }
         }
     }
 
@@ -704,6 +735,46 @@ impl TxParameters {
                 params.max_bytecode_subsections = max_bytecode_subsections;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_bytecode_subsections = max_bytecode_subsections;
+                Self::V2(params)
+            }
+        }
+    }
+
+    /// Replace the max number of message outputs with the given argument.
+    /// Upgrades V1 parameters to V2, without a limit on the message data.
+    pub const fn with_max_message_outputs(self, max_message_outputs: u16) -> Self {
+        let mut params = self.into_v2();
+        params.max_message_outputs = max_message_outputs;
+        Self::V2(params)
+    }
+
+    /// Replace the max total length of the message outputs data with the given
+    /// argument. Upgrades V1 parameters to V2, without a limit on the number of
+    /// messages.
+    pub const fn with_max_message_outputs_data_length(
+        self,
+        max_message_outputs_data_length: u64,
+    ) -> Self {
+        let mut params = self.into_v2();
+        params.max_message_outputs_data_length = max_message_outputs_data_length;
+        Self::V2(params)
+    }
+
+    const fn into_v2(self) -> TxParametersV2 {
+        match self {
+            Self::V1(params) => TxParametersV2 {
+                max_inputs: params.max_inputs,
+                max_outputs: params.max_outputs,
+                max_witnesses: params.max_witnesses,
+                max_gas_per_tx: params.max_gas_per_tx,
+                max_size: params.max_size,
+                max_bytecode_subsections: params.max_bytecode_subsections,
+                max_message_outputs: u16::MAX,
+                max_message_outputs_data_length: u64::MAX,
+            },
+            Self::V2(params) => params,
         }
     }
 }
@@ -713,6 +784,7 @@ impl TxParameters {
     pub const fn max_inputs(&self) -> u16 {
         match self {
             Self::V1(params) => params.max_inputs,
+            Self::V2(params) => params.max_inputs,
         }
     }
 
@@ -720,6 +792,7 @@ impl TxParameters {
     pub const fn max_outputs(&self) -> u16 {
         match self {
             Self::V1(params) => params.max_outputs,
+            Self::V2(params) => params.max_outputs,
         }
     }
 
@@ -727,6 +800,7 @@ impl TxParameters {
     pub const fn max_witnesses(&self) -> u32 {
         match self {
             Self::V1(params) => params.max_witnesses,
+            Self::V2(params) => params.max_witnesses,
         }
     }
 
@@ -734,6 +808,7 @@ impl TxParameters {
     pub const fn max_gas_per_tx(&self) -> u64 {
         match self {
             Self::V1(params) => params.max_gas_per_tx,
+            Self::V2(params) => params.max_gas_per_tx,
         }
     }
 
@@ -741,6 +816,7 @@ impl TxParameters {
     pub const fn max_size(&self) -> u64 {
         match self {
             Self::V1(params) => params.max_size,
+            Self::V2(params) => params.max_size,
         }
     }
 
@@ -748,6 +824,28 @@ impl TxParameters {
     pub const fn max_bytecode_subsections(&self) -> u16 {
         match self {
             Self::V1(params) => params.max_bytecode_subsections,
+            Self::V2(params) => params.max_bytecode_subsections,
+        }
+    }
+
+    /// Get the maximum number of messages sent by the transaction with `SMO`.
+    pub const fn max_message_outputs(&self) -> u16 {
+        match self {
+            // In V1 there was no limit on the number of messages. For the sake of
+            // backwards compatibility we allow for a largest limit possible.
+            Self::V1(_) => u16::MAX,
+            Self::V2(params) => params.max_message_outputs,
+        }
+    }
+
+    /// Get the maximum total length of the data of the messages sent by the
+    /// transaction with `SMO`, in bytes.
+    pub const fn max_message_outputs_data_length(&self) -> u64 {
+        match self {
+            // In V1 there was no limit on the message data per transaction. For the
+            // sake of backwards compatibility we allow for a largest limit possible.
+            Self::V1(_) => u64::MAX,
+            Self::V2(params) => params.max_message_outputs_data_length,
         }
     }
 }
@@ -764,6 +862,7 @@ impl TxParameters {
     pub fn set_max_size(&mut self, max_size: u64) {
         match self {
             Self::V1(params) => params.max_size = max_size,
+            Self::V2(params) => params.max_size = max_size,
         }
     }
 }
@@ -774,6 +873,12 @@ impl From<TxParametersV1> for TxParameters {
     }
 }
 
+impl From<TxParametersV2> for TxParameters {
+    fn from(params: TxParametersV2) -> Self {
+        Self::V2(params)
+    }
+}
+
 #[derive(
     Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
 )]
@@ -812,6 +917,51 @@ impl Default for TxParametersV1 {
     }
 }
 
+/// The difference with [`TxParametersV1`]:
+/// - `max_message_outputs` and `max_message_outputs_data_length` have been added.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct TxParametersV2 {
+    /// Maximum number of inputs.
+    pub max_inputs: u16,
+    /// Maximum number of outputs.
+    pub max_outputs: u16,
+    /// Maximum number of witnesses.
+    pub max_witnesses: u32,
+    /// Maximum gas per transaction.
+    pub max_gas_per_tx: u64,
+    /// Maximum size in bytes
+    pub max_size: u64,
+    /// Maximum number of bytecode subsections.
+    pub max_bytecode_subsections: u16,
+    /// Maximum number of messages sent with `SMO`.
+    pub max_message_outputs: u16,
+    /// Maximum total length of the data of the messages sent with `SMO`, in bytes.
+    pub max_message_outputs_data_length: u64,
+}
+
+#[cfg(feature = "test-helpers")]
+impl TxParametersV2 {
+    /// Default parameters just for testing.
+    pub const DEFAULT: Self = Self {
+        max_inputs: 255,
+        max_outputs: 255,
+        max_witnesses: 255,
+        max_gas_per_tx: MAX_GAS,
+        max_size: MAX_SIZE,
+        max_bytecode_subsections: 255,
+        max_message_outputs: 255,
+        max_message_outputs_data_length: 16 * 1024 * 1024,
+    };
+}
+
+#[cfg(feature = "test-helpers")]
+impl Default for TxParametersV2 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
 /// Versioned script parameters.
 #[derive(
     Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
@@ -1037,6 +1187,8 @@ mod tests {
     use crate::consensus_parameters::{
         ConsensusParametersV2,
         SettingBlockTransactionSizeLimitNotSupported,
+        TxParameters,
+        TxParametersV1,
     };
 
     use super::{
@@ -1066,4 +1218,24 @@ mod tests {
 
         assert!(matches!(result, Ok(())))
     }
+
+    #[test]
+    fn tx_parameters_v1_have_no_message_outputs_limits() {
+        let tx_params: TxParameters = TxParametersV1::default().into();
+
+        assert_eq!(tx_params.max_message_outputs(), u16::MAX);
+        assert_eq!(tx_params.max_message_outputs_data_length(), u64::MAX);
+    }
+
+    #[test]
+    fn setting_message_outputs_limit_upgrades_tx_parameters_v1() {
+        let tx_params: TxParameters = TxParametersV1::default().into();
+
+        let tx_params = tx_params.with_max_message_outputs(10);
+
+        assert!(matches!(tx_params, TxParameters::V2(_)));
+        assert_eq!(tx_params.max_message_outputs(), 10);
+        assert_eq!(tx_params.max_message_outputs_data_length(), u64::MAX);
+        assert_eq!(tx_params.max_inputs(), TxParametersV1::DEFAULT.max_inputs);
+    }
 }
@@ -0,0 +1,182 @@
+//! Self-describing serialization of the full set of the consensus parameters.
+
+use alloc::vec::Vec;
+use fuel_crypto::Hasher;
+use fuel_types::Bytes32;
+
+use super::ConsensusParameters;
+
+/// The version of the bundle format written by [`ConsensusParametersBundle::new`].
+pub const CONSENSUS_PARAMETERS_BUNDLE_VERSION: u32 = 1;
+
+/// The consensus parameters with the version of the bundle format and the checksum
+/// of the parameters, for shipping the parameters to tools working offline.
+///
+/// The checksum is the hash of the `postcard` serialization of the parameters, the
+/// same as the checksum of the parameters set by an `Upgrade` transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ConsensusParametersBundle {
+    version: u32,
+    checksum: Bytes32,
+    parameters: ConsensusParameters,
+}
+
+/// The error of decoding a [`ConsensusParametersBundle`].
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum ConsensusParametersBundleError {
+    /// The bundle format version isn't supported.
+    #[display(fmt = "unsupported consensus parameters bundle version {_0}")]
+    UnsupportedVersion(u32),
+    /// The checksum doesn't match the parameters.
+    #[display(fmt = "checksum mismatch: expected {expected}, calculated {calculated}")]
+    ChecksumMismatch {
+        /// The checksum stored in the bundle.
+        expected: Bytes32,
+        /// The checksum of the parameters in the bundle.
+        calculated: Bytes32,
+    },
+    /// The parameters or the bundle can't be (de)serialized.
+    #[display(fmt = "consensus parameters bundle serialization failed")]
+    Serialization,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConsensusParametersBundleError {}
+
+impl ConsensusParametersBundle {
+    /// Bundles the `parameters` with the current version and their checksum.
+    pub fn new(
+        parameters: ConsensusParameters,
+    ) -> Result<Self, ConsensusParametersBundleError> {
+        let checksum = Self::checksum_of(&parameters)?;
+        Ok(Self {
+            version: CONSENSUS_PARAMETERS_BUNDLE_VERSION,
+            checksum,
+            parameters,
+        })
+    }
+
+    /// Calculates the checksum of the `parameters`.
+    pub fn checksum_of(
+        parameters: &ConsensusParameters,
+    ) -> Result<Bytes32, ConsensusParametersBundleError> {
+        let bytes = postcard::to_allocvec(parameters)
+            .map_err(|_| ConsensusParametersBundleError::Serialization)?;
+        Ok(Hasher::hash(bytes))
+    }
+
+    /// The version of the bundle format.
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The checksum of the parameters.
+    pub const fn checksum(&self) -> &Bytes32 {
+        &self.checksum
+    }
+
+    /// The bundled parameters.
+    pub const fn parameters(&self) -> &ConsensusParameters {
+        &self.parameters
+    }
+
+    /// Checks that the version is supported, and the checksum matches the parameters.
+    pub fn verify(&self) -> Result<(), ConsensusParametersBundleError> {
+        if self.version != CONSENSUS_PARAMETERS_BUNDLE_VERSION {
+            return Err(ConsensusParametersBundleError::UnsupportedVersion(
+                self.version,
+            ))
+        }
+
+        let calculated = Self::checksum_of(&self.parameters)?;
+        if calculated != self.checksum {
+            return Err(ConsensusParametersBundleError::ChecksumMismatch {
+                expected: self.checksum,
+                calculated,
+            })
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the bundle, and returns the parameters.
+    pub fn into_parameters(
+        self,
+    ) -> Result<ConsensusParameters, ConsensusParametersBundleError> {
+        self.verify()?;
+        Ok(self.parameters)
+    }
+
+    /// Serializes the bundle with `postcard`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ConsensusParametersBundleError> {
+        postcard::to_allocvec(self)
+            .map_err(|_| ConsensusParametersBundleError::Serialization)
+    }
+
+    /// Deserializes the bundle serialized by [`Self::to_bytes`], and verifies it.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ConsensusParametersBundleError> {
+        let bundle: Self = postcard::from_bytes(bytes)
+            .map_err(|_| ConsensusParametersBundleError::Serialization)?;
+        bundle.verify()?;
+        Ok(bundle)
+    }
+
+    /// Serializes the bundle to JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(
+        &self,
+    ) -> Result<alloc::string::String, ConsensusParametersBundleError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|_| ConsensusParametersBundleError::Serialization)
+    }
+
+    /// Deserializes the bundle serialized by [`Self::to_json`], and verifies it.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, ConsensusParametersBundleError> {
+        let bundle: Self = serde_json::from_str(json)
+            .map_err(|_| ConsensusParametersBundleError::Serialization)?;
+        bundle.verify()?;
+        Ok(bundle)
+    }
+}
+
+impl TryFrom<ConsensusParameters> for ConsensusParametersBundle {
+    type Error = ConsensusParametersBundleError;
+
+    fn try_from(parameters: ConsensusParameters) -> Result<Self, Self::Error> {
+        Self::new(parameters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_roundtrips_and_detects_tampering() {
+        let parameters = ConsensusParameters::standard();
+        let bundle = ConsensusParametersBundle::new(parameters.clone()).unwrap();
+
+        let bytes = bundle.to_bytes().unwrap();
+        let decoded = ConsensusParametersBundle::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.into_parameters().unwrap(), parameters);
+
+        let json = bundle.to_json().unwrap();
+        let decoded = ConsensusParametersBundle::from_json(&json).unwrap();
+        assert_eq!(decoded, bundle);
+
+        let mut tampered = bundle.clone();
+        tampered.parameters.set_block_gas_limit(1);
+        assert!(matches!(
+            tampered.verify(),
+            Err(ConsensusParametersBundleError::ChecksumMismatch { .. })
+        ));
+
+        let mut unsupported = bundle;
+        unsupported.version = u32::MAX;
+        assert_eq!(
+            unsupported.verify(),
+            Err(ConsensusParametersBundleError::UnsupportedVersion(u32::MAX))
+        );
+    }
+}
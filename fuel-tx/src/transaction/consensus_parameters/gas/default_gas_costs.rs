@@ -4,7 +4,7 @@ use super::*;
 pub const GIT: &str = "98341e564b75d1157e61d7d5f38612f6224a5b30";
 /// Modified manually afterwards in https://github.com/FuelLabs/fuel-vm/pull/780
 pub fn default_gas_costs() -> GasCostsValues {
-    GasCostsValuesV4 {
+    GasCostsValuesV5 {
         add: 1,
         addi: 1,
         and: 1,
@@ -93,6 +93,10 @@ pub fn default_gas_costs() -> GasCostsValues {
             base: 2,
             units_per_gas: 214,
         },
+        assert: DependentCost::LightOperation {
+            base: 2,
+            units_per_gas: 64,
+        },
         cfe: DependentCost::LightOperation {
             base: 2,
             units_per_gas: 214,
@@ -0,0 +1,635 @@
+//! Comparison of two gas cost tables.
+
+use super::{
+    DependentCost,
+    GasCostNotDefined,
+    GasCostsValues,
+};
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+use fuel_types::Word;
+
+/// The component of a cost of an operation.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum GasCostComponent {
+    /// The fixed cost of an operation.
+    Fixed,
+    /// The base of a [`DependentCost`].
+    Base,
+    /// The `units_per_gas` of a [`DependentCost::LightOperation`].
+    UnitsPerGas,
+    /// The `gas_per_unit` of a [`DependentCost::HeavyOperation`].
+    GasPerUnit,
+}
+
+impl fmt::Display for GasCostComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed => write!(f, "fixed"),
+            Self::Base => write!(f, "base"),
+            Self::UnitsPerGas => write!(f, "units_per_gas"),
+            Self::GasPerUnit => write!(f, "gas_per_unit"),
+        }
+    }
+}
+
+/// A change of a single cost component between two gas cost tables.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct GasCostChange {
+    /// The name of the operation, e.g. the lowercase mnemonic of an opcode.
+    pub operation: &'static str,
+    /// The changed component of the cost.
+    pub component: GasCostComponent,
+    /// The old value, or `None` if the component wasn't defined.
+    pub old: Option<Word>,
+    /// The new value, or `None` if the component is no longer defined.
+    pub new: Option<Word>,
+}
+
+impl GasCostChange {
+    /// The change relative to the old value, e.g. `0.5` for an increase by half.
+    ///
+    /// Returns `None` if either value is undefined or the old value is zero.
+    pub fn relative_change(&self) -> Option<f64> {
+        let (old, new) = (self.old?, self.new?);
+        if old == 0 {
+            return None
+        }
+        Some((new as f64 - old as f64) / old as f64)
+    }
+}
+
+/// [`GasCostChange`] with the name of the operation not yet resolved.
+#[derive(serde::Deserialize)]
+struct GasCostChangeRepr {
+    operation: String,
+    component: GasCostComponent,
+    old: Option<Word>,
+    new: Option<Word>,
+}
+
+impl TryFrom<GasCostChangeRepr> for GasCostChange {
+    type Error = String;
+
+    fn try_from(repr: GasCostChangeRepr) -> Result<Self, Self::Error> {
+        let operation = costs(&GasCostsValues::unit())
+            .map(|(operation, _)| operation)
+            .find(|operation| *operation == repr.operation)
+            .ok_or_else(|| alloc::format!("unknown operation `{}`", repr.operation))?;
+        Ok(Self {
+            operation,
+            component: repr.component,
+            old: repr.old,
+            new: repr.new,
+        })
+    }
+}
+
+// Implemented manually, as the derive would borrow the name of the operation from
+// the input and require it to be `'static`.
+impl<'de> serde::Deserialize<'de> for GasCostChange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        GasCostChangeRepr::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// An error produced by [`GasCostsValues::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+pub enum GasCostsDiffError {
+    /// The operation isn't a known one.
+    UnknownOperation(&'static str),
+    /// The value of the component differs from the one replaced by the diff.
+    ValueMismatch(&'static str, GasCostComponent),
+    /// The changed components don't form a cost of the operation supported by the
+    /// version of the table, e.g. a dependent cost without its base.
+    InvalidCost(&'static str),
+}
+
+/// The changes between two gas cost tables, as returned by [`GasCostsValues::diff`].
+///
+/// Displays as a Markdown table suitable for release notes.
+#[derive(
+    Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct GasCostsDiff {
+    /// The changed cost components, in the order of the operations.
+    pub changes: Vec<GasCostChange>,
+}
+
+impl GasCostsDiff {
+    /// Returns `true` if the tables are equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for GasCostsDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "| Operation | Component | Old | New | Change |")?;
+        writeln!(f, "|-----------|-----------|-----|-----|--------|")?;
+        for change in &self.changes {
+            write!(f, "| {} | {} | ", change.operation, change.component)?;
+            fmt_value(f, change.old)?;
+            write!(f, " | ")?;
+            fmt_value(f, change.new)?;
+            match change.relative_change() {
+                Some(relative) => writeln!(f, " | {:+.2}% |", relative * 100.0)?,
+                None => writeln!(f, " | - |")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn fmt_value(f: &mut fmt::Formatter<'_>, value: Option<Word>) -> fmt::Result {
+    match value {
+        Some(value) => write!(f, "{value}"),
+        None => write!(f, "-"),
+    }
+}
+
+/// Splits a cost into its components.
+trait Components {
+    fn components(self) -> Vec<(GasCostComponent, Word)>;
+}
+
+impl Components for Word {
+    fn components(self) -> Vec<(GasCostComponent, Word)> {
+        alloc::vec![(GasCostComponent::Fixed, self)]
+    }
+}
+
+impl Components for DependentCost {
+    fn components(self) -> Vec<(GasCostComponent, Word)> {
+        match self {
+            DependentCost::LightOperation {
+                base,
+                units_per_gas,
+            } => alloc::vec![
+                (GasCostComponent::Base, base),
+                (GasCostComponent::UnitsPerGas, units_per_gas),
+            ],
+            DependentCost::HeavyOperation { base, gas_per_unit } => alloc::vec![
+                (GasCostComponent::Base, base),
+                (GasCostComponent::GasPerUnit, gas_per_unit),
+            ],
+        }
+    }
+}
+
+impl Components for Result<DependentCost, GasCostNotDefined> {
+    fn components(self) -> Vec<(GasCostComponent, Word)> {
+        self.map(DependentCost::components).unwrap_or_default()
+    }
+}
+
+// Lists every cost of the table along with the name of its operation.
+macro_rules! costs {
+    ($values:ident; $($name:literal => $accessor:ident,)*) => {
+        [$(($name, $values.$accessor().components()),)*]
+    };
+}
+
+pub(crate) fn costs(
+    values: &GasCostsValues,
+) -> impl Iterator<Item = (&'static str, Vec<(GasCostComponent, Word)>)> {
+    costs! { values;
+        "add" => add,
+        "addi" => addi,
+        "and" => and,
+        "andi" => andi,
+        "bal" => bal,
+        "bhei" => bhei,
+        "bhsh" => bhsh,
+        "burn" => burn,
+        "cb" => cb,
+        "cfsi" => cfsi,
+        "div" => div,
+        "divi" => divi,
+        "eck1" => eck1,
+        "ecr1" => ecr1,
+        "eq" => eq_,
+        "exp" => exp,
+        "expi" => expi,
+        "flag" => flag,
+        "gm" => gm,
+        "gt" => gt,
+        "gtf" => gtf,
+        "ji" => ji,
+        "jmp" => jmp,
+        "jne" => jne,
+        "jnei" => jnei,
+        "jnzi" => jnzi,
+        "jmpf" => jmpf,
+        "jmpb" => jmpb,
+        "jnzf" => jnzf,
+        "jnzb" => jnzb,
+        "jnef" => jnef,
+        "jneb" => jneb,
+        "lb" => lb,
+        "log" => log,
+        "lt" => lt,
+        "lw" => lw,
+        "mint" => mint,
+        "mlog" => mlog,
+        "mod" => mod_op,
+        "modi" => modi,
+        "move" => move_op,
+        "movi" => movi,
+        "mroo" => mroo,
+        "mul" => mul,
+        "muli" => muli,
+        "mldv" => mldv,
+        "noop" => noop,
+        "not" => not,
+        "or" => or,
+        "ori" => ori,
+        "poph" => poph,
+        "popl" => popl,
+        "pshh" => pshh,
+        "pshl" => pshl,
+        "ret" => ret,
+        "rvrt" => rvrt,
+        "sb" => sb,
+        "sll" => sll,
+        "slli" => slli,
+        "srl" => srl,
+        "srli" => srli,
+        "srw" => srw,
+        "sub" => sub,
+        "subi" => subi,
+        "sw" => sw,
+        "sww" => sww,
+        "time" => time,
+        "tr" => tr,
+        "tro" => tro,
+        "wdcm" => wdcm,
+        "wqcm" => wqcm,
+        "wdop" => wdop,
+        "wqop" => wqop,
+        "wdml" => wdml,
+        "wqml" => wqml,
+        "wddv" => wddv,
+        "wqdv" => wqdv,
+        "wdmd" => wdmd,
+        "wqmd" => wqmd,
+        "wdam" => wdam,
+        "wqam" => wqam,
+        "wdmm" => wdmm,
+        "wqmm" => wqmm,
+        "xor" => xor,
+        "xori" => xori,
+        "aloc" => aloc,
+        "cfe" => cfe,
+        "cfei" => cfei,
+        "call" => call,
+        "ccp" => ccp,
+        "croo" => croo,
+        "csiz" => csiz,
+        "ed19" => ed19,
+        "k256" => k256,
+        "ldc" => ldc,
+        "logd" => logd,
+        "mcl" => mcl,
+        "mcli" => mcli,
+        "mcp" => mcp,
+        "mcpi" => mcpi,
+        "meq" => meq,
+        "retd" => retd,
+        "s256" => s256,
+        "scwq" => scwq,
+        "smo" => smo,
+        "srwq" => srwq,
+        "swwq" => swwq,
+        "bsiz" => bsiz,
+        "bldd" => bldd,
+        "assert" => assert,
+        "contract_root" => contract_root,
+        "state_root" => state_root,
+        "new_storage_per_byte" => new_storage_per_byte,
+        "vm_initialization" => vm_initialization,
+    }
+    .into_iter()
+}
+
+impl GasCostsValues {
+    /// Compares the costs with the `new` ones, regardless of their versions.
+    ///
+    /// Costs that aren't defined by one of the versions are reported as missing.
+    pub fn diff(&self, new: &GasCostsValues) -> GasCostsDiff {
+        const COMPONENTS: [GasCostComponent; 4] = [
+            GasCostComponent::Fixed,
+            GasCostComponent::Base,
+            GasCostComponent::UnitsPerGas,
+            GasCostComponent::GasPerUnit,
+        ];
+
+        let changes = costs(self)
+            .zip(costs(new))
+            .flat_map(|((operation, old), (_, new))| {
+                let value = |costs: &[(GasCostComponent, Word)], component| {
+                    costs
+                        .iter()
+                        .find(|(c, _)| *c == component)
+                        .map(|(_, value)| *value)
+                };
+
+                COMPONENTS.into_iter().filter_map(move |component| {
+                    let old = value(&old, component);
+                    let new = value(&new, component);
+                    (old != new).then_some(GasCostChange {
+                        operation,
+                        component,
+                        old,
+                        new,
+                    })
+                })
+            })
+            .collect();
+
+        GasCostsDiff { changes }
+    }
+
+    /// Applies the changes of the `diff` to the costs, which must be the old ones.
+    /// The costs are left unmodified on failure.
+    ///
+    /// The version of the costs is preserved, so costs it doesn't define can't be
+    /// changed.
+    #[cfg(feature = "serde")]
+    pub fn apply(&mut self, diff: &GasCostsDiff) -> Result<(), GasCostsDiffError> {
+        use alloc::collections::BTreeMap;
+        use serde_json::Value;
+
+        let mut table: BTreeMap<_, _> = costs(self).collect();
+        for change in &diff.changes {
+            let components = table
+                .get_mut(change.operation)
+                .ok_or(GasCostsDiffError::UnknownOperation(change.operation))?;
+            let position = components.iter().position(|(c, _)| *c == change.component);
+            let old = position
+                .and_then(|position| components.get(position))
+                .map(|(_, value)| *value);
+            if old != change.old {
+                return Err(GasCostsDiffError::ValueMismatch(
+                    change.operation,
+                    change.component,
+                ))
+            }
+            match (position, change.new) {
+                (Some(position), Some(new)) => components[position].1 = new,
+                (Some(position), None) => {
+                    components.remove(position);
+                }
+                (None, Some(new)) => components.push((change.component, new)),
+                (None, None) => {}
+            }
+        }
+
+        // The costs are rebuilt from their serialized form, where each cost is a
+        // field named after its operation.
+        let mut serialized =
+            serde_json::to_value(&*self).expect("The costs are serializable");
+        let fields = serialized
+            .as_object_mut()
+            .and_then(|versions| versions.values_mut().next())
+            .and_then(Value::as_object_mut)
+            .expect("The costs are serialized as a versioned struct");
+        for change in &diff.changes {
+            let invalid = GasCostsDiffError::InvalidCost(change.operation);
+            let cost = table
+                .get(change.operation)
+                .and_then(|components| serialize_cost(components))
+                .ok_or(invalid)?;
+            let field = fields
+                .get_mut(serialized_name(change.operation))
+                .ok_or(invalid)?;
+            if field.is_number() != cost.is_number() {
+                return Err(invalid)
+            }
+            *field = cost;
+        }
+        *self = serde_json::from_value(serialized).expect("The costs are deserializable");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl super::GasCosts {
+    /// Applies the changes of the `diff` to the costs, like
+    /// [`GasCostsValues::apply`].
+    pub fn apply(&mut self, diff: &GasCostsDiff) -> Result<(), GasCostsDiffError> {
+        let mut values = (**self).clone();
+        values.apply(diff)?;
+        *self = values.into();
+        Ok(())
+    }
+}
+
+/// The name of the field of the cost of the `operation` in the serialized costs.
+#[cfg(feature = "serde")]
+fn serialized_name(operation: &'static str) -> &'static str {
+    match operation {
+        "ret" => "ret_contract",
+        "rvrt" => "rvrt_contract",
+        "retd" => "retd_contract",
+        operation => operation,
+    }
+}
+
+/// Serializes the cost made of the `components`, or returns `None` if they don't
+/// form a cost.
+#[cfg(feature = "serde")]
+fn serialize_cost(components: &[(GasCostComponent, Word)]) -> Option<serde_json::Value> {
+    let component = |component| {
+        components
+            .iter()
+            .find(|(c, _)| *c == component)
+            .map(|(_, value)| *value)
+    };
+    let cost = match (
+        components.len(),
+        component(GasCostComponent::Fixed),
+        component(GasCostComponent::Base),
+        component(GasCostComponent::UnitsPerGas),
+        component(GasCostComponent::GasPerUnit),
+    ) {
+        (1, Some(fixed), _, _, _) => return serde_json::to_value(fixed).ok(),
+        (2, _, Some(base), Some(units_per_gas), _) if units_per_gas > 0 => {
+            DependentCost::from_units_per_gas(base, units_per_gas)
+        }
+        (2, _, Some(base), _, Some(gas_per_unit)) => {
+            DependentCost::from_gas_per_unit(base, gas_per_unit)
+        }
+        _ => return None,
+    };
+    serde_json::to_value(cost).ok()
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::consensus_parameters::gas::{
+        GasCosts,
+        GasCostsValuesV3,
+        GasCostsValuesV4,
+    };
+
+    #[test]
+    fn diff__equal_tables_have_no_changes() {
+        let costs = GasCostsValues::default();
+
+        assert!(costs.diff(&costs).is_empty());
+    }
+
+    #[test]
+    fn diff__reports_fixed_and_dependent_changes() {
+        let old = GasCostsValuesV4::unit();
+        let mut new = GasCostsValuesV4::unit();
+        new.add = 3;
+        new.k256 = DependentCost::from_units_per_gas(1, 4);
+
+        let diff = GasCostsValues::from(old).diff(&new.into());
+
+        assert_eq!(
+            diff.changes,
+            alloc::vec![
+                GasCostChange {
+                    operation: "add",
+                    component: GasCostComponent::Fixed,
+                    old: Some(1),
+                    new: Some(3),
+                },
+                GasCostChange {
+                    operation: "k256",
+                    component: GasCostComponent::UnitsPerGas,
+                    old: None,
+                    new: Some(4),
+                },
+                GasCostChange {
+                    operation: "k256",
+                    component: GasCostComponent::GasPerUnit,
+                    old: Some(0),
+                    new: None,
+                },
+            ]
+        );
+        assert_eq!(diff.changes[0].relative_change(), Some(2.0));
+        assert_eq!(diff.changes[1].relative_change(), None);
+    }
+
+    #[test]
+    fn diff__reports_costs_missing_in_older_version() {
+        let old = GasCostsValues::from(GasCostsValuesV3::unit());
+        let new = GasCostsValues::from(GasCostsValuesV4::unit());
+
+        let diff = old.diff(&new);
+
+        let operations: Vec<_> = diff
+            .changes
+            .iter()
+            .map(|change| (change.operation, change.component, change.old))
+            .collect();
+        assert_eq!(
+            operations,
+            alloc::vec![
+                ("bsiz", GasCostComponent::Base, None),
+                ("bsiz", GasCostComponent::GasPerUnit, None),
+                ("bldd", GasCostComponent::Base, None),
+                ("bldd", GasCostComponent::GasPerUnit, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff__displays_as_markdown_table() {
+        let old = GasCostsValuesV4::unit();
+        let mut new = GasCostsValuesV4::unit();
+        new.add = 3;
+        new.sub = 0;
+
+        let diff = GasCostsValues::from(old).diff(&new.into());
+
+        assert_eq!(
+            alloc::format!("{diff}"),
+            "| Operation | Component | Old | New | Change |\n\
+             |-----------|-----------|-----|-----|--------|\n\
+             | add | fixed | 1 | 3 | +200.00% |\n\
+             | sub | fixed | 1 | 0 | -100.00% |\n"
+        );
+    }
+
+    #[test]
+    fn apply__reproduces_the_new_costs() {
+        let old = GasCostsValuesV4::unit();
+        let mut new = GasCostsValuesV4::unit();
+        new.add = 3;
+        new.mod_op = 5;
+        new.ret = 7;
+        new.k256 = DependentCost::from_units_per_gas(2, 4);
+        let (old, new) = (GasCostsValues::from(old), GasCostsValues::from(new));
+        let diff = old.diff(&new);
+
+        let mut costs = GasCosts::from(old);
+        costs.apply(&diff).unwrap();
+
+        assert_eq!(*costs, new);
+    }
+
+    #[test]
+    fn apply__fails_on_mismatching_old_value() {
+        let old = GasCostsValues::from(GasCostsValuesV4::unit());
+        let mut new = GasCostsValuesV4::unit();
+        new.add = 3;
+        let diff = old.diff(&new.clone().into());
+
+        let mut costs = GasCostsValues::from(new);
+        let result = costs.apply(&diff);
+
+        assert_eq!(
+            result,
+            Err(GasCostsDiffError::ValueMismatch(
+                "add",
+                GasCostComponent::Fixed
+            ))
+        );
+    }
+
+    #[test]
+    fn apply__fails_on_costs_missing_in_the_version() {
+        let old = GasCostsValues::from(GasCostsValuesV3::unit());
+        let new = GasCostsValues::from(GasCostsValuesV4::unit());
+        let diff = old.diff(&new);
+
+        let mut costs = old.clone();
+        let result = costs.apply(&diff);
+
+        assert_eq!(result, Err(GasCostsDiffError::InvalidCost("bsiz")));
+        assert_eq!(costs, old);
+    }
+
+    #[test]
+    fn diff__roundtrips_through_json() {
+        let old = GasCostsValuesV4::unit();
+        let mut new = GasCostsValuesV4::unit();
+        new.add = 3;
+        let diff = GasCostsValues::from(old).diff(&new.into());
+
+        let json = serde_json::to_string(&diff).unwrap();
+        let decoded: GasCostsDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, diff);
+
+        let unknown = json.replace("\"add\"", "\"nope\"");
+        assert!(serde_json::from_str::<GasCostsDiff>(&unknown).is_err());
+    }
+}
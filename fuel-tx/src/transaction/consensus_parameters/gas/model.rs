@@ -0,0 +1,223 @@
+//! Extension point for alternative gas pricing.
+
+use super::{
+    DependentCost,
+    GasCostNotDefined,
+    GasCostsValues,
+};
+use core::fmt;
+use fuel_types::Word;
+
+#[cfg(feature = "alloc")]
+use super::GasCosts;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+
+// Declares the costs of the model, defaulting to the accessors of the same name of
+// the base costs.
+macro_rules! gas_cost_model {
+    ($($name:ident: $ty:ty,)*) => {
+        /// The costs charged by the interpreter for the operations.
+        ///
+        /// [`GasCosts`] is the default implementation. Other implementations allow
+        /// experimenting with alternative pricing, e.g. costs depending on the memory
+        /// usage, without changing the consensus parameters. Only the changed costs
+        /// need to be overridden, the others are taken from
+        /// [`GasCostModel::base_costs`]. Models with state need to use interior
+        /// mutability, as the costs are queried through shared references.
+        #[allow(missing_docs)]
+        pub trait GasCostModel: fmt::Debug + Send + Sync {
+            /// The costs that aren't overridden by the model.
+            fn base_costs(&self) -> &GasCostsValues;
+
+            $(fn $name(&self) -> $ty {
+                self.base_costs().$name()
+            })*
+        }
+
+        /// The costs charged by the interpreter, looked up directly in the
+        /// [`GasCostsValues`] unless a custom [`GasCostModel`] is used, so the default
+        /// pricing doesn't pay for the dynamic dispatch of every instruction.
+        #[derive(Debug, Clone, Copy)]
+        pub enum GasModelRef<'a> {
+            /// The costs of the consensus parameters.
+            Costs(&'a GasCostsValues),
+            /// A custom model.
+            Model(&'a dyn GasCostModel),
+        }
+
+        impl GasModelRef<'_> {
+            $(#[inline(always)]
+            pub fn $name(&self) -> $ty {
+                match self {
+                    Self::Costs(costs) => costs.$name(),
+                    Self::Model(model) => model.$name(),
+                }
+            })*
+        }
+    };
+}
+
+gas_cost_model! {
+    add: Word,
+    addi: Word,
+    and: Word,
+    andi: Word,
+    bal: Word,
+    bhei: Word,
+    bhsh: Word,
+    burn: Word,
+    cb: Word,
+    cfsi: Word,
+    div: Word,
+    divi: Word,
+    eck1: Word,
+    ecr1: Word,
+    eq_: Word,
+    exp: Word,
+    expi: Word,
+    flag: Word,
+    gm: Word,
+    gt: Word,
+    gtf: Word,
+    ji: Word,
+    jmp: Word,
+    jne: Word,
+    jnei: Word,
+    jnzi: Word,
+    jmpf: Word,
+    jmpb: Word,
+    jnzf: Word,
+    jnzb: Word,
+    jnef: Word,
+    jneb: Word,
+    lb: Word,
+    log: Word,
+    lt: Word,
+    lw: Word,
+    mint: Word,
+    mlog: Word,
+    mod_op: Word,
+    modi: Word,
+    move_op: Word,
+    movi: Word,
+    mroo: Word,
+    mul: Word,
+    muli: Word,
+    mldv: Word,
+    noop: Word,
+    not: Word,
+    or: Word,
+    ori: Word,
+    poph: Word,
+    popl: Word,
+    pshh: Word,
+    pshl: Word,
+    ret: Word,
+    rvrt: Word,
+    sb: Word,
+    sll: Word,
+    slli: Word,
+    srl: Word,
+    srli: Word,
+    srw: Word,
+    sub: Word,
+    subi: Word,
+    sw: Word,
+    sww: Word,
+    time: Word,
+    tr: Word,
+    tro: Word,
+    wdcm: Word,
+    wqcm: Word,
+    wdop: Word,
+    wqop: Word,
+    wdml: Word,
+    wqml: Word,
+    wddv: Word,
+    wqdv: Word,
+    wdmd: Word,
+    wqmd: Word,
+    wdam: Word,
+    wqam: Word,
+    wdmm: Word,
+    wqmm: Word,
+    xor: Word,
+    xori: Word,
+    aloc: DependentCost,
+    cfe: DependentCost,
+    cfei: DependentCost,
+    call: DependentCost,
+    ccp: DependentCost,
+    croo: DependentCost,
+    csiz: DependentCost,
+    ed19: DependentCost,
+    k256: DependentCost,
+    ldc: DependentCost,
+    logd: DependentCost,
+    mcl: DependentCost,
+    mcli: DependentCost,
+    mcp: DependentCost,
+    mcpi: DependentCost,
+    meq: DependentCost,
+    retd: DependentCost,
+    s256: DependentCost,
+    scwq: DependentCost,
+    smo: DependentCost,
+    srwq: DependentCost,
+    swwq: DependentCost,
+    bsiz: Result<DependentCost, GasCostNotDefined>,
+    bldd: Result<DependentCost, GasCostNotDefined>,
+    assert: Result<DependentCost, GasCostNotDefined>,
+    contract_root: DependentCost,
+    state_root: DependentCost,
+    new_storage_per_byte: Word,
+    vm_initialization: DependentCost,
+}
+
+impl GasCostModel for GasCostsValues {
+    fn base_costs(&self) -> &GasCostsValues {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl GasCostModel for GasCosts {
+    fn base_costs(&self) -> &GasCostsValues {
+        &self.0
+    }
+}
+
+/// A shared [`GasCostModel`], cheap to clone.
+///
+/// Two instances are equal only if one is a clone of the other.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct SharedGasCostModel(Arc<dyn GasCostModel>);
+
+#[cfg(feature = "alloc")]
+impl SharedGasCostModel {
+    /// Wraps the model.
+    pub fn new<M: GasCostModel + 'static>(model: M) -> Self {
+        Self(Arc::new(model))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::ops::Deref for SharedGasCostModel {
+    type Target = dyn GasCostModel;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for SharedGasCostModel {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for SharedGasCostModel {}
@@ -0,0 +1,373 @@
+//! Structured differences between a transaction and its replacement, e.g. when a
+//! pending transaction is replaced by one paying a higher tip.
+
+use crate::{
+    field,
+    policies::PolicyType,
+    Input,
+    Output,
+    Witness,
+};
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use fuel_types::Word;
+use strum::IntoEnumIterator;
+
+/// An error produced by [`TransactionDiff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransactionDiffError {
+    /// The transactions differ in anything else than their inputs, outputs,
+    /// witnesses and policies.
+    BodyChanged,
+    /// The input at the index differs from the one removed by the diff.
+    InputMismatch(usize),
+    /// The output at the index differs from the one removed by the diff.
+    OutputMismatch(usize),
+    /// The witness at the index differs from the one removed by the diff.
+    WitnessMismatch(usize),
+    /// The value of the policy differs from the one replaced by the diff.
+    PolicyMismatch(PolicyType),
+}
+
+/// The items removed from and added to a sequence, e.g. the inputs of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceDiff<T> {
+    removed: Vec<(usize, T)>,
+    added: Vec<(usize, T)>,
+}
+
+impl<T> SequenceDiff<T>
+where
+    T: Clone + PartialEq,
+{
+    /// The items of `old` missing in `new`, keeping the longest common subsequence.
+    fn new(old: &[T], new: &[T]) -> Self {
+        // `common[i][j]` is the length of the longest common subsequence of
+        // `old[i..]` and `new[j..]`.
+        let mut common =
+            vec![vec![0usize; new.len().saturating_add(1)]; old.len().saturating_add(1)];
+        for (i, old_item) in old.iter().enumerate().rev() {
+            for (j, new_item) in new.iter().enumerate().rev() {
+                let next_i = i.saturating_add(1);
+                let next_j = j.saturating_add(1);
+                common[i][j] = if old_item == new_item {
+                    common[next_i][next_j].saturating_add(1)
+                } else {
+                    common[next_i][j].max(common[i][next_j])
+                };
+            }
+        }
+
+        let mut diff = Self {
+            removed: Vec::new(),
+            added: Vec::new(),
+        };
+        let (mut i, mut j) = (0, 0);
+        while let (Some(old_item), Some(new_item)) = (old.get(i), new.get(j)) {
+            let next_i = i.saturating_add(1);
+            let next_j = j.saturating_add(1);
+            if old_item == new_item {
+                (i, j) = (next_i, next_j);
+            } else if common[next_i][j] >= common[i][next_j] {
+                diff.removed.push((i, old_item.clone()));
+                i = next_i;
+            } else {
+                diff.added.push((j, new_item.clone()));
+                j = next_j;
+            }
+        }
+        diff.removed.extend(old.iter().cloned().enumerate().skip(i));
+        diff.added.extend(new.iter().cloned().enumerate().skip(j));
+        diff
+    }
+
+    /// The removed items, with their indices in the original sequence.
+    pub fn removed(&self) -> &[(usize, T)] {
+        &self.removed
+    }
+
+    /// The added items, with their indices in the replacing sequence.
+    pub fn added(&self) -> &[(usize, T)] {
+        &self.added
+    }
+
+    /// Returns `true` if the sequences are equal.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+
+    /// Applies the diff to `items`, or returns the index of the first item that
+    /// differs from the removed one.
+    fn apply(&self, items: &mut Vec<T>) -> Result<(), usize> {
+        for (index, item) in self.removed.iter().rev() {
+            if items.get(*index) != Some(item) {
+                return Err(*index)
+            }
+            items.remove(*index);
+        }
+        for (index, item) in &self.added {
+            if *index > items.len() {
+                return Err(*index)
+            }
+            items.insert(*index, item.clone());
+        }
+        Ok(())
+    }
+}
+
+/// A change of the value of a policy, `None` if the policy isn't set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolicyChange {
+    pub policy_type: PolicyType,
+    pub old: Option<Word>,
+    pub new: Option<Word>,
+}
+
+/// The difference between a transaction and its replacement.
+///
+/// The replacement differs only in its inputs, outputs, witnesses and policies, as
+/// when a wallet bumps the fee of a pending transaction. Applying the diff to the
+/// original transaction produces the replacement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionDiff {
+    inputs: SequenceDiff<Input>,
+    outputs: SequenceDiff<Output>,
+    witnesses: SequenceDiff<Witness>,
+    policies: Vec<PolicyChange>,
+}
+
+impl TransactionDiff {
+    /// Computes the changes replacing `old` by `new`.
+    pub fn new<Tx>(old: &Tx, new: &Tx) -> Result<Self, TransactionDiffError>
+    where
+        Tx: Clone
+            + PartialEq
+            + field::Inputs
+            + field::Outputs
+            + field::Witnesses
+            + field::Policies,
+    {
+        let mut body = new.clone();
+        *body.inputs_mut() = old.inputs().clone();
+        *body.outputs_mut() = old.outputs().clone();
+        *body.witnesses_mut() = old.witnesses().clone();
+        *body.policies_mut() = *old.policies();
+        if body != *old {
+            return Err(TransactionDiffError::BodyChanged)
+        }
+
+        let policies = PolicyType::iter()
+            .filter_map(|policy_type| {
+                let old = old.policies().get(policy_type);
+                let new = new.policies().get(policy_type);
+                (old != new).then_some(PolicyChange {
+                    policy_type,
+                    old,
+                    new,
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            inputs: SequenceDiff::new(old.inputs(), new.inputs()),
+            outputs: SequenceDiff::new(old.outputs(), new.outputs()),
+            witnesses: SequenceDiff::new(old.witnesses(), new.witnesses()),
+            policies,
+        })
+    }
+
+    /// The changes of the inputs.
+    pub fn inputs(&self) -> &SequenceDiff<Input> {
+        &self.inputs
+    }
+
+    /// The changes of the outputs.
+    pub fn outputs(&self) -> &SequenceDiff<Output> {
+        &self.outputs
+    }
+
+    /// The changes of the witnesses.
+    pub fn witnesses(&self) -> &SequenceDiff<Witness> {
+        &self.witnesses
+    }
+
+    /// The changed policies.
+    pub fn policies(&self) -> &[PolicyChange] {
+        &self.policies
+    }
+
+    /// Returns `true` if the transactions are equal.
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+            && self.outputs.is_empty()
+            && self.witnesses.is_empty()
+            && self.policies.is_empty()
+    }
+
+    /// Returns `true` if the replacement keeps the inputs and the outputs, and
+    /// changes only the policies of the `policy_types`, e.g. only the tip. The
+    /// witnesses are ignored, as the replacement is signed again.
+    pub fn changes_only(&self, policy_types: &[PolicyType]) -> bool {
+        self.inputs.is_empty()
+            && self.outputs.is_empty()
+            && self
+                .policies
+                .iter()
+                .all(|change| policy_types.contains(&change.policy_type))
+    }
+
+    /// Applies the changes to `tx`, which must be the original transaction. `tx` is
+    /// left unmodified on failure. The metadata of `tx` must be precomputed again.
+    pub fn apply<Tx>(&self, tx: &mut Tx) -> Result<(), TransactionDiffError>
+    where
+        Tx: field::Inputs + field::Outputs + field::Witnesses + field::Policies,
+    {
+        let mut inputs = tx.inputs().clone();
+        self.inputs
+            .apply(&mut inputs)
+            .map_err(TransactionDiffError::InputMismatch)?;
+        let mut outputs = tx.outputs().clone();
+        self.outputs
+            .apply(&mut outputs)
+            .map_err(TransactionDiffError::OutputMismatch)?;
+        let mut witnesses = tx.witnesses().clone();
+        self.witnesses
+            .apply(&mut witnesses)
+            .map_err(TransactionDiffError::WitnessMismatch)?;
+        let mut policies = *tx.policies();
+        for change in &self.policies {
+            if policies.get(change.policy_type) != change.old {
+                return Err(TransactionDiffError::PolicyMismatch(change.policy_type))
+            }
+            policies.set(change.policy_type, change.new);
+        }
+
+        *tx.inputs_mut() = inputs;
+        *tx.outputs_mut() = outputs;
+        *tx.witnesses_mut() = witnesses;
+        *tx.policies_mut() = policies;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        field::{
+            Inputs,
+            Outputs,
+            Policies as _,
+            Script as _,
+            Witnesses,
+        },
+        policies::Policies,
+        Script,
+        Transaction,
+    };
+    use fuel_types::AssetId;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn coin(rng: &mut StdRng, amount: Word) -> Input {
+        Input::coin_signed(rng.gen(), rng.gen(), amount, AssetId::BASE, rng.gen(), 0)
+    }
+
+    fn script(rng: &mut StdRng, tip: Word) -> Script {
+        Transaction::script(
+            100,
+            vec![1, 2, 3],
+            vec![],
+            Policies::new().with_tip(tip).with_max_fee(1000),
+            vec![coin(rng, 10), coin(rng, 20)],
+            vec![Output::change(rng.gen(), 0, AssetId::BASE)],
+            vec![vec![1; 64].into()],
+        )
+    }
+
+    #[test]
+    fn fee_bump_changes_only_the_tip() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let old = script(rng, 1);
+        let mut new = old.clone();
+        new.policies_mut().set(PolicyType::Tip, Some(5));
+        *new.witnesses_mut() = vec![vec![2; 64].into()];
+
+        let diff = TransactionDiff::new(&old, &new).unwrap();
+
+        assert_eq!(
+            diff.policies(),
+            [PolicyChange {
+                policy_type: PolicyType::Tip,
+                old: Some(1),
+                new: Some(5),
+            }]
+        );
+        assert!(diff.changes_only(&[PolicyType::Tip]));
+        assert!(!diff.changes_only(&[PolicyType::MaxFee]));
+        let mut patched = old.clone();
+        diff.apply(&mut patched).unwrap();
+        assert_eq!(patched, new);
+    }
+
+    #[test]
+    fn added_and_removed_inputs_are_patched_in_place() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let old = script(rng, 1);
+        let mut new = old.clone();
+        let added = coin(rng, 30);
+        new.inputs_mut().remove(0);
+        new.inputs_mut().push(added.clone());
+        new.outputs_mut()
+            .push(Output::coin(rng.gen(), 5, AssetId::BASE));
+
+        let diff = TransactionDiff::new(&old, &new).unwrap();
+
+        assert_eq!(diff.inputs().removed(), [(0, old.inputs()[0].clone())]);
+        assert_eq!(diff.inputs().added(), [(1, added)]);
+        assert_eq!(diff.outputs().added().len(), 1);
+        assert!(!diff.changes_only(&[PolicyType::Tip]));
+        let mut patched = old.clone();
+        diff.apply(&mut patched).unwrap();
+        assert_eq!(patched, new);
+    }
+
+    #[test]
+    fn diff_of_other_fields_or_of_another_transaction_fails() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let old = script(rng, 1);
+        let mut new = old.clone();
+        *new.script_mut() = vec![4];
+        assert_eq!(
+            TransactionDiff::new(&old, &new),
+            Err(TransactionDiffError::BodyChanged)
+        );
+
+        let mut new = old.clone();
+        new.inputs_mut().remove(1);
+        new.policies_mut().set(PolicyType::Tip, Some(2));
+        let diff = TransactionDiff::new(&old, &new).unwrap();
+
+        let mut other = script(rng, 1);
+        assert_eq!(
+            diff.apply(&mut other),
+            Err(TransactionDiffError::InputMismatch(1))
+        );
+        let mut other = old.clone();
+        other.policies_mut().set(PolicyType::Tip, Some(3));
+        assert_eq!(
+            diff.apply(&mut other),
+            Err(TransactionDiffError::PolicyMismatch(PolicyType::Tip))
+        );
+        assert_eq!(other.inputs(), old.inputs());
+    }
+}
@@ -1,3 +1,5 @@
+//! Fee and gas computation of chargeable transactions.
+
 use crate::{
     field,
     field::{
@@ -22,6 +24,7 @@ use crate::{
     GasCosts,
     Input,
 };
+use alloc::vec::Vec;
 use fuel_asm::Word;
 use fuel_types::canonical::Serialize;
 use hashbrown::HashSet;
@@ -110,6 +113,44 @@ impl TransactionFee {
     }
 }
 
+/// Fee accounting of an executed transaction, with the inputs of the formula.
+///
+/// The charged fee is the `tip` plus
+/// `ceil((min_gas + execution_gas) * gas_price / gas_price_factor)`, and the rest of
+/// the [`MaxFeeLimit`] is refunded. The gas reserved by the witness limit, but not
+/// used by the witnesses, is never charged.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeBreakdown {
+    /// Gas charged before the execution, see [`Chargeable::min_gas`].
+    pub min_gas: Word,
+    /// Gas charged for the bytes of the witnesses. Included in `min_gas`.
+    pub witness_gas: Word,
+    /// Gas reserved by the witness limit, but not used by the witnesses.
+    pub unused_witness_gas: Word,
+    /// Gas used by the execution.
+    pub execution_gas: Word,
+    /// The gas price the transaction was executed with.
+    pub gas_price: Word,
+    /// The divisor of the gas price, see [`FeeParameters::gas_price_factor`].
+    pub gas_price_factor: Word,
+    /// The tip paid to the block producer. Included in `used_fee`.
+    pub tip: Word,
+    /// The fee charged for the transaction.
+    pub used_fee: Word,
+    /// The maximum fee the transaction was willing to pay.
+    pub max_fee_limit: Word,
+    /// The fee refunded to the owner of the base asset inputs.
+    pub refund: Word,
+}
+
+impl FeeBreakdown {
+    /// Total gas charged for the transaction.
+    pub const fn used_gas(&self) -> Word {
+        self.min_gas.saturating_add(self.execution_gas)
+    }
+}
+
 fn gas_to_fee(gas: Word, gas_price: Word, factor: Word) -> u128 {
     let total_price = (gas as u128)
         .checked_mul(gas_price as u128)
@@ -117,6 +158,42 @@ fn gas_to_fee(gas: Word, gas_price: Word, factor: Word) -> u128 {
     total_price.div_ceil(factor as u128)
 }
 
+/// Estimates the minimum fee of the transaction, charged even if the execution stops
+/// right away.
+///
+/// The estimation doesn't require the transaction to be checked by the VM, so it can be
+/// used to preview the fee while the transaction is being built. The gas used by the
+/// predicates must be estimated beforehand, as it is part of the fee.
+///
+/// Returns `None` if the fee doesn't fit into a [`Word`].
+pub fn min_fee<Tx>(
+    tx: &Tx,
+    fee: &FeeParameters,
+    gas_costs: &GasCosts,
+    gas_price: Word,
+) -> Option<Word>
+where
+    Tx: Chargeable,
+{
+    tx.min_fee(gas_costs, fee, gas_price).try_into().ok()
+}
+
+/// Estimates the maximum fee of the transaction, which has to be covered by the
+/// [`MaxFeeLimit`] policy. See [`min_fee`] for the requirements of the estimation.
+///
+/// Returns `None` if the fee doesn't fit into a [`Word`].
+pub fn estimate_max_fee<Tx>(
+    tx: &Tx,
+    fee: &FeeParameters,
+    gas_costs: &GasCosts,
+    gas_price: Word,
+) -> Option<Word>
+where
+    Tx: Chargeable,
+{
+    tx.max_fee(gas_costs, fee, gas_price).try_into().ok()
+}
+
 /// Returns the minimum gas required to start execution of any transaction.
 pub fn min_gas<Tx>(tx: &Tx, gas_costs: &GasCosts, fee: &FeeParameters) -> Word
 where
@@ -136,6 +213,90 @@ where
         .saturating_add(vm_initialization_gas)
 }
 
+/// Fees of a transaction under one gas price, see [`FeeSimulator`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeScenario {
+    /// The simulated gas price.
+    pub gas_price: Word,
+    /// The minimum and the maximum fees, or `None` if they don't fit into a [`Word`].
+    pub fee: Option<TransactionFee>,
+    /// The maximum fee the transaction is willing to pay, see [`MaxFeeLimit`].
+    pub max_fee_limit: Word,
+    /// The refund if the execution uses all available gas, or `None` if the
+    /// [`MaxFeeLimit`] doesn't cover the fee.
+    pub min_refund: Option<Word>,
+    /// The refund if the execution uses no gas, or `None` if the [`MaxFeeLimit`]
+    /// doesn't cover the fee.
+    pub max_refund: Option<Word>,
+}
+
+impl FeeScenario {
+    /// Returns `true` if the [`MaxFeeLimit`] covers the maximum fee, which is required
+    /// for the transaction to be valid under the gas price.
+    pub fn is_covered(&self) -> bool {
+        self.fee
+            .is_some_and(|fee| fee.max_fee() <= self.max_fee_limit)
+    }
+}
+
+/// Simulates the fees of a transaction across several gas prices.
+///
+/// The simulation uses the same formulas as the node, so the fees and the refunds match
+/// the charged ones, as long as the transaction and the parameters don't change.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeSimulator<'a> {
+    gas_costs: &'a GasCosts,
+    fee: &'a FeeParameters,
+}
+
+impl<'a> FeeSimulator<'a> {
+    /// Creates a simulator with the gas costs and the fee parameters of the chain.
+    pub const fn new(gas_costs: &'a GasCosts, fee: &'a FeeParameters) -> Self {
+        Self { gas_costs, fee }
+    }
+
+    /// Returns the fees of the `tx` for every gas price, in the same order.
+    pub fn simulate<Tx>(&self, tx: &Tx, gas_prices: &[Word]) -> Vec<FeeScenario>
+    where
+        Tx: Chargeable,
+    {
+        let min_gas = tx.min_gas(self.gas_costs, self.fee);
+        let max_gas = tx.max_gas(self.gas_costs, self.fee);
+        let unused_witness_gas = tx
+            .witness_limit()
+            .saturating_sub(tx.witnesses().size_dynamic() as u64)
+            .saturating_mul(self.fee.gas_per_byte());
+        // The gas reserved by the witness limit is never used by the execution.
+        let max_execution_gas = max_gas
+            .saturating_sub(min_gas)
+            .saturating_sub(unused_witness_gas);
+
+        gas_prices
+            .iter()
+            .map(|&gas_price| {
+                let refund = |used_gas| {
+                    tx.fee_breakdown(self.gas_costs, self.fee, used_gas, gas_price)
+                        .map(|breakdown| breakdown.refund)
+                };
+
+                FeeScenario {
+                    gas_price,
+                    fee: TransactionFee::checked_from_tx(
+                        self.gas_costs,
+                        self.fee,
+                        tx,
+                        gas_price,
+                    ),
+                    max_fee_limit: tx.max_fee_limit(),
+                    min_refund: refund(max_execution_gas),
+                    max_refund: refund(0),
+                }
+            })
+            .collect()
+    }
+}
+
 /// Means that the blockchain charges fee for the transaction.
 pub trait Chargeable: field::Inputs + field::Witnesses + field::Policies {
     /// Returns the minimum gas required to start transaction execution.
@@ -201,9 +362,30 @@ pub trait Chargeable: field::Inputs + field::Witnesses + field::Policies {
         used_gas: Word,
         gas_price: Word,
     ) -> Option<Word> {
+        self.fee_breakdown(gas_costs, fee, used_gas, gas_price)
+            .map(|breakdown| breakdown.refund)
+    }
+
+    /// Returns the fee accounting of the transaction based on the `used_gas` and
+    /// current state of the transaction. See [`FeeBreakdown`].
+    ///
+    /// Return `None` if overflow occurs.
+    fn fee_breakdown(
+        &self,
+        gas_costs: &GasCosts,
+        fee: &FeeParameters,
+        used_gas: Word,
+        gas_price: Word,
+    ) -> Option<FeeBreakdown> {
         // We've already charged the user for witnesses as part of the minimal gas and all
         // execution required to validate transaction validity rules.
         let min_gas = self.min_gas(gas_costs, fee);
+        let witnesses_size = self.witnesses().size_dynamic() as u64;
+        let witness_gas = fee.gas_per_byte().saturating_mul(witnesses_size);
+        let unused_witness_gas = self
+            .witness_limit()
+            .saturating_sub(witnesses_size)
+            .saturating_mul(fee.gas_per_byte());
 
         let total_used_gas = min_gas.saturating_add(used_gas);
         let tip = self.policies().get(PolicyType::Tip).unwrap_or(0);
@@ -214,7 +396,21 @@ pub trait Chargeable: field::Inputs + field::Witnesses + field::Policies {
         // of `refund`. But here, because we need to return the amount we
         // want to refund, we need to handle the overflow caused by the price.
         let used_fee: u64 = used_fee.try_into().ok()?;
-        self.max_fee_limit().checked_sub(used_fee)
+        let max_fee_limit = self.max_fee_limit();
+        let refund = max_fee_limit.checked_sub(used_fee)?;
+
+        Some(FeeBreakdown {
+            min_gas,
+            witness_gas,
+            unused_witness_gas,
+            execution_gas: used_gas,
+            gas_price,
+            gas_price_factor: fee.gas_price_factor(),
+            tip,
+            used_fee,
+            max_fee_limit,
+            refund,
+        })
     }
 
     /// Used for accounting purposes when charging byte based fees.
@@ -281,3 +477,116 @@ pub trait Chargeable: field::Inputs + field::Witnesses + field::Policies {
     /// Used for accounting purposes when charging for metadata creation.
     fn gas_used_by_metadata(&self, gas_costs: &GasCosts) -> Word;
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        Finalizable,
+        TransactionBuilder,
+    };
+    use alloc::vec;
+    use fuel_crypto::SecretKey;
+    use fuel_types::AssetId;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    #[test]
+    fn estimated_fees_grow_with_the_inputs() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let fee = &FeeParameters::default()
+            .with_gas_price_factor(1)
+            .with_gas_per_byte(1);
+        let gas_costs = &GasCosts::default();
+        let gas_price = 10;
+
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.script_gas_limit(1_000).witness_limit(100);
+        let tx = builder.finalize_without_signature();
+        let expected = TransactionFee::checked_from_tx(gas_costs, fee, &tx, gas_price)
+            .expect("the fee doesn't overflow");
+
+        assert_eq!(
+            min_fee(&tx, fee, gas_costs, gas_price),
+            Some(expected.min_fee())
+        );
+        assert_eq!(
+            estimate_max_fee(&tx, fee, gas_costs, gas_price),
+            Some(expected.max_fee())
+        );
+
+        builder.add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            100,
+            AssetId::BASE,
+            Default::default(),
+        );
+        let with_input = builder.finalize_without_signature();
+
+        assert!(
+            min_fee(&with_input, fee, gas_costs, gas_price)
+                > min_fee(&tx, fee, gas_costs, gas_price)
+        );
+        assert_eq!(estimate_max_fee(&tx, fee, gas_costs, Word::MAX), None);
+    }
+
+    #[test]
+    fn fee_simulator__matches_the_charged_fees() {
+        let fee = &FeeParameters::default()
+            .with_gas_price_factor(1)
+            .with_gas_per_byte(1);
+        let gas_costs = &GasCosts::default();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1_000)
+            .witness_limit(100)
+            .max_fee_limit(50_000)
+            .finalize_without_signature();
+        let gas_prices = [0, 1, 10];
+
+        let scenarios = FeeSimulator::new(gas_costs, fee).simulate(&tx, &gas_prices);
+
+        assert_eq!(scenarios.len(), gas_prices.len());
+        for (scenario, gas_price) in scenarios.into_iter().zip(gas_prices) {
+            assert_eq!(scenario.gas_price, gas_price);
+            assert_eq!(
+                scenario.fee,
+                TransactionFee::checked_from_tx(gas_costs, fee, &tx, gas_price)
+            );
+            assert_eq!(
+                scenario.max_refund,
+                tx.refund_fee(gas_costs, fee, 0, gas_price)
+            );
+            assert_eq!(
+                scenario.min_refund,
+                tx.refund_fee(gas_costs, fee, 1_000, gas_price)
+            );
+        }
+    }
+
+    #[test]
+    fn fee_simulator__reports_uncovered_gas_prices() {
+        let fee = &FeeParameters::default()
+            .with_gas_price_factor(1)
+            .with_gas_per_byte(1);
+        let gas_costs = &GasCosts::default();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1_000)
+            .max_fee_limit(5_000)
+            .finalize_without_signature();
+
+        let scenarios =
+            FeeSimulator::new(gas_costs, fee).simulate(&tx, &[1, 1_000, Word::MAX]);
+
+        assert!(scenarios[0].is_covered());
+        assert!(scenarios[0].min_refund <= scenarios[0].max_refund);
+        assert!(!scenarios[1].is_covered());
+        assert_eq!(scenarios[1].max_refund, None);
+        assert_eq!(scenarios[2].fee, None);
+        assert!(!scenarios[2].is_covered());
+    }
+}
@@ -13,8 +13,8 @@ use crate::{
 use fuel_crypto::{
     Message,
     PublicKey,
+    RecoverableSignature,
     SecretKey,
-    Signature,
 };
 use fuel_types::{
     Bytes32,
@@ -84,7 +84,7 @@ where
 
         let message = Message::from_bytes_ref(&id);
 
-        let signature = Signature::sign(secret, message);
+        let signature = RecoverableSignature::sign(secret, message);
 
         let inputs = self.inputs();
 
@@ -113,7 +113,7 @@ where
 
         for w in witness_indexes {
             if let Some(w) = self.witnesses_mut().get_mut(w) {
-                *w = signature.as_ref().into();
+                *w = signature.into();
             }
         }
     }
@@ -0,0 +1,362 @@
+//! Description of the fields of a transaction that aren't committed to by its id.
+
+use crate::{
+    field,
+    Input,
+    Output,
+    Transaction,
+};
+use alloc::vec::Vec;
+
+/// A field of an input that isn't committed to by the transaction id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MalleableInputField {
+    /// The UTXO id of the contract, set by the block producer.
+    UtxoId,
+    /// The balance root of the contract, set by the block producer.
+    BalanceRoot,
+    /// The state root of the contract, set by the block producer.
+    StateRoot,
+    /// The pointer to the transaction that created the coin or the contract.
+    TxPointer,
+    /// The gas used by the predicate, which can be estimated after the signing.
+    PredicateGasUsed,
+}
+
+/// A field of an output that isn't committed to by the transaction id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MalleableOutputField {
+    /// The recipient of the variable output, set during the execution.
+    To,
+    /// The amount of the change or variable output, set during the execution.
+    Amount,
+    /// The asset of the variable output, set during the execution.
+    AssetId,
+    /// The balance root of the contract, set during the execution.
+    BalanceRoot,
+    /// The state root of the contract, set during the execution.
+    StateRoot,
+}
+
+/// A part of the transaction that isn't committed to by its id, and therefore by the
+/// signatures of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MalleableField {
+    /// All of the witnesses, as they hold the signatures.
+    Witnesses,
+    /// The receipts root of the script, set during the execution.
+    ReceiptsRoot,
+    /// A field of the input at the index.
+    Input {
+        index: usize,
+        field: MalleableInputField,
+    },
+    /// A field of the output at the index.
+    Output {
+        index: usize,
+        field: MalleableOutputField,
+    },
+    /// A field of the input contract of the mint transaction.
+    InputContract(MalleableInputField),
+    /// A field of the output contract of the mint transaction.
+    OutputContract(MalleableOutputField),
+}
+
+const CONTRACT_INPUT_FIELDS: [MalleableInputField; 4] = [
+    MalleableInputField::UtxoId,
+    MalleableInputField::BalanceRoot,
+    MalleableInputField::StateRoot,
+    MalleableInputField::TxPointer,
+];
+
+const CONTRACT_OUTPUT_FIELDS: [MalleableOutputField; 2] = [
+    MalleableOutputField::BalanceRoot,
+    MalleableOutputField::StateRoot,
+];
+
+fn malleable_input_fields(input: &Input) -> &'static [MalleableInputField] {
+    match input {
+        Input::CoinSigned(_) => &[MalleableInputField::TxPointer],
+        Input::CoinPredicate(_) => &[
+            MalleableInputField::TxPointer,
+            MalleableInputField::PredicateGasUsed,
+        ],
+        Input::Contract(_) => &CONTRACT_INPUT_FIELDS,
+        Input::MessageCoinSigned(_) | Input::MessageDataSigned(_) => &[],
+        Input::MessageCoinPredicate(_) | Input::MessageDataPredicate(_) => {
+            &[MalleableInputField::PredicateGasUsed]
+        }
+    }
+}
+
+fn malleable_output_fields(output: &Output) -> &'static [MalleableOutputField] {
+    match output {
+        Output::Coin { .. } | Output::ContractCreated { .. } => &[],
+        Output::Contract(_) => &CONTRACT_OUTPUT_FIELDS,
+        Output::Change { .. } => &[MalleableOutputField::Amount],
+        Output::Variable { .. } => &[
+            MalleableOutputField::To,
+            MalleableOutputField::Amount,
+            MalleableOutputField::AssetId,
+        ],
+    }
+}
+
+fn chargeable_malleable_fields<Tx>(tx: &Tx) -> Vec<MalleableField>
+where
+    Tx: field::Inputs + field::Outputs,
+{
+    let inputs = tx.inputs().iter().enumerate().flat_map(|(index, input)| {
+        malleable_input_fields(input)
+            .iter()
+            .map(move |field| MalleableField::Input {
+                index,
+                field: *field,
+            })
+    });
+    let outputs = tx.outputs().iter().enumerate().flat_map(|(index, output)| {
+        malleable_output_fields(output)
+            .iter()
+            .map(move |field| MalleableField::Output {
+                index,
+                field: *field,
+            })
+    });
+
+    inputs
+        .chain(outputs)
+        .chain(core::iter::once(MalleableField::Witnesses))
+        .collect()
+}
+
+impl Transaction {
+    /// Describes the fields that aren't committed to by the transaction id, so a
+    /// signature of the transaction doesn't cover them. The fields are listed in the
+    /// order of their appearance in the transaction.
+    pub fn malleable_fields(&self) -> Vec<MalleableField> {
+        match self {
+            Self::Script(tx) => {
+                let mut fields = chargeable_malleable_fields(tx);
+                fields.insert(0, MalleableField::ReceiptsRoot);
+                fields
+            }
+            Self::Create(tx) => chargeable_malleable_fields(tx),
+            Self::Mint(_) => CONTRACT_INPUT_FIELDS
+                .iter()
+                .copied()
+                .map(MalleableField::InputContract)
+                .chain(
+                    CONTRACT_OUTPUT_FIELDS
+                        .iter()
+                        .copied()
+                        .map(MalleableField::OutputContract),
+                )
+                .collect(),
+            Self::Upgrade(tx) => chargeable_malleable_fields(tx),
+            Self::Upload(tx) => chargeable_malleable_fields(tx),
+            Self::Blob(tx) => chargeable_malleable_fields(tx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        field::{
+            Inputs,
+            Outputs,
+            ReceiptsRoot,
+            Witnesses,
+        },
+        Cacheable,
+        Finalizable,
+        Script,
+        TransactionBuilder,
+        TxPointer,
+        UniqueIdentifier,
+    };
+    use alloc::vec;
+    use fuel_types::{
+        AssetId,
+        ChainId,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn script(rng: &mut StdRng) -> Script {
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_signed(
+                rng.gen(),
+                rng.gen(),
+                100,
+                rng.gen(),
+                rng.gen(),
+                0,
+            ))
+            .add_input(Input::coin_predicate(
+                rng.gen(),
+                rng.gen(),
+                100,
+                rng.gen(),
+                rng.gen(),
+                7,
+                vec![1],
+                vec![],
+            ))
+            .add_input(Input::contract(
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+            ))
+            .add_input(Input::message_data_predicate(
+                rng.gen(),
+                rng.gen(),
+                100,
+                rng.gen(),
+                7,
+                vec![2],
+                vec![1],
+                vec![],
+            ))
+            .add_output(Output::coin(rng.gen(), 10, rng.gen()))
+            .add_output(Output::contract(2, rng.gen(), rng.gen()))
+            .add_output(Output::change(rng.gen(), 10, rng.gen()))
+            .add_output(Output::variable(rng.gen(), 10, rng.gen()))
+            .add_witness(vec![1, 2, 3].into())
+            .finalize_without_signature()
+    }
+
+    fn tamper_input(input: &mut Input, field: MalleableInputField, rng: &mut StdRng) {
+        match (input, field) {
+            (Input::Contract(contract), MalleableInputField::UtxoId) => {
+                contract.utxo_id = rng.gen()
+            }
+            (Input::Contract(contract), MalleableInputField::BalanceRoot) => {
+                contract.balance_root = rng.gen()
+            }
+            (Input::Contract(contract), MalleableInputField::StateRoot) => {
+                contract.state_root = rng.gen()
+            }
+            (Input::Contract(contract), MalleableInputField::TxPointer) => {
+                contract.tx_pointer = rng.gen()
+            }
+            (Input::CoinSigned(coin), MalleableInputField::TxPointer) => {
+                coin.tx_pointer = rng.gen()
+            }
+            (Input::CoinPredicate(coin), MalleableInputField::TxPointer) => {
+                coin.tx_pointer = rng.gen()
+            }
+            (Input::CoinPredicate(coin), MalleableInputField::PredicateGasUsed) => {
+                coin.predicate_gas_used = rng.gen()
+            }
+            (
+                Input::MessageDataPredicate(message),
+                MalleableInputField::PredicateGasUsed,
+            ) => message.predicate_gas_used = rng.gen(),
+            (input, field) => panic!("unexpected field {field:?} of {input:?}"),
+        }
+    }
+
+    fn tamper_output(output: &mut Output, field: MalleableOutputField, rng: &mut StdRng) {
+        match (output, field) {
+            (Output::Contract(contract), MalleableOutputField::BalanceRoot) => {
+                contract.balance_root = rng.gen()
+            }
+            (Output::Contract(contract), MalleableOutputField::StateRoot) => {
+                contract.state_root = rng.gen()
+            }
+            (
+                Output::Change { amount, .. } | Output::Variable { amount, .. },
+                MalleableOutputField::Amount,
+            ) => *amount = rng.gen(),
+            (Output::Variable { to, .. }, MalleableOutputField::To) => *to = rng.gen(),
+            (Output::Variable { asset_id, .. }, MalleableOutputField::AssetId) => {
+                *asset_id = rng.gen()
+            }
+            (output, field) => panic!("unexpected field {field:?} of {output:?}"),
+        }
+    }
+
+    #[test]
+    fn malleable_fields_of_script_are_excluded_from_the_id() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let chain_id = ChainId::default();
+        let mut tx = script(rng);
+        let id = tx.id(&chain_id);
+
+        let fields = Transaction::from(tx.clone()).malleable_fields();
+        assert_eq!(fields.len(), 16);
+        assert_eq!(fields.first(), Some(&MalleableField::ReceiptsRoot));
+        assert_eq!(fields.last(), Some(&MalleableField::Witnesses));
+
+        for field in fields {
+            match field {
+                MalleableField::Witnesses => {
+                    tx.witnesses_mut().push(vec![4].into());
+                }
+                MalleableField::ReceiptsRoot => *tx.receipts_root_mut() = rng.gen(),
+                MalleableField::Input { index, field } => {
+                    tamper_input(&mut tx.inputs_mut()[index], field, rng)
+                }
+                MalleableField::Output { index, field } => {
+                    tamper_output(&mut tx.outputs_mut()[index], field, rng)
+                }
+                field => panic!("unexpected field {field:?}"),
+            }
+        }
+
+        tx.precompute(&chain_id)
+            .expect("failed to compute the metadata");
+        assert_eq!(tx.id(&chain_id), id);
+    }
+
+    #[test]
+    fn committed_fields_are_not_reported() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let chain_id = ChainId::default();
+        let mut tx = script(rng);
+        let id = tx.id(&chain_id);
+
+        let fields = Transaction::from(tx.clone()).malleable_fields();
+        assert!(!fields.contains(&MalleableField::Output {
+            index: 0,
+            field: MalleableOutputField::Amount,
+        }));
+
+        if let Output::Coin { amount, .. } = &mut tx.outputs_mut()[0] {
+            *amount = amount.saturating_add(1);
+        }
+        tx.precompute(&chain_id)
+            .expect("failed to compute the metadata");
+        assert_ne!(tx.id(&chain_id), id);
+    }
+
+    #[test]
+    fn mint_reports_its_contracts() {
+        let mint = Transaction::mint(
+            TxPointer::default(),
+            Default::default(),
+            Default::default(),
+            0,
+            AssetId::BASE,
+            0,
+        );
+
+        let fields = Transaction::from(mint).malleable_fields();
+
+        assert_eq!(fields.len(), 6);
+        assert!(fields.contains(&MalleableField::InputContract(
+            MalleableInputField::TxPointer
+        )));
+        assert!(!fields.contains(&MalleableField::Witnesses));
+    }
+}
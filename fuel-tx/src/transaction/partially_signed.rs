@@ -0,0 +1,390 @@
+//! Transactions whose signed inputs are signed by several parties.
+
+use crate::{
+    field,
+    input::{
+        coin::CoinSigned,
+        message::{
+            MessageCoinSigned,
+            MessageDataSigned,
+        },
+    },
+    Input,
+    UniqueIdentifier,
+};
+use alloc::vec::Vec;
+use fuel_crypto::{
+    Message,
+    RecoverableSignature,
+    SecretKey,
+};
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
+    Address,
+    Bytes32,
+    ChainId,
+};
+
+/// A signature required by the signed inputs of a [`PartiallySignedTransaction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RequiredSignature {
+    /// Index of the witness holding the signature.
+    pub witness_index: u16,
+    /// Owner of the inputs, who must sign the transaction id.
+    pub owner: Address,
+}
+
+/// An error produced while collecting the signatures of a
+/// [`PartiallySignedTransaction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartialSigningError {
+    /// The transactions differ in anything else than their signatures.
+    TransactionMismatch,
+    /// The signer doesn't own any of the signed inputs.
+    UnexpectedSigner,
+    /// Some of the required signatures are missing.
+    MissingSignatures,
+    /// The bytes aren't a valid encoding of a [`PartiallySignedTransaction`].
+    InvalidEncoding,
+}
+
+/// A transaction with the signatures collected so far from the owners of its signed
+/// inputs.
+///
+/// Each party adds its signature, or the partially signed transactions of several
+/// parties are merged, until no signature is missing. Inputs of the same owner share
+/// the witness, and a witness that doesn't hold a valid signature of the owner is
+/// considered missing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartiallySignedTransaction<Tx> {
+    chain_id: ChainId,
+    transaction: Tx,
+}
+
+impl<Tx> PartiallySignedTransaction<Tx>
+where
+    Tx: UniqueIdentifier + field::Inputs + field::Witnesses,
+{
+    /// Version of the encoding produced by [`Self::to_bytes`].
+    pub const ENCODING_VERSION: u8 = 0;
+
+    /// Starts collecting the signatures of the transaction for the chain.
+    pub fn new(transaction: Tx, chain_id: ChainId) -> Self {
+        Self {
+            chain_id,
+            transaction,
+        }
+    }
+
+    /// The transaction, including the signatures collected so far.
+    pub fn transaction(&self) -> &Tx {
+        &self.transaction
+    }
+
+    /// The chain the transaction is signed for.
+    pub fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    /// The transaction id signed by the parties.
+    pub fn id(&self) -> Bytes32 {
+        self.transaction.id(&self.chain_id)
+    }
+
+    /// The signatures required by the signed inputs, ordered by the witness index.
+    pub fn required_signatures(&self) -> Vec<RequiredSignature> {
+        let mut required: Vec<_> = self
+            .transaction
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned(CoinSigned {
+                    owner,
+                    witness_index,
+                    ..
+                })
+                | Input::MessageCoinSigned(MessageCoinSigned {
+                    recipient: owner,
+                    witness_index,
+                    ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned {
+                    recipient: owner,
+                    witness_index,
+                    ..
+                }) => Some(RequiredSignature {
+                    witness_index: *witness_index,
+                    owner: *owner,
+                }),
+                _ => None,
+            })
+            .collect();
+        required.sort_unstable_by_key(|required| required.witness_index);
+        required.dedup();
+        required
+    }
+
+    /// The required signatures that weren't collected yet.
+    pub fn missing_signatures(&self) -> Vec<RequiredSignature> {
+        let id = self.id();
+        self.required_signatures()
+            .into_iter()
+            .filter(|required| !self.is_signed(required, &id))
+            .collect()
+    }
+
+    /// Returns `true` if all of the required signatures were collected.
+    pub fn is_complete(&self) -> bool {
+        self.missing_signatures().is_empty()
+    }
+
+    /// Signs the inputs owned by the signer.
+    pub fn sign(&mut self, secret: &SecretKey) -> Result<(), PartialSigningError> {
+        let signature =
+            RecoverableSignature::sign(secret, Message::from_bytes_ref(&self.id()));
+        self.add_signature(signature)
+    }
+
+    /// Adds the signature of the transaction id to the witnesses of the inputs owned by
+    /// the signer.
+    pub fn add_signature(
+        &mut self,
+        signature: RecoverableSignature,
+    ) -> Result<(), PartialSigningError> {
+        let signer = signature
+            .recover(Message::from_bytes_ref(&self.id()))
+            .map(|public_key| Input::owner(&public_key))
+            .map_err(|_| PartialSigningError::UnexpectedSigner)?;
+
+        let mut signed = false;
+        for required in self.required_signatures() {
+            if required.owner != signer {
+                continue
+            }
+            if let Some(witness) = self
+                .transaction
+                .witnesses_mut()
+                .get_mut(required.witness_index as usize)
+            {
+                *witness = signature.into();
+                signed = true;
+            }
+        }
+
+        if signed {
+            Ok(())
+        } else {
+            Err(PartialSigningError::UnexpectedSigner)
+        }
+    }
+
+    /// Adds the signatures collected by `other` for the same transaction.
+    pub fn merge(&mut self, other: &Self) -> Result<(), PartialSigningError> {
+        let id = self.id();
+        if self.chain_id != other.chain_id
+            || id != other.id()
+            || self.transaction.witnesses().len() != other.transaction.witnesses().len()
+        {
+            return Err(PartialSigningError::TransactionMismatch)
+        }
+
+        for required in self.missing_signatures() {
+            if other.is_signed(&required, &id) {
+                let index = required.witness_index as usize;
+                self.transaction.witnesses_mut()[index] =
+                    other.transaction.witnesses()[index].clone();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the transaction once all of the required signatures were collected.
+    pub fn finalize(self) -> Result<Tx, PartialSigningError> {
+        if self.is_complete() {
+            Ok(self.transaction)
+        } else {
+            Err(PartialSigningError::MissingSignatures)
+        }
+    }
+
+    fn is_signed(&self, required: &RequiredSignature, id: &Bytes32) -> bool {
+        self.transaction
+            .witnesses()
+            .get(required.witness_index as usize)
+            .and_then(|witness| witness.recover_witness(id, 0).ok())
+            == Some(required.owner)
+    }
+}
+
+impl<Tx> PartiallySignedTransaction<Tx>
+where
+    Tx: UniqueIdentifier + field::Inputs + field::Witnesses + Serialize + Deserialize,
+{
+    /// Encodes the chain id and the transaction for the exchange between the parties.
+    ///
+    /// The encoding is the [`Self::ENCODING_VERSION`] byte, followed by the canonical
+    /// encoding of the chain id and of the transaction.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::from([Self::ENCODING_VERSION]);
+        bytes.extend(self.chain_id.to_bytes());
+        bytes.extend(self.transaction.to_bytes());
+        bytes
+    }
+
+    /// Decodes the bytes produced by [`Self::to_bytes`].
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, PartialSigningError> {
+        let (&version, rest) = bytes
+            .split_first()
+            .ok_or(PartialSigningError::InvalidEncoding)?;
+        if version != Self::ENCODING_VERSION {
+            return Err(PartialSigningError::InvalidEncoding)
+        }
+        bytes = rest;
+
+        let chain_id = ChainId::decode(&mut bytes)
+            .map_err(|_| PartialSigningError::InvalidEncoding)?;
+        let transaction =
+            Tx::decode(&mut bytes).map_err(|_| PartialSigningError::InvalidEncoding)?;
+        if !bytes.is_empty() {
+            return Err(PartialSigningError::InvalidEncoding)
+        }
+
+        Ok(Self::new(transaction, chain_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Finalizable,
+        FormatValidityChecks,
+        Script,
+        TransactionBuilder,
+    };
+    use alloc::vec;
+    use fuel_types::AssetId;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn unsigned_transaction(
+        rng: &mut StdRng,
+        signers: &[SecretKey],
+    ) -> PartiallySignedTransaction<Script> {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.max_fee_limit(0);
+        for secret in signers.iter().chain(signers) {
+            builder.add_unsigned_coin_input(
+                secret.clone(),
+                rng.gen(),
+                1_000,
+                AssetId::BASE,
+                Default::default(),
+            );
+        }
+        PartiallySignedTransaction::new(
+            builder.finalize_without_signature(),
+            ChainId::default(),
+        )
+    }
+
+    #[test]
+    fn signatures_of_all_parties_complete_the_transaction() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let alice = SecretKey::random(rng);
+        let bob = SecretKey::random(rng);
+        let mut tx = unsigned_transaction(rng, &[alice.clone(), bob.clone()]);
+
+        assert_eq!(tx.required_signatures().len(), 2);
+        assert_eq!(tx.missing_signatures(), tx.required_signatures());
+
+        tx.sign(&alice).unwrap();
+        assert_eq!(
+            tx.missing_signatures(),
+            vec![RequiredSignature {
+                witness_index: 1,
+                owner: Input::owner(&bob.public_key()),
+            }]
+        );
+        assert_eq!(
+            tx.clone().finalize(),
+            Err(PartialSigningError::MissingSignatures)
+        );
+
+        let signature =
+            RecoverableSignature::sign(&bob, Message::from_bytes_ref(&tx.id()));
+        tx.add_signature(signature).unwrap();
+        let tx = tx.finalize().expect("all signatures are collected");
+        assert_eq!(tx.check_signatures(&ChainId::default()), Ok(()));
+    }
+
+    #[test]
+    fn merge_collects_signatures_of_other_parties() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let alice = SecretKey::random(rng);
+        let bob = SecretKey::random(rng);
+        let unsigned = unsigned_transaction(rng, &[alice.clone(), bob.clone()]);
+
+        let mut signed_by_alice = unsigned.clone();
+        signed_by_alice.sign(&alice).unwrap();
+        let mut signed_by_bob = unsigned.clone();
+        signed_by_bob.sign(&bob).unwrap();
+
+        signed_by_alice.merge(&signed_by_bob).unwrap();
+
+        assert!(signed_by_alice.is_complete());
+        let mut other_chain =
+            PartiallySignedTransaction::new(unsigned.transaction().clone(), 1.into());
+        assert_eq!(
+            other_chain.merge(&unsigned),
+            Err(PartialSigningError::TransactionMismatch)
+        );
+    }
+
+    #[test]
+    fn unexpected_signers_are_rejected() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let alice = SecretKey::random(rng);
+        let mut tx = unsigned_transaction(rng, &[alice]);
+
+        assert_eq!(
+            tx.sign(&SecretKey::random(rng)),
+            Err(PartialSigningError::UnexpectedSigner)
+        );
+        assert_eq!(tx.missing_signatures().len(), 1);
+    }
+
+    #[test]
+    fn encoding_roundtrips() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let alice = SecretKey::random(rng);
+        let bob = SecretKey::random(rng);
+        let mut tx = unsigned_transaction(rng, &[alice.clone(), bob]);
+        tx.sign(&alice).unwrap();
+
+        let bytes = tx.to_bytes();
+        let decoded = PartiallySignedTransaction::<Script>::from_bytes(&bytes);
+
+        assert_eq!(decoded.as_ref(), Ok(&tx));
+        assert_eq!(
+            PartiallySignedTransaction::<Script>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(PartialSigningError::InvalidEncoding)
+        );
+        let mut unknown_version = bytes;
+        unknown_version[0] = 1;
+        assert_eq!(
+            PartiallySignedTransaction::<Script>::from_bytes(&unknown_version),
+            Err(PartialSigningError::InvalidEncoding)
+        );
+    }
+}
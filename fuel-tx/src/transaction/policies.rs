@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::ops::Deref;
 use fuel_types::{
     canonical::{
@@ -99,6 +100,43 @@ impl PolicyType {
             PolicyType::MaxFee => PoliciesBits::MaxFee,
         }
     }
+
+    /// The name of the policy in the specification.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            PolicyType::Tip => "tip",
+            PolicyType::WitnessLimit => "witness_limit",
+            PolicyType::Maturity => "maturity",
+            PolicyType::MaxFee => "max_fee",
+        }
+    }
+
+    /// Returns `true` if the chargeable transactions must set the policy.
+    pub const fn is_required(&self) -> bool {
+        matches!(self, PolicyType::MaxFee)
+    }
+
+    /// The consensus rule the policy takes part in.
+    pub const fn rule(&self) -> &'static str {
+        match self {
+            PolicyType::Tip => {
+                "Paid to the block producer on top of the gas fee, and counted in the \
+                 max fee of the transaction"
+            }
+            PolicyType::WitnessLimit => {
+                "Limits the size of the witnesses, `TransactionWitnessLimitExceeded`, \
+                 and is charged instead of their size in the max gas"
+            }
+            PolicyType::Maturity => {
+                "The transaction is only valid in blocks at or above the height, \
+                 `TransactionMaturity`"
+            }
+            PolicyType::MaxFee => {
+                "Required, `TransactionMaxFeeNotSet`, and must cover the max fee of \
+                 the transaction, `InsufficientMaxFee`"
+            }
+        }
+    }
 }
 
 /// The total number of policies.
@@ -167,6 +205,56 @@ impl Policies {
         self
     }
 
+    /// Returns the `tip` policy, if set.
+    pub fn tip(&self) -> Option<Word> {
+        self.get(PolicyType::Tip)
+    }
+
+    /// Returns the `witness_limit` policy, if set.
+    pub fn witness_limit(&self) -> Option<Word> {
+        self.get(PolicyType::WitnessLimit)
+    }
+
+    /// Returns the `maturity` policy, if set and fits into the block height.
+    pub fn maturity(&self) -> Option<BlockHeight> {
+        let maturity = self.get(PolicyType::Maturity)?;
+        u32::try_from(maturity).ok().map(Into::into)
+    }
+
+    /// Returns the `max_fee` policy, if set.
+    pub fn max_fee(&self) -> Option<Word> {
+        self.get(PolicyType::MaxFee)
+    }
+
+    /// Iterates over the set policies with their values.
+    pub fn iter(&self) -> impl Iterator<Item = (PolicyType, Word)> + '_ {
+        use strum::IntoEnumIterator;
+        PolicyType::iter()
+            .filter_map(|policy_type| Some((policy_type, self.get(policy_type)?)))
+    }
+
+    /// Describes the set policies, the consensus rules they take part in, and the
+    /// required policies that aren't set.
+    pub fn report(&self) -> PoliciesReport {
+        use strum::IntoEnumIterator;
+        PoliciesReport {
+            set: self
+                .iter()
+                .map(|(policy_type, value)| PolicyReportEntry {
+                    policy_type,
+                    value,
+                    rule: policy_type.rule(),
+                })
+                .collect(),
+            missing: PolicyType::iter()
+                .filter(|policy_type| {
+                    policy_type.is_required() && !self.is_set(*policy_type)
+                })
+                .collect(),
+            is_valid: self.is_valid(),
+        }
+    }
+
     /// Returns a policy's value if the corresponding bit is set.
     pub fn get(&self, policy_type: PolicyType) -> Option<Word> {
         if self.bits.contains(policy_type.bit()) {
@@ -234,6 +322,35 @@ impl Policies {
     }
 }
 
+/// A set policy of a [`PoliciesReport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PolicyReportEntry {
+    /// The type of the policy.
+    pub policy_type: PolicyType,
+    /// The value of the policy.
+    pub value: Word,
+    /// The consensus rule the policy takes part in.
+    pub rule: &'static str,
+}
+
+/// The summary of the [`Policies`], returned by [`Policies::report`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PoliciesReport {
+    /// The set policies, in the order of the policy types.
+    pub set: Vec<PolicyReportEntry>,
+    /// The required policies that aren't set.
+    pub missing: Vec<PolicyType>,
+    /// `true` if the policies follow the encoding rules of the specification.
+    pub is_valid: bool,
+}
+
+impl PoliciesReport {
+    /// Returns `true` if the policies are valid, and all required policies are set.
+    pub fn is_ok(&self) -> bool {
+        self.is_valid && self.missing.is_empty()
+    }
+}
+
 impl Serialize for Policies {
     fn size_static(&self) -> usize {
         self.bits.bits().size_static()
@@ -412,3 +529,50 @@ fn canonical_serialization_deserialization_for_any_combination_of_values_works()
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn typed_accessors_return_set_policies() {
+        let policies = Policies::new()
+            .with_tip(1)
+            .with_maturity(2u32.into())
+            .with_max_fee(3);
+
+        assert_eq!(policies.tip(), Some(1));
+        assert_eq!(policies.witness_limit(), None);
+        assert_eq!(policies.maturity(), Some(2u32.into()));
+        assert_eq!(policies.max_fee(), Some(3));
+        assert_eq!(
+            policies.iter().collect::<Vec<_>>(),
+            vec![
+                (PolicyType::Tip, 1),
+                (PolicyType::Maturity, 2),
+                (PolicyType::MaxFee, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn report_lists_missing_required_policies() {
+        let report = Policies::new().with_witness_limit(10).report();
+
+        assert_eq!(
+            report.set,
+            vec![PolicyReportEntry {
+                policy_type: PolicyType::WitnessLimit,
+                value: 10,
+                rule: PolicyType::WitnessLimit.rule(),
+            }]
+        );
+        assert_eq!(report.missing, vec![PolicyType::MaxFee]);
+        assert!(report.is_valid);
+        assert!(!report.is_ok());
+
+        let report = Policies::new().with_max_fee(0).report();
+        assert!(report.is_ok());
+    }
+}
@@ -0,0 +1,305 @@
+//! Typed views of the inputs and outputs of a transaction, returned by the summary
+//! iterators of [`Executable`](crate::Executable) and
+//! [`Transaction`](crate::Transaction).
+
+use crate::{
+    input::{
+        coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
+        message::{
+            MessageCoinPredicate,
+            MessageCoinSigned,
+            MessageDataPredicate,
+            MessageDataSigned,
+        },
+    },
+    Input,
+    Output,
+    UtxoId,
+};
+use fuel_types::{
+    Address,
+    AssetId,
+    Nonce,
+    Word,
+};
+
+/// A coin spent by an input of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoinSpent<'a> {
+    /// Index of the input.
+    pub input_index: usize,
+    pub utxo_id: &'a UtxoId,
+    pub owner: &'a Address,
+    pub amount: Word,
+    pub asset_id: &'a AssetId,
+    /// Whether the coin is unlocked by a predicate instead of a signature.
+    pub is_predicate: bool,
+}
+
+impl<'a> CoinSpent<'a> {
+    pub(crate) fn from_input(input_index: usize, input: &'a Input) -> Option<Self> {
+        let (coin, is_predicate) = match input {
+            Input::CoinSigned(CoinSigned {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                ..
+            }) => ((utxo_id, owner, amount, asset_id), false),
+            Input::CoinPredicate(CoinPredicate {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                ..
+            }) => ((utxo_id, owner, amount, asset_id), true),
+            _ => return None,
+        };
+        let (utxo_id, owner, amount, asset_id) = coin;
+
+        Some(Self {
+            input_index,
+            utxo_id,
+            owner,
+            amount: *amount,
+            asset_id,
+            is_predicate,
+        })
+    }
+}
+
+/// A message consumed by an input of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageConsumed<'a> {
+    /// Index of the input.
+    pub input_index: usize,
+    pub sender: &'a Address,
+    pub recipient: &'a Address,
+    pub amount: Word,
+    pub nonce: &'a Nonce,
+    /// The data of the message, empty for message coins.
+    pub data: &'a [u8],
+    /// Whether the message is unlocked by a predicate instead of a signature.
+    pub is_predicate: bool,
+}
+
+impl<'a> MessageConsumed<'a> {
+    pub(crate) fn from_input(input_index: usize, input: &'a Input) -> Option<Self> {
+        let (message, data, is_predicate) = match input {
+            Input::MessageCoinSigned(MessageCoinSigned {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                ..
+            }) => ((sender, recipient, amount, nonce), &[][..], false),
+            Input::MessageCoinPredicate(MessageCoinPredicate {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                ..
+            }) => ((sender, recipient, amount, nonce), &[][..], true),
+            Input::MessageDataSigned(MessageDataSigned {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                data,
+                ..
+            }) => ((sender, recipient, amount, nonce), data.as_slice(), false),
+            Input::MessageDataPredicate(MessageDataPredicate {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                data,
+                ..
+            }) => ((sender, recipient, amount, nonce), data.as_slice(), true),
+            _ => return None,
+        };
+        let (sender, recipient, amount, nonce) = message;
+
+        Some(Self {
+            input_index,
+            sender,
+            recipient,
+            amount: *amount,
+            nonce,
+            data,
+            is_predicate,
+        })
+    }
+}
+
+/// A coin, change or variable output of the transaction sent to an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutputTo<'a> {
+    /// Index of the output.
+    pub output_index: usize,
+    pub to: &'a Address,
+    pub amount: Word,
+    pub asset_id: &'a AssetId,
+    /// The output itself, to distinguish between its kinds.
+    pub output: &'a Output,
+}
+
+impl<'a> OutputTo<'a> {
+    pub(crate) fn from_output(output_index: usize, output: &'a Output) -> Option<Self> {
+        match output {
+            Output::Coin {
+                to,
+                amount,
+                asset_id,
+            }
+            | Output::Change {
+                to,
+                amount,
+                asset_id,
+            }
+            | Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } => Some(Self {
+                output_index,
+                to,
+                amount: *amount,
+                asset_id,
+                output,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Executable,
+        Finalizable,
+        Transaction,
+        TransactionBuilder,
+        TxPointer,
+    };
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use fuel_types::{
+        Bytes32,
+        ContractId,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    #[test]
+    fn summaries_select_the_matching_inputs_and_outputs() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let owner: Address = rng.gen();
+        let utxo_id: UtxoId = rng.gen();
+        let asset_id: AssetId = rng.gen();
+        let contract_id: ContractId = rng.gen();
+        let sender: Address = rng.gen();
+        let nonce: Nonce = rng.gen();
+
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_predicate(
+                utxo_id,
+                owner,
+                100,
+                asset_id,
+                TxPointer::default(),
+                0,
+                vec![1],
+                vec![],
+            ))
+            .add_input(Input::contract(
+                rng.gen(),
+                Bytes32::zeroed(),
+                Bytes32::zeroed(),
+                TxPointer::default(),
+                contract_id,
+            ))
+            .add_input(Input::message_data_signed(
+                sender,
+                owner,
+                200,
+                nonce,
+                0,
+                vec![7, 8],
+            ))
+            .add_output(Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed()))
+            .add_output(Output::coin(rng.gen(), 10, asset_id))
+            .add_output(Output::change(owner, 0, asset_id))
+            .finalize_without_signature();
+
+        assert_eq!(
+            tx.coins_spent().collect::<Vec<_>>(),
+            [CoinSpent {
+                input_index: 0,
+                utxo_id: &utxo_id,
+                owner: &owner,
+                amount: 100,
+                asset_id: &asset_id,
+                is_predicate: true,
+            }]
+        );
+        assert_eq!(
+            tx.messages_consumed().collect::<Vec<_>>(),
+            [MessageConsumed {
+                input_index: 2,
+                sender: &sender,
+                recipient: &owner,
+                amount: 200,
+                nonce: &nonce,
+                data: &[7, 8],
+                is_predicate: false,
+            }]
+        );
+        assert_eq!(tx.contracts_touched().collect::<Vec<_>>(), [&contract_id]);
+
+        let outputs: Vec<_> = tx.outputs_to(&owner).collect();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].output_index, 2);
+        assert!(outputs[0].output.is_change());
+
+        let tx = Transaction::from(tx);
+        assert_eq!(tx.coins_spent().count(), 1);
+        assert_eq!(tx.messages_consumed().count(), 1);
+        assert_eq!(tx.outputs_to(&owner).count(), 1);
+    }
+
+    #[test]
+    fn mint_touches_its_input_contract() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let tx = Transaction::from(
+            TransactionBuilder::script(vec![], vec![]).finalize_without_signature(),
+        );
+        assert_eq!(tx.contracts_touched().count(), 0);
+
+        let contract_id: ContractId = rng.gen();
+        let mint = Transaction::mint(
+            TxPointer::default(),
+            crate::input::contract::Contract {
+                contract_id,
+                ..Default::default()
+            },
+            crate::output::contract::Contract::default(),
+            0,
+            AssetId::BASE,
+            0,
+        );
+        let mint = Transaction::from(mint);
+
+        assert_eq!(mint.contracts_touched().collect::<Vec<_>>(), [&contract_id]);
+        assert_eq!(mint.coins_spent().count(), 0);
+    }
+}
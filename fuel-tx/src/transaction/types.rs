@@ -17,6 +17,7 @@ pub use blob::{
     BlobIdExt,
     BlobMetadata,
 };
+pub(crate) use chargeable_transaction::UniqueFormatValidityChecks;
 pub use chargeable_transaction::{
     ChargeableMetadata,
     ChargeableTransaction,
@@ -40,6 +41,7 @@ pub use upgrade::{
     UpgradePurpose,
 };
 pub use upload::{
+    SplitError,
     Upload,
     UploadBody,
     UploadMetadata,
@@ -62,6 +64,7 @@ pub fn compute_transaction_id<T: fuel_types::canonical::Serialize>(
     // chain ID
     hasher.input(chain_id.to_be_bytes());
     // transaction bytes
-    hasher.input(tx.to_bytes().as_slice());
+    tx.encode(&mut hasher)
+        .expect("Hashing the encoding is infallible");
     hasher.finalize()
 }
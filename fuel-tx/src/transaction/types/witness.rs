@@ -10,7 +10,7 @@ use crate::{
 };
 use fuel_crypto::{
     Message,
-    Signature,
+    RecoverableSignature,
 };
 
 #[cfg(feature = "random")]
@@ -49,15 +49,23 @@ impl Witness {
         self.data
     }
 
+    /// The witness as the signature of the owner of an input, or `None` if it isn't
+    /// [`RecoverableSignature::LEN`] bytes long.
+    pub fn signature(&self) -> Option<RecoverableSignature> {
+        <[u8; RecoverableSignature::LEN]>::try_from(self.as_ref())
+            .ok()
+            .map(RecoverableSignature::from_bytes)
+    }
+
     /// ECRecover an address from a witness
     pub fn recover_witness(
         &self,
         txhash: &TxId,
         input_index: usize,
     ) -> Result<fuel_types::Address, ValidityError> {
-        let bytes = <[u8; Signature::LEN]>::try_from(self.as_ref())
-            .map_err(|_| ValidityError::InputInvalidSignature { index: input_index })?;
-        let signature = Signature::from_bytes(bytes);
+        let signature = self
+            .signature()
+            .ok_or(ValidityError::InputInvalidSignature { index: input_index })?;
 
         let message = Message::from_bytes_ref(txhash);
 
@@ -74,6 +82,14 @@ impl From<Vec<u8>> for Witness {
     }
 }
 
+/// The witnesses of the signed inputs hold the signatures with the recovery id, so
+/// a [`CompactSignature`](fuel_crypto::CompactSignature) must be converted first.
+impl From<RecoverableSignature> for Witness {
+    fn from(signature: RecoverableSignature) -> Self {
+        signature.as_ref().into()
+    }
+}
+
 impl From<&[u8]> for Witness {
     fn from(data: &[u8]) -> Self {
         data.to_vec().into()
@@ -20,6 +20,7 @@ use crate::{
             TxParameters,
         },
         field,
+        types::UniqueFormatValidityChecks,
         Executable,
     },
     Chargeable,
@@ -29,6 +30,7 @@ use crate::{
     Transaction,
     Witness,
 };
+use alloc::vec::Vec;
 use core::hash::Hash;
 use fuel_types::{
     canonical,
@@ -322,6 +324,21 @@ pub(crate) fn check_common_part<T>(
     block_height: BlockHeight,
     consensus_params: &ConsensusParameters,
 ) -> Result<(), ValidityError>
+where
+    T: canonical::Serialize + Chargeable + field::Outputs,
+{
+    visit_common_part(tx, Some(block_height), consensus_params, &mut Err)
+}
+
+/// Checks the rules common for the chargeable transactions, passing each violation
+/// to the `report`. The checks stop at the first violation for which the `report`
+/// returns an error. The maturity is only checked if the `block_height` is known.
+pub(crate) fn visit_common_part<T>(
+    tx: &T,
+    block_height: Option<BlockHeight>,
+    consensus_params: &ConsensusParameters,
+    report: &mut impl FnMut(ValidityError) -> Result<(), ValidityError>,
+) -> Result<(), ValidityError>
 where
     T: canonical::Serialize + Chargeable + field::Outputs,
 {
@@ -331,42 +348,46 @@ where
     let gas_costs = consensus_params.gas_costs();
     let fee_params = consensus_params.fee_params();
 
-    check_size(tx, tx_params)?;
+    if let Err(err) = check_size(tx, tx_params) {
+        report(err)?
+    }
 
     if !tx.policies().is_valid() {
-        Err(ValidityError::TransactionPoliciesAreInvalid)?
+        report(ValidityError::TransactionPoliciesAreInvalid)?
     }
 
     if let Some(witness_limit) = tx.policies().get(PolicyType::WitnessLimit) {
         let witness_size = tx.witnesses().size_dynamic();
         if witness_size as u64 > witness_limit {
-            Err(ValidityError::TransactionWitnessLimitExceeded)?
+            report(ValidityError::TransactionWitnessLimitExceeded)?
         }
     }
 
     let max_gas = tx.max_gas(gas_costs, fee_params);
     if max_gas > tx_params.max_gas_per_tx() {
-        Err(ValidityError::TransactionMaxGasExceeded)?
+        report(ValidityError::TransactionMaxGasExceeded)?
     }
 
     if !tx.policies().is_set(PolicyType::MaxFee) {
-        Err(ValidityError::TransactionMaxFeeNotSet)?
+        report(ValidityError::TransactionMaxFeeNotSet)?
     };
 
-    if tx.maturity() > block_height {
-        Err(ValidityError::TransactionMaturity)?;
+    if let Some(block_height) = block_height {
+        if tx.maturity() > block_height {
+            report(ValidityError::TransactionMaturity)?;
+        }
     }
 
     if tx.inputs().len() > tx_params.max_inputs() as usize {
-        Err(ValidityError::TransactionInputsMax)?
+        report(ValidityError::TransactionInputsMax)?
     }
 
     if tx.outputs().len() > tx_params.max_outputs() as usize {
-        Err(ValidityError::TransactionOutputsMax)?
+        report(ValidityError::TransactionOutputsMax)?
     }
 
     if tx.witnesses().len() > tx_params.max_witnesses() as usize {
-        Err(ValidityError::TransactionWitnessesMax)?
+        report(ValidityError::TransactionWitnessesMax)?
     }
 
     let any_spendable_input = tx.inputs().iter().find(|input| match input {
@@ -380,31 +401,26 @@ where
     });
 
     if any_spendable_input.is_none() {
-        Err(ValidityError::NoSpendableInput)?
+        report(ValidityError::NoSpendableInput)?
     }
 
-    tx.input_asset_ids_unique(base_asset_id)
-        .try_for_each(|input_asset_id| {
-            // check for duplicate change outputs
-            if tx
-                .outputs()
-                .iter()
-                .filter_map(|output| match output {
-                    Output::Change { asset_id, .. } if input_asset_id == asset_id => {
-                        Some(())
-                    }
-                    _ => None,
-                })
-                .count()
-                > 1
-            {
-                return Err(ValidityError::TransactionOutputChangeAssetIdDuplicated(
-                    *input_asset_id,
-                ));
-            }
-
-            Ok(())
-        })?;
+    for input_asset_id in tx.input_asset_ids_unique(base_asset_id) {
+        // check for duplicate change outputs
+        if tx
+            .outputs()
+            .iter()
+            .filter_map(|output| match output {
+                Output::Change { asset_id, .. } if input_asset_id == asset_id => Some(()),
+                _ => None,
+            })
+            .count()
+            > 1
+        {
+            report(ValidityError::TransactionOutputChangeAssetIdDuplicated(
+                *input_asset_id,
+            ))?;
+        }
+    }
 
     // Check for duplicated input utxo id
     let duplicated_utxo_id = tx
@@ -413,67 +429,130 @@ where
         .filter_map(|i| i.is_coin().then(|| i.utxo_id()).flatten());
 
     if let Some(utxo_id) = next_duplicate(duplicated_utxo_id).copied() {
-        return Err(ValidityError::DuplicateInputUtxoId { utxo_id });
+        report(ValidityError::DuplicateInputUtxoId { utxo_id })?;
     }
 
     // Check for duplicated input contract id
     let duplicated_contract_id = tx.inputs().iter().filter_map(Input::contract_id);
 
     if let Some(contract_id) = next_duplicate(duplicated_contract_id).copied() {
-        return Err(ValidityError::DuplicateInputContractId { contract_id });
+        report(ValidityError::DuplicateInputContractId { contract_id })?;
     }
 
     // Check for duplicated input message id
     let duplicated_message_id = tx.inputs().iter().filter_map(Input::message_id);
     if let Some(message_id) = next_duplicate(duplicated_message_id) {
-        return Err(ValidityError::DuplicateMessageInputId { message_id });
+        report(ValidityError::DuplicateMessageInputId { message_id })?;
     }
 
     // Validate the inputs without checking signature
-    tx.inputs()
-        .iter()
-        .enumerate()
-        .try_for_each(|(index, input)| {
-            input.check_without_signature(
-                index,
-                tx.outputs(),
-                tx.witnesses(),
-                predicate_params,
-            )
-        })?;
+    for (index, input) in tx.inputs().iter().enumerate() {
+        if let Err(err) = input.check_without_signature(
+            index,
+            tx.outputs(),
+            tx.witnesses(),
+            predicate_params,
+        ) {
+            report(err)?;
+        }
+    }
 
-    tx.outputs()
-        .iter()
-        .enumerate()
-        .try_for_each(|(index, output)| {
-            output.check(index, tx.inputs())?;
-
-            if let Output::Change { asset_id, .. } = output {
-                if !tx
-                    .input_asset_ids(base_asset_id)
-                    .any(|input_asset_id| input_asset_id == asset_id)
-                {
-                    return Err(ValidityError::TransactionOutputChangeAssetIdNotFound(
-                        *asset_id,
-                    ));
-                }
+    for (index, output) in tx.outputs().iter().enumerate() {
+        if let Err(err) = output.check(index, tx.inputs()) {
+            report(err)?;
+        }
+
+        if let Output::Change { asset_id, .. } = output {
+            if !tx
+                .input_asset_ids(base_asset_id)
+                .any(|input_asset_id| input_asset_id == asset_id)
+            {
+                report(ValidityError::TransactionOutputChangeAssetIdNotFound(
+                    *asset_id,
+                ))?;
             }
+        }
 
-            if let Output::Coin { asset_id, .. } = output {
-                if !tx
-                    .input_asset_ids(base_asset_id)
-                    .any(|input_asset_id| input_asset_id == asset_id)
-                {
-                    return Err(ValidityError::TransactionOutputCoinAssetIdNotFound(
-                        *asset_id,
-                    ));
-                }
+        if let Output::Coin { asset_id, .. } = output {
+            if !tx
+                .input_asset_ids(base_asset_id)
+                .any(|input_asset_id| input_asset_id == asset_id)
+            {
+                report(ValidityError::TransactionOutputCoinAssetIdNotFound(
+                    *asset_id,
+                ))?;
             }
+        }
+    }
+
+    Ok(())
+}
+
+/// All violations of the validity rules by a transaction, returned by
+/// [`Transaction::validity_report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidityReport {
+    /// The violations, in the order the rules are checked.
+    pub violations: Vec<ValidityError>,
+}
+
+impl ValidityReport {
+    /// Returns `true` if no rule is violated.
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
 
+    /// Returns `true` if the `violation` was found.
+    pub fn contains(&self, violation: &ValidityError) -> bool {
+        self.violations.contains(violation)
+    }
+
+    pub(crate) fn collect<T>(tx: &T, consensus_params: &ConsensusParameters) -> Self
+    where
+        T: canonical::Serialize
+            + Chargeable
+            + field::Outputs
+            + UniqueFormatValidityChecks,
+    {
+        let mut violations = Vec::new();
+        let _ = visit_common_part(tx, None, consensus_params, &mut |err| {
+            violations.push(err);
             Ok(())
-        })?;
+        });
+        if let Err(err) = tx.check_unique_rules(consensus_params) {
+            violations.push(err);
+        }
+        Self { violations }
+    }
+}
 
-    Ok(())
+impl Transaction {
+    /// Checks the transaction against the validity rules of the specification, like
+    /// [`FormatValidityChecks::check_without_signatures`], but reports all violations
+    /// of the rules common for all transactions instead of the first one. Only the
+    /// first violation of the rules specific to the type of the transaction is
+    /// reported.
+    ///
+    /// The maturity depends on the block including the transaction, and isn't checked.
+    pub fn validity_report(
+        &self,
+        consensus_params: &ConsensusParameters,
+    ) -> ValidityReport {
+        match self {
+            Self::Script(tx) => ValidityReport::collect(tx, consensus_params),
+            Self::Create(tx) => ValidityReport::collect(tx, consensus_params),
+            Self::Upgrade(tx) => ValidityReport::collect(tx, consensus_params),
+            Self::Upload(tx) => ValidityReport::collect(tx, consensus_params),
+            Self::Blob(tx) => ValidityReport::collect(tx, consensus_params),
+            Self::Mint(tx) => ValidityReport {
+                violations: tx
+                    .check_without_signatures(u32::MAX.into(), consensus_params)
+                    .err()
+                    .into_iter()
+                    .collect(),
+            },
+        }
+    }
 }
 
 // TODO https://github.com/FuelLabs/fuel-tx/issues/148
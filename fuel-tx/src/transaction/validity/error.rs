@@ -142,6 +142,24 @@ pub enum ValidityError {
         /// The fee amount actually provided for spending
         provided: u64,
     },
+    /// The transaction would cover the fees only by counting the amounts of the
+    /// messages with data. These amounts are retryable: they are only spent if the
+    /// execution succeeds, so they can't pay the fees.
+    #[display(
+        fmt = "Insufficient fee amount: expected {}, provided {}, excluding {} retryable \
+               amount of messages with data",
+        expected,
+        provided,
+        retryable
+    )]
+    RetryableAmountCannotCoverFee {
+        /// The expected amount of fees required to cover the transaction
+        expected: u64,
+        /// The fee amount provided by coins and messages without data
+        provided: u64,
+        /// The amount provided by messages with data
+        retryable: u64,
+    },
     /// The transaction doesn't provide enough input amount of the given asset to cover
     /// the amounts used in the outputs.
     #[display(
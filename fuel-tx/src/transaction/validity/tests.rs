@@ -1,4 +1,5 @@
 use super::*;
+use crate::ScriptParameters;
 
 use fuel_types::canonical::{
     Deserialize,
@@ -42,3 +43,37 @@ fn check_size_returns_transaction_size_limit_exceeded_for_invalid_size() {
     let err = result.expect_err("Expected check_size to return err");
     assert_eq!(err, ValidityError::TransactionSizeLimitExceeded);
 }
+
+#[test]
+fn validity_report_returns_all_violations() {
+    // Given
+    let mut params = ConsensusParameters::standard();
+    params.set_script_params(ScriptParameters::default().with_max_script_length(1));
+    let tx: Transaction = Transaction::script(
+        0,
+        alloc::vec![0; 8],
+        alloc::vec![],
+        crate::policies::Policies::new(),
+        alloc::vec![],
+        alloc::vec![],
+        alloc::vec![],
+    )
+    .into();
+
+    // When
+    let report = tx.validity_report(&params);
+
+    // Then
+    assert_eq!(
+        report.violations,
+        alloc::vec![
+            ValidityError::TransactionMaxFeeNotSet,
+            ValidityError::NoSpendableInput,
+            ValidityError::TransactionScriptLength,
+        ]
+    );
+    assert_eq!(
+        tx.check_without_signatures(u32::MAX.into(), &params),
+        Err(ValidityError::TransactionMaxFeeNotSet)
+    );
+}
@@ -0,0 +1,399 @@
+//! Borrowed views over the canonical encoding of transactions.
+//!
+//! The views parse the encoding without copying the scripts, the predicates and the
+//! witnesses into `Vec`s, for the paths that only read the transaction.
+
+use crate::{
+    input::InputRepr,
+    policies::Policies,
+    Input,
+    Output,
+    TransactionRepr,
+    TxPointer,
+    UtxoId,
+};
+use fuel_types::{
+    bytes::padded_len_usize,
+    canonical::{
+        Deserialize,
+        Error,
+        VEC_DECODE_LIMIT,
+    },
+    Address,
+    AssetId,
+    Bytes32,
+    ContractId,
+    Nonce,
+    Word,
+};
+
+/// Reads the `T` from the start of the `buffer`.
+fn read<T: Deserialize>(buffer: &mut &[u8]) -> Result<T, Error> {
+    T::decode(buffer)
+}
+
+/// Reads the length of a vector, with the same limit as the owned decoding.
+fn read_len(buffer: &mut &[u8]) -> Result<usize, Error> {
+    let len: usize = read::<Word>(buffer)?
+        .try_into()
+        .map_err(|_| Error::AllocationLimit)?;
+    if len > VEC_DECODE_LIMIT {
+        return Err(Error::AllocationLimit)
+    }
+    Ok(len)
+}
+
+/// Borrows the `len` bytes from the start of the `buffer`, and skips their padding.
+fn borrow_bytes<'a>(buffer: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    let padded_len = padded_len_usize(len).ok_or(Error::AllocationLimit)?;
+    if padded_len > buffer.len() {
+        return Err(Error::BufferIsTooShort)
+    }
+    let (bytes, rest) = buffer.split_at(padded_len);
+    *buffer = rest;
+    Ok(&bytes[..len])
+}
+
+/// Borrows the `count` encoded elements from the start of the `buffer`, validating them
+/// with `parse`.
+fn borrow_elements<'a, T>(
+    buffer: &mut &'a [u8],
+    count: usize,
+    parse: impl Fn(&mut &'a [u8]) -> Result<T, Error>,
+) -> Result<&'a [u8], Error> {
+    let start = *buffer;
+    for _ in 0..count {
+        parse(buffer)?;
+    }
+    let len = start.len().saturating_sub(buffer.len());
+    Ok(&start[..len])
+}
+
+/// A borrowed coin input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoinRef<'a> {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: Word,
+    pub asset_id: AssetId,
+    pub tx_pointer: TxPointer,
+    /// Ignored if the coin has a predicate.
+    pub witness_index: u16,
+    /// Zero if the coin has no predicate.
+    pub predicate_gas_used: Word,
+    /// Empty if the coin is signed.
+    pub predicate: &'a [u8],
+    pub predicate_data: &'a [u8],
+}
+
+/// A borrowed message input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageRef<'a> {
+    pub sender: Address,
+    pub recipient: Address,
+    pub amount: Word,
+    pub nonce: Nonce,
+    /// Ignored if the message has a predicate.
+    pub witness_index: u16,
+    /// Zero if the message has no predicate.
+    pub predicate_gas_used: Word,
+    /// Empty if the message is a coin.
+    pub data: &'a [u8],
+    /// Empty if the message is signed.
+    pub predicate: &'a [u8],
+    pub predicate_data: &'a [u8],
+}
+
+/// A borrowed contract input. It has no dynamic data, so it is owned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContractRef {
+    pub utxo_id: UtxoId,
+    pub balance_root: Bytes32,
+    pub state_root: Bytes32,
+    pub tx_pointer: TxPointer,
+    pub contract_id: ContractId,
+}
+
+/// A borrowed view over the canonical encoding of an [`Input`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputRef<'a> {
+    Coin(CoinRef<'a>),
+    Contract(ContractRef),
+    Message(MessageRef<'a>),
+}
+
+impl<'a> InputRef<'a> {
+    /// Parses the input from the start of the `buffer`, and advances the `buffer` past
+    /// it.
+    pub fn parse(buffer: &mut &'a [u8]) -> Result<Self, Error> {
+        let repr = read::<InputRepr>(buffer).map_err(|_| Error::UnknownDiscriminant)?;
+        Ok(match repr {
+            InputRepr::Coin => {
+                let utxo_id = read(buffer)?;
+                let owner = read(buffer)?;
+                let amount = read(buffer)?;
+                let asset_id = read(buffer)?;
+                let tx_pointer = read(buffer)?;
+                let witness_index = read(buffer)?;
+                let predicate_gas_used = read(buffer)?;
+                let predicate_len = read_len(buffer)?;
+                let predicate_data_len = read_len(buffer)?;
+                let predicate = borrow_bytes(buffer, predicate_len)?;
+                let predicate_data = borrow_bytes(buffer, predicate_data_len)?;
+                Self::Coin(CoinRef {
+                    utxo_id,
+                    owner,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                    witness_index,
+                    predicate_gas_used,
+                    predicate,
+                    predicate_data,
+                })
+            }
+            InputRepr::Contract => Self::Contract(ContractRef {
+                utxo_id: read(buffer)?,
+                balance_root: read(buffer)?,
+                state_root: read(buffer)?,
+                tx_pointer: read(buffer)?,
+                contract_id: read(buffer)?,
+            }),
+            InputRepr::Message => {
+                let sender = read(buffer)?;
+                let recipient = read(buffer)?;
+                let amount = read(buffer)?;
+                let nonce = read(buffer)?;
+                let witness_index = read(buffer)?;
+                let predicate_gas_used = read(buffer)?;
+                let data_len = read_len(buffer)?;
+                let predicate_len = read_len(buffer)?;
+                let predicate_data_len = read_len(buffer)?;
+                let data = borrow_bytes(buffer, data_len)?;
+                let predicate = borrow_bytes(buffer, predicate_len)?;
+                let predicate_data = borrow_bytes(buffer, predicate_data_len)?;
+                Self::Message(MessageRef {
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    witness_index,
+                    predicate_gas_used,
+                    data,
+                    predicate,
+                    predicate_data,
+                })
+            }
+        })
+    }
+
+    /// Returns `true` if the input is a coin or a message with a predicate.
+    pub const fn is_predicate(&self) -> bool {
+        match self {
+            Self::Coin(coin) => !coin.predicate.is_empty(),
+            Self::Message(message) => !message.predicate.is_empty(),
+            Self::Contract(_) => false,
+        }
+    }
+
+    /// Copies the input into an owned [`Input`].
+    pub fn to_owned(&self) -> Input {
+        match *self {
+            Self::Coin(coin) if coin.predicate.is_empty() => Input::coin_signed(
+                coin.utxo_id,
+                coin.owner,
+                coin.amount,
+                coin.asset_id,
+                coin.tx_pointer,
+                coin.witness_index,
+            ),
+            Self::Coin(coin) => Input::coin_predicate(
+                coin.utxo_id,
+                coin.owner,
+                coin.amount,
+                coin.asset_id,
+                coin.tx_pointer,
+                coin.predicate_gas_used,
+                coin.predicate.to_vec(),
+                coin.predicate_data.to_vec(),
+            ),
+            Self::Contract(contract) => Input::contract(
+                contract.utxo_id,
+                contract.balance_root,
+                contract.state_root,
+                contract.tx_pointer,
+                contract.contract_id,
+            ),
+            Self::Message(message) => {
+                match (message.data.is_empty(), message.predicate.is_empty()) {
+                    (true, true) => Input::message_coin_signed(
+                        message.sender,
+                        message.recipient,
+                        message.amount,
+                        message.nonce,
+                        message.witness_index,
+                    ),
+                    (true, false) => Input::message_coin_predicate(
+                        message.sender,
+                        message.recipient,
+                        message.amount,
+                        message.nonce,
+                        message.predicate_gas_used,
+                        message.predicate.to_vec(),
+                        message.predicate_data.to_vec(),
+                    ),
+                    (false, true) => Input::message_data_signed(
+                        message.sender,
+                        message.recipient,
+                        message.amount,
+                        message.nonce,
+                        message.witness_index,
+                        message.data.to_vec(),
+                    ),
+                    (false, false) => Input::message_data_predicate(
+                        message.sender,
+                        message.recipient,
+                        message.amount,
+                        message.nonce,
+                        message.predicate_gas_used,
+                        message.data.to_vec(),
+                        message.predicate.to_vec(),
+                        message.predicate_data.to_vec(),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// A borrowed view over the canonical encoding of a [`Script`](crate::Script)
+/// transaction.
+///
+/// The encoding is validated once by [`ScriptRef::parse`], so iterating over the inputs,
+/// the outputs and the witnesses doesn't fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptRef<'a> {
+    bytes: &'a [u8],
+    script_gas_limit: Word,
+    receipts_root: Bytes32,
+    script: &'a [u8],
+    script_data: &'a [u8],
+    policies: Policies,
+    inputs_count: usize,
+    inputs: &'a [u8],
+    outputs_count: usize,
+    outputs: &'a [u8],
+    witnesses_count: usize,
+    witnesses: &'a [u8],
+}
+
+impl<'a> ScriptRef<'a> {
+    /// Parses the canonically encoded script transaction. The `bytes` must contain
+    /// exactly one transaction.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, Error> {
+        let buffer = &mut &bytes[..];
+
+        if read::<TransactionRepr>(buffer)? != TransactionRepr::Script {
+            return Err(Error::InvalidPrefix)
+        }
+        let script_gas_limit = read(buffer)?;
+        let receipts_root = read(buffer)?;
+        let script_len = read_len(buffer)?;
+        let script_data_len = read_len(buffer)?;
+        let mut policies = Policies::decode_static(buffer)?;
+        let inputs_count = read_len(buffer)?;
+        let outputs_count = read_len(buffer)?;
+        let witnesses_count = read_len(buffer)?;
+
+        let script = borrow_bytes(buffer, script_len)?;
+        let script_data = borrow_bytes(buffer, script_data_len)?;
+        policies.decode_dynamic(buffer)?;
+        let inputs = borrow_elements(buffer, inputs_count, InputRef::parse)?;
+        let outputs = borrow_elements(buffer, outputs_count, read::<Output>)?;
+        let witnesses = borrow_elements(buffer, witnesses_count, |buffer| {
+            let len = read_len(buffer)?;
+            borrow_bytes(buffer, len)
+        })?;
+
+        if !buffer.is_empty() {
+            return Err(Error::Unknown("Trailing bytes after the transaction"))
+        }
+
+        Ok(Self {
+            bytes,
+            script_gas_limit,
+            receipts_root,
+            script,
+            script_data,
+            policies,
+            inputs_count,
+            inputs,
+            outputs_count,
+            outputs,
+            witnesses_count,
+            witnesses,
+        })
+    }
+
+    /// The whole canonical encoding of the transaction.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub const fn script_gas_limit(&self) -> Word {
+        self.script_gas_limit
+    }
+
+    pub const fn receipts_root(&self) -> &Bytes32 {
+        &self.receipts_root
+    }
+
+    pub const fn script(&self) -> &'a [u8] {
+        self.script
+    }
+
+    pub const fn script_data(&self) -> &'a [u8] {
+        self.script_data
+    }
+
+    pub const fn policies(&self) -> &Policies {
+        &self.policies
+    }
+
+    pub const fn inputs_count(&self) -> usize {
+        self.inputs_count
+    }
+
+    pub fn inputs(&self) -> impl ExactSizeIterator<Item = InputRef<'a>> + 'a {
+        let mut buffer = self.inputs;
+        (0..self.inputs_count).map(move |_| {
+            InputRef::parse(&mut buffer).expect("The inputs were validated by `parse`")
+        })
+    }
+
+    pub const fn outputs_count(&self) -> usize {
+        self.outputs_count
+    }
+
+    /// The outputs have no dynamic data, so they are decoded into owned [`Output`]s.
+    pub fn outputs(&self) -> impl ExactSizeIterator<Item = Output> + 'a {
+        let mut buffer = self.outputs;
+        (0..self.outputs_count).map(move |_| {
+            read(&mut buffer).expect("The outputs were validated by `parse`")
+        })
+    }
+
+    pub const fn witnesses_count(&self) -> usize {
+        self.witnesses_count
+    }
+
+    pub fn witnesses(&self) -> impl ExactSizeIterator<Item = &'a [u8]> + 'a {
+        let mut buffer = self.witnesses;
+        (0..self.witnesses_count).map(move |_| {
+            let len =
+                read_len(&mut buffer).expect("The witnesses were validated by `parse`");
+            borrow_bytes(&mut buffer, len)
+                .expect("The witnesses were validated by `parse`")
+        })
+    }
+}
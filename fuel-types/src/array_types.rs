@@ -355,9 +355,14 @@ key!(Nonce, 32);
 key!(MessageId, 32);
 key!(Salt, 32);
 
+key_with_big_array!(Bytes48, 48);
 key_with_big_array!(Bytes64, 64);
+key_with_big_array!(Bytes96, 96);
 
 impl ContractId {
+    /// Seed for the calculation of the id of a contract created by another contract,
+    /// distinguishing it from the ids of contracts deployed with transactions.
+    pub const CHILD_SEED: [u8; 4] = 0x43484C44_u32.to_be_bytes();
     /// Seed for the calculation of the contract id from its code.
     ///
     /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/identifiers/contract-id.md>
@@ -15,6 +15,17 @@ pub use fuel_derive::{
     Serialize,
 };
 
+mod limits;
+#[cfg(feature = "std")]
+mod stream;
+
+pub use limits::Deserializer;
+#[cfg(feature = "std")]
+pub use stream::{
+    StreamReader,
+    StreamWriter,
+};
+
 /// Error when serializing or deserializing.
 #[derive(Debug, Eq, PartialEq)]
 #[non_exhaustive]
@@ -27,6 +38,10 @@ pub enum Error {
     InvalidPrefix,
     /// Allocation too large to be correct.
     AllocationLimit,
+    /// The collection is longer than the limit of the [`Deserializer`].
+    CollectionLengthLimit,
+    /// The decoding consumed more bytes than the limit of the [`Deserializer`].
+    ByteLimit,
     /// Unknown error.
     Unknown(&'static str),
 }
@@ -40,6 +55,8 @@ impl Error {
                 "prefix set with #[canonical(prefix = ...)] was invalid"
             }
             Error::AllocationLimit => "allocation too large",
+            Error::CollectionLengthLimit => "collection is longer than the limit",
+            Error::ByteLimit => "decoding consumed more bytes than the limit",
             Error::Unknown(str) => str,
         }
     }
@@ -111,6 +128,15 @@ pub trait Serialize {
         self.encode(&mut vec).expect("Unable to encode self");
         vec
     }
+
+    /// Encodes `Self` into the `writer` as the bytes are produced, without buffering
+    /// the whole encoding.
+    #[cfg(feature = "std")]
+    fn encode_to_writer<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        let mut output = StreamWriter::new(writer);
+        self.encode(&mut output)
+            .map_err(|e| stream::into_io_error(e, output.take_io_error()))
+    }
 }
 
 /// Allows reading of data into a slice.
@@ -140,6 +166,19 @@ pub trait Input {
 
     /// Skips next `n` bytes.
     fn skip(&mut self, n: usize) -> Result<(), Error>;
+
+    /// Maximum number of the elements of a decoded collection.
+    fn max_collection_len(&self) -> usize {
+        VEC_DECODE_LIMIT
+    }
+
+    /// Checks that the next `n` bytes may be read, before allocating for them.
+    ///
+    /// The bytes may follow in another input if the static and the dynamic parts are
+    /// decoded separately, so the default implementation doesn't check anything.
+    fn ensure_readable(&mut self, _n: usize) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 /// Allows deserialize the type from the `Input`.
@@ -177,6 +216,18 @@ pub trait Deserialize: Sized {
     fn from_bytes(mut buffer: &[u8]) -> Result<Self, Error> {
         Self::decode(&mut buffer)
     }
+
+    /// Decodes `Self` from the `reader` as the bytes are consumed, without buffering
+    /// the whole encoding.
+    ///
+    /// It may read up to [`ALIGN`] bytes past the end of `Self`. Use the
+    /// [`StreamReader`] directly to decode several objects from the same reader.
+    #[cfg(feature = "std")]
+    fn decode_from_reader<R: std::io::Read>(reader: R) -> std::io::Result<Self> {
+        let mut input = StreamReader::new(reader)?;
+        Self::decode(&mut input)
+            .map_err(|e| stream::into_io_error(e, input.take_io_error()))
+    }
 }
 
 /// The data of each field should be aligned to 64 bits.
@@ -336,6 +387,13 @@ impl<T: Deserialize> Deserialize for Vec<T> {
         if cap > VEC_DECODE_LIMIT {
             return Err(Error::AllocationLimit)
         }
+        if cap > buffer.max_collection_len() {
+            return Err(Error::CollectionLengthLimit)
+        }
+        // Every byte is encoded as a byte, so their count is known before allocating.
+        if T::UNALIGNED_BYTES {
+            buffer.ensure_readable(cap)?;
+        }
         Ok(Vec::with_capacity(cap))
     }
 
@@ -0,0 +1,184 @@
+//! Decoding of untrusted inputs within the configured limits.
+
+use super::{
+    Deserialize,
+    Error,
+    Input,
+    VEC_DECODE_LIMIT,
+};
+
+/// Configuration of the decoding of untrusted inputs.
+///
+/// Bounds the length of every decoded collection and the total number of the consumed
+/// bytes. The lengths are checked before allocating for the collections, so a hostile
+/// length prefix fails the decoding instead of exhausting the memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deserializer {
+    max_collection_len: usize,
+    max_bytes: usize,
+}
+
+impl Default for Deserializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deserializer {
+    /// Creates the configuration with the default limits, the same as for
+    /// [`Deserialize::decode`].
+    pub const fn new() -> Self {
+        Self {
+            max_collection_len: VEC_DECODE_LIMIT,
+            max_bytes: usize::MAX,
+        }
+    }
+
+    /// Limits the number of the elements of every decoded collection. Exceeding it
+    /// fails with [`Error::CollectionLengthLimit`].
+    ///
+    /// The limit can only be lowered below [`VEC_DECODE_LIMIT`].
+    pub const fn with_max_collection_len(mut self, max_collection_len: usize) -> Self {
+        self.max_collection_len = max_collection_len;
+        self
+    }
+
+    /// Limits the total number of the bytes consumed by the decoding. Exceeding it
+    /// fails with [`Error::ByteLimit`].
+    pub const fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// The maximum number of the elements of a collection.
+    pub const fn max_collection_len(&self) -> usize {
+        self.max_collection_len
+    }
+
+    /// The maximum number of the consumed bytes.
+    pub const fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Decodes `T` from the `buffer` within the limits.
+    pub fn decode<T: Deserialize, I: Input + ?Sized>(
+        &self,
+        buffer: &mut I,
+    ) -> Result<T, Error> {
+        let mut input = LimitedInput {
+            max_collection_len: self.max_collection_len,
+            bytes_left: self.max_bytes,
+            inner: buffer,
+        };
+        T::decode(&mut input)
+    }
+
+    /// Decodes `T` from the `bytes` within the limits.
+    pub fn from_bytes<T: Deserialize>(&self, mut bytes: &[u8]) -> Result<T, Error> {
+        self.decode(&mut bytes)
+    }
+}
+
+/// [`Input`] enforcing the limits of the [`Deserializer`] on the inner input.
+struct LimitedInput<'a, I: ?Sized> {
+    max_collection_len: usize,
+    bytes_left: usize,
+    inner: &'a mut I,
+}
+
+impl<I: Input + ?Sized> LimitedInput<'_, I> {
+    fn consume(&mut self, n: usize) -> Result<(), Error> {
+        self.bytes_left = self.bytes_left.checked_sub(n).ok_or(Error::ByteLimit)?;
+        Ok(())
+    }
+}
+
+impl<I: Input + ?Sized> Input for LimitedInput<'_, I> {
+    fn remaining(&mut self) -> usize {
+        self.inner.remaining()
+    }
+
+    fn peek(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.inner.peek(buf)
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        self.consume(buf.len())?;
+        self.inner.read(buf)
+    }
+
+    fn skip(&mut self, n: usize) -> Result<(), Error> {
+        self.consume(n)?;
+        self.inner.skip(n)
+    }
+
+    fn max_collection_len(&self) -> usize {
+        self.max_collection_len.min(self.inner.max_collection_len())
+    }
+
+    fn ensure_readable(&mut self, n: usize) -> Result<(), Error> {
+        if n > self.bytes_left {
+            return Err(Error::ByteLimit)
+        }
+        self.inner.ensure_readable(n)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::canonical::Serialize;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn decode__within_the_limits_succeeds() {
+        let value = vec![vec![1u8, 2, 3], vec![4u8]];
+        let bytes = value.to_bytes();
+
+        let deserializer = Deserializer::new()
+            .with_max_collection_len(3)
+            .with_max_bytes(bytes.len());
+
+        assert_eq!(deserializer.from_bytes(&bytes), Ok(value));
+    }
+
+    #[test]
+    fn decode__too_long_collection_fails() {
+        let bytes = vec![1u64, 2, 3].to_bytes();
+
+        let result = Deserializer::new()
+            .with_max_collection_len(2)
+            .from_bytes::<Vec<u64>>(&bytes);
+
+        assert_eq!(result, Err(Error::CollectionLengthLimit));
+    }
+
+    #[test]
+    fn decode__too_many_bytes_fails() {
+        let bytes = vec![1u64, 2, 3].to_bytes();
+
+        let result = Deserializer::new()
+            .with_max_bytes(bytes.len() - 1)
+            .from_bytes::<Vec<u64>>(&bytes);
+
+        assert_eq!(result, Err(Error::ByteLimit));
+    }
+
+    #[test]
+    fn decode__hostile_length_prefix_fails_before_allocating() {
+        // The prefix claims 64 MiB of bytes, but none follow it.
+        let bytes = (64u64 << 20).to_bytes();
+
+        let limited = Deserializer::new()
+            .with_max_bytes(1 << 20)
+            .from_bytes::<Vec<u8>>(&bytes);
+        let unlimited = Vec::<u8>::from_bytes(&bytes);
+
+        assert_eq!(limited, Err(Error::ByteLimit));
+        assert_eq!(unlimited, Err(Error::BufferIsTooShort));
+    }
+}
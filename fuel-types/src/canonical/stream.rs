@@ -0,0 +1,284 @@
+//! Streaming adapters between the canonical encoding and `std::io`.
+
+use super::{
+    Error,
+    Input,
+    Output,
+    ALIGN,
+};
+use std::io;
+
+/// [`Output`] writing the canonical encoding into an [`io::Write`] as it is produced,
+/// without buffering the whole object.
+///
+/// The writer isn't buffered by the adapter, so small writes go straight to it.
+/// Wrap it into an [`io::BufWriter`] if they are expensive.
+#[derive(Debug)]
+pub struct StreamWriter<W> {
+    writer: W,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> StreamWriter<W> {
+    /// Creates the adapter writing into the `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            written: 0,
+            error: None,
+        }
+    }
+
+    /// Number of the bytes written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Takes the I/O error that failed the latest encoding, if any.
+    pub fn take_io_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: io::Write> Output for StreamWriter<W> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.writer.write_all(bytes).map_err(|e| {
+            self.error = Some(e);
+            Error::Unknown("I/O error")
+        })?;
+        self.written = self.written.saturating_add(bytes.len());
+        Ok(())
+    }
+}
+
+/// [`Input`] reading the canonical encoding from an [`io::Read`] as it is consumed,
+/// without buffering the whole object.
+///
+/// The adapter looks up to [`ALIGN`] bytes ahead, for [`Input::peek`], so it may read
+/// that much past the end of the decoded object. Decode the consecutive objects of a
+/// stream through the same adapter so that no bytes are lost. The reader isn't
+/// buffered by the adapter otherwise; wrap it into an [`io::BufReader`] if small reads
+/// are expensive.
+#[derive(Debug)]
+pub struct StreamReader<R> {
+    reader: R,
+    lookahead: [u8; ALIGN],
+    lookahead_len: usize,
+    read: usize,
+    error: Option<io::Error>,
+}
+
+impl<R: io::Read> StreamReader<R> {
+    /// Creates the adapter reading from the `reader`, and fills the lookahead.
+    pub fn new(reader: R) -> io::Result<Self> {
+        let mut stream = Self {
+            reader,
+            lookahead: [0; ALIGN],
+            lookahead_len: 0,
+            read: 0,
+            error: None,
+        };
+        stream.fill_lookahead()?;
+        Ok(stream)
+    }
+
+    /// Number of the bytes consumed so far, excluding the lookahead.
+    pub fn read_bytes(&self) -> usize {
+        self.read
+    }
+
+    /// Returns `true` if the stream has no more bytes.
+    pub fn is_empty(&self) -> bool {
+        self.lookahead_len == 0
+    }
+
+    /// Takes the I/O error that failed the latest decoding, if any.
+    pub fn take_io_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    /// Returns the underlying reader, along with the bytes read ahead from it.
+    pub fn into_inner(self) -> (R, Vec<u8>) {
+        (self.reader, self.lookahead[..self.lookahead_len].to_vec())
+    }
+
+    fn fill_lookahead(&mut self) -> io::Result<()> {
+        while self.lookahead_len < ALIGN {
+            match self.reader.read(&mut self.lookahead[self.lookahead_len..]) {
+                Ok(0) => break,
+                Ok(n) => self.lookahead_len = self.lookahead_len.saturating_add(n),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    fn io_error(&mut self, error: io::Error) -> Error {
+        if error.kind() == io::ErrorKind::UnexpectedEof {
+            Error::BufferIsTooShort
+        } else {
+            self.error = Some(error);
+            Error::Unknown("I/O error")
+        }
+    }
+
+    /// Moves the first `into.len()` bytes out of the lookahead into `into`.
+    fn take_lookahead(&mut self, into: &mut [u8]) {
+        let n = into.len();
+        into.copy_from_slice(&self.lookahead[..n]);
+        self.lookahead.copy_within(n..self.lookahead_len, 0);
+        self.lookahead_len = self.lookahead_len.saturating_sub(n);
+    }
+}
+
+impl<R: io::Read> Input for StreamReader<R> {
+    /// The size of the stream isn't known in advance, so it is `usize::MAX` until the
+    /// end of the stream is within the lookahead.
+    fn remaining(&mut self) -> usize {
+        if self.lookahead_len < ALIGN {
+            self.lookahead_len
+        } else {
+            usize::MAX
+        }
+    }
+
+    /// Only up to [`ALIGN`] bytes can be peeked.
+    fn peek(&self, buf: &mut [u8]) -> Result<(), Error> {
+        let lookahead = self
+            .lookahead
+            .get(..buf.len())
+            .filter(|_| buf.len() <= self.lookahead_len)
+            .ok_or(Error::BufferIsTooShort)?;
+        buf.copy_from_slice(lookahead);
+        Ok(())
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        let from_lookahead = buf.len().min(self.lookahead_len);
+        let (head, tail) = buf.split_at_mut(from_lookahead);
+        self.take_lookahead(head);
+
+        if !tail.is_empty() {
+            if let Err(e) = self.reader.read_exact(tail) {
+                return Err(self.io_error(e))
+            }
+        }
+        if let Err(e) = self.fill_lookahead() {
+            return Err(self.io_error(e))
+        }
+
+        self.read = self.read.saturating_add(buf.len());
+        Ok(())
+    }
+
+    fn skip(&mut self, n: usize) -> Result<(), Error> {
+        let from_lookahead = n.min(self.lookahead_len);
+        self.lookahead
+            .copy_within(from_lookahead..self.lookahead_len, 0);
+        self.lookahead_len = self.lookahead_len.saturating_sub(from_lookahead);
+
+        let rest = n.saturating_sub(from_lookahead) as u64;
+        if rest > 0 {
+            let skipped =
+                io::copy(&mut io::Read::take(&mut self.reader, rest), &mut io::sink());
+            let skipped = skipped.map_err(|e| self.io_error(e))?;
+            if skipped < rest {
+                return Err(Error::BufferIsTooShort)
+            }
+        }
+        if let Err(e) = self.fill_lookahead() {
+            return Err(self.io_error(e))
+        }
+
+        self.read = self.read.saturating_add(n);
+        Ok(())
+    }
+}
+
+/// Converts the error of the canonical encoding into an [`io::Error`], preferring the
+/// underlying I/O error if there is one.
+pub(super) fn into_io_error(error: Error, io_error: Option<io::Error>) -> io::Error {
+    io_error.unwrap_or_else(|| match error {
+        Error::BufferIsTooShort => {
+            io::Error::new(io::ErrorKind::UnexpectedEof, error.as_str())
+        }
+        _ => io::Error::new(io::ErrorKind::InvalidData, error.as_str()),
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::canonical::{
+        Deserialize,
+        Serialize,
+    };
+
+    struct FailingWriter;
+
+    impl io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk is full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn stream_reader__reads_and_peeks_across_the_lookahead() {
+        let bytes = (0..20u8).collect::<Vec<_>>();
+        let mut input = StreamReader::new(bytes.as_slice()).unwrap();
+
+        let mut peeked = [0u8; ALIGN];
+        input.peek(&mut peeked).unwrap();
+        assert_eq!(peeked, [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(
+            input.peek(&mut [0u8; ALIGN + 1]),
+            Err(Error::BufferIsTooShort)
+        );
+
+        let mut read = [0u8; 11];
+        input.read(&mut read).unwrap();
+        assert_eq!(read, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        input.skip(5).unwrap();
+        assert_eq!(input.read_byte(), Ok(16));
+        assert_eq!(input.remaining(), 3);
+        assert_eq!(input.skip(4), Err(Error::BufferIsTooShort));
+    }
+
+    #[test]
+    fn decode_from_reader__leaves_the_rest_of_the_stream_to_the_reader() {
+        let bytes = [1u64.to_bytes(), 2u64.to_bytes()].concat();
+        let mut input = StreamReader::new(bytes.as_slice()).unwrap();
+
+        assert_eq!(u64::decode(&mut input), Ok(1));
+        assert_eq!(u64::decode(&mut input), Ok(2));
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn encode_to_writer__returns_the_io_error() {
+        let error = 1u64.encode_to_writer(FailingWriter).unwrap_err();
+
+        assert_eq!(error.to_string(), "disk is full");
+    }
+
+    #[test]
+    fn decode_from_reader__invalid_data_is_reported() {
+        let bytes = u64::MAX.to_bytes();
+
+        let error = Vec::<u8>::decode_from_reader(bytes.as_slice()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -60,7 +60,9 @@ fn from_slice_unchecked_safety() {
         check_consistency!(Nonce, rng, bytes);
         check_consistency!(MessageId, rng, bytes);
         check_consistency!(Salt, rng, bytes);
+        check_consistency!(Bytes48, rng, bytes);
         check_consistency!(Bytes64, rng, bytes);
+        check_consistency!(Bytes96, rng, bytes);
     }
 }
 
@@ -133,7 +135,9 @@ fn hex_encoding() {
     encode_decode::<Nonce>(rng.gen());
     encode_decode::<MessageId>(rng.gen());
     encode_decode::<Salt>(rng.gen());
+    encode_decode::<Bytes48>(rng.gen());
     encode_decode::<Bytes64>(rng.gen());
+    encode_decode::<Bytes96>(rng.gen());
 }
 
 #[test]
@@ -0,0 +1,145 @@
+//! Execution of the memory-heavy instructions with the flat [`MemoryInstance`] and the
+//! copy-on-write [`CowMemory`] used for forking.
+//!
+//! Every iteration runs the instruction in a loop on a clone of an initialized
+//! interpreter. The clone of the [`CowMemory`] shares the memory with the original,
+//! so its numbers include the copy on the first write, as after a fork.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    BatchSize,
+    Criterion,
+};
+use fuel_asm::{
+    op,
+    GTFArgs,
+    Instruction,
+    RegId,
+};
+use fuel_tx::{
+    GasCosts,
+    Script,
+};
+use fuel_types::canonical::Serialize;
+use fuel_vm::{
+    interpreter::{
+        CowMemory,
+        InterpreterParams,
+        Memory,
+        MemoryInstance,
+    },
+    prelude::{
+        Call,
+        Interpreter,
+        MemoryStorage,
+    },
+    util::test_helpers::TestBuilder,
+};
+
+/// The number of bytes the instructions read and write.
+const LEN: u32 = 1024;
+
+/// The number of instructions executed per benchmark iteration.
+const ITERATIONS: usize = 1000;
+
+/// The instructions run in the loop. The registers are set up by [`script`]:
+/// `0x10` points to the called contract id, `0x11` to the allocated heap and `0x12`
+/// to its second half, and `0x13` is [`LEN`].
+fn cases() -> Vec<(&'static str, Instruction)> {
+    vec![
+        ("mcp", op::mcp(0x11, 0x12, 0x13)),
+        ("ldc", op::ldc(0x10, RegId::ZERO, 0x13, 0)),
+        ("ccp", op::ccp(0x11, 0x10, RegId::ZERO, 0x13)),
+        ("logd", op::logd(RegId::ZERO, RegId::ZERO, 0x11, 0x13)),
+        ("s256", op::s256(0x11, 0x12, 0x13)),
+        ("k256", op::k256(0x11, 0x12, 0x13)),
+    ]
+}
+
+fn script(instruction: Instruction) -> Vec<Instruction> {
+    vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::movi(0x13, LEN),
+        op::slli(0x14, 0x13, 1),
+        op::aloc(0x14),
+        op::move_(0x11, RegId::HP),
+        op::add(0x12, RegId::HP, 0x13),
+        instruction,
+        op::jmpb(RegId::ZERO, 0),
+    ]
+}
+
+/// Initializes the interpreter running the script of the instruction, calling a
+/// contract of at least [`LEN`] bytes.
+fn interpreter<M>(
+    memory: M,
+    instruction: Instruction,
+) -> Interpreter<M, MemoryStorage, Script>
+where
+    M: Memory,
+{
+    let mut test_context = TestBuilder::new(2322u64);
+    let code = vec![op::noop(); LEN as usize / Instruction::SIZE];
+    let contract_id = test_context.setup_contract(code, None, None).contract_id;
+
+    let tx = test_context
+        .start_script(script(instruction), Call::new(contract_id, 0, 0).to_bytes())
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+    let consensus_params = test_context.get_consensus_params().clone();
+    let ready = tx
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap();
+
+    let mut interpreter = Interpreter::with_storage(
+        memory,
+        test_context.get_storage().clone(),
+        InterpreterParams {
+            gas_costs: GasCosts::free(),
+            ..InterpreterParams::new(0, &consensus_params)
+        },
+    );
+    interpreter.init_script(ready).unwrap();
+    interpreter
+}
+
+fn bench_backend<M>(c: &mut Criterion, backend: &str, memory: impl Fn() -> M)
+where
+    M: Memory + Clone,
+{
+    let mut group = c.benchmark_group("memory");
+
+    for (name, instruction) in cases() {
+        let interpreter = interpreter(memory(), instruction);
+        group.bench_function(format!("{name}/{backend}"), |b| {
+            b.iter_batched(
+                || interpreter.clone(),
+                |mut interpreter| {
+                    for _ in 0..ITERATIONS {
+                        interpreter.execute().unwrap();
+                    }
+                    interpreter
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn memory(c: &mut Criterion) {
+    bench_backend(c, "flat", MemoryInstance::new);
+    bench_backend(c, "cow", CowMemory::default);
+}
+
+criterion_group!(benches, memory);
+criterion_main!(benches);
@@ -0,0 +1,411 @@
+//! End-to-end throughput of blocks of mixed transactions: predicate-owned coin
+//! transfers, contract calls and contract deployments.
+//!
+//! Every transaction goes through the same phases as in a block producer: the
+//! static checks and the signatures, the predicates, the execution by the
+//! [`Transactor`] and the commit into the storage backend. Each phase is reported
+//! as a separate benchmark in transactions per second, so a regression can be
+//! attributed to the phase causing it. The `total` benchmark is the sum of the
+//! phases.
+//!
+//! The phases are timed by [`run_block`], which reports them to a hook. The hook
+//! can be replaced, e.g. to start and stop an external profiler around a phase.
+
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+    Throughput,
+};
+use fuel_asm::{
+    op,
+    GTFArgs,
+    RegId,
+};
+use fuel_tx::{
+    Bytes32,
+    ConsensusParameters,
+    ContractId,
+    Create,
+    Finalizable,
+    Input,
+    Output,
+    Script,
+    StorageSlot,
+    TransactionBuilder,
+    TxPointer,
+    Witness,
+};
+use fuel_types::canonical::Serialize;
+use fuel_vm::{
+    checked_transaction::{
+        CheckPredicateParams,
+        CheckPredicates,
+        Checked,
+        EstimatePredicates,
+        IntoChecked,
+    },
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::{
+        Call,
+        Contract,
+        MemoryStorage,
+        Transactor,
+    },
+    storage::InterpreterStorage,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// The number of the transactions of each kind in a block.
+const TRANSFERS: usize = 16;
+const CALLS: usize = 16;
+const DEPLOYS: usize = 4;
+
+const GAS_LIMIT: u64 = 1_000_000;
+const AMOUNT: u64 = 1_000_000;
+
+/// A phase of the processing of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// The static checks and the signatures.
+    Check,
+    /// The predicates.
+    Predicate,
+    /// The execution by the interpreter.
+    Execute,
+    /// The commit of the changes into the storage.
+    Commit,
+}
+
+impl Phase {
+    const ALL: [Self; 4] = [Self::Check, Self::Predicate, Self::Execute, Self::Commit];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Check => "check",
+            Self::Predicate => "predicate",
+            Self::Execute => "execute",
+            Self::Commit => "commit",
+        }
+    }
+}
+
+/// A storage backend the blocks are executed against.
+trait Backend: Clone {
+    /// The storage of the interpreter.
+    type Storage: InterpreterStorage;
+
+    /// The name of the backend in the benchmark ids.
+    const NAME: &'static str;
+
+    /// The storage the next transaction is executed against.
+    fn storage(&mut self) -> &mut Self::Storage;
+
+    /// Commits the changes of the executed transaction.
+    fn commit(&mut self);
+
+    /// Discards the changes of the failed or the reverted transaction.
+    fn revert(&mut self);
+}
+
+/// Keeps the changes of the committed transactions in the transacted layer of the
+/// [`MemoryStorage`].
+#[derive(Clone, Default)]
+struct Transacted(MemoryStorage);
+
+impl Backend for Transacted {
+    type Storage = MemoryStorage;
+
+    const NAME: &'static str = "transacted";
+
+    fn storage(&mut self) -> &mut MemoryStorage {
+        &mut self.0
+    }
+
+    fn commit(&mut self) {
+        self.0.commit();
+    }
+
+    fn revert(&mut self) {
+        self.0.revert();
+    }
+}
+
+/// Persists the changes of every committed transaction, as if each transaction was
+/// a block.
+#[derive(Clone, Default)]
+struct Persisted(MemoryStorage);
+
+impl Backend for Persisted {
+    type Storage = MemoryStorage;
+
+    const NAME: &'static str = "persisted";
+
+    fn storage(&mut self) -> &mut MemoryStorage {
+        &mut self.0
+    }
+
+    fn commit(&mut self) {
+        self.0.commit();
+        self.0.persist();
+    }
+
+    fn revert(&mut self) {
+        self.0.rollback();
+    }
+}
+
+#[derive(Clone)]
+enum Tx {
+    Script(Script),
+    Create(Create),
+}
+
+enum CheckedTx {
+    Script(Checked<Script>),
+    Create(Checked<Create>),
+}
+
+/// The contract incrementing the counter in its storage.
+fn counter() -> Vec<u8> {
+    vec![
+        op::movi(0x12, Bytes32::LEN as u32),
+        op::aloc(0x12),
+        op::srw(0x10, 0x11, RegId::HP),
+        op::addi(0x10, 0x10, 1),
+        op::sww(RegId::HP, 0x11, 0x10),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn deploy(rng: &mut StdRng, bytecode: Vec<u8>) -> (Create, ContractId) {
+    let salt = rng.gen();
+    let slots: Vec<StorageSlot> = (0..4).map(|_| rng.gen()).collect();
+    let contract = Contract::from(bytecode.as_slice());
+    let state_root = Contract::initial_state_root(slots.iter());
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let tx = TransactionBuilder::create(Witness::from(bytecode), salt, slots)
+        .add_fee_input()
+        .add_contract_created()
+        .finalize();
+    (tx, contract_id)
+}
+
+fn transfer(rng: &mut StdRng, params: &ConsensusParameters) -> Script {
+    let base_asset_id = *params.base_asset_id();
+    let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let owner = Input::predicate_owner(&predicate);
+    let input = Input::coin_predicate(
+        rng.gen(),
+        owner,
+        AMOUNT,
+        base_asset_id,
+        TxPointer::default(),
+        0,
+        predicate,
+        vec![],
+    );
+    let mut tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .add_output(Output::coin(rng.gen(), AMOUNT / 2, base_asset_id))
+        .add_output(Output::change(owner, 0, base_asset_id))
+        .finalize();
+    tx.estimate_predicates(&CheckPredicateParams::from(params), MemoryInstance::new())
+        .expect("The predicate is valid");
+    tx
+}
+
+fn call(contract_id: ContractId) -> Script {
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    TransactionBuilder::script(script, script_data)
+        .script_gas_limit(GAS_LIMIT)
+        .add_fee_input()
+        .add_input(Input::contract(
+            Default::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed()))
+        .finalize()
+}
+
+/// Builds the storage with the called contract, and the block of interleaved
+/// transfers, calls and deployments.
+fn setup<B: Backend + Default>(params: &ConsensusParameters) -> (B, Vec<Tx>) {
+    let rng = &mut StdRng::seed_from_u64(2322);
+
+    let mut backend = B::default();
+    let (create, contract_id) = deploy(rng, counter());
+    let failed = run_block(
+        &mut backend,
+        vec![Tx::Create(create)],
+        params,
+        &mut |_, _| {},
+    );
+    assert_eq!(failed, 0, "The contract isn't deployed");
+
+    let mut block = Vec::new();
+    for i in 0..TRANSFERS.max(CALLS).max(DEPLOYS) {
+        if i < TRANSFERS {
+            block.push(Tx::Script(transfer(rng, params)));
+        }
+        if i < CALLS {
+            block.push(Tx::Script(call(contract_id)));
+        }
+        if i < DEPLOYS {
+            let bytecode = vec![op::noop(); 256]
+                .into_iter()
+                .chain([op::ret(RegId::ONE)])
+                .collect();
+            block.push(Tx::Create(deploy(rng, bytecode).0));
+        }
+    }
+
+    let failed = run_block(&mut backend.clone(), block.clone(), params, &mut |_, _| {});
+    assert_eq!(failed, 0, "The transactions of the block fail");
+
+    (backend, block)
+}
+
+fn timed<T>(
+    phase: Phase,
+    hook: &mut dyn FnMut(Phase, Duration),
+    f: impl FnOnce() -> T,
+) -> T {
+    let start = Instant::now();
+    let result = f();
+    hook(phase, start.elapsed());
+    result
+}
+
+/// Processes the block transaction by transaction, and reports the duration of
+/// every phase to the `hook`. The changes of the failed transactions are reverted.
+/// Returns the number of the failed transactions.
+fn run_block<B: Backend>(
+    backend: &mut B,
+    block: Vec<Tx>,
+    params: &ConsensusParameters,
+    hook: &mut dyn FnMut(Phase, Duration),
+) -> usize {
+    let height = Default::default();
+    let chain_id = params.chain_id();
+    let predicate_params = CheckPredicateParams::from(params);
+    let interpreter_params = InterpreterParams::new(0, params);
+    let mut failed = 0;
+
+    for tx in block {
+        let checked = timed(Phase::Check, hook, || match tx {
+            Tx::Script(tx) => CheckedTx::Script(
+                tx.into_checked_basic(height, params)
+                    .and_then(|tx| tx.check_signatures(&chain_id))
+                    .expect("The script is valid"),
+            ),
+            Tx::Create(tx) => CheckedTx::Create(
+                tx.into_checked_basic(height, params)
+                    .and_then(|tx| tx.check_signatures(&chain_id))
+                    .expect("The create is valid"),
+            ),
+        });
+
+        let checked = timed(Phase::Predicate, hook, || match checked {
+            CheckedTx::Script(tx) => CheckedTx::Script(
+                tx.check_predicates(&predicate_params, MemoryInstance::new())
+                    .expect("The predicates of the script are valid"),
+            ),
+            CheckedTx::Create(tx) => CheckedTx::Create(
+                tx.check_predicates(&predicate_params, MemoryInstance::new())
+                    .expect("The predicates of the create are valid"),
+            ),
+        });
+
+        let success = timed(Phase::Execute, hook, || {
+            let mut transactor = Transactor::<_, _, Script>::new(
+                MemoryInstance::new(),
+                backend.storage(),
+                interpreter_params.clone(),
+            );
+            match checked {
+                CheckedTx::Script(tx) => {
+                    let success = transactor.transact(tx).is_success();
+                    black_box(transactor.receipts());
+                    success
+                }
+                CheckedTx::Create(tx) => black_box(transactor.deploy(tx)).is_ok(),
+            }
+        });
+
+        timed(Phase::Commit, hook, || {
+            if success {
+                backend.commit();
+            } else {
+                backend.revert();
+            }
+        });
+        failed += usize::from(!success);
+    }
+
+    failed
+}
+
+fn throughput_of<B: Backend + Default>(c: &mut Criterion, params: &ConsensusParameters) {
+    let (backend, block) = setup::<B>(params);
+
+    let mut group = c.benchmark_group(format!("throughput/{}", B::NAME));
+    group.throughput(Throughput::Elements(block.len() as u64));
+
+    let phases = Phase::ALL.map(|phase| (phase.name(), Some(phase)));
+    for (name, measured) in phases.into_iter().chain([("total", None)]) {
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter_custom(|iters| {
+                let mut elapsed = Duration::ZERO;
+                for _ in 0..iters {
+                    let mut backend = backend.clone();
+                    let mut hook = |phase, duration| {
+                        if measured.map_or(true, |measured| measured == phase) {
+                            elapsed += duration;
+                        }
+                    };
+                    run_block(&mut backend, block.clone(), params, &mut hook);
+                }
+                elapsed
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn throughput(c: &mut Criterion) {
+    let params = ConsensusParameters::standard();
+    throughput_of::<Transacted>(c, &params);
+    throughput_of::<Persisted>(c, &params);
+}
+
+criterion_group!(benches, throughput);
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+//! See README.md for usage example
+
+use fuel_vm_fuzz::budgeted::{
+    decode,
+    encode,
+    opcode_seeds,
+};
+use std::{
+    fs,
+    path::PathBuf,
+};
+
+fn main() {
+    let output = std::env::args().nth(1).expect("no output path given");
+
+    for (opcode, data) in opcode_seeds() {
+        let encoded = encode(&data);
+        let decoded = decode(&encoded).expect("failed to decode");
+
+        if decoded != data {
+            println!("{:?}", data);
+            println!("{:?}", decoded);
+            panic!("mismatch")
+        }
+
+        fs::write(PathBuf::from(&output).join(format!("{opcode:?}")), &encoded)
+            .expect("failed to write file");
+    }
+}
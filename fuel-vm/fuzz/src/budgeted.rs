@@ -0,0 +1,254 @@
+//! Execution of arbitrary bytecode with an arbitrary gas schedule under an
+//! instruction budget, checking the invariants of the interpreter.
+
+use crate::{
+    split_by_separator,
+    MAGIC_VALUE_SEPARATOR,
+};
+use fuel_vm::{
+    error::{
+        ExecutionLimit,
+        InterpreterError,
+    },
+    fuel_asm::{
+        op,
+        Opcode,
+        RegId,
+    },
+    interpreter::{
+        ExecutionProgress,
+        InterpreterParams,
+        MemoryInstance,
+        ProgressWatcher,
+    },
+    prelude::*,
+    storage::MemoryStorage,
+    util::test_helpers::TestBuilder,
+};
+use std::sync::atomic::{
+    AtomicU64,
+    Ordering,
+};
+
+/// Maximum number of the instructions executed by a single input.
+pub const INSTRUCTION_BUDGET: u64 = 100_000;
+
+/// Gas limit of the executed script.
+const GAS_LIMIT: Word = 1_000_000;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct BudgetedFuzzData {
+    /// Big-endian words overriding the costs of the default gas schedule, in the
+    /// order of their fields. The schedule is left as is if there are no words.
+    pub gas_schedule: Vec<u8>,
+    pub program: Vec<u8>,
+}
+
+pub fn encode(data: &BudgetedFuzzData) -> Vec<u8> {
+    data.gas_schedule
+        .iter()
+        .copied()
+        .chain(MAGIC_VALUE_SEPARATOR)
+        .chain(data.program.iter().copied())
+        .collect()
+}
+
+pub fn decode(data: &[u8]) -> Option<BudgetedFuzzData> {
+    let x = split_by_separator(data, &MAGIC_VALUE_SEPARATOR);
+    if x.len() != 2 {
+        return None;
+    }
+    Some(BudgetedFuzzData {
+        gas_schedule: data[x[0].clone()].to_vec(),
+        program: data[x[1].clone()].to_vec(),
+    })
+}
+
+/// Builds the gas schedule by overriding the costs of the default one with the
+/// `words`, repeated until every cost is overridden.
+///
+/// `units_per_gas` of the dependent costs must be non-zero, so zero is replaced with
+/// one there.
+pub fn gas_costs(words: &[u8]) -> GasCosts {
+    let words: Vec<Word> = words
+        .chunks_exact(8)
+        .map(|word| Word::from_be_bytes(word.try_into().expect("The chunk is a word")))
+        .collect();
+    if words.is_empty() {
+        return GasCosts::default();
+    }
+
+    let mut schedule = serde_json::to_value(GasCostsValues::default())
+        .expect("The gas schedule is serializable");
+    let mut words = words.into_iter().cycle();
+    override_costs(&mut schedule, None, &mut words);
+
+    GasCosts::new(
+        serde_json::from_value(schedule)
+            .expect("The layout of the schedule is unchanged"),
+    )
+}
+
+fn override_costs(
+    value: &mut serde_json::Value,
+    key: Option<&str>,
+    words: &mut impl Iterator<Item = Word>,
+) {
+    match value {
+        serde_json::Value::Number(_) => {
+            let mut word = words.next().expect("The words are repeated");
+            if key == Some("units_per_gas") {
+                word = word.max(1);
+            }
+            *value = word.into();
+        }
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                override_costs(field, Some(key), words);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items.iter_mut() {
+                override_costs(item, None, words);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Panics if the remaining gas of the transaction, `$ggas`, ever grows.
+#[derive(Debug)]
+struct GasIsMonotonic {
+    last: AtomicU64,
+}
+
+impl ProgressWatcher for GasIsMonotonic {
+    fn on_progress(&self, progress: &ExecutionProgress) {
+        let last = self.last.swap(progress.remaining_gas, Ordering::Relaxed);
+        assert!(
+            progress.remaining_gas <= last,
+            "$ggas grew from {last} to {} after {} instructions",
+            progress.remaining_gas,
+            progress.retired_instructions,
+        );
+    }
+}
+
+pub struct BudgetedResult {
+    /// `false` if the script reverted, or the execution ran out of the budget.
+    pub success: bool,
+    /// `None` if the execution ran out of the budget.
+    pub gas_used: Option<Word>,
+}
+
+/// Executes the program, and panics if an invariant is broken:
+/// - The interpreter doesn't report a bug, e.g. an out-of-bounds memory access.
+/// - `$ggas` never grows, checked after every instruction.
+/// - The script doesn't use more gas than its limit.
+///
+/// Returns `None` if the transaction is invalid, e.g. the gas schedule makes its
+/// fee overflow.
+pub fn execute(data: BudgetedFuzzData) -> Option<BudgetedResult> {
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_gas_costs(gas_costs(&data.gas_schedule));
+
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    let max_program_length = 2usize.pow(18)
+        - test_context.get_tx_params().tx_offset()
+        - <Script as field::Script>::script_offset_static();
+    let program = &data.program[..max_program_length.min(data.program.len())];
+
+    let tx = test_context
+        .start_script_bytes(program.to_vec(), vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .gas_price(0)
+        .coin_input(base_asset_id, 1000)
+        .change_output(base_asset_id)
+        .build()
+        .transaction()
+        .clone()
+        .into_checked_basic(Default::default(), &consensus_params)
+        .ok()?;
+
+    let mut interpreter_params = InterpreterParams::new(0, &consensus_params);
+    interpreter_params.max_instructions = Some(INSTRUCTION_BUDGET);
+    interpreter_params.progress_stride = Some(1);
+    interpreter_params.call_frame_integrity_checks = true;
+
+    let mut interpreter = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        interpreter_params,
+    );
+    interpreter.with_progress_watcher(GasIsMonotonic {
+        last: AtomicU64::new(Word::MAX),
+    });
+    let mut transactor = Transactor::from(interpreter);
+    transactor.transact(tx);
+
+    match transactor.error() {
+        None => {}
+        Some(InterpreterError::Bug(bug)) => {
+            panic!("The interpreter reported a bug: {bug}")
+        }
+        Some(InterpreterError::ExecutionLimitExceeded(ExecutionLimit::Instructions)) => {
+            return Some(BudgetedResult {
+                success: false,
+                gas_used: None,
+            })
+        }
+        Some(_) => return None,
+    }
+
+    let gas_used = transactor
+        .receipts()
+        .expect("The execution succeeded")
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .expect("The script result is always the last receipt");
+    assert!(
+        gas_used <= GAS_LIMIT,
+        "The script used {gas_used} gas over its limit of {GAS_LIMIT}"
+    );
+
+    Some(BudgetedResult {
+        success: !transactor.is_reverted(),
+        gas_used: Some(gas_used),
+    })
+}
+
+/// Seeds exercising every opcode, arranged like the fixtures of the opcode tests: the
+/// operands are in the registers `0x10..=0x13`, and `0x10` and `0x11` point to the
+/// allocated heap.
+pub fn opcode_seeds() -> impl Iterator<Item = (Opcode, BudgetedFuzzData)> {
+    (0..=u8::MAX)
+        .filter_map(|byte| Opcode::try_from(byte).ok())
+        .map(|opcode| {
+            let registers: u32 = (0x10 << 18) | (0x11 << 12) | (0x12 << 6) | 0x13;
+            let [_, a, b, c] = registers.to_be_bytes();
+
+            let program = [
+                op::movi(0x20, 1024),
+                op::aloc(0x20),
+                op::addi(0x10, RegId::HP, 0),
+                op::addi(0x11, RegId::HP, 64),
+                op::movi(0x12, 32),
+                op::movi(0x13, 8),
+            ]
+            .into_iter()
+            .flat_map(Instruction::to_bytes)
+            .chain([opcode as u8, a, b, c])
+            .chain(op::ret(0x10).to_bytes())
+            .collect();
+
+            let data = BudgetedFuzzData {
+                gas_schedule: vec![],
+                program,
+            };
+            (opcode, data)
+        })
+}
@@ -9,6 +9,8 @@ use fuel_vm::{fuel_asm, script_with_data_offset};
 use fuel_vm::fuel_types::canonical::Serialize;
 use std::ops::Range;
 
+pub mod budgeted;
+
 /// Magic value used as separator between fuzz data components in corpus files.
 const MAGIC_VALUE_SEPARATOR: [u8; 8] = [0x00u8, 0xAD, 0xBE, 0xEF, 0x55, 0x66, 0xCE, 0xAA];
 
@@ -0,0 +1,87 @@
+//! Generates a corpus of seeded test vectors for alternative client implementations.
+//!
+//! ```text
+//! fuel-vm-test-vectors [--seed <u64>] [--count <u32>] [--output <path>]
+//! ```
+//!
+//! The corpus is written to the standard output unless the `--output` is specified. The
+//! format of the corpus is described by the `fuel_vm::test_vectors` module.
+
+use fuel_vm::test_vectors::write_corpus;
+use std::{
+    fs::File,
+    io::{
+        self,
+        BufWriter,
+        Write,
+    },
+    process::ExitCode,
+};
+
+const USAGE: &str =
+    "Usage: fuel-vm-test-vectors [--seed <u64>] [--count <u32>] [--output <path>]";
+
+struct Args {
+    seed: u64,
+    count: u32,
+    output: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        seed: 0,
+        count: 100,
+        output: None,
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or(format!("Missing the value of `{arg}`"));
+        match arg.as_str() {
+            "--seed" => {
+                args.seed = value()?
+                    .parse()
+                    .map_err(|e| format!("Invalid `--seed`: {e}"))?
+            }
+            "--count" => {
+                args.count = value()?
+                    .parse()
+                    .map_err(|e| format!("Invalid `--count`: {e}"))?
+            }
+            "--output" => args.output = Some(value()?),
+            _ => return Err(format!("Unknown argument `{arg}`")),
+        }
+    }
+
+    Ok(args)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match &args.output {
+        Some(path) => File::create(path).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            write_corpus(&mut writer, args.seed, args.count)?;
+            writer.flush()
+        }),
+        None => {
+            let mut writer = BufWriter::new(io::stdout().lock());
+            write_corpus(&mut writer, args.seed, args.count).and_then(|_| writer.flush())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to write the corpus: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Tree of the contract calls made by a script, with the gas used by each of them.
+//!
+//! The tree is derived from the `Call` and `Return`/`ReturnData` receipts. A call
+//! that doesn't return was interrupted by a `Revert` or a `Panic`, and all the
+//! calls enclosing it end with it. The gas used by a call is counted from the
+//! global gas register, so it includes the gas used by the nested calls, but not
+//! the cost of the `CALL` instruction itself, which is charged to the caller.
+
+use alloc::vec::Vec;
+
+use crate::{
+    consts::VM_REGISTER_COUNT,
+    interpreter::Interpreter,
+};
+use fuel_asm::RegId;
+use fuel_tx::Receipt;
+use fuel_types::{
+    ContractId,
+    Word,
+};
+
+/// How a call ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CallOutcome {
+    /// The called contract returned with `RET` or `RETD`. Its state changes are
+    /// still reverted if the script doesn't succeed.
+    Returned,
+    /// The call, or a call nested in it, reverted.
+    Reverted,
+    /// The call, or a call nested in it, panicked.
+    Panicked,
+}
+
+/// A contract call made during the execution.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CallNode {
+    /// The called contract.
+    pub contract_id: ContractId,
+    /// Depth of the call, `1` for the calls made by the script.
+    pub depth: usize,
+    /// Gas forwarded to the call.
+    pub gas_forwarded: Word,
+    /// Gas used by the call, including the nested calls.
+    pub gas_used: Word,
+    /// How the call ended.
+    pub outcome: CallOutcome,
+    /// Index of the `Call` receipt of the call.
+    pub receipt_index: usize,
+    /// The calls made by the called contract, in the order of the execution.
+    pub children: Vec<CallNode>,
+}
+
+/// The contract calls made by a script, see the [module](self) docs.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CallTree {
+    calls: Vec<CallNode>,
+}
+
+impl CallTree {
+    /// Derives the call tree of the last execution of the interpreter.
+    pub(crate) fn from_vm<M, S, Tx, Ecal>(vm: &Interpreter<M, S, Tx, Ecal>) -> Self {
+        let mut registers = [0; VM_REGISTER_COUNT];
+        registers.copy_from_slice(vm.registers());
+        let unreturned_frames_ggas = vm
+            .call_stack()
+            .iter()
+            .map(|frame| frame.registers()[RegId::GGAS]);
+
+        Self::from_receipts(
+            vm.receipts(),
+            vm.returned_frames_gas(),
+            unreturned_frames_ggas,
+            registers[RegId::GGAS],
+        )
+    }
+
+    /// Derives the call tree from the receipts, the gas used by the returned calls
+    /// in the order of the returns, and the global gas at the entry of the calls
+    /// that didn't return.
+    fn from_receipts(
+        receipts: &[Receipt],
+        returned_gas: &[Word],
+        unreturned_ggas: impl Iterator<Item = Word>,
+        ggas: Word,
+    ) -> Self {
+        let mut calls = Vec::new();
+        let mut stack: Vec<CallNode> = Vec::new();
+        let mut returned_gas = returned_gas.iter().copied();
+        let mut outcome = CallOutcome::Returned;
+
+        for (receipt_index, receipt) in receipts.iter().enumerate() {
+            match receipt {
+                Receipt::Call { to, gas, .. } => stack.push(CallNode {
+                    contract_id: *to,
+                    depth: stack.len().saturating_add(1),
+                    gas_forwarded: *gas,
+                    gas_used: 0,
+                    outcome: CallOutcome::Returned,
+                    receipt_index,
+                    children: Vec::new(),
+                }),
+                Receipt::Return { .. } | Receipt::ReturnData { .. } => {
+                    if let Some(mut node) = stack.pop() {
+                        node.gas_used = returned_gas.next().unwrap_or_default();
+                        Self::attach(&mut stack, &mut calls, node);
+                    }
+                }
+                Receipt::Revert { .. } => outcome = CallOutcome::Reverted,
+                Receipt::Panic { .. } => outcome = CallOutcome::Panicked,
+                _ => {}
+            }
+        }
+
+        // The calls left on the stack were interrupted, and used all the gas since
+        // their entry.
+        let mut unreturned_ggas = unreturned_ggas.fuse();
+        for node in stack.iter_mut() {
+            let entry_ggas = unreturned_ggas.next().unwrap_or(ggas);
+            node.gas_used = entry_ggas.saturating_sub(ggas);
+            node.outcome = outcome;
+        }
+        while let Some(node) = stack.pop() {
+            Self::attach(&mut stack, &mut calls, node);
+        }
+
+        Self { calls }
+    }
+
+    fn attach(stack: &mut [CallNode], calls: &mut Vec<CallNode>, node: CallNode) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => calls.push(node),
+        }
+    }
+
+    /// The calls made by the script, in the order of the execution.
+    pub fn calls(&self) -> &[CallNode] {
+        &self.calls
+    }
+
+    /// Iterates over all the calls depth-first, in the order of the execution.
+    pub fn iter(&self) -> impl Iterator<Item = &CallNode> {
+        let mut stack: Vec<&CallNode> = self.calls.iter().rev().collect();
+        core::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Returns `true` if the script made no calls.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Expose the calls made by the script.
+    pub fn into_inner(self) -> Vec<CallNode> {
+        self.calls
+    }
+}
@@ -0,0 +1,524 @@
+//! C API for decoding and checking transactions and estimating their predicates.
+//!
+//! Lets the SDKs of other languages, e.g. Swift or Kotlin, reuse the consensus rules
+//! instead of reimplementing them. The library is built with
+//! `cargo rustc -p fuel-vm --features capi --crate-type=cdylib` (or `staticlib`).
+//!
+//! The transactions are passed in the canonical encoding, and the consensus
+//! parameters as JSON. Every function returns a [`FuelStatus`], whose values are a
+//! part of the API and must never be changed or reused.
+
+#![allow(unsafe_code)]
+
+use crate::{
+    checked_transaction::{
+        CheckError,
+        CheckPredicateParams,
+        EstimatePredicates,
+        IntoChecked,
+    },
+    interpreter::MemoryInstance,
+};
+
+use alloc::vec::Vec;
+use core::{
+    ffi::c_char,
+    slice,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Transaction,
+    UniqueIdentifier,
+};
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
+    BlockHeight,
+    Bytes32,
+};
+use std::panic::{
+    catch_unwind,
+    AssertUnwindSafe,
+};
+
+/// The outcome of a C API call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuelStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument is null.
+    NullPointer = 1,
+    /// The transaction isn't a valid canonical encoding of a transaction.
+    InvalidTransaction = 2,
+    /// The consensus parameters aren't a valid JSON of the consensus parameters.
+    InvalidConsensusParameters = 3,
+    /// The transaction doesn't pass the validity rules.
+    Validity = 4,
+    /// The predicate verification failed.
+    PredicateVerification = 5,
+    /// The max fee of the transaction is lower than required.
+    InsufficientMaxFee = 6,
+    /// The output buffer is too small. The required length is written to the length
+    /// output argument.
+    BufferTooSmall = 7,
+    /// The library panicked. Indicates a bug.
+    Internal = 8,
+}
+
+impl FuelStatus {
+    /// The status with the numeric `code`, if any.
+    pub const fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Ok),
+            1 => Some(Self::NullPointer),
+            2 => Some(Self::InvalidTransaction),
+            3 => Some(Self::InvalidConsensusParameters),
+            4 => Some(Self::Validity),
+            5 => Some(Self::PredicateVerification),
+            6 => Some(Self::InsufficientMaxFee),
+            7 => Some(Self::BufferTooSmall),
+            8 => Some(Self::Internal),
+            _ => None,
+        }
+    }
+
+    /// The nul-terminated description of the status.
+    pub const fn message(self) -> &'static core::ffi::CStr {
+        match self {
+            Self::Ok => c"ok",
+            Self::NullPointer => c"a required pointer argument is null",
+            Self::InvalidTransaction => c"the transaction encoding is invalid",
+            Self::InvalidConsensusParameters => {
+                c"the consensus parameters JSON is invalid"
+            }
+            Self::Validity => c"the transaction doesn't pass the validity rules",
+            Self::PredicateVerification => c"the predicate verification failed",
+            Self::InsufficientMaxFee => c"the max fee of the transaction is too low",
+            Self::BufferTooSmall => c"the output buffer is too small",
+            Self::Internal => c"internal error",
+        }
+    }
+}
+
+impl From<CheckError> for FuelStatus {
+    fn from(error: CheckError) -> Self {
+        match error {
+            CheckError::Validity(_) => Self::Validity,
+            CheckError::PredicateVerificationFailed(_) => Self::PredicateVerification,
+            CheckError::InsufficientMaxFee { .. } => Self::InsufficientMaxFee,
+        }
+    }
+}
+
+/// Decodes the canonically encoded transaction.
+pub fn decode_transaction(mut bytes: &[u8]) -> Result<Transaction, FuelStatus> {
+    let tx =
+        Transaction::decode(&mut bytes).map_err(|_| FuelStatus::InvalidTransaction)?;
+    if !bytes.is_empty() {
+        return Err(FuelStatus::InvalidTransaction)
+    }
+    Ok(tx)
+}
+
+/// Decodes the canonically encoded transaction into its JSON.
+pub fn transaction_to_json(tx: &[u8]) -> Result<Vec<u8>, FuelStatus> {
+    let tx = decode_transaction(tx)?;
+    serde_json::to_vec(&tx).map_err(|_| FuelStatus::Internal)
+}
+
+/// Decodes the JSON of the consensus parameters.
+pub fn decode_consensus_parameters(
+    json: &[u8],
+) -> Result<ConsensusParameters, FuelStatus> {
+    serde_json::from_slice(json).map_err(|_| FuelStatus::InvalidConsensusParameters)
+}
+
+/// Checks the transaction, including its signatures and predicates, at the block
+/// height. Returns the id of the transaction.
+pub fn check_transaction(
+    tx: &[u8],
+    consensus_params: &[u8],
+    block_height: BlockHeight,
+) -> Result<Bytes32, FuelStatus> {
+    let tx = decode_transaction(tx)?;
+    let consensus_params = decode_consensus_parameters(consensus_params)?;
+    let id = tx.id(&consensus_params.chain_id());
+    tx.into_checked(block_height, &consensus_params)?;
+    Ok(id)
+}
+
+/// Estimates the gas used by the predicates of the transaction. Returns the
+/// canonical encoding of the transaction with the estimated predicates.
+pub fn estimate_predicates(
+    tx: &[u8],
+    consensus_params: &[u8],
+) -> Result<Vec<u8>, FuelStatus> {
+    let mut tx = decode_transaction(tx)?;
+    let consensus_params = decode_consensus_parameters(consensus_params)?;
+    tx.estimate_predicates(
+        &CheckPredicateParams::from(&consensus_params),
+        MemoryInstance::new(),
+    )?;
+    Ok(tx.to_bytes())
+}
+
+/// Runs `f` and converts its panics into [`FuelStatus::Internal`], as unwinding
+/// across the C boundary is undefined behavior.
+fn guarded(f: impl FnOnce() -> Result<(), FuelStatus>) -> FuelStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => FuelStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => FuelStatus::Internal,
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be null or valid for reads of `len` bytes.
+unsafe fn input<'a>(ptr: *const u8, len: usize) -> Result<&'a [u8], FuelStatus> {
+    if ptr.is_null() {
+        return Err(FuelStatus::NullPointer)
+    }
+    Ok(unsafe { slice::from_raw_parts(ptr, len) })
+}
+
+/// Writes `bytes` to the `out` buffer of `out_capacity` bytes, and their length to
+/// `out_len`. Returns [`FuelStatus::BufferTooSmall`] without writing to `out` if the
+/// bytes don't fit.
+///
+/// # Safety
+///
+/// - `out` must be valid for writes of `out_capacity` bytes.
+/// - `out_len` must be valid for writes of a `usize`.
+unsafe fn output(
+    bytes: &[u8],
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> Result<(), FuelStatus> {
+    unsafe { out_len.write(bytes.len()) };
+    if bytes.len() > out_capacity {
+        return Err(FuelStatus::BufferTooSmall)
+    }
+    let out = unsafe { slice::from_raw_parts_mut(out, bytes.len()) };
+    out.copy_from_slice(bytes);
+    Ok(())
+}
+
+/// Returns the nul-terminated description of the `status` code, or of an unknown
+/// status if the code isn't a [`FuelStatus`]. The string is static and must not be
+/// freed.
+// The unit tests link the crate twice, as it is its own dev-dependency, so the
+// symbols are only exported outside of the tests.
+#[cfg_attr(not(test), no_mangle)]
+pub extern "C" fn fuel_status_message(status: u32) -> *const c_char {
+    FuelStatus::from_code(status)
+        .map_or(c"unknown status", FuelStatus::message)
+        .as_ptr()
+}
+
+/// Decodes the canonically encoded transaction, and writes its JSON to `out`.
+///
+/// The length of the JSON is written to `out_len`. If it exceeds `out_capacity`,
+/// nothing is written to `out` and [`FuelStatus::BufferTooSmall`] is returned, so
+/// the call can be repeated with a large enough buffer.
+///
+/// # Safety
+///
+/// - `tx` must be valid for reads of `tx_len` bytes of the canonical encoding.
+/// - `out` must be valid for writes of `out_capacity` bytes.
+/// - `out_len` must be valid for writes of a `usize`.
+#[cfg_attr(not(test), no_mangle)]
+pub unsafe extern "C" fn fuel_transaction_decode(
+    tx: *const u8,
+    tx_len: usize,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> FuelStatus {
+    guarded(|| {
+        let tx = unsafe { input(tx, tx_len) }?;
+        if out.is_null() || out_len.is_null() {
+            return Err(FuelStatus::NullPointer)
+        }
+        let json = transaction_to_json(tx)?;
+        unsafe { output(&json, out, out_capacity, out_len) }
+    })
+}
+
+/// Checks the transaction, including its signatures and predicates, at the
+/// `block_height`, and writes its 32 bytes id to `id_out`.
+///
+/// # Safety
+///
+/// - `tx` must be valid for reads of `tx_len` bytes of the canonical encoding.
+/// - `params` must be valid for reads of `params_len` bytes of the JSON of the consensus
+///   parameters.
+/// - `id_out` must be null or valid for writes of 32 bytes.
+#[cfg_attr(not(test), no_mangle)]
+pub unsafe extern "C" fn fuel_transaction_check(
+    tx: *const u8,
+    tx_len: usize,
+    params: *const u8,
+    params_len: usize,
+    block_height: u32,
+    id_out: *mut u8,
+) -> FuelStatus {
+    guarded(|| {
+        let tx = unsafe { input(tx, tx_len) }?;
+        let params = unsafe { input(params, params_len) }?;
+        let id = check_transaction(tx, params, block_height.into())?;
+        if !id_out.is_null() {
+            let id_out = unsafe { slice::from_raw_parts_mut(id_out, Bytes32::LEN) };
+            id_out.copy_from_slice(id.as_ref());
+        }
+        Ok(())
+    })
+}
+
+/// Estimates the gas used by the predicates of the transaction, and writes the
+/// canonical encoding of the estimated transaction to `out`.
+///
+/// The length of the encoding is written to `out_len`. If it exceeds
+/// `out_capacity`, nothing is written to `out` and [`FuelStatus::BufferTooSmall`]
+/// is returned, so the call can be repeated with a large enough buffer.
+///
+/// # Safety
+///
+/// - `tx` must be valid for reads of `tx_len` bytes of the canonical encoding.
+/// - `params` must be valid for reads of `params_len` bytes of the JSON of the consensus
+///   parameters.
+/// - `out` must be valid for writes of `out_capacity` bytes.
+/// - `out_len` must be valid for writes of a `usize`.
+#[cfg_attr(not(test), no_mangle)]
+pub unsafe extern "C" fn fuel_transaction_estimate_predicates(
+    tx: *const u8,
+    tx_len: usize,
+    params: *const u8,
+    params_len: usize,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> FuelStatus {
+    guarded(|| {
+        let tx = unsafe { input(tx, tx_len) }?;
+        let params = unsafe { input(params, params_len) }?;
+        if out.is_null() || out_len.is_null() {
+            return Err(FuelStatus::NullPointer)
+        }
+        let estimated = estimate_predicates(tx, params)?;
+        unsafe { output(&estimated, out, out_capacity, out_len) }
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_asm::{
+        op,
+        RegId,
+    };
+    use fuel_tx::{
+        Input,
+        TransactionBuilder,
+        TxPointer,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn params() -> (ConsensusParameters, Vec<u8>) {
+        let params = ConsensusParameters::standard();
+        let json = serde_json::to_vec(&params).unwrap();
+        (params, json)
+    }
+
+    fn predicate_tx(params: &ConsensusParameters) -> Transaction {
+        let rng = &mut StdRng::seed_from_u64(2322);
+        let predicate: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1_000)
+            .add_input(Input::coin_predicate(
+                rng.gen(),
+                owner,
+                1_000,
+                *params.base_asset_id(),
+                TxPointer::default(),
+                0,
+                predicate,
+                vec![],
+            ))
+            .finalize_as_transaction()
+    }
+
+    #[test]
+    fn estimate_predicates__output_passes_check() {
+        let (params, json) = params();
+        let tx = predicate_tx(&params).to_bytes();
+
+        let mut out = vec![0; tx.len()];
+        let mut out_len = 0;
+        let status = unsafe {
+            fuel_transaction_estimate_predicates(
+                tx.as_ptr(),
+                tx.len(),
+                json.as_ptr(),
+                json.len(),
+                out.as_mut_ptr(),
+                out.len(),
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, FuelStatus::Ok);
+        assert_eq!(out_len, tx.len());
+
+        let mut id = [0u8; 32];
+        let status = unsafe {
+            fuel_transaction_check(
+                out.as_ptr(),
+                out_len,
+                json.as_ptr(),
+                json.len(),
+                0,
+                id.as_mut_ptr(),
+            )
+        };
+        assert_eq!(status, FuelStatus::Ok);
+        let expected = decode_transaction(&out).unwrap().id(&params.chain_id());
+        assert_eq!(Bytes32::from(id), expected);
+    }
+
+    #[test]
+    fn check__not_estimated_predicate_fails_verification() {
+        let (params, json) = params();
+        let tx = predicate_tx(&params).to_bytes();
+
+        let status = unsafe {
+            fuel_transaction_check(
+                tx.as_ptr(),
+                tx.len(),
+                json.as_ptr(),
+                json.len(),
+                0,
+                core::ptr::null_mut(),
+            )
+        };
+
+        assert_eq!(status, FuelStatus::PredicateVerification);
+    }
+
+    #[test]
+    fn estimate_predicates__small_buffer_reports_required_length() {
+        let (params, json) = params();
+        let tx = predicate_tx(&params).to_bytes();
+
+        let mut out_len = 0;
+        let status = unsafe {
+            fuel_transaction_estimate_predicates(
+                tx.as_ptr(),
+                tx.len(),
+                json.as_ptr(),
+                json.len(),
+                [0u8; 1].as_mut_ptr(),
+                1,
+                &mut out_len,
+            )
+        };
+
+        assert_eq!(status, FuelStatus::BufferTooSmall);
+        assert_eq!(out_len, tx.len());
+    }
+
+    #[test]
+    fn check__invalid_inputs_are_reported() {
+        let (params, json) = params();
+        let tx = predicate_tx(&params).to_bytes();
+
+        assert_eq!(
+            check_transaction(&tx[..tx.len() - 1], &json, 0.into()),
+            Err(FuelStatus::InvalidTransaction)
+        );
+        assert_eq!(
+            check_transaction(&tx, b"{}", 0.into()),
+            Err(FuelStatus::InvalidConsensusParameters)
+        );
+        let status = unsafe {
+            fuel_transaction_check(
+                core::ptr::null(),
+                0,
+                json.as_ptr(),
+                json.len(),
+                0,
+                core::ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, FuelStatus::NullPointer);
+    }
+
+    #[test]
+    fn decode__writes_the_json_of_the_transaction() {
+        let (params, _) = params();
+        let tx = predicate_tx(&params);
+        let bytes = tx.to_bytes();
+
+        let mut out_len = 0;
+        let status = unsafe {
+            fuel_transaction_decode(
+                bytes.as_ptr(),
+                bytes.len(),
+                [0u8; 1].as_mut_ptr(),
+                1,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, FuelStatus::BufferTooSmall);
+
+        let mut out = vec![0; out_len];
+        let status = unsafe {
+            fuel_transaction_decode(
+                bytes.as_ptr(),
+                bytes.len(),
+                out.as_mut_ptr(),
+                out.len(),
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, FuelStatus::Ok);
+        let decoded: Transaction = serde_json::from_slice(&out[..out_len]).unwrap();
+        assert_eq!(decoded, tx);
+    }
+
+    #[test]
+    fn status_message__is_nul_terminated() {
+        let message = unsafe {
+            core::ffi::CStr::from_ptr(fuel_status_message(FuelStatus::Ok as u32))
+        };
+
+        assert_eq!(message.to_str(), Ok("ok"));
+    }
+
+    #[test]
+    fn status_message__unknown_code_is_described() {
+        for code in [9, u32::MAX] {
+            let message = unsafe { core::ffi::CStr::from_ptr(fuel_status_message(code)) };
+
+            assert_eq!(message.to_str(), Ok("unknown status"));
+        }
+    }
+
+    #[test]
+    fn status__codes_roundtrip() {
+        for code in 0..=8 {
+            let status = FuelStatus::from_code(code).unwrap();
+
+            assert_eq!(status as u32, code);
+        }
+    }
+}
@@ -46,6 +46,7 @@ use crate::{
     interpreter::{
         Memory,
         MemoryInstance,
+        TxMemoryLayout,
     },
     pool::VmMemoryPool,
     prelude::*,
@@ -122,6 +123,10 @@ impl<Tx: IntoChecked> Checked<Tx> {
     }
 
     /// Performs check of signatures, if not yet done.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "check_signatures", level = "debug", skip_all)
+    )]
     pub fn check_signatures(mut self, chain_id: &ChainId) -> Result<Self, CheckError> {
         if !self.checks_bitmask.contains(Checks::Signatures) {
             self.transaction.check_signatures(chain_id)?;
@@ -310,6 +315,15 @@ pub trait IntoChecked: FormatValidityChecks + Sized {
 
     /// Returns transaction that passed all `Checks` accepting reusable memory
     /// to run predicates.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "check_transaction",
+            level = "debug",
+            skip_all,
+            fields(%block_height)
+        )
+    )]
     fn into_checked_reusable_memory(
         self,
         block_height: BlockHeight,
@@ -350,7 +364,12 @@ pub struct CheckPredicateParams {
     pub contract_max_size: u64,
     /// Maximum length of the message data
     pub max_message_data_length: u64,
-    /// Offset of the transaction data in the memory
+    /// Maximum number of messages sent by the transaction
+    pub max_message_outputs: u16,
+    /// Maximum total length of the data of the messages sent by the transaction
+    pub max_message_outputs_data_length: u64,
+    /// Offset of the transaction data in the memory. The predicates are executed
+    /// with [`TxMemoryLayout::LATEST`].
     pub tx_offset: usize,
     /// Fee parameters
     pub fee_params: FeeParameters,
@@ -381,7 +400,11 @@ impl From<&ConsensusParameters> for CheckPredicateParams {
             max_inputs: value.tx_params().max_inputs(),
             contract_max_size: value.contract_params().contract_max_size(),
             max_message_data_length: value.predicate_params().max_message_data_length(),
-            tx_offset: value.tx_params().tx_offset(),
+            max_message_outputs: value.tx_params().max_message_outputs(),
+            max_message_outputs_data_length: value
+                .tx_params()
+                .max_message_outputs_data_length(),
+            tx_offset: TxMemoryLayout::LATEST.tx_offset(value.tx_params().max_inputs()),
             fee_params: *(value.fee_params()),
             base_asset_id: *value.base_asset_id(),
         }
@@ -1011,13 +1034,14 @@ mod tests {
             .expect_err("Expected valid transaction");
 
         // then
-        assert!(matches!(
+        assert_eq!(
             err,
-            CheckError::Validity(ValidityError::InsufficientFeeAmount {
-                expected: _,
-                provided: 0
+            CheckError::Validity(ValidityError::RetryableAmountCannotCoverFee {
+                expected: max_fee,
+                provided: 0,
+                retryable: input_amount,
             })
-        ));
+        );
     }
 
     #[test]
@@ -1053,13 +1077,136 @@ mod tests {
             .expect_err("Expected valid transaction");
 
         // then
-        assert!(matches!(
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::RetryableAmountCannotCoverFee {
+                expected: max_fee,
+                provided: 0,
+                retryable: input_amount,
+            })
+        );
+    }
+
+    #[test]
+    fn into_checked__message_data_and_coin_together_do_not_cover_fees() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // given
+        let coin_amount = 50;
+        let message_amount = 100;
+        let max_fee = 100;
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .max_fee_limit(max_fee)
+            .add_unsigned_message_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                message_amount,
+                vec![0xff; 10],
+            )
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                coin_amount,
+                AssetId::BASE,
+                rng.gen(),
+            )
+            .finalize();
+
+        // when
+        let err = tx
+            .into_checked(Default::default(), &ConsensusParameters::standard())
+            .expect_err("Expected invalid transaction");
+
+        // then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::RetryableAmountCannotCoverFee {
+                expected: max_fee,
+                provided: coin_amount,
+                retryable: message_amount,
+            })
+        );
+    }
+
+    #[test]
+    fn into_checked__insufficient_fee_is_reported_if_message_data_is_not_enough() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // given
+        let coin_amount = 50;
+        let message_amount = 10;
+        let max_fee = 100;
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .max_fee_limit(max_fee)
+            .add_unsigned_message_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                message_amount,
+                vec![0xff; 10],
+            )
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                coin_amount,
+                AssetId::BASE,
+                rng.gen(),
+            )
+            .finalize();
+
+        // when
+        let err = tx
+            .into_checked(Default::default(), &ConsensusParameters::standard())
+            .expect_err("Expected invalid transaction");
+
+        // then
+        assert_eq!(
             err,
             CheckError::Validity(ValidityError::InsufficientFeeAmount {
-                expected: _,
-                provided: 0
+                expected: max_fee,
+                provided: coin_amount,
             })
-        ));
+        );
+    }
+
+    #[test]
+    fn into_checked__message_coin_pays_fees_and_message_data_stays_retryable() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // given
+        let message_coin_amount = 100;
+        let message_data_amount = 30;
+        let max_fee = 100;
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .max_fee_limit(max_fee)
+            .add_unsigned_message_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                message_coin_amount,
+                vec![],
+            )
+            .add_unsigned_message_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                message_data_amount,
+                vec![0xff; 10],
+            )
+            .finalize();
+
+        // when
+        let checked = tx
+            .into_checked(Default::default(), &ConsensusParameters::standard())
+            .expect("Expected valid transaction");
+
+        // then
+        assert_eq!(
+            checked.metadata().non_retryable_balances[&AssetId::BASE],
+            message_coin_amount - max_fee
+        );
+        assert_eq!(*checked.metadata().retryable_balance, message_data_amount);
     }
 
     // use quickcheck to fuzz any rounding or precision errors in the max fee w/ coin
@@ -1291,14 +1438,14 @@ mod tests {
             .script_gas_limit(gas_limit)
             // Set up 3 signed inputs
             .add_unsigned_message_input(
-                secret,
+                secret.clone(),
                 rng.gen(),
                 rng.gen(),
                 rng.gen::<u32>() as u64,
                 vec![],
             )
             .add_unsigned_message_input(
-                secret,
+                secret.clone(),
                 rng.gen(),
                 rng.gen(),
                 rng.gen::<u32>() as u64,
@@ -1847,7 +1994,7 @@ mod tests {
             .script_gas_limit(100)
             // base asset
             .add_unsigned_coin_input(
-                secret,
+                secret.clone(),
                 rng.gen(),
                 input_amount,
                 AssetId::default(),
@@ -39,7 +39,12 @@ where
         .policies()
         .get(PolicyType::MaxFee)
         .ok_or(ValidityError::TransactionMaxFeeNotSet)?;
-    deduct_max_fee_from_base_asset(&mut non_retryable_balances, base_asset_id, max_fee)?;
+    deduct_max_fee_from_base_asset(
+        &mut non_retryable_balances,
+        retryable_balance,
+        base_asset_id,
+        max_fee,
+    )?;
 
     reduce_free_balances_by_coin_outputs(&mut non_retryable_balances, tx)?;
 
@@ -89,18 +94,34 @@ fn add_up_input_balances<T: field::Inputs>(
     Some((non_retryable_balances, retryable_balance))
 }
 
+/// Only the non-retryable balance can pay the fees, as the retryable balance is
+/// spent only if the execution succeeds.
 fn deduct_max_fee_from_base_asset(
     non_retryable_balances: &mut BTreeMap<AssetId, Word>,
+    retryable_balance: Word,
     base_asset_id: &AssetId,
     max_fee: Word,
 ) -> Result<(), ValidityError> {
     let base_asset_balance = non_retryable_balances.entry(*base_asset_id).or_default();
-    *base_asset_balance = base_asset_balance.checked_sub(max_fee).ok_or(
-        ValidityError::InsufficientFeeAmount {
-            expected: max_fee,
-            provided: *base_asset_balance,
-        },
-    )?;
+    let Some(remaining) = base_asset_balance.checked_sub(max_fee) else {
+        let provided = *base_asset_balance;
+        let covered_by_retryable = provided
+            .checked_add(retryable_balance)
+            .map_or(true, |total| total >= max_fee);
+        return Err(if covered_by_retryable {
+            ValidityError::RetryableAmountCannotCoverFee {
+                expected: max_fee,
+                provided,
+                retryable: retryable_balance,
+            }
+        } else {
+            ValidityError::InsufficientFeeAmount {
+                expected: max_fee,
+                provided,
+            }
+        })
+    };
+    *base_asset_balance = remaining;
 
     Ok(())
 }
@@ -0,0 +1,666 @@
+//! Remote debugging of the interpreter over the
+//! [Debug Adapter Protocol](https://microsoft.github.io/debug-adapter-protocol/).
+//!
+//! The [`DebugServer`] drives a single execution of an [`Interpreter`] and exposes
+//! its [`Debugger`](crate::state::Debugger) state to an attached IDE: breakpoints,
+//! instruction stepping, registers, call stack and raw memory.
+//!
+//! Breakpoints are set with the `setInstructionBreakpoints` request. The
+//! `instructionReference` of every breakpoint is either `"script"` or the hex
+//! encoded [`ContractId`] of the targeted contract, and `offset` is the
+//! instruction index relative to `$is`, the same convention as
+//! [`Breakpoint::new`].
+
+use crate::{
+    call::CallFrame,
+    error::InterpreterError,
+    interpreter::{
+        EcalHandler,
+        ExecutableTransaction,
+        Interpreter,
+        Memory,
+    },
+    state::{
+        Breakpoint,
+        DebugEval,
+        ProgramState,
+    },
+    storage::InterpreterStorage,
+};
+
+use alloc::{
+    format,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use base64::Engine;
+use core::str::FromStr;
+use fuel_asm::RegId;
+use fuel_types::{
+    ContractId,
+    Word,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use std::io::{
+    self,
+    BufRead,
+    Write,
+};
+
+/// The only thread reported to the client; the VM is single threaded.
+const THREAD_ID: u64 = 1;
+
+/// `variablesReference` of the registers scope.
+const REGISTERS_REFERENCE: u64 = 1;
+
+/// The maximum `Content-Length` of a message accepted from the client.
+pub const MAX_MESSAGE_LENGTH: usize = 4 * 1024 * 1024;
+
+/// Names of the reserved registers, indexed by register id.
+const RESERVED_REGISTER_NAMES: [&str; RegId::WRITABLE.to_u8() as usize] = [
+    "zero", "one", "of", "pc", "ssp", "sp", "fp", "hp", "err", "ggas", "cgas", "bal",
+    "is", "ret", "retl", "flag",
+];
+
+/// Reads a single Debug Adapter Protocol message from `reader`.
+///
+/// Returns `None` if the stream was closed before a new message started.
+pub fn read_message<R: BufRead>(reader: &mut R) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None)
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                let length = usize::from_str(value.trim())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                if length > MAX_MESSAGE_LENGTH {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "Content-Length exceeds the maximum message length",
+                    ))
+                }
+                content_length = Some(length);
+            }
+        }
+    }
+
+    let length = content_length.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "Missing Content-Length header")
+    })?;
+
+    let mut body = alloc::vec![0u8; length];
+    reader.read_exact(&mut body)?;
+
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Writes a single Debug Adapter Protocol message to `writer`.
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> io::Result<()> {
+    let body = serde_json::to_vec(message)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    write!(writer, "Content-Length: {}\r\n\r\n", body.len())?;
+    writer.write_all(&body)?;
+    writer.flush()
+}
+
+/// Parses the `instructionReference`/`offset` pair of an instruction breakpoint.
+fn parse_breakpoint(reference: &str, offset: Word) -> Option<Breakpoint> {
+    if reference == "script" {
+        return Some(Breakpoint::script(offset))
+    }
+
+    let contract = ContractId::from_str(reference).ok()?;
+    Some(Breakpoint::new(contract, offset))
+}
+
+/// Human readable name of a code location.
+fn location_name(contract: &ContractId) -> String {
+    if contract == &ContractId::zeroed() {
+        "script".to_string()
+    } else {
+        format!("{contract:#x}")
+    }
+}
+
+/// Name of the register with the given id, as it appears in the assembly.
+fn register_name(index: usize) -> String {
+    RESERVED_REGISTER_NAMES
+        .get(index)
+        .map(|name| format!("${name}"))
+        .unwrap_or_else(|| format!("$r{index}"))
+}
+
+/// Starts the debugged execution once the client finished its configuration.
+type Launcher<'a, M, S, Tx, Ecal> = dyn FnOnce(
+        &mut Interpreter<M, S, Tx, Ecal>,
+    ) -> Result<ProgramState, InterpreterError<<S as InterpreterStorage>::DataError>>
+    + 'a;
+
+/// Debug Adapter Protocol server for a single [`Interpreter`] execution.
+pub struct DebugServer<'a, M, S, Tx, Ecal>
+where
+    S: InterpreterStorage,
+{
+    vm: &'a mut Interpreter<M, S, Tx, Ecal>,
+    launcher: Option<alloc::boxed::Box<Launcher<'a, M, S, Tx, Ecal>>>,
+    state: Option<ProgramState>,
+    seq: u64,
+}
+
+impl<'a, M, S, Tx, Ecal> DebugServer<'a, M, S, Tx, Ecal>
+where
+    M: Memory,
+    S: InterpreterStorage,
+    Tx: ExecutableTransaction,
+    Ecal: EcalHandler,
+{
+    /// Create a new server for `vm`.
+    ///
+    /// `launcher` is called after the client sent `configurationDone` and is
+    /// expected to start the execution, e.g. by calling
+    /// [`Interpreter::transact`].
+    pub fn new<F>(vm: &'a mut Interpreter<M, S, Tx, Ecal>, launcher: F) -> Self
+    where
+        F: FnOnce(
+                &mut Interpreter<M, S, Tx, Ecal>,
+            ) -> Result<ProgramState, InterpreterError<S::DataError>>
+            + 'a,
+    {
+        Self {
+            vm,
+            launcher: Some(alloc::boxed::Box::new(launcher)),
+            state: None,
+            seq: 0,
+        }
+    }
+
+    /// Last known program state of the debugged execution.
+    pub const fn state(&self) -> Option<&ProgramState> {
+        self.state.as_ref()
+    }
+
+    /// Serve a single client until it disconnects or closes the stream.
+    pub fn serve<R: BufRead, W: Write>(
+        &mut self,
+        mut reader: R,
+        mut writer: W,
+    ) -> io::Result<()> {
+        while let Some(request) = read_message(&mut reader)? {
+            if request["type"] != "request" {
+                continue
+            }
+
+            if !self.handle(&request, &mut writer)? {
+                break
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Accept a single client on `addr` and serve it.
+    pub fn listen<A: std::net::ToSocketAddrs>(&mut self, addr: A) -> io::Result<()> {
+        let listener = std::net::TcpListener::bind(addr)?;
+        let (stream, _) = listener.accept()?;
+        let reader = io::BufReader::new(stream.try_clone()?);
+
+        self.serve(reader, stream)
+    }
+
+    /// Handles a single request; returns `false` once the session is over.
+    fn handle<W: Write>(&mut self, request: &Value, writer: &mut W) -> io::Result<bool> {
+        let command = request["command"].as_str().unwrap_or_default();
+        let arguments = &request["arguments"];
+
+        match command {
+            "initialize" => {
+                let capabilities = json!({
+                    "supportsConfigurationDoneRequest": true,
+                    "supportsInstructionBreakpoints": true,
+                    "supportsReadMemoryRequest": true,
+                    "supportsSteppingGranularity": true,
+                });
+                self.respond(writer, request, Ok(capabilities))?;
+                self.event(writer, "initialized", Value::Null)?;
+            }
+            "launch" | "attach" => {
+                self.respond(writer, request, Ok(Value::Null))?;
+            }
+            "setInstructionBreakpoints" => {
+                let body = self.set_breakpoints(arguments);
+                self.respond(writer, request, Ok(body))?;
+            }
+            "configurationDone" => {
+                self.respond(writer, request, Ok(Value::Null))?;
+                match self.launcher.take() {
+                    Some(launcher) => {
+                        let result = launcher(self.vm);
+                        self.report(writer, result, "entry")?;
+                    }
+                    None => self.terminated(writer)?,
+                }
+            }
+            "threads" => {
+                let body = json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] });
+                self.respond(writer, request, Ok(body))?;
+            }
+            "stackTrace" => {
+                let body = self.stack_trace();
+                self.respond(writer, request, Ok(body))?;
+            }
+            "scopes" => {
+                let body = json!({
+                    "scopes": [{
+                        "name": "Registers",
+                        "presentationHint": "registers",
+                        "variablesReference": REGISTERS_REFERENCE,
+                        "expensive": false,
+                    }]
+                });
+                self.respond(writer, request, Ok(body))?;
+            }
+            "variables" => {
+                let body = self.variables(arguments);
+                self.respond(writer, request, Ok(body))?;
+            }
+            "readMemory" => {
+                let body = self.read_memory(arguments);
+                self.respond(writer, request, body)?;
+            }
+            "continue" => {
+                self.respond(
+                    writer,
+                    request,
+                    Ok(json!({ "allThreadsContinued": true })),
+                )?;
+                self.vm.set_single_stepping(false);
+                self.resume(writer, "breakpoint")?;
+            }
+            "next" | "stepIn" | "stepOut" => {
+                self.respond(writer, request, Ok(Value::Null))?;
+                self.vm.set_single_stepping(true);
+                self.resume(writer, "step")?;
+            }
+            "disconnect" | "terminate" => {
+                self.respond(writer, request, Ok(Value::Null))?;
+                return Ok(false)
+            }
+            _ => {
+                let message = format!("Unsupported command `{command}`");
+                self.respond(writer, request, Err(message))?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn set_breakpoints(&mut self, arguments: &Value) -> Value {
+        let requested = arguments["breakpoints"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let breakpoints: Vec<_> = requested
+            .iter()
+            .map(|bp| {
+                let reference = bp["instructionReference"].as_str().unwrap_or_default();
+                let offset = bp["offset"].as_u64().unwrap_or_default();
+                parse_breakpoint(reference, offset)
+            })
+            .collect();
+
+        let valid: Vec<_> = breakpoints.iter().flatten().copied().collect();
+        self.vm.overwrite_breakpoints(&valid);
+
+        let breakpoints: Vec<_> = breakpoints
+            .iter()
+            .map(|bp| json!({ "verified": bp.is_some() }))
+            .collect();
+
+        json!({ "breakpoints": breakpoints })
+    }
+
+    fn stack_trace(&self) -> Value {
+        let registers = self.vm.registers();
+        let current = self.vm.call_stack().last().map(CallFrame::to);
+        let current = current.copied().unwrap_or_default();
+        let pc = registers[RegId::PC];
+
+        let mut frames = Vec::new();
+        frames.push(json!({
+            "id": 0,
+            "name": location_name(&current),
+            "line": 0,
+            "column": 0,
+            "instructionPointerReference": format!("{pc:#x}"),
+        }));
+
+        // Every call frame stores the registers of its caller.
+        let stack = self.vm.call_stack();
+        for (depth, frame) in stack.iter().enumerate().rev() {
+            let caller = depth
+                .checked_sub(1)
+                .and_then(|i| stack.get(i))
+                .map(|frame| *frame.to())
+                .unwrap_or_default();
+            let pc = frame.registers()[RegId::PC];
+
+            frames.push(json!({
+                "id": frames.len(),
+                "name": location_name(&caller),
+                "line": 0,
+                "column": 0,
+                "instructionPointerReference": format!("{pc:#x}"),
+            }));
+        }
+
+        let total = frames.len();
+        json!({ "stackFrames": frames, "totalFrames": total })
+    }
+
+    fn variables(&self, arguments: &Value) -> Value {
+        if arguments["variablesReference"].as_u64() != Some(REGISTERS_REFERENCE) {
+            return json!({ "variables": [] })
+        }
+
+        let variables: Vec<_> = self
+            .vm
+            .registers()
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                json!({
+                    "name": register_name(i),
+                    "value": format!("{value:#x}"),
+                    "variablesReference": 0,
+                })
+            })
+            .collect();
+
+        json!({ "variables": variables })
+    }
+
+    fn read_memory(&self, arguments: &Value) -> Result<Value, String> {
+        let reference = arguments["memoryReference"].as_str().unwrap_or_default();
+        let address = reference.strip_prefix("0x").unwrap_or(reference);
+        let address = Word::from_str_radix(address, 16)
+            .map_err(|_| format!("Invalid memory reference `{reference}`"))?;
+        let offset = arguments["offset"].as_i64().unwrap_or_default();
+        let address = address
+            .checked_add_signed(offset)
+            .ok_or_else(|| "Memory address overflow".to_string())?;
+        let count = arguments["count"].as_u64().unwrap_or_default();
+
+        let data = self
+            .vm
+            .memory()
+            .read(address, count)
+            .map_err(|reason| format!("Unable to read memory: {reason}"))?;
+
+        Ok(json!({
+            "address": format!("{address:#x}"),
+            "data": base64::engine::general_purpose::STANDARD.encode(data),
+        }))
+    }
+
+    fn resume<W: Write>(&mut self, writer: &mut W, reason: &str) -> io::Result<()> {
+        let result = self.vm.resume();
+        self.report(writer, result, reason)
+    }
+
+    /// Informs the client about the outcome of the last execution step.
+    fn report<W: Write>(
+        &mut self,
+        writer: &mut W,
+        result: Result<ProgramState, InterpreterError<S::DataError>>,
+        reason: &str,
+    ) -> io::Result<()> {
+        match result {
+            Ok(state) => {
+                self.state = Some(state);
+                match state {
+                    ProgramState::RunProgram(DebugEval::Breakpoint(_))
+                    | ProgramState::VerifyPredicate(DebugEval::Breakpoint(_)) => {
+                        let body = json!({
+                            "reason": reason,
+                            "threadId": THREAD_ID,
+                            "allThreadsStopped": true,
+                        });
+                        self.event(writer, "stopped", body)
+                    }
+                    ProgramState::Revert(code) => {
+                        self.exited(writer, 1, &format!("Reverted with {code:#x}\n"))
+                    }
+                    _ => self.exited(writer, 0, ""),
+                }
+            }
+            Err(e) => self.exited(writer, 1, &format!("{e:?}\n")),
+        }
+    }
+
+    fn exited<W: Write>(
+        &mut self,
+        writer: &mut W,
+        code: u64,
+        output: &str,
+    ) -> io::Result<()> {
+        if !output.is_empty() {
+            let body = json!({ "category": "stderr", "output": output });
+            self.event(writer, "output", body)?;
+        }
+        self.event(writer, "exited", json!({ "exitCode": code }))?;
+        self.terminated(writer)
+    }
+
+    fn terminated<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.event(writer, "terminated", Value::Null)
+    }
+
+    fn next_seq(&mut self) -> u64 {
+        self.seq = self.seq.saturating_add(1);
+        self.seq
+    }
+
+    fn respond<W: Write>(
+        &mut self,
+        writer: &mut W,
+        request: &Value,
+        body: Result<Value, String>,
+    ) -> io::Result<()> {
+        let seq = self.next_seq();
+        let mut response = json!({
+            "seq": seq,
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": body.is_ok(),
+        });
+
+        match body {
+            Ok(Value::Null) => {}
+            Ok(body) => response["body"] = body,
+            Err(message) => response["message"] = Value::String(message),
+        }
+
+        write_message(writer, &response)
+    }
+
+    fn event<W: Write>(
+        &mut self,
+        writer: &mut W,
+        event: &str,
+        body: Value,
+    ) -> io::Result<()> {
+        let seq = self.next_seq();
+        let mut message = json!({
+            "seq": seq,
+            "type": "event",
+            "event": event,
+        });
+
+        if !body.is_null() {
+            message["body"] = body;
+        }
+
+        write_message(writer, &message)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        checked_transaction::IntoChecked,
+        interpreter::MemoryInstance,
+        prelude::*,
+    };
+    use alloc::vec;
+    use fuel_asm::op;
+
+    fn request(seq: u64, command: &str, arguments: Value) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let message = json!({
+            "seq": seq,
+            "type": "request",
+            "command": command,
+            "arguments": arguments,
+        });
+        write_message(&mut bytes, &message).unwrap();
+        bytes
+    }
+
+    fn responses(mut bytes: &[u8]) -> Vec<Value> {
+        let mut messages = Vec::new();
+        while let Some(message) = read_message(&mut bytes).unwrap() {
+            messages.push(message);
+        }
+        messages
+    }
+
+    #[test]
+    fn read_message__roundtrips_write_message() {
+        let message = json!({ "seq": 1, "type": "event", "event": "initialized" });
+        let mut bytes = Vec::new();
+        write_message(&mut bytes, &message).unwrap();
+
+        let decoded = read_message(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(decoded, Some(message));
+    }
+
+    #[test]
+    fn read_message__fails_without_content_length() {
+        let mut bytes: &[u8] = b"Foo: 1\r\n\r\n{}";
+
+        read_message(&mut bytes).expect_err("Expected missing header error");
+    }
+
+    #[test]
+    fn read_message__rejects_too_long_content() {
+        let header = format!("Content-Length: {}\r\n\r\n", MAX_MESSAGE_LENGTH + 1);
+        let mut bytes = header.as_bytes();
+
+        let error = read_message(&mut bytes).expect_err("Expected too long error");
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn serve__stops_at_breakpoint_and_steps() {
+        let consensus_params = ConsensusParameters::standard();
+        let script = [
+            op::addi(0x10, RegId::ZERO, 8),
+            op::addi(0x11, RegId::ZERO, 16),
+            op::addi(0x12, RegId::ZERO, 32),
+            op::ret(0x10),
+        ]
+        .into_iter()
+        .collect();
+        let tx = TransactionBuilder::script(script, vec![])
+            .script_gas_limit(1_000_000)
+            .add_fee_input()
+            .finalize()
+            .into_checked(Default::default(), &consensus_params)
+            .expect("failed to generate checked tx")
+            .into_ready(
+                0,
+                consensus_params.gas_costs(),
+                consensus_params.fee_params(),
+            )
+            .unwrap();
+
+        let mut vm = Interpreter::<MemoryInstance, _, _>::with_memory_storage();
+        let mut input = Vec::new();
+        input.extend(request(1, "initialize", json!({})));
+        input.extend(request(
+            2,
+            "setInstructionBreakpoints",
+            json!({ "breakpoints": [
+                { "instructionReference": "script", "offset": 1 },
+                { "instructionReference": "not-a-contract" },
+            ]}),
+        ));
+        input.extend(request(3, "configurationDone", json!({})));
+        input.extend(request(4, "variables", json!({ "variablesReference": 1 })));
+        input.extend(request(5, "next", json!({ "threadId": 1 })));
+        input.extend(request(6, "stackTrace", json!({ "threadId": 1 })));
+        input.extend(request(7, "continue", json!({ "threadId": 1 })));
+        input.extend(request(8, "disconnect", json!({})));
+
+        let mut output = Vec::new();
+        let state = {
+            let mut server =
+                DebugServer::new(&mut vm, move |vm| vm.transact(tx).map(Into::into));
+            server.serve(input.as_slice(), &mut output).unwrap();
+            server.state().copied()
+        };
+
+        let messages = responses(&output);
+        let find_response = |seq: u64| {
+            messages
+                .iter()
+                .find(|m| m["type"] == "response" && m["request_seq"] == seq)
+                .expect("Missing response")
+        };
+        let events: Vec<_> = messages
+            .iter()
+            .filter(|m| m["type"] == "event")
+            .map(|m| m["event"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            events,
+            vec!["initialized", "stopped", "stopped", "exited", "terminated"]
+        );
+        let verified = &find_response(2)["body"]["breakpoints"];
+        assert_eq!(verified[0]["verified"], true);
+        assert_eq!(verified[1]["verified"], false);
+        let registers = &find_response(4)["body"]["variables"];
+        assert_eq!(registers[0x10]["name"], "$r16");
+        assert_eq!(registers[0x10]["value"], "0x8");
+        assert_eq!(registers[0x11]["value"], "0x0");
+        let frames = &find_response(6)["body"]["stackFrames"];
+        assert_eq!(frames[0]["name"], "script");
+        assert_eq!(state, Some(ProgramState::Return(8)));
+    }
+}
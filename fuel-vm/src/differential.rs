@@ -0,0 +1,307 @@
+//! Differential execution of a transaction by two interpreters, e.g. built with
+//! different feature flags, gas schedules or versions.
+//!
+//! Both executors receive the same checked transaction and the same storage. Their
+//! [`ExecutionTrace`]s are compared, and the first difference is reported as a
+//! [`Divergence`]. This is intended for checking that a change of the gas schedule or
+//! of an opcode affects only the expected executions.
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::{
+        EcalHandler,
+        InterpreterParams,
+        MemoryInstance,
+    },
+    storage::MemoryStorage,
+    transactor::Transactor,
+};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+use fuel_asm::{
+    RegId,
+    Word,
+};
+use fuel_tx::{
+    Receipt,
+    Script,
+};
+use fuel_types::{
+    AssetId,
+    Bytes32,
+    ContractId,
+};
+
+/// A write into the storage, compared by the value after the execution.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StorageWrite {
+    /// A written state slot. The value is `None` if the slot was cleared.
+    Slot {
+        /// The contract owning the slot.
+        contract_id: ContractId,
+        /// The key of the slot.
+        key: Bytes32,
+        /// The value after the execution.
+        value: Option<Vec<u8>>,
+    },
+    /// A changed balance. The amount is `None` if the balance was removed.
+    Balance {
+        /// The contract owning the balance.
+        contract_id: ContractId,
+        /// The asset of the balance.
+        asset_id: AssetId,
+        /// The amount after the execution.
+        amount: Option<Word>,
+    },
+}
+
+/// The observable result of an execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionTrace {
+    /// The receipts of the execution.
+    pub receipts: Vec<Receipt>,
+    /// The registers after the execution.
+    pub registers: Vec<Word>,
+    /// The changed state slots and balances, ordered by the contract and the key.
+    pub storage_writes: Vec<StorageWrite>,
+    /// The error aborting the execution, if any.
+    pub error: Option<String>,
+}
+
+impl ExecutionTrace {
+    /// Captures the trace of the execution by the `transactor`, which started with
+    /// the `before` storage.
+    pub fn capture<Ecal>(
+        transactor: &Transactor<MemoryInstance, MemoryStorage, Script, Ecal>,
+        before: &MemoryStorage,
+    ) -> Self
+    where
+        Ecal: EcalHandler,
+    {
+        Self {
+            receipts: transactor.receipts().unwrap_or_default().to_vec(),
+            registers: transactor.interpreter().registers().to_vec(),
+            storage_writes: storage_writes(before, transactor.as_ref()),
+            error: transactor.error().map(|error| format!("{error:?}")),
+        }
+    }
+}
+
+fn storage_writes(before: &MemoryStorage, after: &MemoryStorage) -> Vec<StorageWrite> {
+    fn changes<K: Ord + Copy, V: PartialEq + Clone>(
+        before: BTreeMap<K, V>,
+        after: BTreeMap<K, V>,
+    ) -> impl Iterator<Item = (K, Option<V>)> {
+        let mut keys: Vec<_> = before.keys().chain(after.keys()).copied().collect();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter().filter_map(move |key| {
+            let value = after.get(&key);
+            (before.get(&key) != value).then(|| (key, value.cloned()))
+        })
+    }
+
+    let slots = |storage: &MemoryStorage| {
+        storage
+            .all_contract_state()
+            .map(|(key, value)| ((*key.contract_id(), *key.state_key()), value.0.clone()))
+            .collect()
+    };
+    let balances = |storage: &MemoryStorage| {
+        storage
+            .all_contract_balances()
+            .map(|(key, amount)| ((*key.contract_id(), *key.asset_id()), *amount))
+            .collect()
+    };
+
+    let slots =
+        changes(slots(before), slots(after)).map(|((contract_id, key), value)| {
+            StorageWrite::Slot {
+                contract_id,
+                key,
+                value,
+            }
+        });
+    let balances = changes(balances(before), balances(after)).map(
+        |((contract_id, asset_id), amount)| StorageWrite::Balance {
+            contract_id,
+            asset_id,
+            amount,
+        },
+    );
+
+    let mut writes: Vec<_> = slots.chain(balances).collect();
+    writes.sort();
+    writes
+}
+
+/// Executes a checked script against the storage, and captures the trace.
+///
+/// Implemented for [`InterpreterExecutor`], and for the closures, so that an
+/// interpreter of another version can be wrapped into a trait object.
+pub trait DifferentialExecutor {
+    /// Executes the `tx` against the `storage`.
+    fn execute(&mut self, tx: Checked<Script>, storage: MemoryStorage) -> ExecutionTrace;
+}
+
+impl<F> DifferentialExecutor for F
+where
+    F: FnMut(Checked<Script>, MemoryStorage) -> ExecutionTrace,
+{
+    fn execute(&mut self, tx: Checked<Script>, storage: MemoryStorage) -> ExecutionTrace {
+        self(tx, storage)
+    }
+}
+
+/// Executes the transactions with the interpreter of this crate.
+#[derive(Debug, Clone)]
+pub struct InterpreterExecutor {
+    params: InterpreterParams,
+}
+
+impl InterpreterExecutor {
+    /// Creates the executor with the parameters of the interpreter.
+    pub fn new(params: InterpreterParams) -> Self {
+        Self { params }
+    }
+}
+
+impl DifferentialExecutor for InterpreterExecutor {
+    fn execute(&mut self, tx: Checked<Script>, storage: MemoryStorage) -> ExecutionTrace {
+        let mut transactor = Transactor::<_, _, Script>::new(
+            MemoryInstance::new(),
+            storage.clone(),
+            self.params.clone(),
+        );
+        transactor.transact(tx);
+        ExecutionTrace::capture(&transactor, &storage)
+    }
+}
+
+/// The first difference between the traces of the `left` and the `right` executors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// The receipts differ at the index. The receipt is `None` if the executor
+    /// produced fewer receipts.
+    Receipt {
+        /// The index of the receipt.
+        index: usize,
+        /// The receipt of the left executor.
+        left: Option<Receipt>,
+        /// The receipt of the right executor.
+        right: Option<Receipt>,
+    },
+    /// The storage writes differ at the index. The write is `None` if the executor
+    /// wrote less.
+    StorageWrite {
+        /// The index of the write.
+        index: usize,
+        /// The write of the left executor.
+        left: Option<StorageWrite>,
+        /// The write of the right executor.
+        right: Option<StorageWrite>,
+    },
+    /// The values of the register differ after the execution.
+    Register {
+        /// The register.
+        register: RegId,
+        /// The value of the left executor.
+        left: Word,
+        /// The value of the right executor.
+        right: Word,
+    },
+    /// Only one of the executions failed, or they failed with different errors.
+    Error {
+        /// The error of the left executor.
+        left: Option<String>,
+        /// The error of the right executor.
+        right: Option<String>,
+    },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Receipt { index, left, right } => {
+                write!(f, "receipt #{index} diverges: {left:?} != {right:?}")
+            }
+            Self::StorageWrite { index, left, right } => {
+                write!(f, "storage write #{index} diverges: {left:?} != {right:?}")
+            }
+            Self::Register {
+                register,
+                left,
+                right,
+            } => {
+                write!(f, "register {register} diverges: {left:#x} != {right:#x}")
+            }
+            Self::Error { left, right } => {
+                write!(f, "error diverges: {left:?} != {right:?}")
+            }
+        }
+    }
+}
+
+/// Returns the first difference between the traces: of the receipts, the storage
+/// writes, the registers and the error, in this order.
+pub fn compare(left: &ExecutionTrace, right: &ExecutionTrace) -> Option<Divergence> {
+    fn first_difference<T: PartialEq + Clone>(
+        left: &[T],
+        right: &[T],
+    ) -> Option<(usize, Option<T>, Option<T>)> {
+        (0..left.len().max(right.len())).find_map(|index| {
+            let (left, right) = (left.get(index), right.get(index));
+            (left != right).then(|| (index, left.cloned(), right.cloned()))
+        })
+    }
+
+    if let Some((index, left, right)) = first_difference(&left.receipts, &right.receipts)
+    {
+        return Some(Divergence::Receipt { index, left, right })
+    }
+    if let Some((index, left, right)) =
+        first_difference(&left.storage_writes, &right.storage_writes)
+    {
+        return Some(Divergence::StorageWrite { index, left, right })
+    }
+    if let Some((index, left, right)) =
+        first_difference(&left.registers, &right.registers)
+    {
+        let register = RegId::new(u8::try_from(index).unwrap_or(u8::MAX));
+        return Some(Divergence::Register {
+            register,
+            left: left.unwrap_or_default(),
+            right: right.unwrap_or_default(),
+        })
+    }
+    if left.error != right.error {
+        return Some(Divergence::Error {
+            left: left.error.clone(),
+            right: right.error.clone(),
+        })
+    }
+    None
+}
+
+/// Executes the `tx` against the `storage` by both executors. Returns the common
+/// trace if the executions agree, or the first [`Divergence`], boxed as it holds the
+/// receipts.
+pub fn execute(
+    left: &mut dyn DifferentialExecutor,
+    right: &mut dyn DifferentialExecutor,
+    tx: Checked<Script>,
+    storage: MemoryStorage,
+) -> Result<ExecutionTrace, Box<Divergence>> {
+    let left = left.execute(tx.clone(), storage.clone());
+    let right = right.execute(tx, storage);
+    match compare(&left, &right) {
+        Some(divergence) => Err(Box::new(divergence)),
+        None => Ok(left),
+    }
+}
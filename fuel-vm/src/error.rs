@@ -62,6 +62,25 @@ pub enum InterpreterError<StorageError> {
         /// Actual gas price
         actual: Word,
     },
+    /// The execution was aborted by a limit of
+    /// [`InterpreterParams`](crate::interpreter::InterpreterParams).
+    #[display(fmt = "Execution limit exceeded: {_0}")]
+    ExecutionLimitExceeded(ExecutionLimit),
+}
+
+/// Limits of the execution independent of gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExecutionLimit {
+    /// [`InterpreterParams::max_instructions`](crate::interpreter::InterpreterParams::max_instructions)
+    #[display(fmt = "too many instructions")]
+    Instructions,
+    /// `InterpreterParams::execution_timeout`
+    #[display(fmt = "timeout")]
+    Time,
+    /// Halt requested through a `HaltHandle`
+    #[display(fmt = "halt requested")]
+    HaltRequested,
 }
 
 impl<StorageError> InterpreterError<StorageError> {
@@ -125,6 +144,9 @@ where
                     actual: *actual,
                 }
             }
+            Self::ExecutionLimitExceeded(limit) => {
+                InterpreterError::ExecutionLimitExceeded(*limit)
+            }
         }
     }
 }
@@ -150,6 +172,7 @@ where
             (Self::NoTransactionInitialized, Self::NoTransactionInitialized) => true,
             (Self::Storage(a), Self::Storage(b)) => a == b,
             (Self::DebugStateNotInitialized, Self::DebugStateNotInitialized) => true,
+            (Self::ExecutionLimitExceeded(a), Self::ExecutionLimitExceeded(b)) => a == b,
 
             _ => false,
         }
@@ -377,6 +400,18 @@ pub enum BugVariant {
         message = "The witness subsection index is higher than the total number of parts."
     )]
     NextSubsectionIndexIsHigherThanTotalNumberOfParts,
+
+    /// The memory of a call frame changed while the frame was active.
+    #[strum(
+        message = "The call frame and the contract code in memory cannot be modified while the call is active."
+    )]
+    CallFrameCorrupted,
+
+    /// The transaction offset leaves no room for the transaction size.
+    #[strum(
+        message = "The transaction offset of the memory layout always leaves room for the transaction size."
+    )]
+    InvalidTxOffset,
 }
 
 impl fmt::Display for BugVariant {
@@ -434,6 +469,11 @@ impl Bug {
         self.inner_message = Some(error.to_string());
         self
     }
+
+    /// Type of the bug.
+    pub const fn variant(&self) -> BugVariant {
+        self.variant
+    }
 }
 
 impl PartialEq for Bug {
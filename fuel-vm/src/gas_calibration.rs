@@ -0,0 +1,377 @@
+//! Calibration of the fixed gas costs of the opcodes against the wall-clock time of
+//! their execution by this interpreter.
+//!
+//! Each [`CalibrationCase`] is executed as a script repeating the measured
+//! instruction after the setup of its operands. The time and the gas of a script
+//! executing only the setup are subtracted, so an [`OpcodeMeasurement`] covers the
+//! dispatch and the execution of the instruction alone. The costs suggested by the
+//! [`CalibrationReport`] are proportional to the measured time, scaled so the
+//! reference operation, `noop` by default, keeps its cost.
+//!
+//! The measurements depend on the machine and its load, so the suggested costs are a
+//! starting point of a review of the costs rather than their replacement.
+
+use crate::{
+    checked_transaction::{
+        builder::TransactionBuilderExt,
+        Checked,
+    },
+    interpreter::{
+        Interpreter,
+        InterpreterParams,
+        MemoryInstance,
+    },
+    storage::MemoryStorage,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use fuel_asm::{
+    op,
+    Instruction,
+    RegId,
+};
+use fuel_tx::{
+    consensus_parameters::gas::{
+        GasCostChange,
+        GasCostComponent,
+        GasCostsDiff,
+        GasCostsDiffError,
+    },
+    ConsensusParameters,
+    GasCosts,
+    Receipt,
+    Script,
+    TransactionBuilder,
+};
+use fuel_types::Word;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// An instruction measured by [`calibrate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalibrationCase {
+    /// The name of the operation in the gas costs, e.g. `"mod"` for `MOD`.
+    pub operation: &'static str,
+    /// The instructions setting up the operands, executed once.
+    pub setup: Vec<Instruction>,
+    /// The measured instruction. It must not change its operands, as it's repeated.
+    pub instruction: Instruction,
+}
+
+impl CalibrationCase {
+    /// Creates the case of the `instruction` without a setup.
+    pub fn new(operation: &'static str, instruction: Instruction) -> Self {
+        Self {
+            operation,
+            setup: Vec::new(),
+            instruction,
+        }
+    }
+
+    /// Sets the instructions setting up the operands.
+    pub fn with_setup(mut self, setup: Vec<Instruction>) -> Self {
+        self.setup = setup;
+        self
+    }
+}
+
+/// The cases of the arithmetic and logic opcodes with fixed costs.
+///
+/// The operands are `0x10` and `0x11`, and the results are written into `0x12`.
+pub fn default_cases() -> Vec<CalibrationCase> {
+    let setup = alloc::vec![op::movi(0x10, 7), op::movi(0x11, 3)];
+    let case = |operation, instruction| {
+        CalibrationCase::new(operation, instruction).with_setup(setup.clone())
+    };
+
+    alloc::vec![
+        case("add", op::add(0x12, 0x10, 0x11)),
+        case("addi", op::addi(0x12, 0x10, 3)),
+        case("and", op::and(0x12, 0x10, 0x11)),
+        case("andi", op::andi(0x12, 0x10, 3)),
+        case("div", op::div(0x12, 0x10, 0x11)),
+        case("divi", op::divi(0x12, 0x10, 3)),
+        case("eq", op::eq(0x12, 0x10, 0x11)),
+        case("exp", op::exp(0x12, 0x10, 0x11)),
+        case("expi", op::expi(0x12, 0x10, 3)),
+        case("gt", op::gt(0x12, 0x10, 0x11)),
+        case("lt", op::lt(0x12, 0x10, 0x11)),
+        case("mlog", op::mlog(0x12, 0x10, 0x11)),
+        case("mod", op::mod_(0x12, 0x10, 0x11)),
+        case("modi", op::modi(0x12, 0x10, 3)),
+        case("move", op::move_(0x12, 0x10)),
+        case("movi", op::movi(0x12, 3)),
+        case("mroo", op::mroo(0x12, 0x10, 0x11)),
+        case("mul", op::mul(0x12, 0x10, 0x11)),
+        case("muli", op::muli(0x12, 0x10, 3)),
+        case("noop", op::noop()),
+        case("not", op::not(0x12, 0x10)),
+        case("or", op::or(0x12, 0x10, 0x11)),
+        case("ori", op::ori(0x12, 0x10, 3)),
+        case("sll", op::sll(0x12, 0x10, 0x11)),
+        case("slli", op::slli(0x12, 0x10, 3)),
+        case("srl", op::srl(0x12, 0x10, 0x11)),
+        case("srli", op::srli(0x12, 0x10, 3)),
+        case("sub", op::sub(0x12, 0x10, 0x11)),
+        case("subi", op::subi(0x12, 0x10, 3)),
+        case("xor", op::xor(0x12, 0x10, 0x11)),
+        case("xori", op::xori(0x12, 0x10, 3)),
+    ]
+}
+
+/// The parameters of [`calibrate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationConfig {
+    /// The parameters of the chain, including the gas costs charged for the
+    /// instructions.
+    pub consensus_params: ConsensusParameters,
+    /// The number of the repetitions of the instruction in a script.
+    pub repetitions: u32,
+    /// The number of the executions of each script.
+    pub samples: u32,
+    /// The operation keeping its cost in the suggested costs.
+    pub reference: &'static str,
+}
+
+impl Default for CalibrationConfig {
+    fn default() -> Self {
+        Self {
+            consensus_params: ConsensusParameters::standard(),
+            repetitions: 1000,
+            samples: 25,
+            reference: "noop",
+        }
+    }
+}
+
+/// The gas and the wall-clock time of a single execution of an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct OpcodeMeasurement {
+    /// The name of the operation in the gas costs.
+    pub operation: &'static str,
+    /// The gas charged by the interpreter.
+    pub gas: Word,
+    /// The fastest execution time, in nanoseconds.
+    pub min_nanos: f64,
+    /// The median execution time, in nanoseconds.
+    pub median_nanos: f64,
+    /// The mean execution time, in nanoseconds.
+    pub mean_nanos: f64,
+}
+
+impl OpcodeMeasurement {
+    /// The median time per unit of the charged gas, or `None` if the instruction is
+    /// free.
+    pub fn nanos_per_gas(&self) -> Option<f64> {
+        #[allow(clippy::cast_precision_loss)]
+        let gas = self.gas as f64;
+        (self.gas > 0).then(|| self.median_nanos / gas)
+    }
+}
+
+/// The measurements of [`calibrate`], with the costs they suggest.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct CalibrationReport {
+    /// The measured instructions, in the order of the cases.
+    pub measurements: Vec<OpcodeMeasurement>,
+    /// The time of a unit of gas the suggested costs are based on: the time per gas
+    /// of the reference operation, or the median of all of them if it wasn't
+    /// measured.
+    pub nanos_per_gas: f64,
+    /// The gas costs charged during the measurements.
+    #[serde(skip)]
+    pub gas_costs: GasCosts,
+}
+
+impl CalibrationReport {
+    /// The cost of the measured instruction proportional to its time, at least `1`.
+    pub fn suggested_cost(&self, measurement: &OpcodeMeasurement) -> Word {
+        if self.nanos_per_gas <= 0.0 {
+            return measurement.gas
+        }
+        let cost = (measurement.median_nanos / self.nanos_per_gas).round();
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let cost = cost as Word;
+        cost.max(1)
+    }
+
+    /// The changes of the charged costs into the suggested ones.
+    pub fn suggested_diff(&self) -> GasCostsDiff {
+        let changes = self
+            .measurements
+            .iter()
+            .filter_map(|measurement| {
+                let suggested = self.suggested_cost(measurement);
+                (suggested != measurement.gas).then_some(GasCostChange {
+                    operation: measurement.operation,
+                    component: GasCostComponent::Fixed,
+                    old: Some(measurement.gas),
+                    new: Some(suggested),
+                })
+            })
+            .collect();
+
+        GasCostsDiff { changes }
+    }
+
+    /// The charged costs with the suggested costs of the measured instructions.
+    pub fn suggested_gas_costs(&self) -> Result<GasCosts, GasCostsDiffError> {
+        let mut gas_costs = self.gas_costs.clone();
+        gas_costs.apply(&self.suggested_diff())?;
+        Ok(gas_costs)
+    }
+}
+
+/// Displays the report as a Markdown table.
+impl fmt::Display for CalibrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "| Operation | Gas | Min ns | Median ns | Mean ns | ns/gas | Suggested |"
+        )?;
+        writeln!(
+            f,
+            "|-----------|-----|--------|-----------|---------|--------|-----------|"
+        )?;
+        for measurement in &self.measurements {
+            write!(
+                f,
+                "| {} | {} | {:.2} | {:.2} | {:.2} | ",
+                measurement.operation,
+                measurement.gas,
+                measurement.min_nanos,
+                measurement.median_nanos,
+                measurement.mean_nanos,
+            )?;
+            match measurement.nanos_per_gas() {
+                Some(nanos_per_gas) => write!(f, "{nanos_per_gas:.2}")?,
+                None => write!(f, "-")?,
+            }
+            writeln!(f, " | {} |", self.suggested_cost(measurement))?;
+        }
+        Ok(())
+    }
+}
+
+/// Measures the `cases` in the interpreter.
+///
+/// # Panics
+///
+/// If a case doesn't fit into a script, or its execution fails.
+pub fn calibrate(
+    cases: &[CalibrationCase],
+    config: &CalibrationConfig,
+) -> CalibrationReport {
+    let measurements: Vec<_> = cases.iter().map(|case| measure(case, config)).collect();
+
+    let reference = measurements
+        .iter()
+        .find(|measurement| measurement.operation == config.reference)
+        .and_then(OpcodeMeasurement::nanos_per_gas);
+    let nanos_per_gas = reference.unwrap_or_else(|| {
+        let mut all: Vec<_> = measurements
+            .iter()
+            .filter_map(OpcodeMeasurement::nanos_per_gas)
+            .collect();
+        median(&mut all)
+    });
+
+    CalibrationReport {
+        measurements,
+        nanos_per_gas,
+        gas_costs: config.consensus_params.gas_costs().clone(),
+    }
+}
+
+fn measure(case: &CalibrationCase, config: &CalibrationConfig) -> OpcodeMeasurement {
+    let repetitions = usize::try_from(config.repetitions).expect("`u32` fits `usize`");
+    let mut script = case.setup.clone();
+    script.extend(core::iter::repeat(case.instruction).take(repetitions));
+    script.push(op::ret(RegId::ONE));
+    let mut baseline_script = case.setup.clone();
+    baseline_script.push(op::ret(RegId::ONE));
+
+    let (baseline_times, baseline_gas) = execute(baseline_script, config);
+    let (times, gas) = execute(script, config);
+
+    #[allow(clippy::cast_precision_loss)]
+    let per_instruction = |total: f64, baseline: f64| {
+        ((total - baseline) / config.repetitions.max(1) as f64).max(0.0)
+    };
+    let min = |times: &[f64]| times.iter().copied().fold(f64::INFINITY, f64::min);
+    #[allow(clippy::cast_precision_loss)]
+    let mean = |times: &[f64]| times.iter().sum::<f64>() / times.len().max(1) as f64;
+
+    OpcodeMeasurement {
+        operation: case.operation,
+        gas: gas
+            .saturating_sub(baseline_gas)
+            .checked_div(Word::from(config.repetitions))
+            .unwrap_or_default(),
+        min_nanos: per_instruction(min(&times), min(&baseline_times)),
+        median_nanos: per_instruction(
+            median(&mut times.clone()),
+            median(&mut baseline_times.clone()),
+        ),
+        mean_nanos: per_instruction(mean(&times), mean(&baseline_times)),
+    }
+}
+
+/// Executes the `script` the configured number of times, returning the execution
+/// times in nanoseconds and the gas used by the script.
+fn execute(script: Vec<Instruction>, config: &CalibrationConfig) -> (Vec<f64>, Word) {
+    let params = &config.consensus_params;
+    let script_gas_limit = params.tx_params().max_gas_per_tx();
+    let checked: Checked<Script> =
+        TransactionBuilder::script(script.into_iter().collect(), Vec::new())
+            .with_params(params.clone())
+            .script_gas_limit(script_gas_limit)
+            .add_fee_input()
+            .finalize_checked(Default::default());
+
+    let gas_price = 0;
+    let interpreter_params = InterpreterParams::new(gas_price, params);
+    let mut gas_used = 0;
+    let times = (0..config.samples)
+        .map(|_| {
+            let ready = checked
+                .clone()
+                .into_ready(gas_price, params.gas_costs(), params.fee_params())
+                .expect("The script is valid");
+            let mut vm = Interpreter::<_, _, Script>::with_storage(
+                MemoryInstance::new(),
+                MemoryStorage::default(),
+                interpreter_params.clone(),
+            );
+
+            let start = Instant::now();
+            let state = vm.transact(ready).expect("The script is executed");
+            let elapsed = start.elapsed();
+
+            gas_used = state
+                .receipts()
+                .iter()
+                .find_map(|receipt| match receipt {
+                    Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                    _ => None,
+                })
+                .expect("The script has a result");
+            nanos(elapsed)
+        })
+        .collect();
+
+    (times, gas_used)
+}
+
+fn nanos(duration: Duration) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let nanos = duration.as_nanos() as f64;
+    nanos
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(f64::total_cmp);
+    values.get(values.len() / 2).copied().unwrap_or_default()
+}
@@ -30,10 +30,12 @@ use fuel_tx::{
     Executable,
     FeeParameters,
     GasCosts,
+    GasModelRef,
     Output,
     PrepareSign,
     Receipt,
     Script,
+    SharedGasCostModel,
     Transaction,
     TransactionRepr,
     UniqueIdentifier,
@@ -53,6 +55,7 @@ mod alu;
 mod balances;
 mod blob;
 mod blockchain;
+mod checkpoint;
 mod constructors;
 pub mod contract;
 mod crypto;
@@ -60,31 +63,67 @@ pub mod diff;
 mod executors;
 mod flow;
 mod gas;
+#[cfg(feature = "std")]
+mod halt;
 mod initialization;
 mod internal;
 mod log;
 mod memory;
 mod metadata;
+mod opcode_filter;
 mod post_execution;
+mod progress;
 mod receipts;
+mod tx_memory_layout;
+mod vm_metrics;
 
 mod debug;
 mod ecal;
 
 use crate::profiler::Profiler;
+use checkpoint::Checkpointer;
+use progress::ProgressNotifier;
+use vm_metrics::VmMetricsReporter;
 
 #[cfg(feature = "profile-gas")]
 use crate::profiler::InstructionLocation;
 
 pub use balances::RuntimeBalances;
+pub use checkpoint::{
+    CheckpointReceiver,
+    ExecutionCheckpoint,
+    MemoryPage,
+    CHECKPOINT_PAGE_SIZE,
+};
 pub use ecal::{
     EcalHandler,
     PredicateErrorEcal,
 };
+#[cfg(feature = "std")]
+pub use halt::{
+    HaltDump,
+    HaltHandle,
+    MemorySummary,
+};
 pub use memory::{
+    CowMemory,
     Memory,
     MemoryInstance,
     MemoryRange,
+    MemoryStats,
+};
+pub use opcode_filter::{
+    OpcodeFilter,
+    OpcodeSet,
+};
+pub use progress::{
+    ExecutionProgress,
+    ProgressWatcher,
+};
+pub use tx_memory_layout::TxMemoryLayout;
+pub use vm_metrics::{
+    NoopVmMetrics,
+    VmMetrics,
 };
 
 use crate::checked_transaction::{
@@ -121,6 +160,26 @@ pub struct Interpreter<M, S, Tx = (), Ecal = NotSupportedEcal> {
     registers: [Word; VM_REGISTER_COUNT],
     memory: M,
     frames: Vec<CallFrame>,
+    /// Digests of the memory of the active call frames, recorded if
+    /// [`InterpreterParams::call_frame_integrity_checks`] is enabled.
+    frame_canaries: Vec<Bytes32>,
+    /// Number of the call frames when the outermost active static call was entered.
+    /// See [`fuel_asm::Flags::STATIC_CALL`].
+    static_call_depth: Option<usize>,
+    /// Gas used by the call frames returned from, in the order of the returns. See
+    /// [`CallTree`](crate::call_tree::CallTree).
+    returned_frames_gas: Vec<Word>,
+    /// Number of instructions executed since the initialization. The checkpoints and
+    /// the progress notifications are counted with it.
+    executed_instructions: u64,
+    /// Whether the execution limits, the halt requests, the checkpoints or the
+    /// progress notifications are checked around every instruction.
+    per_instruction_checks: bool,
+    /// Peak memory usage since the initialization.
+    memory_stats: MemoryStats,
+    /// Deadline derived from [`InterpreterParams::execution_timeout`].
+    #[cfg(feature = "std")]
+    execution_deadline: Option<std::time::Instant>,
     receipts: ReceiptsCtx,
     tx: Tx,
     initial_balances: InitialBalances,
@@ -131,6 +190,12 @@ pub struct Interpreter<M, S, Tx = (), Ecal = NotSupportedEcal> {
     context: Context,
     balances: RuntimeBalances,
     profiler: Profiler,
+    checkpointer: Checkpointer,
+    progress_notifier: ProgressNotifier,
+    vm_metrics: VmMetricsReporter,
+    /// Handle of the halt requests, set with [`Interpreter::with_halt_handle`].
+    #[cfg(feature = "std")]
+    halt_handle: Option<HaltHandle>,
     interpreter_params: InterpreterParams,
     /// `PanicContext` after the latest execution. It is consumed by
     /// `append_panic_receipt` and is `PanicContext::None` after consumption.
@@ -149,16 +214,73 @@ pub struct InterpreterParams {
     pub max_inputs: u16,
     /// Maximum size of the contract in bytes
     pub contract_max_size: u64,
-    /// Offset of the transaction data in the memory
-    pub tx_offset: usize,
+    /// Layout of the transaction data in the memory. Historical transactions must be
+    /// replayed with the layout they were executed with.
+    pub tx_memory_layout: TxMemoryLayout,
     /// Maximum length of the message data
     pub max_message_data_length: u64,
+    /// Maximum number of messages sent by the transaction
+    pub max_message_outputs: u16,
+    /// Maximum total length of the data of the messages sent by the transaction
+    pub max_message_outputs_data_length: u64,
     /// Chain ID
     pub chain_id: ChainId,
     /// Fee parameters
     pub fee_params: FeeParameters,
     /// Base Asset ID
     pub base_asset_id: AssetId,
+    /// Reject instructions with non-canonically encoded arguments, both when
+    /// deploying contracts and during execution.
+    /// See [`fuel_asm::Instruction::is_canonical`].
+    pub strict_instruction_decoding: bool,
+    /// Verify on `RET` and `RETD` that the memory of the call frame, including the
+    /// contract code, wasn't modified since the `CALL`. A corrupted frame is reported
+    /// as [`BugVariant::CallFrameCorrupted`](crate::error::BugVariant::CallFrameCorrupted).
+    ///
+    /// Intended for fuzzing and testing, as it hashes the frame on every call.
+    pub call_frame_integrity_checks: bool,
+    /// Experimental. Allow setting [`fuel_asm::Flags::DELEGATE_CALL`], which makes
+    /// `CALL` execute the code of the target contract in the storage context of the
+    /// calling contract.
+    #[cfg(feature = "delegate-call")]
+    pub delegate_calls: bool,
+    /// Experimental. Allow setting [`fuel_asm::Flags::STATIC_CALL`], which makes
+    /// `CALL` forbid the state changes and the coin transfers of the callee.
+    #[cfg(feature = "static-call")]
+    pub static_calls: bool,
+    /// Abort the execution with [`InterpreterError::ExecutionLimitExceeded`] once the
+    /// number of executed instructions exceeds the limit. Unlike gas, the limit
+    /// doesn't depend on the gas costs.
+    ///
+    /// [`InterpreterError::ExecutionLimitExceeded`]: crate::error::InterpreterError::ExecutionLimitExceeded
+    pub max_instructions: Option<u64>,
+    /// Abort the execution with [`InterpreterError::ExecutionLimitExceeded`] once it
+    /// takes longer than the timeout. Intended for fuzzing and sandboxing, as the
+    /// result depends on the host.
+    ///
+    /// [`InterpreterError::ExecutionLimitExceeded`]: crate::error::InterpreterError::ExecutionLimitExceeded
+    #[cfg(feature = "std")]
+    pub execution_timeout: Option<core::time::Duration>,
+    /// Charge the executed instructions according to the model instead of
+    /// [`InterpreterParams::gas_costs`]. The fees and the gas limits of the
+    /// transaction are still computed from [`InterpreterParams::gas_costs`].
+    pub gas_cost_model: Option<SharedGasCostModel>,
+    /// Pass a checkpoint of the execution every `N` executed instructions to the
+    /// receiver set by [`Interpreter::with_checkpoint_receiver`], so tooling can resume
+    /// the analysis of a long execution from the latest checkpoint.
+    pub checkpoint_interval: Option<u64>,
+    /// Notify the watcher set by [`Interpreter::with_progress_watcher`] every `N`
+    /// retired instructions, e.g. to drive a progress bar or a watchdog.
+    pub progress_stride: Option<u64>,
+    /// Restrict the opcodes the interpreter executes, e.g. to forbid `ECAL` or the
+    /// contract calls in a sandbox. Executing a disabled opcode panics with
+    /// [`PanicReason::OpcodeDisabled`].
+    pub opcode_filter: OpcodeFilter,
+    /// The maximum number of nested call frames. A `CALL` beyond the limit panics
+    /// with [`PanicReason::CallDepthExceeded`]. The consensus rules don't limit the
+    /// depth, which is only bounded by the gas and the memory, so the default is
+    /// `None`.
+    pub max_call_depth: Option<usize>,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -169,12 +291,29 @@ impl Default for InterpreterParams {
             gas_costs: Default::default(),
             max_inputs: fuel_tx::TxParameters::DEFAULT.max_inputs(),
             contract_max_size: fuel_tx::ContractParameters::DEFAULT.contract_max_size(),
-            tx_offset: fuel_tx::TxParameters::DEFAULT.tx_offset(),
+            tx_memory_layout: TxMemoryLayout::LATEST,
             max_message_data_length: fuel_tx::PredicateParameters::DEFAULT
                 .max_message_data_length(),
+            max_message_outputs: fuel_tx::TxParameters::DEFAULT.max_message_outputs(),
+            max_message_outputs_data_length: fuel_tx::TxParameters::DEFAULT
+                .max_message_outputs_data_length(),
             chain_id: ChainId::default(),
             fee_params: FeeParameters::default(),
             base_asset_id: Default::default(),
+            strict_instruction_decoding: false,
+            call_frame_integrity_checks: false,
+            #[cfg(feature = "delegate-call")]
+            delegate_calls: false,
+            #[cfg(feature = "static-call")]
+            static_calls: false,
+            max_instructions: None,
+            #[cfg(feature = "std")]
+            execution_timeout: None,
+            gas_cost_model: None,
+            checkpoint_interval: None,
+            progress_stride: None,
+            opcode_filter: OpcodeFilter::AllowAll,
+            max_call_depth: None,
         }
     }
 }
@@ -188,11 +327,27 @@ impl InterpreterParams {
             gas_costs: params.gas_costs,
             max_inputs: params.max_inputs,
             contract_max_size: params.contract_max_size,
-            tx_offset: params.tx_offset,
+            tx_memory_layout: TxMemoryLayout::LATEST,
             max_message_data_length: params.max_message_data_length,
+            max_message_outputs: params.max_message_outputs,
+            max_message_outputs_data_length: params.max_message_outputs_data_length,
             chain_id: params.chain_id,
             fee_params: params.fee_params,
             base_asset_id: params.base_asset_id,
+            strict_instruction_decoding: false,
+            call_frame_integrity_checks: false,
+            #[cfg(feature = "delegate-call")]
+            delegate_calls: false,
+            #[cfg(feature = "static-call")]
+            static_calls: false,
+            max_instructions: None,
+            #[cfg(feature = "std")]
+            execution_timeout: None,
+            gas_cost_model: None,
+            checkpoint_interval: None,
+            progress_stride: None,
+            opcode_filter: OpcodeFilter::AllowAll,
+            max_call_depth: None,
         }
     }
 }
@@ -238,6 +393,15 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         self.frames.as_slice()
     }
 
+    /// The number of the active call frames, `0` while the script itself is running.
+    pub fn call_depth(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub(crate) fn returned_frames_gas(&self) -> &[Word] {
+        self.returned_frames_gas.as_slice()
+    }
+
     /// Debug handler
     pub const fn debugger(&self) -> &Debugger {
         &self.debugger
@@ -274,6 +438,15 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         &self.interpreter_params.gas_costs
     }
 
+    /// The gas costs charged for the executed instructions, see
+    /// [`InterpreterParams::gas_cost_model`].
+    pub fn gas_model(&self) -> GasModelRef<'_> {
+        match &self.interpreter_params.gas_cost_model {
+            Some(model) => GasModelRef::Model(&**model),
+            None => GasModelRef::Costs(&self.interpreter_params.gas_costs),
+        }
+    }
+
     /// Get the Fee Parameters
     pub fn fee_params(&self) -> &FeeParameters {
         &self.interpreter_params.fee_params
@@ -289,9 +462,15 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         self.interpreter_params.contract_max_size
     }
 
-    /// Get tx_offset value
+    /// Offset of the transaction in the memory, according to the
+    /// [`InterpreterParams::tx_memory_layout`].
     pub fn tx_offset(&self) -> usize {
-        self.interpreter_params.tx_offset
+        self.tx_memory_layout().tx_offset(self.max_inputs())
+    }
+
+    /// Layout of the transaction data in the memory
+    pub fn tx_memory_layout(&self) -> TxMemoryLayout {
+        self.interpreter_params.tx_memory_layout
     }
 
     /// Get max_message_data_length value
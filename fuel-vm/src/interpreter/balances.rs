@@ -164,22 +164,22 @@ impl RuntimeBalances {
             .map_or((value == 0).then_some(0), |r| r.ok())
     }
 
-    /// Write all assets into the start of VM stack, i.e. at $ssp.
+    /// Write all assets to their offsets in the VM stack, growing it if needed.
     /// Panics if the assets cannot fit.
     pub fn to_vm<M, S, Tx, Ecal>(self, vm: &mut Interpreter<M, S, Tx, Ecal>)
     where
         M: Memory,
         Tx: ExecutableTransaction,
     {
-        let len = (vm.max_inputs() as usize).saturating_mul(BALANCE_ENTRY_SIZE) as Word;
-
-        let new_ssp = vm.registers[RegId::SSP].checked_add(len).expect(
-            "Consensus parameters must not allow stack overflow during VM initialization",
-        );
-        vm.memory_mut().grow_stack(new_ssp).expect(
-            "Consensus parameters must not allow stack overflow during VM initialization",
-        );
-        vm.registers[RegId::SSP] = new_ssp;
+        let len = (vm.max_inputs() as usize).saturating_mul(BALANCE_ENTRY_SIZE);
+        let end = vm.tx_memory_layout().balances_offset().saturating_add(len) as Word;
+
+        if vm.registers[RegId::SSP] < end {
+            vm.memory_mut().grow_stack(end).expect(
+                "Consensus parameters must not allow stack overflow during VM initialization",
+            );
+            vm.registers[RegId::SSP] = end;
+        }
 
         self.state.iter().for_each(|(asset, balance)| {
             let value = balance.value();
@@ -37,11 +37,7 @@ where
         dst: RegisterId,
         blob_id_ptr: Word,
     ) -> IoResult<(), S::DataError> {
-        let gas_cost = self
-            .interpreter_params
-            .gas_costs
-            .bsiz()
-            .map_err(PanicReason::from)?;
+        let gas_cost = self.gas_model().bsiz().map_err(PanicReason::from)?;
         self.gas_charge(gas_cost.base())?;
 
         let blob_id = BlobId::from(self.memory.as_ref().read_bytes(blob_id_ptr)?);
@@ -64,11 +60,7 @@ where
         blob_offset: Word,
         len: Word,
     ) -> IoResult<(), S::DataError> {
-        let gas_cost = self
-            .interpreter_params
-            .gas_costs
-            .bldd()
-            .map_err(PanicReason::from)?;
+        let gas_cost = self.gas_model().bldd().map_err(PanicReason::from)?;
         self.gas_charge(gas_cost.base())?;
 
         let blob_id = BlobId::from(self.memory.as_ref().read_bytes(blob_id_ptr)?);
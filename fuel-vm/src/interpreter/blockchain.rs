@@ -111,7 +111,7 @@ where
         length_unpadded: Word,
         mode: Imm06,
     ) -> IoResult<(), S::DataError> {
-        let gas_cost = self.gas_costs().ldc();
+        let gas_cost = self.gas_model().ldc();
         // Charge only for the `base` execution.
         // We will charge for the contracts size in the `load_contract_code`.
         self.gas_charge(gas_cost.base())?;
@@ -174,7 +174,7 @@ where
     }
 
     pub(crate) fn mint(&mut self, a: Word, b: Word) -> IoResult<(), S::DataError> {
-        let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte();
+        let new_storage_gas_per_byte = self.gas_model().new_storage_per_byte();
         let (
             SystemRegisters {
                 cgas,
@@ -209,7 +209,7 @@ where
         c: Word,
         d: Word,
     ) -> IoResult<(), S::DataError> {
-        let gas_cost = self.gas_costs().ccp();
+        let gas_cost = self.gas_model().ccp();
         // Charge only for the `base` execution.
         // We will charge for the contract's size in the `code_copy`.
         self.gas_charge(gas_cost.base())?;
@@ -272,7 +272,7 @@ where
     }
 
     pub(crate) fn code_root(&mut self, a: Word, b: Word) -> IoResult<(), S::DataError> {
-        let gas_cost = self.gas_costs().croo();
+        let gas_cost = self.gas_model().croo();
         self.gas_charge(gas_cost.base())?;
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
@@ -307,7 +307,7 @@ where
         ra: RegisterId,
         b: Word,
     ) -> IoResult<(), S::DataError> {
-        let gas_cost = self.gas_costs().csiz();
+        let gas_cost = self.gas_model().csiz();
         // Charge only for the `base` execution.
         // We will charge for the contracts size in the `code_size`.
         self.gas_charge(gas_cost.base())?;
@@ -432,7 +432,7 @@ where
         rb: RegisterId,
         c: Word,
     ) -> IoResult<(), S::DataError> {
-        let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte();
+        let new_storage_gas_per_byte = self.gas_model().new_storage_per_byte();
         let (
             SystemRegisters {
                 cgas,
@@ -478,7 +478,7 @@ where
         c: Word,
         d: Word,
     ) -> IoResult<(), S::DataError> {
-        let new_storage_per_byte = self.gas_costs().new_storage_per_byte();
+        let new_storage_per_byte = self.gas_model().new_storage_per_byte();
         let contract_id = self.internal_contract();
         let (
             SystemRegisters {
@@ -536,10 +536,15 @@ where
     ) -> IoResult<(), S::DataError> {
         let base_asset_id = self.interpreter_params.base_asset_id;
         let max_message_data_length = self.max_message_data_length();
+        let max_message_outputs = self.interpreter_params.max_message_outputs;
+        let max_message_outputs_data_length =
+            self.interpreter_params.max_message_outputs_data_length;
         let (SystemRegisters { fp, pc, .. }, _) = split_registers(&mut self.registers);
         let input = MessageOutputCtx {
             base_asset_id,
             max_message_data_length,
+            max_message_outputs,
+            max_message_outputs_data_length,
             memory: self.memory.as_mut(),
             receipts: &mut self.receipts,
             balances: &mut self.balances,
@@ -1207,6 +1212,8 @@ where
 {
     base_asset_id: AssetId,
     max_message_data_length: u64,
+    max_message_outputs: u16,
+    max_message_outputs_data_length: u64,
     memory: &'vm mut MemoryInstance,
     receipts: &'vm mut ReceiptsCtx,
     balances: &'vm mut RuntimeBalances,
@@ -1233,6 +1240,30 @@ where
             return Err(RuntimeError::Recoverable(PanicReason::MessageDataTooLong));
         }
 
+        let (message_outputs, message_outputs_data_length) = self
+            .receipts
+            .as_ref()
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::MessageOut { len, .. } => Some(*len),
+                _ => None,
+            })
+            .fold((0u64, 0u64), |(count, length), len| {
+                (count.saturating_add(1), length.saturating_add(len))
+            });
+        if message_outputs >= u64::from(self.max_message_outputs) {
+            return Err(RuntimeError::Recoverable(
+                PanicReason::TooManyMessageOutputs,
+            ));
+        }
+        if message_outputs_data_length.saturating_add(self.msg_data_len)
+            > self.max_message_outputs_data_length
+        {
+            return Err(RuntimeError::Recoverable(
+                PanicReason::MessageOutputsDataTooLong,
+            ));
+        }
+
         let msg_data = self
             .memory
             .read(self.msg_data_ptr, self.msg_data_len)?
@@ -32,6 +32,10 @@ struct Input {
     amount_coins_to_send: Word,
     internal: bool,
     max_message_data_length: Word,
+    max_message_outputs: u16,
+    max_message_outputs_data_length: Word,
+    /// Receipts produced by the transaction before the `SMO`
+    receipts: Vec<Receipt>,
     memory: Vec<(usize, Vec<u8>)>,
     /// Initial balance of the zeroed AssedId, same for both default contract and
     /// external context
@@ -57,11 +61,25 @@ impl Default for Input {
             internal: false,
             memory: vec![(400, Address::from([1u8; 32]).to_vec())],
             max_message_data_length: 100,
+            max_message_outputs: u16::MAX,
+            max_message_outputs_data_length: Word::MAX,
+            receipts: vec![],
             initial_balance: 0,
         }
     }
 }
 
+fn message_out(len: usize) -> Receipt {
+    Receipt::message_out(
+        &Bytes32::default(),
+        0,
+        Address::default(),
+        Address::default(),
+        0,
+        vec![0; len],
+    )
+}
+
 #[test_case(
     Input {
         recipient_mem_address: 400,
@@ -126,6 +144,50 @@ impl Default for Input {
     } => Err(RuntimeError::Recoverable(PanicReason::MessageDataTooLong))
     ; "call abi length > max message data length"
 )]
+#[test_case(
+    Input {
+        recipient_mem_address: 400,
+        msg_data_ptr: 0,
+        msg_data_len: 10,
+        max_message_outputs: 2,
+        receipts: vec![message_out(10)],
+        ..Default::default()
+    } => matches Ok(Output { .. })
+    ; "message count below max message outputs"
+)]
+#[test_case(
+    Input {
+        recipient_mem_address: 400,
+        msg_data_ptr: 0,
+        msg_data_len: 10,
+        max_message_outputs: 2,
+        receipts: vec![message_out(10), Receipt::ret(ContractId::default(), 0, 0, 0), message_out(10)],
+        ..Default::default()
+    } => Err(RuntimeError::Recoverable(PanicReason::TooManyMessageOutputs))
+    ; "message count reaches max message outputs"
+)]
+#[test_case(
+    Input {
+        recipient_mem_address: 400,
+        msg_data_ptr: 0,
+        msg_data_len: 10,
+        max_message_outputs_data_length: 30,
+        receipts: vec![message_out(10), message_out(10)],
+        ..Default::default()
+    } => matches Ok(Output { .. })
+    ; "total message data length equal to max message outputs data length"
+)]
+#[test_case(
+    Input {
+        recipient_mem_address: 400,
+        msg_data_ptr: 0,
+        msg_data_len: 11,
+        max_message_outputs_data_length: 30,
+        receipts: vec![message_out(10), message_out(10)],
+        ..Default::default()
+    } => Err(RuntimeError::Recoverable(PanicReason::MessageOutputsDataTooLong))
+    ; "total message data length > max message outputs data length"
+)]
 #[test_case(
     Input {
         recipient_mem_address: 400,
@@ -204,6 +266,9 @@ fn test_smo(
         internal,
         memory: mem,
         max_message_data_length,
+        max_message_outputs,
+        max_message_outputs_data_length,
+        receipts,
         initial_balance,
     }: Input,
 ) -> Result<Output, RuntimeError<Infallible>> {
@@ -214,7 +279,7 @@ fn test_smo(
     for (offset, bytes) in mem {
         memory[offset..offset + bytes.len()].copy_from_slice(bytes.as_slice());
     }
-    let mut receipts = Default::default();
+    let mut receipts = ReceiptsCtx::from(receipts);
     let mut storage = MemoryStorage::default();
     let old_balance = storage
         .contract_asset_id_balance_replace(
@@ -232,6 +297,8 @@ fn test_smo(
     let input = MessageOutputCtx {
         base_asset_id,
         max_message_data_length,
+        max_message_outputs,
+        max_message_outputs_data_length,
         memory: &mut memory,
         receipts: &mut receipts,
         balances: &mut balances,
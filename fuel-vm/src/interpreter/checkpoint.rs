@@ -0,0 +1,249 @@
+//! Checkpoints of long executions, taken every
+//! [`InterpreterParams::checkpoint_interval`](crate::interpreter::InterpreterParams::checkpoint_interval)
+//! instructions.
+
+use super::{
+    Interpreter,
+    Memory,
+};
+use crate::consts::VM_MAX_RAM;
+use alloc::{
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt;
+use fuel_asm::{
+    RegId,
+    Word,
+};
+
+/// Size of the memory pages reported by the checkpoints.
+pub const CHECKPOINT_PAGE_SIZE: usize = 4096;
+
+/// A page of the memory, aligned to [`CHECKPOINT_PAGE_SIZE`]. The first and the last
+/// page of the stack or of the heap may be shorter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryPage {
+    /// Address of the first byte of the page.
+    pub address: Word,
+    /// Content of the page.
+    pub data: Vec<u8>,
+}
+
+/// A lightweight snapshot of the execution, relative to the previous checkpoint.
+///
+/// Applying the dirty pages of all of the checkpoints, in order, to a zeroed memory
+/// restores the stack `[0, $sp)` and the heap `[$hp, VM_MAX_RAM)` at the checkpoint.
+/// The memory outside of them isn't tracked. The storage, the call frames and the
+/// receipts themselves aren't part of the checkpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionCheckpoint {
+    /// Number of the instructions executed since the initialization.
+    pub executed_instructions: u64,
+    /// Values of all of the registers.
+    pub registers: Vec<Word>,
+    /// Pages of the stack and of the heap modified since the previous checkpoint.
+    pub dirty_pages: Vec<MemoryPage>,
+    /// Number of the receipts produced so far.
+    pub receipts_count: usize,
+}
+
+/// Receives the checkpoints of the execution, e.g. to persist them.
+pub trait CheckpointReceiver {
+    /// Called after every
+    /// [`InterpreterParams::checkpoint_interval`](crate::interpreter::InterpreterParams::checkpoint_interval)
+    /// executed instructions.
+    fn on_checkpoint(&self, checkpoint: &ExecutionCheckpoint);
+}
+
+/// Takes the checkpoints and keeps the memory of the previous one.
+#[derive(Default, Clone)]
+pub(crate) struct Checkpointer {
+    receiver: Option<Arc<dyn CheckpointReceiver + Send + Sync>>,
+    /// Stack at the previous checkpoint, starting at the address zero.
+    stack: Vec<u8>,
+    /// Heap at the previous checkpoint, ending at `VM_MAX_RAM`.
+    heap: Vec<u8>,
+}
+
+impl fmt::Debug for Checkpointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checkpointer")
+            .field(
+                "receiver",
+                &match self.receiver {
+                    Some(_) => "enabled",
+                    None => "disabled",
+                },
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl Checkpointer {
+    pub(crate) fn set_receiver(
+        &mut self,
+        receiver: Arc<dyn CheckpointReceiver + Send + Sync>,
+    ) {
+        self.receiver = Some(receiver);
+    }
+
+    pub(crate) fn has_receiver(&self) -> bool {
+        self.receiver.is_some()
+    }
+
+    /// Forgets the previous checkpoint, before a new execution.
+    pub(crate) fn reset(&mut self) {
+        self.stack.clear();
+        self.heap.clear();
+    }
+}
+
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
+where
+    M: Memory,
+{
+    /// Passes a checkpoint to the receiver once the interval elapses.
+    pub(crate) fn checkpoint_if_due(&mut self) {
+        let Some(interval) = self.interpreter_params.checkpoint_interval else {
+            return
+        };
+        if !self.checkpointer.has_receiver()
+            || self.executed_instructions.checked_rem(interval) != Some(0)
+        {
+            return
+        }
+
+        let checkpointer = &mut self.checkpointer;
+
+        let memory = self.memory.as_ref();
+        let sp = self.registers[RegId::SP];
+        let hp = self.registers[RegId::HP];
+        let stack = memory.read(0u64, sp).unwrap_or_default();
+        let heap = memory
+            .read(hp, VM_MAX_RAM.saturating_sub(hp))
+            .unwrap_or_default();
+
+        let mut dirty_pages = Vec::new();
+        push_dirty_pages(&mut dirty_pages, 0, stack, 0, &checkpointer.stack);
+        push_dirty_pages(
+            &mut dirty_pages,
+            heap_start(heap),
+            heap,
+            heap_start(&checkpointer.heap),
+            &checkpointer.heap,
+        );
+        checkpointer.stack = stack.to_vec();
+        checkpointer.heap = heap.to_vec();
+
+        let checkpoint = ExecutionCheckpoint {
+            executed_instructions: self.executed_instructions,
+            registers: self.registers.to_vec(),
+            dirty_pages,
+            receipts_count: self.receipts.len(),
+        };
+
+        if let Some(receiver) = &checkpointer.receiver {
+            receiver.on_checkpoint(&checkpoint);
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)] // Safety: VM_MAX_RAM fits into usize
+fn heap_start(heap: &[u8]) -> usize {
+    (VM_MAX_RAM as usize).saturating_sub(heap.len())
+}
+
+/// Appends the pages of `current`, starting at the address `start`, that differ from
+/// `previous`, starting at `previous_start`. Bytes outside of `previous` are zero.
+fn push_dirty_pages(
+    pages: &mut Vec<MemoryPage>,
+    start: usize,
+    current: &[u8],
+    previous_start: usize,
+    previous: &[u8],
+) {
+    let end = start.saturating_add(current.len());
+    let previous_end = previous_start.saturating_add(previous.len());
+
+    let mut page_start = start;
+    while page_start < end {
+        let page_end = page_start
+            .saturating_div(CHECKPOINT_PAGE_SIZE)
+            .saturating_add(1)
+            .saturating_mul(CHECKPOINT_PAGE_SIZE)
+            .min(end);
+        #[allow(clippy::arithmetic_side_effects)]
+        // Safety: start <= page_start < page_end <= end
+        let data = &current[page_start - start..page_end - start];
+
+        let overlap_start = page_start.max(previous_start);
+        let overlap_end = page_end.min(previous_end);
+        #[allow(clippy::arithmetic_side_effects)] // Safety: the overlap is within both
+        let is_dirty = if overlap_start < overlap_end {
+            data[overlap_start - page_start..overlap_end - page_start]
+                != previous[overlap_start - previous_start..overlap_end - previous_start]
+                || data[..overlap_start - page_start]
+                    .iter()
+                    .chain(&data[overlap_end - page_start..])
+                    .any(|byte| *byte != 0)
+        } else {
+            data.iter().any(|byte| *byte != 0)
+        };
+
+        if is_dirty {
+            pages.push(MemoryPage {
+                address: page_start as Word,
+                data: data.to_vec(),
+            });
+        }
+        page_start = page_end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn only_modified_pages_are_dirty() {
+        let previous = vec![0u8; 3 * CHECKPOINT_PAGE_SIZE];
+        let mut current = previous.clone();
+        current[CHECKPOINT_PAGE_SIZE + 1] = 1;
+        current.push(2);
+
+        let mut pages = Vec::new();
+        push_dirty_pages(&mut pages, 0, &current, 0, &previous);
+
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| (page.address, page.data.len()))
+                .collect::<Vec<_>>(),
+            [
+                (CHECKPOINT_PAGE_SIZE as Word, CHECKPOINT_PAGE_SIZE),
+                (3 * CHECKPOINT_PAGE_SIZE as Word, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn unaligned_heap_pages_end_at_the_page_boundary() {
+        let start = 2 * CHECKPOINT_PAGE_SIZE - 3;
+        let current = vec![1u8; 5];
+
+        let mut pages = Vec::new();
+        push_dirty_pages(&mut pages, start, &current, start, &[1, 1, 1]);
+
+        assert_eq!(
+            pages,
+            [MemoryPage {
+                address: 2 * CHECKPOINT_PAGE_SIZE as Word,
+                data: vec![1, 1],
+            }]
+        );
+    }
+}
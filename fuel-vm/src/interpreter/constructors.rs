@@ -14,14 +14,25 @@ use crate::{
     consts::*,
     context::Context,
     interpreter::{
+        checkpoint::Checkpointer,
+        progress::ProgressNotifier,
+        vm_metrics::VmMetricsReporter,
+        CheckpointReceiver,
+        CowMemory,
         InterpreterParams,
+        MemoryStats,
         PanicContext,
+        ProgressWatcher,
+        VmMetrics,
     },
     state::Debugger,
 };
 
 use alloc::vec;
 
+#[cfg(feature = "std")]
+use crate::interpreter::HaltHandle;
+
 #[cfg(feature = "profile-any")]
 use crate::profiler::ProfileReceiver;
 
@@ -71,6 +82,14 @@ where
             registers: [0; VM_REGISTER_COUNT],
             memory,
             frames: vec![],
+            frame_canaries: vec![],
+            static_call_depth: None,
+            returned_frames_gas: vec![],
+            executed_instructions: 0,
+            per_instruction_checks: interpreter_params.max_instructions.is_some(),
+            memory_stats: MemoryStats::default(),
+            #[cfg(feature = "std")]
+            execution_deadline: None,
             receipts: Default::default(),
             tx: Default::default(),
             input_contracts: Default::default(),
@@ -81,6 +100,11 @@ where
             context: Context::default(),
             balances: RuntimeBalances::default(),
             profiler: Profiler::default(),
+            checkpointer: Checkpointer::default(),
+            progress_notifier: ProgressNotifier::default(),
+            vm_metrics: VmMetricsReporter::default(),
+            #[cfg(feature = "std")]
+            halt_handle: None,
             interpreter_params,
             panic_context: PanicContext::None,
             ecal_state,
@@ -98,6 +122,64 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         self.profiler.set_receiver(alloc::boxed::Box::new(receiver));
         self
     }
+
+    /// Sets the receiver of the checkpoints taken every
+    /// [`InterpreterParams::checkpoint_interval`] instructions.
+    pub fn with_checkpoint_receiver<R>(&mut self, receiver: R) -> &mut Self
+    where
+        R: CheckpointReceiver + Send + Sync + 'static,
+    {
+        self.checkpointer
+            .set_receiver(alloc::sync::Arc::new(receiver));
+        self.update_per_instruction_checks();
+        self
+    }
+
+    /// Sets the watcher notified every [`InterpreterParams::progress_stride`] retired
+    /// instructions.
+    pub fn with_progress_watcher<W>(&mut self, watcher: W) -> &mut Self
+    where
+        W: ProgressWatcher + Send + Sync + 'static,
+    {
+        self.progress_notifier
+            .set_watcher(alloc::sync::Arc::new(watcher));
+        self.update_per_instruction_checks();
+        self
+    }
+
+    /// Sets the receiver of the metrics of the interpreter internals, replacing the
+    /// `metrics::FacadeMetrics` with the `metrics` feature.
+    pub fn with_vm_metrics<V>(&mut self, metrics: V) -> &mut Self
+    where
+        V: VmMetrics + Send + Sync + 'static,
+    {
+        self.vm_metrics.set_metrics(alloc::sync::Arc::new(metrics));
+        self
+    }
+
+    /// Sets the handle through which the execution can be halted and dumped.
+    #[cfg(feature = "std")]
+    pub fn with_halt_handle(&mut self, handle: HaltHandle) -> &mut Self {
+        self.halt_handle = Some(handle);
+        self.update_per_instruction_checks();
+        self
+    }
+}
+
+impl<S, Tx, Ecal> Interpreter<CowMemory, S, Tx, Ecal>
+where
+    Self: Clone,
+{
+    /// Forks the interpreter, e.g. at a call boundary to explore the speculative
+    /// branches of the execution independently.
+    ///
+    /// The [`CowMemory`] of the fork is shared with the original until either of them
+    /// writes to it, so forking doesn't copy the memory. The storage is cloned, so it
+    /// should be cheap to clone too, e.g. an overlay of the changes over a storage
+    /// shared behind an `Arc`.
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -90,7 +90,7 @@ where
         b: Word,
         c: Word,
     ) -> IoResult<(), S::DataError> {
-        let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte();
+        let new_storage_gas_per_byte = self.gas_model().new_storage_per_byte();
         let tx_offset = self.tx_offset();
         let (
             SystemRegisters {
@@ -134,7 +134,7 @@ where
         d: Word,
     ) -> IoResult<(), S::DataError> {
         let tx_offset = self.tx_offset();
-        let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte();
+        let new_storage_gas_per_byte = self.gas_model().new_storage_per_byte();
         let (
             SystemRegisters {
                 cgas,
@@ -19,7 +19,7 @@ use fuel_crypto::{
     Hasher,
     Message,
     PublicKey,
-    Signature,
+    RecoverableSignature,
 };
 use fuel_types::{
     Bytes32,
@@ -105,7 +105,7 @@ pub(crate) fn secp256k1_recover(
     let sig = Bytes64::from(memory.read_bytes(b)?);
     let msg = Bytes32::from(memory.read_bytes(c)?);
 
-    let signature = Signature::from_bytes_ref(&sig);
+    let signature = RecoverableSignature::from_bytes_ref(&sig);
     let message = Message::from_bytes_ref(&msg);
 
     match signature.recover(message) {
@@ -31,9 +31,10 @@ fn test_recover_secp256k1() -> SimpleResult<()> {
     let secret = SecretKey::try_from(&[2u8; 32][..]).unwrap();
     let public_key = PublicKey::from(&secret);
     let message = Message::new([3u8; 100]);
-    let signature = Signature::sign(&secret, &message);
+    let signature = RecoverableSignature::sign(&secret, &message);
 
-    memory[sig_address..sig_address + Signature::LEN].copy_from_slice(signature.as_ref());
+    memory[sig_address..sig_address + RecoverableSignature::LEN]
+        .copy_from_slice(signature.as_ref());
     memory[msg_address..msg_address + Message::LEN].copy_from_slice(message.as_ref());
 
     secp256k1_recover(
@@ -124,7 +125,7 @@ fn test_verify_ed25519() -> SimpleResult<()> {
 
     memory[pubkey_address..pubkey_address + Bytes32::LEN]
         .copy_from_slice(signing_key.verifying_key().as_ref());
-    memory[sig_address..sig_address + Signature::LEN]
+    memory[sig_address..sig_address + Bytes64::LEN]
         .copy_from_slice(&signature.to_bytes());
     memory[msg_address..msg_address + message.len()].copy_from_slice(message.as_ref());
 
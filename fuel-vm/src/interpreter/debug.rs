@@ -65,7 +65,13 @@ mod tests {
     };
 
     use super::Interpreter;
-    use crate::prelude::*;
+    use crate::{
+        interpreter::{
+            InterpreterParams,
+            MemoryInstance,
+        },
+        prelude::*,
+    };
     use fuel_asm::RegId;
 
     #[test]
@@ -148,6 +154,68 @@ mod tests {
             });
     }
 
+    #[test]
+    fn breakpoint_contract() {
+        use crate::util::test_helpers::TestBuilder;
+        use fuel_asm::op;
+        use fuel_types::canonical::Serialize;
+
+        let mut test_context = TestBuilder::new(2322u64);
+
+        let contract_code = vec![
+            op::addi(0x10, RegId::ZERO, 8),
+            op::addi(0x11, RegId::ZERO, 16),
+            op::ret(RegId::ONE),
+        ];
+        let contract_id = test_context
+            .setup_contract(contract_code, None, None)
+            .contract_id;
+
+        let script = vec![
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ];
+        let tx = test_context
+            .start_script(script, Call::new(contract_id, 0, 0).to_bytes())
+            .script_gas_limit(1_000_000)
+            .contract_input(contract_id)
+            .fee_input()
+            .contract_output(&contract_id)
+            .build();
+        let consensus_params = test_context.get_consensus_params().clone();
+        let tx = tx
+            .into_ready(
+                0,
+                consensus_params.gas_costs(),
+                consensus_params.fee_params(),
+            )
+            .unwrap();
+
+        let mut vm = Interpreter::<_, _, Script>::with_storage(
+            MemoryInstance::new(),
+            test_context.get_storage().clone(),
+            InterpreterParams::new(0, &consensus_params),
+        );
+        let breakpoint = Breakpoint::new(contract_id, 1);
+        vm.set_breakpoint(breakpoint);
+
+        let state = vm
+            .transact(tx)
+            .map(ProgramState::from)
+            .expect("Failed to execute script!");
+
+        // The execution stops inside the call
+        let debug = state.debug_ref().expect("Expected breakpoint");
+        assert_eq!(debug.breakpoint(), Some(&breakpoint));
+        assert_eq!(vm.call_depth(), 1);
+        assert_eq!(vm.registers()[0x10], 8);
+        assert_eq!(vm.registers()[0x11], 0);
+
+        let state = vm.resume().expect("Failed to resume");
+        assert_eq!(state, ProgramState::Return(1));
+    }
+
     #[test]
     fn single_stepping() {
         use fuel_asm::op;
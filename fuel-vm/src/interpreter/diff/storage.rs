@@ -17,11 +17,14 @@ use fuel_types::{
 use crate::storage::{
     BlobBytes,
     BlobData,
+    ContractStateIter,
+    ContractStateIterator,
     ContractsAssetKey,
     ContractsAssetsStorage,
     ContractsStateData,
     ContractsStateKey,
     InterpreterStorage,
+    UpgradesStorage,
     UploadedBytecode,
     UploadedBytecodes,
 };
@@ -97,6 +100,14 @@ where
             registers: self.registers,
             memory: self.memory,
             frames: self.frames,
+            frame_canaries: self.frame_canaries,
+            static_call_depth: self.static_call_depth,
+            returned_frames_gas: self.returned_frames_gas,
+            executed_instructions: self.executed_instructions,
+            per_instruction_checks: self.per_instruction_checks,
+            memory_stats: self.memory_stats,
+            #[cfg(feature = "std")]
+            execution_deadline: self.execution_deadline,
             receipts: self.receipts,
             tx: self.tx,
             initial_balances: self.initial_balances,
@@ -109,6 +120,11 @@ where
             balances: self.balances,
             panic_context: self.panic_context,
             profiler: self.profiler,
+            checkpointer: self.checkpointer,
+            progress_notifier: self.progress_notifier,
+            vm_metrics: self.vm_metrics,
+            #[cfg(feature = "std")]
+            halt_handle: self.halt_handle,
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
         }
@@ -187,6 +203,14 @@ where
             registers: self.registers,
             memory: self.memory,
             frames: self.frames,
+            frame_canaries: self.frame_canaries,
+            static_call_depth: self.static_call_depth,
+            returned_frames_gas: self.returned_frames_gas,
+            executed_instructions: self.executed_instructions,
+            per_instruction_checks: self.per_instruction_checks,
+            memory_stats: self.memory_stats,
+            #[cfg(feature = "std")]
+            execution_deadline: self.execution_deadline,
             receipts: self.receipts,
             tx: self.tx,
             initial_balances: self.initial_balances,
@@ -199,6 +223,11 @@ where
             balances: self.balances,
             panic_context: self.panic_context,
             profiler: self.profiler,
+            checkpointer: self.checkpointer,
+            progress_notifier: self.progress_notifier,
+            vm_metrics: self.vm_metrics,
+            #[cfg(feature = "std")]
+            halt_handle: self.halt_handle,
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
         }
@@ -520,6 +549,37 @@ where
     }
 }
 
+impl<S> ContractStateIterator for Record<S>
+where
+    S: ContractStateIterator,
+{
+    fn contract_state_iter(
+        &self,
+        id: &ContractId,
+    ) -> ContractStateIter<'_, Self::DataError> {
+        self.0.contract_state_iter(id)
+    }
+}
+
+impl<S> UpgradesStorage for Record<S>
+where
+    S: UpgradesStorage,
+{
+    fn consensus_parameters(
+        &self,
+        version: u32,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        self.0.consensus_parameters(version)
+    }
+
+    fn state_transition_bytecode(
+        &self,
+        version: u32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.0.state_transition_bytecode(version)
+    }
+}
+
 impl StorageType for ContractsState {
     fn record_replace(
         key: &Self::Key,
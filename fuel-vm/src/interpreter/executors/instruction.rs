@@ -1,6 +1,7 @@
 use crate::{
     constraints::reg_key::ProgramRegistersSegment,
     error::{
+        ExecutionLimit,
         InterpreterError,
         IoResult,
         RuntimeError,
@@ -41,10 +42,57 @@ where
 {
     /// Execute the current instruction located in `$m[$pc]`.
     pub fn execute(&mut self) -> Result<ExecuteState, InterpreterError<S::DataError>> {
+        if self.per_instruction_checks {
+            return self.execute_with_checks()
+        }
         let raw_instruction = self.fetch_instruction()?;
         self.instruction(raw_instruction)
     }
 
+    /// Executes the current instruction within the execution limits, and takes the
+    /// checkpoints and notifies the progress after it.
+    fn execute_with_checks(
+        &mut self,
+    ) -> Result<ExecuteState, InterpreterError<S::DataError>> {
+        self.check_execution_limits()?;
+        let raw_instruction = self.fetch_instruction()?;
+        let state = self.instruction(raw_instruction)?;
+        self.checkpoint_if_due();
+        self.notify_progress_if_due();
+        Ok(state)
+    }
+
+    /// Aborts the execution if the instruction about to be executed exceeds a limit of
+    /// [`InterpreterParams`](crate::interpreter::InterpreterParams).
+    fn check_execution_limits(&self) -> Result<(), InterpreterError<S::DataError>> {
+        if let Some(max_instructions) = self.interpreter_params.max_instructions {
+            if self.executed_instructions >= max_instructions {
+                return Err(InterpreterError::ExecutionLimitExceeded(
+                    ExecutionLimit::Instructions,
+                ))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        if self
+            .execution_deadline
+            .is_some_and(|deadline| std::time::Instant::now() >= deadline)
+        {
+            return Err(InterpreterError::ExecutionLimitExceeded(
+                ExecutionLimit::Time,
+            ))
+        }
+
+        #[cfg(feature = "std")]
+        if self.halt_if_requested() {
+            return Err(InterpreterError::ExecutionLimitExceeded(
+                ExecutionLimit::HaltRequested,
+            ))
+        }
+
+        Ok(())
+    }
+
     /// Reads the current instruction located in `$m[$pc]`,
     /// performing memory boundary checks.
     fn fetch_instruction(
@@ -80,8 +128,23 @@ where
             }
         }
 
-        self.instruction_inner(raw.into())
-            .map_err(|e| InterpreterError::from_runtime(e, raw.into()))
+        self.executed_instructions = self.executed_instructions.saturating_add(1);
+        let memory_in_use = self.memory_in_use();
+        let result = self.instruction_inner(raw.into()).map_err(|e| {
+            let error = InterpreterError::from_runtime(e, raw.into());
+            if let Some(reason) = error.panic_reason() {
+                self.vm_metrics.panic(reason);
+            }
+            error
+        });
+        let memory_grown = self.memory_in_use().saturating_sub(memory_in_use);
+        self.memory_stats.record(
+            self.registers[RegId::SP],
+            self.registers[RegId::HP],
+            memory_grown,
+        );
+        self.vm_metrics.memory_grown(memory_grown);
+        result
     }
 
     fn instruction_inner(
@@ -91,12 +154,32 @@ where
         let instruction = Instruction::try_from(raw)
             .map_err(|_| RuntimeError::from(PanicReason::InvalidInstruction))?;
 
+        if self.interpreter_params.strict_instruction_decoding
+            && !instruction.is_canonical()
+        {
+            return Err(PanicReason::InvalidInstruction.into())
+        }
+
+        self.vm_metrics.instruction(instruction.opcode());
+
         // TODO additional branch that might be optimized after
         // https://github.com/FuelLabs/fuel-asm/issues/68
         if self.is_predicate() && !instruction.opcode().is_predicate_allowed() {
             return Err(PanicReason::ContractInstructionNotAllowed.into())
         }
 
+        if self.is_static_call() && !instruction.opcode().is_static_call_allowed() {
+            return Err(PanicReason::StateChangeInStaticCall.into())
+        }
+
+        if !self
+            .interpreter_params
+            .opcode_filter
+            .is_allowed(instruction.opcode())
+        {
+            return Err(PanicReason::OpcodeDisabled.into())
+        }
+
         // Short-hand for retrieving the value from the register with the given ID.
         // We use a macro to "close over" `self.registers` without taking ownership of it.
         macro_rules! r {
@@ -107,7 +190,7 @@ where
 
         match instruction {
             Instruction::ADD(add) => {
-                self.gas_charge(self.gas_costs().add())?;
+                self.gas_charge(self.gas_model().add())?;
                 let (a, b, c) = add.unpack();
                 self.alu_capture_overflow(
                     a.into(),
@@ -118,7 +201,7 @@ where
             }
 
             Instruction::ADDI(addi) => {
-                self.gas_charge(self.gas_costs().addi())?;
+                self.gas_charge(self.gas_model().addi())?;
                 let (a, b, imm) = addi.unpack();
                 self.alu_capture_overflow(
                     a.into(),
@@ -129,64 +212,64 @@ where
             }
 
             Instruction::AND(and) => {
-                self.gas_charge(self.gas_costs().and())?;
+                self.gas_charge(self.gas_model().and())?;
                 let (a, b, c) = and.unpack();
                 self.alu_set(a.into(), r!(b) & r!(c))?;
             }
 
             Instruction::ANDI(andi) => {
-                self.gas_charge(self.gas_costs().andi())?;
+                self.gas_charge(self.gas_model().andi())?;
                 let (a, b, imm) = andi.unpack();
                 self.alu_set(a.into(), r!(b) & Word::from(imm))?;
             }
 
             Instruction::DIV(div) => {
-                self.gas_charge(self.gas_costs().div())?;
+                self.gas_charge(self.gas_model().div())?;
                 let (a, b, c) = div.unpack();
                 let c = r!(c);
                 self.alu_error(a.into(), Word::div, r!(b), c, c == 0)?;
             }
 
             Instruction::DIVI(divi) => {
-                self.gas_charge(self.gas_costs().divi())?;
+                self.gas_charge(self.gas_model().divi())?;
                 let (a, b, imm) = divi.unpack();
                 let imm = Word::from(imm);
                 self.alu_error(a.into(), Word::div, r!(b), imm, imm == 0)?;
             }
 
             Instruction::EQ(eq) => {
-                self.gas_charge(self.gas_costs().eq_())?;
+                self.gas_charge(self.gas_model().eq_())?;
                 let (a, b, c) = eq.unpack();
                 self.alu_set(a.into(), (r!(b) == r!(c)) as Word)?;
             }
 
             Instruction::EXP(exp) => {
-                self.gas_charge(self.gas_costs().exp())?;
+                self.gas_charge(self.gas_model().exp())?;
                 let (a, b, c) = exp.unpack();
                 self.alu_boolean_overflow(a.into(), alu::exp, r!(b), r!(c))?;
             }
 
             Instruction::EXPI(expi) => {
-                self.gas_charge(self.gas_costs().expi())?;
+                self.gas_charge(self.gas_model().expi())?;
                 let (a, b, imm) = expi.unpack();
                 let expo = u32::from(imm);
                 self.alu_boolean_overflow(a.into(), Word::overflowing_pow, r!(b), expo)?;
             }
 
             Instruction::GT(gt) => {
-                self.gas_charge(self.gas_costs().gt())?;
+                self.gas_charge(self.gas_model().gt())?;
                 let (a, b, c) = gt.unpack();
                 self.alu_set(a.into(), (r!(b) > r!(c)) as Word)?;
             }
 
             Instruction::LT(lt) => {
-                self.gas_charge(self.gas_costs().lt())?;
+                self.gas_charge(self.gas_model().lt())?;
                 let (a, b, c) = lt.unpack();
                 self.alu_set(a.into(), (r!(b) < r!(c)) as Word)?;
             }
 
             Instruction::WDCM(wdcm) => {
-                self.gas_charge(self.gas_costs().wdcm())?;
+                self.gas_charge(self.gas_model().wdcm())?;
                 let (a, b, c, imm) = wdcm.unpack();
                 let args = wideint::CompareArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -194,7 +277,7 @@ where
             }
 
             Instruction::WQCM(wqcm) => {
-                self.gas_charge(self.gas_costs().wqcm())?;
+                self.gas_charge(self.gas_model().wqcm())?;
                 let (a, b, c, imm) = wqcm.unpack();
                 let args = wideint::CompareArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -202,7 +285,7 @@ where
             }
 
             Instruction::WDOP(wdop) => {
-                self.gas_charge(self.gas_costs().wdop())?;
+                self.gas_charge(self.gas_model().wdop())?;
                 let (a, b, c, imm) = wdop.unpack();
                 let args = wideint::MathArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -210,7 +293,7 @@ where
             }
 
             Instruction::WQOP(wqop) => {
-                self.gas_charge(self.gas_costs().wqop())?;
+                self.gas_charge(self.gas_model().wqop())?;
                 let (a, b, c, imm) = wqop.unpack();
                 let args = wideint::MathArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -218,7 +301,7 @@ where
             }
 
             Instruction::WDML(wdml) => {
-                self.gas_charge(self.gas_costs().wdml())?;
+                self.gas_charge(self.gas_model().wdml())?;
                 let (a, b, c, imm) = wdml.unpack();
                 let args = wideint::MulArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -226,7 +309,7 @@ where
             }
 
             Instruction::WQML(wqml) => {
-                self.gas_charge(self.gas_costs().wqml())?;
+                self.gas_charge(self.gas_model().wqml())?;
                 let (a, b, c, imm) = wqml.unpack();
                 let args = wideint::MulArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -234,7 +317,7 @@ where
             }
 
             Instruction::WDDV(wddv) => {
-                self.gas_charge(self.gas_costs().wddv())?;
+                self.gas_charge(self.gas_model().wddv())?;
                 let (a, b, c, imm) = wddv.unpack();
                 let args = wideint::DivArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -242,7 +325,7 @@ where
             }
 
             Instruction::WQDV(wqdv) => {
-                self.gas_charge(self.gas_costs().wqdv())?;
+                self.gas_charge(self.gas_model().wqdv())?;
                 let (a, b, c, imm) = wqdv.unpack();
                 let args = wideint::DivArgs::from_imm(imm)
                     .ok_or(PanicReason::InvalidImmediateValue)?;
@@ -250,40 +333,40 @@ where
             }
 
             Instruction::WDMD(wdmd) => {
-                self.gas_charge(self.gas_costs().wdmd())?;
+                self.gas_charge(self.gas_model().wdmd())?;
                 let (a, b, c, d) = wdmd.unpack();
                 self.alu_wideint_muldiv_u128(r!(a), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::WQMD(wqmd) => {
-                self.gas_charge(self.gas_costs().wqmd())?;
+                self.gas_charge(self.gas_model().wqmd())?;
                 let (a, b, c, d) = wqmd.unpack();
                 self.alu_wideint_muldiv_u256(r!(a), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::WDAM(wdam) => {
-                self.gas_charge(self.gas_costs().wdam())?;
+                self.gas_charge(self.gas_model().wdam())?;
                 let (a, b, c, d) = wdam.unpack();
                 self.alu_wideint_addmod_u128(r!(a), r!(b), r!(c), r!(d))?;
             }
             Instruction::WQAM(wqam) => {
-                self.gas_charge(self.gas_costs().wqam())?;
+                self.gas_charge(self.gas_model().wqam())?;
                 let (a, b, c, d) = wqam.unpack();
                 self.alu_wideint_addmod_u256(r!(a), r!(b), r!(c), r!(d))?;
             }
             Instruction::WDMM(wdmm) => {
-                self.gas_charge(self.gas_costs().wdmm())?;
+                self.gas_charge(self.gas_model().wdmm())?;
                 let (a, b, c, d) = wdmm.unpack();
                 self.alu_wideint_mulmod_u128(r!(a), r!(b), r!(c), r!(d))?;
             }
             Instruction::WQMM(wqmm) => {
-                self.gas_charge(self.gas_costs().wqmm())?;
+                self.gas_charge(self.gas_model().wqmm())?;
                 let (a, b, c, d) = wqmm.unpack();
                 self.alu_wideint_mulmod_u256(r!(a), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::MLOG(mlog) => {
-                self.gas_charge(self.gas_costs().mlog())?;
+                self.gas_charge(self.gas_model().mlog())?;
                 let (a, b, c) = mlog.unpack();
                 let (lhs, rhs) = (r!(b), r!(c));
                 self.alu_error(
@@ -300,33 +383,33 @@ where
             }
 
             Instruction::MOD(mod_) => {
-                self.gas_charge(self.gas_costs().mod_op())?;
+                self.gas_charge(self.gas_model().mod_op())?;
                 let (a, b, c) = mod_.unpack();
                 let rhs = r!(c);
                 self.alu_error(a.into(), Word::wrapping_rem, r!(b), rhs, rhs == 0)?;
             }
 
             Instruction::MODI(modi) => {
-                self.gas_charge(self.gas_costs().modi())?;
+                self.gas_charge(self.gas_model().modi())?;
                 let (a, b, imm) = modi.unpack();
                 let rhs = Word::from(imm);
                 self.alu_error(a.into(), Word::wrapping_rem, r!(b), rhs, rhs == 0)?;
             }
 
             Instruction::MOVE(move_) => {
-                self.gas_charge(self.gas_costs().move_op())?;
+                self.gas_charge(self.gas_model().move_op())?;
                 let (a, b) = move_.unpack();
                 self.alu_set(a.into(), r!(b))?;
             }
 
             Instruction::MOVI(movi) => {
-                self.gas_charge(self.gas_costs().movi())?;
+                self.gas_charge(self.gas_model().movi())?;
                 let (a, imm) = movi.unpack();
                 self.alu_set(a.into(), Word::from(imm))?;
             }
 
             Instruction::MROO(mroo) => {
-                self.gas_charge(self.gas_costs().mroo())?;
+                self.gas_charge(self.gas_model().mroo())?;
                 let (a, b, c) = mroo.unpack();
                 let (lhs, rhs) = (r!(b), r!(c));
                 self.alu_error(
@@ -343,7 +426,7 @@ where
             }
 
             Instruction::MUL(mul) => {
-                self.gas_charge(self.gas_costs().mul())?;
+                self.gas_charge(self.gas_model().mul())?;
                 let (a, b, c) = mul.unpack();
                 self.alu_capture_overflow(
                     a.into(),
@@ -354,7 +437,7 @@ where
             }
 
             Instruction::MULI(muli) => {
-                self.gas_charge(self.gas_costs().muli())?;
+                self.gas_charge(self.gas_model().muli())?;
                 let (a, b, imm) = muli.unpack();
                 self.alu_capture_overflow(
                     a.into(),
@@ -365,36 +448,36 @@ where
             }
 
             Instruction::MLDV(mldv) => {
-                self.gas_charge(self.gas_costs().mldv())?;
+                self.gas_charge(self.gas_model().mldv())?;
                 let (a, b, c, d) = mldv.unpack();
                 self.alu_muldiv(a.into(), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::NOOP(_noop) => {
-                self.gas_charge(self.gas_costs().noop())?;
+                self.gas_charge(self.gas_model().noop())?;
                 self.alu_clear()?;
             }
 
             Instruction::NOT(not) => {
-                self.gas_charge(self.gas_costs().not())?;
+                self.gas_charge(self.gas_model().not())?;
                 let (a, b) = not.unpack();
                 self.alu_set(a.into(), !r!(b))?;
             }
 
             Instruction::OR(or) => {
-                self.gas_charge(self.gas_costs().or())?;
+                self.gas_charge(self.gas_model().or())?;
                 let (a, b, c) = or.unpack();
                 self.alu_set(a.into(), r!(b) | r!(c))?;
             }
 
             Instruction::ORI(ori) => {
-                self.gas_charge(self.gas_costs().ori())?;
+                self.gas_charge(self.gas_model().ori())?;
                 let (a, b, imm) = ori.unpack();
                 self.alu_set(a.into(), r!(b) | Word::from(imm))?;
             }
 
             Instruction::SLL(sll) => {
-                self.gas_charge(self.gas_costs().sll())?;
+                self.gas_charge(self.gas_model().sll())?;
                 let (a, b, c) = sll.unpack();
 
                 self.alu_set(
@@ -408,14 +491,14 @@ where
             }
 
             Instruction::SLLI(slli) => {
-                self.gas_charge(self.gas_costs().slli())?;
+                self.gas_charge(self.gas_model().slli())?;
                 let (a, b, imm) = slli.unpack();
                 let rhs = u32::from(imm);
                 self.alu_set(a.into(), r!(b).checked_shl(rhs).unwrap_or_default())?;
             }
 
             Instruction::SRL(srl) => {
-                self.gas_charge(self.gas_costs().srl())?;
+                self.gas_charge(self.gas_model().srl())?;
                 let (a, b, c) = srl.unpack();
                 self.alu_set(
                     a.into(),
@@ -428,14 +511,14 @@ where
             }
 
             Instruction::SRLI(srli) => {
-                self.gas_charge(self.gas_costs().srli())?;
+                self.gas_charge(self.gas_model().srli())?;
                 let (a, b, imm) = srli.unpack();
                 let rhs = u32::from(imm);
                 self.alu_set(a.into(), r!(b).checked_shr(rhs).unwrap_or_default())?;
             }
 
             Instruction::SUB(sub) => {
-                self.gas_charge(self.gas_costs().sub())?;
+                self.gas_charge(self.gas_model().sub())?;
                 let (a, b, c) = sub.unpack();
                 self.alu_capture_overflow(
                     a.into(),
@@ -446,7 +529,7 @@ where
             }
 
             Instruction::SUBI(subi) => {
-                self.gas_charge(self.gas_costs().subi())?;
+                self.gas_charge(self.gas_model().subi())?;
                 let (a, b, imm) = subi.unpack();
                 self.alu_capture_overflow(
                     a.into(),
@@ -457,25 +540,25 @@ where
             }
 
             Instruction::XOR(xor) => {
-                self.gas_charge(self.gas_costs().xor())?;
+                self.gas_charge(self.gas_model().xor())?;
                 let (a, b, c) = xor.unpack();
                 self.alu_set(a.into(), r!(b) ^ r!(c))?;
             }
 
             Instruction::XORI(xori) => {
-                self.gas_charge(self.gas_costs().xori())?;
+                self.gas_charge(self.gas_model().xori())?;
                 let (a, b, imm) = xori.unpack();
                 self.alu_set(a.into(), r!(b) ^ Word::from(imm))?;
             }
 
             Instruction::JI(ji) => {
-                self.gas_charge(self.gas_costs().ji())?;
+                self.gas_charge(self.gas_model().ji())?;
                 let imm = ji.unpack();
                 self.jump(JumpArgs::new(JumpMode::Absolute).to_address(imm.into()))?;
             }
 
             Instruction::JNEI(jnei) => {
-                self.gas_charge(self.gas_costs().jnei())?;
+                self.gas_charge(self.gas_model().jnei())?;
                 let (a, b, imm) = jnei.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::Absolute)
@@ -485,7 +568,7 @@ where
             }
 
             Instruction::JNZI(jnzi) => {
-                self.gas_charge(self.gas_costs().jnzi())?;
+                self.gas_charge(self.gas_model().jnzi())?;
                 let (a, imm) = jnzi.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::Absolute)
@@ -495,13 +578,13 @@ where
             }
 
             Instruction::JMP(jmp) => {
-                self.gas_charge(self.gas_costs().jmp())?;
+                self.gas_charge(self.gas_model().jmp())?;
                 let a = jmp.unpack();
                 self.jump(JumpArgs::new(JumpMode::Absolute).to_address(r!(a)))?;
             }
 
             Instruction::JNE(jne) => {
-                self.gas_charge(self.gas_costs().jne())?;
+                self.gas_charge(self.gas_model().jne())?;
                 let (a, b, c) = jne.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::Absolute)
@@ -511,7 +594,7 @@ where
             }
 
             Instruction::JMPF(jmpf) => {
-                self.gas_charge(self.gas_costs().jmpf())?;
+                self.gas_charge(self.gas_model().jmpf())?;
                 let (a, offset) = jmpf.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::RelativeForwards)
@@ -521,7 +604,7 @@ where
             }
 
             Instruction::JMPB(jmpb) => {
-                self.gas_charge(self.gas_costs().jmpb())?;
+                self.gas_charge(self.gas_model().jmpb())?;
                 let (a, offset) = jmpb.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::RelativeBackwards)
@@ -531,7 +614,7 @@ where
             }
 
             Instruction::JNZF(jnzf) => {
-                self.gas_charge(self.gas_costs().jnzf())?;
+                self.gas_charge(self.gas_model().jnzf())?;
                 let (a, b, offset) = jnzf.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::RelativeForwards)
@@ -542,7 +625,7 @@ where
             }
 
             Instruction::JNZB(jnzb) => {
-                self.gas_charge(self.gas_costs().jnzb())?;
+                self.gas_charge(self.gas_model().jnzb())?;
                 let (a, b, offset) = jnzb.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::RelativeBackwards)
@@ -553,7 +636,7 @@ where
             }
 
             Instruction::JNEF(jnef) => {
-                self.gas_charge(self.gas_costs().jnef())?;
+                self.gas_charge(self.gas_model().jnef())?;
                 let (a, b, c, offset) = jnef.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::RelativeForwards)
@@ -564,7 +647,7 @@ where
             }
 
             Instruction::JNEB(jneb) => {
-                self.gas_charge(self.gas_costs().jneb())?;
+                self.gas_charge(self.gas_model().jneb())?;
                 let (a, b, c, offset) = jneb.unpack();
                 self.jump(
                     JumpArgs::new(JumpMode::RelativeBackwards)
@@ -575,7 +658,7 @@ where
             }
 
             Instruction::RET(ret) => {
-                self.gas_charge(self.gas_costs().ret())?;
+                self.gas_charge(self.gas_model().ret())?;
                 let a = ret.unpack();
                 let ra = r!(a);
                 self.ret(ra)?;
@@ -585,12 +668,12 @@ where
             Instruction::RETD(retd) => {
                 let (a, b) = retd.unpack();
                 let len = r!(b);
-                self.dependent_gas_charge(self.gas_costs().retd(), len)?;
+                self.dependent_gas_charge(self.gas_model().retd(), len)?;
                 return Ok(self.ret_data(r!(a), len).map(ExecuteState::ReturnData)?)
             }
 
             Instruction::RVRT(rvrt) => {
-                self.gas_charge(self.gas_costs().rvrt())?;
+                self.gas_charge(self.gas_model().rvrt())?;
                 let a = rvrt.unpack();
                 let ra = r!(a);
                 self.revert(ra)?;
@@ -599,74 +682,74 @@ where
 
             Instruction::SMO(smo) => {
                 let (a, b, c, d) = smo.unpack();
-                self.dependent_gas_charge(self.gas_costs().smo(), r!(c))?;
+                self.dependent_gas_charge(self.gas_model().smo(), r!(c))?;
                 self.message_output(r!(a), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::ALOC(aloc) => {
                 let a = aloc.unpack();
                 let number_of_bytes = r!(a);
-                self.dependent_gas_charge(self.gas_costs().aloc(), number_of_bytes)?;
+                self.dependent_gas_charge(self.gas_model().aloc(), number_of_bytes)?;
                 self.malloc(number_of_bytes)?;
             }
 
             Instruction::CFEI(cfei) => {
                 let number_of_bytes = cfei.unpack().into();
-                self.dependent_gas_charge(self.gas_costs().cfei(), number_of_bytes)?;
+                self.dependent_gas_charge(self.gas_model().cfei(), number_of_bytes)?;
                 self.stack_pointer_overflow(Word::overflowing_add, number_of_bytes)?;
             }
 
             Instruction::CFE(cfe) => {
                 let a = cfe.unpack();
                 let number_of_bytes = r!(a);
-                self.dependent_gas_charge(self.gas_costs().cfe(), number_of_bytes)?;
+                self.dependent_gas_charge(self.gas_model().cfe(), number_of_bytes)?;
                 self.stack_pointer_overflow(Word::overflowing_add, number_of_bytes)?;
             }
 
             Instruction::CFSI(cfsi) => {
-                self.gas_charge(self.gas_costs().cfsi())?;
+                self.gas_charge(self.gas_model().cfsi())?;
                 let imm = cfsi.unpack();
                 self.stack_pointer_overflow(Word::overflowing_sub, imm.into())?;
             }
 
             Instruction::CFS(cfs) => {
-                self.gas_charge(self.gas_costs().cfsi())?;
+                self.gas_charge(self.gas_model().cfsi())?;
                 let a = cfs.unpack();
                 self.stack_pointer_overflow(Word::overflowing_sub, r!(a))?;
             }
 
             Instruction::PSHL(pshl) => {
-                self.gas_charge(self.gas_costs().pshl())?;
+                self.gas_charge(self.gas_model().pshl())?;
                 let bitmask = pshl.unpack();
                 self.push_selected_registers(ProgramRegistersSegment::Low, bitmask)?;
             }
 
             Instruction::PSHH(pshh) => {
-                self.gas_charge(self.gas_costs().pshh())?;
+                self.gas_charge(self.gas_model().pshh())?;
                 let bitmask = pshh.unpack();
                 self.push_selected_registers(ProgramRegistersSegment::High, bitmask)?;
             }
 
             Instruction::POPL(popl) => {
-                self.gas_charge(self.gas_costs().popl())?;
+                self.gas_charge(self.gas_model().popl())?;
                 let bitmask = popl.unpack();
                 self.pop_selected_registers(ProgramRegistersSegment::Low, bitmask)?;
             }
 
             Instruction::POPH(poph) => {
-                self.gas_charge(self.gas_costs().poph())?;
+                self.gas_charge(self.gas_model().poph())?;
                 let bitmask = poph.unpack();
                 self.pop_selected_registers(ProgramRegistersSegment::High, bitmask)?;
             }
 
             Instruction::LB(lb) => {
-                self.gas_charge(self.gas_costs().lb())?;
+                self.gas_charge(self.gas_model().lb())?;
                 let (a, b, imm) = lb.unpack();
                 self.load_byte(a.into(), r!(b), imm.into())?;
             }
 
             Instruction::LW(lw) => {
-                self.gas_charge(self.gas_costs().lw())?;
+                self.gas_charge(self.gas_model().lw())?;
                 let (a, b, imm) = lw.unpack();
                 self.load_word(a.into(), r!(b), imm)?;
             }
@@ -674,70 +757,70 @@ where
             Instruction::MCL(mcl) => {
                 let (a, b) = mcl.unpack();
                 let len = r!(b);
-                self.dependent_gas_charge(self.gas_costs().mcl(), len)?;
+                self.dependent_gas_charge(self.gas_model().mcl(), len)?;
                 self.memclear(r!(a), len)?;
             }
 
             Instruction::MCLI(mcli) => {
                 let (a, imm) = mcli.unpack();
                 let len = Word::from(imm);
-                self.dependent_gas_charge(self.gas_costs().mcli(), len)?;
+                self.dependent_gas_charge(self.gas_model().mcli(), len)?;
                 self.memclear(r!(a), len)?;
             }
 
             Instruction::MCP(mcp) => {
                 let (a, b, c) = mcp.unpack();
                 let len = r!(c);
-                self.dependent_gas_charge(self.gas_costs().mcp(), len)?;
+                self.dependent_gas_charge(self.gas_model().mcp(), len)?;
                 self.memcopy(r!(a), r!(b), len)?;
             }
 
             Instruction::MCPI(mcpi) => {
                 let (a, b, imm) = mcpi.unpack();
                 let len = imm.into();
-                self.dependent_gas_charge(self.gas_costs().mcpi(), len)?;
+                self.dependent_gas_charge(self.gas_model().mcpi(), len)?;
                 self.memcopy(r!(a), r!(b), len)?;
             }
 
             Instruction::MEQ(meq) => {
                 let (a, b, c, d) = meq.unpack();
                 let len = r!(d);
-                self.dependent_gas_charge(self.gas_costs().meq(), len)?;
+                self.dependent_gas_charge(self.gas_model().meq(), len)?;
                 self.memeq(a.into(), r!(b), r!(c), len)?;
             }
 
             Instruction::SB(sb) => {
-                self.gas_charge(self.gas_costs().sb())?;
+                self.gas_charge(self.gas_model().sb())?;
                 let (a, b, imm) = sb.unpack();
                 self.store_byte(r!(a), r!(b), imm.into())?;
             }
 
             Instruction::SW(sw) => {
-                self.gas_charge(self.gas_costs().sw())?;
+                self.gas_charge(self.gas_model().sw())?;
                 let (a, b, imm) = sw.unpack();
                 self.store_word(r!(a), r!(b), imm)?;
             }
 
             Instruction::BAL(bal) => {
-                self.gas_charge(self.gas_costs().bal())?;
+                self.gas_charge(self.gas_model().bal())?;
                 let (a, b, c) = bal.unpack();
                 self.contract_balance(a.into(), r!(b), r!(c))?;
             }
 
             Instruction::BHEI(bhei) => {
-                self.gas_charge(self.gas_costs().bhei())?;
+                self.gas_charge(self.gas_model().bhei())?;
                 let a = bhei.unpack();
                 self.block_height(a.into())?;
             }
 
             Instruction::BHSH(bhsh) => {
-                self.gas_charge(self.gas_costs().bhsh())?;
+                self.gas_charge(self.gas_model().bhsh())?;
                 let (a, b) = bhsh.unpack();
                 self.block_hash(r!(a), r!(b))?;
             }
 
             Instruction::BURN(burn) => {
-                self.gas_charge(self.gas_costs().burn())?;
+                self.gas_charge(self.gas_model().burn())?;
                 let (a, b) = burn.unpack();
                 self.burn(r!(a), r!(b))?;
             }
@@ -751,7 +834,7 @@ where
             }
 
             Instruction::CB(cb) => {
-                self.gas_charge(self.gas_costs().cb())?;
+                self.gas_charge(self.gas_model().cb())?;
                 let a = cb.unpack();
                 self.block_proposer(r!(a))?;
             }
@@ -779,67 +862,67 @@ where
             }
 
             Instruction::LOG(log) => {
-                self.gas_charge(self.gas_costs().log())?;
+                self.gas_charge(self.gas_model().log())?;
                 let (a, b, c, d) = log.unpack();
                 self.log(r!(a), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::LOGD(logd) => {
                 let (a, b, c, d) = logd.unpack();
-                self.dependent_gas_charge(self.gas_costs().logd(), r!(d))?;
+                self.dependent_gas_charge(self.gas_model().logd(), r!(d))?;
                 self.log_data(r!(a), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::MINT(mint) => {
-                self.gas_charge(self.gas_costs().mint())?;
+                self.gas_charge(self.gas_model().mint())?;
                 let (a, b) = mint.unpack();
                 self.mint(r!(a), r!(b))?;
             }
 
             Instruction::SCWQ(scwq) => {
                 let (a, b, c) = scwq.unpack();
-                self.dependent_gas_charge(self.gas_costs().scwq(), r!(c))?;
+                self.dependent_gas_charge(self.gas_model().scwq(), r!(c))?;
                 self.state_clear_qword(r!(a), b.into(), r!(c))?;
             }
 
             Instruction::SRW(srw) => {
-                self.gas_charge(self.gas_costs().srw())?;
+                self.gas_charge(self.gas_model().srw())?;
                 let (a, b, c) = srw.unpack();
                 self.state_read_word(a.into(), b.into(), r!(c))?;
             }
 
             Instruction::SRWQ(srwq) => {
                 let (a, b, c, d) = srwq.unpack();
-                self.dependent_gas_charge(self.gas_costs().srwq(), r!(d))?;
+                self.dependent_gas_charge(self.gas_model().srwq(), r!(d))?;
                 self.state_read_qword(r!(a), b.into(), r!(c), r!(d))?;
             }
 
             Instruction::SWW(sww) => {
-                self.gas_charge(self.gas_costs().sww())?;
+                self.gas_charge(self.gas_model().sww())?;
                 let (a, b, c) = sww.unpack();
                 self.state_write_word(r!(a), b.into(), r!(c))?;
             }
 
             Instruction::SWWQ(swwq) => {
                 let (a, b, c, d) = swwq.unpack();
-                self.dependent_gas_charge(self.gas_costs().swwq(), r!(d))?;
+                self.dependent_gas_charge(self.gas_model().swwq(), r!(d))?;
                 self.state_write_qword(r!(a), b.into(), r!(c), r!(d))?;
             }
 
             Instruction::TIME(time) => {
-                self.gas_charge(self.gas_costs().time())?;
+                self.gas_charge(self.gas_model().time())?;
                 let (a, b) = time.unpack();
                 self.timestamp(a.into(), r!(b))?;
             }
 
             Instruction::ECK1(eck1) => {
-                self.gas_charge(self.gas_costs().eck1())?;
+                self.gas_charge(self.gas_model().eck1())?;
                 let (a, b, c) = eck1.unpack();
                 self.secp256k1_recover(r!(a), r!(b), r!(c))?;
             }
 
             Instruction::ECR1(ecr1) => {
-                self.gas_charge(self.gas_costs().ecr1())?;
+                self.gas_charge(self.gas_model().ecr1())?;
                 let (a, b, c) = ecr1.unpack();
                 self.secp256r1_recover(r!(a), r!(b), r!(c))?;
             }
@@ -853,50 +936,50 @@ where
                     len = 32;
                 }
 
-                self.dependent_gas_charge(self.gas_costs().ed19(), len)?;
+                self.dependent_gas_charge(self.gas_model().ed19(), len)?;
                 self.ed25519_verify(r!(a), r!(b), r!(c), len)?;
             }
 
             Instruction::K256(k256) => {
                 let (a, b, c) = k256.unpack();
                 let len = r!(c);
-                self.dependent_gas_charge(self.gas_costs().k256(), len)?;
+                self.dependent_gas_charge(self.gas_model().k256(), len)?;
                 self.keccak256(r!(a), r!(b), len)?;
             }
 
             Instruction::S256(s256) => {
                 let (a, b, c) = s256.unpack();
                 let len = r!(c);
-                self.dependent_gas_charge(self.gas_costs().s256(), len)?;
+                self.dependent_gas_charge(self.gas_model().s256(), len)?;
                 self.sha256(r!(a), r!(b), len)?;
             }
 
             Instruction::FLAG(flag) => {
-                self.gas_charge(self.gas_costs().flag())?;
+                self.gas_charge(self.gas_model().flag())?;
                 let a = flag.unpack();
                 self.set_flag(r!(a))?;
             }
 
             Instruction::GM(gm) => {
-                self.gas_charge(self.gas_costs().gm())?;
+                self.gas_charge(self.gas_model().gm())?;
                 let (a, imm) = gm.unpack();
                 self.metadata(a.into(), imm.into())?;
             }
 
             Instruction::GTF(gtf) => {
-                self.gas_charge(self.gas_costs().gtf())?;
+                self.gas_charge(self.gas_model().gtf())?;
                 let (a, b, imm) = gtf.unpack();
                 self.get_transaction_field(a.into(), r!(b), imm.into())?;
             }
 
             Instruction::TR(tr) => {
-                self.gas_charge(self.gas_costs().tr())?;
+                self.gas_charge(self.gas_model().tr())?;
                 let (a, b, c) = tr.unpack();
                 self.transfer(r!(a), r!(b), r!(c))?;
             }
 
             Instruction::TRO(tro) => {
-                self.gas_charge(self.gas_costs().tro())?;
+                self.gas_charge(self.gas_model().tro())?;
                 let (a, b, c, d) = tro.unpack();
                 self.transfer_output(r!(a), r!(b), r!(c), r!(d))?;
             }
@@ -917,12 +1000,40 @@ where
                 let (a, b, c, d) = bldd.unpack();
                 self.blob_load_data(r!(a), r!(b), r!(c), r!(d))?;
             }
+
+            Instruction::ASSERT(assert) => {
+                let (a, b, c, d) = assert.unpack();
+                let cost = self.gas_model().assert().map_err(PanicReason::from)?;
+                if r!(a) != 0 {
+                    self.gas_charge(cost.base())?;
+                } else {
+                    self.dependent_gas_charge(cost, r!(d))?;
+                }
+                if !self.assert(r!(a), r!(b), r!(c), r!(d))? {
+                    return Ok(ExecuteState::Revert(r!(b)))
+                }
+            }
         }
 
         Ok(ExecuteState::Proceed)
     }
 }
 
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
+    /// Enables the checks [`Interpreter::execute`] runs around every instruction if
+    /// any of them can abort the execution, take a checkpoint or notify the progress.
+    pub(crate) fn update_per_instruction_checks(&mut self) {
+        let params = &self.interpreter_params;
+        let checks = params.max_instructions.is_some()
+            || (params.checkpoint_interval.is_some() && self.checkpointer.has_receiver())
+            || (params.progress_stride.is_some() && self.progress_notifier.has_watcher());
+        #[cfg(feature = "std")]
+        let checks =
+            checks || self.execution_deadline.is_some() || self.halt_handle.is_some();
+        self.per_instruction_checks = checks;
+    }
+}
+
 /// Computes nth root of target, rounding down to nearest integer.
 /// This function uses the floating point operation to get an approximate solution,
 /// but corrects the result using exponentation to check for inaccuracy.
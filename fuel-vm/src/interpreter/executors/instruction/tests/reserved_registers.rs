@@ -244,6 +244,7 @@ fn writes_to_ra(opcode: Opcode) -> bool {
         Opcode::ECAL => true,
         Opcode::BSIZ => true,
         Opcode::BLDD => false,
+        Opcode::ASSERT => false,
     }
 }
 
@@ -361,5 +362,6 @@ fn writes_to_rb(opcode: Opcode) -> bool {
         Opcode::ECAL => true,
         Opcode::BSIZ => false,
         Opcode::BLDD => false,
+        Opcode::ASSERT => false,
     }
 }
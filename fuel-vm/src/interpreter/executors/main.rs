@@ -28,7 +28,12 @@ use crate::{
         RuntimeBalances,
     },
     pool::VmMemoryPool,
-    predicate::RuntimePredicate,
+    predicate::{
+        PredicateCacheKey,
+        PredicateCacheMetrics,
+        PredicateGasCache,
+        RuntimePredicate,
+    },
     prelude::{
         BugVariant,
         RuntimeError,
@@ -51,6 +56,7 @@ use crate::{
         CheckPredicateParams,
         Ready,
     },
+    consts::WORD_SIZE,
     interpreter::InterpreterParams,
     prelude::MemoryInstance,
     storage::{
@@ -58,7 +64,11 @@ use crate::{
         UploadedBytecodes,
     },
 };
-use fuel_asm::PanicReason;
+use fuel_asm::{
+    Instruction,
+    PanicReason,
+    RegId,
+};
 use fuel_storage::{
     StorageAsMut,
     StorageAsRef,
@@ -107,6 +117,45 @@ use fuel_types::{
     Word,
 };
 
+/// Returns the parts of the contract bytecode holding its instructions.
+///
+/// Contracts compiled by Sway start with a preamble jumping over the offset of their
+/// data section, which is followed by the data section and the configurables. Only the
+/// instructions around the jumped over words and before the data section are returned.
+/// Bytecode without a valid preamble is returned whole.
+fn contract_code(bytecode: &[u8]) -> [&[u8]; 2] {
+    sway_contract_code(bytecode).unwrap_or([bytecode, &[]])
+}
+
+fn sway_contract_code(bytecode: &[u8]) -> Option<[&[u8]; 2]> {
+    // The preamble's second instruction jumps over the embedded offsets
+    const JUMP_END: usize = 2 * Instruction::SIZE;
+
+    let jump: [u8; Instruction::SIZE] =
+        bytecode.get(Instruction::SIZE..JUMP_END)?.try_into().ok()?;
+    let skipped = match Instruction::try_from(jump).ok()? {
+        Instruction::JMPF(jmpf) if jmpf.ra() == RegId::ZERO => {
+            usize::try_from(u32::from(jmpf.imm18()))
+                .ok()?
+                .checked_mul(Instruction::SIZE)?
+        }
+        _ => return None,
+    };
+    let preamble_end = JUMP_END.checked_add(skipped)?;
+
+    let data_offset = bytecode.get(JUMP_END..JUMP_END.checked_add(WORD_SIZE)?)?;
+    let data_offset =
+        usize::try_from(Word::from_be_bytes(data_offset.try_into().ok()?)).ok()?;
+    if skipped < WORD_SIZE || data_offset < preamble_end {
+        return None
+    }
+
+    Some([
+        bytecode.get(..JUMP_END)?,
+        bytecode.get(preamble_end..data_offset)?,
+    ])
+}
+
 /// Predicates were checked succesfully
 #[derive(Debug, Clone, Copy)]
 pub struct PredicatesChecked {
@@ -228,6 +277,95 @@ where
         Ok(predicates_checked)
     }
 
+    /// Estimates the predicates like [`Self::estimate_predicates`], taking the gas used
+    /// by the predicates found in the `cache` from it instead of executing them, and
+    /// recording the gas used by the executed ones.
+    ///
+    /// The storage provider is not used since contract opcodes are not allowed for
+    /// predicates.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "estimate_predicates_cached",
+            level = "debug",
+            skip_all
+        )
+    )]
+    pub fn estimate_predicates_cached(
+        transaction: &mut Tx,
+        params: &CheckPredicateParams,
+        mut memory: impl Memory,
+        cache: &mut impl PredicateGasCache,
+        metrics: &mut PredicateCacheMetrics,
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed> {
+        let mut checks = vec![];
+
+        let max_gas = transaction.max_gas(&params.gas_costs, &params.fee_params);
+        let max_gas_per_tx = params.max_gas_per_tx;
+        let max_gas_per_predicate = params.max_gas_per_predicate;
+        let mut available_gas =
+            core::cmp::min(max_gas_per_predicate, max_gas_per_tx).saturating_sub(max_gas);
+
+        for index in 0..transaction.inputs().len() {
+            let input = &transaction.inputs()[index];
+            let Some(key) = PredicateCacheKey::from_input(input) else {
+                continue;
+            };
+
+            // A cached value above the available gas would have been cut short by
+            // running out of gas, so the predicate is executed instead.
+            let cached = cache
+                .get(&key)
+                .filter(|gas_used| *gas_used < available_gas)
+                .filter(|_| match (input.input_owner(), input.predicate()) {
+                    (Some(owner), Some((predicate, _, _))) => {
+                        Input::is_predicate_owner_valid(owner, predicate)
+                    }
+                    _ => false,
+                });
+
+            let (gas_used, result) = if let Some(gas_used) = cached {
+                metrics.hits = metrics.hits.saturating_add(1);
+                (gas_used, Ok(()))
+            } else {
+                metrics.misses = metrics.misses.saturating_add(1);
+                let tx = transaction.clone();
+                let Some(predicate) =
+                    RuntimePredicate::from_tx(&tx, params.tx_offset, index)
+                else {
+                    continue;
+                };
+                let (gas_used, result) = Interpreter::check_predicate(
+                    tx,
+                    index,
+                    PredicateAction::Estimating { available_gas },
+                    predicate,
+                    params.clone(),
+                    memory.as_mut(),
+                );
+                // Running out of gas doesn't fail the estimation, but its gas isn't
+                // the gas used by the predicate.
+                if result.is_ok() && gas_used < available_gas {
+                    cache.insert(key, gas_used);
+                }
+                (gas_used, result)
+            };
+
+            available_gas = available_gas.saturating_sub(gas_used);
+            checks.push(result.map(|_| (gas_used, index)));
+        }
+
+        Self::finalize_check_predicate(
+            PredicateRunKind::Estimating(transaction),
+            checks,
+            params,
+        )
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "run_predicates", level = "debug", skip_all)
+    )]
     async fn run_predicate_async<E>(
         kind: PredicateRunKind<'_, Tx>,
         params: &CheckPredicateParams,
@@ -280,6 +418,10 @@ where
         Self::finalize_check_predicate(kind, checks, params)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "run_predicates", level = "debug", skip_all)
+    )]
     fn run_predicates(
         kind: PredicateRunKind<'_, Tx>,
         params: &CheckPredicateParams,
@@ -322,6 +464,16 @@ where
         Self::finalize_check_predicate(kind, checks, params)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "check_predicate",
+            level = "debug",
+            skip_all,
+            fields(index = index),
+            ret
+        )
+    )]
     fn check_predicate(
         tx: Tx,
         index: usize,
@@ -395,7 +547,7 @@ where
                     (gas_used, Err(err))
                 } else {
                     (gas_used, Err(PredicateVerificationFailed::False))
-                }
+                };
             }
 
             if vm.remaining_gas() != 0 {
@@ -461,6 +613,11 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
 {
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "deploy", level = "debug", skip_all)
+    )]
     fn deploy_inner(
         create: &mut Create,
         storage: &mut S,
@@ -469,6 +626,7 @@ where
         fee_params: &FeeParameters,
         base_asset_id: &AssetId,
         gas_price: Word,
+        strict_instruction_decoding: bool,
     ) -> Result<(), InterpreterError<S::DataError>> {
         let metadata = create.metadata().as_ref();
         debug_assert!(
@@ -478,6 +636,19 @@ where
         let salt = create.salt();
         let storage_slots = create.storage_slots();
         let contract = Contract::try_from(&*create)?;
+
+        if strict_instruction_decoding
+            && fuel_asm::from_bytes_strict(
+                contract_code(contract.as_ref())
+                    .into_iter()
+                    .flatten()
+                    .copied(),
+            )
+            .any(|instruction| instruction.is_err())
+        {
+            return Err(InterpreterError::Panic(PanicReason::InvalidInstruction));
+        }
+
         let root = if let Some(m) = metadata {
             m.body.contract_root
         } else {
@@ -528,6 +699,10 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
 {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "upgrade", level = "debug", skip_all)
+    )]
     fn upgrade_inner(
         upgrade: &mut Upgrade,
         storage: &mut S,
@@ -575,7 +750,7 @@ where
                 if !exists {
                     return Err(InterpreterError::Panic(
                         PanicReason::UnknownStateTransactionBytecodeRoot,
-                    ))
+                    ));
                 }
 
                 let current_version = storage
@@ -631,6 +806,10 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
 {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "upload", level = "debug", skip_all)
+    )]
     fn upload_inner(
         upload: &mut Upload,
         storage: &mut S,
@@ -719,7 +898,7 @@ where
         if new_uploaded_subsections_number > *upload.subsections_number() {
             return Err(InterpreterError::Bug(Bug::new(
                 BugVariant::NextSubsectionIndexIsHigherThanTotalNumberOfParts,
-            )))
+            )));
         }
 
         let updated_uploaded_bytecode =
@@ -740,6 +919,10 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
 {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "blob", level = "debug", skip_all)
+    )]
     fn blob_inner(
         blob: &mut Blob,
         storage: &mut S,
@@ -807,6 +990,10 @@ where
         Ok(())
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "execute", level = "debug", skip_all)
+    )]
     pub(crate) fn run(&mut self) -> Result<ProgramState, InterpreterError<S::DataError>> {
         // TODO: Remove `Create`, `Upgrade`, and `Upload` from here
         //  https://github.com/FuelLabs/fuel-vm/issues/251
@@ -814,6 +1001,8 @@ where
         let fee_params = *self.fee_params();
         let base_asset_id = *self.base_asset_id();
         let gas_price = self.gas_price();
+        let strict_instruction_decoding =
+            self.interpreter_params.strict_instruction_decoding;
         let state = if let Some(create) = self.tx.as_create_mut() {
             Self::deploy_inner(
                 create,
@@ -823,6 +1012,7 @@ where
                 &fee_params,
                 &base_asset_id,
                 gas_price,
+                strict_instruction_decoding,
             )?;
             ProgramState::Return(1)
         } else if let Some(upgrade) = self.tx.as_upgrade_mut() {
@@ -922,6 +1112,10 @@ where
                 },
             };
 
+            self.vm_metrics.script_executed(&status, gas_used);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?status, gas_used, "script executed");
+
             let receipt = Receipt::script_result(status, gas_used);
 
             self.receipts.push(receipt)?;
@@ -951,6 +1145,10 @@ where
         Ok(state)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "execute_script", level = "debug", skip_all)
+    )]
     pub(crate) fn run_program(
         &mut self,
     ) -> Result<ProgramState, InterpreterError<S::DataError>> {
@@ -961,9 +1159,12 @@ where
             let state = self.execute()?;
 
             if in_call {
-                // Only reverts should terminate execution from a call context
-                if let ExecuteState::Revert(r) = state {
-                    return Ok(ProgramState::Revert(r));
+                // Only reverts and the debug events should terminate execution from a
+                // call context
+                match state {
+                    ExecuteState::Revert(r) => return Ok(ProgramState::Revert(r)),
+                    ExecuteState::DebugEvent(d) => return Ok(ProgramState::RunProgram(d)),
+                    _ => (),
                 }
             } else {
                 match state {
@@ -1001,6 +1202,10 @@ where
     /// transaction and execute it. The result will be bound to the lifetime
     /// of the interpreter and will avoid unnecessary copy with the data
     /// that can be referenced from the interpreter instance itself.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "transact", level = "debug", skip_all)
+    )]
     pub fn transact(
         &mut self,
         tx: Ready<Tx>,
@@ -1055,6 +1260,7 @@ where
             &self.interpreter_params.fee_params,
             &base_asset_id,
             gas_price,
+            self.interpreter_params.strict_instruction_decoding,
         )?;
         Ok(create)
     }
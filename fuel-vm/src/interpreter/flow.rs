@@ -52,10 +52,12 @@ use crate::{
 use alloc::vec::Vec;
 use core::cmp;
 use fuel_asm::{
+    Flags,
     Instruction,
     PanicInstruction,
     RegId,
 };
+use fuel_crypto::Hasher;
 use fuel_storage::{
     StorageAsRef,
     StorageRead,
@@ -93,6 +95,8 @@ where
     }
 
     pub(crate) fn ret(&mut self, a: Word) -> SimpleResult<()> {
+        self.verify_call_frame()?;
+        let frame_gas_used = self.frame_gas_used();
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
         let input = RetCtx {
@@ -103,10 +107,15 @@ where
             context: &mut self.context,
             current_contract,
         };
-        input.ret(a)
+        input.ret(a)?;
+        self.returned_frames_gas.extend(frame_gas_used);
+        self.leave_static_call();
+        Ok(())
     }
 
     pub(crate) fn ret_data(&mut self, a: Word, b: Word) -> SimpleResult<Bytes32> {
+        self.verify_call_frame()?;
+        let frame_gas_used = self.frame_gas_used();
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
         let input = RetCtx {
@@ -117,7 +126,75 @@ where
             context: &mut self.context,
             current_contract,
         };
-        input.ret_data(a, b)
+        let digest = input.ret_data(a, b)?;
+        self.returned_frames_gas.extend(frame_gas_used);
+        self.leave_static_call();
+        Ok(digest)
+    }
+
+    /// Returns `true` if the current context is executed by a static call, and may
+    /// not change the state.
+    pub(crate) const fn is_static_call(&self) -> bool {
+        self.static_call_depth.is_some()
+    }
+
+    /// The global gas used since the innermost call frame was entered, if any.
+    fn frame_gas_used(&self) -> Option<Word> {
+        self.frames.last().map(|frame| {
+            frame.registers()[RegId::GGAS].saturating_sub(self.registers[RegId::GGAS])
+        })
+    }
+
+    /// Ends the static call if its call frame was returned from.
+    fn leave_static_call(&mut self) {
+        if self
+            .static_call_depth
+            .is_some_and(|depth| self.frames.len() < depth)
+        {
+            self.static_call_depth = None;
+        }
+    }
+
+    /// Reads the memory of the innermost call frame, including the contract code.
+    fn call_frame_memory(&self) -> SimpleResult<Option<(&CallFrame, &[u8])>> {
+        let Some(frame) = self.frames.last() else {
+            return Ok(None)
+        };
+        let len = CallFrame::serialized_size().saturating_add(frame.code_size_padded());
+        let memory = self.memory.as_ref().read(self.registers[RegId::FP], len)?;
+        Ok(Some((frame, memory)))
+    }
+
+    /// Records the digest of the call frame entered by `CALL`, if the integrity checks
+    /// are enabled.
+    fn push_call_frame_canary(&mut self) -> SimpleResult<()> {
+        if !self.interpreter_params.call_frame_integrity_checks {
+            return Ok(())
+        }
+        if let Some((_, memory)) = self.call_frame_memory()? {
+            let canary = Hasher::hash(memory);
+            self.frame_canaries.push(canary);
+        }
+        Ok(())
+    }
+
+    /// Verifies that the call frame being returned from wasn't modified since `CALL`,
+    /// if the integrity checks are enabled.
+    fn verify_call_frame(&mut self) -> SimpleResult<()> {
+        if !self.interpreter_params.call_frame_integrity_checks {
+            return Ok(())
+        }
+        let Some((frame, memory)) = self.call_frame_memory()? else {
+            return Ok(())
+        };
+        let header_intact = memory.get(..CallFrame::serialized_size())
+            == Some(frame.to_bytes().as_slice());
+        let canary = Hasher::hash(memory);
+
+        if !header_intact || self.frame_canaries.pop() != Some(canary) {
+            return Err(Bug::new(BugVariant::CallFrameCorrupted).into())
+        }
+        Ok(())
     }
 
     pub(crate) fn revert(&mut self, a: Word) -> SimpleResult<()> {
@@ -345,12 +422,37 @@ where
         rc: RegId,
         rd: RegId,
     ) -> IoResult<(), S::DataError> {
+        let is_static = self.is_static_call()
+            || Flags::from_bits_truncate(self.registers[RegId::FLAG])
+                .contains(Flags::STATIC_CALL);
+        if is_static && self.registers[rb] != 0 {
+            return Err(PanicReason::StateChangeInStaticCall.into())
+        }
+
+        if self
+            .interpreter_params
+            .max_call_depth
+            .is_some_and(|max_call_depth| self.frames.len() >= max_call_depth)
+        {
+            return Err(PanicReason::CallDepthExceeded.into())
+        }
+
+        let is_delegate = self.delegate_calls_enabled()
+            && Flags::from_bits_truncate(self.registers[RegId::FLAG])
+                .contains(Flags::DELEGATE_CALL);
+
         self.prepare_call_inner(
             self.registers[ra],
             self.registers[rb],
             self.registers[rc],
             self.registers[rd],
-        )
+            is_delegate,
+        )?;
+
+        if is_static && self.static_call_depth.is_none() {
+            self.static_call_depth = Some(self.frames.len());
+        }
+        Ok(self.push_call_frame_canary()?)
     }
 
     /// Prepare a call instruction for execution
@@ -360,6 +462,7 @@ where
         amount_of_coins_to_forward: Word,
         asset_id_pointer: Word,
         amount_of_gas_to_forward: Word,
+        delegate: bool,
     ) -> IoResult<(), S::DataError> {
         let params = PrepareCallParams {
             call_params_pointer,
@@ -367,8 +470,8 @@ where
             amount_of_coins_to_forward,
             amount_of_gas_to_forward,
         };
-        let gas_cost = self.gas_costs().call();
-        let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte();
+        let gas_cost = self.gas_model().call();
+        let new_storage_gas_per_byte = self.gas_model().new_storage_per_byte();
         // Charge only for the `base` execution.
         // We will charge for the frame size in the `prepare_call`.
         self.gas_charge(gas_cost.base())?;
@@ -391,6 +494,7 @@ where
             receipts: &mut self.receipts,
             frames: &mut self.frames,
             current_contract,
+            delegate,
             profiler: &mut self.profiler,
         }
         .prepare_call()
@@ -456,6 +560,8 @@ struct PrepareCallCtx<'vm, S> {
     receipts: &'vm mut ReceiptsCtx,
     frames: &'vm mut Vec<CallFrame>,
     current_contract: Option<ContractId>,
+    /// Execute the code of the called contract in the context of the current one.
+    delegate: bool,
     profiler: &'vm mut Profiler,
 }
 
@@ -477,6 +583,14 @@ where
         let asset_id =
             AssetId::new(self.memory.read_bytes(self.params.asset_id_pointer)?);
 
+        // The contract whose storage and balances are used by the called code
+        let context_contract = if self.delegate {
+            self.current_contract
+                .ok_or(PanicReason::ExpectedInternalContext)?
+        } else {
+            *call.to()
+        };
+
         let code_size = contract_size(&self.storage, call.to())? as usize;
         let code_size_padded =
             padded_len_usize(code_size).ok_or(PanicReason::MemoryOverflow)?;
@@ -521,7 +635,7 @@ where
         // credit contract asset_id balance
         let (_, created_new_entry) = balance_increase(
             self.storage,
-            call.to(),
+            &context_contract,
             &asset_id,
             self.params.amount_of_coins_to_forward,
         )?;
@@ -555,7 +669,7 @@ where
 
         // Construct frame
         let mut frame = CallFrame::new(
-            *call.to(),
+            context_contract,
             asset_id,
             self.registers.copy_registers(),
             code_size_padded,
@@ -382,6 +382,7 @@ fn test_prepare_call(input: Input) -> Result<Output, RuntimeError<Infallible>> {
         receipts: &mut receipts,
         frames: &mut frames,
         current_contract,
+        delegate: false,
         profiler: &mut Profiler::default(),
     };
     input.prepare_call().map(|_| Output {
@@ -0,0 +1,212 @@
+//! Halting of a running execution on request from another thread or a signal, with a
+//! dump of the interpreter for diagnosing stuck executions.
+
+use super::{
+    Interpreter,
+    Memory,
+};
+use crate::{
+    call::CallFrame,
+    consts::VM_MAX_RAM,
+};
+use alloc::{
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    fmt,
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+use fuel_asm::{
+    RegId,
+    Word,
+};
+use fuel_types::ContractId;
+use std::sync::Mutex;
+
+/// Sizes of the regions of the memory at the halt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemorySummary {
+    /// Size of the stack, `$sp`.
+    pub stack_size: Word,
+    /// Size of the heap, `VM_MAX_RAM - $hp`.
+    pub heap_size: Word,
+    /// Size of the unallocated memory between the stack and the heap.
+    pub free_size: Word,
+}
+
+/// The state of the interpreter at the halt.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HaltDump {
+    /// The call frames, the innermost last. Empty if the script itself was running.
+    pub call_stack: Vec<CallFrame>,
+    /// The contract running at the halt, if any.
+    pub contract: Option<ContractId>,
+    /// Values of all of the registers.
+    pub registers: Vec<Word>,
+    /// Summary of the memory.
+    pub memory: MemorySummary,
+}
+
+impl fmt::Display for HaltDump {
+    /// Renders the dump for the logs: the call stack, the reserved and the non-zero
+    /// general purpose registers, and the memory summary.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.contract {
+            Some(contract) => writeln!(f, "halted in contract {contract}")?,
+            None => writeln!(f, "halted in the script")?,
+        }
+
+        writeln!(f, "call stack ({} frames):", self.call_stack.len())?;
+        for (depth, frame) in self.call_stack.iter().enumerate().rev() {
+            writeln!(
+                f,
+                "  #{depth} {} returning to $pc={:#x}",
+                frame.to(),
+                frame
+                    .registers()
+                    .get(usize::from(RegId::PC))
+                    .copied()
+                    .unwrap_or_default()
+            )?;
+        }
+
+        writeln!(f, "registers:")?;
+        for (index, value) in self.registers.iter().enumerate() {
+            let Ok(index) = u8::try_from(index) else {
+                break
+            };
+            let reg = RegId::new(index);
+            if reg.reserved_name().is_some() || *value != 0 {
+                writeln!(f, "  {reg} = {value:#x}")?;
+            }
+        }
+
+        write!(
+            f,
+            "memory: stack {} bytes, heap {} bytes, free {} bytes",
+            self.memory.stack_size, self.memory.heap_size, self.memory.free_size
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct HaltState {
+    requested: AtomicBool,
+    dump: Mutex<Option<HaltDump>>,
+}
+
+/// Requests the interpreter to halt, and receives the dump of the halted interpreter.
+///
+/// The interpreter set with [`Interpreter::with_halt_handle`] checks the handle before
+/// every instruction. Once a halt is requested, it stops at the next instruction
+/// boundary with
+/// [`ExecutionLimit::HaltRequested`](crate::error::ExecutionLimit::HaltRequested), and
+/// stores the [`HaltDump`] into the handle. A halt requested between the executions
+/// stops the next one at its first instruction.
+///
+/// The handle is cheap to clone, and the clones share the request.
+#[derive(Debug, Default, Clone)]
+pub struct HaltHandle {
+    state: Arc<HaltState>,
+}
+
+impl HaltHandle {
+    /// Creates the handle without a pending request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the interpreter to halt at the next instruction boundary.
+    pub fn request_halt(&self) {
+        self.state.requested.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` if a halt is requested, and the interpreter didn't halt yet.
+    pub fn is_halt_requested(&self) -> bool {
+        self.state.requested.load(Ordering::Acquire)
+    }
+
+    /// Takes the dump of the latest halt, if any.
+    pub fn take_dump(&self) -> Option<HaltDump> {
+        self.state
+            .dump
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+    }
+
+    /// Requests the halt whenever the process receives the `signal`, e.g. `SIGUSR1`.
+    ///
+    /// The signal handler stays registered for the lifetime of the process. Fails for
+    /// the signals that can't be handled, like `SIGKILL`.
+    #[cfg(feature = "halt-on-signal")]
+    pub fn halt_on_signal(&self, signal: core::ffi::c_int) -> std::io::Result<()> {
+        if signal_hook_registry::FORBIDDEN.contains(&signal) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the signal can't be handled",
+            ))
+        }
+        let state = self.state.clone();
+        // SAFETY: The action only stores into an atomic, which is async-signal-safe.
+        #[allow(unsafe_code)]
+        unsafe {
+            signal_hook_registry::register(signal, move || {
+                state.requested.store(true, Ordering::Release);
+            })?;
+        }
+        Ok(())
+    }
+
+    fn take_request(&self) -> bool {
+        self.state.requested.swap(false, Ordering::AcqRel)
+    }
+
+    fn store_dump(&self, dump: HaltDump) {
+        *self
+            .state
+            .dump
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(dump);
+    }
+}
+
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
+where
+    M: Memory,
+{
+    /// Dumps the current state of the interpreter.
+    pub fn halt_dump(&self) -> HaltDump {
+        let sp = self.registers[RegId::SP];
+        let hp = self.registers[RegId::HP];
+
+        HaltDump {
+            call_stack: self.frames.clone(),
+            contract: self.internal_contract().ok(),
+            registers: self.registers.to_vec(),
+            memory: MemorySummary {
+                stack_size: sp,
+                heap_size: VM_MAX_RAM.saturating_sub(hp),
+                free_size: hp.saturating_sub(sp),
+            },
+        }
+    }
+
+    /// Returns `true` and stores the dump into the handle if a halt is requested.
+    pub(crate) fn halt_if_requested(&self) -> bool {
+        let Some(handle) = &self.halt_handle else {
+            return false
+        };
+        if !handle.take_request() {
+            return false
+        }
+
+        handle.store_dump(self.halt_dump());
+        true
+    }
+}
@@ -3,6 +3,7 @@ use super::{
     InitialBalances,
     Interpreter,
     Memory,
+    MemoryStats,
     RuntimeBalances,
 };
 use crate::{
@@ -12,7 +13,11 @@ use crate::{
     },
     consts::*,
     context::Context,
-    error::InterpreterError,
+    error::{
+        Bug,
+        BugVariant,
+        InterpreterError,
+    },
     prelude::RuntimeError,
     storage::InterpreterStorage,
 };
@@ -76,6 +81,20 @@ where
         self.initial_balances = initial_balances.clone();
 
         self.frames.clear();
+        self.frame_canaries.clear();
+        self.static_call_depth = None;
+        self.returned_frames_gas.clear();
+        self.executed_instructions = 0;
+        self.memory_stats = MemoryStats::default();
+        self.checkpointer.reset();
+        #[cfg(feature = "std")]
+        {
+            self.execution_deadline = self
+                .interpreter_params
+                .execution_timeout
+                .and_then(|timeout| std::time::Instant::now().checked_add(timeout));
+        }
+        self.update_per_instruction_checks();
         self.receipts.clear();
         self.memory_mut().reset();
 
@@ -87,37 +106,41 @@ where
         // Set heap area
         self.registers[RegId::HP] = VM_MAX_RAM;
 
-        // Initialize stack
-        macro_rules! push_stack {
-            ($v:expr) => {{
-                let data = $v;
-                let old_ssp = self.registers[RegId::SSP];
-                let new_ssp = old_ssp
-                    .checked_add(data.len() as Word)
-                    .expect("VM initialization data must fit into the stack");
-                self.memory_mut().grow_stack(new_ssp)?;
-                self.registers[RegId::SSP] = new_ssp;
+        self.set_gas(gas_limit);
+
+        // Initialize the stack, which ends with the transaction
+        let layout = self.tx_memory_layout();
+        let tx_offset = self.tx_offset();
+        let tx_bytes = self.tx.to_bytes();
+        let ssp = tx_offset
+            .checked_add(tx_bytes.len())
+            .expect("VM initialization data must fit into the stack")
+            as Word;
+        self.memory_mut().grow_stack(ssp)?;
+        self.registers[RegId::SSP] = ssp;
+
+        macro_rules! write_stack {
+            ($offset:expr, $v:expr) => {{
                 self.memory_mut()
-                    .write_noownerchecks(old_ssp, data.len())
-                    .expect("VM initialization data must fit into the stack")
-                    .copy_from_slice(data);
+                    .write_bytes_noownerchecks($offset, $v)
+                    .expect("VM initialization data must fit into the stack");
             }};
         }
 
-        push_stack!(&*self.transaction().id(&self.chain_id()));
-
+        let tx_id = self.transaction().id(&self.chain_id());
+        write_stack!(layout.tx_id_offset(), *tx_id);
         let base_asset_id = self.interpreter_params.base_asset_id;
-        push_stack!(&*base_asset_id);
-
+        write_stack!(layout.base_asset_id_offset(), *base_asset_id);
         runtime_balances.to_vm(self);
-
         let tx_size = self.transaction().size() as Word;
-        self.set_gas(gas_limit);
-
-        push_stack!(&tx_size.to_be_bytes());
-
-        let tx_bytes = self.tx.to_bytes();
-        push_stack!(tx_bytes.as_slice());
+        let tx_size_offset = layout
+            .tx_size_offset(tx_offset)
+            .ok_or_else(|| Bug::new(BugVariant::InvalidTxOffset))?;
+        write_stack!(tx_size_offset, tx_size.to_be_bytes());
+        self.memory_mut()
+            .write_noownerchecks(tx_offset, tx_bytes.len())
+            .expect("VM initialization data must fit into the stack")
+            .copy_from_slice(&tx_bytes);
 
         self.registers[RegId::SP] = self.registers[RegId::SSP];
 
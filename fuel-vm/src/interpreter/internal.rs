@@ -103,10 +103,40 @@ where
     M: Memory,
 {
     pub(crate) fn set_flag(&mut self, a: Word) -> SimpleResult<()> {
+        if a & Flags::STATIC_CALL.bits() != 0 && !self.static_calls_enabled() {
+            return Err(PanicReason::InvalidFlags.into())
+        }
+        if a & Flags::DELEGATE_CALL.bits() != 0 && !self.delegate_calls_enabled() {
+            return Err(PanicReason::InvalidFlags.into())
+        }
         let (SystemRegisters { flag, pc, .. }, _) = split_registers(&mut self.registers);
         set_flag(flag, pc, a)
     }
 
+    /// Returns `true` if the VM accepts [`Flags::STATIC_CALL`].
+    #[cfg(feature = "static-call")]
+    pub(crate) const fn static_calls_enabled(&self) -> bool {
+        self.interpreter_params.static_calls
+    }
+
+    /// Returns `true` if the VM accepts [`Flags::STATIC_CALL`].
+    #[cfg(not(feature = "static-call"))]
+    pub(crate) const fn static_calls_enabled(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the VM accepts [`Flags::DELEGATE_CALL`].
+    #[cfg(feature = "delegate-call")]
+    pub(crate) const fn delegate_calls_enabled(&self) -> bool {
+        self.interpreter_params.delegate_calls
+    }
+
+    /// Returns `true` if the VM accepts [`Flags::DELEGATE_CALL`].
+    #[cfg(not(feature = "delegate-call"))]
+    pub(crate) const fn delegate_calls_enabled(&self) -> bool {
+        false
+    }
+
     pub(crate) const fn context(&self) -> &Context {
         &self.context
     }
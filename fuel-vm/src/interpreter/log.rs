@@ -59,6 +59,36 @@ where
         };
         input.log_data(a, b, c, d)
     }
+
+    /// Returns `true` if the condition holds. Otherwise logs the message with the
+    /// code, and appends the revert receipt with the code.
+    pub(crate) fn assert(
+        &mut self,
+        cond: Word,
+        code: Word,
+        msg_ptr: Word,
+        msg_len: Word,
+    ) -> SimpleResult<bool> {
+        if cond != 0 {
+            inc_pc(self.registers.pc_mut())?;
+            return Ok(true)
+        }
+
+        let (SystemRegisters { fp, is, pc, .. }, _) =
+            split_registers(&mut self.registers);
+        let mut input = LogInput {
+            memory: self.memory.as_mut(),
+            context: &self.context,
+            receipts: &mut self.receipts,
+            fp: fp.as_ref(),
+            is: is.as_ref(),
+            pc,
+        };
+        input.push_log_data(code, 0, msg_ptr, msg_len)?;
+        self.revert(code)?;
+
+        Ok(false)
+    }
 }
 
 struct LogInput<'vm> {
@@ -87,7 +117,19 @@ impl LogInput<'_> {
         Ok(inc_pc(self.pc)?)
     }
 
-    pub(crate) fn log_data(self, a: Word, b: Word, c: Word, d: Word) -> SimpleResult<()> {
+    pub(crate) fn log_data(
+        mut self,
+        a: Word,
+        b: Word,
+        c: Word,
+        d: Word,
+    ) -> SimpleResult<()> {
+        self.push_log_data(a, b, c, d)?;
+
+        Ok(inc_pc(self.pc)?)
+    }
+
+    fn push_log_data(&mut self, a: Word, b: Word, c: Word, d: Word) -> SimpleResult<()> {
         let data = self.memory.read(c, d)?.to_vec();
 
         let receipt = Receipt::log_data(
@@ -100,8 +142,6 @@ impl LogInput<'_> {
             data,
         );
 
-        self.receipts.push(receipt)?;
-
-        Ok(inc_pc(self.pc)?)
+        self.receipts.push(receipt)
     }
 }
@@ -36,6 +36,7 @@ use core::ops::{
 };
 
 use alloc::{
+    sync::Arc,
     vec,
     vec::Vec,
 };
@@ -407,6 +408,45 @@ impl IndexMut<Range<usize>> for MemoryInstance {
     }
 }
 
+/// Copy-on-write memory of the VM, for forking the interpreter with
+/// [`Interpreter::fork`].
+///
+/// The clones share the [`MemoryInstance`] until either of them writes to it, which
+/// copies the stack and the heap in use. Writing to the shared memory is slower than
+/// to the [`MemoryInstance`], so it should only be used when forking.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CowMemory(Arc<MemoryInstance>);
+
+impl CowMemory {
+    /// Create a new copy-on-write memory.
+    pub fn new(memory: MemoryInstance) -> Self {
+        Self(Arc::new(memory))
+    }
+
+    /// Returns `true` if the memory is still shared with a fork.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.0) > 1
+    }
+}
+
+impl From<MemoryInstance> for CowMemory {
+    fn from(memory: MemoryInstance) -> Self {
+        Self::new(memory)
+    }
+}
+
+impl AsRef<MemoryInstance> for CowMemory {
+    fn as_ref(&self) -> &MemoryInstance {
+        &self.0
+    }
+}
+
+impl AsMut<MemoryInstance> for CowMemory {
+    fn as_mut(&mut self) -> &mut MemoryInstance {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
 /// Used to handle `Word` to `usize` conversions for memory addresses,
 /// as well as checking that the resulting value is withing the VM ram boundaries.
 pub trait ToAddr {
@@ -444,6 +484,51 @@ impl ToAddr for i32 {
     }
 }
 
+/// The peak memory usage of the execution, see [`Interpreter::memory_stats`].
+///
+/// The values are sampled after every instruction, so they are deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryStats {
+    /// The highest value of `$sp`, i.e. the largest size of the stack.
+    pub max_sp: Word,
+    /// The lowest value of `$hp`.
+    pub min_hp: Word,
+    /// The total number of bytes the stack and the heap grew by.
+    pub memory_grown: Word,
+}
+
+impl Default for MemoryStats {
+    fn default() -> Self {
+        Self {
+            max_sp: 0,
+            min_hp: VM_MAX_RAM,
+            memory_grown: 0,
+        }
+    }
+}
+
+impl MemoryStats {
+    /// The largest size of the heap, `VM_MAX_RAM - min_hp`.
+    pub const fn max_heap_size(&self) -> Word {
+        VM_MAX_RAM.saturating_sub(self.min_hp)
+    }
+
+    /// The distance between the highest `$sp` and the lowest `$hp`. The stack and the
+    /// heap could grow by this many bytes at their peaks without overlapping.
+    pub const fn headroom(&self) -> Word {
+        self.min_hp.saturating_sub(self.max_sp)
+    }
+
+    /// Records the registers after an instruction, which grew the memory in use by
+    /// `grown` bytes.
+    pub(crate) fn record(&mut self, sp: Word, hp: Word, grown: Word) {
+        self.max_sp = self.max_sp.max(sp);
+        self.min_hp = self.min_hp.min(hp);
+        self.memory_grown = self.memory_grown.saturating_add(grown);
+    }
+}
+
 /// A range of memory. No guarantees are made about validity of access.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -493,6 +578,13 @@ impl MemoryRange {
     }
 }
 
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
+    /// The peak memory usage of the execution since the initialization.
+    pub const fn memory_stats(&self) -> MemoryStats {
+        self.memory_stats
+    }
+}
+
 impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     M: Memory,
@@ -287,3 +287,40 @@ fn test_copy_from_slice_zero_fill(
     let memory: [u8; 5] = memory[..5].try_into().unwrap();
     (r, memory)
 }
+
+#[test]
+fn cow_memory_clone_shares_memory_until_written() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(64).unwrap();
+    memory.write_bytes_noownerchecks(0, [1u8; 8]).unwrap();
+    let memory = CowMemory::new(memory);
+
+    let mut fork = memory.clone();
+    assert!(memory.is_shared());
+    assert!(fork.is_shared());
+
+    fork.as_mut()
+        .write_bytes_noownerchecks(0, [2u8; 8])
+        .unwrap();
+
+    assert!(!memory.is_shared());
+    assert_eq!(memory.as_ref().read_bytes(0), Ok([1u8; 8]));
+    assert_eq!(fork.as_ref().read_bytes(0), Ok([2u8; 8]));
+}
+
+#[test]
+fn cow_memory_reset_keeps_the_fork_intact() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(8).unwrap();
+    memory.write_bytes_noownerchecks(0, [1u8; 8]).unwrap();
+    let mut memory = CowMemory::new(memory);
+    let fork = memory.clone();
+
+    memory.as_mut().reset();
+
+    assert_eq!(
+        memory.as_ref().verify(0, 8),
+        Err(PanicReason::UninitalizedMemoryAccess)
+    );
+    assert_eq!(fork.as_ref().read_bytes(0), Ok([1u8; 8]));
+}
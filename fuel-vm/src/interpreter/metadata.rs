@@ -3,6 +3,7 @@ use super::{
     ExecutableTransaction,
     Interpreter,
     Memory,
+    TxMemoryLayout,
 };
 use crate::{
     call::CallFrame,
@@ -10,7 +11,11 @@ use crate::{
     consts::*,
     context::Context,
     convert,
-    error::SimpleResult,
+    error::{
+        Bug,
+        BugVariant,
+        SimpleResult,
+    },
 };
 
 use fuel_asm::{
@@ -57,6 +62,7 @@ where
         imm: Immediate18,
     ) -> SimpleResult<()> {
         let tx_offset = self.tx_offset() as Word;
+        let tx_memory_layout = self.tx_memory_layout();
         let chain_id = self.chain_id();
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let result = &mut w[WriteRegKey::try_from(ra)?];
@@ -68,6 +74,7 @@ where
             imm,
             chain_id,
             tx_offset,
+            tx_memory_layout,
         )
     }
 
@@ -78,8 +85,10 @@ where
         imm: Immediate12,
     ) -> SimpleResult<()> {
         let tx_offset = self.tx_offset();
-        // Tx size is stored just below the tx bytes
-        let tx_size_ptr = tx_offset.checked_sub(8).expect("Tx offset is not valid");
+        let tx_size_ptr = self
+            .tx_memory_layout()
+            .tx_size_offset(tx_offset)
+            .ok_or_else(|| Bug::new(BugVariant::InvalidTxOffset))?;
         let tx_size = Word::from_be_bytes(
             self.memory()
                 .read_bytes(tx_size_ptr)
@@ -99,6 +108,7 @@ where
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn metadata(
     context: &Context,
     frames: &[CallFrame],
@@ -107,6 +117,7 @@ pub(crate) fn metadata(
     imm: Immediate18,
     chain_id: ChainId,
     tx_offset: Word,
+    tx_memory_layout: TxMemoryLayout,
 ) -> SimpleResult<()> {
     let parent = context
         .is_internal()
@@ -119,7 +130,7 @@ pub(crate) fn metadata(
             .map(|p| p.idx() as Word)
             .ok_or(PanicReason::TransactionValidity)?,
         GMArgs::GetChainId => chain_id.into(),
-        GMArgs::BaseAssetId => VM_MEMORY_BASE_ASSET_ID_OFFSET as Word,
+        GMArgs::BaseAssetId => tx_memory_layout.base_asset_id_offset() as Word,
         GMArgs::TxStart => tx_offset,
         GMArgs::GetCaller => match parent {
             Some(0) => return Err(PanicReason::ExpectedNestedCaller.into()),
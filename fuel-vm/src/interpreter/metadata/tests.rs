@@ -28,6 +28,7 @@ fn test_metadata() {
         imm,
         ChainId::default(),
         TxParameters::default().tx_offset() as Word,
+        TxMemoryLayout::V1,
     )
     .unwrap();
     assert_eq!(pc, 8);
@@ -76,6 +77,7 @@ fn get_chain_id(context: Context, chain_id: u64) {
         imm,
         chain_id.into(),
         TxParameters::default().tx_offset() as Word,
+        TxMemoryLayout::V1,
     )
     .unwrap();
 
@@ -0,0 +1,138 @@
+use fuel_asm::Opcode;
+
+/// A set of opcodes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpcodeSet([u64; 4]);
+
+impl OpcodeSet {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self([0; 4])
+    }
+
+    fn position(opcode: Opcode) -> (usize, u64) {
+        let byte = opcode as u8;
+        let bit = 1u64.checked_shl(u32::from(byte % 64)).unwrap_or_default();
+        (usize::from(byte / 64), bit)
+    }
+
+    /// Adds the opcode to the set.
+    pub fn insert(&mut self, opcode: Opcode) {
+        let (word, bit) = Self::position(opcode);
+        if let Some(word) = self.0.get_mut(word) {
+            *word |= bit;
+        }
+    }
+
+    /// Removes the opcode from the set.
+    pub fn remove(&mut self, opcode: Opcode) {
+        let (word, bit) = Self::position(opcode);
+        if let Some(word) = self.0.get_mut(word) {
+            *word &= !bit;
+        }
+    }
+
+    /// Returns `true` if the set contains the opcode.
+    pub fn contains(&self, opcode: Opcode) -> bool {
+        let (word, bit) = Self::position(opcode);
+        self.0.get(word).is_some_and(|word| word & bit != 0)
+    }
+
+    /// Returns `true` if the set contains no opcodes.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|word| *word == 0)
+    }
+}
+
+impl FromIterator<Opcode> for OpcodeSet {
+    fn from_iter<I: IntoIterator<Item = Opcode>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|opcode| set.insert(opcode));
+        set
+    }
+}
+
+/// The opcodes the interpreter executes, see
+/// [`InterpreterParams::opcode_filter`](crate::interpreter::InterpreterParams::opcode_filter).
+///
+/// Executing a disabled opcode panics with [`fuel_asm::PanicReason::OpcodeDisabled`].
+/// The filter only restricts the opcodes further: the opcodes not allowed in
+/// predicates or static calls stay forbidden there.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpcodeFilter {
+    /// All opcodes are enabled.
+    #[default]
+    AllowAll,
+    /// Only the opcodes of the set are enabled.
+    Allow(OpcodeSet),
+    /// The opcodes of the set are disabled.
+    Deny(OpcodeSet),
+}
+
+impl OpcodeFilter {
+    /// Enables only the `opcodes`.
+    pub fn allow(opcodes: impl IntoIterator<Item = Opcode>) -> Self {
+        Self::Allow(opcodes.into_iter().collect())
+    }
+
+    /// Disables the `opcodes`.
+    pub fn deny(opcodes: impl IntoIterator<Item = Opcode>) -> Self {
+        Self::Deny(opcodes.into_iter().collect())
+    }
+
+    /// Enables only the opcodes allowed in predicates, see
+    /// [`Opcode::is_predicate_allowed`].
+    pub fn predicate_allowed() -> Self {
+        Self::allow(
+            (0..=u8::MAX)
+                .filter_map(|byte| Opcode::try_from(byte).ok())
+                .filter(Opcode::is_predicate_allowed),
+        )
+    }
+
+    /// Returns `true` if the opcode is enabled.
+    pub fn is_allowed(&self, opcode: Opcode) -> bool {
+        match self {
+            Self::AllowAll => true,
+            Self::Allow(opcodes) => opcodes.contains(opcode),
+            Self::Deny(opcodes) => !opcodes.contains(opcode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opcode_set_contains_inserted_opcodes() {
+        let mut set = OpcodeSet::new();
+        assert!(set.is_empty());
+
+        set.insert(Opcode::ECAL);
+        set.insert(Opcode::CALL);
+        assert!(set.contains(Opcode::ECAL));
+        assert!(set.contains(Opcode::CALL));
+        assert!(!set.contains(Opcode::ADD));
+
+        set.remove(Opcode::ECAL);
+        assert!(!set.contains(Opcode::ECAL));
+        assert!(!set.is_empty());
+    }
+
+    #[test]
+    fn filter_allows_listed_or_unlisted_opcodes() {
+        let allow = OpcodeFilter::allow([Opcode::ADD]);
+        let deny = OpcodeFilter::deny([Opcode::ADD]);
+
+        assert!(OpcodeFilter::AllowAll.is_allowed(Opcode::ADD));
+        assert!(allow.is_allowed(Opcode::ADD));
+        assert!(!allow.is_allowed(Opcode::ECAL));
+        assert!(!deny.is_allowed(Opcode::ADD));
+        assert!(deny.is_allowed(Opcode::ECAL));
+        assert!(!OpcodeFilter::predicate_allowed().is_allowed(Opcode::CALL));
+        assert!(OpcodeFilter::predicate_allowed().is_allowed(Opcode::RET));
+    }
+}
@@ -0,0 +1,88 @@
+//! Notifications of the execution progress, sent every
+//! [`InterpreterParams::progress_stride`](crate::interpreter::InterpreterParams::progress_stride)
+//! retired instructions.
+
+use super::Interpreter;
+use alloc::sync::Arc;
+use core::fmt;
+use fuel_asm::{
+    RegId,
+    Word,
+};
+
+/// The progress of the execution at a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionProgress {
+    /// Number of the instructions retired since the initialization.
+    pub retired_instructions: u64,
+    /// Gas remaining for the whole transaction, i.e. `$ggas`.
+    pub remaining_gas: Word,
+    /// Gas remaining for the current call context, i.e. `$cgas`.
+    pub remaining_context_gas: Word,
+}
+
+/// Watches the progress of the execution, e.g. to render a progress bar or to notice a
+/// stalled execution.
+///
+/// The watcher is called from the main loop of the interpreter, so it should return
+/// quickly.
+pub trait ProgressWatcher {
+    /// Called after every
+    /// [`InterpreterParams::progress_stride`](crate::interpreter::InterpreterParams::progress_stride)
+    /// retired instructions.
+    fn on_progress(&self, progress: &ExecutionProgress);
+}
+
+/// Notifies the watcher.
+#[derive(Default, Clone)]
+pub(crate) struct ProgressNotifier {
+    watcher: Option<Arc<dyn ProgressWatcher + Send + Sync>>,
+}
+
+impl fmt::Debug for ProgressNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressNotifier")
+            .field(
+                "watcher",
+                &match self.watcher {
+                    Some(_) => "enabled",
+                    None => "disabled",
+                },
+            )
+            .finish()
+    }
+}
+
+impl ProgressNotifier {
+    pub(crate) fn set_watcher(
+        &mut self,
+        watcher: Arc<dyn ProgressWatcher + Send + Sync>,
+    ) {
+        self.watcher = Some(watcher);
+    }
+
+    pub(crate) fn has_watcher(&self) -> bool {
+        self.watcher.is_some()
+    }
+}
+
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
+    /// Notifies the watcher once the stride elapses.
+    pub(crate) fn notify_progress_if_due(&self) {
+        let Some(stride) = self.interpreter_params.progress_stride else {
+            return
+        };
+        if self.executed_instructions.checked_rem(stride) != Some(0) {
+            return
+        }
+
+        if let Some(watcher) = &self.progress_notifier.watcher {
+            watcher.on_progress(&ExecutionProgress {
+                retired_instructions: self.executed_instructions,
+                remaining_gas: self.registers[RegId::GGAS],
+                remaining_context_gas: self.registers[RegId::CGAS],
+            });
+        }
+    }
+}
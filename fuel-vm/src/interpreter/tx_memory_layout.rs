@@ -0,0 +1,93 @@
+use crate::consts::{
+    VM_MEMORY_BALANCES_OFFSET,
+    VM_MEMORY_BASE_ASSET_ID_OFFSET,
+    WORD_SIZE,
+};
+use fuel_tx::consts::BALANCE_ENTRY_SIZE;
+
+/// Layout of the data written to the stack before the transaction is executed.
+///
+/// Programs read this data at fixed offsets, so changing the layout changes the
+/// result of executing them. A new layout is introduced as a new version, and the
+/// historical transactions are replayed with the version they were executed with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TxMemoryLayout {
+    /// The transaction id, the base asset id, the initial balances with an entry
+    /// reserved for every input, the size of the transaction and the serialized
+    /// transaction. The transaction offset is
+    /// [`TxParameters::tx_offset`](fuel_tx::TxParameters::tx_offset).
+    #[default]
+    V1,
+}
+
+impl TxMemoryLayout {
+    /// The latest version of the layout.
+    pub const LATEST: Self = Self::V1;
+
+    /// Offset of the transaction id.
+    pub const fn tx_id_offset(&self) -> usize {
+        match self {
+            Self::V1 => 0,
+        }
+    }
+
+    /// Offset of the base asset id.
+    pub const fn base_asset_id_offset(&self) -> usize {
+        match self {
+            Self::V1 => VM_MEMORY_BASE_ASSET_ID_OFFSET,
+        }
+    }
+
+    /// Offset of the initial balances.
+    pub const fn balances_offset(&self) -> usize {
+        match self {
+            Self::V1 => VM_MEMORY_BALANCES_OFFSET,
+        }
+    }
+
+    /// Offset of the transaction size, given the offset of the transaction.
+    pub const fn tx_size_offset(&self, tx_offset: usize) -> Option<usize> {
+        match self {
+            Self::V1 => tx_offset.checked_sub(WORD_SIZE),
+        }
+    }
+
+    /// Offset of the transaction when the number of inputs is limited to
+    /// `max_inputs`.
+    pub const fn tx_offset(&self, max_inputs: u16) -> usize {
+        match self {
+            Self::V1 => self
+                .balances_offset()
+                .saturating_add((max_inputs as usize).saturating_mul(BALANCE_ENTRY_SIZE))
+                .saturating_add(WORD_SIZE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_tx::TxParameters;
+
+    #[test]
+    fn v1_matches_the_consensus_tx_offset() {
+        let params = TxParameters::DEFAULT;
+
+        // The runtime balances are laid out at the fixed offset
+        assert_eq!(
+            TxMemoryLayout::V1.balances_offset(),
+            VM_MEMORY_BALANCES_OFFSET
+        );
+
+        assert_eq!(
+            TxMemoryLayout::V1.tx_offset(params.max_inputs()),
+            params.tx_offset()
+        );
+        assert_eq!(
+            TxMemoryLayout::V1.tx_size_offset(params.tx_offset()),
+            Some(params.tx_offset() - WORD_SIZE)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Metrics of the interpreter internals, reported to a [`VmMetrics`] attached with
+//! [`Interpreter::with_vm_metrics`].
+//!
+//! With the `metrics` feature, the interpreters report to
+//! `metrics::FacadeMetrics` until another [`VmMetrics`] is
+//! attached.
+
+use super::Interpreter;
+use alloc::sync::Arc;
+use core::fmt;
+use fuel_asm::{
+    Opcode,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::ScriptExecutionResult;
+use fuel_types::Word;
+
+/// Receives the metrics of the interpreter, e.g. to export them to Prometheus.
+///
+/// All the methods are no-ops by default, so an implementation only overrides the
+/// metrics it is interested in. They are called from the main loop of the
+/// interpreter, so they should return quickly, e.g. by incrementing an atomic
+/// counter.
+pub trait VmMetrics {
+    /// Called before an instruction is executed, including the ones that panic.
+    fn on_instruction(&self, _opcode: Opcode) {}
+
+    /// Called before an instruction reading the contract storage is executed:
+    /// `SRW`, `SRWQ`, `BAL`, `CSIZ`, `CCP`, `LDC`, `CROO`, `BSIZ` and `BLDD`.
+    fn on_storage_read(&self, _opcode: Opcode) {}
+
+    /// Called before an instruction writing the contract storage is executed:
+    /// `SWW`, `SWWQ`, `SCWQ`, `TR`, `TRO`, `MINT` and `BURN`.
+    fn on_storage_write(&self, _opcode: Opcode) {}
+
+    /// Called after an instruction grew the memory in use, i.e. the stack and the
+    /// heap, by `bytes`.
+    fn on_memory_grown(&self, _bytes: Word) {}
+
+    /// Called before a `CALL` instruction is executed.
+    fn on_call(&self) {}
+
+    /// Called after an instruction panicked.
+    fn on_panic(&self, _reason: PanicReason) {}
+
+    /// Called after a script is executed, with the gas it used.
+    fn on_script_executed(&self, _result: &ScriptExecutionResult, _gas_used: Word) {}
+}
+
+/// The [`VmMetrics`] ignoring all the metrics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoopVmMetrics;
+
+impl VmMetrics for NoopVmMetrics {}
+
+/// The metrics receiver attached to the interpreter.
+#[derive(Clone)]
+pub(crate) struct VmMetricsReporter {
+    metrics: Option<Arc<dyn VmMetrics + Send + Sync>>,
+}
+
+impl Default for VmMetricsReporter {
+    fn default() -> Self {
+        #[cfg(feature = "metrics")]
+        let metrics: Option<Arc<dyn VmMetrics + Send + Sync>> =
+            Some(Arc::new(crate::metrics::FacadeMetrics));
+        #[cfg(not(feature = "metrics"))]
+        let metrics = None;
+
+        Self { metrics }
+    }
+}
+
+impl fmt::Debug for VmMetricsReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VmMetricsReporter")
+            .field(
+                "metrics",
+                &match self.metrics {
+                    Some(_) => "enabled",
+                    None => "disabled",
+                },
+            )
+            .finish()
+    }
+}
+
+impl VmMetricsReporter {
+    pub(crate) fn set_metrics(&mut self, metrics: Arc<dyn VmMetrics + Send + Sync>) {
+        self.metrics = Some(metrics);
+    }
+
+    /// Reports the instruction about to be executed.
+    pub(crate) fn instruction(&self, opcode: Opcode) {
+        let Some(metrics) = &self.metrics else { return };
+
+        metrics.on_instruction(opcode);
+        match opcode {
+            Opcode::CALL => metrics.on_call(),
+            Opcode::SRW
+            | Opcode::SRWQ
+            | Opcode::BAL
+            | Opcode::CSIZ
+            | Opcode::CCP
+            | Opcode::LDC
+            | Opcode::CROO
+            | Opcode::BSIZ
+            | Opcode::BLDD => metrics.on_storage_read(opcode),
+            Opcode::SWW
+            | Opcode::SWWQ
+            | Opcode::SCWQ
+            | Opcode::TR
+            | Opcode::TRO
+            | Opcode::MINT
+            | Opcode::BURN => metrics.on_storage_write(opcode),
+            _ => {}
+        }
+    }
+
+    /// Reports the growth of the memory in use by an instruction.
+    pub(crate) fn memory_grown(&self, bytes: Word) {
+        if let Some(metrics) = &self.metrics {
+            if bytes > 0 {
+                metrics.on_memory_grown(bytes);
+            }
+        }
+    }
+
+    pub(crate) fn panic(&self, reason: PanicReason) {
+        if let Some(metrics) = &self.metrics {
+            metrics.on_panic(reason);
+        }
+    }
+
+    pub(crate) fn script_executed(&self, result: &ScriptExecutionResult, gas_used: Word) {
+        if let Some(metrics) = &self.metrics {
+            metrics.on_script_executed(result, gas_used);
+        }
+    }
+}
+
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
+    /// The bytes of the memory in use by the stack and the heap.
+    pub(crate) fn memory_in_use(&self) -> Word {
+        let heap = crate::consts::VM_MAX_RAM.saturating_sub(self.registers[RegId::HP]);
+        self.registers[RegId::SP].saturating_add(heap)
+    }
+}
@@ -0,0 +1,301 @@
+//! Mapping of the VM errors into [JSON-RPC](https://www.jsonrpc.org/specification#error_object)
+//! error objects.
+//!
+//! API servers exposing the VM should use these conversions instead of their own, so
+//! that every server reports identical errors. Each error class has a stable
+//! [`code`](codes), and the `data` member describes the exact error in a structured
+//! form derived from the serialization of the crate types, e.g.
+//! `{"kind": "InputPredicateOwner", "index": 2}`.
+
+use crate::{
+    checked_transaction::CheckError,
+    error::{
+        InterpreterError,
+        PredicateVerificationFailed,
+    },
+};
+
+use alloc::{
+    format,
+    string::{
+        String,
+        ToString,
+    },
+};
+use core::fmt;
+use fuel_asm::{
+    PanicInstruction,
+    PanicReason,
+};
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+
+/// Error codes of the produced [`JsonRpcError`]s.
+///
+/// The codes are a part of the API and must never be changed or reused.
+pub mod codes {
+    /// The transaction doesn't pass the validity rules.
+    pub const TRANSACTION_VALIDITY: i64 = 1000;
+    /// The predicate verification failed.
+    pub const PREDICATE_VERIFICATION: i64 = 1001;
+    /// The max fee of the transaction is lower than required.
+    pub const INSUFFICIENT_MAX_FEE: i64 = 1002;
+    /// The VM execution panicked.
+    pub const PANIC: i64 = 2000;
+    /// The transaction's gas price doesn't match the expected one.
+    pub const WRONG_GAS_PRICE: i64 = 2001;
+    /// The execution was aborted by a limit of the interpreter parameters.
+    pub const EXECUTION_LIMIT: i64 = 2002;
+    /// The storage failed.
+    pub const STORAGE: i64 = 3000;
+    /// The interpreter was used incorrectly or encountered a bug.
+    pub const INTERNAL: i64 = 3001;
+}
+
+/// A JSON-RPC error object.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct JsonRpcError {
+    /// The error class, one of the [`codes`].
+    pub code: i64,
+    /// Short human-readable description of the error.
+    pub message: String,
+    /// Structured description of the error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcError {
+    fn new(code: i64, message: impl ToString, data: Value) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+            data: Some(data),
+        }
+    }
+}
+
+impl fmt::Display for JsonRpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+/// Flattens the serialized form of an enum into an object tagged with `kind`.
+///
+/// Fields of struct variants are inlined, while the payload of tuple variants is
+/// stored under `value`.
+fn tagged<T: serde::Serialize>(value: &T) -> Value {
+    let mut data = Map::new();
+    match serde_json::to_value(value) {
+        Ok(Value::String(kind)) => {
+            data.insert("kind".to_string(), Value::String(kind));
+        }
+        Ok(Value::Object(object)) if object.len() == 1 => {
+            for (kind, payload) in object {
+                data.insert("kind".to_string(), Value::String(kind));
+                match payload {
+                    Value::Object(fields) => data.extend(fields),
+                    payload => {
+                        data.insert("value".to_string(), payload);
+                    }
+                }
+            }
+        }
+        Ok(other) => {
+            data.insert("value".to_string(), other);
+        }
+        Err(err) => {
+            data.insert("error".to_string(), Value::String(err.to_string()));
+        }
+    }
+    Value::Object(data)
+}
+
+fn panic_data(reason: PanicReason) -> Value {
+    json!({
+        "reason": format!("{reason:?}"),
+        "reason_code": reason as u8,
+    })
+}
+
+impl From<PanicReason> for JsonRpcError {
+    fn from(reason: PanicReason) -> Self {
+        Self::new(codes::PANIC, "VM panic", panic_data(reason))
+    }
+}
+
+impl From<PanicInstruction> for JsonRpcError {
+    fn from(panic: PanicInstruction) -> Self {
+        let mut data = panic_data(*panic.reason());
+        data["instruction"] = json!(panic.instruction());
+        Self::new(codes::PANIC, "VM panic", data)
+    }
+}
+
+impl From<&PredicateVerificationFailed> for JsonRpcError {
+    fn from(error: &PredicateVerificationFailed) -> Self {
+        let data = match error {
+            PredicateVerificationFailed::Panic(reason) => panic_data(*reason),
+            PredicateVerificationFailed::PanicInstruction(panic) => {
+                Self::from(*panic).data.unwrap_or_default()
+            }
+            error => tagged(error),
+        };
+        Self::new(codes::PREDICATE_VERIFICATION, error, data)
+    }
+}
+
+impl From<&CheckError> for JsonRpcError {
+    fn from(error: &CheckError) -> Self {
+        match error {
+            CheckError::Validity(error) => {
+                Self::new(codes::TRANSACTION_VALIDITY, error, tagged(error))
+            }
+            CheckError::PredicateVerificationFailed(error) => Self::from(error),
+            CheckError::InsufficientMaxFee {
+                max_fee_from_policies,
+                max_fee_from_gas_price,
+            } => Self::new(
+                codes::INSUFFICIENT_MAX_FEE,
+                "Insufficient max fee",
+                json!({
+                    "max_fee_from_policies": max_fee_from_policies,
+                    "max_fee_from_gas_price": max_fee_from_gas_price,
+                }),
+            ),
+        }
+    }
+}
+
+impl<StorageError> From<&InterpreterError<StorageError>> for JsonRpcError
+where
+    StorageError: fmt::Debug,
+{
+    fn from(error: &InterpreterError<StorageError>) -> Self {
+        match error {
+            InterpreterError::PanicInstruction(panic) => Self::from(*panic),
+            InterpreterError::Panic(reason) => Self::from(*reason),
+            InterpreterError::CheckError(error) => Self::from(error),
+            InterpreterError::ReadyTransactionWrongGasPrice { expected, actual } => {
+                Self::new(
+                    codes::WRONG_GAS_PRICE,
+                    "Wrong gas price",
+                    json!({
+                        "expected": expected,
+                        "actual": actual,
+                    }),
+                )
+            }
+            InterpreterError::ExecutionLimitExceeded(limit) => Self::new(
+                codes::EXECUTION_LIMIT,
+                "Execution limit exceeded",
+                tagged(limit),
+            ),
+            InterpreterError::Storage(error) => Self::new(
+                codes::STORAGE,
+                "Storage error",
+                json!({ "error": format!("{error:?}") }),
+            ),
+            InterpreterError::Bug(bug) => {
+                Self::new(codes::INTERNAL, "Bug", json!({ "bug": bug.to_string() }))
+            }
+            InterpreterError::NoTransactionInitialized
+            | InterpreterError::DebugStateNotInitialized => Self::new(
+                codes::INTERNAL,
+                "Internal error",
+                tagged(&error.erase_generics()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_tx::ValidityError;
+    use fuel_types::AssetId;
+
+    #[test]
+    fn validity_error__data_contains_index() {
+        let error = CheckError::Validity(ValidityError::InputPredicateOwner { index: 2 });
+
+        let rpc_error = JsonRpcError::from(&error);
+
+        assert_eq!(rpc_error.code, codes::TRANSACTION_VALIDITY);
+        assert_eq!(
+            rpc_error.data,
+            Some(json!({ "kind": "InputPredicateOwner", "index": 2 }))
+        );
+    }
+
+    #[test]
+    fn validity_error__unit_and_tuple_variants_are_tagged() {
+        let unit =
+            JsonRpcError::from(&CheckError::Validity(ValidityError::NoSpendableInput));
+        let tuple = JsonRpcError::from(&CheckError::Validity(
+            ValidityError::TransactionOutputChangeAssetIdNotFound(AssetId::zeroed()),
+        ));
+
+        assert_eq!(unit.data, Some(json!({ "kind": "NoSpendableInput" })));
+        assert_eq!(
+            tuple.data,
+            Some(json!({
+                "kind": "TransactionOutputChangeAssetIdNotFound",
+                "value": serde_json::to_value(AssetId::zeroed()).unwrap(),
+            }))
+        );
+    }
+
+    #[test]
+    fn interpreter_error__panic_instruction_has_reason_and_instruction() {
+        let panic = PanicInstruction::error(PanicReason::OutOfGas, 0x24040000);
+        let error = InterpreterError::<String>::PanicInstruction(panic);
+
+        let rpc_error = JsonRpcError::from(&error);
+
+        assert_eq!(rpc_error.code, codes::PANIC);
+        assert_eq!(
+            rpc_error.data,
+            Some(json!({
+                "reason": "OutOfGas",
+                "reason_code": PanicReason::OutOfGas as u8,
+                "instruction": 0x24040000,
+            }))
+        );
+    }
+
+    #[test]
+    fn interpreter_error__predicate_failure_maps_to_predicate_code() {
+        let error = InterpreterError::<String>::CheckError(
+            CheckError::PredicateVerificationFailed(PredicateVerificationFailed::False),
+        );
+
+        let rpc_error = JsonRpcError::from(&error);
+
+        assert_eq!(rpc_error.code, codes::PREDICATE_VERIFICATION);
+        assert_eq!(rpc_error.data, Some(json!({ "kind": "False" })));
+    }
+
+    #[test]
+    fn json_rpc_error__serializes_to_error_object() {
+        let rpc_error = JsonRpcError::from(PanicReason::Revert);
+
+        let value = serde_json::to_value(&rpc_error).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "code": codes::PANIC,
+                "message": "VM panic",
+                "data": {
+                    "reason": "Revert",
+                    "reason_code": PanicReason::Revert as u8,
+                },
+            })
+        );
+    }
+}
@@ -24,20 +24,37 @@ use criterion as _;
 
 pub mod backtrace;
 pub mod call;
+pub mod call_tree;
+#[cfg(feature = "capi")]
+pub mod capi;
 pub mod checked_transaction;
 pub mod constraints;
 pub mod consts;
 pub mod context;
 mod convert;
 pub mod crypto;
+#[cfg(feature = "debug-server")]
+pub mod debug_server;
+#[cfg(feature = "test-helpers")]
+pub mod differential;
 pub mod error;
+#[cfg(feature = "gas-calibration")]
+pub mod gas_calibration;
 pub mod interpreter;
+#[cfg(feature = "json-rpc")]
+pub mod json_rpc;
 #[cfg(feature = "test-helpers")]
 pub mod memory_client;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod pool;
 pub mod predicate;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod state;
 pub mod storage;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
 pub mod transactor;
 pub mod util;
 
@@ -65,6 +82,12 @@ pub mod profiler {
     }
 }
 
+#[cfg(not(feature = "metrics"))]
+/// Placeholder
+mod metrics {
+    pub(crate) fn code_cache_lookup(_hit: bool) {}
+}
+
 // Fully re-export fuel dependencies
 #[doc(no_inline)]
 pub use fuel_asm;
@@ -0,0 +1,211 @@
+//! VM-level metrics reported through the [`metrics`](::metrics) facade.
+//!
+//! The metrics are emitted to the recorder installed by the application, and are
+//! no-ops if there is none. The interpreters report them with [`FacadeMetrics`], unless
+//! another [`VmMetrics`] is attached with
+//! [`Interpreter::with_vm_metrics`](crate::interpreter::Interpreter::with_vm_metrics).
+//! The following metrics are reported:
+//!
+//! | Name | Type | Labels | Description |
+//! |------|------|--------|-------------|
+//! | `fuel_vm_instructions_total` | counter | | Instructions executed, including the ones that panicked. |
+//! | `fuel_vm_calls_total` | counter | | Executed `CALL` instructions. |
+//! | `fuel_vm_storage_operations_total` | counter | `op` | Executed contract storage instructions, by lowercase mnemonic. |
+//! | `fuel_vm_panics_total` | counter | `reason` | Panics, by [`PanicReason`]. |
+//! | `fuel_vm_code_cache_lookups_total` | counter | `result` | Lookups of the [`ContractCodeCache`](crate::storage::ContractCodeCache), by `hit` or `miss`. |
+//! | `fuel_vm_script_gas_used` | histogram | `result` | Gas used by script executions, by `success`, `revert` or `panic`. |
+//!
+//! Note that counting instructions touches the recorder on every executed instruction.
+
+use crate::interpreter::VmMetrics;
+use fuel_asm::{
+    Opcode,
+    PanicReason,
+};
+use fuel_tx::ScriptExecutionResult;
+use fuel_types::Word;
+
+/// Name of the counter of executed instructions.
+pub const INSTRUCTIONS: &str = "fuel_vm_instructions_total";
+/// Name of the counter of executed `CALL` instructions.
+pub const CALLS: &str = "fuel_vm_calls_total";
+/// Name of the counter of executed contract storage instructions.
+pub const STORAGE_OPERATIONS: &str = "fuel_vm_storage_operations_total";
+/// Name of the counter of panics.
+pub const PANICS: &str = "fuel_vm_panics_total";
+/// Name of the counter of contract code cache lookups.
+pub const CODE_CACHE_LOOKUPS: &str = "fuel_vm_code_cache_lookups_total";
+/// Name of the histogram of gas used by script executions.
+pub const SCRIPT_GAS_USED: &str = "fuel_vm_script_gas_used";
+
+/// The [`VmMetrics`] reporting to the [`metrics`](::metrics) facade.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FacadeMetrics;
+
+impl VmMetrics for FacadeMetrics {
+    fn on_instruction(&self, opcode: Opcode) {
+        ::metrics::counter!(INSTRUCTIONS).increment(1);
+
+        let storage_op = match opcode {
+            Opcode::CALL => {
+                ::metrics::counter!(CALLS).increment(1);
+                return
+            }
+            Opcode::SCWQ => "scwq",
+            Opcode::SRW => "srw",
+            Opcode::SRWQ => "srwq",
+            Opcode::SWW => "sww",
+            Opcode::SWWQ => "swwq",
+            _ => return,
+        };
+        ::metrics::counter!(STORAGE_OPERATIONS, "op" => storage_op).increment(1);
+    }
+
+    fn on_panic(&self, reason: PanicReason) {
+        ::metrics::counter!(PANICS, "reason" => alloc::format!("{reason:?}"))
+            .increment(1);
+    }
+
+    fn on_script_executed(&self, result: &ScriptExecutionResult, gas_used: Word) {
+        let result = match result {
+            ScriptExecutionResult::Success => "success",
+            ScriptExecutionResult::Revert => "revert",
+            ScriptExecutionResult::Panic => "panic",
+            ScriptExecutionResult::GenericFailure(_) => "failure",
+        };
+        #[allow(clippy::cast_precision_loss)]
+        ::metrics::histogram!(SCRIPT_GAS_USED, "result" => result)
+            .record(gas_used as f64);
+    }
+}
+
+/// Records a lookup of the contract code cache.
+pub(crate) fn code_cache_lookup(hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    ::metrics::counter!(CODE_CACHE_LOOKUPS, "result" => result).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use ::metrics::{
+        Counter,
+        Gauge,
+        Histogram,
+        Key,
+        KeyName,
+        Metadata,
+        Recorder,
+        SharedString,
+        Unit,
+    };
+    use alloc::{
+        string::{
+            String,
+            ToString,
+        },
+        sync::Arc,
+        vec,
+        vec::Vec,
+    };
+    use core::sync::atomic::{
+        AtomicU64,
+        Ordering,
+    };
+    use fuel_asm::{
+        op,
+        RegId,
+    };
+    use std::{
+        collections::HashMap,
+        sync::Mutex,
+    };
+
+    #[derive(Default)]
+    struct TestRecorder {
+        counters: Mutex<HashMap<String, Arc<AtomicU64>>>,
+        histograms: Mutex<Vec<String>>,
+    }
+
+    impl TestRecorder {
+        fn key(key: &Key) -> String {
+            let mut name = key.name().to_string();
+            for label in key.labels() {
+                name.push_str(&alloc::format!(",{}={}", label.key(), label.value()));
+            }
+            name
+        }
+
+        fn counter(&self, key: &str) -> u64 {
+            self.counters
+                .lock()
+                .unwrap()
+                .get(key)
+                .map(|counter| counter.load(Ordering::SeqCst))
+                .unwrap_or_default()
+        }
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            let counter = self
+                .counters
+                .lock()
+                .unwrap()
+                .entry(Self::key(key))
+                .or_default()
+                .clone();
+            Counter::from_arc(counter)
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            self.histograms.lock().unwrap().push(Self::key(key));
+            Histogram::noop()
+        }
+    }
+
+    #[test]
+    fn script_execution_reports_metrics() {
+        let recorder = TestRecorder::default();
+        let script = vec![
+            op::movi(0x10, 0),
+            op::srw(0x11, 0x12, 0x10),
+            op::ret(RegId::ONE),
+        ];
+
+        ::metrics::with_local_recorder(&recorder, || {
+            let mut client = MemoryClient::default();
+            let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+                .script_gas_limit(1_000_000)
+                .add_fee_input()
+                .finalize_checked(Default::default());
+            client.transact(tx);
+        });
+
+        // `SRW` panics outside of a contract context, so `RET` is never executed.
+        assert_eq!(recorder.counter(INSTRUCTIONS), 2);
+        assert_eq!(
+            recorder.counter("fuel_vm_storage_operations_total,op=srw"),
+            1
+        );
+        assert_eq!(
+            recorder.counter("fuel_vm_panics_total,reason=ExpectedInternalContext"),
+            1
+        );
+        assert_eq!(
+            recorder.histograms.lock().unwrap().as_slice(),
+            ["fuel_vm_script_gas_used,result=panic"]
+        );
+    }
+}
@@ -1,9 +1,54 @@
 //! Predicate representations with required data to be executed during VM runtime
+//!
+//! The verification of the predicates only needs the memory and the interpreter core,
+//! and no storage, so it builds without `std` (`--no-default-features --features
+//! alloc`), e.g. for verifying the predicates inside an enclave:
+//!
+//! ```rust
+//! use fuel_tx::Script;
+//! use fuel_vm::{
+//!     checked_transaction::{
+//!         CheckError,
+//!         CheckPredicateParams,
+//!         CheckPredicates,
+//!         Checked,
+//!     },
+//!     interpreter::MemoryInstance,
+//! };
+//!
+//! fn verify(
+//!     tx: Checked<Script>,
+//!     params: &CheckPredicateParams,
+//! ) -> Result<Checked<Script>, CheckError> {
+//!     tx.check_predicates(params, MemoryInstance::new())
+//! }
+//! ```
 
 use fuel_tx::field;
 
 use crate::interpreter::MemoryRange;
 
+mod cache;
+mod executor;
+mod gas_bound;
+
+pub use cache::{
+    CachingPredicateEstimator,
+    PredicateCacheKey,
+    PredicateCacheMetrics,
+    PredicateGasCache,
+};
+pub use executor::{
+    execute_predicate,
+    PredicateContext,
+    PredicateOutcome,
+};
+pub use gas_bound::{
+    predicate_gas_upper_bound,
+    PredicateGasBound,
+    UnboundedReason,
+};
+
 /// Runtime representation of a predicate
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,178 @@
+//! Memoized estimation of the gas used by predicates.
+//!
+//! Transactions often spend many inputs owned by the same predicate with the same
+//! predicate data, and
+//! [`EstimatePredicates`](crate::checked_transaction::EstimatePredicates) executes each
+//! of them. [`CachingPredicateEstimator`] keys the estimated gas by the hash of the
+//! predicate code and the hash of the predicate data, and executes each
+//! distinct predicate once. Reusing the estimator, or supplying a shared
+//! [`PredicateGasCache`], extends the memoization across transactions.
+//!
+//! The cached gas is only correct for predicates whose execution doesn't depend on the
+//! rest of the transaction, e.g. on the index of the input being spent.
+
+use fuel_crypto::Hasher;
+use fuel_tx::Input;
+use fuel_types::{
+    Bytes32,
+    Word,
+};
+use hashbrown::HashMap;
+
+use crate::{
+    checked_transaction::{
+        CheckError,
+        CheckPredicateParams,
+    },
+    interpreter::{
+        ExecutableTransaction,
+        Interpreter,
+        Memory,
+    },
+};
+
+/// Key of the estimated gas of a predicate in a [`PredicateGasCache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredicateCacheKey {
+    /// Hash of the predicate bytecode.
+    pub code_hash: Bytes32,
+    /// Hash of the predicate data.
+    pub data_hash: Bytes32,
+}
+
+impl PredicateCacheKey {
+    /// Creates the key of the predicate with its data.
+    pub fn new(predicate: &[u8], predicate_data: &[u8]) -> Self {
+        Self {
+            code_hash: Hasher::hash(predicate),
+            data_hash: Hasher::hash(predicate_data),
+        }
+    }
+
+    /// Creates the key of the predicate of the input, or `None` if the input has no
+    /// predicate.
+    pub fn from_input(input: &Input) -> Option<Self> {
+        let (predicate, predicate_data, _) = input.predicate()?;
+        Some(Self::new(predicate, predicate_data))
+    }
+}
+
+/// Storage of the gas used by the predicates, shared by the estimations.
+pub trait PredicateGasCache {
+    /// Returns the gas used by the predicate, if it was estimated before.
+    fn get(&self, key: &PredicateCacheKey) -> Option<Word>;
+
+    /// Records the gas used by the predicate.
+    fn insert(&mut self, key: PredicateCacheKey, gas_used: Word);
+}
+
+impl PredicateGasCache for HashMap<PredicateCacheKey, Word> {
+    fn get(&self, key: &PredicateCacheKey) -> Option<Word> {
+        HashMap::get(self, key).copied()
+    }
+
+    fn insert(&mut self, key: PredicateCacheKey, gas_used: Word) {
+        HashMap::insert(self, key, gas_used);
+    }
+}
+
+impl<C: PredicateGasCache + ?Sized> PredicateGasCache for &mut C {
+    fn get(&self, key: &PredicateCacheKey) -> Option<Word> {
+        (**self).get(key)
+    }
+
+    fn insert(&mut self, key: PredicateCacheKey, gas_used: Word) {
+        (**self).insert(key, gas_used)
+    }
+}
+
+/// Hit and miss counters of a [`CachingPredicateEstimator`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredicateCacheMetrics {
+    /// Predicates whose gas was taken from the cache.
+    pub hits: u64,
+    /// Predicates that were executed.
+    pub misses: u64,
+}
+
+impl PredicateCacheMetrics {
+    /// The ratio of the predicates served from the cache, or `0` if there were none.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits.saturating_add(self.misses);
+        if lookups == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let rate = self.hits as f64 / lookups as f64;
+        rate
+    }
+}
+
+/// Estimates the predicates of transactions, executing each distinct pair of the
+/// predicate and its data once.
+#[derive(Debug, Clone, Default)]
+pub struct CachingPredicateEstimator<C = HashMap<PredicateCacheKey, Word>> {
+    cache: C,
+    metrics: PredicateCacheMetrics,
+}
+
+impl CachingPredicateEstimator {
+    /// Creates the estimator with an empty in-memory cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: PredicateGasCache> CachingPredicateEstimator<C> {
+    /// Creates the estimator backed by the user-supplied `cache`.
+    pub fn with_cache(cache: C) -> Self {
+        Self {
+            cache,
+            metrics: PredicateCacheMetrics::default(),
+        }
+    }
+
+    /// The cache of the estimated gas.
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+
+    /// Consumes the estimator, returning the cache.
+    pub fn into_cache(self) -> C {
+        self.cache
+    }
+
+    /// The hit and miss counters of the estimations so far.
+    pub const fn metrics(&self) -> PredicateCacheMetrics {
+        self.metrics
+    }
+
+    /// Resets the hit and miss counters.
+    pub fn reset_metrics(&mut self) {
+        self.metrics = PredicateCacheMetrics::default();
+    }
+
+    /// Estimates the predicates of the transaction, setting the `predicate_gas_used`
+    /// of each predicate input, like
+    /// [`EstimatePredicates::estimate_predicates`](crate::checked_transaction::EstimatePredicates::estimate_predicates).
+    pub fn estimate<Tx>(
+        &mut self,
+        transaction: &mut Tx,
+        params: &CheckPredicateParams,
+        memory: impl Memory,
+    ) -> Result<(), CheckError>
+    where
+        Tx: ExecutableTransaction,
+    {
+        Interpreter::estimate_predicates_cached(
+            transaction,
+            params,
+            memory,
+            &mut self.cache,
+            &mut self.metrics,
+        )?;
+        Ok(())
+    }
+}
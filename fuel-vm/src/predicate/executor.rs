@@ -0,0 +1,244 @@
+//! Execution of a single predicate in isolation.
+//!
+//! [`execute_predicate`] wraps the predicate into a coin input appended to the
+//! transaction of the [`PredicateContext`], an empty script by default, and runs it
+//! with the gas limit of the context. It is meant for testing predicates without
+//! building and signing a whole transaction.
+//!
+//! The context also carries the height of the block including the transaction. The
+//! predicates can't read the block height or the timestamp, since `BHEI` and `TIME`
+//! aren't allowed in them, and time locks are expressed with the maturity of the
+//! transaction instead: a transaction is only valid in blocks at or above its
+//! maturity. Unless the transaction sets the maturity, it is set to the block height,
+//! the highest maturity valid at the height.
+
+use alloc::vec::Vec;
+use fuel_asm::{
+    PanicInstruction,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    field::{
+        Inputs,
+        Maturity,
+        Policies as PoliciesField,
+    },
+    policies::{
+        Policies,
+        PolicyType,
+    },
+    Input,
+    Receipt,
+    Script,
+    Transaction,
+    TxPointer,
+    UtxoId,
+    ValidityError,
+};
+use fuel_types::{
+    BlockHeight,
+    ContractId,
+    Word,
+};
+
+use crate::{
+    checked_transaction::{
+        CheckError,
+        CheckPredicateParams,
+    },
+    context::Context,
+    error::PredicateVerificationFailed,
+    interpreter::{
+        Interpreter,
+        InterpreterParams,
+        MemoryInstance,
+    },
+    predicate::RuntimePredicate,
+    state::ProgramState,
+    storage::PredicateStorage,
+};
+
+/// The environment of a predicate executed by [`execute_predicate`].
+#[derive(Debug, Clone)]
+pub struct PredicateContext {
+    /// Parameters of the chain the predicate is executed for.
+    pub params: CheckPredicateParams,
+    /// Gas available to the predicate.
+    pub gas_limit: Word,
+    /// Height of the block including the transaction.
+    pub block_height: BlockHeight,
+    /// The transaction spending the predicate, which is appended to its inputs.
+    pub transaction: Script,
+}
+
+impl PredicateContext {
+    /// Creates the context of an empty script at the genesis height, with the gas
+    /// limit set to the maximum gas per predicate.
+    pub fn new(params: CheckPredicateParams) -> Self {
+        let gas_limit = params.max_gas_per_predicate;
+        let transaction = Transaction::script(
+            0,
+            Vec::new(),
+            Vec::new(),
+            Policies::new().with_max_fee(0),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        );
+        Self {
+            params,
+            gas_limit,
+            block_height: BlockHeight::default(),
+            transaction,
+        }
+    }
+
+    /// Sets the gas available to the predicate.
+    pub fn with_gas_limit(mut self, gas_limit: Word) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Sets the height of the block including the transaction.
+    pub fn with_block_height(mut self, block_height: BlockHeight) -> Self {
+        self.block_height = block_height;
+        self
+    }
+
+    /// Sets the transaction spending the predicate, e.g. with the outputs or the
+    /// policies checked by the predicate.
+    pub fn with_transaction(mut self, transaction: Script) -> Self {
+        self.transaction = transaction;
+        self
+    }
+}
+
+#[cfg(feature = "test-helpers")]
+impl Default for PredicateContext {
+    fn default() -> Self {
+        Self::new(CheckPredicateParams::default())
+    }
+}
+
+/// The result of [`execute_predicate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PredicateOutcome {
+    /// `Ok` if the predicate returned `1`, or the reason why it didn't. The
+    /// transaction of the context not being valid at the block height is reported as
+    /// [`CheckError::Validity`].
+    pub result: Result<(), CheckError>,
+    /// Gas used by the predicate.
+    pub gas_used: Word,
+    /// The receipts the predicate would have produced as a script, ending with the
+    /// `Return`, `Revert` or `Panic` receipt of the execution.
+    pub trace: Vec<Receipt>,
+}
+
+impl PredicateOutcome {
+    /// Returns `true` if the predicate returned `1`.
+    pub fn passed(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Executes the predicate `code` with the `data` in isolation, as the last input of
+/// the transaction of the `context`.
+///
+/// Unlike the verification of the predicates of a transaction, the predicate isn't
+/// required to use exactly the gas set for it.
+pub fn execute_predicate(
+    code: &[u8],
+    data: &[u8],
+    context: &PredicateContext,
+) -> PredicateOutcome {
+    let params = &context.params;
+    let mut tx = context.transaction.clone();
+    if !tx.policies().is_set(PolicyType::Maturity) {
+        tx.set_maturity(context.block_height);
+    }
+    if tx.maturity() > context.block_height {
+        return PredicateOutcome {
+            result: Err(ValidityError::TransactionMaturity.into()),
+            gas_used: 0,
+            trace: Vec::new(),
+        }
+    }
+
+    let input = Input::coin_predicate(
+        UtxoId::default(),
+        Input::predicate_owner(code),
+        0,
+        params.base_asset_id,
+        TxPointer::default(),
+        context.gas_limit,
+        code.to_vec(),
+        data.to_vec(),
+    );
+    tx.inputs_mut().push(input);
+    let index = tx.inputs().len().saturating_sub(1);
+
+    run(tx, index, context)
+}
+
+fn run(tx: Script, index: usize, context: &PredicateContext) -> PredicateOutcome {
+    let predicate = RuntimePredicate::from_tx(&tx, context.params.tx_offset, index)
+        .expect("The last input of the transaction is the predicate");
+
+    let zero_gas_price = 0;
+    let interpreter_params =
+        InterpreterParams::new(zero_gas_price, context.params.clone());
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        PredicateStorage {},
+        interpreter_params,
+    );
+
+    if let Err(err) = vm.init_predicate(
+        Context::PredicateEstimation { program: predicate },
+        tx,
+        context.gas_limit,
+    ) {
+        return PredicateOutcome {
+            result: Err(PredicateVerificationFailed::from(err).into()),
+            gas_used: 0,
+            trace: Vec::new(),
+        }
+    }
+
+    let state = vm.verify_predicate();
+    let gas_used = context.gas_limit.saturating_sub(vm.remaining_gas());
+    let pc = vm.registers()[RegId::PC];
+    let is = vm.registers()[RegId::IS];
+    let id = ContractId::zeroed();
+
+    let mut trace = vm.receipts().to_vec();
+    let panic = |reason: PanicReason| {
+        Receipt::panic(id, PanicInstruction::error(reason, 0), pc, is)
+    };
+    // The `RET` and `RVRT` receipts are recorded by the VM, the panics are not
+    let (result, last) = match state {
+        Ok(ProgramState::Return(_)) => (Ok(()), None),
+        Ok(_) => (Err(PredicateVerificationFailed::False), None),
+        Err(PredicateVerificationFailed::PanicInstruction(instruction)) => (
+            Err(PredicateVerificationFailed::PanicInstruction(instruction)),
+            Some(Receipt::panic(id, instruction, pc, is)),
+        ),
+        Err(PredicateVerificationFailed::Panic(reason)) => (
+            Err(PredicateVerificationFailed::Panic(reason)),
+            Some(panic(reason)),
+        ),
+        Err(PredicateVerificationFailed::OutOfGas) => (
+            Err(PredicateVerificationFailed::OutOfGas),
+            Some(panic(PanicReason::OutOfGas)),
+        ),
+        Err(err) => (Err(err), None),
+    };
+    trace.extend(last);
+
+    PredicateOutcome {
+        result: result.map_err(Into::into),
+        gas_used,
+        trace,
+    }
+}
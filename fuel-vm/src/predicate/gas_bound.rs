@@ -0,0 +1,446 @@
+//! Static upper bound of the gas used by a predicate.
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use fuel_asm::{
+    cfg::{
+        ControlFlowGraph,
+        Exit,
+    },
+    Instruction,
+    RegId,
+};
+use fuel_tx::GasCosts;
+use fuel_types::Word;
+
+/// Upper bound of the gas used by the execution of a predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PredicateGasBound {
+    /// The predicate never uses more gas than this.
+    Bounded(Word),
+    /// The gas usage can't be bounded statically, and is only limited by the maximum
+    /// gas per predicate.
+    Unbounded(UnboundedReason),
+}
+
+/// The reason why the gas usage of a predicate can't be bounded. The `index` is the
+/// position of the responsible instruction in the bytecode, counted in instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnboundedReason {
+    /// The instruction is the first one of a loop.
+    Loop {
+        /// Index of the instruction.
+        index: usize,
+    },
+    /// The instruction jumps to a target that depends on a register.
+    DynamicJump {
+        /// Index of the instruction.
+        index: usize,
+    },
+    /// The cost of the instruction depends on a length that isn't known statically.
+    DynamicLength {
+        /// Index of the instruction.
+        index: usize,
+    },
+    /// The execution may continue outside of the bytecode after the instruction.
+    LeavesProgram {
+        /// Index of the instruction.
+        index: usize,
+    },
+}
+
+/// Computes a conservative upper bound of the gas used by executing the predicate
+/// bytecode with the gas costs, without executing it.
+///
+/// The bound is the most expensive path through the [`ControlFlowGraph`] of the
+/// bytecode. Instructions that aren't allowed in predicates, or can't be decoded,
+/// end the path, as they panic before charging gas. Lengths of the instructions with
+/// dependent costs are resolved when they are immediates, or registers set with
+/// `MOVI` earlier in the same basic block.
+pub fn predicate_gas_upper_bound(
+    bytecode: &[u8],
+    gas_costs: &GasCosts,
+) -> PredicateGasBound {
+    match upper_bound(&ControlFlowGraph::new(bytecode), gas_costs) {
+        Ok(gas) => PredicateGasBound::Bounded(gas),
+        Err(reason) => PredicateGasBound::Unbounded(reason),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Visit {
+    Unvisited,
+    InProgress,
+    Done(Word),
+}
+
+fn upper_bound(
+    cfg: &ControlFlowGraph,
+    gas_costs: &GasCosts,
+) -> Result<Word, UnboundedReason> {
+    if cfg.blocks().is_empty() {
+        return Err(UnboundedReason::LeavesProgram { index: 0 })
+    }
+
+    // Depth-first traversal from the entry block, computing the most expensive path
+    // from each block once all of its successors are done.
+    let mut visits = vec![Visit::Unvisited; cfg.blocks().len()];
+    let mut summaries = vec![BlockSummary::default(); cfg.blocks().len()];
+    let mut stack = vec![(0, 0)];
+    summaries[0] = summarize(cfg, 0, gas_costs)?;
+    visits[0] = Visit::InProgress;
+
+    while let Some((block, next)) = stack.last_mut() {
+        let block = *block;
+        let summary = &summaries[block];
+        if let Some(&successor) = summary.successors.get(*next) {
+            *next = next.saturating_add(1);
+            match visits[successor] {
+                Visit::InProgress => {
+                    return Err(UnboundedReason::Loop {
+                        index: cfg.blocks()[successor].range.start,
+                    })
+                }
+                Visit::Unvisited => {
+                    summaries[successor] = summarize(cfg, successor, gas_costs)?;
+                    visits[successor] = Visit::InProgress;
+                    stack.push((successor, 0));
+                }
+                Visit::Done(_) => {}
+            }
+        } else {
+            let continuation = summary
+                .successors
+                .iter()
+                .filter_map(|successor| match visits[*successor] {
+                    Visit::Done(gas) => Some(gas),
+                    _ => None,
+                })
+                .max()
+                .unwrap_or_default();
+            visits[block] = Visit::Done(summary.gas.saturating_add(continuation));
+            stack.pop();
+        }
+    }
+
+    match visits[0] {
+        Visit::Done(gas) => Ok(gas),
+        _ => unreachable!("the entry block is visited first and finished last"),
+    }
+}
+
+#[derive(Clone, Default)]
+struct BlockSummary {
+    gas: Word,
+    successors: Vec<usize>,
+}
+
+/// Sums the costs of the instructions of the block, and collects the blocks the
+/// execution may continue with.
+fn summarize(
+    cfg: &ControlFlowGraph,
+    block: usize,
+    gas_costs: &GasCosts,
+) -> Result<BlockSummary, UnboundedReason> {
+    let basic_block = &cfg.blocks()[block];
+    let mut values = [None; 64];
+    values[usize::from(RegId::ZERO)] = Some(0);
+    values[usize::from(RegId::ONE)] = Some(1);
+
+    let mut gas: Word = 0;
+    for (index, instruction) in basic_block.range.clone().zip(cfg.instructions(block)) {
+        let Ok(instruction) = instruction else { break };
+        if !instruction.opcode().is_predicate_allowed() {
+            // The predicate panics before charging gas, so the path ends here.
+            return Ok(BlockSummary {
+                gas,
+                successors: vec![],
+            })
+        }
+
+        let cost =
+            instruction_cost(instruction, gas_costs, |reg| values[usize::from(reg)])
+                .ok_or(UnboundedReason::DynamicLength { index })?;
+        gas = gas.saturating_add(cost);
+
+        let value = match instruction {
+            Instruction::MOVI(op) => Some(Word::from(op.imm18())),
+            _ => None,
+        };
+        for reg in instruction.written_registers().iter() {
+            values[usize::from(reg)] = value;
+        }
+    }
+
+    let last = basic_block.range.end.saturating_sub(1);
+    let is_last_block = block.saturating_add(1) == cfg.blocks().len();
+    let leaves_program = match basic_block.exit {
+        Exit::DynamicJump { .. } => {
+            return Err(UnboundedReason::DynamicJump { index: last })
+        }
+        Exit::End => true,
+        Exit::Jump {
+            target,
+            conditional,
+        } => {
+            target.and_then(|target| cfg.block_of(target)).is_none()
+                || (conditional && is_last_block)
+        }
+        Exit::Halt { conditional } => conditional && is_last_block,
+        Exit::FallThrough | Exit::Invalid => false,
+    };
+    if leaves_program {
+        return Err(UnboundedReason::LeavesProgram { index: last })
+    }
+
+    Ok(BlockSummary {
+        gas,
+        successors: basic_block.successors.clone(),
+    })
+}
+
+/// Gas charged by the predicate-allowed instruction, or `None` if it depends on a
+/// register with unknown value.
+fn instruction_cost(
+    instruction: &Instruction,
+    gas_costs: &GasCosts,
+    value: impl Fn(RegId) -> Option<Word>,
+) -> Option<Word> {
+    let cost = match instruction {
+        Instruction::ADD(_) => gas_costs.add(),
+        Instruction::ADDI(_) => gas_costs.addi(),
+        Instruction::AND(_) => gas_costs.and(),
+        Instruction::ANDI(_) => gas_costs.andi(),
+        Instruction::DIV(_) => gas_costs.div(),
+        Instruction::DIVI(_) => gas_costs.divi(),
+        Instruction::EQ(_) => gas_costs.eq_(),
+        Instruction::EXP(_) => gas_costs.exp(),
+        Instruction::EXPI(_) => gas_costs.expi(),
+        Instruction::GT(_) => gas_costs.gt(),
+        Instruction::LT(_) => gas_costs.lt(),
+        Instruction::WDCM(_) => gas_costs.wdcm(),
+        Instruction::WQCM(_) => gas_costs.wqcm(),
+        Instruction::WDOP(_) => gas_costs.wdop(),
+        Instruction::WQOP(_) => gas_costs.wqop(),
+        Instruction::WDML(_) => gas_costs.wdml(),
+        Instruction::WQML(_) => gas_costs.wqml(),
+        Instruction::WDDV(_) => gas_costs.wddv(),
+        Instruction::WQDV(_) => gas_costs.wqdv(),
+        Instruction::WDMD(_) => gas_costs.wdmd(),
+        Instruction::WQMD(_) => gas_costs.wqmd(),
+        Instruction::WDAM(_) => gas_costs.wdam(),
+        Instruction::WQAM(_) => gas_costs.wqam(),
+        Instruction::WDMM(_) => gas_costs.wdmm(),
+        Instruction::WQMM(_) => gas_costs.wqmm(),
+        Instruction::MLOG(_) => gas_costs.mlog(),
+        Instruction::MOD(_) => gas_costs.mod_op(),
+        Instruction::MODI(_) => gas_costs.modi(),
+        Instruction::MOVE(_) => gas_costs.move_op(),
+        Instruction::MOVI(_) => gas_costs.movi(),
+        Instruction::MROO(_) => gas_costs.mroo(),
+        Instruction::MUL(_) => gas_costs.mul(),
+        Instruction::MULI(_) => gas_costs.muli(),
+        Instruction::MLDV(_) => gas_costs.mldv(),
+        Instruction::NOOP(_) => gas_costs.noop(),
+        Instruction::NOT(_) => gas_costs.not(),
+        Instruction::OR(_) => gas_costs.or(),
+        Instruction::ORI(_) => gas_costs.ori(),
+        Instruction::SLL(_) => gas_costs.sll(),
+        Instruction::SLLI(_) => gas_costs.slli(),
+        Instruction::SRL(_) => gas_costs.srl(),
+        Instruction::SRLI(_) => gas_costs.srli(),
+        Instruction::SUB(_) => gas_costs.sub(),
+        Instruction::SUBI(_) => gas_costs.subi(),
+        Instruction::XOR(_) => gas_costs.xor(),
+        Instruction::XORI(_) => gas_costs.xori(),
+        Instruction::JI(_) => gas_costs.ji(),
+        Instruction::JNEI(_) => gas_costs.jnei(),
+        Instruction::JNZI(_) => gas_costs.jnzi(),
+        Instruction::JMP(_) => gas_costs.jmp(),
+        Instruction::JNE(_) => gas_costs.jne(),
+        Instruction::JMPF(_) => gas_costs.jmpf(),
+        Instruction::JMPB(_) => gas_costs.jmpb(),
+        Instruction::JNZF(_) => gas_costs.jnzf(),
+        Instruction::JNZB(_) => gas_costs.jnzb(),
+        Instruction::JNEF(_) => gas_costs.jnef(),
+        Instruction::JNEB(_) => gas_costs.jneb(),
+        Instruction::RET(_) => gas_costs.ret(),
+        Instruction::CFSI(_) | Instruction::CFS(_) => gas_costs.cfsi(),
+        Instruction::PSHL(_) => gas_costs.pshl(),
+        Instruction::PSHH(_) => gas_costs.pshh(),
+        Instruction::POPL(_) => gas_costs.popl(),
+        Instruction::POPH(_) => gas_costs.poph(),
+        Instruction::LB(_) => gas_costs.lb(),
+        Instruction::LW(_) => gas_costs.lw(),
+        Instruction::SB(_) => gas_costs.sb(),
+        Instruction::SW(_) => gas_costs.sw(),
+        Instruction::ECK1(_) => gas_costs.eck1(),
+        Instruction::ECR1(_) => gas_costs.ecr1(),
+        Instruction::FLAG(_) => gas_costs.flag(),
+        Instruction::GM(_) => gas_costs.gm(),
+        Instruction::GTF(_) => gas_costs.gtf(),
+        Instruction::ALOC(op) => gas_costs.aloc().resolve(value(op.ra())?),
+        Instruction::CFEI(op) => gas_costs.cfei().resolve(op.imm24().into()),
+        Instruction::CFE(op) => gas_costs.cfe().resolve(value(op.ra())?),
+        Instruction::MCL(op) => gas_costs.mcl().resolve(value(op.rb())?),
+        Instruction::MCLI(op) => gas_costs.mcli().resolve(op.imm18().into()),
+        Instruction::MCP(op) => gas_costs.mcp().resolve(value(op.rc())?),
+        Instruction::MCPI(op) => gas_costs.mcpi().resolve(op.imm12().into()),
+        Instruction::MEQ(op) => gas_costs.meq().resolve(value(op.rd())?),
+        Instruction::K256(op) => gas_costs.k256().resolve(value(op.rc())?),
+        Instruction::S256(op) => gas_costs.s256().resolve(value(op.rc())?),
+        Instruction::ED19(op) => {
+            // Zero length is interpreted as 32 bytes for backwards compatibility.
+            let len = match value(op.rd())? {
+                0 => 32,
+                len => len,
+            };
+            gas_costs.ed19().resolve(len)
+        }
+        // Not allowed in predicates, the execution panics without charging gas.
+        _ => 0,
+    };
+
+    Some(cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        checked_transaction::{
+            CheckPredicateParams,
+            EstimatePredicates,
+        },
+        interpreter::MemoryInstance,
+    };
+    use fuel_asm::op;
+    use fuel_tx::{
+        field::Inputs,
+        ConsensusParameters,
+        Finalizable,
+        Input,
+        TransactionBuilder,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn bytecode(program: &[Instruction]) -> Vec<u8> {
+        program.iter().copied().collect()
+    }
+
+    fn estimated_gas_used(predicate: Vec<u8>) -> Word {
+        let rng = &mut StdRng::seed_from_u64(2322);
+        let owner = Input::predicate_owner(&predicate);
+        let input = Input::coin_predicate(
+            rng.gen(),
+            owner,
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            0,
+            predicate,
+            vec![],
+        );
+        let mut tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(input)
+            .finalize();
+        let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+
+        tx.estimate_predicates(&params, MemoryInstance::new())
+            .expect("the predicate should succeed");
+
+        tx.inputs()[0]
+            .predicate_gas_used()
+            .expect("it is a predicate")
+    }
+
+    #[test]
+    fn straight_line_bound_matches_execution() {
+        let program = bytecode(&[
+            op::movi(0x10, 64),
+            op::aloc(0x10),
+            op::s256(RegId::HP, RegId::HP, 0x10),
+            op::mcli(RegId::HP, 32),
+            op::ret(RegId::ONE),
+        ]);
+        let gas_costs = GasCosts::default();
+
+        let bound = predicate_gas_upper_bound(&program, &gas_costs);
+
+        assert_eq!(
+            bound,
+            PredicateGasBound::Bounded(estimated_gas_used(program))
+        );
+    }
+
+    #[test]
+    fn bound_takes_the_most_expensive_branch() {
+        let program = bytecode(&[
+            op::gm(0x10, 1),
+            op::jnzf(0x10, RegId::ZERO, 1),
+            op::ret(RegId::ONE),
+            op::mcli(RegId::HP, 1000),
+            op::ret(RegId::ONE),
+        ]);
+        let gas_costs = GasCosts::default();
+
+        let bound = predicate_gas_upper_bound(&program, &gas_costs);
+
+        let expected = gas_costs
+            .gm()
+            .saturating_add(gas_costs.jnzf())
+            .saturating_add(gas_costs.mcli().resolve(1000))
+            .saturating_add(gas_costs.ret());
+        assert_eq!(bound, PredicateGasBound::Bounded(expected));
+    }
+
+    #[test]
+    fn loops_and_dynamic_code_are_unbounded() {
+        let gas_costs = GasCosts::default();
+        let unbounded = |program: &[Instruction]| {
+            predicate_gas_upper_bound(&bytecode(program), &gas_costs)
+        };
+
+        assert_eq!(
+            unbounded(&[
+                op::movi(0x10, 3),
+                op::subi(0x10, 0x10, 1),
+                op::jnzb(0x10, RegId::ZERO, 0),
+                op::ret(RegId::ONE),
+            ]),
+            PredicateGasBound::Unbounded(UnboundedReason::Loop { index: 1 })
+        );
+        assert_eq!(
+            unbounded(&[op::jmp(0x10), op::ret(RegId::ONE)]),
+            PredicateGasBound::Unbounded(UnboundedReason::DynamicJump { index: 0 })
+        );
+        assert_eq!(
+            unbounded(&[
+                op::gm(0x10, 1),
+                op::mcl(RegId::HP, 0x10),
+                op::ret(RegId::ONE)
+            ]),
+            PredicateGasBound::Unbounded(UnboundedReason::DynamicLength { index: 1 })
+        );
+        assert_eq!(
+            unbounded(&[op::noop()]),
+            PredicateGasBound::Unbounded(UnboundedReason::LeavesProgram { index: 0 })
+        );
+    }
+
+    #[test]
+    fn disallowed_instructions_end_the_path() {
+        let gas_costs = GasCosts::default();
+        let program = bytecode(&[op::noop(), op::log(0, 0, 0, 0), op::jmp(0x10)]);
+
+        let bound = predicate_gas_upper_bound(&program, &gas_costs);
+
+        assert_eq!(bound, PredicateGasBound::Bounded(gas_costs.noop()));
+    }
+}
@@ -0,0 +1,347 @@
+//! Python bindings of the interpreter, built with [PyO3](https://pyo3.rs).
+//!
+//! Lets researchers script experiments and gas measurements without writing Rust
+//! harnesses. The extension module is built with
+//! `maturin build --features python,pyo3/extension-module`, and exposes:
+//!
+//! - `MemoryStorage`, the in-memory [`MemoryStorage`].
+//! - `Transactor`, executing the canonically encoded transactions against a storage.
+//! - `Receipt`, the receipts of the executed script.
+//!
+//! The ids and the keys are passed as 32 bytes long `bytes`.
+
+use crate::{
+    checked_transaction::IntoChecked,
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    storage::{
+        ContractsAssetsStorage,
+        InterpreterStorage,
+        MemoryStorage,
+    },
+    transactor::Transactor,
+};
+
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Receipt,
+    Script,
+    Transaction,
+};
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+};
+
+fn value_error(error: impl core::fmt::Debug) -> PyErr {
+    PyValueError::new_err(format!("{error:?}"))
+}
+
+fn bytes32<T: From<[u8; 32]>>(bytes: &[u8]) -> PyResult<T> {
+    <[u8; 32]>::try_from(bytes)
+        .map(T::from)
+        .map_err(|_| PyValueError::new_err("expected 32 bytes"))
+}
+
+/// The in-memory storage of the interpreter.
+#[pyclass(name = "MemoryStorage")]
+#[derive(Debug, Clone, Default)]
+pub struct PyMemoryStorage(pub MemoryStorage);
+
+#[pymethods]
+impl PyMemoryStorage {
+    #[new]
+    #[pyo3(signature = (block_height = 1))]
+    fn new(block_height: u32) -> Self {
+        let coinbase = MemoryStorage::default().coinbase().expect("Infallible");
+        Self(MemoryStorage::new(block_height.into(), coinbase))
+    }
+
+    /// Sets the transacted state to the memory state.
+    fn commit(&mut self) {
+        self.0.commit()
+    }
+
+    /// Reverts the memory state to the transacted state.
+    fn revert(&mut self) {
+        self.0.revert()
+    }
+
+    /// Reverts the memory and the transacted state to the persisted state.
+    fn rollback(&mut self) {
+        self.0.rollback()
+    }
+
+    /// Persists the transacted state.
+    fn persist(&mut self) {
+        self.0.persist()
+    }
+
+    /// The code of the contract, or `None` if it isn't deployed.
+    fn contract_code(&self, contract_id: &[u8]) -> PyResult<Option<Cow<'_, [u8]>>> {
+        let contract_id = bytes32::<ContractId>(contract_id)?;
+        let code = self.0.storage_contract(&contract_id).expect("Infallible");
+        Ok(code.map(|code| Cow::Owned(code.into_owned().into())))
+    }
+
+    /// The value of the storage slot of the contract.
+    fn contract_state(&self, contract_id: &[u8], key: &[u8]) -> PyResult<Cow<'_, [u8]>> {
+        let contract_id = bytes32::<ContractId>(contract_id)?;
+        let state = self
+            .0
+            .contract_state(&contract_id, &bytes32::<Bytes32>(key)?);
+        Ok(Cow::Owned(state.as_ref().as_ref().to_vec()))
+    }
+
+    /// The balance of the asset of the contract.
+    fn contract_balance(&self, contract_id: &[u8], asset_id: &[u8]) -> PyResult<u64> {
+        let contract_id = bytes32::<ContractId>(contract_id)?;
+        let asset_id = bytes32::<AssetId>(asset_id)?;
+        let balance = self
+            .0
+            .contract_asset_id_balance(&contract_id, &asset_id)
+            .expect("Infallible");
+        Ok(balance.unwrap_or_default())
+    }
+}
+
+/// A receipt of the executed script.
+#[pyclass(name = "Receipt", frozen)]
+#[derive(Debug, Clone)]
+pub struct PyReceipt(pub Receipt);
+
+#[pymethods]
+impl PyReceipt {
+    /// The variant of the receipt, e.g. `"Call"` or `"ScriptResult"`.
+    #[getter]
+    fn kind(&self) -> String {
+        match serde_json::to_value(&self.0) {
+            Ok(serde_json::Value::Object(receipt)) => {
+                receipt.keys().next().cloned().unwrap_or_default()
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// The gas used by the script, if the receipt is a `ScriptResult`.
+    #[getter]
+    fn gas_used(&self) -> Option<u64> {
+        self.0.gas_used()
+    }
+
+    /// The data of the `LogData`, `ReturnData` and `MessageOut` receipts.
+    #[getter]
+    fn data(&self) -> Option<Cow<'_, [u8]>> {
+        self.0.data().map(Cow::Borrowed)
+    }
+
+    /// The JSON representation of the receipt.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.0).map_err(value_error)
+    }
+
+    /// The canonical encoding of the receipt.
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.0.to_bytes())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// Executes the transactions against a [`PyMemoryStorage`].
+#[pyclass(name = "Transactor", unsendable)]
+pub struct PyTransactor {
+    transactor: Transactor<MemoryInstance, MemoryStorage, Script>,
+    consensus_params: ConsensusParameters,
+    block_height: BlockHeight,
+}
+
+#[pymethods]
+impl PyTransactor {
+    /// Creates the transactor executing against a copy of the `storage`. The
+    /// `consensus_parameters` are JSON, e.g. as served by a node.
+    #[new]
+    #[pyo3(signature = (consensus_parameters, storage = None, gas_price = 0, block_height = 0))]
+    pub fn new(
+        consensus_parameters: &str,
+        storage: Option<PyMemoryStorage>,
+        gas_price: u64,
+        block_height: u32,
+    ) -> PyResult<Self> {
+        let consensus_params: ConsensusParameters =
+            serde_json::from_str(consensus_parameters).map_err(value_error)?;
+        let transactor = Transactor::new(
+            MemoryInstance::new(),
+            storage.unwrap_or_default().0,
+            InterpreterParams::new(gas_price, &consensus_params),
+        );
+        Ok(Self {
+            transactor,
+            consensus_params,
+            block_height: block_height.into(),
+        })
+    }
+
+    /// Checks and executes the canonically encoded `Script` or `Create`
+    /// transaction. Returns whether the execution succeeded; raises `ValueError`
+    /// if the transaction is invalid.
+    pub fn transact(&mut self, tx: &[u8]) -> PyResult<bool> {
+        let tx = Transaction::from_bytes(tx).map_err(value_error)?;
+        match tx {
+            Transaction::Script(tx) => {
+                let checked = tx
+                    .into_checked(self.block_height, &self.consensus_params)
+                    .map_err(value_error)?;
+                Ok(self.transactor.transact(checked).is_success())
+            }
+            Transaction::Create(tx) => {
+                let checked = tx
+                    .into_checked(self.block_height, &self.consensus_params)
+                    .map_err(value_error)?;
+                Ok(self.transactor.deploy(checked).is_ok())
+            }
+            _ => Err(PyValueError::new_err(
+                "only `Script` and `Create` transactions are supported",
+            )),
+        }
+    }
+
+    /// Whether the last executed transaction succeeded.
+    #[getter]
+    pub fn is_success(&self) -> bool {
+        self.transactor.is_success()
+    }
+
+    /// The error of the last executed script, if any.
+    #[getter]
+    pub fn error(&self) -> Option<String> {
+        self.transactor.error().map(ToString::to_string)
+    }
+
+    /// The gas used by the last executed script.
+    #[getter]
+    pub fn gas_used(&self) -> Option<u64> {
+        self.transactor
+            .receipts()?
+            .iter()
+            .rev()
+            .find_map(Receipt::gas_used)
+    }
+
+    /// The receipts of the last executed script.
+    pub fn receipts(&self) -> Vec<PyReceipt> {
+        self.transactor
+            .receipts()
+            .unwrap_or_default()
+            .iter()
+            .cloned()
+            .map(PyReceipt)
+            .collect()
+    }
+
+    /// A copy of the storage of the transactor.
+    pub fn storage(&self) -> PyMemoryStorage {
+        let storage: &MemoryStorage = self.transactor.as_ref();
+        PyMemoryStorage(storage.clone())
+    }
+}
+
+/// The `fuel_vm` Python module.
+// The unit tests link the crate twice, as it is its own dev-dependency, so the
+// module is only exported outside of the tests.
+#[cfg(not(test))]
+#[pymodule]
+fn fuel_vm(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyMemoryStorage>()?;
+    module.add_class::<PyReceipt>()?;
+    module.add_class::<PyTransactor>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_asm::{
+        op,
+        RegId,
+    };
+    use fuel_tx::TransactionBuilder;
+
+    fn transactor() -> PyTransactor {
+        let params = serde_json::to_string(&ConsensusParameters::standard()).unwrap();
+        PyTransactor::new(&params, None, 0, 0).unwrap()
+    }
+
+    fn script(program: Vec<fuel_asm::Instruction>) -> Vec<u8> {
+        TransactionBuilder::script(program.into_iter().collect(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_fee_input()
+            .finalize_as_transaction()
+            .to_bytes()
+    }
+
+    #[test]
+    fn transact__returns_receipts_of_script() {
+        let mut transactor = transactor();
+
+        let success = transactor
+            .transact(&script(vec![
+                op::log(RegId::ONE, 0, 0, 0),
+                op::ret(RegId::ONE),
+            ]))
+            .unwrap();
+
+        assert!(success);
+        let kinds: Vec<_> = transactor.receipts().iter().map(|r| r.kind()).collect();
+        assert_eq!(kinds, ["Log", "Return", "ScriptResult"]);
+        assert!(transactor.gas_used().unwrap() > 0);
+    }
+
+    #[test]
+    fn transact__reverted_script_is_not_success() {
+        let mut transactor = transactor();
+
+        let success = transactor
+            .transact(&script(vec![op::rvrt(RegId::ONE)]))
+            .unwrap();
+
+        assert!(!success);
+        assert!(!transactor.is_success());
+    }
+
+    #[test]
+    fn transact__invalid_encoding_is_error() {
+        let mut transactor = transactor();
+
+        assert!(transactor.transact(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn new__invalid_consensus_parameters_is_error() {
+        assert!(PyTransactor::new("{}", None, 0, 0).is_err());
+    }
+}
@@ -2,12 +2,18 @@
 
 use alloc::vec::Vec;
 
-use fuel_tx::Receipt;
+use fuel_crypto::Hasher;
+use fuel_tx::{
+    Receipt,
+    ScriptReturn,
+};
 use fuel_types::{
     Bytes32,
     Word,
 };
 
+use crate::interpreter::MemoryInstance;
+
 mod debug;
 
 mod debugger;
@@ -82,6 +88,10 @@ impl PartialEq<Breakpoint> for ProgramState {
     }
 }
 
+/// The digest, the pointer and the length of the data returned by `RETD`, and the
+/// data itself if the receipt holds it.
+type ReturnDataReceipt<'a> = (Bytes32, Word, Word, Option<&'a [u8]>);
+
 impl ProgramState {
     /// Debug event representation.
     ///
@@ -97,6 +107,107 @@ impl ProgramState {
     pub const fn is_debug(&self) -> bool {
         self.debug_ref().is_some()
     }
+
+    /// The word returned by the program with `RET`.
+    ///
+    /// Will return `None` if the program didn't halt with `RET`.
+    pub const fn return_value(&self) -> Option<Word> {
+        match self {
+            Self::Return(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The data returned by the program with `RETD`, taken from its `ReturnData`
+    /// receipt.
+    ///
+    /// The data is checked against the digest returned by the program, so it can't be
+    /// confused with the data returned by a contract.
+    pub fn return_data<'a>(
+        &self,
+        receipts: &'a [Receipt],
+    ) -> Result<&'a [u8], ReturnDataError> {
+        let (digest, _, _, data) = self.return_data_receipt(receipts)?;
+        let data = data.ok_or(ReturnDataError::DataStripped)?;
+
+        if Hasher::hash(data) != digest {
+            return Err(ReturnDataError::DigestMismatch)
+        }
+
+        Ok(data)
+    }
+
+    /// The data returned by the program with `RETD`, read from the memory of the VM
+    /// at the range of its `ReturnData` receipt.
+    ///
+    /// Unlike [`Self::return_data`], this works with receipts without the data, but
+    /// the memory must be the one of the execution: the range is checked against its
+    /// bounds and the data against the returned digest.
+    pub fn return_data_from_memory<'a>(
+        &self,
+        receipts: &[Receipt],
+        memory: &'a MemoryInstance,
+    ) -> Result<&'a [u8], ReturnDataError> {
+        let (digest, ptr, len, _) = self.return_data_receipt(receipts)?;
+        let data = memory
+            .read(ptr, len)
+            .map_err(|_| ReturnDataError::OutOfBounds)?;
+
+        if Hasher::hash(data) != digest {
+            return Err(ReturnDataError::DigestMismatch)
+        }
+
+        Ok(data)
+    }
+
+    fn return_data_receipt<'a>(
+        &self,
+        receipts: &'a [Receipt],
+    ) -> Result<ReturnDataReceipt<'a>, ReturnDataError> {
+        let Self::ReturnData(digest) = self else {
+            return Err(ReturnDataError::NotReturnData)
+        };
+
+        // The program halts after its `RETD`, so its receipt is the last one with the
+        // returned digest.
+        receipts
+            .iter()
+            .rev()
+            .find_map(|receipt| match receipt {
+                Receipt::ReturnData {
+                    ptr,
+                    len,
+                    digest: receipt_digest,
+                    data,
+                    ..
+                } if receipt_digest == digest => {
+                    Some((*digest, *ptr, *len, data.as_deref()))
+                }
+                _ => None,
+            })
+            .ok_or(ReturnDataError::MissingReceipt)
+    }
+}
+
+/// The data returned by a program can't be retrieved, see
+/// [`ProgramState::return_data`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum ReturnDataError {
+    /// The program didn't halt with `RETD`.
+    #[display(fmt = "the program didn't return data")]
+    NotReturnData,
+    /// The receipts don't contain the `ReturnData` receipt of the program.
+    #[display(fmt = "missing return data receipt")]
+    MissingReceipt,
+    /// The data was stripped from the `ReturnData` receipt.
+    #[display(fmt = "the return data was stripped from the receipt")]
+    DataStripped,
+    /// The range of the `ReturnData` receipt is out of the memory bounds.
+    #[display(fmt = "the return data is out of the memory bounds")]
+    OutOfBounds,
+    /// The data doesn't match the digest returned by the program.
+    #[display(fmt = "the return data doesn't match its digest")]
+    DigestMismatch,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -139,6 +250,16 @@ impl<Tx> StateTransition<Tx> {
         self.receipts.as_slice()
     }
 
+    /// Outcome of the script, extracted from the receipts.
+    pub fn script_return(&self) -> Option<ScriptReturn> {
+        ScriptReturn::from_receipts(&self.receipts)
+    }
+
+    /// The data returned by the program, see [`ProgramState::return_data`].
+    pub fn return_data(&self) -> Result<&[u8], ReturnDataError> {
+        self.state.return_data(&self.receipts)
+    }
+
     /// Convert this instance into its internal attributes.
     pub fn into_inner(self) -> (ProgramState, Tx, Vec<Receipt>) {
         (self.state, self.tx, self.receipts)
@@ -185,6 +306,16 @@ impl<'a, Tx> StateTransitionRef<'a, Tx> {
         self.receipts
     }
 
+    /// Outcome of the script, extracted from the receipts.
+    pub fn script_return(&self) -> Option<ScriptReturn> {
+        ScriptReturn::from_receipts(self.receipts)
+    }
+
+    /// The data returned by the program, see [`ProgramState::return_data`].
+    pub fn return_data(&self) -> Result<&'a [u8], ReturnDataError> {
+        self.state.return_data(self.receipts)
+    }
+
     /// Flag whether the client should revert after execution.
     pub fn should_revert(&self) -> bool {
         self.receipts
@@ -7,18 +7,31 @@ use fuel_types::{
     ContractId,
 };
 
+mod audit;
 mod blob_data;
+mod code_cache;
 mod contracts_assets;
 mod contracts_state;
 mod interpreter;
-#[cfg(feature = "test-helpers")]
+mod journal;
+#[cfg(feature = "memory-storage")]
 mod memory;
 pub(crate) mod predicate;
 
+pub use audit::{
+    AuditFinding,
+    ExpectedContractRoots,
+    StorageAudit,
+};
 pub use blob_data::{
     BlobBytes,
     BlobData,
 };
+pub use code_cache::{
+    CodeCacheMetrics,
+    CodeCachedStorage,
+    ContractCodeCache,
+};
 pub use contracts_assets::{
     ContractsAssetKey,
     ContractsAssets,
@@ -29,11 +42,25 @@ pub use contracts_state::{
     ContractsStateKey,
 };
 pub use interpreter::{
+    ContractStateIter,
+    ContractStateIterator,
     ContractsAssetsStorage,
     InterpreterStorage,
+    UpgradesStorage,
 };
-#[cfg(feature = "test-helpers")]
+pub use journal::{
+    JournaledStorage,
+    StorageMutation,
+    WriteAheadLog,
+};
+#[cfg(feature = "memory-storage")]
 pub use memory::MemoryStorage;
+#[cfg(all(feature = "memory-storage", feature = "serde"))]
+pub use memory::{
+    MemoryStorageSnapshot,
+    SnapshotError,
+    SNAPSHOT_VERSION,
+};
 pub use predicate::PredicateStorage;
 
 #[cfg(feature = "alloc")]
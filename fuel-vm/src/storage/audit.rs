@@ -0,0 +1,267 @@
+//! Offline consistency audit of the contract storage.
+
+use super::{
+    ContractsAssetKey,
+    ContractsRawCode,
+    ContractsStateKey,
+};
+use alloc::{
+    collections::BTreeMap,
+    vec::Vec,
+};
+use fuel_merkle::sparse::{
+    in_memory::MerkleTree,
+    MerkleTreeKey,
+};
+use fuel_storage::StorageInspect;
+use fuel_types::{
+    AssetId,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+/// The roots a contract is expected to have, e.g. the roots of its latest
+/// [`Output::Contract`](fuel_tx::Output::Contract). A root set to `None` isn't
+/// checked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExpectedContractRoots {
+    /// Root of the state of the contract.
+    pub state_root: Option<Bytes32>,
+    /// Root of the balances of the contract.
+    pub balance_root: Option<Bytes32>,
+}
+
+/// An inconsistency found by [`StorageAudit`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AuditFinding {
+    /// A state slot of a contract without code in the storage.
+    OrphanedSlot(ContractsStateKey),
+    /// A balance of a contract without code in the storage.
+    OrphanedBalance(ContractsAssetKey),
+    /// The state root recomputed from the slots differs from the expected one.
+    StateRootMismatch {
+        /// The audited contract.
+        contract_id: ContractId,
+        /// The expected root.
+        expected: Bytes32,
+        /// The root recomputed from the slots in the storage.
+        computed: Bytes32,
+    },
+    /// The balance root recomputed from the balances differs from the expected one.
+    BalanceRootMismatch {
+        /// The audited contract.
+        contract_id: ContractId,
+        /// The expected root.
+        expected: Bytes32,
+        /// The root recomputed from the balances in the storage.
+        computed: Bytes32,
+    },
+}
+
+/// Cross-checks the state slots and the balances of a storage backend against the
+/// contracts in the storage and their expected roots.
+///
+/// The entries are fed by iterating over the tables of the backend, as the storage
+/// traits don't support the iteration. The roots are recomputed as sparse Merkle
+/// trees: the state root from the slot keys and their data, the same way as
+/// [`Contract::initial_state_root`](fuel_tx::Contract::initial_state_root), and the
+/// balance root from the asset ids and the big-endian amounts.
+#[derive(Debug, Default, Clone)]
+pub struct StorageAudit {
+    slots: BTreeMap<ContractId, Vec<(Bytes32, Vec<u8>)>>,
+    balances: BTreeMap<ContractId, Vec<(AssetId, Word)>>,
+}
+
+impl StorageAudit {
+    /// Creates an audit without entries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a state slot of the storage.
+    pub fn add_slot(&mut self, key: &ContractsStateKey, data: &[u8]) -> &mut Self {
+        self.slots
+            .entry(*key.contract_id())
+            .or_default()
+            .push((*key.state_key(), data.to_vec()));
+        self
+    }
+
+    /// Adds a balance of the storage.
+    pub fn add_balance(&mut self, key: &ContractsAssetKey, amount: Word) -> &mut Self {
+        self.balances
+            .entry(*key.contract_id())
+            .or_default()
+            .push((*key.asset_id(), amount));
+        self
+    }
+
+    /// Reports the entries of the contracts without code in `storage`, and the
+    /// contracts whose recomputed roots differ from the `expected` ones.
+    pub fn finish<S>(
+        &self,
+        storage: &S,
+        expected: &BTreeMap<ContractId, ExpectedContractRoots>,
+    ) -> Result<Vec<AuditFinding>, S::Error>
+    where
+        S: StorageInspect<ContractsRawCode>,
+    {
+        let mut findings = Vec::new();
+
+        for (contract_id, slots) in &self.slots {
+            if !storage.contains_key(contract_id)? {
+                findings.extend(slots.iter().map(|(key, _)| {
+                    AuditFinding::OrphanedSlot(ContractsStateKey::new(contract_id, key))
+                }));
+            }
+        }
+        for (contract_id, balances) in &self.balances {
+            if !storage.contains_key(contract_id)? {
+                findings.extend(balances.iter().map(|(asset_id, _)| {
+                    AuditFinding::OrphanedBalance(ContractsAssetKey::new(
+                        contract_id,
+                        asset_id,
+                    ))
+                }));
+            }
+        }
+
+        for (contract_id, roots) in expected {
+            if let Some(expected) = roots.state_root {
+                let computed = self.state_root(contract_id);
+                if computed != expected {
+                    findings.push(AuditFinding::StateRootMismatch {
+                        contract_id: *contract_id,
+                        expected,
+                        computed,
+                    });
+                }
+            }
+            if let Some(expected) = roots.balance_root {
+                let computed = self.balance_root(contract_id);
+                if computed != expected {
+                    findings.push(AuditFinding::BalanceRootMismatch {
+                        contract_id: *contract_id,
+                        expected,
+                        computed,
+                    });
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+
+    /// The state root of the contract recomputed from the added slots.
+    pub fn state_root(&self, contract_id: &ContractId) -> Bytes32 {
+        let slots = self.slots.get(contract_id).into_iter().flatten();
+        MerkleTree::root_from_set(
+            slots.map(|(key, data)| (MerkleTreeKey::new(key), data)),
+        )
+        .into()
+    }
+
+    /// The balance root of the contract recomputed from the added balances.
+    pub fn balance_root(&self, contract_id: &ContractId) -> Bytes32 {
+        let balances = self.balances.get(contract_id).into_iter().flatten();
+        MerkleTree::root_from_set(balances.map(|(asset_id, amount)| {
+            (MerkleTreeKey::new(asset_id), amount.to_be_bytes())
+        }))
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{
+        InterpreterStorage,
+        MemoryStorage,
+    };
+    use alloc::vec;
+    use fuel_tx::{
+        Contract,
+        StorageSlot,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn audit_of(storage: &MemoryStorage) -> StorageAudit {
+        let mut audit = StorageAudit::new();
+        for (key, data) in storage.all_contract_state() {
+            audit.add_slot(key, data.as_ref());
+        }
+        audit
+    }
+
+    #[test]
+    fn consistent_storage_has_no_findings() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let contract_id: ContractId = rng.gen();
+        let slots = vec![StorageSlot::new(rng.gen(), rng.gen())];
+        let mut storage = MemoryStorage::default();
+        storage
+            .deploy_contract_with_id(&slots, &Contract::default(), &contract_id)
+            .unwrap();
+
+        let expected = BTreeMap::from([(
+            contract_id,
+            ExpectedContractRoots {
+                state_root: Some(Contract::initial_state_root(slots.iter())),
+                balance_root: Some(StorageAudit::new().balance_root(&contract_id)),
+            },
+        )]);
+
+        assert_eq!(audit_of(&storage).finish(&storage, &expected), Ok(vec![]));
+    }
+
+    #[test]
+    fn orphaned_and_mismatched_entries_are_reported() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let contract_id: ContractId = rng.gen();
+        let slots = vec![StorageSlot::new(rng.gen(), rng.gen())];
+        let mut storage = MemoryStorage::default();
+        storage
+            .deploy_contract_with_id(&slots, &Contract::default(), &contract_id)
+            .unwrap();
+        storage
+            .contract_state_insert(&contract_id, slots[0].key(), &[1; 32])
+            .unwrap();
+
+        let orphan_id: ContractId = rng.gen();
+        let orphan_slot = ContractsStateKey::new(&orphan_id, &rng.gen());
+        let orphan_balance = ContractsAssetKey::new(&orphan_id, &rng.gen());
+        let mut audit = audit_of(&storage);
+        audit
+            .add_slot(&orphan_slot, &[2; 32])
+            .add_balance(&orphan_balance, 10);
+
+        let expected_root = Contract::initial_state_root(slots.iter());
+        let expected = BTreeMap::from([(
+            contract_id,
+            ExpectedContractRoots {
+                state_root: Some(expected_root),
+                balance_root: None,
+            },
+        )]);
+
+        assert_eq!(
+            audit.finish(&storage, &expected),
+            Ok(vec![
+                AuditFinding::OrphanedSlot(orphan_slot),
+                AuditFinding::OrphanedBalance(orphan_balance),
+                AuditFinding::StateRootMismatch {
+                    contract_id,
+                    expected: expected_root,
+                    computed: audit.state_root(&contract_id),
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,760 @@
+//! Contract bytecode cache shared across transactions.
+//!
+//! Executing a block calls the same hot contracts over and over, and every call
+//! reads the full code of the contract from the storage. [`CodeCachedStorage`]
+//! wraps an [`InterpreterStorage`] and serves the [`ContractsRawCode`] reads from
+//! a [`ContractCodeCache`], so the code is read from the underlying storage once.
+//! The cache is shared across the transactions by keeping the wrapper around and
+//! executing each of them against a `&mut` reference to it.
+//!
+//! The cache is keyed by the contract id and the root of the code: the contracts
+//! deployed with the same code share a single cached copy of it. The code is
+//! evicted in least recently used order once the cached code exceeds the capacity
+//! in bytes.
+
+use crate::storage::{
+    BlobData,
+    ContractStateIter,
+    ContractStateIterator,
+    ContractsAssets,
+    ContractsAssetsStorage,
+    ContractsRawCode,
+    ContractsState,
+    ContractsStateData,
+    InterpreterStorage,
+    UpgradesStorage,
+    UploadedBytecodes,
+};
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    vec::Vec,
+};
+use core::cell::RefCell;
+use fuel_storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+};
+use fuel_types::{
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+use hashbrown::HashMap;
+
+/// Hit and miss counters of a [`ContractCodeCache`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CodeCacheMetrics {
+    /// Lookups served from the cache.
+    pub hits: u64,
+    /// Lookups of the code missing in the cache.
+    pub misses: u64,
+    /// Code evicted to stay within the capacity.
+    pub evictions: u64,
+}
+
+impl CodeCacheMetrics {
+    /// The ratio of the lookups served from the cache, or `0` if there were none.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits.saturating_add(self.misses);
+        if lookups == 0 {
+            return 0.0
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let rate = self.hits as f64 / lookups as f64;
+        rate
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedCode {
+    code: Contract,
+    last_used: u64,
+    contracts: Vec<ContractId>,
+}
+
+/// Least recently used cache of the contract code, bounded by the total size of
+/// the cached code in bytes.
+#[derive(Debug, Clone)]
+pub struct ContractCodeCache {
+    capacity: usize,
+    size: usize,
+    tick: u64,
+    contracts: HashMap<ContractId, Bytes32>,
+    codes: HashMap<Bytes32, CachedCode>,
+    recency: BTreeMap<u64, Bytes32>,
+    metrics: CodeCacheMetrics,
+}
+
+impl ContractCodeCache {
+    /// Creates the cache holding up to `capacity` bytes of code.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            size: 0,
+            tick: 0,
+            contracts: HashMap::new(),
+            codes: HashMap::new(),
+            recency: BTreeMap::new(),
+            metrics: CodeCacheMetrics::default(),
+        }
+    }
+
+    /// The maximum size of the cached code in bytes.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The size of the cached code in bytes.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The number of the cached contracts.
+    pub fn len(&self) -> usize {
+        self.contracts.len()
+    }
+
+    /// Returns `true` if no code is cached.
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty()
+    }
+
+    /// The hit and miss counters of the cache.
+    pub const fn metrics(&self) -> CodeCacheMetrics {
+        self.metrics
+    }
+
+    /// Resets the hit and miss counters.
+    pub fn reset_metrics(&mut self) {
+        self.metrics = CodeCacheMetrics::default();
+    }
+
+    /// Returns the code of the contract, marking it as recently used.
+    pub fn get(&mut self, contract: &ContractId) -> Option<&Contract> {
+        let Some(root) = self.contracts.get(contract) else {
+            self.metrics.misses = self.metrics.misses.saturating_add(1);
+            crate::metrics::code_cache_lookup(false);
+            return None
+        };
+        self.metrics.hits = self.metrics.hits.saturating_add(1);
+        crate::metrics::code_cache_lookup(true);
+
+        self.tick = self.tick.wrapping_add(1);
+        let entry = self
+            .codes
+            .get_mut(root)
+            .expect("Every contract has its code");
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(self.tick, *root);
+        entry.last_used = self.tick;
+        Some(&entry.code)
+    }
+
+    /// Returns the code of the contract without affecting the order of the
+    /// eviction or the metrics.
+    pub fn peek(&self, contract: &ContractId) -> Option<&Contract> {
+        let root = self.contracts.get(contract)?;
+        self.codes.get(root).map(|entry| &entry.code)
+    }
+
+    /// Caches the code of the contract, evicting the least recently used code if
+    /// the capacity is exceeded. The code larger than the capacity isn't cached.
+    pub fn insert(&mut self, contract: ContractId, code: Contract) {
+        self.invalidate(&contract);
+        let len = code.as_ref().len();
+        if len > self.capacity {
+            return
+        }
+
+        let root = code.root();
+        self.tick = self.tick.wrapping_add(1);
+        match self.codes.get_mut(&root) {
+            Some(entry) => {
+                self.recency.remove(&entry.last_used);
+                entry.last_used = self.tick;
+                entry.contracts.push(contract);
+            }
+            None => {
+                self.size = self.size.saturating_add(len);
+                self.codes.insert(
+                    root,
+                    CachedCode {
+                        code,
+                        last_used: self.tick,
+                        contracts: alloc::vec![contract],
+                    },
+                );
+            }
+        }
+        self.recency.insert(self.tick, root);
+        self.contracts.insert(contract, root);
+
+        while self.size > self.capacity {
+            let Some((_, root)) = self.recency.pop_first() else {
+                break
+            };
+            self.remove_code(&root);
+            self.metrics.evictions = self.metrics.evictions.saturating_add(1);
+        }
+    }
+
+    /// Removes the code of the contract from the cache.
+    pub fn invalidate(&mut self, contract: &ContractId) {
+        let Some(root) = self.contracts.remove(contract) else {
+            return
+        };
+        let entry = self
+            .codes
+            .get_mut(&root)
+            .expect("Every contract has its code");
+        entry.contracts.retain(|id| id != contract);
+        if entry.contracts.is_empty() {
+            let last_used = entry.last_used;
+            self.recency.remove(&last_used);
+            self.remove_code(&root);
+        }
+    }
+
+    /// Removes all the cached code, keeping the metrics.
+    pub fn clear(&mut self) {
+        self.size = 0;
+        self.contracts.clear();
+        self.codes.clear();
+        self.recency.clear();
+    }
+
+    fn remove_code(&mut self, root: &Bytes32) {
+        if let Some(entry) = self.codes.remove(root) {
+            self.size = self.size.saturating_sub(entry.code.as_ref().len());
+            for contract in &entry.contracts {
+                self.contracts.remove(contract);
+            }
+        }
+    }
+}
+
+/// The storage serving the contract code from a [`ContractCodeCache`]. Writes of
+/// the contract code invalidate the cached code of the contract.
+#[derive(Debug)]
+pub struct CodeCachedStorage<S> {
+    storage: S,
+    cache: RefCell<ContractCodeCache>,
+}
+
+impl<S> CodeCachedStorage<S> {
+    /// Wraps the `storage` with a cache holding up to `capacity` bytes of code.
+    pub fn new(storage: S, capacity: usize) -> Self {
+        Self::with_cache(storage, ContractCodeCache::new(capacity))
+    }
+
+    /// Wraps the `storage` with the `cache`, e.g. the one warmed up by a previous
+    /// block.
+    pub fn with_cache(storage: S, cache: ContractCodeCache) -> Self {
+        Self {
+            storage,
+            cache: RefCell::new(cache),
+        }
+    }
+
+    /// The hit and miss counters of the cache.
+    pub fn metrics(&self) -> CodeCacheMetrics {
+        self.cache.borrow().metrics()
+    }
+
+    /// The cache of the contract code.
+    pub fn cache(&self) -> core::cell::Ref<'_, ContractCodeCache> {
+        self.cache.borrow()
+    }
+
+    /// The mutable cache of the contract code.
+    pub fn cache_mut(&mut self) -> &mut ContractCodeCache {
+        self.cache.get_mut()
+    }
+
+    /// The wrapped storage.
+    pub const fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// The wrapped mutable storage. The contract code written directly to it
+    /// isn't invalidated in the cache.
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Unwraps the storage and the cache.
+    pub fn into_inner(self) -> (S, ContractCodeCache) {
+        (self.storage, self.cache.into_inner())
+    }
+}
+
+impl<S> CodeCachedStorage<S>
+where
+    S: StorageInspect<ContractsRawCode>,
+{
+    fn cached_code<R>(
+        &self,
+        contract: &ContractId,
+        f: impl FnOnce(&Contract) -> R,
+    ) -> Result<Option<R>, S::Error> {
+        let mut cache = self.cache.borrow_mut();
+        if let Some(code) = cache.get(contract) {
+            return Ok(Some(f(code)))
+        }
+
+        let Some(code) = self.storage.get(contract)? else {
+            return Ok(None)
+        };
+        let code = code.into_owned();
+        let result = f(&code);
+        cache.insert(*contract, code);
+        Ok(Some(result))
+    }
+}
+
+impl<S> StorageInspect<ContractsRawCode> for CodeCachedStorage<S>
+where
+    S: StorageInspect<ContractsRawCode>,
+{
+    type Error = S::Error;
+
+    fn get(&self, key: &ContractId) -> Result<Option<Cow<'_, Contract>>, Self::Error> {
+        let code = self.cached_code(key, Contract::clone)?;
+        Ok(code.map(Cow::Owned))
+    }
+
+    fn contains_key(&self, key: &ContractId) -> Result<bool, Self::Error> {
+        if self.cache.borrow().peek(key).is_some() {
+            return Ok(true)
+        }
+        self.storage.contains_key(key)
+    }
+}
+
+impl<S> StorageSize<ContractsRawCode> for CodeCachedStorage<S>
+where
+    S: StorageSize<ContractsRawCode>,
+{
+    fn size_of_value(&self, key: &ContractId) -> Result<Option<usize>, Self::Error> {
+        if let Some(code) = self.cache.borrow().peek(key) {
+            return Ok(Some(code.as_ref().len()))
+        }
+        self.storage.size_of_value(key)
+    }
+}
+
+impl<S> StorageRead<ContractsRawCode> for CodeCachedStorage<S>
+where
+    S: StorageRead<ContractsRawCode>,
+{
+    fn read(
+        &self,
+        key: &ContractId,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, Self::Error> {
+        self.cached_code(key, |code| {
+            let len = buf.len().min(code.as_ref().len());
+            buf[..len].copy_from_slice(&code.as_ref()[..len]);
+            len
+        })
+    }
+
+    fn read_alloc(&self, key: &ContractId) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.cached_code(key, |code| code.as_ref().to_vec())
+    }
+}
+
+impl<S> StorageMutate<ContractsRawCode> for CodeCachedStorage<S>
+where
+    S: StorageMutate<ContractsRawCode>,
+{
+    fn replace(
+        &mut self,
+        key: &ContractId,
+        value: &[u8],
+    ) -> Result<Option<Contract>, Self::Error> {
+        self.cache.get_mut().invalidate(key);
+        self.storage.replace(key, value)
+    }
+
+    fn take(&mut self, key: &ContractId) -> Result<Option<Contract>, Self::Error> {
+        self.cache.get_mut().invalidate(key);
+        self.storage.take(key)
+    }
+}
+
+impl<S> StorageWrite<ContractsRawCode> for CodeCachedStorage<S>
+where
+    S: StorageWrite<ContractsRawCode>,
+{
+    fn write_bytes(
+        &mut self,
+        key: &ContractId,
+        buf: &[u8],
+    ) -> Result<usize, Self::Error> {
+        self.cache.get_mut().invalidate(key);
+        self.storage.write_bytes(key, buf)
+    }
+
+    fn replace_bytes(
+        &mut self,
+        key: &ContractId,
+        buf: &[u8],
+    ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+        self.cache.get_mut().invalidate(key);
+        self.storage.replace_bytes(key, buf)
+    }
+
+    fn take_bytes(&mut self, key: &ContractId) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.cache.get_mut().invalidate(key);
+        self.storage.take_bytes(key)
+    }
+}
+
+/// Delegates the storage traits of the table to the wrapped storage.
+macro_rules! delegate_table {
+    ($table:ty) => {
+        impl<S> StorageInspect<$table> for CodeCachedStorage<S>
+        where
+            S: StorageInspect<$table>,
+        {
+            type Error = S::Error;
+
+            fn get(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Cow<'_, <$table as Mappable>::OwnedValue>>, Self::Error>
+            {
+                self.storage.get(key)
+            }
+
+            fn contains_key(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<bool, Self::Error> {
+                self.storage.contains_key(key)
+            }
+        }
+
+        impl<S> StorageMutate<$table> for CodeCachedStorage<S>
+        where
+            S: StorageMutate<$table>,
+        {
+            fn replace(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                value: &<$table as Mappable>::Value,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                self.storage.replace(key, value)
+            }
+
+            fn take(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                self.storage.take(key)
+            }
+        }
+    };
+    ($table:ty,bytes) => {
+        delegate_table!($table);
+
+        impl<S> StorageSize<$table> for CodeCachedStorage<S>
+        where
+            S: StorageSize<$table>,
+        {
+            fn size_of_value(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<usize>, Self::Error> {
+                self.storage.size_of_value(key)
+            }
+        }
+
+        impl<S> StorageRead<$table> for CodeCachedStorage<S>
+        where
+            S: StorageRead<$table>,
+        {
+            fn read(
+                &self,
+                key: &<$table as Mappable>::Key,
+                buf: &mut [u8],
+            ) -> Result<Option<usize>, Self::Error> {
+                self.storage.read(key, buf)
+            }
+
+            fn read_alloc(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                self.storage.read_alloc(key)
+            }
+        }
+
+        impl<S> StorageWrite<$table> for CodeCachedStorage<S>
+        where
+            S: StorageWrite<$table>,
+        {
+            fn write_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<usize, Self::Error> {
+                self.storage.write_bytes(key, buf)
+            }
+
+            fn replace_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+                self.storage.replace_bytes(key, buf)
+            }
+
+            fn take_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                self.storage.take_bytes(key)
+            }
+        }
+    };
+}
+
+delegate_table!(ContractsState, bytes);
+delegate_table!(BlobData, bytes);
+delegate_table!(ContractsAssets);
+delegate_table!(UploadedBytecodes);
+
+impl<S> ContractsAssetsStorage for CodeCachedStorage<S> where S: ContractsAssetsStorage {}
+
+impl<S> InterpreterStorage for CodeCachedStorage<S>
+where
+    S: InterpreterStorage,
+{
+    type DataError = S::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        self.storage.block_height()
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.consensus_parameters_version()
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.state_transition_version()
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        self.storage.timestamp(height)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        self.storage.coinbase()
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        self.storage
+            .set_consensus_parameters(version, consensus_parameters)
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.storage.set_state_transition_bytecode(version, hash)
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<'_, ContractsStateData>>>, Self::DataError> {
+        self.storage.contract_state_range(id, start_key, range)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        self.storage
+            .contract_state_insert_range(contract, start_key, values)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        self.storage
+            .contract_state_remove_range(contract, start_key, range)
+    }
+}
+
+impl<S> ContractStateIterator for CodeCachedStorage<S>
+where
+    S: ContractStateIterator,
+{
+    fn contract_state_iter(
+        &self,
+        id: &ContractId,
+    ) -> ContractStateIter<'_, Self::DataError> {
+        self.storage.contract_state_iter(id)
+    }
+}
+
+impl<S> UpgradesStorage for CodeCachedStorage<S>
+where
+    S: UpgradesStorage,
+{
+    fn consensus_parameters(
+        &self,
+        version: u32,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        self.storage.consensus_parameters(version)
+    }
+
+    fn state_transition_bytecode(
+        &self,
+        version: u32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.storage.state_transition_bytecode(version)
+    }
+}
+
+#[cfg(all(test, feature = "test-helpers"))]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+    use fuel_storage::StorageAsMut;
+
+    fn contract(id: u8) -> ContractId {
+        ContractId::from([id; 32])
+    }
+
+    fn code(byte: u8, len: usize) -> Contract {
+        alloc::vec![byte; len].into()
+    }
+
+    #[test]
+    fn get__counts_hits_and_misses() {
+        let mut cache = ContractCodeCache::new(100);
+        cache.insert(contract(1), code(1, 10));
+
+        assert_eq!(cache.get(&contract(1)), Some(&code(1, 10)));
+        assert_eq!(cache.get(&contract(2)), None);
+
+        let metrics = cache.metrics();
+        assert_eq!((metrics.hits, metrics.misses), (1, 1));
+        assert_eq!(metrics.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn insert__evicts_least_recently_used_code() {
+        let mut cache = ContractCodeCache::new(30);
+        cache.insert(contract(1), code(1, 10));
+        cache.insert(contract(2), code(2, 10));
+        cache.insert(contract(3), code(3, 10));
+        cache.get(&contract(1));
+
+        cache.insert(contract(4), code(4, 10));
+
+        assert!(cache.peek(&contract(1)).is_some());
+        assert!(cache.peek(&contract(2)).is_none());
+        assert!(cache.peek(&contract(3)).is_some());
+        assert_eq!(cache.size(), 30);
+        assert_eq!(cache.metrics().evictions, 1);
+    }
+
+    #[test]
+    fn insert__shares_code_with_same_root() {
+        let mut cache = ContractCodeCache::new(15);
+        cache.insert(contract(1), code(1, 10));
+        cache.insert(contract(2), code(1, 10));
+
+        assert_eq!(cache.size(), 10);
+        assert_eq!(cache.len(), 2);
+
+        cache.invalidate(&contract(1));
+        assert_eq!(cache.peek(&contract(2)), Some(&code(1, 10)));
+        cache.invalidate(&contract(2));
+        assert!(cache.is_empty());
+        assert_eq!(cache.size(), 0);
+    }
+
+    #[test]
+    fn insert__code_larger_than_capacity_is_not_cached() {
+        let mut cache = ContractCodeCache::new(5);
+        cache.insert(contract(1), code(1, 10));
+
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn read__is_served_from_cache_after_first_read() {
+        let mut storage = MemoryStorage::default();
+        storage
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract(1), &[1; 10])
+            .unwrap();
+        let storage = CodeCachedStorage::new(storage, 100);
+
+        let mut buf = [0; 10];
+        for _ in 0..3 {
+            StorageRead::<ContractsRawCode>::read(&storage, &contract(1), &mut buf)
+                .unwrap();
+        }
+
+        assert_eq!(buf, [1; 10]);
+        let metrics = storage.metrics();
+        assert_eq!((metrics.hits, metrics.misses), (2, 1));
+    }
+
+    #[test]
+    fn write__invalidates_cached_code() {
+        let mut storage = CodeCachedStorage::new(MemoryStorage::default(), 100);
+        storage
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract(1), &[1; 10])
+            .unwrap();
+        StorageRead::<ContractsRawCode>::read_alloc(&storage, &contract(1)).unwrap();
+
+        storage
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract(1), &[2; 10])
+            .unwrap();
+
+        let code =
+            StorageRead::<ContractsRawCode>::read_alloc(&storage, &contract(1)).unwrap();
+        assert_eq!(code, Some(alloc::vec![2; 10]));
+    }
+}
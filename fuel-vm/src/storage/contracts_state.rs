@@ -109,3 +109,27 @@ impl IntoIterator for ContractsStateData {
         self.0.into_iter()
     }
 }
+
+/// The state keys in ascending order, starting with the `start_key` and ending with
+/// the last key.
+pub(crate) fn successive_keys(start_key: &Bytes32) -> impl Iterator<Item = Bytes32> {
+    core::iter::successors(Some(**start_key), |key| {
+        let mut key = *key;
+        (!add_one(&mut key)).then_some(key)
+    })
+    .map(Bytes32::from)
+}
+
+/// Increments the big-endian key, returning `true` on the overflow.
+pub(crate) fn add_one(a: &mut [u8; 32]) -> bool {
+    let right = u128::from_be_bytes(a[16..].try_into().unwrap());
+    let (right, of) = right.overflowing_add(1);
+    a[16..].copy_from_slice(&right.to_be_bytes()[..]);
+    if of {
+        let left = u128::from_be_bytes(a[..16].try_into().unwrap());
+        let (left, of) = left.overflowing_add(1);
+        a[..16].copy_from_slice(&left.to_be_bytes()[..]);
+        return of
+    }
+    false
+}
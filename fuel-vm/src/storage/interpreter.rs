@@ -37,6 +37,7 @@ use crate::{
 };
 use alloc::{
     borrow::Cow,
+    boxed::Box,
     vec::Vec,
 };
 use core::ops::{
@@ -46,6 +47,11 @@ use core::ops::{
 
 use super::blob_data::BlobData;
 
+/// The iterator over the state slots of a contract, see
+/// [`ContractStateIterator::contract_state_iter`].
+pub type ContractStateIter<'a, E> =
+    Box<dyn Iterator<Item = Result<(Bytes32, Cow<'a, ContractsStateData>), E>> + 'a>;
+
 /// When this trait is implemented, the underlying interpreter is guaranteed to
 /// have full functionality
 pub trait InterpreterStorage:
@@ -304,8 +310,66 @@ pub trait ContractsAssetsStorage: StorageMutate<ContractsAssets> {
     }
 }
 
+/// Iteration over the state slots of a contract, for the storages able to enumerate
+/// them.
+pub trait ContractStateIterator: InterpreterStorage {
+    /// Iterate over all the set slots of a contract storage, in ascending order of
+    /// the keys.
+    fn contract_state_iter(
+        &self,
+        id: &ContractId,
+    ) -> ContractStateIter<'_, Self::DataError>;
+}
+
+/// Storage reads of the consensus parameters and the state transition bytecode set
+/// by the upgrades.
+pub trait UpgradesStorage: InterpreterStorage {
+    /// Fetch the consensus parameters set under the `version`.
+    fn consensus_parameters(
+        &self,
+        version: u32,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError>;
+
+    /// Fetch the root of the state transition bytecode set under the `version`.
+    fn state_transition_bytecode(
+        &self,
+        version: u32,
+    ) -> Result<Option<Bytes32>, Self::DataError>;
+}
+
 impl<S> ContractsAssetsStorage for &mut S where S: ContractsAssetsStorage {}
 
+impl<S> ContractStateIterator for &mut S
+where
+    S: ContractStateIterator,
+{
+    fn contract_state_iter(
+        &self,
+        id: &ContractId,
+    ) -> ContractStateIter<'_, Self::DataError> {
+        <S as ContractStateIterator>::contract_state_iter(self.deref(), id)
+    }
+}
+
+impl<S> UpgradesStorage for &mut S
+where
+    S: UpgradesStorage,
+{
+    fn consensus_parameters(
+        &self,
+        version: u32,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        <S as UpgradesStorage>::consensus_parameters(self.deref(), version)
+    }
+
+    fn state_transition_bytecode(
+        &self,
+        version: u32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        <S as UpgradesStorage>::state_transition_bytecode(self.deref(), version)
+    }
+}
+
 impl<S> InterpreterStorage for &mut S
 where
     S: InterpreterStorage,
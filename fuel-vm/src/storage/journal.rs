@@ -0,0 +1,888 @@
+//! Journaling of the storage mutations.
+//!
+//! [`JournaledStorage`] wraps an [`InterpreterStorage`] and keeps every mutation
+//! in memory instead of writing it through: the reads are served from the pending
+//! mutations first and from the wrapped storage otherwise. The mutations are
+//! recorded in order in a [`WriteAheadLog`], which is either committed to the
+//! wrapped storage at once, dropped with [`JournaledStorage::rollback`], or taken
+//! out and replayed onto another backend, e.g. after it was persisted for the
+//! crash recovery.
+//!
+//! The consensus parameters and the state transition bytecode set before are read
+//! through [`UpgradesStorage`] of the wrapped storage, so the upgrades overriding
+//! them fail the same way as without the journal.
+
+use crate::storage::{
+    contracts_state::successive_keys,
+    BlobBytes,
+    BlobData,
+    ContractStateIter,
+    ContractStateIterator,
+    ContractsAssetKey,
+    ContractsAssets,
+    ContractsAssetsStorage,
+    ContractsRawCode,
+    ContractsState,
+    ContractsStateData,
+    ContractsStateKey,
+    InterpreterStorage,
+    UpgradesStorage,
+    UploadedBytecode,
+    UploadedBytecodes,
+};
+use alloc::{
+    borrow::{
+        Cow,
+        ToOwned,
+    },
+    boxed::Box,
+    collections::BTreeMap,
+    vec::Vec,
+};
+use fuel_storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+};
+use fuel_types::{
+    BlobId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+/// A mutation of the storage, see [`WriteAheadLog`]. The value `None` stands for
+/// the removal of the entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageMutation {
+    /// The code of the contract was written.
+    ContractCode {
+        /// The id of the contract.
+        contract: ContractId,
+        /// The code of the contract.
+        code: Option<Contract>,
+    },
+    /// The state slot of the contract was written.
+    ContractState {
+        /// The contract and the key of the slot.
+        key: ContractsStateKey,
+        /// The value of the slot.
+        value: Option<ContractsStateData>,
+    },
+    /// The balance of the contract was written.
+    ContractAsset {
+        /// The contract and the asset.
+        key: ContractsAssetKey,
+        /// The balance of the asset.
+        balance: Option<Word>,
+    },
+    /// The uploaded bytecode was written.
+    UploadedBytecode {
+        /// The Merkle root of the bytecode.
+        root: Bytes32,
+        /// The uploaded bytecode.
+        bytecode: Option<UploadedBytecode>,
+    },
+    /// The blob was written.
+    Blob {
+        /// The id of the blob.
+        id: BlobId,
+        /// The data of the blob.
+        data: Option<BlobBytes>,
+    },
+    /// The consensus parameters were set under the version.
+    ConsensusParameters {
+        /// The version of the consensus parameters.
+        version: u32,
+        /// The consensus parameters.
+        consensus_parameters: Box<ConsensusParameters>,
+    },
+    /// The state transition bytecode was set under the version.
+    StateTransitionBytecode {
+        /// The version of the state transition.
+        version: u32,
+        /// The Merkle root of the bytecode.
+        root: Bytes32,
+    },
+}
+
+impl StorageMutation {
+    /// Applies the mutation to the `storage`.
+    pub fn apply<S>(&self, storage: &mut S) -> Result<(), S::DataError>
+    where
+        S: InterpreterStorage,
+    {
+        match self {
+            Self::ContractCode { contract, code } => match code {
+                Some(code) => StorageMutate::<ContractsRawCode>::insert(
+                    storage,
+                    contract,
+                    code.as_ref(),
+                ),
+                None => StorageMutate::<ContractsRawCode>::remove(storage, contract),
+            },
+            Self::ContractState { key, value } => match value {
+                Some(value) => {
+                    StorageMutate::<ContractsState>::insert(storage, key, value.as_ref())
+                }
+                None => StorageMutate::<ContractsState>::remove(storage, key),
+            },
+            Self::ContractAsset { key, balance } => match balance {
+                Some(balance) => {
+                    StorageMutate::<ContractsAssets>::insert(storage, key, balance)
+                }
+                None => StorageMutate::<ContractsAssets>::remove(storage, key),
+            },
+            Self::UploadedBytecode { root, bytecode } => match bytecode {
+                Some(bytecode) => {
+                    StorageMutate::<UploadedBytecodes>::insert(storage, root, bytecode)
+                }
+                None => StorageMutate::<UploadedBytecodes>::remove(storage, root),
+            },
+            Self::Blob { id, data } => match data {
+                Some(data) => {
+                    StorageMutate::<BlobData>::insert(storage, id, data.as_ref())
+                }
+                None => StorageMutate::<BlobData>::remove(storage, id),
+            },
+            Self::ConsensusParameters {
+                version,
+                consensus_parameters,
+            } => {
+                storage.set_consensus_parameters(*version, consensus_parameters)?;
+                Ok(())
+            }
+            Self::StateTransitionBytecode { version, root } => {
+                storage.set_state_transition_bytecode(*version, root)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The ordered log of the storage mutations recorded by a [`JournaledStorage`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WriteAheadLog {
+    mutations: Vec<StorageMutation>,
+}
+
+impl WriteAheadLog {
+    /// Creates an empty log.
+    pub const fn new() -> Self {
+        Self {
+            mutations: Vec::new(),
+        }
+    }
+
+    /// The recorded mutations, in the order they were made.
+    pub fn mutations(&self) -> &[StorageMutation] {
+        &self.mutations
+    }
+
+    /// The number of the recorded mutations.
+    pub fn len(&self) -> usize {
+        self.mutations.len()
+    }
+
+    /// Returns `true` if no mutations were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.mutations.is_empty()
+    }
+
+    /// Appends the mutation to the log.
+    pub fn push(&mut self, mutation: StorageMutation) {
+        self.mutations.push(mutation);
+    }
+
+    /// Applies the mutations to the `storage` in order, stopping at the first error.
+    ///
+    /// The replay is only atomic if the `storage` is, e.g. if it's a transaction of
+    /// a database committed after the replay succeeds.
+    pub fn replay<S>(&self, storage: &mut S) -> Result<(), S::DataError>
+    where
+        S: InterpreterStorage,
+    {
+        self.mutations
+            .iter()
+            .try_for_each(|mutation| mutation.apply(storage))
+    }
+}
+
+impl From<Vec<StorageMutation>> for WriteAheadLog {
+    fn from(mutations: Vec<StorageMutation>) -> Self {
+        Self { mutations }
+    }
+}
+
+impl IntoIterator for WriteAheadLog {
+    type IntoIter = alloc::vec::IntoIter<StorageMutation>;
+    type Item = StorageMutation;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.mutations.into_iter()
+    }
+}
+
+/// The latest pending value of every entry mutated through the journal.
+#[derive(Debug, Default, Clone)]
+pub struct Pending {
+    contracts: BTreeMap<ContractId, Option<Contract>>,
+    contract_state: BTreeMap<ContractsStateKey, Option<ContractsStateData>>,
+    balances: BTreeMap<ContractsAssetKey, Option<Word>>,
+    uploaded_bytecodes: BTreeMap<Bytes32, Option<UploadedBytecode>>,
+    blobs: BTreeMap<BlobId, Option<BlobBytes>>,
+    consensus_parameters: BTreeMap<u32, ConsensusParameters>,
+    state_transition_bytecodes: BTreeMap<u32, Bytes32>,
+}
+
+/// The storage keeping the mutations in a [`WriteAheadLog`] until they are
+/// committed to the wrapped storage.
+#[derive(Debug, Clone)]
+pub struct JournaledStorage<S> {
+    storage: S,
+    pending: Pending,
+    log: WriteAheadLog,
+}
+
+impl<S> JournaledStorage<S> {
+    /// Wraps the `storage` with an empty journal.
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            pending: Pending::default(),
+            log: WriteAheadLog::new(),
+        }
+    }
+
+    /// The mutations recorded since the last commit or rollback.
+    pub const fn log(&self) -> &WriteAheadLog {
+        &self.log
+    }
+
+    /// Takes the recorded mutations out of the journal, leaving the wrapped storage
+    /// unchanged.
+    pub fn take_log(&mut self) -> WriteAheadLog {
+        self.pending = Pending::default();
+        core::mem::take(&mut self.log)
+    }
+
+    /// Drops the recorded mutations.
+    pub fn rollback(&mut self) {
+        self.take_log();
+    }
+
+    /// The wrapped storage, without the pending mutations.
+    pub const fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Unwraps the storage, dropping the pending mutations.
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+
+    fn record<T>(&mut self, key: T::OwnedKey, value: Option<T::OwnedValue>)
+    where
+        T: JournaledTable,
+        T::OwnedKey: Ord,
+    {
+        self.log.push(T::mutation(key.clone(), value.clone()));
+        T::pending_mut(&mut self.pending).insert(key, value);
+    }
+}
+
+impl<S> JournaledStorage<S>
+where
+    S: InterpreterStorage,
+{
+    /// Applies the recorded mutations to the wrapped storage and clears the journal.
+    ///
+    /// On an error the journal is kept, while the wrapped storage may be left with a
+    /// part of the mutations applied unless it discards them itself.
+    pub fn commit(&mut self) -> Result<(), S::DataError> {
+        self.log.replay(&mut self.storage)?;
+        self.take_log();
+        Ok(())
+    }
+}
+
+/// The tables mutated through a [`JournaledStorage`].
+pub trait JournaledTable: Mappable<OwnedKey: 'static, OwnedValue: 'static> {
+    fn pending(pending: &Pending) -> &BTreeMap<Self::OwnedKey, Option<Self::OwnedValue>>;
+
+    fn pending_mut(
+        pending: &mut Pending,
+    ) -> &mut BTreeMap<Self::OwnedKey, Option<Self::OwnedValue>>;
+
+    fn mutation(key: Self::OwnedKey, value: Option<Self::OwnedValue>) -> StorageMutation;
+}
+
+macro_rules! journaled_table {
+    ($table:ty, $field:ident, $variant:ident { $key:ident, $value:ident }) => {
+        impl JournaledTable for $table {
+            fn pending(
+                pending: &Pending,
+            ) -> &BTreeMap<Self::OwnedKey, Option<Self::OwnedValue>> {
+                &pending.$field
+            }
+
+            fn pending_mut(
+                pending: &mut Pending,
+            ) -> &mut BTreeMap<Self::OwnedKey, Option<Self::OwnedValue>> {
+                &mut pending.$field
+            }
+
+            fn mutation(
+                key: Self::OwnedKey,
+                value: Option<Self::OwnedValue>,
+            ) -> StorageMutation {
+                StorageMutation::$variant {
+                    $key: key,
+                    $value: value,
+                }
+            }
+        }
+    };
+}
+
+journaled_table!(ContractsRawCode, contracts, ContractCode { contract, code });
+journaled_table!(ContractsState, contract_state, ContractState { key, value });
+journaled_table!(ContractsAssets, balances, ContractAsset { key, balance });
+journaled_table!(
+    UploadedBytecodes,
+    uploaded_bytecodes,
+    UploadedBytecode { root, bytecode }
+);
+journaled_table!(BlobData, blobs, Blob { id, data });
+
+impl<S> JournaledStorage<S> {
+    /// The pending value of the entry, `Some(None)` if it was removed, or `None` if
+    /// it wasn't mutated through the journal.
+    fn pending_value<T>(&self, key: &T::Key) -> Option<Option<&T::OwnedValue>>
+    where
+        T: JournaledTable,
+        T::OwnedKey: Ord,
+    {
+        let key: T::OwnedKey = key.to_owned().into();
+        T::pending(&self.pending).get(&key).map(Option::as_ref)
+    }
+}
+
+impl<T, S> StorageInspect<T> for JournaledStorage<S>
+where
+    T: JournaledTable,
+    T::OwnedKey: Ord,
+    S: StorageInspect<T>,
+{
+    type Error = S::Error;
+
+    fn get(&self, key: &T::Key) -> Result<Option<Cow<'_, T::OwnedValue>>, Self::Error> {
+        match self.pending_value::<T>(key) {
+            Some(value) => Ok(value.map(Cow::Borrowed)),
+            None => StorageInspect::<T>::get(&self.storage, key),
+        }
+    }
+
+    fn contains_key(&self, key: &T::Key) -> Result<bool, Self::Error> {
+        match self.pending_value::<T>(key) {
+            Some(value) => Ok(value.is_some()),
+            None => StorageInspect::<T>::contains_key(&self.storage, key),
+        }
+    }
+}
+
+impl<T, S> StorageMutate<T> for JournaledStorage<S>
+where
+    T: JournaledTable,
+    T::OwnedKey: Ord,
+    S: StorageMutate<T>,
+{
+    fn replace(
+        &mut self,
+        key: &T::Key,
+        value: &T::Value,
+    ) -> Result<Option<T::OwnedValue>, Self::Error> {
+        let prev = StorageInspect::<T>::get(self, key)?.map(Cow::into_owned);
+        self.record::<T>(key.to_owned().into(), Some(value.to_owned().into()));
+        Ok(prev)
+    }
+
+    fn take(&mut self, key: &T::Key) -> Result<Option<T::OwnedValue>, Self::Error> {
+        let prev = StorageInspect::<T>::get(self, key)?.map(Cow::into_owned);
+        if prev.is_some() {
+            self.record::<T>(key.to_owned().into(), None);
+        }
+        Ok(prev)
+    }
+}
+
+impl<T, S> StorageSize<T> for JournaledStorage<S>
+where
+    T: JournaledTable,
+    T::OwnedKey: Ord,
+    T::OwnedValue: AsRef<[u8]>,
+    S: StorageSize<T>,
+{
+    fn size_of_value(&self, key: &T::Key) -> Result<Option<usize>, Self::Error> {
+        match self.pending_value::<T>(key) {
+            Some(value) => Ok(value.map(|value| value.as_ref().len())),
+            None => StorageSize::<T>::size_of_value(&self.storage, key),
+        }
+    }
+}
+
+impl<T, S> StorageRead<T> for JournaledStorage<S>
+where
+    T: JournaledTable,
+    T::OwnedKey: Ord,
+    T::OwnedValue: AsRef<[u8]>,
+    S: StorageRead<T>,
+{
+    fn read(&self, key: &T::Key, buf: &mut [u8]) -> Result<Option<usize>, Self::Error> {
+        match self.pending_value::<T>(key) {
+            Some(value) => Ok(value.map(|value| {
+                let value = value.as_ref();
+                let len = buf.len().min(value.len());
+                buf[..len].copy_from_slice(&value[..len]);
+                len
+            })),
+            None => StorageRead::<T>::read(&self.storage, key, buf),
+        }
+    }
+
+    fn read_alloc(&self, key: &T::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        match self.pending_value::<T>(key) {
+            Some(value) => Ok(value.map(|value| value.as_ref().to_vec())),
+            None => StorageRead::<T>::read_alloc(&self.storage, key),
+        }
+    }
+}
+
+impl<T, S> StorageWrite<T> for JournaledStorage<S>
+where
+    T: JournaledTable<Value = [u8]>,
+    T::OwnedKey: Ord,
+    T::OwnedValue: AsRef<[u8]>,
+    S: StorageWrite<T> + StorageRead<T>,
+{
+    fn write_bytes(&mut self, key: &T::Key, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.record::<T>(key.to_owned().into(), Some(buf.to_vec().into()));
+        Ok(buf.len())
+    }
+
+    fn replace_bytes(
+        &mut self,
+        key: &T::Key,
+        buf: &[u8],
+    ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+        let prev = StorageRead::<T>::read_alloc(self, key)?;
+        let size = StorageWrite::<T>::write_bytes(self, key, buf)?;
+        Ok((size, prev))
+    }
+
+    fn take_bytes(&mut self, key: &T::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        let prev = StorageRead::<T>::read_alloc(self, key)?;
+        if prev.is_some() {
+            self.record::<T>(key.to_owned().into(), None);
+        }
+        Ok(prev)
+    }
+}
+
+impl<S> ContractsAssetsStorage for JournaledStorage<S> where S: ContractsAssetsStorage {}
+
+impl<S> InterpreterStorage for JournaledStorage<S>
+where
+    S: UpgradesStorage,
+{
+    type DataError = S::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        self.storage.block_height()
+    }
+
+    /// The upgrades don't change the current version, like with the wrapped
+    /// storage, so a second upgrade is rejected as overriding the first one.
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.consensus_parameters_version()
+    }
+
+    /// The upgrades don't change the current version, like with the wrapped
+    /// storage, so a second upgrade is rejected as overriding the first one.
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.state_transition_version()
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        self.storage.timestamp(height)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        self.storage.coinbase()
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        let previous = self.consensus_parameters(version)?;
+        self.log.push(StorageMutation::ConsensusParameters {
+            version,
+            consensus_parameters: Box::new(consensus_parameters.clone()),
+        });
+        self.pending
+            .consensus_parameters
+            .insert(version, consensus_parameters.clone());
+        Ok(previous)
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        let previous = self.state_transition_bytecode(version)?;
+        self.log.push(StorageMutation::StateTransitionBytecode {
+            version,
+            root: *hash,
+        });
+        self.pending
+            .state_transition_bytecodes
+            .insert(version, *hash);
+        Ok(previous)
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<'_, ContractsStateData>>>, Self::DataError> {
+        let mut values = self.storage.contract_state_range(id, start_key, range)?;
+        if self.pending.contract_state.is_empty() {
+            return Ok(values)
+        }
+
+        values
+            .iter_mut()
+            .zip(successive_keys(start_key))
+            .for_each(|(value, key)| {
+                let key: ContractsStateKey = (id, &key).into();
+                if let Some(pending) = self.pending.contract_state.get(&key) {
+                    *value = pending.as_ref().map(Cow::Borrowed);
+                }
+            });
+        Ok(values)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        let mut unset_count: usize = 0;
+        for (key, value) in successive_keys(start_key).zip(values) {
+            let key: ContractsStateKey = (contract, &key).into();
+            if !StorageInspect::<ContractsState>::contains_key(self, &key)? {
+                unset_count = unset_count.saturating_add(1);
+            }
+            self.record::<ContractsState>(key, Some(value.into()));
+        }
+        Ok(unset_count)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        let mut all_set = true;
+        for key in successive_keys(start_key).take(range) {
+            let key: ContractsStateKey = (contract, &key).into();
+            if StorageInspect::<ContractsState>::contains_key(self, &key)? {
+                self.record::<ContractsState>(key, None);
+            } else {
+                all_set = false;
+            }
+        }
+        Ok(all_set.then_some(()))
+    }
+}
+
+impl<S> UpgradesStorage for JournaledStorage<S>
+where
+    S: UpgradesStorage,
+{
+    fn consensus_parameters(
+        &self,
+        version: u32,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        match self.pending.consensus_parameters.get(&version) {
+            Some(consensus_parameters) => Ok(Some(consensus_parameters.clone())),
+            None => self.storage.consensus_parameters(version),
+        }
+    }
+
+    fn state_transition_bytecode(
+        &self,
+        version: u32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        match self.pending.state_transition_bytecodes.get(&version) {
+            Some(root) => Ok(Some(*root)),
+            None => self.storage.state_transition_bytecode(version),
+        }
+    }
+}
+
+impl<S> ContractStateIterator for JournaledStorage<S>
+where
+    S: ContractStateIterator + UpgradesStorage,
+{
+    fn contract_state_iter(
+        &self,
+        id: &ContractId,
+    ) -> ContractStateIter<'_, Self::DataError> {
+        let start: ContractsStateKey = (id, &Bytes32::zeroed()).into();
+        let end: ContractsStateKey = (id, &Bytes32::new([u8::MAX; 32])).into();
+        let mut pending = self
+            .pending
+            .contract_state
+            .range(start..=end)
+            .map(|(key, value)| (*key.state_key(), value))
+            .peekable();
+        let mut stored = self.storage.contract_state_iter(id).peekable();
+
+        // Merges the stored slots with the pending ones, which take precedence.
+        Box::new(core::iter::from_fn(move || loop {
+            let stored_key = match stored.peek() {
+                Some(Ok((key, _))) => Some(*key),
+                Some(Err(_)) => return stored.next(),
+                None => None,
+            };
+            let pending_key = pending.peek().map(|(key, _)| *key);
+            match (stored_key, pending_key) {
+                (None, None) => return None,
+                (Some(_), None) => return stored.next(),
+                (Some(stored_key), Some(pending_key)) if stored_key < pending_key => {
+                    return stored.next()
+                }
+                (stored_key, Some(pending_key)) => {
+                    if stored_key == Some(pending_key) {
+                        stored.next();
+                    }
+                    if let Some((key, Some(value))) = pending.next() {
+                        return Some(Ok((key, Cow::Borrowed(value))))
+                    }
+                }
+            }
+        }))
+    }
+}
+
+#[cfg(all(test, feature = "test-helpers"))]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+    use fuel_storage::{
+        StorageAsMut,
+        StorageAsRef,
+    };
+
+    fn contract(id: u8) -> ContractId {
+        ContractId::from([id; 32])
+    }
+
+    fn key(k: u8) -> Bytes32 {
+        Bytes32::from([k; 32])
+    }
+
+    #[test]
+    fn commit__applies_pending_mutations_to_the_storage() {
+        let mut storage = MemoryStorage::default();
+        storage
+            .contract_state_insert(&contract(1), &key(1), &[1; 32])
+            .unwrap();
+        let mut journal = JournaledStorage::new(storage);
+
+        journal
+            .storage_contract_insert(&contract(2), &Contract::from(alloc::vec![2; 10]))
+            .unwrap();
+        journal
+            .contract_state_insert(&contract(1), &key(1), &[3; 32])
+            .unwrap();
+        journal
+            .contract_state_insert(&contract(1), &key(2), &[4; 32])
+            .unwrap();
+
+        assert_eq!(journal.log().len(), 3);
+        assert!(!journal
+            .storage()
+            .storage_contract_exists(&contract(2))
+            .unwrap());
+        assert_eq!(
+            journal.contract_state(&contract(1), &key(1)).unwrap(),
+            Some(Cow::Owned(ContractsStateData::from(&[3; 32][..])))
+        );
+
+        journal.commit().unwrap();
+
+        assert!(journal.log().is_empty());
+        let storage = journal.into_inner();
+        assert!(storage.storage_contract_exists(&contract(2)).unwrap());
+        assert_eq!(
+            storage.contract_state(&contract(1), &key(1)).into_owned(),
+            ContractsStateData::from(&[3; 32][..])
+        );
+        assert_eq!(
+            storage.contract_state(&contract(1), &key(2)).into_owned(),
+            ContractsStateData::from(&[4; 32][..])
+        );
+    }
+
+    #[test]
+    fn rollback__leaves_the_storage_unchanged() {
+        let mut journal = JournaledStorage::new(MemoryStorage::default());
+        journal
+            .contract_asset_id_balance_insert(&contract(1), &Default::default(), 10)
+            .unwrap();
+
+        journal.rollback();
+
+        assert_eq!(
+            journal
+                .contract_asset_id_balance(&contract(1), &Default::default())
+                .unwrap(),
+            None
+        );
+        assert!(journal.log().is_empty());
+    }
+
+    #[test]
+    fn contract_state_range__merges_pending_and_stored_slots() {
+        let mut storage = MemoryStorage::default();
+        storage
+            .contract_state_insert_range(
+                &contract(1),
+                &Bytes32::zeroed(),
+                [&[1; 32][..], &[2; 32][..]].into_iter(),
+            )
+            .unwrap();
+        let mut journal = JournaledStorage::new(storage);
+
+        let unset = journal
+            .contract_state_insert_range(
+                &contract(1),
+                &Bytes32::zeroed(),
+                [&[3; 32][..], &[3; 32][..]].into_iter(),
+            )
+            .unwrap();
+        let removed = journal
+            .contract_state_remove_range(&contract(1), &Bytes32::zeroed(), 1)
+            .unwrap();
+
+        assert_eq!(unset, 0);
+        assert_eq!(removed, Some(()));
+        let range: Vec<_> = journal
+            .contract_state_range(&contract(1), &Bytes32::zeroed(), 3)
+            .unwrap()
+            .into_iter()
+            .map(|value| value.map(Cow::into_owned))
+            .collect();
+        assert_eq!(
+            range,
+            alloc::vec![None, Some(ContractsStateData::from(&[3; 32][..])), None]
+        );
+    }
+
+    #[test]
+    fn contract_state_iter__merges_pending_and_stored_slots() {
+        let mut storage = MemoryStorage::default();
+        storage
+            .contract_state_insert(&contract(1), &key(1), &[1; 32])
+            .unwrap();
+        storage
+            .contract_state_insert(&contract(1), &key(3), &[3; 32])
+            .unwrap();
+        storage
+            .contract_state_insert(&contract(2), &key(2), &[5; 32])
+            .unwrap();
+        let mut journal = JournaledStorage::new(storage);
+
+        journal
+            .contract_state_insert(&contract(1), &key(2), &[2; 32])
+            .unwrap();
+        journal
+            .contract_state_insert(&contract(1), &key(3), &[4; 32])
+            .unwrap();
+        journal
+            .storage_as_mut::<ContractsState>()
+            .remove(&(&contract(1), &key(1)).into())
+            .unwrap();
+
+        let slots: Vec<_> = journal
+            .contract_state_iter(&contract(1))
+            .map(|slot| slot.map(|(key, value)| (key, value.into_owned())))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            slots,
+            alloc::vec![
+                (key(2), ContractsStateData::from(&[2; 32][..])),
+                (key(3), ContractsStateData::from(&[4; 32][..])),
+            ]
+        );
+    }
+
+    #[test]
+    fn take_log__replays_onto_another_backend() {
+        let mut journal = JournaledStorage::new(MemoryStorage::default());
+        journal
+            .contract_state_insert(&contract(1), &key(1), &[1; 32])
+            .unwrap();
+        journal
+            .storage_as_mut::<BlobData>()
+            .insert(&BlobId::zeroed(), &[5; 4])
+            .unwrap();
+        journal
+            .storage_as_mut::<ContractsState>()
+            .remove(&(&contract(1), &key(1)).into())
+            .unwrap();
+
+        let log = journal.take_log();
+        let mut backend = MemoryStorage::default();
+        log.replay(&mut backend).unwrap();
+
+        assert_eq!(log.len(), 3);
+        assert!(!StorageInspect::<ContractsState>::contains_key(
+            &backend,
+            &(&contract(1), &key(1)).into()
+        )
+        .unwrap());
+        assert!(backend
+            .storage::<BlobData>()
+            .contains_key(&BlobId::zeroed())
+            .unwrap());
+        assert!(journal.storage().all_contract_state().next().is_none());
+    }
+}
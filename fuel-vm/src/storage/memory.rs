@@ -37,15 +37,30 @@ use tai64::Tai64;
 
 use alloc::{
     borrow::Cow,
+    boxed::Box,
     collections::BTreeMap,
     vec::Vec,
 };
 use core::convert::Infallible;
 
 use super::{
+    contracts_state::add_one,
     interpreter::ContractsAssetsStorage,
     BlobBytes,
     BlobData,
+    ContractStateIter,
+    ContractStateIterator,
+    UpgradesStorage,
+};
+
+#[cfg(feature = "serde")]
+mod snapshot;
+
+#[cfg(feature = "serde")]
+pub use snapshot::{
+    MemoryStorageSnapshot,
+    SnapshotError,
+    SNAPSHOT_VERSION,
 };
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -111,6 +126,13 @@ impl MemoryStorage {
         self.memory.contract_state.iter()
     }
 
+    /// Iterate over all contract balances in storage
+    pub fn all_contract_balances(
+        &self,
+    ) -> impl Iterator<Item = (&ContractsAssetKey, &Word)> {
+        self.memory.balances.iter()
+    }
+
     /// Fetch a mapping from the contract state.
     pub fn contract_state(
         &self,
@@ -124,6 +146,10 @@ impl MemoryStorage {
     }
 
     /// Set the transacted state to the memory state.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "commit", level = "debug", skip_all)
+    )]
     pub fn commit(&mut self) {
         self.transacted = self.memory.clone();
     }
@@ -140,6 +166,10 @@ impl MemoryStorage {
     }
 
     /// Persist the changes from transacted to memory+persisted state.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "persist", level = "debug", skip_all)
+    )]
     pub fn persist(&mut self) {
         self.memory = self.transacted.clone();
         self.persisted = self.transacted.clone();
@@ -724,17 +754,44 @@ impl InterpreterStorage for MemoryStorage {
     }
 }
 
-fn add_one(a: &mut [u8; 32]) -> bool {
-    let right = u128::from_be_bytes(a[16..].try_into().unwrap());
-    let (right, of) = right.overflowing_add(1);
-    a[16..].copy_from_slice(&right.to_be_bytes()[..]);
-    if of {
-        let left = u128::from_be_bytes(a[..16].try_into().unwrap());
-        let (left, of) = left.overflowing_add(1);
-        a[..16].copy_from_slice(&left.to_be_bytes()[..]);
-        return of
-    }
-    false
+impl ContractStateIterator for MemoryStorage {
+    fn contract_state_iter(
+        &self,
+        id: &ContractId,
+    ) -> ContractStateIter<'_, Self::DataError> {
+        let start: ContractsStateKey = (id, &Bytes32::zeroed()).into();
+        let end: ContractsStateKey = (id, &Bytes32::new([u8::MAX; 32])).into();
+        Box::new(
+            self.memory
+                .contract_state
+                .range(start..=end)
+                .map(|(key, value)| Ok((*key.state_key(), Cow::Borrowed(value)))),
+        )
+    }
+}
+
+impl UpgradesStorage for MemoryStorage {
+    fn consensus_parameters(
+        &self,
+        version: u32,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        Ok(self
+            .memory
+            .consensus_parameters_versions
+            .get(&version)
+            .cloned())
+    }
+
+    fn state_transition_bytecode(
+        &self,
+        version: u32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        Ok(self
+            .memory
+            .state_transition_bytecodes_versions
+            .get(&version)
+            .copied())
+    }
 }
 
 #[cfg(test)]
@@ -775,4 +832,30 @@ mod tests {
             .map(|v| v.map(|v| v.into_owned()))
             .collect()
     }
+
+    #[test]
+    fn contract_state_iter_returns_only_the_slots_of_the_contract() {
+        let mut mem = MemoryStorage::default();
+        let contract = ContractId::from([1; 32]);
+        for (id, k) in [
+            (contract, key(2)),
+            (ContractId::default(), key(1)),
+            (contract, key(1)),
+        ] {
+            mem.memory
+                .contract_state
+                .insert((&id, &k.into()).into(), vec![k[31]; 32].into());
+        }
+
+        let slots: Vec<_> = mem
+            .contract_state_iter(&contract)
+            .map(|slot| slot.map(|(key, value)| (*key, value.into_owned())))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            slots,
+            vec![(key(1), vec![1; 32].into()), (key(2), vec![2; 32].into())]
+        );
+    }
 }
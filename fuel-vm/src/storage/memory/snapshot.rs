@@ -0,0 +1,257 @@
+//! Export and import of the whole [`MemoryStorage`].
+//!
+//! A [`MemoryStorageSnapshot`] holds the contracts, their balances and state
+//! slots, the blobs, the upgrade tables and the block data of the storage. The
+//! tables are grouped by the contract and sorted by the keys, so the serialized
+//! snapshot is deterministic and can be kept as a fixture, e.g. produced by a real
+//! execution and shared between the integration tests and the local devnets.
+
+use super::{
+    MemoryStorage,
+    MemoryStorageInner,
+};
+use crate::storage::{
+    BlobBytes,
+    ContractsStateData,
+    UploadedBytecode,
+};
+use alloc::collections::BTreeMap;
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+};
+use fuel_types::{
+    AssetId,
+    BlobId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+/// The current version of the [`MemoryStorageSnapshot`] format.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// The state of a [`MemoryStorage`] in a stable, serializable format.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MemoryStorageSnapshot {
+    /// The version of the format, [`SNAPSHOT_VERSION`] for the snapshots made by
+    /// this version of the crate.
+    pub version: u32,
+    /// The current block height.
+    pub block_height: BlockHeight,
+    /// The coinbase contract.
+    pub coinbase: ContractId,
+    /// The current version of the consensus parameters.
+    pub consensus_parameters_version: u32,
+    /// The current version of the state transition.
+    pub state_transition_version: u32,
+    /// The code of the deployed contracts.
+    pub contracts: BTreeMap<ContractId, Contract>,
+    /// The balances of the contracts.
+    pub balances: BTreeMap<ContractId, BTreeMap<AssetId, Word>>,
+    /// The state slots of the contracts.
+    pub contract_state: BTreeMap<ContractId, BTreeMap<Bytes32, ContractsStateData>>,
+    /// The uploaded blobs.
+    pub blobs: BTreeMap<BlobId, BlobBytes>,
+    /// The consensus parameters by their version.
+    pub consensus_parameters_versions: BTreeMap<u32, ConsensusParameters>,
+    /// The uploaded state transition bytecode by its root.
+    pub state_transition_bytecodes: BTreeMap<Bytes32, UploadedBytecode>,
+    /// The root of the state transition bytecode by its version.
+    pub state_transition_bytecodes_versions: BTreeMap<u32, Bytes32>,
+}
+
+/// The snapshot can't be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum SnapshotError {
+    /// The snapshot was made with an unknown version of the format.
+    #[display(fmt = "unsupported snapshot version {_0}")]
+    UnsupportedVersion(u32),
+}
+
+impl MemoryStorage {
+    /// Exports the current state of the storage, i.e. including the changes which
+    /// weren't committed yet.
+    pub fn snapshot(&self) -> MemoryStorageSnapshot {
+        let MemoryStorageInner {
+            contracts,
+            balances,
+            contract_state,
+            blobs,
+            consensus_parameters_versions,
+            state_transition_bytecodes,
+            state_transition_bytecodes_versions,
+        } = &self.memory;
+
+        let mut balances_snapshot = BTreeMap::<_, BTreeMap<_, _>>::new();
+        for (key, balance) in balances {
+            balances_snapshot
+                .entry(*key.contract_id())
+                .or_default()
+                .insert(*key.asset_id(), *balance);
+        }
+        let mut state_snapshot = BTreeMap::<_, BTreeMap<_, _>>::new();
+        for (key, value) in contract_state {
+            state_snapshot
+                .entry(*key.contract_id())
+                .or_default()
+                .insert(*key.state_key(), value.clone());
+        }
+
+        MemoryStorageSnapshot {
+            version: SNAPSHOT_VERSION,
+            block_height: self.block_height,
+            coinbase: self.coinbase,
+            consensus_parameters_version: self.consensus_parameters_version,
+            state_transition_version: self.state_transition_version,
+            contracts: contracts.clone(),
+            balances: balances_snapshot,
+            contract_state: state_snapshot,
+            blobs: blobs.clone(),
+            consensus_parameters_versions: consensus_parameters_versions.clone(),
+            state_transition_bytecodes: state_transition_bytecodes.clone(),
+            state_transition_bytecodes_versions: state_transition_bytecodes_versions
+                .clone(),
+        }
+    }
+
+    /// Imports the storage from the snapshot. The imported state is persisted, so
+    /// [`MemoryStorage::rollback`] returns to it.
+    pub fn from_snapshot(snapshot: MemoryStorageSnapshot) -> Result<Self, SnapshotError> {
+        let MemoryStorageSnapshot {
+            version,
+            block_height,
+            coinbase,
+            consensus_parameters_version,
+            state_transition_version,
+            contracts,
+            balances,
+            contract_state,
+            blobs,
+            consensus_parameters_versions,
+            state_transition_bytecodes,
+            state_transition_bytecodes_versions,
+        } = snapshot;
+
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version))
+        }
+
+        let state = MemoryStorageInner {
+            contracts,
+            balances: balances
+                .into_iter()
+                .flat_map(|(contract, balances)| {
+                    balances.into_iter().map(move |(asset_id, balance)| {
+                        ((&contract, &asset_id).into(), balance)
+                    })
+                })
+                .collect(),
+            contract_state: contract_state
+                .into_iter()
+                .flat_map(|(contract, slots)| {
+                    slots
+                        .into_iter()
+                        .map(move |(key, value)| ((&contract, &key).into(), value))
+                })
+                .collect(),
+            blobs,
+            consensus_parameters_versions,
+            state_transition_bytecodes,
+            state_transition_bytecodes_versions,
+        };
+
+        let mut storage = Self::new_with_versions(
+            block_height,
+            coinbase,
+            consensus_parameters_version,
+            state_transition_version,
+        );
+        storage.memory = state.clone();
+        storage.transacted = state.clone();
+        storage.persisted = state;
+        Ok(storage)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::storage::{
+        ContractsAssetsStorage,
+        InterpreterStorage,
+    };
+
+    fn storage() -> MemoryStorage {
+        let mut storage = MemoryStorage::new(7.into(), ContractId::from([9; 32]));
+        let contract = ContractId::from([1; 32]);
+        storage
+            .storage_contract_insert(&contract, &Contract::from(alloc::vec![1; 8]))
+            .unwrap();
+        storage
+            .contract_state_insert(&contract, &Bytes32::from([2; 32]), &[3; 32])
+            .unwrap();
+        storage
+            .contract_asset_id_balance_insert(&contract, &AssetId::from([4; 32]), 5)
+            .unwrap();
+        storage
+            .contract_asset_id_balance_insert(
+                &ContractId::zeroed(),
+                &AssetId::zeroed(),
+                6,
+            )
+            .unwrap();
+        storage
+    }
+
+    #[test]
+    fn from_snapshot__restores_the_storage() {
+        // Given
+        let storage = storage();
+
+        // When
+        let imported = MemoryStorage::from_snapshot(storage.snapshot()).unwrap();
+
+        // Then
+        assert_eq!(imported.snapshot(), storage.snapshot());
+        assert_eq!(imported.block_height().unwrap(), 7.into());
+        assert_eq!(
+            imported
+                .contract_asset_id_balance(&ContractId::zeroed(), &AssetId::zeroed())
+                .unwrap(),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn snapshot__roundtrips_through_json() {
+        // Given
+        let snapshot = storage().snapshot();
+
+        // When
+        let json = serde_json::to_string(&snapshot).unwrap();
+
+        // Then
+        let decoded: MemoryStorageSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, snapshot);
+        assert_eq!(decoded.contract_state[&ContractId::from([1; 32])].len(), 1);
+    }
+
+    #[test]
+    fn from_snapshot__rejects_unknown_version() {
+        // Given
+        let mut snapshot = storage().snapshot();
+        snapshot.version = SNAPSHOT_VERSION + 1;
+
+        // When
+        let result = MemoryStorage::from_snapshot(snapshot);
+
+        // Then
+        assert_eq!(
+            result.map(|_| ()),
+            Err(SnapshotError::UnsupportedVersion(SNAPSHOT_VERSION + 1))
+        );
+    }
+}
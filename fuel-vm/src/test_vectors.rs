@@ -0,0 +1,434 @@
+//! Seeded test vectors for the consensus testing of alternative client
+//! implementations.
+//!
+//! A vector bundles a transaction, the parameters and the storage it is executed
+//! against, and the receipts and the state changes produced by this interpreter. The
+//! vectors are randomized, but the same seed always produces the same corpus.
+//!
+//! # Container format
+//!
+//! A corpus is a [JSON Lines](https://jsonlines.org) document: one JSON object per
+//! line. The first line is the header:
+//!
+//! ```json
+//! { "format": "fuel-vm-test-vectors", "version": 1, "seed": "42", "count": 100 }
+//! ```
+//!
+//! Every following line is a vector with the keys:
+//! - `index`: the position of the vector in the corpus, starting from `0`.
+//! - `block_height`: the height of the block executing the transaction.
+//! - `gas_price`: the gas price of the block.
+//! - `consensus_parameters`: the parameters of the chain, in the canonical JSON encoding
+//!   of [`fuel_tx::json`].
+//! - `transaction`: the canonically encoded transaction, as a hex string.
+//! - `fixture`: the storage before the execution. Its `contracts` list the deployed
+//!   contracts with their `contract_id`, `bytecode`, `state` slots (`key` and `value`)
+//!   and `balances` (`asset_id` and `amount`).
+//! - `expected`: the result of the execution. Its `receipts` are in the canonical JSON
+//!   encoding, the `transaction` is the canonically encoded transaction with the outputs
+//!   updated by the execution, and the `state_diff` lists the changed state slots and
+//!   balances.
+//!
+//! An entry of the `state_diff` is tagged by the `"type"` key, either `"Slot"` with the
+//! `contract_id` and the `key`, or `"Balance"` with the `contract_id` and the
+//! `asset_id`. Its `before` and `after` values are `null` if the entry doesn't exist.
+//!
+//! Byte fields are `0x`-prefixed lowercase hex strings, and 64-bit integers are
+//! decimal strings, like in the canonical JSON encoding.
+
+use crate::{
+    call::Call,
+    prelude::*,
+    storage::{
+        ContractsRawCode,
+        MemoryStorage,
+    },
+    util::test_helpers::TestBuilder,
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::fmt::Write as _;
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::json::CanonicalJson;
+use fuel_types::canonical::Serialize as _;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use serde::Serialize;
+
+/// The value of the `format` key of the header.
+pub const FORMAT: &str = "fuel-vm-test-vectors";
+
+/// The version of the container format.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// The first line of a corpus.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Header {
+    /// Always [`FORMAT`].
+    pub format: String,
+    /// Always [`FORMAT_VERSION`].
+    pub version: u32,
+    /// The seed of the corpus.
+    pub seed: String,
+    /// The number of vectors in the corpus.
+    pub count: u32,
+}
+
+/// A transaction along with the context of its execution and its result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TestVector {
+    /// The position of the vector in the corpus.
+    pub index: u32,
+    /// The height of the block executing the transaction.
+    pub block_height: u32,
+    /// The gas price of the block.
+    pub gas_price: String,
+    /// The parameters of the chain.
+    pub consensus_parameters: serde_json::Value,
+    /// The canonically encoded transaction.
+    pub transaction: String,
+    /// The storage before the execution.
+    pub fixture: Fixture,
+    /// The result of the execution.
+    pub expected: Expected,
+}
+
+/// The storage before the execution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Fixture {
+    /// The deployed contracts.
+    pub contracts: Vec<ContractFixture>,
+}
+
+/// A deployed contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractFixture {
+    /// The id of the contract.
+    pub contract_id: String,
+    /// The bytecode of the contract.
+    pub bytecode: String,
+    /// The state slots of the contract.
+    pub state: Vec<SlotFixture>,
+    /// The balances of the contract.
+    pub balances: Vec<BalanceFixture>,
+}
+
+/// A state slot of a contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SlotFixture {
+    /// The key of the slot.
+    pub key: String,
+    /// The value of the slot.
+    pub value: String,
+}
+
+/// A balance of a contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BalanceFixture {
+    /// The asset of the balance.
+    pub asset_id: String,
+    /// The amount of the asset.
+    pub amount: String,
+}
+
+/// The result of the execution.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Expected {
+    /// The receipts of the execution.
+    pub receipts: serde_json::Value,
+    /// The canonically encoded transaction with the outputs updated by the execution.
+    pub transaction: String,
+    /// The changed state slots and balances.
+    pub state_diff: Vec<StateChange>,
+}
+
+/// A state slot or a balance changed by the execution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum StateChange {
+    /// A changed state slot.
+    Slot {
+        /// The contract owning the slot.
+        contract_id: String,
+        /// The key of the slot.
+        key: String,
+        /// The value before the execution.
+        before: Option<String>,
+        /// The value after the execution.
+        after: Option<String>,
+    },
+    /// A changed balance.
+    Balance {
+        /// The contract owning the balance.
+        contract_id: String,
+        /// The asset of the balance.
+        asset_id: String,
+        /// The amount before the execution.
+        before: Option<String>,
+        /// The amount after the execution.
+        after: Option<String>,
+    },
+}
+
+fn hex(bytes: impl AsRef<[u8]>) -> String {
+    let bytes = bytes.as_ref();
+    let mut encoded =
+        String::with_capacity(bytes.len().saturating_mul(2).saturating_add(2));
+    encoded.push_str("0x");
+    for byte in bytes {
+        write!(encoded, "{byte:02x}").expect("Writing into `String` is infallible");
+    }
+    encoded
+}
+
+/// The state slots and the balances of the storage, in the encoding of the vectors.
+#[derive(Default)]
+struct Snapshot {
+    slots: BTreeMap<(ContractId, Bytes32), String>,
+    balances: BTreeMap<(ContractId, AssetId), String>,
+}
+
+impl Snapshot {
+    fn of(storage: &MemoryStorage) -> Self {
+        Self {
+            slots: storage
+                .all_contract_state()
+                .map(|(key, value)| {
+                    ((*key.contract_id(), *key.state_key()), hex(&value.0))
+                })
+                .collect(),
+            balances: storage
+                .all_contract_balances()
+                .map(|(key, amount)| {
+                    ((*key.contract_id(), *key.asset_id()), amount.to_string())
+                })
+                .collect(),
+        }
+    }
+
+    fn diff(&self, after: &Self) -> Vec<StateChange> {
+        fn changes<K: Ord + Copy>(
+            before: &BTreeMap<K, String>,
+            after: &BTreeMap<K, String>,
+        ) -> Vec<(K, Option<String>, Option<String>)> {
+            let mut keys: Vec<_> = before.keys().chain(after.keys()).copied().collect();
+            keys.sort();
+            keys.dedup();
+            keys.into_iter()
+                .map(|key| (key, before.get(&key).cloned(), after.get(&key).cloned()))
+                .filter(|(_, before, after)| before != after)
+                .collect()
+        }
+
+        let slots = changes(&self.slots, &after.slots).into_iter().map(
+            |((contract_id, key), before, after)| StateChange::Slot {
+                contract_id: hex(contract_id),
+                key: hex(key),
+                before,
+                after,
+            },
+        );
+        let balances = changes(&self.balances, &after.balances).into_iter().map(
+            |((contract_id, asset_id), before, after)| StateChange::Balance {
+                contract_id: hex(contract_id),
+                asset_id: hex(asset_id),
+                before,
+                after,
+            },
+        );
+
+        slots.chain(balances).collect()
+    }
+}
+
+fn fixture(storage: &MemoryStorage, contracts: &[ContractId]) -> Fixture {
+    let snapshot = Snapshot::of(storage);
+    let contracts = contracts
+        .iter()
+        .map(|contract_id| {
+            let bytecode = storage
+                .storage::<ContractsRawCode>()
+                .get(contract_id)
+                .expect("Infallible")
+                .expect("The contract is deployed");
+
+            ContractFixture {
+                contract_id: hex(contract_id),
+                bytecode: hex(bytecode.as_ref()),
+                state: snapshot
+                    .slots
+                    .range((*contract_id, Bytes32::zeroed())..)
+                    .take_while(|((id, _), _)| id == contract_id)
+                    .map(|((_, key), value)| SlotFixture {
+                        key: hex(key),
+                        value: value.clone(),
+                    })
+                    .collect(),
+                balances: snapshot
+                    .balances
+                    .range((*contract_id, AssetId::zeroed())..)
+                    .take_while(|((id, _), _)| id == contract_id)
+                    .map(|((_, asset_id), amount)| BalanceFixture {
+                        asset_id: hex(asset_id),
+                        amount: amount.clone(),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Fixture { contracts }
+}
+
+/// A contract storing the first argument of the call under the key pointed by the
+/// second one, and logging the previous value.
+fn storing_contract() -> Vec<Instruction> {
+    let a_offset = Immediate12::try_from(CallFrame::a_offset())
+        .expect("The offset fits into an immediate");
+    let b_offset = Immediate12::try_from(CallFrame::b_offset())
+        .expect("The offset fits into an immediate");
+    vec![
+        op::addi(0x10, RegId::FP, a_offset),
+        op::lw(0x10, 0x10, 0),
+        op::addi(0x11, RegId::FP, b_offset),
+        op::lw(0x11, 0x11, 0),
+        op::srw(0x20, 0x21, 0x11),
+        op::sww(0x11, 0x21, 0x10),
+        op::log(0x20, 0x10, 0x21, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+}
+
+/// Generates the vector at the `index` of the corpus, using the `seed` of the vector.
+fn generate(index: u32, seed: u64) -> TestVector {
+    let mut test_context = TestBuilder::new(seed);
+    let rng = &mut StdRng::seed_from_u64(seed);
+
+    let block_height: u32 = rng.gen_range(0..1_000_000);
+    let gas_price: Word = rng.gen_range(0..=2);
+    test_context
+        .block_height(block_height.into())
+        .gas_price(gas_price)
+        .max_fee_limit(1_000_000);
+
+    let initial_state = (0..rng.gen_range(0..3))
+        .map(|_| StorageSlot::new(rng.gen(), rng.gen()))
+        .collect();
+    let initial_balance = (rng.gen(), rng.gen_range(0..1_000_000));
+    let contract_id = test_context
+        .setup_contract(
+            storing_contract(),
+            Some(initial_balance),
+            Some(initial_state),
+        )
+        .contract_id;
+
+    let calls_contract = rng.gen_bool(0.5);
+    let (a, b): (Immediate18, Immediate18) =
+        (rng.gen_range(0..1 << 18), rng.gen_range(0..1 << 18));
+    let arithmetic = match rng.gen_range(0..4) {
+        0 => op::add(0x14, 0x12, 0x13),
+        1 => op::sub(0x14, 0x12, 0x13),
+        2 => op::mul(0x14, 0x12, 0x13),
+        _ => op::div(0x14, 0x12, 0x13),
+    };
+    let end = if rng.gen_ratio(1, 10) {
+        op::rvrt(0x14)
+    } else {
+        op::ret(0x14)
+    };
+    let key: Bytes32 = rng.gen();
+    let value: Word = rng.gen();
+
+    let (script, data_offset) = crate::script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::movi(0x11, if calls_contract { 1 } else { 0 }),
+            op::jnzf(0x11, RegId::ZERO, 1),
+            op::jmpf(RegId::ZERO, 1),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::movi(0x12, a),
+            op::movi(0x13, b),
+            arithmetic,
+            op::log(0x14, 0x12, 0x13, RegId::ZERO),
+            end,
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let key_pointer = Word::from(data_offset).saturating_add(Call::LEN as Word);
+    let mut script_data = Call::new(contract_id, value, key_pointer).to_bytes();
+    script_data.extend(key.as_ref());
+
+    let before = test_context.get_storage().clone();
+    let consensus_parameters = test_context.get_consensus_params().clone();
+    let base_asset_id = *test_context.get_base_asset_id();
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(10_000)
+        .coin_input(base_asset_id, 1_000_000_000)
+        .contract_input(contract_id)
+        .change_output(base_asset_id)
+        .contract_output(&contract_id)
+        .build();
+    let transaction = hex(tx.transaction().to_bytes());
+
+    let state = test_context
+        .execute_tx(tx)
+        .expect("The generated transaction is valid");
+    let after = test_context.get_storage();
+
+    TestVector {
+        index,
+        block_height,
+        gas_price: gas_price.to_string(),
+        consensus_parameters: consensus_parameters.to_canonical_json(),
+        transaction,
+        fixture: fixture(&before, &[contract_id]),
+        expected: Expected {
+            receipts: state.receipts().to_vec().to_canonical_json(),
+            transaction: hex(state.tx().to_bytes()),
+            state_diff: Snapshot::of(&before).diff(&Snapshot::of(after)),
+        },
+    }
+}
+
+/// Generates `count` vectors of the corpus with the `seed`.
+pub fn generate_corpus(seed: u64, count: u32) -> impl Iterator<Item = TestVector> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count).map(move |index| generate(index, rng.gen()))
+}
+
+/// Writes the corpus of `count` vectors with the `seed` in the container format.
+pub fn write_corpus<W>(writer: &mut W, seed: u64, count: u32) -> std::io::Result<()>
+where
+    W: std::io::Write,
+{
+    let header = Header {
+        format: FORMAT.to_string(),
+        version: FORMAT_VERSION,
+        seed: seed.to_string(),
+        count,
+    };
+    serde_json::to_writer(&mut *writer, &header)?;
+    writeln!(writer)?;
+
+    for vector in generate_corpus(seed, count) {
+        serde_json::to_writer(&mut *writer, &vector)?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
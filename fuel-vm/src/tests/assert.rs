@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use super::test_helpers::{
+    assert_panics,
+    assert_success,
+    run_script,
+};
+use fuel_asm::*;
+use fuel_tx::{
+    Receipt,
+    ScriptExecutionResult,
+};
+
+#[test]
+fn assert__continues_when_the_condition_holds() {
+    // Given
+    let script = vec![
+        op::movi(0x10, 42),
+        op::assert(RegId::ONE, 0x10, RegId::IS, RegId::ONE),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = run_script(script);
+
+    // Then
+    assert_success(&receipts);
+    assert!(matches!(receipts[0], Receipt::Log { ra: 42, .. }));
+}
+
+#[test]
+fn assert__reverts_with_the_code_and_logs_the_message_when_the_condition_is_zero() {
+    // Given
+    let script = vec![
+        op::movi(0x10, 42),
+        op::movi(0x11, 8),
+        op::assert(RegId::ZERO, 0x10, RegId::IS, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let message: Vec<u8> = script.iter().copied().collect();
+
+    // When
+    let receipts = run_script(script);
+
+    // Then
+    let Receipt::LogData { ra, rb, data, .. } = &receipts[0] else {
+        panic!("expected a log data receipt, got {:?}", receipts[0]);
+    };
+    assert_eq!(*ra, 42);
+    assert_eq!(*rb, 0);
+    assert_eq!(data.as_deref(), Some(&message[..8]));
+    assert!(matches!(receipts[1], Receipt::Revert { ra: 42, .. }));
+    assert!(matches!(
+        receipts[2],
+        Receipt::ScriptResult {
+            result: ScriptExecutionResult::Revert,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn assert__panics_when_the_message_is_out_of_memory() {
+    // Given
+    let script = vec![
+        op::not(0x10, RegId::ZERO),
+        op::assert(RegId::ZERO, RegId::ONE, 0x10, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = run_script(script);
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
@@ -1788,7 +1788,13 @@ fn smo_instruction_works() {
             .max_fee_limit(max_fee);
         // add inputs
         for (amount, data) in inputs {
-            tx.add_unsigned_message_input(secret, sender, rng.gen(), amount, data);
+            tx.add_unsigned_message_input(
+                secret.clone(),
+                sender,
+                rng.gen(),
+                amount,
+                data,
+            );
         }
         tx.add_unsigned_coin_input(secret, rng.gen(), max_fee, AssetId::BASE, rng.gen());
         let tx = tx
@@ -0,0 +1,107 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::Receipt;
+use fuel_types::canonical::Serialize;
+
+/// Executes a script calling a contract which calls itself until the execution fails,
+/// returning the call depth at the failure and the receipts.
+fn recursive_call(max_call_depth: Option<usize>) -> (usize, Vec<Receipt>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let call_self = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context
+        .setup_contract(call_self.clone(), None, None)
+        .contract_id;
+
+    let tx = test_context
+        .start_script(call_self, Call::new(contract_id, 0, 0).to_bytes())
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+    let consensus_params = test_context.get_consensus_params().clone();
+    let ready = tx
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap();
+
+    let params = InterpreterParams {
+        max_call_depth,
+        ..InterpreterParams::new(0, &consensus_params)
+    };
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        params,
+    );
+    vm.transact(ready).unwrap();
+
+    (vm.call_depth(), vm.receipts().to_vec())
+}
+
+fn panic_reason(receipts: &[Receipt]) -> Option<PanicReason> {
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    })
+}
+
+fn calls(receipts: &[Receipt]) -> usize {
+    receipts
+        .iter()
+        .filter(|receipt| matches!(receipt, Receipt::Call { .. }))
+        .count()
+}
+
+#[test]
+fn max_call_depth__call_beyond_limit_panics() {
+    // When
+    let (call_depth, receipts) = recursive_call(Some(3));
+
+    // Then
+    assert_eq!(call_depth, 3);
+    assert_eq!(calls(&receipts), 3);
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::CallDepthExceeded)
+    );
+}
+
+#[test]
+fn max_call_depth__unlimited_by_default() {
+    // When
+    let (call_depth, receipts) = recursive_call(None);
+
+    // Then
+    assert!(call_depth > 3);
+    assert_eq!(calls(&receipts), call_depth);
+    assert_ne!(
+        panic_reason(&receipts),
+        Some(PanicReason::CallDepthExceeded)
+    );
+}
@@ -0,0 +1,165 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    error::{
+        BugVariant,
+        InterpreterError,
+        SimpleResult,
+    },
+    interpreter::{
+        EcalHandler,
+        InterpreterParams,
+        Memory,
+        MemoryInstance,
+    },
+    prelude::*,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    Receipt,
+    Script,
+    ScriptExecutionResult,
+};
+use fuel_types::canonical::Serialize;
+
+/// An ECAL handler emulating a buggy extension, which overwrites the first byte of
+/// the current call frame.
+#[derive(Debug, Default, Clone, Copy)]
+struct FrameCorruptingEcal;
+
+impl EcalHandler for FrameCorruptingEcal {
+    fn ecal<M, S, Tx>(
+        vm: &mut Interpreter<M, S, Tx, Self>,
+        _: RegId,
+        _: RegId,
+        _: RegId,
+        _: RegId,
+    ) -> SimpleResult<()>
+    where
+        M: Memory,
+    {
+        let fp = vm.registers()[RegId::FP];
+        vm.memory_mut().write_noownerchecks(fp, 1)?[0] ^= 0xff;
+        Ok(())
+    }
+}
+
+fn execute_call(
+    contract: Vec<Instruction>,
+    call_frame_integrity_checks: bool,
+) -> Result<Vec<Receipt>, BugVariant> {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+
+    let tx = test_context
+        .start_script(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            Call::new(contract_id, 0, 0).to_bytes(),
+        )
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .contract_output(&contract_id)
+        .fee_input()
+        .build();
+
+    let params = InterpreterParams {
+        call_frame_integrity_checks,
+        ..Default::default()
+    };
+    let mut transactor = Transactor::<_, _, Script, FrameCorruptingEcal>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        params,
+    );
+    transactor.transact(tx);
+
+    transactor
+        .result()
+        .map(|state| state.receipts().to_vec())
+        .map_err(|error| match error {
+            InterpreterError::Bug(bug) => bug.variant(),
+            error => panic!("Unexpected error: {error:?}"),
+        })
+}
+
+fn assert_success(receipts: &[Receipt]) {
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn call_frame_integrity__intact_frame_returns_normally() {
+    // Given
+    let contract = vec![op::noop(), op::ret(RegId::ONE)];
+
+    // When
+    let result = execute_call(contract, true);
+
+    // Then
+    assert_success(&result.expect("the frame is intact"));
+}
+
+#[test]
+fn call_frame_integrity__corrupted_frame_is_ignored_without_checks() {
+    // Given
+    let contract = vec![
+        op::ecal(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let result = execute_call(contract, false);
+
+    // Then
+    assert_success(&result.expect("the corruption isn't detected"));
+}
+
+#[test]
+fn call_frame_integrity__corrupted_frame_is_reported_as_bug_on_ret() {
+    // Given
+    let contract = vec![
+        op::ecal(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let result = execute_call(contract, true);
+
+    // Then
+    assert_eq!(result, Err(BugVariant::CallFrameCorrupted));
+}
+
+#[test]
+fn call_frame_integrity__corrupted_frame_is_reported_as_bug_on_retd() {
+    // Given
+    let contract = vec![
+        op::ecal(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::retd(RegId::ZERO, RegId::ZERO),
+    ];
+
+    // When
+    let result = execute_call(contract, true);
+
+    // Then
+    assert_eq!(result, Err(BugVariant::CallFrameCorrupted));
+}
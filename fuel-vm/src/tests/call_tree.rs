@@ -0,0 +1,136 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    call_tree::{
+        CallOutcome,
+        CallTree,
+    },
+    prelude::*,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_types::canonical::Serialize;
+
+/// Calls the contract described by the second `Call` of the script data, and
+/// returns.
+fn forwarder() -> Vec<Instruction> {
+    vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x10, 0x10, Call::LEN as u16),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+}
+
+fn leaf(result: Instruction) -> Vec<Instruction> {
+    vec![op::noop(), op::noop(), op::noop(), result]
+}
+
+/// Calls the first contract, which calls the second one, and returns the call tree.
+fn call_tree(contracts: Vec<Vec<Instruction>>, script_calls: usize) -> CallTree {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_ids: Vec<_> = contracts
+        .into_iter()
+        .map(|contract| {
+            test_context
+                .setup_contract(contract, None, None)
+                .contract_id
+        })
+        .collect();
+
+    let mut script = vec![op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData)];
+    for _ in 0..script_calls {
+        script.push(op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS));
+    }
+    script.push(op::ret(RegId::ONE));
+
+    let script_data = contract_ids
+        .iter()
+        .flat_map(|id| Call::new(*id, 0, 0).to_bytes())
+        .collect();
+
+    let builder = test_context.start_script(script, script_data);
+    builder.script_gas_limit(1_000_000).fee_input();
+    for id in &contract_ids {
+        builder.contract_input(*id).contract_output(id);
+    }
+    let tx = builder.build();
+
+    let (_, call_tree) = test_context
+        .execute_tx_with_call_tree(tx)
+        .expect("expected vm execution");
+    call_tree
+}
+
+#[test]
+fn call_tree__nests_calls_by_depth() {
+    // When
+    let tree = call_tree(vec![forwarder(), leaf(op::ret(RegId::ONE))], 2);
+
+    // Then
+    let calls = tree.calls();
+    assert_eq!(calls.len(), 2);
+    for call in calls {
+        assert_eq!(call.depth, 1);
+        assert_eq!(call.outcome, CallOutcome::Returned);
+        assert_eq!(call.children.len(), 1);
+
+        let child = &call.children[0];
+        assert_eq!(child.depth, 2);
+        assert_eq!(child.outcome, CallOutcome::Returned);
+        assert!(child.children.is_empty());
+        assert!(child.gas_used > 0);
+        assert!(child.gas_used <= child.gas_forwarded);
+        assert!(call.gas_used > child.gas_used);
+        assert!(call.gas_used <= call.gas_forwarded);
+    }
+    assert_eq!(
+        tree.iter().map(|call| call.depth).collect::<Vec<_>>(),
+        [1, 2, 1, 2]
+    );
+}
+
+#[test]
+fn call_tree__marks_enclosing_calls_of_revert_as_reverted() {
+    // When
+    let tree = call_tree(vec![forwarder(), leaf(op::rvrt(RegId::ONE))], 1);
+
+    // Then
+    let calls: Vec<_> = tree.iter().collect();
+    assert_eq!(calls.len(), 2);
+    assert!(calls
+        .iter()
+        .all(|call| call.outcome == CallOutcome::Reverted));
+    assert!(calls[1].gas_used > 0);
+    assert!(calls[0].gas_used > calls[1].gas_used);
+}
+
+#[test]
+fn call_tree__marks_enclosing_calls_of_panic_as_panicked() {
+    // When
+    let tree = call_tree(
+        vec![forwarder(), leaf(op::div(0x10, RegId::ONE, RegId::ZERO))],
+        1,
+    );
+
+    // Then
+    let outcomes: Vec<_> = tree.iter().map(|call| call.outcome).collect();
+    assert_eq!(outcomes, [CallOutcome::Panicked, CallOutcome::Panicked]);
+}
+
+#[test]
+fn call_tree__is_empty_without_calls() {
+    // When
+    let tree = call_tree(vec![], 0);
+
+    // Then
+    assert!(tree.is_empty());
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "std")]
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use crate::{
+    consts::VM_MAX_RAM,
+    interpreter::{
+        CheckpointReceiver,
+        ExecutionCheckpoint,
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+
+#[derive(Clone, Default)]
+struct CollectedCheckpoints(Arc<Mutex<Vec<ExecutionCheckpoint>>>);
+
+impl CheckpointReceiver for CollectedCheckpoints {
+    fn on_checkpoint(&self, checkpoint: &ExecutionCheckpoint) {
+        self.0.lock().unwrap().push(checkpoint.clone());
+    }
+}
+
+fn execute(
+    script: Vec<Instruction>,
+    checkpoint_interval: Option<u64>,
+) -> Vec<ExecutionCheckpoint> {
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let checkpoints = CollectedCheckpoints::default();
+    let mut interpreter = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            checkpoint_interval,
+            ..Default::default()
+        },
+    );
+    interpreter.with_checkpoint_receiver(checkpoints.clone());
+    let mut transactor = Transactor::from(interpreter);
+    transactor.transact(tx);
+    assert!(!transactor.is_reverted());
+
+    let checkpoints = checkpoints.0.lock().unwrap().clone();
+    checkpoints
+}
+
+fn heap_writes() -> Vec<Instruction> {
+    vec![
+        op::movi(0x10, 8),
+        op::aloc(0x10),
+        op::movi(0x11, 42),
+        op::sw(RegId::HP, 0x11, 0),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::noop(),
+        op::ret(RegId::ONE),
+    ]
+}
+
+#[test]
+fn checkpoint__is_taken_every_interval_instructions() {
+    // Given
+    let script = heap_writes();
+
+    // When
+    let checkpoints = execute(script, Some(2));
+
+    // Then
+    assert_eq!(
+        checkpoints
+            .iter()
+            .map(|checkpoint| checkpoint.executed_instructions)
+            .collect::<Vec<_>>(),
+        [2, 4, 6]
+    );
+    assert_eq!(checkpoints[1].registers[0x11], 42);
+    assert_eq!(checkpoints[2].receipts_count, 1);
+}
+
+#[test]
+fn checkpoint__reports_only_the_pages_modified_since_the_previous_one() {
+    // Given
+    let script = heap_writes();
+
+    // When
+    let checkpoints = execute(script, Some(2));
+
+    // Then
+    // The first checkpoint includes the transaction on the stack.
+    assert!(!checkpoints[0].dirty_pages.is_empty());
+    let heap_page = &checkpoints[1].dirty_pages[..];
+    assert_eq!(heap_page.len(), 1);
+    assert_eq!(heap_page[0].address, VM_MAX_RAM - 8);
+    assert_eq!(heap_page[0].data, 42u64.to_be_bytes());
+    assert!(checkpoints[2].dirty_pages.is_empty());
+}
+
+#[test]
+fn checkpoint__is_not_taken_without_interval() {
+    // Given
+    let script = heap_writes();
+
+    // When
+    let checkpoints = execute(script, None);
+
+    // Then
+    assert!(checkpoints.is_empty());
+}
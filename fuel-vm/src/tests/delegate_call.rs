@@ -0,0 +1,177 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+    storage::ContractsStateData,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    Flags,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    Receipt,
+    Script,
+    ScriptExecutionResult,
+};
+use fuel_types::{
+    canonical::Serialize,
+    Bytes32,
+};
+
+fn set_delegate_call() -> Vec<Instruction> {
+    vec![
+        op::movi(0x20, Flags::DELEGATE_CALL.bits() as u32),
+        op::flag(0x20),
+    ]
+}
+
+/// Delegates to the contract described by the second `Call` of the script data.
+fn proxy() -> Vec<Instruction> {
+    let mut code = set_delegate_call();
+    code.extend([
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x10, 0x10, Call::LEN as u16),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]);
+    code
+}
+
+/// Writes one to the storage slot at the key zero.
+fn implementation() -> Vec<Instruction> {
+    vec![
+        op::movi(0x11, Bytes32::LEN as u32),
+        op::aloc(0x11),
+        op::sww(RegId::HP, 0x10, RegId::ONE),
+        op::ret(RegId::ONE),
+    ]
+}
+
+/// Calls the first contract with the script, passing the `Call`s of both contracts
+/// in the script data.
+fn execute(
+    mut script: Vec<Instruction>,
+    delegate_calls: bool,
+) -> (Vec<Receipt>, MemoryStorage, ContractId, ContractId) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let proxy_id = test_context.setup_contract(proxy(), None, None).contract_id;
+    let implementation_id = test_context
+        .setup_contract(implementation(), None, None)
+        .contract_id;
+
+    script.extend([
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]);
+    let script_data = [proxy_id, implementation_id]
+        .iter()
+        .flat_map(|id| Call::new(*id, 0, 0).to_bytes())
+        .collect();
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(proxy_id)
+        .contract_output(&proxy_id)
+        .contract_input(implementation_id)
+        .contract_output(&implementation_id)
+        .fee_input()
+        .build();
+
+    let params = InterpreterParams {
+        delegate_calls,
+        ..Default::default()
+    };
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        params,
+    );
+    transactor.transact(tx);
+
+    let receipts = transactor
+        .result()
+        .expect("expected successful vm execution")
+        .receipts()
+        .to_vec();
+    let storage = AsRef::<MemoryStorage>::as_ref(&transactor).clone();
+    (receipts, storage, proxy_id, implementation_id)
+}
+
+fn panic_reason(receipts: &[Receipt]) -> Option<PanicReason> {
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    })
+}
+
+#[test]
+fn delegate_call__writes_to_the_storage_of_the_caller() {
+    // Given
+    let script = vec![];
+
+    // When
+    let (receipts, storage, proxy_id, implementation_id) = execute(script, true);
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+    let mut expected = [0u8; Bytes32::LEN];
+    expected[..8].copy_from_slice(&1u64.to_be_bytes());
+    let key = Bytes32::zeroed();
+    assert_eq!(
+        storage.contract_state(&proxy_id, &key).into_owned(),
+        ContractsStateData::from(expected.as_ref())
+    );
+    assert_eq!(
+        storage
+            .contract_state(&implementation_id, &key)
+            .into_owned(),
+        ContractsStateData::default()
+    );
+}
+
+#[test]
+fn delegate_call__flag_is_rejected_when_disabled() {
+    // Given
+    let script = vec![];
+
+    // When
+    let (receipts, ..) = execute(script, false);
+
+    // Then
+    assert_eq!(panic_reason(&receipts), Some(PanicReason::InvalidFlags));
+}
+
+#[test]
+fn delegate_call__requires_a_calling_contract() {
+    // Given
+    let script = set_delegate_call();
+
+    // When
+    let (receipts, ..) = execute(script, true);
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::ExpectedInternalContext)
+    );
+}
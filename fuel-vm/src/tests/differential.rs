@@ -0,0 +1,198 @@
+#![cfg(feature = "test-helpers")]
+#![allow(non_snake_case)]
+
+use alloc::{
+    string::ToString,
+    vec,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    differential::{
+        compare,
+        execute,
+        DifferentialExecutor,
+        Divergence,
+        ExecutionTrace,
+        InterpreterExecutor,
+        StorageWrite,
+    },
+    interpreter::InterpreterParams,
+    prelude::*,
+    script_with_data_offset,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_types::canonical::Serialize;
+
+/// A script calling the contract, which stores `0xbeef` into the zero slot.
+fn storing_call() -> (TestBuilder, Checked<Script>, ContractId) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(
+            vec![
+                op::movi(0x10, 0xbeef),
+                op::movi(0x12, 32),
+                op::aloc(0x12),
+                op::sww(RegId::HP, 0x11, 0x10),
+                op::ret(RegId::ONE),
+            ],
+            None,
+            None,
+        )
+        .contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let base_asset_id = *test_context.get_base_asset_id();
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .coin_input(base_asset_id, 1_000_000)
+        .contract_input(contract_id)
+        .change_output(base_asset_id)
+        .contract_output(&contract_id)
+        .build();
+
+    (test_context, tx, contract_id)
+}
+
+fn executor(test_context: &TestBuilder) -> InterpreterExecutor {
+    InterpreterExecutor::new(InterpreterParams::new(
+        0,
+        test_context.get_consensus_params(),
+    ))
+}
+
+#[test]
+fn execute__same_interpreters_agree() {
+    // Given
+    let (test_context, tx, contract_id) = storing_call();
+    let storage = test_context.get_storage().clone();
+
+    // When
+    let trace = execute(
+        &mut executor(&test_context),
+        &mut executor(&test_context),
+        tx,
+        storage,
+    )
+    .expect("The executions agree");
+
+    // Then
+    assert_eq!(trace.error, None);
+    assert!(matches!(
+        trace.receipts.last(),
+        Some(Receipt::ScriptResult { .. })
+    ));
+    let mut value = vec![0; 32];
+    value[..8].copy_from_slice(&0xbeefu64.to_be_bytes());
+    assert!(trace.storage_writes.contains(&StorageWrite::Slot {
+        contract_id,
+        key: Bytes32::zeroed(),
+        value: Some(value),
+    }));
+}
+
+#[test]
+fn execute__different_gas_costs_diverge_at_the_forwarded_gas() {
+    // Given
+    let (test_context, tx, _) = storing_call();
+    let storage = test_context.get_storage().clone();
+    let mut free = InterpreterParams::new(0, test_context.get_consensus_params());
+    free.gas_costs = GasCosts::free();
+
+    // When
+    let divergence = execute(
+        &mut executor(&test_context),
+        &mut InterpreterExecutor::new(free),
+        tx,
+        storage,
+    )
+    .expect_err("The gas used differs");
+
+    // Then
+    let Divergence::Receipt { index, left, right } = *divergence else {
+        panic!("Expected a receipt divergence, got {divergence}");
+    };
+    assert_eq!(index, 0);
+    assert_ne!(left.unwrap().gas(), right.unwrap().gas());
+}
+
+#[test]
+fn execute__reports_the_diverging_storage_write_of_a_wrapped_executor() {
+    // Given
+    let (test_context, tx, contract_id) = storing_call();
+    let storage = test_context.get_storage().clone();
+    let mut inner = executor(&test_context);
+    let mut tampering = |tx, storage| {
+        let mut trace = inner.execute(tx, storage);
+        trace.storage_writes[0] = StorageWrite::Slot {
+            contract_id,
+            key: Bytes32::zeroed(),
+            value: None,
+        };
+        trace
+    };
+
+    // When
+    let divergence = execute(&mut executor(&test_context), &mut tampering, tx, storage)
+        .expect_err("The storage writes differ");
+
+    // Then
+    assert!(matches!(
+        *divergence,
+        Divergence::StorageWrite {
+            index: 0,
+            right: Some(StorageWrite::Slot { value: None, .. }),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn compare__reports_registers_before_the_error() {
+    // Given
+    let left = ExecutionTrace {
+        receipts: vec![],
+        registers: vec![0, 1, 2],
+        storage_writes: vec![],
+        error: None,
+    };
+    let mut right = left.clone();
+    right.registers[2] = 3;
+    right.error = Some("error".to_string());
+
+    // When
+    let divergence = compare(&left, &right);
+
+    // Then
+    assert_eq!(
+        divergence,
+        Some(Divergence::Register {
+            register: RegId::new(2),
+            left: 2,
+            right: 3,
+        })
+    );
+    right.registers[2] = 2;
+    assert_eq!(
+        compare(&left, &right),
+        Some(Divergence::Error {
+            left: None,
+            right: Some("error".to_string()),
+        })
+    );
+    assert_eq!(compare(&left, &left), None);
+}
@@ -0,0 +1,146 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    string::String,
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use core::time::Duration;
+
+use crate::{
+    error::{
+        ExecutionLimit,
+        InterpreterError,
+    },
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    Receipt,
+    ScriptExecutionResult,
+};
+
+fn execute(
+    script: Vec<Instruction>,
+    params: InterpreterParams,
+) -> Result<Vec<Receipt>, InterpreterError<String>> {
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        params,
+    );
+    transactor.transact(tx);
+
+    transactor
+        .result()
+        .map(|state| state.receipts().to_vec())
+        .map_err(|error| error.erase_generics())
+}
+
+/// Loops forever, without ever running out of gas.
+fn infinite_loop() -> (Vec<Instruction>, InterpreterParams) {
+    let params = InterpreterParams {
+        gas_costs: GasCosts::free(),
+        ..Default::default()
+    };
+    (vec![op::ji(0)], params)
+}
+
+#[test]
+fn execution_limits__script_within_instruction_limit_succeeds() {
+    // Given
+    let script = vec![op::noop(), op::noop(), op::ret(RegId::ONE)];
+    let params = InterpreterParams {
+        max_instructions: Some(3),
+        ..Default::default()
+    };
+
+    // When
+    let receipts = execute(script, params).expect("the script is within the limit");
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn execution_limits__too_many_instructions_abort_execution() {
+    // Given
+    let script = vec![op::noop(), op::noop(), op::ret(RegId::ONE)];
+    let params = InterpreterParams {
+        max_instructions: Some(2),
+        ..Default::default()
+    };
+
+    // When
+    let result = execute(script, params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(InterpreterError::ExecutionLimitExceeded(
+            ExecutionLimit::Instructions
+        ))
+    );
+}
+
+#[test]
+fn execution_limits__instruction_limit_stops_infinite_loop() {
+    // Given
+    let (script, params) = infinite_loop();
+    let params = InterpreterParams {
+        max_instructions: Some(100_000),
+        ..params
+    };
+
+    // When
+    let result = execute(script, params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(InterpreterError::ExecutionLimitExceeded(
+            ExecutionLimit::Instructions
+        ))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn execution_limits__timeout_stops_infinite_loop() {
+    // Given
+    let (script, params) = infinite_loop();
+    let params = InterpreterParams {
+        execution_timeout: Some(Duration::from_millis(10)),
+        ..params
+    };
+
+    // When
+    let result = execute(script, params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(InterpreterError::ExecutionLimitExceeded(
+            ExecutionLimit::Time
+        ))
+    );
+}
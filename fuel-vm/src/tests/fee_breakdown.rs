@@ -0,0 +1,165 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    interpreter::InterpreterParams,
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::{
+        Outputs,
+        Witnesses,
+    },
+    ConsensusParameters,
+    FeeParameters,
+};
+use fuel_types::canonical::Serialize;
+
+const GAS_PRICE: Word = 3;
+const TIP: Word = 7;
+
+fn transact(
+    script: Vec<Instruction>,
+    revert: bool,
+) -> Transactor<MemoryInstance, MemoryStorage, Script> {
+    let mut params = ConsensusParameters::standard();
+    params.set_fee_params(
+        FeeParameters::default()
+            .with_gas_per_byte(2)
+            .with_gas_price_factor(5),
+    );
+    let base_asset_id = *params.base_asset_id();
+
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(10_000)
+        .witness_limit(1_000)
+        .max_fee_limit(1_000_000)
+        .tip(TIP)
+        .with_params(params.clone())
+        .add_fee_input()
+        .add_output(Output::change(Default::default(), 0, base_asset_id))
+        .finalize_checked_basic(Default::default())
+        .into_ready(GAS_PRICE, params.gas_costs(), params.fee_params())
+        .expect("failed to compute the fees");
+
+    let mut transactor = Transactor::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::new(GAS_PRICE, &params),
+    );
+    transactor.transact_ready_tx(tx);
+    assert_eq!(transactor.is_reverted(), revert);
+    transactor
+}
+
+fn change(transactor: &Transactor<MemoryInstance, MemoryStorage, Script>) -> Word {
+    let state = transactor
+        .state_transition()
+        .expect("no transaction executed");
+    state
+        .tx()
+        .outputs()
+        .iter()
+        .find_map(|output| match output {
+            Output::Change { amount, .. } => Some(*amount),
+            _ => None,
+        })
+        .expect("missing change output")
+}
+
+#[test]
+fn fee_breakdown__matches_the_refund_in_the_change_output() {
+    // Given
+    let script = vec![
+        op::movi(0x10, 5),
+        op::addi(0x10, 0x10, 1),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let transactor = transact(script, false);
+
+    // Then
+    let breakdown = transactor.fee_breakdown().expect("missing fee breakdown");
+    let gas_used = transactor
+        .receipts()
+        .unwrap()
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(breakdown.execution_gas, gas_used);
+    assert_eq!(breakdown.gas_price, GAS_PRICE);
+    assert_eq!(breakdown.gas_price_factor, 5);
+    assert_eq!(breakdown.tip, TIP);
+    assert_eq!(breakdown.max_fee_limit, 1_000_000);
+    assert_eq!(
+        breakdown.used_fee,
+        (breakdown.used_gas() * GAS_PRICE).div_ceil(5) + TIP
+    );
+    assert_eq!(
+        breakdown.refund,
+        breakdown.max_fee_limit - breakdown.used_fee
+    );
+    assert_eq!(change(&transactor), u32::MAX as Word - breakdown.used_fee);
+}
+
+#[test]
+fn fee_breakdown__separates_witness_gas_from_the_unused_witness_limit() {
+    // Given
+    let script = vec![op::ret(RegId::ONE)];
+
+    // When
+    let transactor = transact(script, false);
+
+    // Then
+    let breakdown = transactor.fee_breakdown().expect("missing fee breakdown");
+    let witnesses_size = transactor
+        .interpreter()
+        .transaction()
+        .witnesses()
+        .size_dynamic() as Word;
+    assert_eq!(breakdown.witness_gas, witnesses_size * 2);
+    assert_eq!(breakdown.unused_witness_gas, (1_000 - witnesses_size) * 2);
+    assert!(breakdown.min_gas > breakdown.witness_gas);
+}
+
+#[test]
+fn fee_breakdown__is_reported_for_reverted_scripts() {
+    // Given
+    let script = vec![op::rvrt(RegId::ONE)];
+
+    // When
+    let transactor = transact(script, true);
+
+    // Then
+    let breakdown = transactor.fee_breakdown().expect("missing fee breakdown");
+    assert!(breakdown.execution_gas > 0);
+    assert_eq!(change(&transactor), u32::MAX as Word - breakdown.used_fee);
+}
+
+#[test]
+fn fee_breakdown__is_none_without_executed_transaction() {
+    // Given
+    let transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+    );
+
+    // When
+    let breakdown = transactor.fee_breakdown();
+
+    // Then
+    assert_eq!(breakdown, None);
+}
@@ -0,0 +1,46 @@
+use alloc::vec;
+
+use crate::{
+    interpreter::{
+        CowMemory,
+        InterpreterParams,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+
+#[test]
+fn fork_diverges_independently_of_the_original() {
+    let mut interpreter = Interpreter::<_, _, Script>::with_storage(
+        CowMemory::default(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+    );
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize_checked(Default::default())
+    .into_ready(0, interpreter.gas_costs(), interpreter.fee_params())
+    .unwrap();
+    interpreter.init_script(tx).unwrap();
+    interpreter.instruction(op::movi(0x10, 32)).unwrap();
+    interpreter.instruction(op::aloc(0x10)).unwrap();
+    interpreter.instruction(op::movi(0x11, 1)).unwrap();
+    interpreter.instruction(op::sb(RegId::HP, 0x11, 0)).unwrap();
+
+    let mut fork = interpreter.fork();
+    fork.instruction(op::movi(0x11, 2)).unwrap();
+    fork.instruction(op::sb(RegId::HP, 0x11, 0)).unwrap();
+
+    let hp = interpreter.registers()[RegId::HP];
+    assert_eq!(interpreter.registers()[0x11], 1);
+    assert_eq!(fork.registers()[0x11], 2);
+    assert_eq!(interpreter.memory().read_bytes(hp), Ok([1u8]));
+    assert_eq!(fork.memory().read_bytes(hp), Ok([2u8]));
+}
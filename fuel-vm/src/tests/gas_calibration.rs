@@ -0,0 +1,107 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::gas_calibration::{
+    calibrate,
+    default_cases,
+    CalibrationCase,
+    CalibrationConfig,
+    OpcodeMeasurement,
+};
+use fuel_asm::op;
+use fuel_tx::{
+    consensus_parameters::gas::GasCostsValuesV5,
+    ConsensusParameters,
+    GasCostsValues,
+};
+
+fn config() -> CalibrationConfig {
+    CalibrationConfig {
+        repetitions: 16,
+        samples: 3,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn calibrate__measures_gas_charged_per_instruction() {
+    // Given
+    let mut costs = GasCostsValuesV5::unit();
+    costs.add = 3;
+    costs.mul = 5;
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_gas_costs(GasCostsValues::from(costs).into());
+    let config = CalibrationConfig {
+        consensus_params,
+        ..config()
+    };
+    let cases: Vec<_> = default_cases()
+        .into_iter()
+        .filter(|case| ["add", "mul", "noop"].contains(&case.operation))
+        .collect();
+
+    // When
+    let report = calibrate(&cases, &config);
+
+    // Then
+    let gas: Vec<_> = report
+        .measurements
+        .iter()
+        .map(|measurement| (measurement.operation, measurement.gas))
+        .collect();
+    assert_eq!(gas, vec![("add", 3), ("mul", 5), ("noop", 1)]);
+}
+
+#[test]
+fn calibrate__reference_keeps_its_cost() {
+    // Given
+    let cases = vec![
+        CalibrationCase::new("noop", op::noop()),
+        CalibrationCase::new("movi", op::movi(0x10, 1)),
+    ];
+
+    // When
+    let report = calibrate(&cases, &config());
+
+    // Then
+    let suggested = report.suggested_gas_costs().unwrap();
+    assert_eq!(suggested.noop(), 1);
+    assert_eq!(
+        suggested.movi(),
+        report.suggested_cost(&report.measurements[1])
+    );
+}
+
+#[test]
+fn suggested_diff__lists_changed_costs() {
+    // Given
+    let measurement = |operation, gas, median_nanos| OpcodeMeasurement {
+        operation,
+        gas,
+        min_nanos: median_nanos,
+        median_nanos,
+        mean_nanos: median_nanos,
+    };
+    let mut report = calibrate(&[], &config());
+    report.nanos_per_gas = 2.0;
+    report.measurements = vec![
+        measurement("noop", 1, 2.0),
+        measurement("add", 1, 6.0),
+        measurement("mul", 1, 0.1),
+    ];
+
+    // When
+    let diff = report.suggested_diff();
+
+    // Then
+    let changes: Vec<_> = diff
+        .changes
+        .iter()
+        .map(|change| (change.operation, change.old, change.new))
+        .collect();
+    assert_eq!(changes, vec![("add", Some(1), Some(3))]);
+}
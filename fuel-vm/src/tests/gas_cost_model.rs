@@ -0,0 +1,102 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use core::sync::atomic::{
+    AtomicU64,
+    Ordering,
+};
+
+use crate::{
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    GasCostModel,
+    GasCostsValues,
+    Receipt,
+    SharedGasCostModel,
+};
+use fuel_types::Word;
+
+/// Free costs, except for `NOOP` whose cost grows with every executed `NOOP`.
+#[derive(Debug)]
+struct IncreasingNoopCost {
+    base: GasCostsValues,
+    executed_noops: AtomicU64,
+}
+
+impl GasCostModel for IncreasingNoopCost {
+    fn base_costs(&self) -> &GasCostsValues {
+        &self.base
+    }
+
+    fn noop(&self) -> Word {
+        self.executed_noops
+            .fetch_add(1, Ordering::Relaxed)
+            .saturating_add(1)
+    }
+}
+
+fn script_gas_used(gas_cost_model: Option<SharedGasCostModel>) -> Word {
+    let script: Vec<u8> = [op::noop(), op::noop(), op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let params = InterpreterParams {
+        gas_costs: GasCosts::free(),
+        gas_cost_model,
+        ..Default::default()
+    };
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        params,
+    );
+    transactor.transact(tx);
+
+    let receipts = transactor
+        .receipts()
+        .expect("expected successful vm execution");
+    match receipts.last() {
+        Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+        _ => panic!("Expected a script result"),
+    }
+}
+
+#[test]
+fn gas_cost_model__gas_costs_are_used_without_a_model() {
+    // When
+    let gas_used = script_gas_used(None);
+
+    // Then
+    assert_eq!(gas_used, 0);
+}
+
+#[test]
+fn gas_cost_model__instructions_are_charged_by_the_model() {
+    // Given
+    let model = SharedGasCostModel::new(IncreasingNoopCost {
+        base: GasCostsValues::free(),
+        executed_noops: AtomicU64::new(0),
+    });
+
+    // When
+    let gas_used = script_gas_used(Some(model));
+
+    // Then
+    assert_eq!(gas_used, 1 + 2 + 3);
+}
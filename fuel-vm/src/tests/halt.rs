@@ -0,0 +1,217 @@
+#![cfg(feature = "std")]
+#![allow(non_snake_case)]
+
+use alloc::{
+    string::String,
+    vec,
+    vec::Vec,
+};
+use core::time::Duration;
+use std::thread;
+
+use crate::{
+    checked_transaction::Checked,
+    consts::*,
+    error::{
+        ExecutionLimit,
+        InterpreterError,
+    },
+    interpreter::{
+        HaltDump,
+        HaltHandle,
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    GTFArgs,
+    RegId,
+};
+
+/// Executes the transaction, requesting the halt from another thread once it runs.
+fn execute_and_halt(
+    tx: Checked<Script>,
+    storage: MemoryStorage,
+    params: InterpreterParams,
+) -> (Result<(), InterpreterError<String>>, Option<HaltDump>) {
+    let params = InterpreterParams {
+        // Stops the test if the halt isn't honored.
+        execution_timeout: Some(Duration::from_secs(30)),
+        ..params
+    };
+    let handle = HaltHandle::new();
+    let mut interpreter =
+        Interpreter::<_, _, Script>::with_storage(MemoryInstance::new(), storage, params);
+    interpreter.with_halt_handle(handle.clone());
+
+    let requester = {
+        let handle = handle.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            handle.request_halt();
+        })
+    };
+    let mut transactor = Transactor::from(interpreter);
+    transactor.transact(tx);
+    requester.join().unwrap();
+
+    let result = transactor
+        .result()
+        .map(|_| ())
+        .map_err(|error| error.erase_generics());
+    (result, handle.take_dump())
+}
+
+#[test]
+fn halt__stops_an_infinite_loop_in_the_script_with_a_dump() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_free_gas_costs();
+    let script = vec![op::movi(0x10, 0xbeef), op::ji(1)];
+    let tx = test_context
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let params = InterpreterParams::new(0, test_context.get_consensus_params());
+
+    // When
+    let (result, dump) = execute_and_halt(tx, test_context.get_storage().clone(), params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(InterpreterError::ExecutionLimitExceeded(
+            ExecutionLimit::HaltRequested
+        ))
+    );
+    let dump = dump.expect("The dump is stored into the handle");
+    assert!(dump.call_stack.is_empty());
+    assert_eq!(dump.contract, None);
+    assert_eq!(dump.registers[0x10], 0xbeef);
+    assert_eq!(
+        dump.registers[RegId::PC.to_u8() as usize]
+            - dump.registers[RegId::IS.to_u8() as usize],
+        Instruction::SIZE as Word
+    );
+    assert!(dump.to_string().contains("$r16 = 0xbeef"));
+}
+
+#[test]
+fn halt__stops_an_infinite_loop_in_a_contract() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_free_gas_costs();
+    let contract_id = test_context
+        .setup_contract(vec![op::ji(0)], None, None)
+        .contract_id;
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data: Vec<u8> = contract_id
+        .iter()
+        .copied()
+        .chain([0u8; WORD_SIZE * 2])
+        .collect();
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+    let params = InterpreterParams::new(0, test_context.get_consensus_params());
+
+    // When
+    let (result, dump) = execute_and_halt(tx, test_context.get_storage().clone(), params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(InterpreterError::ExecutionLimitExceeded(
+            ExecutionLimit::HaltRequested
+        ))
+    );
+    let dump = dump.expect("The dump is stored into the handle");
+    assert_eq!(dump.contract, Some(contract_id));
+    assert_eq!(
+        dump.call_stack
+            .iter()
+            .map(|frame| *frame.to())
+            .collect::<Vec<_>>(),
+        [contract_id]
+    );
+    assert!(dump.memory.stack_size > 0);
+    assert_eq!(
+        dump.memory.stack_size + dump.memory.free_size + dump.memory.heap_size,
+        VM_MAX_RAM
+    );
+}
+
+#[test]
+fn halt__pending_request_stops_the_next_execution_at_its_first_instruction() {
+    // Given
+    let handle = HaltHandle::new();
+    handle.request_halt();
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize_checked(Default::default());
+    let mut interpreter = Interpreter::<_, _, Script>::with_memory_storage();
+    interpreter.with_halt_handle(handle.clone());
+
+    // When
+    let result = Transactor::from(interpreter)
+        .transact(tx)
+        .result()
+        .map(|_| ())
+        .map_err(|e| e.erase_generics());
+
+    // Then
+    assert_eq!(
+        result,
+        Err(InterpreterError::ExecutionLimitExceeded(
+            ExecutionLimit::HaltRequested
+        ))
+    );
+    assert!(!handle.is_halt_requested());
+    assert!(handle.take_dump().is_some());
+    assert!(handle.take_dump().is_none());
+}
+
+#[test]
+fn halt__without_request_the_execution_completes() {
+    // Given
+    let handle = HaltHandle::new();
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize_checked(Default::default());
+    let mut interpreter = Interpreter::<_, _, Script>::with_memory_storage();
+    interpreter.with_halt_handle(handle.clone());
+
+    // When
+    let mut transactor = Transactor::from(interpreter);
+    transactor.transact(tx);
+
+    // Then
+    assert!(transactor.is_success());
+    assert!(handle.take_dump().is_none());
+}
+
+#[cfg(feature = "halt-on-signal")]
+#[test]
+fn halt_on_signal__unhandleable_signal_is_rejected() {
+    // SIGKILL
+    assert!(HaltHandle::new().halt_on_signal(9).is_err());
+}
@@ -503,3 +503,23 @@ fn test_heap_allocation_zeroes_memory() {
         panic!("Expected return receipt");
     }
 }
+
+#[test]
+fn test_memory_stats_record_high_water_marks() {
+    let script = vec![
+        op::cfei(100),
+        op::movi(0x10, 64),
+        op::aloc(0x10),
+        op::cfsi(100),
+        op::ret(RegId::ONE),
+    ];
+    let vm = setup(script);
+    let registers = vm.interpreter().registers();
+
+    let stats = vm.memory_stats();
+    assert_eq!(stats.max_sp, registers[RegId::SP] + 100);
+    assert_eq!(stats.min_hp, registers[RegId::HP]);
+    assert_eq!(stats.max_heap_size(), 64);
+    assert_eq!(stats.memory_grown, 164);
+    assert_eq!(stats.headroom(), VM_MAX_RAM - 64 - stats.max_sp);
+}
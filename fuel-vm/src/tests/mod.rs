@@ -8,31 +8,57 @@ use tokio_rayon as _;
 mod test_helpers;
 
 mod alu;
+mod assert;
 mod backtrace;
 mod blob;
 mod blockchain;
+mod call_depth;
+mod call_tree;
+mod call_frame_integrity;
 mod cgas;
+mod checkpoint;
 mod code_coverage;
 mod coins;
 mod contract;
 mod crypto;
+#[cfg(feature = "delegate-call")]
+mod delegate_call;
+mod differential;
 mod encoding;
+mod execution_limits;
 mod external;
+mod fee_breakdown;
 mod flow;
+mod fork;
+#[cfg(feature = "gas-calibration")]
+mod gas_calibration;
+mod gas_cost_model;
 mod gas_factor;
+mod halt;
 mod jump_absolute;
 mod jump_relative;
 mod limits;
 mod log;
 mod memory;
 mod metadata;
+mod opcode_filter;
 mod outputs;
 mod predicate;
 mod profile_gas;
+mod program_generator;
+mod progress;
 mod receipts;
+mod return_data;
 mod serde_profile;
 mod spec;
+#[cfg(feature = "static-call")]
+mod static_call;
+mod strict_decoding;
+#[cfg(feature = "test-vectors")]
+mod test_vectors;
+mod tracing;
 mod upgrade;
 mod upload;
 mod validation;
+mod vm_metrics;
 mod wideint;
@@ -0,0 +1,122 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+        OpcodeFilter,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    Opcode,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    Receipt,
+    ScriptExecutionResult,
+};
+
+fn execute(script: Vec<Instruction>, opcode_filter: OpcodeFilter) -> Vec<Receipt> {
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+    let params = InterpreterParams {
+        opcode_filter,
+        ..Default::default()
+    };
+
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        params,
+    );
+    transactor.transact(tx);
+
+    transactor
+        .result()
+        .expect("the script is executed")
+        .receipts()
+        .to_vec()
+}
+
+fn panic_reason(receipts: &[Receipt]) -> Option<PanicReason> {
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    })
+}
+
+fn script() -> Vec<Instruction> {
+    vec![
+        op::movi(0x10, 1),
+        op::add(0x10, 0x10, 0x10),
+        op::ret(RegId::ONE),
+    ]
+}
+
+#[test]
+fn opcode_filter__allowed_opcodes_are_executed() {
+    // Given
+    let filter = OpcodeFilter::allow([Opcode::MOVI, Opcode::ADD, Opcode::RET]);
+
+    // When
+    let receipts = execute(script(), filter);
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn opcode_filter__opcode_missing_in_allowlist_panics() {
+    // Given
+    let filter = OpcodeFilter::allow([Opcode::MOVI, Opcode::RET]);
+
+    // When
+    let receipts = execute(script(), filter);
+
+    // Then
+    assert_eq!(panic_reason(&receipts), Some(PanicReason::OpcodeDisabled));
+}
+
+#[test]
+fn opcode_filter__denied_opcode_panics() {
+    // Given
+    let filter = OpcodeFilter::deny([Opcode::ECAL]);
+    let script = vec![
+        op::ecal(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = execute(script, filter);
+
+    // Then
+    assert_eq!(panic_reason(&receipts), Some(PanicReason::OpcodeDisabled));
+}
+
+#[test]
+fn opcode_filter__denylist_allows_other_opcodes() {
+    // Given
+    let filter = OpcodeFilter::deny([Opcode::ECAL, Opcode::CALL]);
+
+    // When
+    let receipts = execute(script(), filter);
+
+    // Then
+    assert_eq!(panic_reason(&receipts), None);
+}
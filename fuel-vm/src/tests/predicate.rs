@@ -7,7 +7,10 @@ use fuel_asm::{
     Instruction,
     RegId,
 };
-use fuel_tx::TransactionBuilder;
+use fuel_tx::{
+    field::Maturity,
+    TransactionBuilder,
+};
 use rand::{
     rngs::StdRng,
     Rng,
@@ -608,3 +611,156 @@ fn synchronous_estimate_predicates_respects_total_tx_gas_limit() {
     // Then
     assert_eq!(Ok(()), result);
 }
+
+#[test]
+fn caching_estimator_matches_estimate_predicates() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let params = CheckPredicateParams::default();
+
+    let predicate: Vec<u8> = vec![
+        op::addi(0x10, 0x00, 0x01),
+        op::addi(0x10, 0x10, 0x01),
+        op::ret(0x01),
+    ]
+    .into_iter()
+    .collect();
+    let owner = Input::predicate_owner(&predicate);
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(1000);
+    for data in [vec![1u8], vec![1u8], vec![2u8], vec![1u8]] {
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            1000,
+            AssetId::default(),
+            rng.gen(),
+            0,
+            predicate.clone(),
+            data,
+        ));
+    }
+    let transaction = builder.finalize();
+
+    let mut expected = transaction.clone();
+    expected
+        .estimate_predicates(&params, MemoryInstance::new())
+        .expect("Should estimate predicates");
+
+    // When
+    let mut estimator = crate::predicate::CachingPredicateEstimator::new();
+    let mut cached = transaction.clone();
+    estimator
+        .estimate(&mut cached, &params, MemoryInstance::new())
+        .expect("Should estimate predicates");
+
+    // Then
+    assert_eq!(cached, expected);
+    assert_eq!(estimator.metrics().misses, 2);
+    assert_eq!(estimator.metrics().hits, 2);
+
+    // The cache is reused by the following transactions
+    let mut cached = transaction;
+    estimator
+        .estimate(&mut cached, &params, MemoryInstance::new())
+        .expect("Should estimate predicates");
+    assert_eq!(cached, expected);
+    assert_eq!(estimator.metrics().misses, 2);
+    assert_eq!(estimator.metrics().hits, 6);
+}
+
+#[test]
+fn execute_predicate_reports_gas_used_and_return_receipt() {
+    let predicate: Vec<u8> =
+        vec![op::movi(0x10, 2), op::subi(0x10, 0x10, 1), op::ret(0x10)]
+            .into_iter()
+            .collect();
+    let context = crate::predicate::PredicateContext::default();
+
+    // When
+    let outcome = crate::predicate::execute_predicate(&predicate, &[], &context);
+
+    // Then
+    assert!(outcome.passed());
+    let gas_costs = &context.params.gas_costs;
+    assert_eq!(
+        outcome.gas_used,
+        gas_costs.movi() + gas_costs.subi() + gas_costs.ret()
+    );
+    assert!(matches!(
+        outcome.trace.as_slice(),
+        [Receipt::Return { val: 1, .. }]
+    ));
+}
+
+#[test]
+fn execute_predicate_reports_panic_and_out_of_gas() {
+    let predicate: Vec<u8> = vec![op::ret(RegId::ZERO)].into_iter().collect();
+    let context = crate::predicate::PredicateContext::default();
+    let outcome = crate::predicate::execute_predicate(&predicate, &[], &context);
+    assert_eq!(
+        outcome.result,
+        Err(CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::Panic(PanicReason::PredicateReturnedNonOne)
+        ))
+    );
+    assert!(matches!(
+        outcome.trace.as_slice(),
+        [Receipt::Return { val: 0, .. }, Receipt::Panic { .. }]
+    ));
+
+    // Infinite loop
+    let predicate: Vec<u8> = vec![op::noop(), op::jmpb(RegId::ZERO, 0)]
+        .into_iter()
+        .collect();
+    let context = context.with_gas_limit(1_000);
+    let outcome = crate::predicate::execute_predicate(&predicate, &[], &context);
+    assert_eq!(
+        outcome.result,
+        Err(CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::OutOfGas
+        ))
+    );
+    assert_eq!(outcome.gas_used, 1_000);
+}
+
+#[test]
+fn execute_predicate_at_block_height_checks_time_lock() {
+    const UNLOCK_HEIGHT: u16 = 100;
+    // Spendable only by the transactions with the maturity at or above the height
+    let predicate: Vec<u8> = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::PolicyMaturity),
+        op::movi(0x11, UNLOCK_HEIGHT as u32),
+        op::lt(0x12, 0x10, 0x11),
+        op::eq(0x12, 0x12, RegId::ZERO),
+        op::ret(0x12),
+    ]
+    .into_iter()
+    .collect();
+    let context = crate::predicate::PredicateContext::default();
+
+    let before = context
+        .clone()
+        .with_block_height((UNLOCK_HEIGHT as u32 - 1).into());
+    let outcome = crate::predicate::execute_predicate(&predicate, &[], &before);
+    assert!(!outcome.passed());
+
+    let after = context
+        .clone()
+        .with_block_height((UNLOCK_HEIGHT as u32).into());
+    let outcome = crate::predicate::execute_predicate(&predicate, &[], &after);
+    assert!(outcome.passed());
+
+    // The maturity set by the transaction must be reached by the block height
+    let mut transaction = context.transaction.clone();
+    transaction.set_maturity((UNLOCK_HEIGHT as u32 + 1).into());
+    let outcome = crate::predicate::execute_predicate(
+        &predicate,
+        &[],
+        &after.with_transaction(transaction),
+    );
+    assert_eq!(
+        outcome.result,
+        Err(CheckError::Validity(ValidityError::TransactionMaturity))
+    );
+}
@@ -0,0 +1,110 @@
+#![cfg(all(feature = "random", feature = "test-helpers"))]
+#![allow(non_snake_case)]
+
+use alloc::vec;
+
+use crate::{
+    prelude::*,
+    util::program_generator::{
+        OpcodeGroup,
+        ProgramGenerator,
+    },
+};
+use fuel_asm::Opcode;
+
+fn execute(program: Vec<Instruction>) -> Vec<Receipt> {
+    let tx = TransactionBuilder::script(program.into_iter().collect(), vec![])
+        .script_gas_limit(10_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let mut client = MemoryClient::default();
+    client.transact(tx).to_vec()
+}
+
+fn opcodes(program: &[Instruction]) -> impl Iterator<Item = Opcode> + '_ {
+    program.iter().map(|instruction| instruction.opcode())
+}
+
+#[test]
+fn generate__same_seed_generates_same_programs() {
+    // Given
+    let mut first = ProgramGenerator::new(42);
+    let mut second = ProgramGenerator::new(42);
+
+    // When
+    let first = [first.generate(), first.generate()];
+    let second = [second.generate(), second.generate()];
+
+    // Then
+    assert_eq!(first, second);
+    assert_ne!(first[0], first[1]);
+}
+
+#[test]
+fn generate__programs_run_to_completion() {
+    for seed in 0..64 {
+        // Given
+        let program = ProgramGenerator::new(seed).generate();
+
+        // When
+        let receipts = execute(program);
+
+        // Then
+        let Some(Receipt::ScriptResult { result, .. }) = receipts.last() else {
+            panic!("The script of the seed {seed} didn't finish: {receipts:?}");
+        };
+        assert_eq!(
+            *result,
+            ScriptExecutionResult::Success,
+            "The script of the seed {seed} failed: {receipts:?}"
+        );
+    }
+}
+
+#[test]
+fn generate__loops_execute_more_instructions_than_the_program_has() {
+    // Given
+    let mut generator = ProgramGenerator::new(7)
+        .with_weight(OpcodeGroup::Loop, 10)
+        .with_max_iterations(4);
+    let program = generator.generate();
+    let straight_line = ProgramGenerator::new(7)
+        .with_weight(OpcodeGroup::Branch, 0)
+        .with_weight(OpcodeGroup::Loop, 0)
+        .generate();
+
+    // When
+    let gas_used = |receipts: Vec<Receipt>| match receipts.last() {
+        Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+        _ => panic!("The script didn't finish"),
+    };
+    let looping = gas_used(execute(program.clone()));
+    let straight = gas_used(execute(straight_line.clone()));
+
+    // Then
+    assert!(opcodes(&program).any(|opcode| opcode == Opcode::JNZB));
+    assert!(opcodes(&straight_line).all(|opcode| opcode != Opcode::JNZB));
+    assert!(looping > straight);
+}
+
+#[test]
+fn with_weight__zero_weight_excludes_the_group() {
+    // Given
+    let mut generator = ProgramGenerator::new(3)
+        .with_weight(OpcodeGroup::Arithmetic, 0)
+        .with_weight(OpcodeGroup::Branch, 0)
+        .with_weight(OpcodeGroup::Loop, 0)
+        .with_weight(OpcodeGroup::Log, 0)
+        .with_len(32);
+
+    // When
+    let program = generator.generate();
+
+    // Then
+    let body = &program[program.len() - 33..program.len() - 1];
+    assert!(opcodes(body).all(|opcode| matches!(
+        opcode,
+        Opcode::SW | Opcode::LW | Opcode::SB | Opcode::LB | Opcode::MCPI | Opcode::MCLI
+    )));
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "std")]
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use crate::{
+    interpreter::{
+        ExecutionProgress,
+        InterpreterParams,
+        MemoryInstance,
+        ProgressWatcher,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+
+#[derive(Clone, Default)]
+struct CollectedProgress(Arc<Mutex<Vec<ExecutionProgress>>>);
+
+impl ProgressWatcher for CollectedProgress {
+    fn on_progress(&self, progress: &ExecutionProgress) {
+        self.0.lock().unwrap().push(*progress);
+    }
+}
+
+const SCRIPT_GAS_LIMIT: Word = 1_000_000;
+
+fn execute(
+    script: Vec<Instruction>,
+    progress_stride: Option<u64>,
+) -> Vec<ExecutionProgress> {
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(SCRIPT_GAS_LIMIT)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let progress = CollectedProgress::default();
+    let mut interpreter = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            progress_stride,
+            ..Default::default()
+        },
+    );
+    interpreter.with_progress_watcher(progress.clone());
+    let mut transactor = Transactor::from(interpreter);
+    transactor.transact(tx);
+    assert!(!transactor.is_reverted());
+
+    let progress = progress.0.lock().unwrap().clone();
+    progress
+}
+
+fn seven_instructions() -> Vec<Instruction> {
+    vec![
+        op::noop(),
+        op::noop(),
+        op::noop(),
+        op::noop(),
+        op::noop(),
+        op::noop(),
+        op::ret(RegId::ONE),
+    ]
+}
+
+#[test]
+fn progress__is_notified_every_stride_instructions() {
+    // Given
+    let script = seven_instructions();
+
+    // When
+    let progress = execute(script, Some(3));
+
+    // Then
+    assert_eq!(
+        progress
+            .iter()
+            .map(|progress| progress.retired_instructions)
+            .collect::<Vec<_>>(),
+        [3, 6]
+    );
+}
+
+#[test]
+fn progress__reports_decreasing_remaining_gas() {
+    // Given
+    let script = seven_instructions();
+
+    // When
+    let progress = execute(script, Some(1));
+
+    // Then
+    assert_eq!(progress.len(), 7);
+    assert!(progress
+        .windows(2)
+        .all(|pair| pair[1].remaining_gas < pair[0].remaining_gas));
+    assert!(progress
+        .iter()
+        .all(|progress| progress.remaining_gas < SCRIPT_GAS_LIMIT
+            && progress.remaining_context_gas <= progress.remaining_gas));
+}
+
+#[test]
+fn progress__is_not_notified_without_stride() {
+    // Given
+    let script = seven_instructions();
+
+    // When
+    let progress = execute(script, None);
+
+    // Then
+    assert!(progress.is_empty());
+}
+
+#[test]
+fn progress__zero_stride_never_notifies() {
+    // Given
+    let script = seven_instructions();
+
+    // When
+    let progress = execute(script, Some(0));
+
+    // Then
+    assert!(progress.is_empty());
+}
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use fuel_asm::{
     op,
     PanicReason,
@@ -6,6 +8,7 @@ use fuel_asm::{
 use fuel_tx::{
     Receipt,
     ScriptExecutionResult,
+    ScriptReturn,
 };
 
 use alloc::vec;
@@ -68,3 +71,48 @@ fn can_return_successfully_just_below_max_receipts() {
     };
     assert_eq!(result, ScriptExecutionResult::Success);
 }
+
+#[test]
+fn script_return__decodes_the_returned_data() {
+    let receipts = run_script(vec![
+        op::movi(0x10, 16),
+        op::aloc(0x10),
+        op::movi(0x11, 7),
+        op::sw(RegId::HP, 0x11, 0),
+        op::movi(0x11, 9),
+        op::sw(RegId::HP, 0x11, 1),
+        op::retd(RegId::HP, 0x10),
+    ]);
+
+    let script_return = ScriptReturn::from_receipts(&receipts).unwrap();
+
+    assert!(script_return.is_success());
+    assert_eq!(script_return.decode::<[u64; 2]>(), Some(Ok([7, 9])));
+}
+
+#[test]
+fn script_return__reports_the_value_and_the_revert() {
+    let returned = run_script(vec![op::movi(0x10, 42), op::ret(0x10)]);
+    let reverted = run_script(vec![op::movi(0x10, 13), op::rvrt(0x10)]);
+
+    assert_eq!(
+        ScriptReturn::from_receipts(&returned),
+        Some(ScriptReturn::Value(42))
+    );
+    assert_eq!(
+        ScriptReturn::from_receipts(&reverted),
+        Some(ScriptReturn::Reverted(13))
+    );
+}
+
+#[test]
+fn receipts_root__matches_the_root_computed_by_the_vm() {
+    let receipts = run_script(vec![
+        op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::logd(RegId::ZERO, RegId::ONE, RegId::ZERO, RegId::ONE),
+        op::ret(RegId::ONE),
+    ]);
+    let expected = ReceiptsCtx::from(receipts.clone()).root();
+
+    assert_eq!(fuel_tx::receipts_root(&receipts), expected);
+}
@@ -0,0 +1,152 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    consts::VM_MAX_RAM,
+    interpreter::MemoryInstance,
+    prelude::*,
+    state::ReturnDataError,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+
+fn transact(
+    script: Vec<Instruction>,
+) -> Transactor<MemoryInstance, MemoryStorage, Script> {
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let mut transactor = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        Default::default(),
+    );
+    transactor.transact(tx);
+    transactor
+}
+
+fn return_data_script() -> Vec<Instruction> {
+    vec![
+        op::movi(0x10, 16),
+        op::aloc(0x10),
+        op::movi(0x11, 7),
+        op::sw(RegId::HP, 0x11, 0),
+        op::movi(0x11, 9),
+        op::sw(RegId::HP, 0x11, 1),
+        op::retd(RegId::HP, 0x10),
+    ]
+}
+
+fn expected_data() -> Vec<u8> {
+    [7u64.to_be_bytes(), 9u64.to_be_bytes()].concat()
+}
+
+#[test]
+fn return_value__returns_the_word_of_ret() {
+    // Given
+    let transactor = transact(vec![op::movi(0x10, 42), op::ret(0x10)]);
+
+    // When
+    let state = *transactor.state_transition().unwrap().state();
+
+    // Then
+    assert_eq!(state.return_value(), Some(42));
+    assert_eq!(
+        state.return_data(transactor.receipts().unwrap()),
+        Err(ReturnDataError::NotReturnData)
+    );
+}
+
+#[test]
+fn return_data__returns_the_data_of_the_receipt() {
+    // Given
+    let transactor = transact(return_data_script());
+
+    // When
+    let state_transition = transactor.state_transition().unwrap();
+
+    // Then
+    assert_eq!(state_transition.state().return_value(), None);
+    assert_eq!(
+        state_transition.return_data(),
+        Ok(expected_data().as_slice())
+    );
+}
+
+#[test]
+fn return_data__fails_without_the_data_of_the_receipt() {
+    // Given
+    let transactor = transact(return_data_script());
+    let state = *transactor.state_transition().unwrap().state();
+    let mut receipts = transactor.receipts().unwrap().to_vec();
+    receipts.iter_mut().for_each(|receipt| {
+        if let Receipt::ReturnData { data, .. } = receipt {
+            *data = None;
+        }
+    });
+
+    // When
+    let result = state.return_data(&receipts);
+
+    // Then
+    assert_eq!(result, Err(ReturnDataError::DataStripped));
+    assert_eq!(
+        state.return_data_from_memory(&receipts, transactor.interpreter().memory()),
+        Ok(expected_data().as_slice())
+    );
+}
+
+#[test]
+fn return_data__fails_without_the_receipt() {
+    // Given
+    let transactor = transact(return_data_script());
+    let state = *transactor.state_transition().unwrap().state();
+
+    // When
+    let result = state.return_data(&[]);
+
+    // Then
+    assert_eq!(result, Err(ReturnDataError::MissingReceipt));
+}
+
+#[test]
+fn return_data_from_memory__checks_the_memory_bounds_and_digest() {
+    // Given
+    let transactor = transact(return_data_script());
+    let state = *transactor.state_transition().unwrap().state();
+    let receipts = transactor.receipts().unwrap();
+    let patched = |f: fn(&mut Word, &mut Word)| {
+        let mut receipts = receipts.to_vec();
+        receipts.iter_mut().for_each(|receipt| {
+            if let Receipt::ReturnData { ptr, len, .. } = receipt {
+                f(ptr, len);
+            }
+        });
+        receipts
+    };
+    let memory = MemoryInstance::new();
+
+    // When
+    let out_of_bounds = state.return_data_from_memory(
+        &patched(|ptr, _| *ptr = VM_MAX_RAM),
+        transactor.interpreter().memory(),
+    );
+    let shorter = state.return_data_from_memory(
+        &patched(|_, len| *len = 8),
+        transactor.interpreter().memory(),
+    );
+    let other_memory = state.return_data_from_memory(receipts, &memory);
+
+    // Then
+    assert_eq!(out_of_bounds, Err(ReturnDataError::OutOfBounds));
+    assert_eq!(shorter, Err(ReturnDataError::DigestMismatch));
+    assert_eq!(other_memory, Err(ReturnDataError::OutOfBounds));
+}
@@ -0,0 +1,266 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    Flags,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    Receipt,
+    Script,
+    ScriptExecutionResult,
+};
+use fuel_types::canonical::Serialize;
+
+/// Reads the storage slot at the key zero.
+fn reader() -> Vec<Instruction> {
+    vec![op::srw(0x10, 0x11, RegId::ZERO), op::ret(RegId::ONE)]
+}
+
+/// Writes the storage slot at the key zero.
+fn writer() -> Vec<Instruction> {
+    vec![op::sww(RegId::ZERO, 0x10, RegId::ONE), op::ret(RegId::ONE)]
+}
+
+/// Calls the contract described by the second `Call` of the script data.
+fn forwarder() -> Vec<Instruction> {
+    vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x10, 0x10, Call::LEN as u16),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+}
+
+fn set_static_call(enabled: bool) -> Vec<Instruction> {
+    let flags = if enabled {
+        Flags::STATIC_CALL
+    } else {
+        Flags::empty()
+    };
+    vec![op::movi(0x20, flags.bits() as u32), op::flag(0x20)]
+}
+
+/// Calls the first contract with the given amount of coins, and then the second one
+/// with the `$flag` cleared, if there is one.
+fn call_contracts(
+    contracts: Vec<Vec<Instruction>>,
+    is_static: bool,
+    coins: u32,
+) -> Vec<Receipt> {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_ids: Vec<_> = contracts
+        .into_iter()
+        .map(|contract| {
+            test_context
+                .setup_contract(contract, None, None)
+                .contract_id
+        })
+        .collect();
+
+    let mut script = set_static_call(is_static);
+    script.extend([
+        op::movi(0x11, coins),
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, 0x11, RegId::ZERO, RegId::CGAS),
+    ]);
+    if contract_ids.len() > 1 {
+        script.extend(set_static_call(false));
+        script.extend([
+            op::addi(0x10, 0x10, Call::LEN as u16),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        ]);
+    }
+    script.push(op::ret(RegId::ONE));
+
+    let script_data = contract_ids
+        .iter()
+        .flat_map(|id| Call::new(*id, 0, 0).to_bytes())
+        .collect();
+
+    let builder = test_context.start_script(script, script_data);
+    builder.script_gas_limit(1_000_000).fee_input();
+    for id in &contract_ids {
+        builder.contract_input(*id).contract_output(id);
+    }
+    let tx = builder.build();
+
+    execute(&test_context, tx, true)
+}
+
+/// Executes the transaction, with the static calls enabled or not.
+fn execute(
+    test_context: &TestBuilder,
+    tx: Checked<Script>,
+    static_calls: bool,
+) -> Vec<Receipt> {
+    let params = InterpreterParams {
+        static_calls,
+        ..InterpreterParams::new(0, test_context.get_consensus_params())
+    };
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        params,
+    );
+    transactor.transact(tx);
+
+    transactor
+        .result()
+        .expect("expected successful vm execution")
+        .receipts()
+        .to_vec()
+}
+
+/// Sets the flags in a script, with the static calls enabled or not.
+fn set_flags(flags: Word, static_calls: bool) -> Vec<Receipt> {
+    let mut test_context = TestBuilder::new(2322u64);
+    let script = vec![
+        op::movi(0x20, flags as u32),
+        op::flag(0x20),
+        op::ret(RegId::ONE),
+    ];
+    let tx = test_context
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+
+    execute(&test_context, tx, static_calls)
+}
+
+fn panic_reason(receipts: &[Receipt]) -> Option<PanicReason> {
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    })
+}
+
+fn assert_success(receipts: &[Receipt]) {
+    assert_eq!(panic_reason(receipts), None);
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn static_call__reading_state_is_allowed() {
+    // Given
+    let contracts = vec![reader()];
+
+    // When
+    let receipts = call_contracts(contracts, true, 0);
+
+    // Then
+    assert_success(&receipts);
+}
+
+#[test]
+fn static_call__writing_state_panics() {
+    // Given
+    let contracts = vec![writer()];
+
+    // When
+    let receipts = call_contracts(contracts, true, 0);
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StateChangeInStaticCall)
+    );
+}
+
+#[test]
+fn static_call__forwarding_coins_panics() {
+    // Given
+    let contracts = vec![reader()];
+
+    // When
+    let receipts = call_contracts(contracts, true, 1);
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StateChangeInStaticCall)
+    );
+}
+
+#[test]
+fn static_call__nested_calls_are_static() {
+    // Given
+    let contracts = vec![forwarder(), writer()];
+
+    // When
+    let receipts = call_contracts(contracts, true, 0);
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StateChangeInStaticCall)
+    );
+}
+
+#[test]
+fn static_call__ends_when_the_callee_returns() {
+    // Given
+    let contracts = vec![reader(), writer()];
+
+    // When
+    let receipts = call_contracts(contracts, true, 0);
+
+    // Then
+    assert_success(&receipts);
+}
+
+#[test]
+fn static_call__state_is_writable_without_the_flag() {
+    // Given
+    let contracts = vec![forwarder(), writer()];
+
+    // When
+    let receipts = call_contracts(contracts, false, 0);
+
+    // Then
+    assert_success(&receipts);
+}
+
+#[test]
+fn static_call__flag_combinations_are_allowed_when_enabled() {
+    for flags in [0b100, 0b111] {
+        // When
+        let receipts = set_flags(flags, true);
+
+        // Then
+        assert_success(&receipts);
+    }
+}
+
+#[test]
+fn static_call__flag_panics_when_disabled() {
+    for flags in [0b100, 0b111] {
+        // When
+        let receipts = set_flags(flags, false);
+
+        // Then
+        assert_eq!(panic_reason(&receipts), Some(PanicReason::InvalidFlags));
+    }
+}
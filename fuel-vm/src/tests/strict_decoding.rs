@@ -0,0 +1,221 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::IntoChecked,
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+        NotSupportedEcal,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    GMArgs,
+    Instruction,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Create,
+    Finalizable,
+    Receipt,
+    Salt,
+    TransactionBuilder,
+};
+
+/// Comparison with the reserved flag bits set.
+fn non_canonical_instruction() -> Instruction {
+    op::wdcm(0x10, RegId::ZERO, RegId::ZERO, 0b011000)
+}
+
+fn params(strict_instruction_decoding: bool) -> InterpreterParams {
+    InterpreterParams {
+        strict_instruction_decoding,
+        ..Default::default()
+    }
+}
+
+fn run_script(script: Vec<Instruction>, params: InterpreterParams) -> Vec<Receipt> {
+    let consensus_params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate checked tx");
+
+    let mut client = MemoryClient::<_, NotSupportedEcal>::new(
+        MemoryInstance::new(),
+        Default::default(),
+        params,
+    );
+    client.transact(tx).to_vec()
+}
+
+/// Bytecode laid out like a Sway contract: a preamble holding the offset of the data
+/// section, the code, and the data section.
+fn with_data_section(code: Vec<Instruction>, data: &[u8]) -> Vec<u8> {
+    let preamble_len = 4 * Instruction::SIZE;
+    let data_offset = preamble_len + code.len() * Instruction::SIZE;
+
+    let mut bytecode: Vec<u8> = [op::move_(0x10, RegId::PC), op::jmpf(RegId::ZERO, 2)]
+        .into_iter()
+        .collect();
+    bytecode.extend((data_offset as Word).to_be_bytes());
+    bytecode.extend(code.into_iter().collect::<Vec<u8>>());
+    bytecode.extend(data);
+    bytecode
+}
+
+fn deploy(
+    bytecode: Vec<Instruction>,
+    params: InterpreterParams,
+) -> Result<Create, InterpreterError<<MemoryStorage as InterpreterStorage>::DataError>> {
+    deploy_bytes(bytecode.into_iter().collect(), params)
+}
+
+fn deploy_bytes(
+    bytecode: Vec<u8>,
+    params: InterpreterParams,
+) -> Result<Create, InterpreterError<<MemoryStorage as InterpreterStorage>::DataError>> {
+    let consensus_params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::create(bytecode.into(), Salt::zeroed(), vec![])
+        .add_fee_input()
+        .add_contract_created()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate checked tx")
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .expect("failed to make tx ready");
+
+    let mut vm = Interpreter::<_, _, Create>::with_storage(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        params,
+    );
+    vm.deploy(tx)
+}
+
+#[test]
+fn non_canonical_instruction__panics_with_error_in_default_mode() {
+    // Given
+    let script = vec![non_canonical_instruction(), op::ret(RegId::ONE)];
+
+    // When
+    let receipts = run_script(script, params(false));
+
+    // Then
+    // The instruction is decoded, but its arguments are rejected during execution.
+    assert!(matches!(
+        receipts[0],
+        Receipt::Panic { reason, .. } if reason.reason() == &PanicReason::InvalidImmediateValue
+    ));
+}
+
+#[test]
+fn non_canonical_instruction__panics_as_invalid_instruction_in_strict_mode() {
+    // Given
+    let script = vec![non_canonical_instruction(), op::ret(RegId::ONE)];
+
+    // When
+    let receipts = run_script(script, params(true));
+
+    // Then
+    assert!(matches!(
+        receipts[0],
+        Receipt::Panic { reason, .. } if reason.reason() == &PanicReason::InvalidInstruction
+    ));
+}
+
+#[test]
+fn canonical_instructions__execute_in_strict_mode() {
+    // Given
+    let script = vec![op::gm_args(0x10, GMArgs::GetChainId), op::ret(RegId::ONE)];
+
+    // When
+    let receipts = run_script(script, params(true));
+
+    // Then
+    assert!(matches!(receipts[0], Receipt::Return { val: 1, .. }));
+}
+
+#[test]
+fn deploy__accepts_non_canonical_bytecode_in_default_mode() {
+    // Given
+    let bytecode = vec![non_canonical_instruction(), op::ret(RegId::ONE)];
+
+    // When
+    let result = deploy(bytecode, params(false));
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[test]
+fn deploy__rejects_non_canonical_bytecode_in_strict_mode() {
+    // Given
+    let bytecode = vec![non_canonical_instruction(), op::ret(RegId::ONE)];
+
+    // When
+    let result = deploy(bytecode, params(true));
+
+    // Then
+    assert_eq!(
+        result.unwrap_err(),
+        InterpreterError::Panic(PanicReason::InvalidInstruction)
+    );
+}
+
+#[test]
+fn deploy__accepts_canonical_bytecode_in_strict_mode() {
+    // Given
+    let bytecode = vec![op::noop(), op::ret(RegId::ONE)];
+
+    // When
+    let result = deploy(bytecode, params(true));
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[test]
+fn deploy__accepts_data_section_in_strict_mode() {
+    // Given
+    let code = vec![op::noop(), op::ret(RegId::ONE)];
+    let mut data: Vec<u8> = [non_canonical_instruction()].into_iter().collect();
+    data.extend([0xff; 8]);
+    let bytecode = with_data_section(code, &data);
+
+    // When
+    let result = deploy_bytes(bytecode, params(true));
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[test]
+fn deploy__rejects_non_canonical_code_before_data_section_in_strict_mode() {
+    // Given
+    let code = vec![non_canonical_instruction(), op::ret(RegId::ONE)];
+    let bytecode = with_data_section(code, &[0xff; 8]);
+
+    // When
+    let result = deploy_bytes(bytecode, params(true));
+
+    // Then
+    assert_eq!(
+        result.unwrap_err(),
+        InterpreterError::Panic(PanicReason::InvalidInstruction)
+    );
+}
@@ -0,0 +1,98 @@
+#![allow(non_snake_case)]
+
+use crate::test_vectors::{
+    generate_corpus,
+    write_corpus,
+    StateChange,
+    FORMAT,
+    FORMAT_VERSION,
+};
+
+#[test]
+fn write_corpus__same_seed_produces_same_corpus() {
+    // Given
+    let mut first = vec![];
+    let mut second = vec![];
+
+    // When
+    write_corpus(&mut first, 42, 8).unwrap();
+    write_corpus(&mut second, 42, 8).unwrap();
+
+    // Then
+    assert_eq!(first, second);
+}
+
+#[test]
+fn write_corpus__different_seeds_produce_different_corpora() {
+    // Given
+    let mut first = vec![];
+    let mut second = vec![];
+
+    // When
+    write_corpus(&mut first, 1, 4).unwrap();
+    write_corpus(&mut second, 2, 4).unwrap();
+
+    // Then
+    assert_ne!(first, second);
+}
+
+#[test]
+fn write_corpus__starts_with_header_followed_by_vectors() {
+    // Given
+    let mut corpus = vec![];
+
+    // When
+    write_corpus(&mut corpus, 3, 5).unwrap();
+
+    // Then
+    let corpus = String::from_utf8(corpus).unwrap();
+    let lines: Vec<serde_json::Value> = corpus
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[0]["format"], FORMAT);
+    assert_eq!(lines[0]["version"], FORMAT_VERSION);
+    assert_eq!(lines[0]["seed"], "3");
+    assert_eq!(lines[0]["count"], 5);
+    for (index, vector) in lines[1..].iter().enumerate() {
+        assert_eq!(vector["index"], index);
+    }
+}
+
+#[test]
+fn generate_corpus__vectors_end_with_script_result() {
+    // When
+    let vectors: Vec<_> = generate_corpus(5, 16).collect();
+
+    // Then
+    for vector in vectors {
+        let receipts = vector.expected.receipts.as_array().unwrap();
+        assert_eq!(receipts.last().unwrap()["type"], "ScriptResult");
+        assert_eq!(vector.fixture.contracts.len(), 1);
+    }
+}
+
+#[test]
+fn generate_corpus__successful_contract_calls_change_state() {
+    // When
+    let vectors: Vec<_> = generate_corpus(1, 16).collect();
+
+    // Then
+    let calls = vectors
+        .iter()
+        .filter(|vector| {
+            let receipts = vector.expected.receipts.as_array().unwrap();
+            receipts.first().unwrap()["type"] == "Call"
+                && receipts.last().unwrap()["result"]["type"] == "Success"
+        })
+        .collect::<Vec<_>>();
+    assert!(!calls.is_empty());
+    for vector in calls {
+        assert!(vector
+            .expected
+            .state_diff
+            .iter()
+            .any(|change| matches!(change, StateChange::Slot { .. })));
+    }
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "tracing")]
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use std::sync::{
+    atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    Arc,
+    Mutex,
+};
+
+use crate::{
+    checked_transaction::IntoChecked,
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::ConsensusParameters;
+use tracing::{
+    span,
+    subscriber::with_default,
+    Event,
+    Metadata,
+    Subscriber,
+};
+
+/// Collects the names of the created spans.
+#[derive(Default)]
+struct SpanNames {
+    names: Arc<Mutex<Vec<&'static str>>>,
+    next_id: AtomicU64,
+}
+
+impl Subscriber for SpanNames {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        self.names.lock().unwrap().push(span.metadata().name());
+        span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+#[test]
+fn tracing__spans_cover_checks_and_execution() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize();
+    let subscriber = SpanNames::default();
+    let names = subscriber.names.clone();
+
+    // When
+    with_default(subscriber, || {
+        let checked = tx.into_checked(Default::default(), &params).unwrap();
+        let mut transactor = Transactor::<_, _, Script>::new(
+            MemoryInstance::new(),
+            MemoryStorage::default(),
+            InterpreterParams::new(0, &params),
+        );
+        transactor.transact(checked);
+        assert!(transactor.is_success());
+    });
+
+    // Then
+    let names = names.lock().unwrap().clone();
+    for expected in [
+        "check_transaction",
+        "check_signatures",
+        "run_predicates",
+        "transact",
+        "execute",
+        "execute_script",
+    ] {
+        assert!(
+            names.contains(&expected),
+            "missing `{expected}` in {names:?}"
+        );
+    }
+}
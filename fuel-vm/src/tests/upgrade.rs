@@ -9,7 +9,10 @@ use crate::{
         InterpreterParams,
     },
     prelude::*,
-    storage::MemoryStorage,
+    storage::{
+        JournaledStorage,
+        MemoryStorage,
+    },
 };
 use fuel_asm::{
     op,
@@ -255,6 +258,34 @@ mod state_transition {
             result
         );
     }
+
+    #[test]
+    fn transact_over_journal_fails_when_try_to_override_stored_state_bytecode() {
+        let state_transition_hash = [1; 32].into();
+        let mut storage = valid_storage(state_transition_hash, vec![]);
+        storage
+            .state_transition_bytecodes_versions_mut()
+            .insert(CURRENT_STATE_TRANSITION_VERSION + 1, state_transition_hash);
+        let mut client = Interpreter::<_, _, Upgrade>::with_storage(
+            MemoryInstance::new(),
+            JournaledStorage::new(storage),
+            InterpreterParams::default(),
+        );
+
+        // Given
+        let tx = valid_transaction(state_transition_hash).test_into_ready();
+
+        // When
+        let result = client.transact(tx).map(|_| ());
+
+        // Then
+        assert_eq!(
+            Err(InterpreterError::Panic(
+                PanicReason::OverridingStateTransactionBytecode
+            )),
+            result
+        );
+    }
 }
 
 mod consensus_parameters {
@@ -413,4 +444,32 @@ mod consensus_parameters {
             result
         );
     }
+
+    #[test]
+    fn transact_over_journal_fails_when_try_to_override_stored_consensus_parameters() {
+        let mut storage = valid_storage();
+        storage.consensus_parameters_versions_mut().insert(
+            CURRENT_CONSENSUS_PARAMETERS_VERSION + 1,
+            ConsensusParameters::standard(),
+        );
+        let mut client = Interpreter::<_, _, Upgrade>::with_storage(
+            MemoryInstance::new(),
+            JournaledStorage::new(storage),
+            InterpreterParams::default(),
+        );
+
+        // Given
+        let tx = valid_transaction().test_into_ready();
+
+        // When
+        let result = client.transact(tx).map(|_| ());
+
+        // Then
+        assert_eq!(
+            Err(InterpreterError::Panic(
+                PanicReason::OverridingConsensusParameters
+            )),
+            result
+        );
+    }
 }
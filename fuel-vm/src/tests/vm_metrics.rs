@@ -0,0 +1,114 @@
+#![cfg(feature = "std")]
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use crate::{
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+        VmMetrics,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    Opcode,
+    RegId,
+};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Counters {
+    instructions: u64,
+    storage_reads: Vec<Opcode>,
+    storage_writes: Vec<Opcode>,
+    memory_grown: Word,
+    calls: u64,
+}
+
+#[derive(Clone, Default)]
+struct CollectedMetrics(Arc<Mutex<Counters>>);
+
+impl VmMetrics for CollectedMetrics {
+    fn on_instruction(&self, _opcode: Opcode) {
+        self.0.lock().unwrap().instructions += 1;
+    }
+
+    fn on_storage_read(&self, opcode: Opcode) {
+        self.0.lock().unwrap().storage_reads.push(opcode);
+    }
+
+    fn on_storage_write(&self, opcode: Opcode) {
+        self.0.lock().unwrap().storage_writes.push(opcode);
+    }
+
+    fn on_memory_grown(&self, bytes: Word) {
+        self.0.lock().unwrap().memory_grown += bytes;
+    }
+
+    fn on_call(&self) {
+        self.0.lock().unwrap().calls += 1;
+    }
+}
+
+fn execute(script: Vec<Instruction>) -> Counters {
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    let metrics = CollectedMetrics::default();
+    let mut interpreter = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+    );
+    interpreter.with_vm_metrics(metrics.clone());
+    let mut transactor = Transactor::from(interpreter);
+    transactor.transact(tx);
+
+    let counters = metrics.0.lock().unwrap().clone();
+    counters
+}
+
+#[test]
+fn vm_metrics__counts_instructions_and_memory_growth() {
+    // Given
+    let script = vec![
+        op::movi(0x10, 100),
+        op::aloc(0x10),
+        op::cfei(32),
+        op::cfsi(32),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let counters = execute(script);
+
+    // Then
+    assert_eq!(counters.instructions, 5);
+    assert_eq!(counters.memory_grown, 132);
+    assert!(counters.storage_reads.is_empty());
+    assert!(counters.storage_writes.is_empty());
+    assert_eq!(counters.calls, 0);
+}
+
+#[test]
+fn vm_metrics__counts_storage_instructions_even_if_they_panic() {
+    // Given
+    let script = vec![op::srw(0x10, 0x11, RegId::ZERO), op::ret(RegId::ONE)];
+
+    // When
+    let counters = execute(script);
+
+    // Then
+    assert_eq!(counters.storage_reads, [Opcode::SRW]);
+    assert!(counters.storage_writes.is_empty());
+}
@@ -2,6 +2,7 @@
 
 use crate::{
     backtrace::Backtrace,
+    call_tree::CallTree,
     checked_transaction::{
         Checked,
         IntoChecked,
@@ -15,6 +16,7 @@ use crate::{
         Interpreter,
         InterpreterParams,
         Memory,
+        MemoryStats,
         NotSupportedEcal,
     },
     state::{
@@ -27,6 +29,7 @@ use crate::{
 use fuel_tx::{
     Blob,
     Create,
+    FeeBreakdown,
     FeeParameters,
     GasCosts,
     Receipt,
@@ -163,6 +166,33 @@ where
         self.interpreter.fee_params()
     }
 
+    /// Fee accounting of the last executed transaction, as used to compute the
+    /// refund in its change outputs.
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return `None`.
+    pub fn fee_breakdown(&self) -> Option<FeeBreakdown> {
+        self.program_state?;
+
+        // Only scripts use gas during the execution, and report it in the last receipt
+        let used_gas = self
+            .interpreter
+            .receipts()
+            .iter()
+            .rev()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        self.interpreter.transaction().fee_breakdown(
+            self.gas_costs(),
+            self.fee_params(),
+            used_gas,
+            self.interpreter.gas_price(),
+        )
+    }
+
     #[cfg(feature = "test-helpers")]
     /// Sets the gas price of the `Interpreter`
     pub fn set_gas_price(&mut self, gas_price: u64) {
@@ -173,6 +203,11 @@ where
     pub fn tx_offset(&self) -> usize {
         self.interpreter.tx_offset()
     }
+
+    /// The peak memory usage of the last execution.
+    pub fn memory_stats(&self) -> MemoryStats {
+        self.interpreter.memory_stats()
+    }
 }
 
 impl<M, S, Ecal> Transactor<M, S, Script, Ecal>
@@ -198,6 +233,15 @@ where
             .copied()
             .map(|result| Backtrace::from_vm_error(&self.interpreter, result))
     }
+
+    /// Tree of the contract calls made by the last executed script, with the gas
+    /// forwarded to and used by each of them.
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return `None`.
+    pub fn call_tree(&self) -> Option<CallTree> {
+        self.program_state?;
+        Some(CallTree::from_vm(&self.interpreter))
+    }
 }
 
 impl<M, S, Tx, Ecal> Transactor<M, S, Tx, Ecal>
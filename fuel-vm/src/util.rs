@@ -81,6 +81,10 @@ macro_rules! script_with_data_offset {
     }};
 }
 
+#[cfg(feature = "random")]
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod program_generator;
+
 #[allow(missing_docs)]
 #[cfg(feature = "random")]
 #[cfg(any(test, feature = "test-helpers"))]
@@ -92,6 +96,7 @@ pub mod test_helpers {
     };
 
     use crate::{
+        call_tree::CallTree,
         checked_transaction::{
             builder::TransactionBuilderExt,
             Checked,
@@ -368,6 +373,10 @@ pub mod test_helpers {
             self.builder.finalize_checked(self.block_height)
         }
 
+        pub fn get_consensus_params(&self) -> &ConsensusParameters {
+            &self.consensus_params
+        }
+
         pub fn get_tx_params(&self) -> &TxParameters {
             self.consensus_params.tx_params()
         }
@@ -640,6 +649,24 @@ pub mod test_helpers {
             Ok((state, backtrace))
         }
 
+        pub fn execute_tx_with_call_tree(
+            &mut self,
+            checked: Checked<Script>,
+        ) -> anyhow::Result<(StateTransition<Script>, CallTree)> {
+            let interpreter_params =
+                InterpreterParams::new(self.gas_price, &self.consensus_params);
+            let mut transactor = Transactor::<_, _, _>::new(
+                MemoryInstance::new(),
+                self.storage.clone(),
+                interpreter_params,
+            );
+
+            let state = self.execute_tx_inner(&mut transactor, checked)?;
+            let call_tree = transactor.call_tree().unwrap_or_default();
+
+            Ok((state, call_tree))
+        }
+
         /// Build test tx and execute it
         pub fn execute(&mut self) -> StateTransition<Script> {
             let tx = self.build();
@@ -0,0 +1,277 @@
+//! Deterministic generation of random programs for stress testing the interpreter.
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use fuel_asm::{
+    op,
+    Flags,
+    Instruction,
+    RegId,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// A group of the instructions emitted by [`ProgramGenerator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpcodeGroup {
+    /// The arithmetic and the logic instructions, e.g. `ADD`, `EXP` or `MOVI`.
+    Arithmetic,
+    /// Loads, stores, copies and clears of the allocated heap.
+    Memory,
+    /// Conditional forward jumps over a nested block.
+    Branch,
+    /// Bounded loops over a nested block.
+    Loop,
+    /// `LOG` of the general purpose registers.
+    Log,
+}
+
+impl OpcodeGroup {
+    /// All of the groups.
+    pub const ALL: [Self; 5] = [
+        Self::Arithmetic,
+        Self::Memory,
+        Self::Branch,
+        Self::Loop,
+        Self::Log,
+    ];
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The first general purpose register written by the programs.
+const FIRST_REGISTER: u8 = 0x10;
+/// The number of the general purpose registers written by the programs.
+const REGISTERS: u8 = 0x20;
+/// The counters of the loops, one per nesting level.
+const LOOP_COUNTERS: u8 = 0x30;
+/// A scratch register of the prologue.
+const SCRATCH: u8 = 0x3d;
+/// The middle of the allocated heap.
+const HEAP_MIDDLE: u8 = 0x3e;
+/// The start of the allocated heap.
+const HEAP_START: u8 = 0x3f;
+/// The size of the allocated heap.
+const HEAP_SIZE: u16 = 1024;
+/// The maximum number of the instructions skipped by a branch or repeated by a loop.
+const MAX_BLOCK_LEN: usize = 64;
+
+/// Generates syntactically valid random programs, which always terminate.
+///
+/// The programs start with a prologue setting [`Flags::UNSAFEMATH`] and
+/// [`Flags::WRAPPING`], allocating a heap and filling the general purpose registers
+/// with random values, so that the arithmetic and the memory instructions don't
+/// panic. The only backward jumps are the ones of the loops, whose counters live in
+/// the reserved registers, so every loop runs at most `max_iterations` times. The
+/// programs end with `RET`.
+///
+/// The same seed and configuration always generate the same programs.
+#[derive(Debug, Clone)]
+pub struct ProgramGenerator {
+    rng: StdRng,
+    weights: [u32; OpcodeGroup::ALL.len()],
+    len: usize,
+    max_iterations: u16,
+    max_loop_depth: u8,
+}
+
+impl ProgramGenerator {
+    /// Creates the generator with the default configuration: programs of 256
+    /// instructions besides the prologue, mostly arithmetic, with up to 3 nested loops
+    /// of up to 8 iterations.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            weights: [10, 4, 2, 1, 1],
+            len: 256,
+            max_iterations: 8,
+            max_loop_depth: 3,
+        }
+    }
+
+    /// Sets the relative weight of the group. The group isn't emitted if the weight
+    /// is zero. At least one group must have a non-zero weight.
+    pub fn with_weight(mut self, group: OpcodeGroup, weight: u32) -> Self {
+        self.weights[group.index()] = weight;
+        self
+    }
+
+    /// Sets the number of the instructions after the prologue, excluding the final
+    /// `RET`.
+    pub fn with_len(mut self, len: usize) -> Self {
+        self.len = len;
+        self
+    }
+
+    /// Sets the maximum number of the iterations of a loop.
+    pub fn with_max_iterations(mut self, max_iterations: u16) -> Self {
+        self.max_iterations = max_iterations.max(1);
+        self
+    }
+
+    /// Sets the maximum nesting of the loops, up to 8.
+    pub fn with_max_loop_depth(mut self, max_loop_depth: u8) -> Self {
+        self.max_loop_depth = max_loop_depth.min(8);
+        self
+    }
+
+    /// Generates the next program.
+    pub fn generate(&mut self) -> Vec<Instruction> {
+        assert!(
+            self.weights.iter().any(|weight| *weight > 0),
+            "At least one group must have a non-zero weight"
+        );
+
+        let mut program = self.prologue();
+        self.block(&mut program, self.len, 0);
+        let value = self.register();
+        program.push(op::ret(value));
+        program
+    }
+
+    fn prologue(&mut self) -> Vec<Instruction> {
+        let flags = (Flags::UNSAFEMATH | Flags::WRAPPING).bits();
+        let flags = u32::try_from(flags).expect("The flags fit into an immediate");
+        let mut program = vec![
+            op::movi(SCRATCH, flags),
+            op::flag(SCRATCH),
+            op::movi(SCRATCH, HEAP_SIZE.into()),
+            op::aloc(SCRATCH),
+            op::move_(HEAP_START, RegId::HP),
+            op::addi(HEAP_MIDDLE, HEAP_START, HEAP_SIZE / 2),
+        ];
+        for offset in 0..REGISTERS {
+            let value = self.rng.gen_range(0..1 << 18);
+            program.push(op::movi(FIRST_REGISTER.saturating_add(offset), value));
+        }
+        program
+    }
+
+    /// Emits exactly `len` instructions.
+    fn block(&mut self, program: &mut Vec<Instruction>, len: usize, depth: u8) {
+        let mut left = len;
+        while left > 0 {
+            let emitted = match self.group() {
+                OpcodeGroup::Branch if left >= 2 => {
+                    let skipped = self
+                        .rng
+                        .gen_range(1..=left.saturating_sub(1).min(MAX_BLOCK_LEN));
+                    let condition = self.register();
+                    let skipped_imm = u16::try_from(skipped)
+                        .expect("The block is shorter than `MAX_BLOCK_LEN`");
+                    program.push(op::jnzf(condition, RegId::ZERO, skipped_imm));
+                    self.block(program, skipped, depth);
+                    skipped.saturating_add(1)
+                }
+                OpcodeGroup::Loop if left >= 4 && depth < self.max_loop_depth => {
+                    let body = self
+                        .rng
+                        .gen_range(1..=left.saturating_sub(3).min(MAX_BLOCK_LEN));
+                    let counter = LOOP_COUNTERS.saturating_add(depth);
+                    let iterations = self.rng.gen_range(1..=self.max_iterations);
+                    program.push(op::movi(counter, iterations.into()));
+                    self.block(program, body, depth.saturating_add(1));
+                    program.push(op::subi(counter, counter, 1));
+                    // Jumps back to the first instruction of the body.
+                    let body_imm = u16::try_from(body)
+                        .expect("The block is shorter than `MAX_BLOCK_LEN`");
+                    program.push(op::jnzb(counter, RegId::ZERO, body_imm));
+                    body.saturating_add(3)
+                }
+                OpcodeGroup::Memory => {
+                    let instruction = self.memory();
+                    program.push(instruction);
+                    1
+                }
+                OpcodeGroup::Log => {
+                    let instruction = op::log(
+                        self.register(),
+                        self.register(),
+                        self.register(),
+                        self.register(),
+                    );
+                    program.push(instruction);
+                    1
+                }
+                _ => {
+                    let instruction = self.arithmetic();
+                    program.push(instruction);
+                    1
+                }
+            };
+            left = left.saturating_sub(emitted);
+        }
+    }
+
+    fn group(&mut self) -> OpcodeGroup {
+        let total: u32 = self.weights.iter().sum();
+        let mut pick = self.rng.gen_range(0..total);
+        for group in OpcodeGroup::ALL {
+            let weight = self.weights[group.index()];
+            if pick < weight {
+                return group
+            }
+            pick = pick.saturating_sub(weight);
+        }
+        unreachable!("The pick is less than the total weight")
+    }
+
+    /// A random general purpose register.
+    fn register(&mut self) -> u8 {
+        FIRST_REGISTER.saturating_add(self.rng.gen_range(0..REGISTERS))
+    }
+
+    fn arithmetic(&mut self) -> Instruction {
+        let (dst, lhs, rhs) = (self.register(), self.register(), self.register());
+        let imm12 = self.rng.gen_range(0..1 << 12);
+        match self.rng.gen_range(0..20) {
+            0 => op::add(dst, lhs, rhs),
+            1 => op::sub(dst, lhs, rhs),
+            2 => op::mul(dst, lhs, rhs),
+            3 => op::div(dst, lhs, rhs),
+            4 => op::mod_(dst, lhs, rhs),
+            5 => op::and(dst, lhs, rhs),
+            6 => op::or(dst, lhs, rhs),
+            7 => op::xor(dst, lhs, rhs),
+            8 => op::sll(dst, lhs, rhs),
+            9 => op::srl(dst, lhs, rhs),
+            10 => op::eq(dst, lhs, rhs),
+            11 => op::lt(dst, lhs, rhs),
+            12 => op::gt(dst, lhs, rhs),
+            13 => op::exp(dst, lhs, rhs),
+            14 => op::not(dst, lhs),
+            15 => op::move_(dst, lhs),
+            16 => op::addi(dst, lhs, imm12),
+            17 => op::subi(dst, lhs, imm12),
+            18 => op::muli(dst, lhs, imm12),
+            _ => op::movi(dst, self.rng.gen_range(0..1 << 18)),
+        }
+    }
+
+    fn memory(&mut self) -> Instruction {
+        let register = self.register();
+        let words = HEAP_SIZE / 8;
+        match self.rng.gen_range(0..6) {
+            0 => op::sw(HEAP_START, register, self.rng.gen_range(0..words)),
+            1 => op::lw(register, HEAP_START, self.rng.gen_range(0..words)),
+            2 => op::sb(HEAP_START, register, self.rng.gen_range(0..HEAP_SIZE)),
+            3 => op::lb(register, HEAP_START, self.rng.gen_range(0..HEAP_SIZE)),
+            4 => op::mcpi(
+                HEAP_START,
+                HEAP_MIDDLE,
+                self.rng.gen_range(0..=HEAP_SIZE / 2),
+            ),
+            _ => op::mcli(
+                HEAP_MIDDLE,
+                self.rng.gen_range(0..=u32::from(HEAP_SIZE / 2)),
+            ),
+        }
+    }
+}